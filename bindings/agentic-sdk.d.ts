@@ -0,0 +1,1135 @@
+// Generated by agentic-sdk (`typescript` feature). Do not edit.
+
+/**
+ * Action outcome.
+ */
+export type ActionOutcome = { "status": "success", result: JsonValue | null, } | { "status": "failure", error_code: string, error_message: string, } | { "status": "partial", result: JsonValue | null, warnings: Array<string>, 
+/**
+ * Per-item failures, for batch actions
+ */
+errors?: ErrorGroup | null, };
+
+/**
+ * Action record to be receipted.
+ */
+export type ActionRecord = { 
+/**
+ * What sister performed this.
+ */
+sister_type: SisterType, 
+/**
+ * What action was performed.
+ */
+action_type: string, 
+/**
+ * Action parameters (sanitized - no secrets).
+ */
+parameters: { [key in string]?: JsonValue }, 
+/**
+ * Outcome.
+ */
+outcome: ActionOutcome, 
+/**
+ * Evidence pointers.
+ */
+evidence_ids: Array<string>, 
+/**
+ * Context ID where this happened.
+ */
+context_id: ContextId | null, 
+/**
+ * Hydra run this action belongs to.
+ */
+run_id?: string | null, 
+/**
+ * Step within the Hydra run.
+ */
+step_id?: number | null, 
+/**
+ * Timestamp.
+ */
+timestamp: string, };
+
+/**
+ * Resource limits for a command or run. `None` fields are unlimited.
+ */
+export type Budget = { max_tokens?: number | null, max_cost_usd?: number | null, 
+/**
+ * Serialized as milliseconds
+ */
+max_duration?: number, };
+
+/**
+ * Capability that a sister provides.
+ */
+export type Capability = { name: string, description: string, 
+/**
+ * Preference when several sisters offer this capability (higher wins)
+ */
+priority?: number, 
+/**
+ * What this sister is best at for the capability (e.g. "code",
+ * "conversation"); planners match these against task hints
+ */
+tags?: Array<string>, };
+
+/**
+ * When a sister should checkpoint on its own.
+ *
+ * Either trigger fires a checkpoint; with neither set, checkpoints are
+ * only taken when asked.
+ */
+export type CheckpointPolicy = { 
+/**
+ * Checkpoint after this many new items
+ */
+every_items?: number | null, 
+/**
+ * Checkpoint when this much time has passed since the last one
+ */
+every?: number, 
+/**
+ * Keep at most this many checkpoints per context (oldest dropped first)
+ */
+max_checkpoints?: number | null, };
+
+/**
+ * A payload encoding.
+ */
+export type Codec = "json" | "msgpack" | "cbor";
+
+/**
+ * Result of executing a Hydra command
+ */
+export type CommandResult = { 
+/**
+ * Whether the command succeeded
+ */
+success: boolean, 
+/**
+ * Result data
+ */
+data: JsonValue, 
+/**
+ * Error message (if failed)
+ */
+error: string | null, 
+/**
+ * Evidence IDs produced by this command
+ */
+evidence_ids: Array<string>, 
+/**
+ * Resources the command consumed
+ */
+usage?: Usage | null, };
+
+/**
+ * One entry of `HealthStatus::components`.
+ */
+export type ComponentHealth = { 
+/**
+ * Subsystem name (e.g. "receipt_store")
+ */
+name: string, status: ComponentStatus, message?: string | null, last_checked: string, };
+
+/**
+ * Health of one subsystem.
+ */
+export type ComponentStatus = "healthy" | "degraded" | "unhealthy";
+
+/**
+ * Compression codec for file payloads.
+ */
+export type CompressionCodec = "none" | "zstd" | "lz4";
+
+/**
+ * Unique identifier for a context (session or workspace).
+ */
+export type ContextId = UniqueId;
+
+/**
+ * Full context information
+ */
+export type ContextInfo = { id: ContextId, name: string, created_at: string, updated_at: string, item_count: number, size_bytes: number, tags?: Array<string>, metadata: { [key in string]?: JsonValue }, 
+/**
+ * Context this one was forked from
+ */
+parent_id?: ContextId | null, 
+/**
+ * Contexts forked from this one
+ */
+child_ids?: Array<ContextId>, 
+/**
+ * Usage against the sister's `ContextQuota`, if one is configured
+ */
+quota?: QuotaStatus | null, };
+
+/**
+ * Size limits for a single session or workspace.
+ *
+ * Sisters check it with `enforce` before growing a context: over the
+ * limit they refuse with `ResourceExhausted`; above
+ * `QUOTA_PRESSURE_PERCENT` they should emit
+ * `SisterEvent::storage_pressure` so Hydra can archive or split work.
+ */
+export type ContextQuota = { 
+/**
+ * Maximum items per context
+ */
+max_items?: number | null, 
+/**
+ * Maximum stored bytes per context
+ */
+max_bytes?: number | null, };
+
+/**
+ * Exportable context snapshot (for backup/transfer)
+ */
+export type ContextSnapshot = { 
+/**
+ * Layout version of this struct (`SNAPSHOT_FORMAT` when written by
+ * this SDK). Absent in snapshots that predate versioning, which
+ * read as `0`
+ */
+snapshot_format: number, 
+/**
+ * Which sister type this came from
+ */
+sister_type: SisterType, 
+/**
+ * Version of the sister that created this
+ */
+version: Version, 
+/**
+ * Context information
+ */
+context_info: ContextInfo, 
+/**
+ * Serialized context data (sister-specific format).
+ * A `compress_frame` frame when `compression` is not `None`
+ */
+data: string, 
+/**
+ * Checksum of the uncompressed data (BLAKE3)
+ */
+checksum: string, 
+/**
+ * When this snapshot was created
+ */
+snapshot_at: string, 
+/**
+ * Compression applied to `data` (absent in older snapshots = none)
+ */
+compression: CompressionCodec, 
+/**
+ * Who signed the snapshot (Identity ID), if signed
+ */
+signer?: string | null, 
+/**
+ * Signature over `signing_payload()`, if signed
+ */
+signature?: string | null, };
+
+/**
+ * Summary information about a context
+ */
+export type ContextSummary = { id: ContextId, name: string, created_at: string, updated_at: string, item_count: number, size_bytes: number, tags?: Array<string>, };
+
+/**
+ * One change in a `SnapshotDelta`.
+ */
+export type DeltaOp = { "op": "append", data: string, } | { "op": "replace", data: string, } | { "op": "put", key: string, data: string, } | { "op": "delete", key: string, };
+
+/**
+ * One problem (or notable fact) found by a check.
+ */
+export type DiagnosticFinding = { 
+/**
+ * Which check produced this (`CHECK_STORAGE`, ...)
+ */
+check: string, severity: Severity, message: string, details?: JsonValue | null, };
+
+/**
+ * How thorough a self-test should be.
+ */
+export type DiagnosticLevel = "quick" | "standard" | "deep";
+
+/**
+ * Result of `Sister::self_test`.
+ */
+export type DiagnosticReport = { sister_type: SisterType, level: DiagnosticLevel, started_at: string, duration_ms: number, 
+/**
+ * Checks that ran, whether or not they found anything
+ */
+checks: Array<string>, findings: Array<DiagnosticFinding>, };
+
+/**
+ * One sister's entry in Hydra's fleet overview: identity, health,
+ * query surface, active context, and (redacted) config in one document.
+ */
+export type DiscoveryDocument = { health: HealthStatus, 
+/**
+ * Everything `query()` accepts
+ */
+query_types: Array<QueryTypeInfo>, 
+/**
+ * Active session/workspace; `None` if there is none or it failed to load
+ */
+context?: ContextSummary | null, 
+/**
+ * Config in effect; secrets serialize redacted
+ */
+config?: SisterConfig | null, generated_at: string, sister_type: SisterType, version: Version, file_extension: string, capabilities: Array<Capability>, mcp_prefix: string, requires?: Array<SisterRequirement>, optional_dependencies?: Array<SisterRequirement>, };
+
+/**
+ * Standard error codes across ALL sisters.
+ *
+ * Serialized as SCREAMING_SNAKE_CASE strings. Sisters outside the core
+ * set add their own codes with `register_error_code` instead of forking
+ * the enum; those serialize as their code string too.
+ */
+export type ErrorCode = string;
+
+/**
+ * Per-item failures of a batch operation (ground_batch, bulk import, ...).
+ *
+ * ```rust,ignore
+ * let (grounded, errors) = ErrorGroup::partition(claims.iter().map(|c| sister.ground(c)));
+ * errors.into_result()?; // or report ActionOutcome::partial_failure(&errors)
+ * ```
+ */
+export type ErrorGroup = { 
+/**
+ * Number of items in the batch
+ */
+total: number, 
+/**
+ * Failed items, in index order
+ */
+errors: Array<ItemError>, };
+
+/**
+ * Unique event identifier.
+ */
+export type EventId = UniqueId;
+
+/**
+ * Detailed evidence item returned by the `evidence()` method.
+ *
+ * More detailed than `GroundingEvidence` — includes full content,
+ * timestamps, relationships, etc.
+ */
+export type EvidenceDetail = { 
+/**
+ * Evidence type
+ */
+evidence_type: string, 
+/**
+ * Unique ID
+ */
+id: string, 
+/**
+ * Relevance score
+ */
+score: number, 
+/**
+ * When this evidence was created
+ */
+created_at: string, 
+/**
+ * Which sister produced this
+ */
+source_sister: SisterType, 
+/**
+ * Full content/description
+ */
+content: string, 
+/**
+ * Sister-specific structured data (edges, dimensions, capabilities, etc.)
+ */
+data: { [key in string]?: JsonValue }, };
+
+/**
+ * Type of evidence (kept for categorization, but no longer used
+ * as the primary lookup mechanism).
+ */
+export type EvidenceType = "memory_node" | "memory_relation" | "memory_session" | "screenshot" | "dom_fingerprint" | "visual_diff" | "visual_comparison" | "code_node" | "impact_analysis" | "prophecy" | "dependency_graph" | "receipt" | "trust_grant" | "competence_proof" | "signature" | "timeline_event" | "duration_proof" | "deadline_check" | "agreement" | "policy_check" | "boundary_verification" | { "custom": string };
+
+/**
+ * A registered extension sister, as held by `SisterType::Extension`.
+ *
+ * Only the registry hands these out, so the id is always in
+ * `EXTENSION_ID_RANGE` and never collides with a built-in byte.
+ */
+export type ExtensionType = { 
+/**
+ * Unique name, snake_case
+ */
+namespace: string, 
+/**
+ * File-header byte, in `EXTENSION_ID_RANGE`
+ */
+id: number, };
+
+/**
+ * A piece of evidence returned by grounding.
+ *
+ * Intentionally flexible — each sister populates the fields
+ * relevant to its domain. Memory returns nodes, Vision returns
+ * observations, Identity returns trust grants + receipts, etc.
+ */
+export type GroundingEvidence = { 
+/**
+ * Evidence type (sister-specific: "memory_node", "observation",
+ * "trust_grant", "receipt", "code_symbol", etc.)
+ */
+evidence_type: string, 
+/**
+ * Evidence identifier (node_id, observation_id, grant_id, etc.)
+ */
+id: string, 
+/**
+ * Relevance score (higher = more relevant)
+ */
+score: number, 
+/**
+ * Human-readable summary of the evidence
+ */
+summary: string, 
+/**
+ * Sister-specific structured data
+ */
+data: { [key in string]?: JsonValue }, };
+
+/**
+ * Result of a grounding check.
+ *
+ * Mirrors the actual response shape all sisters return.
+ */
+export type GroundingResult = { 
+/**
+ * Grounding status
+ */
+status: GroundingStatus, 
+/**
+ * The claim that was checked
+ */
+claim: string, 
+/**
+ * Confidence level (0.0 = no support, 1.0 = full support)
+ */
+confidence: number, 
+/**
+ * Evidence that supports (or fails to support) the claim
+ */
+evidence: Array<GroundingEvidence>, 
+/**
+ * Human-readable explanation
+ */
+reason: string, 
+/**
+ * Suggestions for related content (when ungrounded)
+ */
+suggestions: Array<string>, 
+/**
+ * Timestamp of grounding check
+ */
+timestamp: string, };
+
+/**
+ * Status of a grounding check
+ */
+export type GroundingStatus = "verified" | "partial" | "ungrounded";
+
+/**
+ * A suggestion returned when a claim doesn't match exactly
+ */
+export type GroundingSuggestion = { 
+/**
+ * What type of item this is
+ */
+item_type: string, 
+/**
+ * Item identifier
+ */
+id: string, 
+/**
+ * Relevance score
+ */
+relevance_score: number, 
+/**
+ * Human-readable description
+ */
+description: string, 
+/**
+ * Sister-specific data
+ */
+data: { [key in string]?: JsonValue }, };
+
+/**
+ * Health status returned by all sisters.
+ */
+export type HealthStatus = { 
+/**
+ * Is the sister operational?
+ */
+healthy: boolean, 
+/**
+ * Liveness probe: the process is up and not wedged. A failing live
+ * probe means restart it.
+ */
+live: boolean, 
+/**
+ * Readiness probe: it can take traffic now. Not ready while warming
+ * up or paused; route elsewhere or wait, but do not restart.
+ */
+ready: boolean, 
+/**
+ * Current status.
+ */
+status: Status, 
+/**
+ * Time since initialization.
+ */
+uptime: number, 
+/**
+ * Resource usage.
+ */
+resources: ResourceUsage, 
+/**
+ * Any warnings (non-fatal issues).
+ */
+warnings: Array<string>, 
+/**
+ * Last error if any.
+ */
+last_error: string | null, 
+/**
+ * Per-subsystem health (storage, index, receipt store, ...)
+ */
+components?: Array<ComponentHealth>, };
+
+/**
+ * A command from Hydra to a sister
+ */
+export type HydraCommand = { 
+/**
+ * Command type (sister interprets this)
+ */
+command_type: string, 
+/**
+ * Command parameters
+ */
+params: { [key in string]?: JsonValue }, 
+/**
+ * Hydra run ID (for receipt chain)
+ */
+run_id: string, 
+/**
+ * Step ID within the run
+ */
+step_id: number, 
+/**
+ * Limits the sister should stay within (None = unlimited)
+ */
+budget?: Budget | null, 
+/**
+ * Preview only: validate and report a `DryRunReport` in the
+ * result data, never mutate state
+ */
+dry_run?: boolean, 
+/**
+ * Caller-chosen key; retries with the same key return the first
+ * result instead of re-applying (see `IdempotentBridge`)
+ */
+idempotency_key?: string | null, 
+/**
+ * Scheduling priority (see `CommandQueue`)
+ */
+priority?: Priority, 
+/**
+ * Latest useful completion time; stale commands can be dropped
+ */
+deadline?: string | null, 
+/**
+ * Must run with no other command in flight on the sister
+ */
+exclusive?: boolean, 
+/**
+ * Encodings the caller can read the result in, most preferred
+ * first (see `Codec::negotiate`); empty means JSON only
+ */
+accept?: Array<Codec>, };
+
+/**
+ * How `UniqueId::new` generates identifiers.
+ */
+export type IdGenerator = "random" | "sortable";
+
+/**
+ * One failed item in a batch.
+ */
+export type ItemError = { 
+/**
+ * Position of the item in the batch input
+ */
+index: number, 
+/**
+ * Why it failed
+ */
+error: SisterError, };
+
+export type JsonValue = number | string | boolean | Array<JsonValue> | { [key in string]?: JsonValue } | null;
+
+/**
+ * Scheduling priority of a `HydraCommand`.
+ */
+export type Priority = "low" | "normal" | "high" | "critical";
+
+/**
+ * Which side a sibling hash sits on.
+ */
+export type ProofSide = "left" | "right";
+
+/**
+ * One sibling on the path from a leaf to the root.
+ */
+export type ProofStep = { 
+/**
+ * Sibling hash (hex)
+ */
+hash: string, 
+/**
+ * Side the sibling is on
+ */
+side: ProofSide, };
+
+/**
+ * MCP protocol error — becomes a JSON-RPC error response.
+ *
+ * Use this for:
+ * - Parse errors
+ * - Invalid requests
+ * - Unknown methods
+ * - Unknown tools (code -32803, NOT -32602)
+ * - Invalid parameters (before the tool is invoked)
+ */
+export type ProtocolError = { code: number, message: string, data?: JsonValue };
+
+/**
+ * Standard query request.
+ */
+export type Query = { 
+/**
+ * Query type (e.g., "list", "search", "recent", "related", "temporal").
+ */
+query_type: string, 
+/**
+ * Query-specific parameters.
+ */
+params: { [key in string]?: JsonValue }, 
+/**
+ * Maximum results.
+ */
+limit: number | null, 
+/**
+ * Offset for pagination.
+ */
+offset: number | null, 
+/**
+ * Context to query in (None = current).
+ */
+context_id: ContextId | null, 
+/**
+ * Multiple contexts for V2 multi-context queries.
+ */
+context_ids: Array<ContextId> | null, 
+/**
+ * Whether to merge results from multiple contexts.
+ */
+merge_results: boolean, };
+
+/**
+ * Query result.
+ */
+export type QueryResult = { 
+/**
+ * The query that produced this result.
+ */
+query: Query, 
+/**
+ * Results (structure depends on sister and query type).
+ */
+results: Array<JsonValue>, 
+/**
+ * Total count (if known).
+ */
+total_count: number | null, 
+/**
+ * Whether there are more results.
+ */
+has_more: boolean, 
+/**
+ * Query execution time.
+ */
+query_time: number, 
+/**
+ * Which contexts were queried.
+ */
+queried_contexts: Array<ContextId> | null, };
+
+/**
+ * Information about a supported query type.
+ */
+export type QueryTypeInfo = { 
+/**
+ * Query type name.
+ */
+name: string, 
+/**
+ * Description.
+ */
+description: string, 
+/**
+ * Required parameters.
+ */
+required_params: Array<string>, 
+/**
+ * Optional parameters.
+ */
+optional_params: Array<string>, 
+/**
+ * Example usage.
+ */
+example: JsonValue | null, };
+
+/**
+ * A context's usage against its quota.
+ */
+export type QuotaStatus = { 
+/**
+ * The limits that apply
+ */
+quota: ContextQuota, 
+/**
+ * Items currently in the context
+ */
+item_count: number, 
+/**
+ * Bytes currently stored
+ */
+size_bytes: number, };
+
+/**
+ * A receipt (signed action record).
+ */
+export type Receipt = { 
+/**
+ * Receipt ID.
+ */
+id: ReceiptId, 
+/**
+ * The action that was recorded.
+ */
+action: ActionRecord, 
+/**
+ * Signature (from Identity).
+ */
+signature: string, 
+/**
+ * Position in the hash chain.
+ */
+chain_position: number, 
+/**
+ * Hash of previous receipt (for chain integrity).
+ */
+previous_hash: string, 
+/**
+ * This receipt's hash.
+ */
+hash: string, 
+/**
+ * When the receipt was created.
+ */
+created_at: string, 
+/**
+ * External timestamp proof, once this receipt's hash was anchored.
+ * Not covered by `canonical_bytes`, so anchoring never changes a
+ * receipt's identity.
+ */
+anchor?: ReceiptAnchor | null, };
+
+/**
+ * Proof that a chain head hash existed at a point in time, issued by
+ * a third party (RFC 3161 TSA, transparency log, ...).
+ */
+export type ReceiptAnchor = { 
+/**
+ * Provider that issued the proof (`AnchorProvider::name`)
+ */
+provider: string, 
+/**
+ * Chain head hash that was anchored
+ */
+anchored_hash: string, 
+/**
+ * Chain position of that head
+ */
+chain_position: number, 
+/**
+ * Time asserted by the provider
+ */
+anchored_at: string, 
+/**
+ * Provider-specific proof (e.g. base64 RFC 3161 token, log inclusion proof)
+ */
+proof: string, 
+/**
+ * Provider-specific extras (log index, TSA policy OID, ...)
+ */
+metadata?: { [key in string]?: JsonValue }, };
+
+/**
+ * Unique receipt identifier.
+ */
+export type ReceiptId = UniqueId;
+
+/**
+ * Proof that one receipt is part of a batch with a given Merkle root.
+ *
+ * Lets a holder show "this action happened" by sharing one receipt and
+ * a logarithmic number of hashes instead of the whole chain.
+ */
+export type ReceiptProof = { 
+/**
+ * Receipt being proven
+ */
+receipt_id: ReceiptId, 
+/**
+ * Leaf hash of that receipt (hex, `Receipt::leaf_hash`)
+ */
+leaf_hash: string, 
+/**
+ * Position of the receipt in the batch
+ */
+leaf_index: number, 
+/**
+ * Number of receipts in the batch
+ */
+leaf_count: number, 
+/**
+ * Siblings from the leaf up to the root
+ */
+path: Array<ProofStep>, 
+/**
+ * Root of the batch (hex)
+ */
+root: string, };
+
+/**
+ * Resource usage metrics.
+ */
+export type ResourceUsage = { memory_bytes: number, disk_bytes: number, open_handles: number, };
+
+/**
+ * How many past sessions to keep, and for how long.
+ *
+ * Sessions beyond either limit are archived by `purge_sessions`: their
+ * snapshot is written to `archive_path` (when set) and the sister drops
+ * them from active storage. The current session is never purged.
+ */
+export type RetentionPolicy = { 
+/**
+ * Keep at most this many sessions (newest first)
+ */
+max_sessions?: number | null, 
+/**
+ * Archive sessions not updated for this long
+ */
+max_age?: number, 
+/**
+ * Directory for archived snapshots; without it, expired sessions are discarded
+ */
+archive_path?: string | null, };
+
+/**
+ * Hydra run identifier (the `run_id` in `HydraCommand`).
+ */
+export type RunId = string;
+
+/**
+ * Lifecycle status of a run or one of its steps.
+ */
+export type RunStatus = "pending" | "running" | "succeeded" | "failed" | "cancelled";
+
+/**
+ * A sensitive string (API key, token) that never shows up in logs.
+ *
+ * `Debug`, `Display`, and `Serialize` all print `"***"`, so configs and
+ * snapshots containing it are safe to dump. Read the real value with
+ * `expose`; to persist it, opt in with
+ * `#[serde(serialize_with = "SecretValue::serialize_exposed")]`.
+ * Deserializes from a plain string, except the `"***"` placeholder
+ * itself: reading back a redacted dump is an error, not a secret.
+ */
+export type SecretValue = string;
+
+/**
+ * Severity levels
+ */
+export type Severity = "info" | "warning" | "error" | "fatal";
+
+/**
+ * Configuration for initializing a sister.
+ *
+ * v0.2.0: Made data paths flexible to support sisters with different
+ * storage models:
+ * - Memory/Vision: single data file (`data_path`)
+ * - Identity: multiple directories (`data_paths`)
+ * - Codebase: multiple graph files loaded dynamically
+ * - Time: single data file
+ *
+ * Missing fields deserialize to their `Default` values.
+ */
+export type SisterConfig = { 
+/**
+ * Primary data file/directory path.
+ * Used by sisters with a single data location (Memory, Vision, Time)
+ */
+data_path: string | null, 
+/**
+ * Additional named data paths.
+ * Used by sisters with multiple data locations (Identity, Codebase).
+ *
+ * Examples:
+ * - Identity: {"identities": "/path/to/identities", "receipts": "/path/to/receipts"}
+ * - Codebase: {"default_graph": "/path/to/graph.acb"}
+ */
+data_paths: { [key in string]?: string }, 
+/**
+ * Whether to create if not exists
+ */
+create_if_missing: boolean, 
+/**
+ * Read-only mode
+ */
+read_only: boolean, 
+/**
+ * Memory budget in megabytes (optional)
+ */
+memory_budget_mb: number | null, 
+/**
+ * Take an advisory lock on the data path at init, so a second
+ * instance pointed at the same file fails fast instead of corrupting it
+ */
+exclusive_lock: boolean, 
+/**
+ * Session retention (sisters with `SessionManagement` apply it via `purge_sessions`)
+ */
+retention?: RetentionPolicy | null, 
+/**
+ * Per-context size limits (sisters enforce them with `ContextQuota::enforce`)
+ */
+quota?: ContextQuota | null, 
+/**
+ * Automatic checkpointing (sisters with `Checkpointing` apply it)
+ */
+checkpoint?: CheckpointPolicy | null, 
+/**
+ * Custom options (sister-specific)
+ */
+options: { [key in string]?: JsonValue }, 
+/**
+ * Sensitive options (API keys); redacted in Debug and serialization
+ */
+secrets?: { [key in string]?: SecretValue }, 
+/**
+ * Capabilities switched off by the operator (e.g. `trust_grant`).
+ * Sisters omit them from `capabilities()` and refuse to run them
+ */
+disabled_capabilities?: Array<string>, 
+/**
+ * ID generator for this process; hosts apply it with
+ * `set_id_generator` at startup (it is process-wide, not per sister)
+ */
+id_generator?: IdGenerator | null, };
+
+/**
+ * Standard error type for ALL sisters — domain/business logic errors.
+ *
+ * These errors occur AFTER a tool is found and invoked.
+ * In MCP, they become `{isError: true}` in the tool result,
+ * NOT JSON-RPC error responses.
+ */
+export type SisterError = { 
+/**
+ * Error code (machine-readable)
+ */
+code: ErrorCode, 
+/**
+ * Severity level
+ */
+severity: Severity, 
+/**
+ * Human-readable message (should be actionable for LLMs)
+ */
+message: string, 
+/**
+ * Additional context (for debugging)
+ */
+context: { [key in string]?: JsonValue } | null, 
+/**
+ * Is this recoverable?
+ */
+recoverable: boolean, 
+/**
+ * Suggested action for recovery
+ */
+suggested_action: SuggestedAction | null, };
+
+/**
+ * Event emitted by a sister.
+ */
+export type SisterEvent = { 
+/**
+ * Unique event ID.
+ */
+id: EventId, 
+/**
+ * Which sister emitted this.
+ */
+sister_type: SisterType, 
+/**
+ * Timestamp.
+ */
+timestamp: string, 
+/**
+ * Context this event occurred in (if applicable).
+ */
+context_id: ContextId | null, } & ({ "event_type": "ready" } | { "event_type": "shutting_down" } | { "event_type": "paused" } | { "event_type": "resumed" } | { "event_type": "config_changed", changed: Array<string>, } | { "event_type": "warm_up_progress", phase: string, percent: number, } | { "event_type": "status_changed", from: Status, to: Status, } | { "event_type": "context_created", context_id: ContextId, name: string, } | { "event_type": "context_switched", from: ContextId, to: ContextId, } | { "event_type": "context_deleted", context_id: ContextId, } | { "event_type": "context_archived", context_id: ContextId, archive_path: string | null, } | { "event_type": "operation_started", operation_id: string, operation_type: string, } | { "event_type": "operation_completed", operation_id: string, duration: number, } | { "event_type": "operation_failed", operation_id: string, error_code: string, error_message: string, } | { "event_type": "evidence_created", evidence_id: string, evidence_type: EvidenceType, } | { "event_type": "grounding_performed", grounding_id: string, grounded: boolean, confidence: number, } | { "event_type": "run_started", run_id: RunId, goal: string, } | { "event_type": "run_finished", run_id: RunId, status: RunStatus, } | { "event_type": "step_started", run_id: RunId, step_id: StepId, command_type: string, } | { "event_type": "step_completed", run_id: RunId, step_id: StepId, status: RunStatus, } | { "event_type": "memory_pressure", usage_percent: number, } | { "event_type": "storage_pressure", usage_percent: number, } | { "event_type": "custom", name: string, data: JsonValue, });
+
+/**
+ * Information about a sister (for discovery)
+ */
+export type SisterInfo = { sister_type: SisterType, version: Version, file_extension: string, capabilities: Array<Capability>, mcp_prefix: string, requires?: Array<SisterRequirement>, optional_dependencies?: Array<SisterRequirement>, };
+
+/**
+ * A dependency on another sister.
+ */
+export type SisterRequirement = { sister_type: SisterType, 
+/**
+ * Capability names the dependency must provide
+ */
+capabilities?: Array<string>, 
+/**
+ * Oldest acceptable version; the major version must match
+ */
+min_version?: Version | null, };
+
+/**
+ * All sister types in the ecosystem.
+ */
+export type SisterType = "memory" | "vision" | "codebase" | "identity" | "time" | "contract" | "comm" | "planning" | "cognition" | "reality" | "attention" | "affect" | "motivation" | "learning" | "bond" | "meaning" | "wonder" | "imagination" | "conscience" | "meta" | "duration" | { "extension": ExtensionType };
+
+/**
+ * Changes between two snapshots of the same context.
+ *
+ * `base_checksum` pins the snapshot the delta applies to;
+ * `result_checksum` is what the context checksums to afterwards, so a
+ * misapplied delta is caught instead of silently diverging.
+ */
+export type SnapshotDelta = { 
+/**
+ * Which sister type this came from
+ */
+sister_type: SisterType, 
+/**
+ * Context the delta belongs to
+ */
+context_id: ContextId, 
+/**
+ * Checksum of the base snapshot
+ */
+base_checksum: string, 
+/**
+ * Checksum after applying the delta
+ */
+result_checksum: string, 
+/**
+ * When the base snapshot was taken
+ */
+since: string, 
+/**
+ * When this delta was created
+ */
+created_at: string, 
+/**
+ * Changes, applied in order
+ */
+ops: Array<DeltaOp>, };
+
+/**
+ * Sister status.
+ */
+export type Status = "starting" | "ready" | "busy" | "degraded" | "paused" | "shutting_down" | "error";
+
+/**
+ * Step number within a run (the `step_id` in `HydraCommand`).
+ */
+export type StepId = number;
+
+/**
+ * Suggested actions for error recovery
+ */
+export type SuggestedAction = { "type": "retry", 
+/**
+ * Milliseconds to wait before retry
+ */
+after_ms: number, } | { "type": "alternative", 
+/**
+ * Description of the alternative
+ */
+description: string, } | { "type": "user_action", 
+/**
+ * Description of what the user should do
+ */
+description: string, } | { "type": "restart" } | { "type": "check_config", 
+/**
+ * Configuration key to check
+ */
+key: string, } | { "type": "report_bug" };
+
+/**
+ * Unique identifier (UUID-based).
+ *
+ * Random (v4) by default; see `set_id_generator` for time-sortable (v7)
+ * IDs. Any UUID version parses and deserializes.
+ */
+export type UniqueId = string;
+
+/**
+ * Resources consumed by a command.
+ */
+export type Usage = { tokens: number, cost_usd: number, 
+/**
+ * Serialized as milliseconds
+ */
+duration: number, };
+
+/**
+ * Semantic version.
+ *
+ * Pre-release and build metadata follow semver: `0.2.0-rc.1 < 0.2.0`,
+ * and build metadata does not affect compatibility. Both are omitted
+ * from the wire format when empty, so plain versions serialize exactly
+ * as before. Binary file headers carry only major/minor/patch.
+ */
+export type Version = { major: number, minor: number, patch: number, 
+/**
+ * Pre-release identifiers (e.g. "rc.1"), empty for a release
+ */
+pre?: string, 
+/**
+ * Build metadata (e.g. "git.abc123"), ignored for precedence
+ */
+build?: string, };
@@ -0,0 +1,116 @@
+//! Injectable time source.
+//!
+//! Constructors that stamp a time have a `new_with_clock` variant taking
+//! a `&dyn Clock`; the plain `new` uses `SystemClock`. Tests pass a
+//! `MockClock` to get the same timestamps on every run.
+//!
+//! ```rust,ignore
+//! let clock = MockClock::new(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+//! let first = SisterEvent::new_with_clock(SisterType::Memory, EventType::Ready, &clock);
+//! clock.advance(Duration::from_secs(5));
+//! let record = ActionRecord::new_with_clock(SisterType::Memory, "memory_add", outcome, &clock);
+//! assert_eq!(record.timestamp - first.timestamp, chrono::Duration::seconds(5));
+//! ```
+
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Source of the current time.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The real wall clock (`Utc::now()`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Manually driven clock for tests.
+///
+/// Time stands still until `set` or `advance`. Clones share the same
+/// time, so a test can keep one handle and give another to the code
+/// under test.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl MockClock {
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(start)),
+        }
+    }
+
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner()) = now;
+    }
+
+    pub fn advance(&self, by: Duration) {
+        let mut now = self.now.lock().unwrap_or_else(|e| e.into_inner());
+        *now += chrono::Duration::from_std(by).unwrap_or(chrono::Duration::MAX);
+    }
+}
+
+/// Starts at the Unix epoch.
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new(DateTime::UNIX_EPOCH)
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{EventType, SisterEvent};
+    use crate::receipts::{ActionOutcome, ActionRecord};
+    use crate::types::SisterType;
+
+    #[test]
+    fn test_mock_clock_stamps() {
+        let clock = MockClock::default();
+        let handle = clock.clone();
+
+        let event = SisterEvent::new_with_clock(SisterType::Memory, EventType::Ready, &clock);
+        assert_eq!(event.timestamp, DateTime::UNIX_EPOCH);
+
+        handle.advance(Duration::from_secs(90));
+        let record = ActionRecord::new_with_clock(
+            SisterType::Memory,
+            "memory_add",
+            ActionOutcome::success(),
+            &clock,
+        );
+        assert_eq!(
+            record.timestamp - event.timestamp,
+            chrono::Duration::seconds(90)
+        );
+
+        let shared: Arc<dyn Clock> = Arc::new(SystemClock);
+        assert!(shared.now() > clock.now());
+    }
+}
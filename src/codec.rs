@@ -0,0 +1,282 @@
+//! Payload encodings: JSON, MessagePack, CBOR.
+//!
+//! JSON stays the default and the only encoding every sister must speak.
+//! Binary codecs cut snapshot and query payloads to a fraction of their
+//! JSON size: byte fields (snapshot data, checksums) travel raw instead
+//! of as base64/hex text. MessagePack needs the `msgpack` feature and
+//! CBOR the `cbor` feature; `Codec` itself is always available so peers
+//! can negotiate even when a codec is compiled out.
+//!
+//! ```rust,ignore
+//! // Hydra lists what it can read; the sister answers in the best match.
+//! let codec = Codec::negotiate(&command.accept);
+//! let body = result.encode(codec)?;
+//! respond(codec.content_type(), body);
+//! ```
+
+use crate::errors::{ErrorCode, SisterError, SisterResult};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// A payload encoding.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum Codec {
+    /// `application/json`, always available
+    #[default]
+    Json,
+
+    /// `application/msgpack` (`msgpack` feature)
+    #[serde(rename = "msgpack")]
+    MsgPack,
+
+    /// `application/cbor` (`cbor` feature)
+    Cbor,
+}
+
+impl Codec {
+    /// Every codec, JSON first.
+    pub const ALL: [Codec; 3] = [Codec::Json, Codec::MsgPack, Codec::Cbor];
+
+    /// MIME type for transport headers.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::MsgPack => "application/msgpack",
+            Self::Cbor => "application/cbor",
+        }
+    }
+
+    /// Codec for a MIME type; parameters (`; charset=utf-8`) and the
+    /// common MessagePack aliases are accepted.
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type.split(';').next().unwrap_or("").trim();
+        match mime.to_ascii_lowercase().as_str() {
+            "application/json" => Some(Self::Json),
+            "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack" => {
+                Some(Self::MsgPack)
+            }
+            "application/cbor" => Some(Self::Cbor),
+            _ => None,
+        }
+    }
+
+    /// Whether this build can encode and decode with this codec.
+    pub fn is_available(&self) -> bool {
+        match self {
+            Self::Json => true,
+            Self::MsgPack => cfg!(feature = "msgpack"),
+            Self::Cbor => cfg!(feature = "cbor"),
+        }
+    }
+
+    /// Codecs this build supports, JSON first.
+    pub fn available() -> Vec<Codec> {
+        Self::ALL.into_iter().filter(Codec::is_available).collect()
+    }
+
+    /// First codec in `accept` (most preferred first) that this build
+    /// supports; JSON when none is.
+    pub fn negotiate(accept: &[Codec]) -> Codec {
+        accept
+            .iter()
+            .copied()
+            .find(Codec::is_available)
+            .unwrap_or_default()
+    }
+
+    /// Encode `value`.
+    ///
+    /// Fails with `NotImplemented` if the codec's feature is disabled.
+    pub fn encode<T: Serialize + ?Sized>(&self, value: &T) -> SisterResult<Vec<u8>> {
+        let encode_error = |e: &dyn std::fmt::Display| {
+            SisterError::new(
+                ErrorCode::Internal,
+                format!("{} encode failed: {}", self, e),
+            )
+            .with_context("codec", self.to_string())
+        };
+        match self {
+            Self::Json => serde_json::to_vec(value).map_err(|e| encode_error(&e)),
+            #[cfg(feature = "msgpack")]
+            Self::MsgPack => rmp_serde::to_vec_named(value).map_err(|e| encode_error(&e)),
+            #[cfg(feature = "cbor")]
+            Self::Cbor => {
+                let mut bytes = Vec::new();
+                ciborium::into_writer(value, &mut bytes).map_err(|e| encode_error(&e))?;
+                Ok(bytes)
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(self.disabled()),
+        }
+    }
+
+    /// Decode a `T`.
+    ///
+    /// Fails with `InvalidInput` on malformed bytes and `NotImplemented`
+    /// if the codec's feature is disabled.
+    pub fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> SisterResult<T> {
+        let decode_error = |e: &dyn std::fmt::Display| {
+            SisterError::invalid_input(format!("{} decode failed: {}", self, e))
+                .with_context("codec", self.to_string())
+        };
+        match self {
+            Self::Json => serde_json::from_slice(bytes).map_err(|e| decode_error(&e)),
+            #[cfg(feature = "msgpack")]
+            Self::MsgPack => rmp_serde::from_slice(bytes).map_err(|e| decode_error(&e)),
+            #[cfg(feature = "cbor")]
+            Self::Cbor => ciborium::from_reader(bytes).map_err(|e| decode_error(&e)),
+            #[allow(unreachable_patterns)]
+            _ => Err(self.disabled()),
+        }
+    }
+
+    #[allow(dead_code)]
+    fn disabled(&self) -> SisterError {
+        SisterError::feature_disabled(match self {
+            Self::Json => "json",
+            Self::MsgPack => "msgpack",
+            Self::Cbor => "cbor",
+        })
+        .with_context("codec", self.to_string())
+    }
+}
+
+impl std::fmt::Display for Codec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Json => "json",
+            Self::MsgPack => "msgpack",
+            Self::Cbor => "cbor",
+        })
+    }
+}
+
+impl std::str::FromStr for Codec {
+    type Err = SisterError;
+
+    /// Accepts the codec name or its content type.
+    fn from_str(s: &str) -> SisterResult<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "msgpack" | "messagepack" => Ok(Self::MsgPack),
+            "cbor" => Ok(Self::Cbor),
+            other => Self::from_content_type(other).ok_or_else(|| {
+                SisterError::invalid_input(format!("Unknown codec {:?}", s))
+                    .with_context("codec", s)
+            }),
+        }
+    }
+}
+
+/// `Codec::encode`/`decode` as methods on the payloads that cross the
+/// transport: snapshots, events, commands, and their results.
+pub trait Encodable: Serialize + DeserializeOwned {
+    fn encode(&self, codec: Codec) -> SisterResult<Vec<u8>> {
+        codec.encode(self)
+    }
+
+    fn decode(codec: Codec, bytes: &[u8]) -> SisterResult<Self> {
+        codec.decode(bytes)
+    }
+}
+
+impl Encodable for crate::context::ContextSnapshot {}
+impl Encodable for crate::context::SnapshotDelta {}
+impl Encodable for crate::events::SisterEvent {}
+impl Encodable for crate::hydra::HydraCommand {}
+impl Encodable for crate::hydra::CommandResult {}
+impl Encodable for crate::query::Query {}
+impl Encodable for crate::query::QueryResult {}
+impl Encodable for crate::receipts::Receipt {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_type_negotiation() {
+        for codec in Codec::ALL {
+            assert_eq!(Codec::from_content_type(codec.content_type()), Some(codec));
+            assert_eq!(codec.to_string().parse::<Codec>().unwrap(), codec);
+            assert_eq!(serde_json::to_value(codec).unwrap(), codec.to_string());
+        }
+        assert_eq!(
+            Codec::from_content_type("Application/JSON; charset=utf-8"),
+            Some(Codec::Json)
+        );
+        assert_eq!(
+            "application/x-msgpack".parse::<Codec>().unwrap(),
+            Codec::MsgPack
+        );
+        assert!("yaml".parse::<Codec>().is_err());
+
+        assert_eq!(Codec::negotiate(&[]), Codec::Json);
+        let preferred = Codec::negotiate(&[Codec::Cbor, Codec::MsgPack]);
+        assert!(preferred.is_available());
+        assert_eq!(
+            preferred == Codec::Json,
+            !cfg!(any(feature = "cbor", feature = "msgpack"))
+        );
+    }
+
+    #[test]
+    fn test_unavailable_codec_errors() {
+        for codec in Codec::ALL.into_iter().filter(|c| !c.is_available()) {
+            let err = codec.encode(&1u8).unwrap_err();
+            assert_eq!(err.code, ErrorCode::NotImplemented);
+        }
+        let err = Codec::Json.decode::<u8>(b"nope").unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidInput);
+    }
+
+    #[cfg(all(feature = "hashing", any(feature = "msgpack", feature = "cbor")))]
+    #[test]
+    fn test_binary_codecs_round_trip() {
+        use crate::context::{ContextId, ContextInfo, ContextSnapshot};
+        use crate::events::{EventType, SisterEvent};
+        use crate::types::{Metadata, SisterType, Version};
+        use chrono::Utc;
+
+        let info = ContextInfo {
+            id: ContextId::new(),
+            name: "main".into(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            item_count: 1,
+            size_bytes: 4096,
+            tags: Vec::new(),
+            metadata: Metadata::new(),
+            parent_id: None,
+            child_ids: Vec::new(),
+            quota: None,
+        };
+        let data = (0..4096u32).map(|i| (i * 31 % 251) as u8).collect();
+        let snapshot = ContextSnapshot::new(SisterType::Memory, Version::new(1, 0, 0), info, data);
+        let event = SisterEvent::new(
+            SisterType::Memory,
+            EventType::Custom {
+                name: "tick".into(),
+                data: serde_json::json!({"n": 1, "tags": ["a"]}),
+            },
+        );
+        // Smaller than the base64 of the data alone: bytes travel raw
+        let base64_len = snapshot.data.len() * 4 / 3;
+
+        for codec in Codec::available().into_iter().skip(1) {
+            let bytes = snapshot.encode(codec).unwrap();
+            assert!(bytes.len() < base64_len, "{codec}: {} bytes", bytes.len());
+            let back = ContextSnapshot::decode(codec, &bytes).unwrap();
+            assert_eq!(back.data, snapshot.data);
+            assert!(back.verify().unwrap());
+
+            let back = SisterEvent::decode(codec, &event.encode(codec).unwrap()).unwrap();
+            assert_eq!(
+                serde_json::to_value(&back).unwrap(),
+                serde_json::to_value(&event).unwrap()
+            );
+        }
+    }
+}
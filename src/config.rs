@@ -0,0 +1,211 @@
+//! One config file for the whole fleet.
+//!
+//! Enabled by the `config` cargo feature. `agenticos.toml` holds a
+//! `[sister.<name>]` table per sister, each a `SisterConfig`:
+//!
+//! ```toml
+//! [sister.memory]
+//! data_path = "~/.agentic/brain.amem"
+//! memory_budget_mb = 512
+//!
+//! [sister.identity.data_paths]
+//! receipts = "${AGENTIC_HOME}/receipts"
+//!
+//! [sister.codebase.options]
+//! max_files = 50000
+//! ```
+//!
+//! Data paths expand a leading `~` and `$VAR` / `${VAR}` references.
+//! `FleetConfig` is plain serde, so YAML or JSON files work too: parse
+//! with the format crate of your choice, then call `expand_paths`.
+
+use crate::errors::{SisterError, SisterResult};
+use crate::sister::SisterConfig;
+use crate::types::SisterType;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Conventional fleet config file name.
+pub const FLEET_CONFIG_FILE: &str = "agenticos.toml";
+
+/// Configuration for every sister in a deployment.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FleetConfig {
+    /// Per-sister configs (`[sister.<name>]` tables)
+    #[serde(rename = "sister", default)]
+    pub sisters: HashMap<SisterType, SisterConfig>,
+}
+
+impl FleetConfig {
+    /// Read and parse a TOML fleet config, expanding data paths.
+    pub fn load(path: &Path) -> SisterResult<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Self::from_toml_str(&text).map_err(|e| e.with_context("path", path.display().to_string()))
+    }
+
+    /// Parse a TOML fleet config, expanding data paths.
+    pub fn from_toml_str(text: &str) -> SisterResult<Self> {
+        let mut fleet: Self = toml::from_str(text).map_err(|e| {
+            SisterError::invalid_input(format!("Invalid fleet config: {}", e.message()))
+        })?;
+        fleet.expand_paths()?;
+        Ok(fleet)
+    }
+
+    /// Serialize as TOML.
+    pub fn to_toml_string(&self) -> SisterResult<String> {
+        toml::to_string(self).map_err(|e| {
+            SisterError::invalid_input(format!("Cannot serialize fleet config: {}", e))
+        })
+    }
+
+    /// Expand `~` and environment variables in every data path.
+    pub fn expand_paths(&mut self) -> SisterResult<()> {
+        for (sister_type, config) in &mut self.sisters {
+            let with_sister =
+                |e: SisterError| e.with_context("sister_type", sister_type.to_string());
+            if let Some(path) = &config.data_path {
+                config.data_path = Some(expand_path(path).map_err(with_sister)?);
+            }
+            for path in config.data_paths.values_mut() {
+                *path = expand_path(path).map_err(with_sister)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Config for one sister.
+    pub fn get(&self, sister_type: SisterType) -> Option<&SisterConfig> {
+        self.sisters.get(&sister_type)
+    }
+
+    /// Config for one sister, or the default if it has no section.
+    pub fn config_for(&self, sister_type: SisterType) -> SisterConfig {
+        self.get(sister_type).cloned().unwrap_or_default()
+    }
+}
+
+/// Expand a leading `~` (to `$HOME`) and `$VAR` / `${VAR}` references.
+///
+/// Fails with `InvalidInput` if a referenced variable is unset.
+pub fn expand_path(path: &Path) -> SisterResult<PathBuf> {
+    let raw = path.to_string_lossy();
+    let unset = |name: &str| {
+        SisterError::invalid_input(format!(
+            "{} references unset environment variable {}",
+            raw, name
+        ))
+        .with_context("variable", name)
+    };
+    let var = |name: &str| std::env::var(name).map_err(|_| unset(name));
+
+    let mut out = String::new();
+    let mut rest: &str = &raw;
+    if rest == "~" || rest.starts_with("~/") {
+        out.push_str(&var("HOME")?);
+        rest = &rest[1..];
+    }
+    while let Some(start) = rest.find('$') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let (name, consumed) = if let Some(braced) = after.strip_prefix('{') {
+            match braced.find('}') {
+                Some(end) => (&braced[..end], end + 2),
+                None => {
+                    return Err(SisterError::invalid_input(format!(
+                        "Unclosed ${{ in {}",
+                        raw
+                    )))
+                }
+            }
+        } else {
+            let end = after
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(after.len());
+            (&after[..end], end)
+        };
+        if name.is_empty() {
+            out.push('$');
+        } else {
+            out.push_str(&var(name)?);
+        }
+        rest = &after[consumed..];
+    }
+    out.push_str(rest);
+    Ok(PathBuf::from(out))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fleet_config_toml() {
+        let home = std::env::var("HOME").unwrap_or_default();
+        let fleet = FleetConfig::from_toml_str(
+            r#"
+            [sister.memory]
+            data_path = "~/brain.amem"
+            memory_budget_mb = 512
+
+            [sister.identity.data_paths]
+            receipts = "${HOME}/receipts"
+
+            [sister.codebase.options]
+            max_files = 50000
+            "#,
+        )
+        .unwrap();
+
+        let memory = fleet.get(SisterType::Memory).unwrap();
+        assert_eq!(memory.primary_path(), Path::new(&home).join("brain.amem"));
+        assert_eq!(memory.memory_budget_mb, Some(512));
+        assert!(memory.create_if_missing);
+        assert_eq!(
+            fleet
+                .get(SisterType::Identity)
+                .unwrap()
+                .get_path("receipts"),
+            Some(&Path::new(&home).join("receipts"))
+        );
+        assert_eq!(
+            fleet
+                .config_for(SisterType::Codebase)
+                .get_option::<u64>("max_files"),
+            Some(50000)
+        );
+        assert!(fleet.get(SisterType::Vision).is_none());
+
+        let roundtrip = FleetConfig::from_toml_str(&fleet.to_toml_string().unwrap()).unwrap();
+        assert_eq!(roundtrip.sisters.len(), 3);
+    }
+
+    #[test]
+    fn test_fleet_config_secrets_not_roundtripped() {
+        let fleet = FleetConfig::from_toml_str(
+            r#"
+            [sister.memory.secrets]
+            api_key = "sk-live-123"
+            "#,
+        )
+        .unwrap();
+        let memory = fleet.get(SisterType::Memory).unwrap();
+        assert_eq!(memory.secrets["api_key"].expose(), "sk-live-123");
+
+        let text = fleet.to_toml_string().unwrap();
+        assert!(!text.contains("sk-live-123"));
+        assert!(FleetConfig::from_toml_str(&text).is_err());
+    }
+
+    #[test]
+    fn test_expand_path_errors() {
+        let err = expand_path(Path::new("/data/$AGENTIC_SURELY_UNSET_VAR/x")).unwrap_err();
+        assert_eq!(err.context.unwrap()["variable"], "AGENTIC_SURELY_UNSET_VAR");
+        assert!(FleetConfig::from_toml_str("[sister.nonsense]").is_err());
+        assert_eq!(
+            expand_path(Path::new("/plain/$")).unwrap(),
+            PathBuf::from("/plain/$")
+        );
+    }
+}
@@ -9,13 +9,22 @@
 //! Sisters implement whichever fits. Time implements neither (stateless).
 //! Hydra can query both via the unified `ContextInfo` type.
 
-use crate::errors::SisterResult;
+#[cfg(feature = "hashing")]
+use crate::clock::{Clock, SystemClock};
+use crate::errors::{ErrorCode, SisterError, SisterResult, SuggestedAction};
+use crate::events::EventType;
+use crate::file_format::{atomic_write, compress_frame, decompress_frame, CompressionCodec};
 use crate::types::{Metadata, SisterType, UniqueId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
 
 /// Unique identifier for a context (session or workspace).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct ContextId(pub UniqueId);
 
 impl ContextId {
@@ -60,6 +69,8 @@ impl From<&str> for ContextId {
 
 /// Summary information about a context
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct ContextSummary {
     pub id: ContextId,
     pub name: String,
@@ -67,10 +78,14 @@ pub struct ContextSummary {
     pub updated_at: DateTime<Utc>,
     pub item_count: usize,
     pub size_bytes: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 /// Full context information
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct ContextInfo {
     pub id: ContextId,
     pub name: String,
@@ -78,8 +93,26 @@ pub struct ContextInfo {
     pub updated_at: DateTime<Utc>,
     pub item_count: usize,
     pub size_bytes: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
     #[serde(default)]
     pub metadata: Metadata,
+    /// Context this one was forked from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<ContextId>,
+    /// Contexts forked from this one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub child_ids: Vec<ContextId>,
+    /// Usage against the sister's `ContextQuota`, if one is configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quota: Option<QuotaStatus>,
+}
+
+impl ContextInfo {
+    /// Whether this context was forked from another
+    pub fn is_fork(&self) -> bool {
+        self.parent_id.is_some()
+    }
 }
 
 impl From<ContextInfo> for ContextSummary {
@@ -91,39 +124,1235 @@ impl From<ContextInfo> for ContextSummary {
             updated_at: info.updated_at,
             item_count: info.item_count,
             size_bytes: info.size_bytes,
+            tags: info.tags,
+        }
+    }
+}
+
+/// Criteria for `find_sessions` / `find_workspaces`. Empty matches everything.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContextFilter {
+    /// Case-insensitive substring of the name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_contains: Option<String>,
+
+    /// Tags that must all be present
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// Created at or after
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_after: Option<DateTime<Utc>>,
+
+    /// Created before
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_before: Option<DateTime<Utc>>,
+
+    /// Updated at or after
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_after: Option<DateTime<Utc>>,
+
+    /// Maximum results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl ContextFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name_contains(mut self, text: impl Into<String>) -> Self {
+        self.name_contains = Some(text.into());
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    pub fn created_after(mut self, time: DateTime<Utc>) -> Self {
+        self.created_after = Some(time);
+        self
+    }
+
+    pub fn created_before(mut self, time: DateTime<Utc>) -> Self {
+        self.created_before = Some(time);
+        self
+    }
+
+    pub fn updated_after(mut self, time: DateTime<Utc>) -> Self {
+        self.updated_after = Some(time);
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Check whether a context matches (ignores `limit`).
+    pub fn matches(&self, summary: &ContextSummary) -> bool {
+        if let Some(text) = &self.name_contains {
+            if !summary.name.to_lowercase().contains(&text.to_lowercase()) {
+                return false;
+            }
+        }
+        if !self.tags.iter().all(|t| summary.tags.contains(t)) {
+            return false;
+        }
+        if self.created_after.is_some_and(|t| summary.created_at < t) {
+            return false;
+        }
+        if self.created_before.is_some_and(|t| summary.created_at >= t) {
+            return false;
+        }
+        if self.updated_after.is_some_and(|t| summary.updated_at < t) {
+            return false;
+        }
+        true
+    }
+
+    /// Filter a list, keeping order and applying `limit`.
+    pub fn apply(&self, contexts: Vec<ContextSummary>) -> Vec<ContextSummary> {
+        contexts
+            .into_iter()
+            .filter(|c| self.matches(c))
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+/// Tagging for sessions or workspaces.
+///
+/// Separate from `SessionManagement`/`WorkspaceManagement` so either
+/// kind of sister can opt in.
+pub trait ContextTagging {
+    /// Add tags to a context (existing tags are kept; duplicates ignored)
+    fn tag_context(&mut self, id: ContextId, tags: &[&str]) -> SisterResult<()>;
+
+    /// Remove tags from a context (missing tags are ignored)
+    fn untag_context(&mut self, id: ContextId, tags: &[&str]) -> SisterResult<()>;
+
+    /// Tags on a context
+    fn context_tags(&self, id: ContextId) -> SisterResult<Vec<String>>;
+}
+
+/// Exportable context snapshot (for backup/transfer)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct ContextSnapshot {
+    /// Layout version of this struct (`SNAPSHOT_FORMAT` when written by
+    /// this SDK). Absent in snapshots that predate versioning, which
+    /// read as `0`
+    #[serde(default)]
+    pub snapshot_format: u8,
+
+    /// Which sister type this came from
+    pub sister_type: SisterType,
+
+    /// Version of the sister that created this
+    pub version: crate::types::Version,
+
+    /// Context information
+    pub context_info: ContextInfo,
+
+    /// Serialized context data (sister-specific format).
+    /// A `compress_frame` frame when `compression` is not `None`
+    #[serde(with = "base64_serde")]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    pub data: Vec<u8>,
+
+    /// Checksum of the uncompressed data (BLAKE3)
+    #[serde(with = "hex_serde")]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    pub checksum: [u8; 32],
+
+    /// When this snapshot was created
+    pub snapshot_at: DateTime<Utc>,
+
+    /// Compression applied to `data` (absent in older snapshots = none)
+    #[serde(default)]
+    pub compression: CompressionCodec,
+
+    /// Who signed the snapshot (Identity ID), if signed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer: Option<String>,
+
+    /// Signature over `signing_payload()`, if signed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+/// Current `ContextSnapshot` layout version.
+///
+/// Bump when the struct changes shape and add a step to
+/// `migrate_snapshot` that upgrades the previous layout.
+pub const SNAPSHOT_FORMAT: u8 = 1;
+
+/// Read a serialized snapshot of any known layout and upgrade it to
+/// `SNAPSHOT_FORMAT`.
+///
+/// Snapshots without a `snapshot_format` field are format 0 (the
+/// unversioned shape). Snapshots from a newer SDK are rejected rather
+/// than misread.
+pub fn migrate_snapshot(json: &[u8]) -> SisterResult<ContextSnapshot> {
+    let mut value: serde_json::Value = serde_json::from_slice(json)?;
+    let object = value.as_object_mut().ok_or_else(|| {
+        SisterError::new(ErrorCode::InvalidInput, "Snapshot is not a JSON object")
+    })?;
+
+    let format = match object.get("snapshot_format") {
+        None => 0,
+        Some(v) => v
+            .as_u64()
+            .and_then(|f| u8::try_from(f).ok())
+            .ok_or_else(|| {
+                SisterError::new(
+                    ErrorCode::InvalidInput,
+                    format!("Invalid snapshot_format: {}", v),
+                )
+            })?,
+    };
+    if format > SNAPSHOT_FORMAT {
+        return Err(SisterError::new(
+            ErrorCode::VersionMismatch,
+            format!(
+                "Snapshot format {} is newer than supported format {}",
+                format, SNAPSHOT_FORMAT
+            ),
+        )
+        .with_context("snapshot_format", format)
+        .with_suggestion(SuggestedAction::UserAction {
+            description: "Upgrade agentic-sdk to read this snapshot".into(),
+        }));
+    }
+
+    // 0 -> 1: versioning introduced. Fields added alongside it
+    // (compression, signer, signature) default when absent.
+    if format < 1 {
+        object.insert("snapshot_format".into(), 1.into());
+    }
+
+    Ok(serde_json::from_value(value)?)
+}
+
+/// BLAKE3 checksum of uncompressed snapshot data.
+fn snapshot_checksum(data: &[u8]) -> SisterResult<[u8; 32]> {
+    #[cfg(feature = "hashing")]
+    return Ok(*blake3::hash(data).as_bytes());
+    #[cfg(not(feature = "hashing"))]
+    {
+        let _ = data;
+        Err(SisterError::feature_disabled("hashing"))
+    }
+}
+
+/// Signs and verifies snapshots.
+///
+/// Intended to be backed by Identity: `signer_id` is the identity ID and
+/// signatures are produced with its key. Any identity's signature can be
+/// verified given its ID.
+pub trait SnapshotSigner {
+    /// ID recorded in `ContextSnapshot::signer`
+    fn signer_id(&self) -> String;
+
+    /// Sign a message, returning an encoded signature
+    fn sign(&self, message: &[u8]) -> SisterResult<String>;
+
+    /// Check that `signature` over `message` was made by `signer`
+    fn verify_signature(&self, signer: &str, message: &[u8], signature: &str)
+        -> SisterResult<bool>;
+}
+
+impl ContextSnapshot {
+    /// Create an uncompressed snapshot, computing the checksum.
+    #[cfg(feature = "hashing")]
+    pub fn new(
+        sister_type: SisterType,
+        version: crate::types::Version,
+        context_info: ContextInfo,
+        data: Vec<u8>,
+    ) -> Self {
+        Self::new_with_clock(sister_type, version, context_info, data, &SystemClock)
+    }
+
+    /// `new`, stamping `snapshot_at` from `clock`.
+    #[cfg(feature = "hashing")]
+    pub fn new_with_clock(
+        sister_type: SisterType,
+        version: crate::types::Version,
+        context_info: ContextInfo,
+        data: Vec<u8>,
+        clock: &dyn Clock,
+    ) -> Self {
+        Self {
+            snapshot_format: SNAPSHOT_FORMAT,
+            sister_type,
+            version,
+            context_info,
+            checksum: *blake3::hash(&data).as_bytes(),
+            data,
+            snapshot_at: clock.now(),
+            compression: CompressionCodec::None,
+            signer: None,
+            signature: None,
+        }
+    }
+
+    /// Whether this snapshot was written in an older layout.
+    pub fn needs_migration(&self) -> bool {
+        self.snapshot_format < SNAPSHOT_FORMAT
+    }
+
+    /// Verify the checksum (decompressing first if needed).
+    ///
+    /// `Ok(false)` on a mismatch. Errors when the data cannot be checked:
+    /// a damaged compressed frame, or the `hashing` feature is disabled.
+    pub fn verify(&self) -> SisterResult<bool> {
+        let data = self.decompressed_data()?;
+        Ok(snapshot_checksum(&data)? == self.checksum)
+    }
+
+    /// Compress `data` for export. The checksum is unchanged.
+    pub fn compressed(mut self, codec: CompressionCodec) -> SisterResult<Self> {
+        if codec == self.compression {
+            return Ok(self);
+        }
+        let raw = self.decompressed_data()?;
+        self.data = compress_frame(codec, &raw)?;
+        self.compression = codec;
+        Ok(self)
+    }
+
+    /// Decompress `data` in place (e.g., right after import).
+    pub fn decompressed(self) -> SisterResult<Self> {
+        self.compressed(CompressionCodec::None)
+    }
+
+    /// The uncompressed context data.
+    pub fn decompressed_data(&self) -> SisterResult<Vec<u8>> {
+        if self.compression == CompressionCodec::None {
+            return Ok(self.data.clone());
+        }
+        let (raw, codec) = decompress_frame(&self.data)?;
+        if codec != self.compression {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                format!(
+                    "Snapshot declares {} compression but data is {}",
+                    self.compression, codec
+                ),
+            ));
+        }
+        Ok(raw)
+    }
+
+    /// Bytes covered by the signature.
+    ///
+    /// Binds the checksum (and so the uncompressed data) to the snapshot's
+    /// identity, so compressing a signed snapshot does not invalidate it.
+    #[cfg(feature = "hashing")]
+    pub fn signing_payload(&self) -> Vec<u8> {
+        format!(
+            "agentic-snapshot-v1\n{}\n{}\n{}\n{}\n{}",
+            self.sister_type,
+            self.version,
+            self.context_info.id,
+            hex::encode(self.checksum),
+            self.snapshot_at
+                .to_rfc3339_opts(chrono::SecondsFormat::Nanos, true),
+        )
+        .into_bytes()
+    }
+
+    /// Sign the snapshot, replacing any previous signature.
+    #[cfg(feature = "hashing")]
+    pub fn signed(mut self, signer: &dyn SnapshotSigner) -> SisterResult<Self> {
+        self.signature = Some(signer.sign(&self.signing_payload())?);
+        self.signer = Some(signer.signer_id());
+        Ok(self)
+    }
+
+    /// Whether the snapshot carries a signature.
+    pub fn is_signed(&self) -> bool {
+        self.signer.is_some() && self.signature.is_some()
+    }
+
+    /// Verify both the checksum and the signature.
+    ///
+    /// `Ok(false)` for unsigned snapshots; use `verify` when a signature
+    /// is optional. Errors as `verify` does, or when the verifier fails.
+    #[cfg(feature = "hashing")]
+    pub fn verify_signed(&self, verifier: &dyn SnapshotSigner) -> SisterResult<bool> {
+        let (Some(signer), Some(signature)) = (&self.signer, &self.signature) else {
+            return Ok(false);
+        };
+        Ok(self.verify()?
+            && verifier.verify_signature(signer, &self.signing_payload(), signature)?)
+    }
+
+    /// Decompress and verify in one step, returning the context data.
+    pub fn verified_data(&self) -> SisterResult<Vec<u8>> {
+        let data = self.decompressed_data()?;
+        if snapshot_checksum(&data)? != self.checksum {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                "Snapshot checksum verification failed",
+            ));
+        }
+        Ok(data)
+    }
+
+    /// Apply a delta produced against this snapshot.
+    ///
+    /// Handles the byte-level ops (`Append`, `Replace`) generically;
+    /// keyed ops need the owning sister (`apply_session_delta`).
+    /// The result is uncompressed and unsigned.
+    #[cfg(feature = "hashing")]
+    pub fn apply_delta(&self, delta: &SnapshotDelta) -> SisterResult<ContextSnapshot> {
+        if delta.base_checksum != self.checksum {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                "Delta was not produced against this snapshot",
+            )
+            .with_context("base_checksum", hex::encode(delta.base_checksum))
+            .with_context("snapshot_checksum", hex::encode(self.checksum)));
+        }
+
+        let mut data = self.verified_data()?;
+        for op in &delta.ops {
+            match op {
+                DeltaOp::Append { data: tail } => data.extend_from_slice(tail),
+                DeltaOp::Replace { data: full } => data = full.clone(),
+                DeltaOp::Put { .. } | DeltaOp::Delete { .. } => {
+                    return Err(SisterError::new(
+                        ErrorCode::NotImplemented,
+                        "Keyed delta ops must be applied by the owning sister",
+                    ));
+                }
+            }
+        }
+
+        let mut applied = ContextSnapshot::new(
+            self.sister_type,
+            self.version.clone(),
+            self.context_info.clone(),
+            data,
+        );
+        if applied.checksum != delta.result_checksum {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                "Applying the delta did not reproduce the expected checksum",
+            ));
+        }
+        applied.snapshot_at = delta.created_at;
+        applied.context_info.updated_at = delta.created_at;
+        applied.context_info.size_bytes = applied.data.len();
+        Ok(applied)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// INCREMENTAL SNAPSHOTS
+// ═══════════════════════════════════════════════════════════════════
+
+/// One change in a `SnapshotDelta`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum DeltaOp {
+    /// Append bytes to the serialized context (append-only sisters)
+    Append {
+        #[serde(with = "base64_serde")]
+        #[cfg_attr(feature = "schema", schemars(with = "String"))]
+        #[cfg_attr(feature = "typescript", ts(type = "string"))]
+        data: Vec<u8>,
+    },
+
+    /// Replace the serialized context entirely
+    Replace {
+        #[serde(with = "base64_serde")]
+        #[cfg_attr(feature = "schema", schemars(with = "String"))]
+        #[cfg_attr(feature = "typescript", ts(type = "string"))]
+        data: Vec<u8>,
+    },
+
+    /// Insert or update a sister-defined item
+    Put {
+        key: String,
+        #[serde(with = "base64_serde")]
+        #[cfg_attr(feature = "schema", schemars(with = "String"))]
+        #[cfg_attr(feature = "typescript", ts(type = "string"))]
+        data: Vec<u8>,
+    },
+
+    /// Remove a sister-defined item
+    Delete { key: String },
+}
+
+/// Changes between two snapshots of the same context.
+///
+/// `base_checksum` pins the snapshot the delta applies to;
+/// `result_checksum` is what the context checksums to afterwards, so a
+/// misapplied delta is caught instead of silently diverging.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct SnapshotDelta {
+    /// Which sister type this came from
+    pub sister_type: SisterType,
+
+    /// Context the delta belongs to
+    pub context_id: ContextId,
+
+    /// Checksum of the base snapshot
+    #[serde(with = "hex_serde")]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    pub base_checksum: [u8; 32],
+
+    /// Checksum after applying the delta
+    #[serde(with = "hex_serde")]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    pub result_checksum: [u8; 32],
+
+    /// When the base snapshot was taken
+    pub since: DateTime<Utc>,
+
+    /// When this delta was created
+    pub created_at: DateTime<Utc>,
+
+    /// Changes, applied in order
+    pub ops: Vec<DeltaOp>,
+}
+
+impl SnapshotDelta {
+    /// Delta from `base` to `current` using only byte-level ops.
+    ///
+    /// Emits a single `Append` when `current` extends `base` (the common
+    /// case for append-only sessions), otherwise a full `Replace`.
+    pub fn between(base: &ContextSnapshot, current: &ContextSnapshot) -> SisterResult<Self> {
+        let old = base.verified_data()?;
+        let new = current.verified_data()?;
+        let ops = if new.len() >= old.len() && new.starts_with(&old) {
+            let tail = new[old.len()..].to_vec();
+            if tail.is_empty() {
+                vec![]
+            } else {
+                vec![DeltaOp::Append { data: tail }]
+            }
+        } else {
+            vec![DeltaOp::Replace { data: new }]
+        };
+        Ok(Self {
+            sister_type: current.sister_type,
+            context_id: current.context_info.id,
+            base_checksum: base.checksum,
+            result_checksum: current.checksum,
+            since: base.snapshot_at,
+            created_at: current.snapshot_at,
+            ops,
+        })
+    }
+
+    /// Whether nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Whether the delta carries the full context (no savings).
+    pub fn is_full(&self) -> bool {
+        self.ops
+            .iter()
+            .any(|op| matches!(op, DeltaOp::Replace { .. }))
+    }
+
+    /// Payload bytes carried by the ops.
+    pub fn payload_size(&self) -> usize {
+        self.ops
+            .iter()
+            .map(|op| match op {
+                DeltaOp::Append { data } | DeltaOp::Replace { data } => data.len(),
+                DeltaOp::Put { key, data } => key.len() + data.len(),
+                DeltaOp::Delete { key } => key.len(),
+            })
+            .sum()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// CHUNKED SNAPSHOTS
+// ═══════════════════════════════════════════════════════════════════
+
+/// One piece of a snapshot too large to ship as a single message.
+///
+/// Chunk 0 carries the snapshot `header` (every field except `data`);
+/// all chunks carry the snapshot checksum so receivers can tell
+/// transfers apart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotChunk {
+    /// Position of this chunk (0-based)
+    pub index: usize,
+
+    /// Number of chunks in the transfer
+    pub total: usize,
+
+    /// Checksum of the whole snapshot (same on every chunk)
+    #[serde(with = "hex_serde")]
+    pub snapshot_checksum: [u8; 32],
+
+    /// Snapshot with empty `data`; present on chunk 0 only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header: Option<Box<ContextSnapshot>>,
+
+    /// This chunk's slice of the snapshot data (as stored, possibly compressed)
+    #[serde(with = "base64_serde")]
+    pub data: Vec<u8>,
+}
+
+/// Iterator over the chunks of a snapshot.
+#[derive(Debug)]
+pub struct SnapshotChunks {
+    header: Option<ContextSnapshot>,
+    data: Vec<u8>,
+    chunk_size: usize,
+    index: usize,
+    total: usize,
+    checksum: [u8; 32],
+}
+
+impl Iterator for SnapshotChunks {
+    type Item = SnapshotChunk;
+
+    fn next(&mut self) -> Option<SnapshotChunk> {
+        if self.index >= self.total {
+            return None;
+        }
+        let start = (self.index * self.chunk_size).min(self.data.len());
+        let end = (start + self.chunk_size).min(self.data.len());
+        let chunk = SnapshotChunk {
+            index: self.index,
+            total: self.total,
+            snapshot_checksum: self.checksum,
+            header: self.header.take().map(Box::new),
+            data: self.data[start..end].to_vec(),
+        };
+        self.index += 1;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.total - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for SnapshotChunks {}
+
+impl ContextSnapshot {
+    /// Split into chunks of at most `chunk_size` data bytes.
+    ///
+    /// Always yields at least one chunk (the header), even for empty data.
+    pub fn into_chunks(mut self, chunk_size: usize) -> SnapshotChunks {
+        let chunk_size = chunk_size.max(1);
+        let data = std::mem::take(&mut self.data);
+        let total = data.len().div_ceil(chunk_size).max(1);
+        SnapshotChunks {
+            checksum: self.checksum,
+            header: Some(self),
+            data,
+            chunk_size,
+            index: 0,
+            total,
         }
     }
 }
 
-/// Exportable context snapshot (for backup/transfer)
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ContextSnapshot {
-    /// Which sister type this came from
-    pub sister_type: SisterType,
+/// Most chunks `SnapshotAssembler` accepts in one transfer.
+pub const MAX_SNAPSHOT_CHUNKS: usize = 1 << 20;
+
+/// Reassembles chunks (in any order) into a verified snapshot.
+#[derive(Debug, Default)]
+pub struct SnapshotAssembler {
+    header: Option<ContextSnapshot>,
+    checksum: Option<[u8; 32]>,
+    chunks: Vec<Option<Vec<u8>>>,
+    received: usize,
+}
+
+impl SnapshotAssembler {
+    /// Start an empty transfer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a chunk. Rejects chunks from a different transfer, duplicates,
+    /// and transfers of more than `MAX_SNAPSHOT_CHUNKS` chunks. Chunk 0
+    /// must carry the header, no other chunk may, and the header's
+    /// checksum must be the transfer's.
+    ///
+    /// The transfer is fixed by the first chunk accepted; a rejected
+    /// chunk leaves the assembler unchanged.
+    pub fn push(&mut self, chunk: SnapshotChunk) -> SisterResult<()> {
+        let total = match self.checksum {
+            None => chunk.total,
+            Some(checksum) if checksum != chunk.snapshot_checksum => {
+                return Err(SisterError::invalid_input(
+                    "Chunk belongs to a different snapshot transfer",
+                ));
+            }
+            Some(_) => self.chunks.len(),
+        };
+        if chunk.total > MAX_SNAPSHOT_CHUNKS {
+            return Err(SisterError::invalid_input(format!(
+                "Transfer of {} chunks exceeds the limit of {}",
+                chunk.total, MAX_SNAPSHOT_CHUNKS
+            )));
+        }
+        if chunk.total != total || chunk.index >= chunk.total {
+            return Err(SisterError::invalid_input(format!(
+                "Chunk {}/{} does not fit a transfer of {} chunks",
+                chunk.index, chunk.total, total
+            )));
+        }
+        match &chunk.header {
+            Some(_) if chunk.index != 0 => {
+                return Err(SisterError::invalid_input(format!(
+                    "Chunk {} carries a header; only chunk 0 may",
+                    chunk.index
+                )));
+            }
+            Some(header) if header.checksum != chunk.snapshot_checksum => {
+                return Err(SisterError::invalid_input(
+                    "Chunk header checksum does not match the transfer",
+                ));
+            }
+            None if chunk.index == 0 => {
+                return Err(SisterError::invalid_input(
+                    "Chunk 0 is missing the snapshot header",
+                ));
+            }
+            _ => {}
+        }
+        if self.checksum.is_none() {
+            self.checksum = Some(chunk.snapshot_checksum);
+            self.chunks = vec![None; total];
+        }
+        if self.chunks[chunk.index].is_some() {
+            return Err(SisterError::new(
+                ErrorCode::AlreadyExists,
+                format!("Chunk {} received twice", chunk.index),
+            ));
+        }
+
+        if let Some(header) = chunk.header {
+            self.header = Some(*header);
+        }
+        self.chunks[chunk.index] = Some(chunk.data);
+        self.received += 1;
+        Ok(())
+    }
+
+    /// Whether every chunk (including the header) has arrived.
+    pub fn is_complete(&self) -> bool {
+        self.header.is_some() && self.received == self.chunks.len() && self.received > 0
+    }
+
+    /// Reassemble and verify the checksum.
+    pub fn finish(self) -> SisterResult<ContextSnapshot> {
+        if !self.is_complete() {
+            return Err(SisterError::new(
+                ErrorCode::InvalidState,
+                format!(
+                    "Snapshot transfer incomplete: {} of {} chunks received",
+                    self.received,
+                    self.chunks.len()
+                ),
+            ));
+        }
+        let mut snapshot = self.header.expect("checked by is_complete");
+        snapshot.data = self.chunks.into_iter().flatten().flatten().collect();
+        if snapshot_checksum(&snapshot.decompressed_data()?)? != snapshot.checksum {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                "Reassembled snapshot failed checksum verification",
+            ));
+        }
+        Ok(snapshot)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// RETENTION
+// ═══════════════════════════════════════════════════════════════════
+
+/// How many past sessions to keep, and for how long.
+///
+/// Sessions beyond either limit are archived by `purge_sessions`: their
+/// snapshot is written to `archive_path` (when set) and the sister drops
+/// them from active storage. The current session is never purged.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct RetentionPolicy {
+    /// Keep at most this many sessions (newest first)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_sessions: Option<usize>,
+
+    /// Archive sessions not updated for this long
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "opt_duration_millis"
+    )]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<u64>"))]
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub max_age: Option<Duration>,
+
+    /// Directory for archived snapshots; without it, expired sessions are discarded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_path: Option<PathBuf>,
+}
+
+impl RetentionPolicy {
+    /// Policy with no limits (keeps everything).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep at most `max` sessions
+    pub fn max_sessions(mut self, max: usize) -> Self {
+        self.max_sessions = Some(max);
+        self
+    }
+
+    /// Archive sessions older than `age`
+    pub fn max_age(mut self, age: Duration) -> Self {
+        self.max_age = Some(age);
+        self
+    }
+
+    /// Write archived snapshots under `path`
+    pub fn archive_to(mut self, path: impl Into<PathBuf>) -> Self {
+        self.archive_path = Some(path.into());
+        self
+    }
+
+    /// Sessions that fall outside the policy, oldest first.
+    pub fn expired(
+        &self,
+        sessions: &[ContextSummary],
+        current: Option<ContextId>,
+        now: DateTime<Utc>,
+    ) -> Vec<ContextId> {
+        let mut newest_first: Vec<_> = sessions.iter().collect();
+        newest_first.sort_by_key(|s| std::cmp::Reverse(s.created_at));
+
+        let mut expired: Vec<_> = newest_first
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| Some(s.id) != current)
+            .filter(|(rank, s)| {
+                let over_count = self.max_sessions.is_some_and(|max| *rank >= max);
+                let over_age = self.max_age.is_some_and(|age| {
+                    now.signed_duration_since(s.updated_at)
+                        .to_std()
+                        .is_ok_and(|elapsed| elapsed > age)
+                });
+                over_count || over_age
+            })
+            .map(|(_, s)| s.id)
+            .collect();
+        expired.reverse();
+        expired
+    }
+}
+
+/// Result of `purge_sessions`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PurgeReport {
+    /// Sessions removed from active storage
+    pub purged: Vec<ContextId>,
+
+    /// Snapshot files written for archived sessions
+    pub archive_files: Vec<PathBuf>,
+
+    /// Sessions still active after the purge
+    pub remaining: usize,
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// CHECKPOINTING
+// ═══════════════════════════════════════════════════════════════════
+
+/// Unique identifier for a checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CheckpointId(pub UniqueId);
+
+impl CheckpointId {
+    /// Create a new random checkpoint ID
+    pub fn new() -> Self {
+        Self(UniqueId::new())
+    }
+}
+
+impl Default for CheckpointId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for CheckpointId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ckpt_{}", self.0)
+    }
+}
+
+/// A stored recovery point.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointInfo {
+    pub id: CheckpointId,
+    /// Session or workspace the checkpoint belongs to
+    pub context_id: ContextId,
+    pub created_at: DateTime<Utc>,
+    /// Items in the context when the checkpoint was taken
+    pub item_count: usize,
+    pub size_bytes: usize,
+}
+
+/// When a sister should checkpoint on its own.
+///
+/// Either trigger fires a checkpoint; with neither set, checkpoints are
+/// only taken when asked.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct CheckpointPolicy {
+    /// Checkpoint after this many new items
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every_items: Option<usize>,
+
+    /// Checkpoint when this much time has passed since the last one
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "opt_duration_millis"
+    )]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<u64>"))]
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub every: Option<Duration>,
+
+    /// Keep at most this many checkpoints per context (oldest dropped first)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_checkpoints: Option<usize>,
+}
+
+impl CheckpointPolicy {
+    /// Policy with no automatic triggers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checkpoint every `items` new items
+    pub fn every_items(mut self, items: usize) -> Self {
+        self.every_items = Some(items);
+        self
+    }
+
+    /// Checkpoint every `interval`
+    pub fn every(mut self, interval: Duration) -> Self {
+        self.every = Some(interval);
+        self
+    }
+
+    /// Keep at most `max` checkpoints
+    pub fn max_checkpoints(mut self, max: usize) -> Self {
+        self.max_checkpoints = Some(max);
+        self
+    }
+
+    /// Whether a checkpoint is due, given the items added and the time of
+    /// the last checkpoint (`None` = never checkpointed).
+    pub fn is_due(
+        &self,
+        items_since: usize,
+        last_checkpoint: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        let items_due = self
+            .every_items
+            .is_some_and(|every| every > 0 && items_since >= every);
+        let time_due = self.every.is_some_and(|every| match last_checkpoint {
+            Some(last) => now
+                .signed_duration_since(last)
+                .to_std()
+                .is_ok_and(|elapsed| elapsed >= every),
+            None => items_since > 0,
+        });
+        items_due || time_due
+    }
+}
+
+/// Crash-recovery points for long-running sisters.
+///
+/// Checkpoints cover the current session or workspace. Sisters apply
+/// their `SisterConfig::checkpoint` policy themselves; Hydra can also
+/// call `checkpoint` directly (e.g., before a risky step).
+pub trait Checkpointing {
+    /// Take a checkpoint of the current context
+    fn checkpoint(&mut self) -> SisterResult<CheckpointId>;
+
+    /// Roll the checkpoint's context back to it
+    fn restore_checkpoint(&mut self, id: CheckpointId) -> SisterResult<()>;
+
+    /// Checkpoints for the current context, oldest first
+    fn list_checkpoints(&self) -> SisterResult<Vec<CheckpointInfo>>;
+
+    /// Most recent checkpoint, if any
+    fn latest_checkpoint(&self) -> SisterResult<Option<CheckpointInfo>> {
+        Ok(self.list_checkpoints()?.pop())
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// QUOTAS
+// ═══════════════════════════════════════════════════════════════════
+
+/// Usage percentage at which sisters should emit `StoragePressure`.
+pub const QUOTA_PRESSURE_PERCENT: f64 = 90.0;
+
+/// Size limits for a single session or workspace.
+///
+/// Sisters check it with `enforce` before growing a context: over the
+/// limit they refuse with `ResourceExhausted`; above
+/// `QUOTA_PRESSURE_PERCENT` they should emit
+/// `SisterEvent::storage_pressure` so Hydra can archive or split work.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct ContextQuota {
+    /// Maximum items per context
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_items: Option<usize>,
+
+    /// Maximum stored bytes per context
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_bytes: Option<usize>,
+}
+
+impl ContextQuota {
+    /// Quota with no limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit items per context
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.max_items = Some(max);
+        self
+    }
+
+    /// Limit bytes per context
+    pub fn max_bytes(mut self, max: usize) -> Self {
+        self.max_bytes = Some(max);
+        self
+    }
+
+    /// Usage of a context with this many items and bytes.
+    pub fn status(&self, item_count: usize, size_bytes: usize) -> QuotaStatus {
+        QuotaStatus {
+            quota: self.clone(),
+            item_count,
+            size_bytes,
+        }
+    }
+
+    /// Check a context's usage, failing with `ResourceExhausted` if it
+    /// is over quota.
+    pub fn enforce(
+        &self,
+        context_id: ContextId,
+        item_count: usize,
+        size_bytes: usize,
+    ) -> SisterResult<QuotaStatus> {
+        let status = self.status(item_count, size_bytes);
+        if status.is_exceeded() {
+            return Err(status.exceeded_error(context_id));
+        }
+        Ok(status)
+    }
+}
+
+/// A context's usage against its quota.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct QuotaStatus {
+    /// The limits that apply
+    pub quota: ContextQuota,
+
+    /// Items currently in the context
+    pub item_count: usize,
+
+    /// Bytes currently stored
+    pub size_bytes: usize,
+}
+
+impl QuotaStatus {
+    /// Highest usage across the configured limits, in percent (0 when unlimited).
+    pub fn usage_percent(&self) -> f64 {
+        let ratio = |used: usize, max: Option<usize>| match max {
+            Some(0) => f64::INFINITY,
+            Some(max) => used as f64 / max as f64 * 100.0,
+            None => 0.0,
+        };
+        ratio(self.item_count, self.quota.max_items)
+            .max(ratio(self.size_bytes, self.quota.max_bytes))
+    }
+
+    /// Whether any limit is exceeded.
+    pub fn is_exceeded(&self) -> bool {
+        self.quota
+            .max_items
+            .is_some_and(|max| self.item_count > max)
+            || self
+                .quota
+                .max_bytes
+                .is_some_and(|max| self.size_bytes > max)
+    }
+
+    /// Whether usage is at or above `QUOTA_PRESSURE_PERCENT`.
+    pub fn is_under_pressure(&self) -> bool {
+        self.usage_percent() >= QUOTA_PRESSURE_PERCENT
+    }
+
+    /// The standard error for a context over its quota.
+    pub fn exceeded_error(&self, context_id: ContextId) -> SisterError {
+        SisterError::new(
+            ErrorCode::ResourceExhausted,
+            format!(
+                "Context {} exceeds its quota ({:.0}% used)",
+                context_id,
+                self.usage_percent()
+            ),
+        )
+        .in_context(context_id)
+        .with_context("item_count", self.item_count)
+        .with_context("size_bytes", self.size_bytes)
+        .with_context("quota", &self.quota)
+        .with_suggestion(SuggestedAction::Alternative {
+            description: "Archive old sessions or start a new context".into(),
+        })
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// ACTIVITY STATISTICS
+// ═══════════════════════════════════════════════════════════════════
 
-    /// Version of the sister that created this
-    pub version: crate::types::Version,
+/// Items added during one time bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityBucket {
+    /// Bucket start (aligned to `ContextStats::bucket_width`)
+    pub start: DateTime<Utc>,
 
-    /// Context information
-    pub context_info: ContextInfo,
+    /// Items added within `[start, start + bucket_width)`
+    pub items_added: usize,
+}
 
-    /// Serialized context data (sister-specific format)
-    #[serde(with = "base64_serde")]
-    pub data: Vec<u8>,
+/// Per-context activity, in a shape dashboards can render for any sister.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContextStats {
+    pub context_id: ContextId,
 
-    /// Checksum of the data (BLAKE3)
-    #[serde(with = "hex_serde")]
-    pub checksum: [u8; 32],
+    /// Items currently in the context
+    pub item_count: usize,
 
-    /// When this snapshot was created
-    pub snapshot_at: DateTime<Utc>,
+    pub size_bytes: usize,
+
+    /// Width of each `items_added` bucket
+    #[serde(with = "duration_millis")]
+    pub bucket_width: Duration,
+
+    /// Items added over time, oldest bucket first (empty buckets omitted)
+    #[serde(default)]
+    pub items_added: Vec<ActivityBucket>,
+
+    /// Operations performed, by operation name (e.g., "query", "ground")
+    #[serde(default)]
+    pub operation_counts: HashMap<String, u64>,
+
+    /// Most recent activity of any kind
+    pub last_activity: Option<DateTime<Utc>>,
 }
 
-impl ContextSnapshot {
-    /// Verify the checksum
-    pub fn verify(&self) -> bool {
-        let computed = blake3::hash(&self.data);
-        computed.as_bytes() == &self.checksum
+impl ContextStats {
+    /// Empty stats with the given bucket width.
+    pub fn new(context_id: ContextId, bucket_width: Duration) -> Self {
+        Self {
+            context_id,
+            item_count: 0,
+            size_bytes: 0,
+            bucket_width,
+            items_added: Vec::new(),
+            operation_counts: HashMap::new(),
+            last_activity: None,
+        }
+    }
+
+    /// Totals only, from a context's info (hourly buckets, none filled).
+    pub fn from_info(info: &ContextInfo) -> Self {
+        let mut stats = Self::new(info.id, Duration::from_secs(3600));
+        stats.item_count = info.item_count;
+        stats.size_bytes = info.size_bytes;
+        stats.last_activity = Some(info.updated_at);
+        stats
+    }
+
+    /// Count `count` items added at `at`.
+    pub fn record_items(&mut self, at: DateTime<Utc>, count: usize) {
+        let width = (self.bucket_width.as_millis() as i64).max(1);
+        let start_ms = at.timestamp_millis().div_euclid(width) * width;
+        let start = DateTime::from_timestamp_millis(start_ms).unwrap_or(at);
+
+        match self.items_added.binary_search_by_key(&start, |b| b.start) {
+            Ok(i) => self.items_added[i].items_added += count,
+            Err(i) => self.items_added.insert(
+                i,
+                ActivityBucket {
+                    start,
+                    items_added: count,
+                },
+            ),
+        }
+        self.touch(at);
+    }
+
+    /// Count one operation performed at `at`.
+    pub fn record_operation(&mut self, operation: &str, at: DateTime<Utc>) {
+        *self
+            .operation_counts
+            .entry(operation.to_string())
+            .or_default() += 1;
+        self.touch(at);
+    }
+
+    /// Total items added across all buckets.
+    pub fn total_items_added(&self) -> usize {
+        self.items_added.iter().map(|b| b.items_added).sum()
+    }
+
+    fn touch(&mut self, at: DateTime<Utc>) {
+        if self.last_activity.is_none_or(|last| at > last) {
+            self.last_activity = Some(at);
+        }
     }
 }
 
@@ -170,6 +1399,13 @@ pub trait SessionManagement {
     /// List all past sessions (most recent first)
     fn list_sessions(&self) -> SisterResult<Vec<ContextSummary>>;
 
+    /// Find sessions by name, tags, or date (most recent first).
+    ///
+    /// Default: filters `list_sessions()`. Sisters with an index should override
+    fn find_sessions(&self, filter: &ContextFilter) -> SisterResult<Vec<ContextSummary>> {
+        Ok(filter.apply(self.list_sessions()?))
+    }
+
     /// Get info about a specific past session
     fn get_session_info(&self, id: ContextId) -> SisterResult<ContextInfo> {
         self.list_sessions()?
@@ -182,16 +1418,142 @@ pub trait SessionManagement {
                 updated_at: summary.updated_at,
                 item_count: summary.item_count,
                 size_bytes: summary.size_bytes,
+                tags: summary.tags,
                 metadata: Metadata::new(),
+                parent_id: None,
+                child_ids: Vec::new(),
+                quota: None,
             })
             .ok_or_else(|| crate::errors::SisterError::context_not_found(id.to_string()))
     }
 
+    /// Activity statistics for a session.
+    ///
+    /// Default: totals and last activity from `get_session_info()`, with
+    /// no buckets or operation counts. Sisters that track activity should
+    /// override
+    fn session_stats(&self, id: ContextId) -> SisterResult<ContextStats> {
+        Ok(ContextStats::from_info(&self.get_session_info(id)?))
+    }
+
+    /// Set a metadata entry on a session (replacing any existing value).
+    ///
+    /// Lets orchestrators annotate sessions, e.g. with a run ID. The
+    /// value shows up in `ContextInfo::metadata`
+    fn set_context_metadata(
+        &mut self,
+        id: ContextId,
+        key: &str,
+        value: serde_json::Value,
+    ) -> SisterResult<()> {
+        let _ = (key, value);
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            format!("This sister does not support metadata on session {}", id),
+        ))
+    }
+
+    /// Remove a metadata entry from a session (missing keys are ignored)
+    fn remove_context_metadata(&mut self, id: ContextId, key: &str) -> SisterResult<()> {
+        let _ = key;
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            format!("This sister does not support metadata on session {}", id),
+        ))
+    }
+
+    /// Fork a new session from the state of `from`, without ending it.
+    ///
+    /// The new session's `parent_id` is `from`, and `from` lists it in
+    /// `child_ids`. Whether the fork becomes the current session is up to
+    /// the sister; implementations should emit `ContextCreated`
+    fn fork_session(&mut self, from: ContextId, name: &str) -> SisterResult<ContextId> {
+        let _ = name;
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            format!("This sister does not support forking session {}", from),
+        ))
+    }
+
     /// Export a session as a snapshot (for backup/transfer)
     fn export_session(&self, id: ContextId) -> SisterResult<ContextSnapshot>;
 
     /// Import a session from a snapshot
     fn import_session(&mut self, snapshot: ContextSnapshot) -> SisterResult<ContextId>;
+
+    /// Export only what changed in a session since `since` was exported.
+    ///
+    /// Default: a full export diffed byte-wise against `since`. Sisters
+    /// that track changes should override this to avoid the full export
+    fn export_session_delta(
+        &self,
+        id: ContextId,
+        since: &ContextSnapshot,
+    ) -> SisterResult<SnapshotDelta> {
+        SnapshotDelta::between(since, &self.export_session(id)?)
+    }
+
+    /// Drop a past session from active storage (after it has been archived).
+    /// Must not be called for the current session
+    fn archive_session(&mut self, id: ContextId) -> SisterResult<()> {
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            format!("This sister does not support archiving session {}", id),
+        ))
+    }
+
+    /// Archive every session outside `policy`.
+    ///
+    /// Default: for each expired session, write its snapshot to
+    /// `<archive_path>/<id>.json` (if set), call `archive_session`, and
+    /// report `ContextArchived` / `ContextDeleted` via `on_session_event`
+    fn purge_sessions(&mut self, policy: &RetentionPolicy) -> SisterResult<PurgeReport> {
+        let sessions = self.list_sessions()?;
+        let expired = policy.expired(&sessions, self.current_session(), Utc::now());
+        let mut report = PurgeReport::default();
+
+        for id in expired {
+            let event = match &policy.archive_path {
+                Some(dir) => {
+                    let snapshot = self.export_session(id)?;
+                    std::fs::create_dir_all(dir)?;
+                    let path = dir.join(format!("{}.json", id));
+                    atomic_write(&path, &serde_json::to_vec(&snapshot)?)?;
+                    self.archive_session(id)?;
+                    let event = EventType::ContextArchived {
+                        context_id: id,
+                        archive_path: Some(path.display().to_string()),
+                    };
+                    report.archive_files.push(path);
+                    event
+                }
+                None => {
+                    self.archive_session(id)?;
+                    EventType::ContextDeleted { context_id: id }
+                }
+            };
+            self.on_session_event(event);
+            report.purged.push(id);
+        }
+
+        report.remaining = sessions.len() - report.purged.len();
+        Ok(report)
+    }
+
+    /// Hook for events raised by default methods (archive/purge).
+    /// Sisters with an `EventManager` forward these to it
+    fn on_session_event(&self, event: EventType) {
+        let _ = event;
+    }
+
+    /// Apply a delta to a session previously imported or exported
+    fn apply_session_delta(&mut self, delta: &SnapshotDelta) -> SisterResult<()> {
+        let _ = delta;
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            "This sister does not support incremental session import",
+        ))
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════
@@ -232,6 +1594,13 @@ pub trait WorkspaceManagement {
     /// List all workspaces
     fn list_workspaces(&self) -> SisterResult<Vec<ContextSummary>>;
 
+    /// Find workspaces by name, tags, or date.
+    ///
+    /// Default: filters `list_workspaces()`
+    fn find_workspaces(&self, filter: &ContextFilter) -> SisterResult<Vec<ContextSummary>> {
+        Ok(filter.apply(self.list_workspaces()?))
+    }
+
     /// Delete a workspace.
     /// Cannot delete the current workspace — switch first
     fn delete_workspace(&mut self, id: ContextId) -> SisterResult<()>;
@@ -239,12 +1608,78 @@ pub trait WorkspaceManagement {
     /// Rename a workspace
     fn rename_workspace(&mut self, id: ContextId, new_name: &str) -> SisterResult<()>;
 
+    /// Set a metadata entry on a workspace (replacing any existing value).
+    ///
+    /// The value shows up in `ContextInfo::metadata`
+    fn set_context_metadata(
+        &mut self,
+        id: ContextId,
+        key: &str,
+        value: serde_json::Value,
+    ) -> SisterResult<()> {
+        let _ = (key, value);
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            format!("This sister does not support metadata on workspace {}", id),
+        ))
+    }
+
+    /// Remove a metadata entry from a workspace (missing keys are ignored)
+    fn remove_context_metadata(&mut self, id: ContextId, key: &str) -> SisterResult<()> {
+        let _ = key;
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            format!("This sister does not support metadata on workspace {}", id),
+        ))
+    }
+
     /// Export workspace as snapshot
     fn export_workspace(&self, id: ContextId) -> SisterResult<ContextSnapshot>;
 
     /// Import workspace from snapshot
     fn import_workspace(&mut self, snapshot: ContextSnapshot) -> SisterResult<ContextId>;
 
+    /// Export only what changed in a workspace since `since` was exported.
+    ///
+    /// Default: a full export diffed byte-wise against `since`
+    fn export_workspace_delta(
+        &self,
+        id: ContextId,
+        since: &ContextSnapshot,
+    ) -> SisterResult<SnapshotDelta> {
+        SnapshotDelta::between(since, &self.export_workspace(id)?)
+    }
+
+    /// Export a workspace as a stream of chunks of at most `chunk_size` bytes
+    fn export_workspace_chunked(
+        &self,
+        id: ContextId,
+        chunk_size: usize,
+    ) -> SisterResult<SnapshotChunks> {
+        Ok(self.export_workspace(id)?.into_chunks(chunk_size))
+    }
+
+    /// Import a workspace from chunks, verifying the checksum once all have arrived
+    fn import_workspace_chunked(
+        &mut self,
+        chunks: &mut dyn Iterator<Item = SnapshotChunk>,
+    ) -> SisterResult<ContextId> {
+        let mut assembler = SnapshotAssembler::new();
+        for chunk in chunks {
+            assembler.push(chunk)?;
+        }
+        self.import_workspace(assembler.finish()?)
+    }
+
+    /// Apply a delta to an existing workspace
+    fn apply_workspace_delta(&mut self, delta: &SnapshotDelta) -> SisterResult<()> {
+        let _ = delta;
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            "This sister does not support incremental workspace import",
+        ))
+    }
+
     /// Get workspace info by ID
     fn get_workspace_info(&self, id: ContextId) -> SisterResult<ContextInfo> {
         self.list_workspaces()?
@@ -257,7 +1692,11 @@ pub trait WorkspaceManagement {
                 updated_at: summary.updated_at,
                 item_count: summary.item_count,
                 size_bytes: summary.size_bytes,
+                tags: summary.tags,
                 metadata: Metadata::new(),
+                parent_id: None,
+                child_ids: Vec::new(),
+                quota: None,
             })
             .ok_or_else(|| crate::errors::SisterError::context_not_found(id.to_string()))
     }
@@ -295,22 +1734,52 @@ pub struct SessionContext {
 
 // Base64 serialization for binary data
 mod base64_serde {
-    use base64::{engine::general_purpose::STANDARD, Engine};
     use serde::{Deserialize, Deserializer, Serializer};
 
+    /// Base64 text in JSON; raw bytes in binary codecs.
     pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_str(&STANDARD.encode(bytes))
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&encode(bytes).map_err(serde::ser::Error::custom)?)
+        } else {
+            serializer.serialize_bytes(bytes)
+        }
     }
 
     pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        STANDARD.decode(&s).map_err(serde::de::Error::custom)
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            decode(&s).map_err(serde::de::Error::custom)
+        } else {
+            deserializer.deserialize_byte_buf(super::BytesVisitor)
+        }
+    }
+
+    #[cfg(feature = "hashing")]
+    fn encode(bytes: &[u8]) -> Result<String, String> {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+        Ok(STANDARD.encode(bytes))
+    }
+
+    #[cfg(feature = "hashing")]
+    fn decode(text: &str) -> Result<Vec<u8>, String> {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+        STANDARD.decode(text).map_err(|e| e.to_string())
+    }
+
+    #[cfg(not(feature = "hashing"))]
+    fn encode(_bytes: &[u8]) -> Result<String, String> {
+        Err(super::TEXT_BYTES_DISABLED.into())
+    }
+
+    #[cfg(not(feature = "hashing"))]
+    fn decode(_text: &str) -> Result<Vec<u8>, String> {
+        Err(super::TEXT_BYTES_DISABLED.into())
     }
 }
 
@@ -318,26 +1787,133 @@ mod base64_serde {
 mod hex_serde {
     use serde::{Deserialize, Deserializer, Serializer};
 
+    /// Hex text in JSON; raw bytes in binary codecs.
     pub fn serialize<S>(bytes: &[u8; 32], serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_str(&hex::encode(bytes))
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&encode(bytes).map_err(serde::ser::Error::custom)?)
+        } else {
+            serializer.serialize_bytes(bytes)
+        }
     }
 
     pub fn deserialize<'de, D>(deserializer: D) -> Result<[u8; 32], D::Error>
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        let bytes = hex::decode(&s).map_err(serde::de::Error::custom)?;
+        let bytes = if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            decode(&s).map_err(serde::de::Error::custom)?
+        } else {
+            deserializer.deserialize_byte_buf(super::BytesVisitor)?
+        };
         bytes
             .try_into()
             .map_err(|_| serde::de::Error::custom("invalid checksum length"))
     }
+
+    #[cfg(feature = "hashing")]
+    fn encode(bytes: &[u8]) -> Result<String, String> {
+        Ok(hex::encode(bytes))
+    }
+
+    #[cfg(feature = "hashing")]
+    fn decode(text: &str) -> Result<Vec<u8>, String> {
+        hex::decode(text).map_err(|e| e.to_string())
+    }
+
+    #[cfg(not(feature = "hashing"))]
+    fn encode(_bytes: &[u8]) -> Result<String, String> {
+        Err(super::TEXT_BYTES_DISABLED.into())
+    }
+
+    #[cfg(not(feature = "hashing"))]
+    fn decode(_text: &str) -> Result<Vec<u8>, String> {
+        Err(super::TEXT_BYTES_DISABLED.into())
+    }
+}
+
+/// Why snapshot byte fields fail in JSON without the `hashing` feature,
+/// which brings the hex and base64 encodings. Binary codecs carry the
+/// raw bytes and work either way.
+#[cfg(not(feature = "hashing"))]
+const TEXT_BYTES_DISABLED: &str =
+    "hex/base64 snapshot fields need the `hashing` feature of agentic-sdk";
+
+/// Byte fields from binary codecs (MessagePack bin, CBOR byte string, or
+/// a plain array of integers).
+struct BytesVisitor;
+
+impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a byte string")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, bytes: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(bytes)
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
+// Duration serialization as milliseconds
+mod duration_millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(duration.as_millis() as u64)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Duration::from_millis(u64::deserialize(deserializer)?))
+    }
+}
+
+// Optional duration serialization as milliseconds
+mod opt_duration_millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match duration {
+            Some(d) => serializer.serialize_some(&(d.as_millis() as u64)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<u64>::deserialize(deserializer)?.map(Duration::from_millis))
+    }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "hashing"))]
 mod tests {
     use super::*;
 
@@ -358,4 +1934,442 @@ mod tests {
         let parsed: ContextId = s.as_str().into();
         assert!(!parsed.is_default() || id.is_default());
     }
+
+    fn sample_info() -> ContextInfo {
+        ContextInfo {
+            id: ContextId::new(),
+            name: "session".into(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            item_count: 1,
+            size_bytes: 5,
+            tags: Vec::new(),
+            metadata: Metadata::new(),
+            parent_id: None,
+            child_ids: Vec::new(),
+            quota: None,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_verify_and_legacy_json() {
+        let snapshot = ContextSnapshot::new(
+            SisterType::Memory,
+            crate::types::Version::new(1, 0, 0),
+            sample_info(),
+            b"hello".to_vec(),
+        );
+        assert!(snapshot.verify().unwrap());
+        assert_eq!(snapshot.verified_data().unwrap(), b"hello");
+
+        // Snapshots exported before the compression field still load
+        let mut json = serde_json::to_value(&snapshot).unwrap();
+        json.as_object_mut().unwrap().remove("compression");
+        let legacy: ContextSnapshot = serde_json::from_value(json).unwrap();
+        assert_eq!(legacy.compression, CompressionCodec::None);
+        assert!(legacy.verify().unwrap());
+        assert!(!legacy.needs_migration());
+
+        let mut tampered = snapshot;
+        tampered.data[0] ^= 1;
+        assert!(!tampered.verify().unwrap());
+        assert!(tampered.verified_data().is_err());
+    }
+
+    #[test]
+    fn test_checkpoint_policy_due() {
+        let now = Utc::now();
+        let policy = CheckpointPolicy::new()
+            .every_items(100)
+            .every(Duration::from_secs(60));
+
+        assert!(!policy.is_due(0, None, now));
+        assert!(policy.is_due(1, None, now));
+        assert!(!policy.is_due(99, Some(now), now));
+        assert!(policy.is_due(100, Some(now), now));
+        assert!(policy.is_due(1, Some(now - chrono::Duration::seconds(61)), now));
+        assert!(!CheckpointPolicy::new().is_due(1_000, None, now));
+
+        let json = serde_json::to_value(&policy).unwrap();
+        assert_eq!(json["every"], 60_000);
+    }
+
+    #[test]
+    fn test_quota_enforcement() {
+        let quota = ContextQuota::new().max_items(10).max_bytes(1_000);
+        let id = ContextId::new();
+
+        let status = quota.enforce(id, 5, 950).unwrap();
+        assert_eq!(status.usage_percent(), 95.0);
+        assert!(status.is_under_pressure());
+        assert!(!ContextQuota::new().status(1_000_000, 0).is_under_pressure());
+
+        let err = quota.enforce(id, 11, 0).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ResourceExhausted);
+        assert_eq!(err.context.unwrap()["quota"]["max_items"], 10);
+    }
+
+    #[test]
+    fn test_context_stats_buckets() {
+        let mut stats = ContextStats::new(ContextId::new(), Duration::from_secs(60));
+        let t0 = DateTime::from_timestamp(1_699_999_980, 0).unwrap(); // minute-aligned
+        let later = t0 + chrono::Duration::seconds(90);
+
+        stats.record_items(later, 2);
+        stats.record_items(t0 + chrono::Duration::seconds(5), 3);
+        stats.record_items(t0 + chrono::Duration::seconds(10), 1);
+        stats.record_operation("query", t0);
+
+        assert_eq!(stats.items_added.len(), 2);
+        assert_eq!(stats.items_added[0].items_added, 4);
+        assert_eq!(
+            stats.items_added[1].start,
+            t0 + chrono::Duration::seconds(60)
+        );
+        assert_eq!(stats.total_items_added(), 6);
+        assert_eq!(stats.operation_counts["query"], 1);
+        assert_eq!(stats.last_activity, Some(later));
+    }
+
+    #[test]
+    fn test_migrate_unversioned_snapshot() {
+        let snapshot = ContextSnapshot::new(
+            SisterType::Memory,
+            crate::types::Version::new(1, 0, 0),
+            sample_info(),
+            b"hello".to_vec(),
+        );
+        assert_eq!(snapshot.snapshot_format, SNAPSHOT_FORMAT);
+
+        let mut json = serde_json::to_value(&snapshot).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        for field in ["snapshot_format", "compression"] {
+            fields.remove(field);
+        }
+        let bytes = serde_json::to_vec(&json).unwrap();
+
+        let unversioned: ContextSnapshot = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(unversioned.snapshot_format, 0);
+        assert!(unversioned.needs_migration());
+
+        let migrated = migrate_snapshot(&bytes).unwrap();
+        assert_eq!(migrated.snapshot_format, SNAPSHOT_FORMAT);
+        assert!(migrated.verify().unwrap());
+
+        json["snapshot_format"] = (SNAPSHOT_FORMAT + 1).into();
+        let err = migrate_snapshot(&serde_json::to_vec(&json).unwrap()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::VersionMismatch);
+    }
+
+    #[test]
+    fn test_snapshot_delta_append_and_replace() {
+        let info = sample_info();
+        let version = crate::types::Version::new(1, 0, 0);
+        let base = ContextSnapshot::new(
+            SisterType::Memory,
+            version.clone(),
+            info.clone(),
+            b"abc".into(),
+        );
+        let grown = ContextSnapshot::new(
+            SisterType::Memory,
+            version.clone(),
+            info.clone(),
+            b"abcdef".into(),
+        );
+
+        let delta = SnapshotDelta::between(&base, &grown).unwrap();
+        assert_eq!(
+            delta.ops,
+            vec![DeltaOp::Append {
+                data: b"def".to_vec()
+            }]
+        );
+        assert!(!delta.is_full());
+        assert_eq!(base.apply_delta(&delta).unwrap().data, b"abcdef");
+
+        // Applying against the wrong base is rejected
+        let err = grown.apply_delta(&delta).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ChecksumMismatch);
+
+        let rewritten = ContextSnapshot::new(SisterType::Memory, version, info, b"xyz".into());
+        let delta = SnapshotDelta::between(&grown, &rewritten).unwrap();
+        assert!(delta.is_full());
+        assert_eq!(
+            grown.apply_delta(&delta).unwrap().checksum,
+            rewritten.checksum
+        );
+
+        assert!(SnapshotDelta::between(&base, &base).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_chunk_roundtrip() {
+        let data: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        let snapshot = ContextSnapshot::new(
+            SisterType::Codebase,
+            crate::types::Version::new(1, 0, 0),
+            sample_info(),
+            data.clone(),
+        );
+        let mut chunks: Vec<_> = snapshot.into_chunks(300).collect();
+        assert_eq!(chunks.len(), 4);
+        assert!(chunks[0].header.as_ref().unwrap().data.is_empty());
+        assert!(chunks[1].header.is_none());
+
+        // Out of order, via JSON
+        chunks.reverse();
+        let mut assembler = SnapshotAssembler::new();
+        for chunk in chunks.clone() {
+            let json = serde_json::to_string(&chunk).unwrap();
+            assembler
+                .push(serde_json::from_str(&json).unwrap())
+                .unwrap();
+        }
+        assert_eq!(assembler.finish().unwrap().data, data);
+
+        // Oversized or malformed first chunks are rejected without
+        // fixing the transfer
+        let mut assembler = SnapshotAssembler::new();
+        let mut huge = chunks[0].clone();
+        huge.total = usize::MAX;
+        assert!(assembler.push(huge).is_err());
+        let mut bad_index = chunks[0].clone();
+        bad_index.index = bad_index.total;
+        assert!(assembler.push(bad_index).is_err());
+
+        // The header only rides on chunk 0 and must match the transfer
+        let header_chunk = chunks[3].clone();
+        assert_eq!(header_chunk.index, 0);
+        let mut late_header = chunks[0].clone();
+        late_header.header = header_chunk.header.clone();
+        assert!(assembler.push(late_header).is_err());
+        let mut forged = header_chunk.clone();
+        forged.header.as_mut().unwrap().checksum = [0; 32];
+        assert!(assembler.push(forged).is_err());
+        let mut headless = header_chunk;
+        headless.header = None;
+        assert!(assembler.push(headless).is_err());
+
+        // Missing and corrupted chunks are caught
+        assembler.push(chunks[0].clone()).unwrap();
+        assert!(assembler.push(chunks[0].clone()).is_err());
+        assert_eq!(
+            assembler.finish().unwrap_err().code,
+            ErrorCode::InvalidState
+        );
+
+        chunks[1].data[0] ^= 1;
+        let mut assembler = SnapshotAssembler::new();
+        for chunk in chunks {
+            assembler.push(chunk).unwrap();
+        }
+        assert_eq!(
+            assembler.finish().unwrap_err().code,
+            ErrorCode::ChecksumMismatch
+        );
+    }
+
+    #[test]
+    fn test_context_filter() {
+        let now = Utc::now();
+        let summary = |name: &str, tags: &[&str], age_days: i64| ContextSummary {
+            id: ContextId::new(),
+            name: name.into(),
+            created_at: now - chrono::Duration::days(age_days),
+            updated_at: now - chrono::Duration::days(age_days),
+            item_count: 0,
+            size_bytes: 0,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        };
+        let contexts = vec![
+            summary("Auth refactor", &["auth", "backend"], 1),
+            summary("auth docs", &["auth"], 10),
+            summary("UI polish", &["frontend"], 2),
+        ];
+
+        let found = ContextFilter::new()
+            .name_contains("AUTH")
+            .apply(contexts.clone());
+        assert_eq!(found.len(), 2);
+
+        let found = ContextFilter::new()
+            .tag("auth")
+            .tag("backend")
+            .apply(contexts.clone());
+        assert_eq!(found[0].name, "Auth refactor");
+        assert_eq!(found.len(), 1);
+
+        let found = ContextFilter::new()
+            .created_after(now - chrono::Duration::days(5))
+            .limit(1)
+            .apply(contexts.clone());
+        assert_eq!(found.len(), 1);
+        assert_eq!(ContextFilter::new().apply(contexts).len(), 3);
+    }
+
+    #[test]
+    fn test_retention_policy_expired() {
+        let now = Utc::now();
+        let sessions: Vec<_> = (0..5)
+            .map(|i| ContextSummary {
+                id: ContextId::new(),
+                name: format!("s{}", i),
+                created_at: now - chrono::Duration::days(i),
+                updated_at: now - chrono::Duration::days(i),
+                item_count: 0,
+                size_bytes: 0,
+                tags: Vec::new(),
+            })
+            .collect();
+
+        let policy = RetentionPolicy::new().max_sessions(2);
+        let expired = policy.expired(&sessions, Some(sessions[0].id), now);
+        assert_eq!(
+            expired,
+            vec![sessions[4].id, sessions[3].id, sessions[2].id]
+        );
+
+        // The current session survives even when it is the oldest
+        let policy = RetentionPolicy::new().max_age(Duration::from_secs(36 * 3600));
+        let expired = policy.expired(&sessions, Some(sessions[4].id), now);
+        assert_eq!(expired, vec![sessions[3].id, sessions[2].id]);
+
+        assert!(RetentionPolicy::new()
+            .expired(&sessions, None, now)
+            .is_empty());
+
+        let json = serde_json::to_value(&policy).unwrap();
+        assert_eq!(json["max_age"], 36 * 3600 * 1000);
+        let parsed: RetentionPolicy = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, policy);
+    }
+
+    /// Keyed-hash signer standing in for Identity
+    struct TestSigner(&'static str);
+
+    impl SnapshotSigner for TestSigner {
+        fn signer_id(&self) -> String {
+            self.0.to_string()
+        }
+
+        fn sign(&self, message: &[u8]) -> SisterResult<String> {
+            let key = blake3::hash(self.0.as_bytes());
+            Ok(blake3::keyed_hash(key.as_bytes(), message)
+                .to_hex()
+                .to_string())
+        }
+
+        fn verify_signature(
+            &self,
+            signer: &str,
+            message: &[u8],
+            signature: &str,
+        ) -> SisterResult<bool> {
+            let key = blake3::hash(signer.as_bytes());
+            Ok(blake3::keyed_hash(key.as_bytes(), message)
+                .to_hex()
+                .as_str()
+                == signature)
+        }
+    }
+
+    #[test]
+    fn test_signed_snapshot() {
+        let snapshot = ContextSnapshot::new(
+            SisterType::Memory,
+            crate::types::Version::new(1, 0, 0),
+            sample_info(),
+            b"hello".to_vec(),
+        );
+        let verifier = TestSigner("verifier");
+        assert!(!snapshot.is_signed());
+        assert!(!snapshot.verify_signed(&verifier).unwrap());
+
+        let signed = snapshot.signed(&TestSigner("aid_alice")).unwrap();
+        assert_eq!(signed.signer.as_deref(), Some("aid_alice"));
+        assert!(signed.verify_signed(&verifier).unwrap());
+
+        let json = serde_json::to_string(&signed).unwrap();
+        let parsed: ContextSnapshot = serde_json::from_str(&json).unwrap();
+        assert!(parsed.verify_signed(&verifier).unwrap());
+
+        let mut forged = parsed.clone();
+        forged.signer = Some("aid_mallory".into());
+        assert!(!forged.verify_signed(&verifier).unwrap());
+
+        let mut tampered = parsed;
+        tampered.data = b"world".to_vec();
+        tampered.checksum = *blake3::hash(&tampered.data).as_bytes();
+        assert!(tampered.verify().unwrap());
+        assert!(!tampered.verify_signed(&verifier).unwrap());
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_snapshot_compression_keeps_checksum() {
+        let data = vec![b'a'; 10_000];
+        let snapshot = ContextSnapshot::new(
+            SisterType::Memory,
+            crate::types::Version::new(1, 0, 0),
+            sample_info(),
+            data.clone(),
+        );
+        let checksum = snapshot.checksum;
+
+        let compressed = snapshot.compressed(CompressionCodec::Zstd).unwrap();
+        assert!(compressed.data.len() < 1000);
+        assert_eq!(compressed.checksum, checksum);
+        assert!(compressed.verify().unwrap());
+
+        let json = serde_json::to_string(&compressed).unwrap();
+        let parsed: ContextSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.compression, CompressionCodec::Zstd);
+        assert_eq!(parsed.decompressed().unwrap().data, data);
+    }
+}
+
+#[cfg(all(test, not(feature = "hashing")))]
+mod no_hashing_tests {
+    use super::*;
+
+    fn sample_snapshot() -> ContextSnapshot {
+        ContextSnapshot {
+            snapshot_format: SNAPSHOT_FORMAT,
+            sister_type: SisterType::Memory,
+            version: crate::types::Version::new(1, 0, 0),
+            context_info: ContextInfo {
+                id: ContextId::new(),
+                name: "session".into(),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                item_count: 1,
+                size_bytes: 5,
+                tags: Vec::new(),
+                metadata: Metadata::new(),
+                parent_id: None,
+                child_ids: Vec::new(),
+                quota: None,
+            },
+            data: b"hello".to_vec(),
+            checksum: [0; 32],
+            snapshot_at: Utc::now(),
+            compression: CompressionCodec::None,
+            signer: None,
+            signature: None,
+        }
+    }
+
+    #[test]
+    fn test_verify_requires_hashing() {
+        let err = sample_snapshot().verify().unwrap_err();
+        assert_eq!(err.code, ErrorCode::NotImplemented);
+        assert_eq!(err.context.unwrap()["feature"], "hashing");
+    }
+
+    #[test]
+    fn test_json_bytes_require_hashing() {
+        let err = serde_json::to_string(&sample_snapshot()).unwrap_err();
+        assert!(err.to_string().contains("`hashing` feature"));
+    }
 }
@@ -0,0 +1,416 @@
+//! Cross-sister context coordination.
+//!
+//! When Hydra starts a task it starts a Memory session, a Vision session,
+//! and switches the Codebase workspace. If any of those fails, the ones
+//! that already happened must be undone, or the sisters disagree about
+//! what the current task is.
+//!
+//! ```rust,ignore
+//! let outcome = ContextCoordinator::new()
+//!     .session(SisterType::Memory, &mut memory)
+//!     .session(SisterType::Vision, &mut vision)
+//!     .workspace(SisterType::Codebase, &mut codebase, feature_ws)
+//!     .on_event(|event| hydra_log.record(event))
+//!     .begin("fix-auth-bug")?;
+//! ```
+//!
+//! Steps run in registration order. On the first failure, completed
+//! steps are compensated in reverse (sessions ended, workspaces switched
+//! back, created workspaces deleted) and one `CoordinationEvent`
+//! describes the whole attempt either way.
+
+use crate::context::{ContextId, SessionManagement, WorkspaceManagement};
+use crate::errors::{context_keys, SisterResult};
+use crate::types::SisterType;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// What the coordinator did to one sister.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoordinatedAction {
+    /// Started a new session
+    SessionStarted,
+
+    /// Switched to an existing workspace
+    WorkspaceSwitched,
+
+    /// Created a workspace and switched to it
+    WorkspaceCreated,
+}
+
+/// One sister's context after a coordinated operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoordinatedContext {
+    /// Which sister
+    pub sister_type: SisterType,
+
+    /// Its now-current context
+    pub context_id: ContextId,
+
+    /// What was done
+    pub action: CoordinatedAction,
+}
+
+/// Result of a successful `begin`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoordinatedContexts {
+    /// ID shared by every context in this operation
+    pub correlation_id: String,
+
+    /// Task/session name
+    pub name: String,
+
+    /// Per-sister contexts, in registration order
+    pub contexts: Vec<CoordinatedContext>,
+}
+
+impl CoordinatedContexts {
+    /// Context ID for one sister.
+    pub fn context_for(&self, sister_type: SisterType) -> Option<ContextId> {
+        self.contexts
+            .iter()
+            .find(|c| c.sister_type == sister_type)
+            .map(|c| c.context_id)
+    }
+}
+
+/// How a coordinated operation ended.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum CoordinationOutcome {
+    /// Every sister switched
+    Committed,
+
+    /// A sister failed; earlier steps were compensated
+    RolledBack {
+        /// Sister whose step failed
+        failed: SisterType,
+        /// Its error code
+        error_code: String,
+        /// Its error message
+        error_message: String,
+        /// Sisters whose compensation also failed (left inconsistent)
+        compensation_failures: Vec<SisterType>,
+    },
+}
+
+/// The single correlated event emitted per coordinated operation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoordinationEvent {
+    /// Correlation ID (same as `CoordinatedContexts::correlation_id`)
+    pub correlation_id: String,
+
+    /// Task/session name
+    pub name: String,
+
+    /// Contexts that were reached (all of them on commit; the compensated ones on rollback)
+    pub contexts: Vec<CoordinatedContext>,
+
+    /// How it ended
+    #[serde(flatten)]
+    pub outcome: CoordinationOutcome,
+
+    /// When it ended
+    pub timestamp: DateTime<Utc>,
+}
+
+impl CoordinationEvent {
+    /// Whether the operation committed.
+    pub fn is_committed(&self) -> bool {
+        self.outcome == CoordinationOutcome::Committed
+    }
+}
+
+enum Participant<'a> {
+    Session {
+        sister_type: SisterType,
+        manager: &'a mut dyn SessionManagement,
+    },
+    Workspace {
+        sister_type: SisterType,
+        manager: &'a mut dyn WorkspaceManagement,
+        /// None = create a workspace named after the task
+        target: Option<ContextId>,
+    },
+}
+
+impl Participant<'_> {
+    fn sister_type(&self) -> SisterType {
+        match self {
+            Self::Session { sister_type, .. } | Self::Workspace { sister_type, .. } => *sister_type,
+        }
+    }
+}
+
+/// A completed step and what is needed to undo it.
+struct Applied {
+    index: usize,
+    context: CoordinatedContext,
+    previous_workspace: Option<ContextId>,
+}
+
+type EventSink<'a> = Box<dyn Fn(&CoordinationEvent) + 'a>;
+
+/// Begins a task across several sisters, all-or-nothing.
+#[derive(Default)]
+pub struct ContextCoordinator<'a> {
+    participants: Vec<Participant<'a>>,
+    sink: Option<EventSink<'a>>,
+}
+
+impl<'a> ContextCoordinator<'a> {
+    /// Create a coordinator with no participants.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new session on this sister.
+    pub fn session(
+        mut self,
+        sister_type: SisterType,
+        manager: &'a mut dyn SessionManagement,
+    ) -> Self {
+        self.participants.push(Participant::Session {
+            sister_type,
+            manager,
+        });
+        self
+    }
+
+    /// Switch this sister to an existing workspace.
+    pub fn workspace(
+        mut self,
+        sister_type: SisterType,
+        manager: &'a mut dyn WorkspaceManagement,
+        target: ContextId,
+    ) -> Self {
+        self.participants.push(Participant::Workspace {
+            sister_type,
+            manager,
+            target: Some(target),
+        });
+        self
+    }
+
+    /// Create a workspace named after the task on this sister and switch to it.
+    pub fn new_workspace(
+        mut self,
+        sister_type: SisterType,
+        manager: &'a mut dyn WorkspaceManagement,
+    ) -> Self {
+        self.participants.push(Participant::Workspace {
+            sister_type,
+            manager,
+            target: None,
+        });
+        self
+    }
+
+    /// Receive the `CoordinationEvent` for each operation.
+    pub fn on_event(mut self, sink: impl Fn(&CoordinationEvent) + 'a) -> Self {
+        self.sink = Some(Box::new(sink));
+        self
+    }
+
+    /// Number of participating sisters.
+    pub fn len(&self) -> usize {
+        self.participants.len()
+    }
+
+    /// Whether no sisters are registered.
+    pub fn is_empty(&self) -> bool {
+        self.participants.is_empty()
+    }
+
+    /// Begin `name` on every participant, or on none of them.
+    ///
+    /// On failure the original error is returned, annotated with the
+    /// correlation ID and any sisters whose compensation also failed.
+    pub fn begin(&mut self, name: &str) -> SisterResult<CoordinatedContexts> {
+        let correlation_id = format!("coord_{}", uuid::Uuid::new_v4().simple());
+        let mut applied: Vec<Applied> = Vec::new();
+
+        for index in 0..self.participants.len() {
+            match Self::apply(&mut self.participants[index], index, name) {
+                Ok(step) => applied.push(step),
+                Err(error) => {
+                    let failed = self.participants[index].sister_type();
+                    let compensation_failures = self.compensate(&applied);
+                    self.emit(CoordinationEvent {
+                        correlation_id: correlation_id.clone(),
+                        name: name.to_string(),
+                        contexts: applied.iter().map(|a| a.context.clone()).collect(),
+                        outcome: CoordinationOutcome::RolledBack {
+                            failed,
+                            error_code: error.code_str().to_string(),
+                            error_message: error.message.clone(),
+                            compensation_failures: compensation_failures.clone(),
+                        },
+                        timestamp: Utc::now(),
+                    });
+
+                    let mut error = error
+                        .with_context(context_keys::CORRELATION_ID, &correlation_id)
+                        .with_context("failed_sister", failed)
+                        .with_context("rolled_back", applied.len());
+                    if !compensation_failures.is_empty() {
+                        error = error.with_context("compensation_failures", compensation_failures);
+                    }
+                    return Err(error);
+                }
+            }
+        }
+
+        let contexts: Vec<_> = applied.into_iter().map(|a| a.context).collect();
+        self.emit(CoordinationEvent {
+            correlation_id: correlation_id.clone(),
+            name: name.to_string(),
+            contexts: contexts.clone(),
+            outcome: CoordinationOutcome::Committed,
+            timestamp: Utc::now(),
+        });
+        Ok(CoordinatedContexts {
+            correlation_id,
+            name: name.to_string(),
+            contexts,
+        })
+    }
+
+    fn apply(participant: &mut Participant<'_>, index: usize, name: &str) -> SisterResult<Applied> {
+        match participant {
+            Participant::Session {
+                sister_type,
+                manager,
+            } => {
+                let context_id = manager.start_session(name)?;
+                Ok(Applied {
+                    index,
+                    context: CoordinatedContext {
+                        sister_type: *sister_type,
+                        context_id,
+                        action: CoordinatedAction::SessionStarted,
+                    },
+                    previous_workspace: None,
+                })
+            }
+            Participant::Workspace {
+                sister_type,
+                manager,
+                target,
+            } => {
+                let previous = manager.current_workspace();
+                let (context_id, action) = match target {
+                    Some(id) => (*id, CoordinatedAction::WorkspaceSwitched),
+                    None => (
+                        manager.create_workspace(name)?,
+                        CoordinatedAction::WorkspaceCreated,
+                    ),
+                };
+                if let Err(e) = manager.switch_workspace(context_id) {
+                    if action == CoordinatedAction::WorkspaceCreated {
+                        let _ = manager.delete_workspace(context_id);
+                    }
+                    return Err(e);
+                }
+                Ok(Applied {
+                    index,
+                    context: CoordinatedContext {
+                        sister_type: *sister_type,
+                        context_id,
+                        action,
+                    },
+                    previous_workspace: Some(previous),
+                })
+            }
+        }
+    }
+
+    /// Undo completed steps in reverse; returns sisters that could not be restored.
+    fn compensate(&mut self, applied: &[Applied]) -> Vec<SisterType> {
+        let mut failures = Vec::new();
+        for step in applied.iter().rev() {
+            let result: SisterResult<()> = match &mut self.participants[step.index] {
+                Participant::Session { manager, .. } => manager.end_session(),
+                Participant::Workspace { manager, .. } => {
+                    let previous = step
+                        .previous_workspace
+                        .unwrap_or_else(ContextId::default_context);
+                    manager.switch_workspace(previous).and_then(|_| {
+                        if step.context.action == CoordinatedAction::WorkspaceCreated {
+                            manager.delete_workspace(step.context.context_id)
+                        } else {
+                            Ok(())
+                        }
+                    })
+                }
+            };
+            if result.is_err() {
+                failures.push(step.context.sister_type);
+            }
+        }
+        failures
+    }
+
+    fn emit(&self, event: CoordinationEvent) {
+        if let Some(sink) = &self.sink {
+            sink(&event);
+        }
+    }
+}
+
+impl std::fmt::Debug for ContextCoordinator<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContextCoordinator")
+            .field(
+                "participants",
+                &self
+                    .participants
+                    .iter()
+                    .map(Participant::sister_type)
+                    .collect::<Vec<_>>(),
+            )
+            .field("has_event_sink", &self.sink.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordination_event_serialization() {
+        let event = CoordinationEvent {
+            correlation_id: "coord_1".into(),
+            name: "task".into(),
+            contexts: vec![CoordinatedContext {
+                sister_type: SisterType::Memory,
+                context_id: ContextId::new(),
+                action: CoordinatedAction::SessionStarted,
+            }],
+            outcome: CoordinationOutcome::RolledBack {
+                failed: SisterType::Codebase,
+                error_code: "NOT_FOUND".into(),
+                error_message: "workspace not found".into(),
+                compensation_failures: vec![],
+            },
+            timestamp: Utc::now(),
+        };
+        assert!(!event.is_committed());
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["outcome"], "rolled_back");
+        assert_eq!(json["failed"], "codebase");
+        assert_eq!(json["contexts"][0]["action"], "session_started");
+    }
+
+    #[test]
+    fn test_empty_coordinator_commits() {
+        let mut coordinator = ContextCoordinator::new();
+        assert!(coordinator.is_empty());
+        let result = coordinator.begin("noop").unwrap();
+        assert!(result.contexts.is_empty());
+        assert!(result.correlation_id.starts_with("coord_"));
+    }
+}
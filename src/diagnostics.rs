@@ -0,0 +1,256 @@
+//! Self-test diagnostics.
+//!
+//! `Sister::self_test` replaces the ad-hoc checks support asks users to
+//! run: it returns one `DiagnosticReport` that can be attached to a bug
+//! report as JSON.
+//!
+//! ```rust,ignore
+//! fn self_test(&self, level: DiagnosticLevel) -> SisterResult<DiagnosticReport> {
+//!     let mut report = DiagnosticReport::new(Self::SISTER_TYPE, level);
+//!     report.check_storage(&self.config);
+//!     if level >= DiagnosticLevel::Standard {
+//!         report.check(CHECK_INDEX_INTEGRITY, || self.index.verify());
+//!     }
+//!     if level >= DiagnosticLevel::Deep {
+//!         for block in self.blocks.sample(32) {
+//!             report.check_checksum(&block.data, &block.checksum);
+//!         }
+//!     }
+//!     Ok(report.finish())
+//! }
+//! ```
+
+use crate::errors::{Severity, SisterResult};
+use crate::sister::SisterConfig;
+use crate::types::SisterType;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Instant;
+
+/// Data paths exist and have the expected permissions.
+pub const CHECK_STORAGE: &str = "storage";
+
+/// Indices agree with the data they index.
+pub const CHECK_INDEX_INTEGRITY: &str = "index_integrity";
+
+/// Sampled blocks match their stored checksums.
+pub const CHECK_CHECKSUM_SAMPLE: &str = "checksum_sample";
+
+/// Reported health matches reality.
+pub const CHECK_HEALTH: &str = "health";
+
+/// How thorough a self-test should be.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticLevel {
+    /// Metadata only; safe to run on a busy sister
+    #[default]
+    Quick,
+
+    /// Also verify indices
+    Standard,
+
+    /// Also read and checksum sampled data
+    Deep,
+}
+
+/// One problem (or notable fact) found by a check.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct DiagnosticFinding {
+    /// Which check produced this (`CHECK_STORAGE`, ...)
+    pub check: String,
+    pub severity: Severity,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+}
+
+impl DiagnosticFinding {
+    pub fn new(check: impl Into<String>, severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            check: check.into(),
+            severity,
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    pub fn with_details(mut self, details: serde_json::Value) -> Self {
+        self.details = Some(details);
+        self
+    }
+}
+
+/// Result of `Sister::self_test`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct DiagnosticReport {
+    pub sister_type: SisterType,
+    pub level: DiagnosticLevel,
+    pub started_at: DateTime<Utc>,
+    pub duration_ms: u64,
+
+    /// Checks that ran, whether or not they found anything
+    pub checks: Vec<String>,
+
+    pub findings: Vec<DiagnosticFinding>,
+
+    #[serde(skip)]
+    started: Option<Instant>,
+}
+
+impl DiagnosticReport {
+    pub fn new(sister_type: SisterType, level: DiagnosticLevel) -> Self {
+        Self {
+            sister_type,
+            level,
+            started_at: Utc::now(),
+            duration_ms: 0,
+            checks: Vec::new(),
+            findings: Vec::new(),
+            started: Some(Instant::now()),
+        }
+    }
+
+    /// Record a finding (and that its check ran).
+    pub fn finding(&mut self, finding: DiagnosticFinding) {
+        self.ran(&finding.check);
+        self.findings.push(finding);
+    }
+
+    /// Run a check; an error becomes an `Error` finding.
+    pub fn check(&mut self, name: &str, check: impl FnOnce() -> SisterResult<()>) {
+        self.ran(name);
+        if let Err(e) = check() {
+            let details = serde_json::to_value(&e).ok();
+            self.findings.push(DiagnosticFinding {
+                details,
+                ..DiagnosticFinding::new(name, Severity::Error, e.message)
+            });
+        }
+    }
+
+    /// Check that the config's data paths are accessible.
+    pub fn check_storage(&mut self, config: &SisterConfig) {
+        self.ran(CHECK_STORAGE);
+        let named = config.data_paths.iter().map(|(n, p)| (n.as_str(), p));
+        for (name, path) in config
+            .data_path
+            .iter()
+            .map(|p| ("data_path", p))
+            .chain(named)
+        {
+            if let Some(finding) = storage_finding(name, path, config) {
+                self.findings.push(finding);
+            }
+        }
+    }
+
+    /// Compare `data` against a stored blake3 checksum.
+    #[cfg(feature = "hashing")]
+    pub fn check_checksum(&mut self, data: &[u8], expected: &[u8; 32]) {
+        self.ran(CHECK_CHECKSUM_SAMPLE);
+        let actual = blake3::hash(data);
+        if actual.as_bytes() != expected {
+            self.findings.push(
+                DiagnosticFinding::new(CHECK_CHECKSUM_SAMPLE, Severity::Error, "checksum mismatch")
+                    .with_details(serde_json::json!({
+                        "expected": hex::encode(expected),
+                        "actual": actual.to_hex().to_string(),
+                        "len": data.len(),
+                    })),
+            );
+        }
+    }
+
+    /// Stop the clock.
+    pub fn finish(mut self) -> Self {
+        if let Some(started) = self.started.take() {
+            self.duration_ms = started.elapsed().as_millis() as u64;
+        }
+        self
+    }
+
+    /// Most severe finding, if any.
+    pub fn worst(&self) -> Option<Severity> {
+        self.findings.iter().map(|f| f.severity).max()
+    }
+
+    /// No findings at `Error` or above.
+    pub fn passed(&self) -> bool {
+        self.worst().is_none_or(|s| s < Severity::Error)
+    }
+
+    /// Findings from one check.
+    pub fn findings_for<'a>(
+        &'a self,
+        check: &'a str,
+    ) -> impl Iterator<Item = &'a DiagnosticFinding> {
+        self.findings.iter().filter(move |f| f.check == check)
+    }
+
+    fn ran(&mut self, check: &str) {
+        if !self.checks.iter().any(|c| c == check) {
+            self.checks.push(check.to_string());
+        }
+    }
+}
+
+fn storage_finding(name: &str, path: &Path, config: &SisterConfig) -> Option<DiagnosticFinding> {
+    let details = serde_json::json!({ "name": name, "path": path.display().to_string() });
+    let finding = |severity, message: String| {
+        Some(DiagnosticFinding::new(CHECK_STORAGE, severity, message).with_details(details.clone()))
+    };
+    match std::fs::metadata(path) {
+        Ok(meta) if !config.read_only && meta.permissions().readonly() => {
+            finding(Severity::Error, format!("{name} is not writable"))
+        }
+        Ok(_) => None,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound && config.create_if_missing => {
+            finding(Severity::Info, format!("{name} does not exist yet"))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            finding(Severity::Error, format!("{name} does not exist"))
+        }
+        Err(e) => finding(Severity::Error, format!("{name} is not accessible: {e}")),
+    }
+}
+
+#[cfg(all(test, feature = "hashing"))]
+mod tests {
+    use super::*;
+    use crate::errors::SisterError;
+
+    #[test]
+    fn test_diagnostic_report() {
+        let dir = crate::test_support::TempDir::new("diag");
+        let config = SisterConfig::new(dir.join("missing.amem")).add_path("index", dir.path());
+
+        let mut report = DiagnosticReport::new(SisterType::Memory, DiagnosticLevel::Deep);
+        report.check_storage(&config);
+        report.check(CHECK_INDEX_INTEGRITY, || Ok(()));
+        report.check_checksum(b"block", blake3::hash(b"block").as_bytes());
+        assert!(report.passed(), "{:?}", report.findings);
+        assert_eq!(report.worst(), Some(Severity::Info));
+
+        report.check(CHECK_INDEX_INTEGRITY, || {
+            Err(SisterError::storage("dangling edge 42"))
+        });
+        report.check_checksum(b"block", &[0; 32]);
+        let report = report.finish();
+        assert!(!report.passed());
+        assert_eq!(report.checks.len(), 3);
+        assert_eq!(report.findings_for(CHECK_CHECKSUM_SAMPLE).count(), 1);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["level"], "deep");
+    }
+}
@@ -13,6 +13,8 @@
 //! If the tool was found and invoked, errors go through `isError: true`.
 //! JSON-RPC errors are only for protocol/routing failures.
 
+use crate::context::ContextId;
+use crate::types::UniqueId;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
@@ -75,6 +77,11 @@ impl std::fmt::Display for ProtocolErrorCode {
 /// - Unknown tools (code -32803, NOT -32602)
 /// - Invalid parameters (before the tool is invoked)
 #[derive(Debug, Clone, Error)]
+#[cfg_attr(
+    feature = "typescript",
+    derive(ts_rs::TS),
+    ts(type = "{ code: number, message: string, data?: JsonValue }")
+)]
 #[error("[{code}] {message}")]
 pub struct ProtocolError {
     /// JSON-RPC error code
@@ -138,6 +145,84 @@ impl ProtocolError {
     pub fn json_rpc_code(&self) -> i32 {
         self.code.code()
     }
+
+    /// The JSON-RPC error object: `{"code", "message", "data"?}`.
+    pub fn to_error_object(&self) -> serde_json::Value {
+        let mut object = serde_json::json!({
+            "code": self.json_rpc_code(),
+            "message": self.message,
+        });
+        if let Some(data) = &self.data {
+            object["data"] = data.clone();
+        }
+        object
+    }
+
+    /// A complete JSON-RPC error response for request `id`
+    /// (`null` when the request ID could not be read).
+    pub fn to_response(&self, id: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": self.to_error_object(),
+        })
+    }
+}
+
+impl Serialize for ProtocolError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_error_object().serialize(serializer)
+    }
+}
+
+/// Matches `to_error_object`: the code is the JSON-RPC integer.
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for ProtocolError {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "ProtocolError".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "JSON-RPC error object",
+            "type": "object",
+            "properties": {
+                "code": { "type": "integer", "format": "int32" },
+                "message": { "type": "string" },
+                "data": true
+            },
+            "required": ["code", "message"]
+        })
+    }
+}
+
+/// Unparseable request bodies are JSON-RPC parse errors (-32700).
+impl From<serde_json::Error> for ProtocolError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::parse_error(format!("Parse error: {}", e))
+    }
+}
+
+/// Conversion for domain errors that surface before a tool runs (e.g.,
+/// while validating arguments).
+///
+/// Policy: `InvalidInput` becomes `InvalidParams` (-32602); every other
+/// code becomes `InternalError` (-32603). The full `SisterError` is kept
+/// in `data`. Errors from a tool that was invoked should NOT use this;
+/// they belong in an `isError: true` result.
+impl From<SisterError> for ProtocolError {
+    fn from(e: SisterError) -> Self {
+        let code = match e.code {
+            ErrorCode::InvalidInput => ProtocolErrorCode::InvalidParams,
+            _ => ProtocolErrorCode::InternalError,
+        };
+        let data = serde_json::to_value(&e).ok();
+        Self {
+            code,
+            message: e.message,
+            data,
+        }
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════
@@ -149,8 +234,10 @@ impl ProtocolError {
 /// These errors occur AFTER a tool is found and invoked.
 /// In MCP, they become `{isError: true}` in the tool result,
 /// NOT JSON-RPC error responses.
-#[derive(Debug, Clone, Error, Serialize, Deserialize)]
-#[error("[{code}] {message}")]
+#[derive(Debug, Clone, Error)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[error("[{}] {message}", self.code_str())]
 pub struct SisterError {
     /// Error code (machine-readable)
     pub code: ErrorCode,
@@ -162,15 +249,18 @@ pub struct SisterError {
     pub message: String,
 
     /// Additional context (for debugging)
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub context: Option<HashMap<String, serde_json::Value>>,
 
     /// Is this recoverable?
     pub recoverable: bool,
 
     /// Suggested action for recovery
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub suggested_action: Option<SuggestedAction>,
+
+    /// Code string when `code` is `Unregistered`; serialized as `code`
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    #[cfg_attr(feature = "typescript", ts(skip))]
+    pub extended_code: Option<ExtendedCode>,
 }
 
 impl SisterError {
@@ -186,6 +276,28 @@ impl SisterError {
             context: None,
             recoverable,
             suggested_action: None,
+            extended_code: None,
+        }
+    }
+
+    /// Create an error from a code string, e.g. one received from a peer.
+    ///
+    /// Codes this build has not registered become `Unregistered`, with the
+    /// string kept in `extended_code` so the error re-serializes unchanged.
+    pub fn extended(code: &str, message: impl Into<String>) -> Self {
+        let parsed = ErrorCode::parse(code);
+        let mut error = Self::new(parsed, message);
+        if parsed == ErrorCode::Unregistered && code != parsed.as_str() {
+            error.extended_code = Some(ExtendedCode::new(code));
+        }
+        error
+    }
+
+    /// The wire code: `extended_code` if set, else `code.as_str()`.
+    pub fn code_str(&self) -> &str {
+        match &self.extended_code {
+            Some(code) => code.as_str(),
+            None => self.code.as_str(),
         }
     }
 
@@ -198,6 +310,53 @@ impl SisterError {
         self
     }
 
+    /// Tag the error with the resource it concerns (`context_keys::RESOURCE_ID`)
+    pub fn for_resource(self, resource_id: impl Into<String>) -> Self {
+        self.with_context(context_keys::RESOURCE_ID, resource_id.into())
+    }
+
+    /// Tag the error with the session/workspace it occurred in (`context_keys::CONTEXT_ID`)
+    pub fn in_context(self, context_id: ContextId) -> Self {
+        self.with_context(context_keys::CONTEXT_ID, context_id.to_string())
+    }
+
+    /// Tag the error with the operation that failed (`context_keys::OPERATION_ID`)
+    pub fn for_operation(self, operation_id: impl Into<String>) -> Self {
+        self.with_context(context_keys::OPERATION_ID, operation_id.into())
+    }
+
+    /// Raw context value for `key`.
+    pub fn context_value(&self, key: &str) -> Option<&serde_json::Value> {
+        self.context.as_ref()?.get(key)
+    }
+
+    /// Context value for `key`, if it is a string.
+    pub fn context_str(&self, key: &str) -> Option<&str> {
+        self.context_value(key)?.as_str()
+    }
+
+    /// Context value for `key`, if it is an unsigned integer.
+    pub fn context_u64(&self, key: &str) -> Option<u64> {
+        self.context_value(key)?.as_u64()
+    }
+
+    /// The `RESOURCE_ID` entry.
+    pub fn resource_id(&self) -> Option<&str> {
+        self.context_str(context_keys::RESOURCE_ID)
+    }
+
+    /// The `CONTEXT_ID` entry, parsed (accepts "ctx_<uuid>" or a bare UUID).
+    pub fn context_id(&self) -> Option<ContextId> {
+        let raw = self.context_str(context_keys::CONTEXT_ID)?;
+        let uuid = uuid::Uuid::parse_str(raw.strip_prefix("ctx_").unwrap_or(raw)).ok()?;
+        Some(ContextId(UniqueId::from_uuid(uuid)))
+    }
+
+    /// The `OPERATION_ID` entry.
+    pub fn operation_id(&self) -> Option<&str> {
+        self.context_str(context_keys::OPERATION_ID)
+    }
+
     /// Set recoverable flag
     pub fn recoverable(mut self, recoverable: bool) -> Self {
         self.recoverable = recoverable;
@@ -247,6 +406,36 @@ impl SisterError {
         msg
     }
 
+    /// Structured form of the error for MCP clients.
+    ///
+    /// `{code, message, severity, recoverable, suggested_action?, context?}`,
+    /// the machine-readable companion to `to_mcp_message()`.
+    pub fn to_mcp_content(&self) -> serde_json::Value {
+        let mut content = serde_json::json!({
+            "code": self.code_str(),
+            "message": self.message,
+            "severity": self.severity,
+            "recoverable": self.recoverable,
+        });
+        if let Some(action) = &self.suggested_action {
+            content["suggested_action"] = serde_json::to_value(action).unwrap_or_default();
+        }
+        if let Some(context) = &self.context {
+            content["context"] = serde_json::to_value(context).unwrap_or_default();
+        }
+        content
+    }
+
+    /// Complete MCP tool result: the text form in `content`, the
+    /// structured form in `structuredContent`, and `isError: true`.
+    pub fn to_mcp_result(&self) -> serde_json::Value {
+        serde_json::json!({
+            "content": [{ "type": "text", "text": self.to_mcp_message() }],
+            "structuredContent": self.to_mcp_content(),
+            "isError": true,
+        })
+    }
+
     // ═══════════════════════════════════════════════════════════
     // Common error constructors
     // ═══════════════════════════════════════════════════════════
@@ -271,6 +460,20 @@ impl SisterError {
         Self::new(ErrorCode::PermissionDenied, message).recoverable(false)
     }
 
+    /// Functionality compiled out by a disabled cargo feature
+    #[allow(dead_code)]
+    pub(crate) fn feature_disabled(feature: &str) -> Self {
+        Self::new(
+            ErrorCode::NotImplemented,
+            format!("Requires the `{}` feature of agentic-sdk", feature),
+        )
+        .recoverable(false)
+        .with_context("feature", feature)
+        .with_suggestion(SuggestedAction::UserAction {
+            description: format!("Enable the `{}` cargo feature", feature),
+        })
+    }
+
     /// Internal error (bug)
     pub fn internal(message: impl Into<String>) -> Self {
         Self::new(ErrorCode::Internal, message)
@@ -285,6 +488,29 @@ impl SisterError {
             .with_suggestion(SuggestedAction::Retry { after_ms: 1000 })
     }
 
+    /// Operation timed out after `elapsed`
+    pub fn timeout(operation: impl Into<String>, elapsed: std::time::Duration) -> Self {
+        let operation = operation.into();
+        Self::new(
+            ErrorCode::Timeout,
+            format!("{} timed out after {}ms", operation, elapsed.as_millis()),
+        )
+        .with_context("operation", operation)
+        .with_context("elapsed_ms", elapsed.as_millis() as u64)
+        .with_suggestion(SuggestedAction::Retry { after_ms: 1000 })
+    }
+
+    /// Rate limit hit; the caller may retry after `retry_after`
+    pub fn rate_limited(retry_after: std::time::Duration) -> Self {
+        let after_ms = retry_after.as_millis() as u64;
+        Self::new(
+            ErrorCode::ResourceExhausted,
+            format!("Rate limited; retry after {}ms", after_ms),
+        )
+        .with_context("retry_after_ms", after_ms)
+        .with_suggestion(SuggestedAction::Retry { after_ms })
+    }
+
     /// Context/session not found error
     pub fn context_not_found(context_id: impl Into<String>) -> Self {
         Self::new(
@@ -306,15 +532,116 @@ impl SisterError {
     }
 }
 
+/// Standard keys for `SisterError::context`.
+///
+/// Use these instead of ad-hoc names so readers can find common facts
+/// without guessing; sister-specific keys are still fine alongside them.
+pub mod context_keys {
+    /// ID of the resource the error concerns (node, file, receipt, ...)
+    pub const RESOURCE_ID: &str = "resource_id";
+
+    /// Session/workspace the error occurred in ("ctx_<uuid>")
+    pub const CONTEXT_ID: &str = "context_id";
+
+    /// Operation that failed (matches `OperationStarted::operation_id`)
+    pub const OPERATION_ID: &str = "operation_id";
+
+    /// ID correlating several operations (e.g., a coordinated begin)
+    pub const CORRELATION_ID: &str = "correlation_id";
+
+    /// Number of attempts made before giving up (set by `retry`)
+    pub const ATTEMPTS: &str = "attempts";
+}
+
 impl Default for SisterError {
     fn default() -> Self {
         Self::new(ErrorCode::Internal, "Unknown error")
     }
 }
 
+impl Serialize for SisterError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("SisterError", 6)?;
+        state.serialize_field("code", self.code_str())?;
+        state.serialize_field("severity", &self.severity)?;
+        state.serialize_field("message", &self.message)?;
+        match &self.context {
+            Some(context) => state.serialize_field("context", context)?,
+            None => state.skip_field("context")?,
+        }
+        state.serialize_field("recoverable", &self.recoverable)?;
+        match &self.suggested_action {
+            Some(action) => state.serialize_field("suggested_action", action)?,
+            None => state.skip_field("suggested_action")?,
+        }
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for SisterError {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "SisterError")]
+        struct Wire {
+            code: String,
+            severity: Severity,
+            message: String,
+            context: Option<HashMap<String, serde_json::Value>>,
+            recoverable: bool,
+            suggested_action: Option<SuggestedAction>,
+        }
+
+        let wire = Wire::deserialize(deserializer)?;
+        let mut error = Self::extended(&wire.code, wire.message);
+        error.severity = wire.severity;
+        error.context = wire.context;
+        error.recoverable = wire.recoverable;
+        error.suggested_action = wire.suggested_action;
+        Ok(error)
+    }
+}
+
+/// An extension code string this build has not registered.
+///
+/// A thin pointer (not `String` or `Box<str>`) so `SisterError` stays
+/// under clippy's `result_large_err` limit.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[allow(clippy::box_collection)]
+pub struct ExtendedCode(Box<String>);
+
+impl ExtendedCode {
+    /// Wrap a code string.
+    pub fn new(code: impl Into<String>) -> Self {
+        Self(Box::new(code.into()))
+    }
+
+    /// The code string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for ExtendedCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ExtendedCode({:?})", self.as_str())
+    }
+}
+
+impl std::fmt::Display for ExtendedCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Standard error codes across ALL sisters.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+///
+/// Serialized as SCREAMING_SNAKE_CASE strings. Sisters outside the core
+/// set add their own codes with `register_error_code` instead of forking
+/// the enum; those serialize as their code string too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS), ts(type = "string"))]
 pub enum ErrorCode {
     // ═══════════════════════════════════════════════════════
     // COMMON ERRORS (All sisters use these)
@@ -387,12 +714,205 @@ pub enum ErrorCode {
 
     /// Contract-specific error
     ContractError,
+
+    // ═══════════════════════════════════════════════════════
+    // EXTENSION CODES
+    // ═══════════════════════════════════════════════════════
+    /// Registered code defined outside the SDK (e.g., "PLANNING_GOAL_CYCLE").
+    /// Create with `register_error_code`
+    Custom(CustomCode),
+
+    /// Extension code this build has not registered. `SisterError` keeps
+    /// the code string in `extended_code`
+    Unregistered,
 }
 
 impl ErrorCode {
+    /// Every built-in code (excludes `Custom` and `Unregistered`).
+    pub const BUILTIN: [ErrorCode; 22] = [
+        Self::NotFound,
+        Self::InvalidInput,
+        Self::PermissionDenied,
+        Self::StorageError,
+        Self::NetworkError,
+        Self::Timeout,
+        Self::ResourceExhausted,
+        Self::Internal,
+        Self::NotImplemented,
+        Self::ContextNotFound,
+        Self::EvidenceNotFound,
+        Self::GroundingFailed,
+        Self::VersionMismatch,
+        Self::ChecksumMismatch,
+        Self::AlreadyExists,
+        Self::InvalidState,
+        Self::MemoryError,
+        Self::VisionError,
+        Self::CodebaseError,
+        Self::IdentityError,
+        Self::TimeError,
+        Self::ContractError,
+    ];
+
+    /// Stable string form (e.g., "NOT_FOUND").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NotFound => "NOT_FOUND",
+            Self::InvalidInput => "INVALID_INPUT",
+            Self::PermissionDenied => "PERMISSION_DENIED",
+            Self::StorageError => "STORAGE_ERROR",
+            Self::NetworkError => "NETWORK_ERROR",
+            Self::Timeout => "TIMEOUT",
+            Self::ResourceExhausted => "RESOURCE_EXHAUSTED",
+            Self::Internal => "INTERNAL",
+            Self::NotImplemented => "NOT_IMPLEMENTED",
+            Self::ContextNotFound => "CONTEXT_NOT_FOUND",
+            Self::EvidenceNotFound => "EVIDENCE_NOT_FOUND",
+            Self::GroundingFailed => "GROUNDING_FAILED",
+            Self::VersionMismatch => "VERSION_MISMATCH",
+            Self::ChecksumMismatch => "CHECKSUM_MISMATCH",
+            Self::AlreadyExists => "ALREADY_EXISTS",
+            Self::InvalidState => "INVALID_STATE",
+            Self::MemoryError => "MEMORY_ERROR",
+            Self::VisionError => "VISION_ERROR",
+            Self::CodebaseError => "CODEBASE_ERROR",
+            Self::IdentityError => "IDENTITY_ERROR",
+            Self::TimeError => "TIME_ERROR",
+            Self::ContractError => "CONTRACT_ERROR",
+            Self::Custom(code) => code.as_str(),
+            Self::Unregistered => "UNREGISTERED",
+        }
+    }
+
+    /// Parse a code string. Strings that are neither built-in nor
+    /// registered become `Unregistered`; use `SisterError::extended` to
+    /// keep the string.
+    pub fn parse(code: &str) -> Self {
+        Self::BUILTIN
+            .into_iter()
+            .find(|c| c.as_str() == code)
+            .or_else(|| CustomCode::lookup(code).map(Self::Custom))
+            .unwrap_or(Self::Unregistered)
+    }
+
+    /// A registered extension code. Built-in names return the built-in
+    /// code; anything else is `Unregistered`.
+    pub fn custom(code: &str) -> Self {
+        Self::parse(code)
+    }
+
+    /// One-line description of the code.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::NotFound => "Resource not found",
+            Self::InvalidInput => "Invalid input provided",
+            Self::PermissionDenied => "Operation not permitted",
+            Self::StorageError => "Storage error (read/write failed)",
+            Self::NetworkError => "Network error",
+            Self::Timeout => "Operation timed out",
+            Self::ResourceExhausted => "Resource limits exceeded",
+            Self::Internal => "Internal error (bug)",
+            Self::NotImplemented => "Not implemented yet",
+            Self::ContextNotFound => "Context/session not found",
+            Self::EvidenceNotFound => "Evidence not found",
+            Self::GroundingFailed => "Grounding failed",
+            Self::VersionMismatch => "Version mismatch",
+            Self::ChecksumMismatch => "Checksum mismatch (corruption)",
+            Self::AlreadyExists => "Already exists",
+            Self::InvalidState => "Invalid state for operation",
+            Self::MemoryError => "Memory-specific error",
+            Self::VisionError => "Vision-specific error",
+            Self::CodebaseError => "Codebase-specific error",
+            Self::IdentityError => "Identity-specific error",
+            Self::TimeError => "Time-specific error",
+            Self::ContractError => "Contract-specific error",
+            Self::Custom(code) => code.defaults().description,
+            Self::Unregistered => "Extension code not registered in this build",
+        }
+    }
+
+    /// HTTP status for gateways exposing sisters over HTTP.
+    ///
+    /// Sister-specific and custom codes map to 500.
+    pub fn http_status(&self) -> u16 {
+        match self {
+            Self::InvalidInput => 400,
+            Self::PermissionDenied => 403,
+            Self::NotFound | Self::ContextNotFound | Self::EvidenceNotFound => 404,
+            Self::AlreadyExists | Self::InvalidState | Self::VersionMismatch => 409,
+            Self::GroundingFailed => 422,
+            Self::ResourceExhausted => 429,
+            Self::NotImplemented => 501,
+            Self::NetworkError => 502,
+            Self::Timeout => 504,
+            _ => 500,
+        }
+    }
+
+    /// Process exit code for CLIs (BSD `sysexits.h` values).
+    ///
+    /// Sister-specific and custom codes map to 1.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::InvalidInput
+            | Self::GroundingFailed
+            | Self::VersionMismatch
+            | Self::ChecksumMismatch => 65, // EX_DATAERR
+            Self::NotFound | Self::ContextNotFound | Self::EvidenceNotFound => 66, // EX_NOINPUT
+            Self::NetworkError | Self::NotImplemented => 69,                       // EX_UNAVAILABLE
+            Self::Internal | Self::InvalidState => 70,                             // EX_SOFTWARE
+            Self::AlreadyExists => 73,                                             // EX_CANTCREAT
+            Self::StorageError => 74,                                              // EX_IOERR
+            Self::Timeout | Self::ResourceExhausted => 75,                         // EX_TEMPFAIL
+            Self::PermissionDenied => 77,                                          // EX_NOPERM
+            _ => 1,
+        }
+    }
+
+    /// Best-matching code for an HTTP error status (`None` below 400).
+    ///
+    /// Statuses shared by several codes return the most general one
+    /// (404 is `NotFound`, 409 is `AlreadyExists`).
+    pub fn from_http_status(status: u16) -> Option<Self> {
+        Some(match status {
+            0..=399 => return None,
+            401 | 403 => Self::PermissionDenied,
+            404 | 410 => Self::NotFound,
+            408 | 504 => Self::Timeout,
+            409 => Self::AlreadyExists,
+            422 => Self::GroundingFailed,
+            429 => Self::ResourceExhausted,
+            501 => Self::NotImplemented,
+            502 | 503 => Self::NetworkError,
+            400..=499 => Self::InvalidInput,
+            _ => Self::Internal,
+        })
+    }
+
+    /// Best-matching code for a process exit code (`None` for 0).
+    pub fn from_exit_code(code: i32) -> Option<Self> {
+        Some(match code {
+            0 => return None,
+            64 | 65 => Self::InvalidInput,
+            66 => Self::NotFound,
+            69 => Self::NetworkError,
+            73 => Self::AlreadyExists,
+            74 => Self::StorageError,
+            75 => Self::Timeout,
+            77 => Self::PermissionDenied,
+            _ => Self::Internal,
+        })
+    }
+
+    /// Whether this is an extension code (registered or not).
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Self::Custom(_) | Self::Unregistered)
+    }
+
     /// Get default severity for this error code
     pub fn default_severity(&self) -> Severity {
         match self {
+            Self::Custom(code) => code.defaults().severity,
             Self::Internal | Self::ChecksumMismatch => Severity::Fatal,
             Self::PermissionDenied | Self::VersionMismatch => Severity::Error,
             Self::NotFound | Self::InvalidInput | Self::AlreadyExists => Severity::Error,
@@ -405,6 +925,7 @@ impl ErrorCode {
     /// Check if this error is typically recoverable
     pub fn is_typically_recoverable(&self) -> bool {
         match self {
+            Self::Custom(code) => code.defaults().recoverable,
             Self::Internal | Self::ChecksumMismatch | Self::VersionMismatch => false,
             Self::NotFound | Self::EvidenceNotFound => true, // Can try different ID
             Self::Timeout | Self::NetworkError | Self::StorageError => true, // Can retry
@@ -418,36 +939,261 @@ impl ErrorCode {
 
 impl std::fmt::Display for ErrorCode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = match self {
-            Self::NotFound => "NOT_FOUND",
-            Self::InvalidInput => "INVALID_INPUT",
-            Self::PermissionDenied => "PERMISSION_DENIED",
-            Self::StorageError => "STORAGE_ERROR",
-            Self::NetworkError => "NETWORK_ERROR",
-            Self::Timeout => "TIMEOUT",
-            Self::ResourceExhausted => "RESOURCE_EXHAUSTED",
-            Self::Internal => "INTERNAL",
-            Self::NotImplemented => "NOT_IMPLEMENTED",
-            Self::ContextNotFound => "CONTEXT_NOT_FOUND",
-            Self::EvidenceNotFound => "EVIDENCE_NOT_FOUND",
-            Self::GroundingFailed => "GROUNDING_FAILED",
-            Self::VersionMismatch => "VERSION_MISMATCH",
-            Self::ChecksumMismatch => "CHECKSUM_MISMATCH",
-            Self::AlreadyExists => "ALREADY_EXISTS",
-            Self::InvalidState => "INVALID_STATE",
-            Self::MemoryError => "MEMORY_ERROR",
-            Self::VisionError => "VISION_ERROR",
-            Self::CodebaseError => "CODEBASE_ERROR",
-            Self::IdentityError => "IDENTITY_ERROR",
-            Self::TimeError => "TIME_ERROR",
-            Self::ContractError => "CONTRACT_ERROR",
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = std::borrow::Cow::<'de, str>::deserialize(deserializer)?;
+        Ok(Self::parse(&code))
+    }
+}
+
+/// Any SCREAMING_SNAKE_CASE string; the built-in codes are listed as examples.
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for ErrorCode {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "ErrorCode".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        let builtin: Vec<&str> = Self::BUILTIN.iter().map(|c| c.as_str()).collect();
+        schemars::json_schema!({
+            "description": "Error code; extension sisters may register their own",
+            "type": "string",
+            "examples": builtin
+        })
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// CUSTOM ERROR CODES (extension codes for sisters outside the core set)
+// ═══════════════════════════════════════════════════════════════════
+
+/// Defaults for an extension error code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CustomErrorCode {
+    /// Code string, SCREAMING_SNAKE_CASE (e.g., "PLANNING_GOAL_CYCLE")
+    pub code: &'static str,
+
+    /// Severity used by `SisterError::new`
+    pub severity: Severity,
+
+    /// Recoverability used by `SisterError::new`
+    pub recoverable: bool,
+
+    /// One-line description (shown in `catalog()`)
+    pub description: &'static str,
+}
+
+impl CustomErrorCode {
+    /// A recoverable `Error`-severity code.
+    pub const fn new(code: &'static str) -> Self {
+        Self {
+            code,
+            severity: Severity::Error,
+            recoverable: true,
+            description: "",
+        }
+    }
+
+    /// Set the description
+    pub const fn description(mut self, description: &'static str) -> Self {
+        self.description = description;
+        self
+    }
+
+    /// Set the default severity
+    pub const fn severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Set the default recoverability
+    pub const fn recoverable(mut self, recoverable: bool) -> Self {
+        self.recoverable = recoverable;
+        self
+    }
+}
+
+/// Handle to a registered extension code (keeps `ErrorCode` small and `Copy`).
+///
+/// Only `register_error_code` creates one, so the table holds the
+/// program's own definitions, never strings received from peers.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomCode(u16);
+
+impl CustomCode {
+    /// The code string.
+    pub fn as_str(&self) -> &'static str {
+        self.defaults().code
+    }
+
+    /// Registered defaults.
+    pub fn defaults(&self) -> CustomErrorCode {
+        code_table().codes[self.0 as usize]
+    }
+
+    fn lookup(code: &str) -> Option<Self> {
+        code_table().ids.get(code).copied().map(Self)
+    }
+}
+
+impl std::fmt::Debug for CustomCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CustomCode({:?})", self.as_str())
+    }
+}
+
+#[derive(Default)]
+struct CodeTable {
+    codes: Vec<CustomErrorCode>,
+    ids: HashMap<&'static str, u16>,
+}
+
+fn code_table_lock() -> &'static std::sync::RwLock<CodeTable> {
+    static TABLE: std::sync::OnceLock<std::sync::RwLock<CodeTable>> = std::sync::OnceLock::new();
+    TABLE.get_or_init(Default::default)
+}
+
+fn code_table() -> std::sync::RwLockReadGuard<'static, CodeTable> {
+    code_table_lock().read().unwrap_or_else(|e| e.into_inner())
+}
+
+fn code_table_mut() -> std::sync::RwLockWriteGuard<'static, CodeTable> {
+    code_table_lock().write().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Register an extension error code and its defaults.
+///
+/// Registering the same definition twice is a no-op. Fails for names
+/// that are not SCREAMING_SNAKE_CASE, that shadow a built-in code, or
+/// that are already registered with different defaults.
+///
+/// ```rust,ignore
+/// const GOAL_CYCLE: CustomErrorCode = CustomErrorCode::new("PLANNING_GOAL_CYCLE").recoverable(false);
+/// let code = register_error_code(GOAL_CYCLE)?;
+/// return Err(SisterError::new(code, "Goal graph has a cycle"));
+/// ```
+pub fn register_error_code(definition: CustomErrorCode) -> SisterResult<ErrorCode> {
+    let code = definition.code;
+    let well_formed = !code.is_empty()
+        && code.starts_with(|c: char| c.is_ascii_uppercase())
+        && code
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+    if !well_formed {
+        return Err(SisterError::invalid_input(format!(
+            "Error code {:?} must be SCREAMING_SNAKE_CASE",
+            code
+        )));
+    }
+    if ErrorCode::BUILTIN.iter().any(|c| c.as_str() == code) {
+        return Err(SisterError::new(
+            ErrorCode::AlreadyExists,
+            format!("{} is a built-in error code", code),
+        ));
+    }
+
+    let mut table = code_table_mut();
+    if let Some(&id) = table.ids.get(code) {
+        return if table.codes[id as usize] == definition {
+            Ok(ErrorCode::Custom(CustomCode(id)))
+        } else {
+            Err(SisterError::new(
+                ErrorCode::AlreadyExists,
+                format!(
+                    "Error code {} is already registered with different defaults",
+                    code
+                ),
+            ))
         };
-        write!(f, "{}", s)
     }
+    let Ok(id) = u16::try_from(table.codes.len()) else {
+        return Err(SisterError::new(
+            ErrorCode::ResourceExhausted,
+            "Too many registered error codes",
+        ));
+    };
+    table.codes.push(definition);
+    table.ids.insert(code, id);
+    Ok(ErrorCode::Custom(CustomCode(id)))
+}
+
+/// All registered extension codes, sorted by code.
+pub fn registered_error_codes() -> Vec<CustomErrorCode> {
+    let mut codes = code_table().codes.clone();
+    codes.sort_by_key(|c| c.code);
+    codes
+}
+
+/// One entry in the error catalog.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorCodeInfo {
+    /// Wire form (e.g., "NOT_FOUND")
+    pub code: String,
+
+    /// Default severity
+    pub severity: Severity,
+
+    /// Whether errors with this code are typically recoverable
+    pub recoverable: bool,
+
+    /// One-line description
+    pub description: String,
+
+    /// `ErrorCode::http_status`
+    pub http_status: u16,
+
+    /// `ErrorCode::exit_code`
+    pub exit_code: i32,
+
+    /// Whether this is an extension code rather than a built-in
+    #[serde(default)]
+    pub custom: bool,
+}
+
+impl From<ErrorCode> for ErrorCodeInfo {
+    fn from(code: ErrorCode) -> Self {
+        Self {
+            code: code.as_str().to_string(),
+            severity: code.default_severity(),
+            recoverable: code.is_typically_recoverable(),
+            description: code.description().to_string(),
+            http_status: code.http_status(),
+            exit_code: code.exit_code(),
+            custom: code.is_custom(),
+        }
+    }
+}
+
+/// Every known error code: built-ins in declaration order, then
+/// registered extension codes sorted by name.
+///
+/// Serialize it to JSON to generate error tables for non-Rust clients.
+pub fn catalog() -> Vec<ErrorCodeInfo> {
+    ErrorCode::BUILTIN
+        .into_iter()
+        .chain(
+            registered_error_codes()
+                .into_iter()
+                .map(|c| ErrorCode::custom(c.code)),
+        )
+        .map(ErrorCodeInfo::from)
+        .collect()
 }
 
 /// Severity levels
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[serde(rename_all = "snake_case")]
 pub enum Severity {
     /// Informational, not really an error
@@ -476,6 +1222,8 @@ impl std::fmt::Display for Severity {
 
 /// Suggested actions for error recovery
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SuggestedAction {
     /// Retry the operation
@@ -525,6 +1273,554 @@ impl From<serde_json::Error> for SisterError {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════
+// ERROR GROUPS (partial failure of batch operations)
+// ═══════════════════════════════════════════════════════════════════
+
+/// One failed item in a batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct ItemError {
+    /// Position of the item in the batch input
+    pub index: usize,
+
+    /// Why it failed
+    pub error: SisterError,
+}
+
+/// Per-item failures of a batch operation (ground_batch, bulk import, ...).
+///
+/// ```rust,ignore
+/// let (grounded, errors) = ErrorGroup::partition(claims.iter().map(|c| sister.ground(c)));
+/// errors.into_result()?; // or report ActionOutcome::partial_failure(&errors)
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct ErrorGroup {
+    /// Number of items in the batch
+    pub total: usize,
+
+    /// Failed items, in index order
+    pub errors: Vec<ItemError>,
+}
+
+impl ErrorGroup {
+    /// Empty group for a batch of `total` items.
+    pub fn new(total: usize) -> Self {
+        Self {
+            total,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Split batch results into successes (with their indices) and failures.
+    pub fn partition<T>(
+        results: impl IntoIterator<Item = SisterResult<T>>,
+    ) -> (Vec<(usize, T)>, Self) {
+        let mut ok = Vec::new();
+        let mut group = Self::default();
+        for (index, result) in results.into_iter().enumerate() {
+            group.total += 1;
+            match result {
+                Ok(value) => ok.push((index, value)),
+                Err(error) => group.push(index, error),
+            }
+        }
+        (ok, group)
+    }
+
+    /// Record a failure.
+    pub fn push(&mut self, index: usize, error: SisterError) {
+        let at = self.errors.partition_point(|e| e.index < index);
+        self.errors.insert(at, ItemError { index, error });
+        self.total = self.total.max(index + 1);
+    }
+
+    /// Whether nothing failed.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Number of failed items.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Number of items that succeeded.
+    pub fn succeeded(&self) -> usize {
+        self.total.saturating_sub(self.errors.len())
+    }
+
+    /// Whether every item failed.
+    pub fn is_total_failure(&self) -> bool {
+        self.total > 0 && self.errors.len() == self.total
+    }
+
+    /// Indices of failed items.
+    pub fn failed_indices(&self) -> Vec<usize> {
+        self.errors.iter().map(|e| e.index).collect()
+    }
+
+    /// `Ok(())` if nothing failed, otherwise the summary error.
+    pub fn into_result(self) -> SisterResult<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.to_error())
+        }
+    }
+
+    /// A single error summarizing the group.
+    ///
+    /// The code is shared by all failures if they agree, otherwise that of
+    /// the first. Severity is the worst among them; the group is
+    /// recoverable only if every failure is. Per-item codes and messages
+    /// are in the `failures` context entry.
+    pub fn to_error(&self) -> SisterError {
+        let Some(first) = self.errors.first() else {
+            return SisterError::internal("ErrorGroup::to_error called on an empty group");
+        };
+        let message = format!(
+            "{} of {} items failed; first (item {}): {}",
+            self.errors.len(),
+            self.total,
+            first.index,
+            first.error.message
+        );
+        let failures: Vec<_> = self
+            .errors
+            .iter()
+            .map(|e| {
+                serde_json::json!({
+                    "index": e.index,
+                    "code": e.error.code_str(),
+                    "message": e.error.message,
+                })
+            })
+            .collect();
+
+        let mut error = SisterError::new(first.error.code, message);
+        error.extended_code = first.error.extended_code.clone();
+        error
+            .with_severity(
+                self.errors
+                    .iter()
+                    .map(|e| e.error.severity)
+                    .max()
+                    .unwrap_or(Severity::Error),
+            )
+            .recoverable(self.errors.iter().all(|e| e.error.recoverable))
+            .with_context("total", self.total)
+            .with_context("failed", self.errors.len())
+            .with_context("failures", failures)
+    }
+}
+
+impl std::fmt::Display for ErrorGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} of {} items failed", self.errors.len(), self.total)
+    }
+}
+
+impl From<ErrorGroup> for SisterError {
+    fn from(group: ErrorGroup) -> Self {
+        group.to_error()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// ERROR REPORTING
+// ═══════════════════════════════════════════════════════════════════
+
+/// Receives errors so operators see them (logs, alerting, telemetry).
+///
+/// Sisters call `SisterError::report()` (global reporter) or
+/// `report_to(&reporter)` (injected) where an error is final, not at
+/// every `?`.
+pub trait ErrorReporter: Send + Sync {
+    /// Report one error
+    fn report(&self, error: &SisterError);
+}
+
+/// Writes errors at or above a severity to stderr.
+///
+/// The global default when neither the `log` nor `tracing` feature is
+/// enabled, set to `Fatal` so only errors that need an operator appear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StderrReporter {
+    /// Lowest severity written
+    pub min_severity: Severity,
+}
+
+impl StderrReporter {
+    /// Report errors at `min_severity` and above.
+    pub fn new(min_severity: Severity) -> Self {
+        Self { min_severity }
+    }
+}
+
+impl ErrorReporter for StderrReporter {
+    fn report(&self, error: &SisterError) {
+        if error.severity >= self.min_severity {
+            eprintln!("[{}] {}", error.severity, error);
+        }
+    }
+}
+
+/// Forwards errors to the `log` crate: Fatal/Error → `error!`,
+/// Warning → `warn!`, Info → `info!`.
+#[cfg(feature = "log")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogReporter;
+
+#[cfg(feature = "log")]
+impl ErrorReporter for LogReporter {
+    fn report(&self, error: &SisterError) {
+        let level = match error.severity {
+            Severity::Fatal | Severity::Error => log::Level::Error,
+            Severity::Warning => log::Level::Warn,
+            Severity::Info => log::Level::Info,
+        };
+        log::log!(target: "agentic_sdk", level, "[{}] {}", error.severity, error);
+    }
+}
+
+/// Forwards errors to `tracing` events, leveled like `LogReporter`,
+/// with code, severity, and recoverability as fields.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingReporter;
+
+#[cfg(feature = "tracing")]
+impl ErrorReporter for TracingReporter {
+    fn report(&self, error: &SisterError) {
+        let code = error.code_str();
+        let message = error.message.as_str();
+        match error.severity {
+            Severity::Fatal | Severity::Error => tracing::error!(
+                target: "agentic_sdk",
+                code,
+                severity = %error.severity,
+                recoverable = error.recoverable,
+                "{}",
+                message
+            ),
+            Severity::Warning => tracing::warn!(target: "agentic_sdk", code, "{}", message),
+            Severity::Info => tracing::info!(target: "agentic_sdk", code, "{}", message),
+        }
+    }
+}
+
+fn global_reporter() -> &'static std::sync::RwLock<std::sync::Arc<dyn ErrorReporter>> {
+    static REPORTER: std::sync::OnceLock<std::sync::RwLock<std::sync::Arc<dyn ErrorReporter>>> =
+        std::sync::OnceLock::new();
+    REPORTER.get_or_init(|| std::sync::RwLock::new(default_reporter()))
+}
+
+fn default_reporter() -> std::sync::Arc<dyn ErrorReporter> {
+    #[cfg(feature = "log")]
+    return std::sync::Arc::new(LogReporter);
+    #[cfg(all(feature = "tracing", not(feature = "log")))]
+    return std::sync::Arc::new(TracingReporter);
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    std::sync::Arc::new(StderrReporter::new(Severity::Fatal))
+}
+
+/// Replace the process-wide reporter used by `SisterError::report`.
+pub fn set_error_reporter(reporter: std::sync::Arc<dyn ErrorReporter>) {
+    *global_reporter().write().unwrap_or_else(|e| e.into_inner()) = reporter;
+}
+
+/// The process-wide reporter.
+pub fn error_reporter() -> std::sync::Arc<dyn ErrorReporter> {
+    global_reporter()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+impl SisterError {
+    /// Send to the global reporter; returns the error for `?`/`Err(...)`.
+    pub fn report(self) -> Self {
+        error_reporter().report(&self);
+        self
+    }
+
+    /// Send to a specific reporter; returns the error.
+    pub fn report_to(self, reporter: &dyn ErrorReporter) -> Self {
+        reporter.report(&self);
+        self
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// RETRY
+// ═══════════════════════════════════════════════════════════════════
+
+type RetryPredicate = std::sync::Arc<dyn Fn(&SisterError) -> bool + Send + Sync>;
+
+/// When and how often to retry a failing operation.
+///
+/// Delays grow exponentially from `initial_delay` up to `max_delay`,
+/// with up to `jitter` (a fraction) added so callers don't retry in
+/// lockstep. An error's `SuggestedAction::Retry { after_ms }` overrides
+/// the computed delay.
+///
+/// By default an error is retried if it suggests a retry, or if it is
+/// recoverable and its code is transient (`Timeout`, `NetworkError`,
+/// `StorageError`, `ResourceExhausted`). Use `retry_on` to change that.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first (at least 1)
+    pub max_attempts: u32,
+
+    /// Delay before the second attempt
+    pub initial_delay: std::time::Duration,
+
+    /// Upper bound for computed delays
+    pub max_delay: std::time::Duration,
+
+    /// Growth factor between attempts
+    pub multiplier: f64,
+
+    /// Random extra delay, as a fraction of the computed delay (0.0–1.0)
+    pub jitter: f64,
+
+    retry_on: Option<RetryPredicate>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay: std::time::Duration::from_millis(100),
+            max_delay: std::time::Duration::from_secs(10),
+            multiplier: 2.0,
+            jitter: 0.2,
+            retry_on: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_delay", &self.initial_delay)
+            .field("max_delay", &self.max_delay)
+            .field("multiplier", &self.multiplier)
+            .field("jitter", &self.jitter)
+            .field("custom_retry_on", &self.retry_on.is_some())
+            .finish()
+    }
+}
+
+impl RetryPolicy {
+    /// Default policy: 3 attempts, 100ms doubling up to 10s, 20% jitter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Never retry.
+    pub fn none() -> Self {
+        Self::default().max_attempts(1)
+    }
+
+    /// Set total attempts
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Set the first delay and cap
+    pub fn backoff(mut self, initial: std::time::Duration, max: std::time::Duration) -> Self {
+        self.initial_delay = initial;
+        self.max_delay = max;
+        self
+    }
+
+    /// Set the growth factor
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Set jitter (fraction of the delay, clamped to 0.0–1.0)
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Retry only errors whose code satisfies `predicate`
+    pub fn retry_on(
+        mut self,
+        predicate: impl Fn(ErrorCode) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.retry_on = Some(std::sync::Arc::new(move |e: &SisterError| {
+            predicate(e.code)
+        }));
+        self
+    }
+
+    /// Whether `error` should be retried (ignoring the attempt count).
+    pub fn should_retry(&self, error: &SisterError) -> bool {
+        if let Some(predicate) = &self.retry_on {
+            return predicate(error);
+        }
+        if matches!(error.suggested_action, Some(SuggestedAction::Retry { .. })) {
+            return true;
+        }
+        error.recoverable
+            && matches!(
+                error.code,
+                ErrorCode::Timeout
+                    | ErrorCode::NetworkError
+                    | ErrorCode::StorageError
+                    | ErrorCode::ResourceExhausted
+            )
+    }
+
+    /// Delay before the attempt after `attempt` (1-based) failed with `error`.
+    pub fn delay_after(&self, attempt: u32, error: &SisterError) -> std::time::Duration {
+        if let Some(SuggestedAction::Retry { after_ms }) = error.suggested_action {
+            return std::time::Duration::from_millis(after_ms);
+        }
+        let exponent = attempt.saturating_sub(1).min(63) as i32;
+        let base = self
+            .initial_delay
+            .mul_f64(self.multiplier.powi(exponent))
+            .min(self.max_delay);
+        let jitter = base.mul_f64(self.jitter * random_fraction());
+        (base + jitter).min(self.max_delay)
+    }
+
+    fn exhausted(&self, error: SisterError, attempts: u32) -> SisterError {
+        if attempts > 1 {
+            error.with_context(context_keys::ATTEMPTS, attempts)
+        } else {
+            error
+        }
+    }
+}
+
+/// Uniform value in [0, 1) without pulling in an RNG crate.
+fn random_fraction() -> f64 {
+    (uuid::Uuid::new_v4().as_u128() >> 75) as f64 / (1u64 << 53) as f64
+}
+
+/// Run `operation` until it succeeds, fails with a non-retryable error,
+/// or `policy.max_attempts` is reached. Blocks the thread between attempts.
+///
+/// `operation` receives the 1-based attempt number. The last error is
+/// returned with an `attempts` context entry when more than one was made.
+pub fn retry<T>(
+    policy: &RetryPolicy,
+    mut operation: impl FnMut(u32) -> SisterResult<T>,
+) -> SisterResult<T> {
+    let mut attempt = 1;
+    loop {
+        match operation(attempt) {
+            Ok(value) => return Ok(value),
+            Err(error) if attempt < policy.max_attempts && policy.should_retry(&error) => {
+                std::thread::sleep(policy.delay_after(attempt, &error));
+                attempt += 1;
+            }
+            Err(error) => return Err(policy.exhausted(error, attempt)),
+        }
+    }
+}
+
+/// Async `retry`. The SDK doesn't pick a runtime, so pass its sleep
+/// function (e.g., `tokio::time::sleep`).
+pub async fn retry_async<T, F, Fut, S, SFut>(
+    policy: &RetryPolicy,
+    mut operation: F,
+    sleep: S,
+) -> SisterResult<T>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: std::future::Future<Output = SisterResult<T>>,
+    S: Fn(std::time::Duration) -> SFut,
+    SFut: std::future::Future<Output = ()>,
+{
+    let mut attempt = 1;
+    loop {
+        match operation(attempt).await {
+            Ok(value) => return Ok(value),
+            Err(error) if attempt < policy.max_attempts && policy.should_retry(&error) => {
+                sleep(policy.delay_after(attempt, &error)).await;
+                attempt += 1;
+            }
+            Err(error) => return Err(policy.exhausted(error, attempt)),
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// anyhow / eyre interop (features "anyhow", "eyre")
+// ═══════════════════════════════════════════════════════════════════
+
+impl SisterError {
+    /// Build a `SisterError` from an error chain, keeping codes where possible.
+    ///
+    /// A `SisterError` anywhere in the chain is used as-is; otherwise an
+    /// `io::Error` maps like `From<io::Error>`; anything else is `Internal`.
+    /// The full chain is kept under the `error_chain` context key.
+    #[cfg(any(feature = "anyhow", feature = "eyre"))]
+    fn from_chain<'a>(
+        message: String,
+        chain: impl Iterator<Item = &'a (dyn std::error::Error + 'static)>,
+    ) -> Self {
+        let chain: Vec<_> = chain.collect();
+        let descriptions: Vec<String> = chain.iter().map(|e| e.to_string()).collect();
+
+        let base = chain
+            .iter()
+            .find_map(|e| e.downcast_ref::<SisterError>().cloned())
+            .or_else(|| {
+                chain.iter().find_map(|e| {
+                    e.downcast_ref::<std::io::Error>()
+                        .map(|io| SisterError::from(std::io::Error::new(io.kind(), io.to_string())))
+                })
+            })
+            .unwrap_or_else(|| Self::new(ErrorCode::Internal, message.clone()));
+
+        if descriptions.len() > 1 {
+            base.with_context("error_chain", descriptions)
+        } else {
+            base
+        }
+    }
+
+    /// Convert into an `anyhow::Error` (recoverable with `downcast_ref::<SisterError>()`).
+    #[cfg(feature = "anyhow")]
+    pub fn into_anyhow(self) -> anyhow::Error {
+        anyhow::Error::new(self)
+    }
+
+    /// Convert into an `eyre::Report` (recoverable with `downcast_ref::<SisterError>()`).
+    #[cfg(feature = "eyre")]
+    pub fn into_eyre(self) -> eyre::Report {
+        eyre::Report::new(self)
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl From<anyhow::Error> for SisterError {
+    fn from(e: anyhow::Error) -> Self {
+        Self::from_chain(e.to_string(), e.chain())
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl From<eyre::Report> for SisterError {
+    fn from(e: eyre::Report) -> Self {
+        Self::from_chain(e.to_string(), e.chain())
+    }
+}
+
 /// Result type alias for sister operations (domain errors)
 pub type SisterResult<T> = Result<T, SisterError>;
 
@@ -554,6 +1850,64 @@ mod tests {
         assert_eq!(ctx.get("field").unwrap(), "name");
     }
 
+    #[test]
+    fn test_standard_context_keys() {
+        let ctx = ContextId::new();
+        let err = SisterError::not_found("node 42")
+            .for_resource("node_42")
+            .in_context(ctx)
+            .for_operation("op_7")
+            .with_context("count", 3);
+
+        assert_eq!(err.resource_id(), Some("node_42"));
+        assert_eq!(err.context_id(), Some(ctx));
+        assert_eq!(err.operation_id(), Some("op_7"));
+        assert_eq!(err.context_u64("count"), Some(3));
+        assert_eq!(err.context_str("count"), None);
+        assert_eq!(SisterError::default().resource_id(), None);
+    }
+
+    #[cfg(feature = "anyhow")]
+    #[test]
+    fn test_anyhow_interop() {
+        use anyhow::Context;
+
+        let wrapped = Err::<(), _>(SisterError::not_found("node 42"))
+            .context("loading graph")
+            .unwrap_err();
+        let err = SisterError::from(wrapped);
+        assert_eq!(err.code, ErrorCode::NotFound);
+        assert_eq!(
+            err.context_value("error_chain").unwrap()[0],
+            "loading graph"
+        );
+
+        let io = anyhow::Error::new(std::io::Error::other("disk gone")).context("saving");
+        assert_eq!(SisterError::from(io).code, ErrorCode::StorageError);
+        assert_eq!(
+            SisterError::from(anyhow::anyhow!("boom")).code,
+            ErrorCode::Internal
+        );
+
+        let back = SisterError::invalid_input("bad").into_anyhow();
+        assert_eq!(
+            back.downcast_ref::<SisterError>().unwrap().code,
+            ErrorCode::InvalidInput
+        );
+    }
+
+    #[cfg(feature = "eyre")]
+    #[test]
+    fn test_eyre_interop() {
+        let report = SisterError::storage("disk").into_eyre().wrap_err("saving");
+        let err = SisterError::from(report);
+        assert_eq!(err.code, ErrorCode::StorageError);
+        assert_eq!(
+            SisterError::from(eyre::eyre!("boom")).code,
+            ErrorCode::Internal
+        );
+    }
+
     #[test]
     fn test_error_serialization() {
         let err = SisterError::not_found("test");
@@ -564,6 +1918,253 @@ mod tests {
         assert_eq!(recovered.code, ErrorCode::NotFound);
     }
 
+    #[test]
+    fn test_custom_error_codes() {
+        let code = register_error_code(
+            CustomErrorCode::new("TEST_GOAL_CYCLE")
+                .severity(Severity::Fatal)
+                .recoverable(false),
+        )
+        .unwrap();
+        assert_eq!(code, ErrorCode::custom("TEST_GOAL_CYCLE"));
+        assert!(code.is_custom());
+        assert!(register_error_code(CustomErrorCode::new("TEST_GOAL_CYCLE")).is_err());
+        assert!(register_error_code(CustomErrorCode::new("NOT_FOUND")).is_err());
+        assert!(register_error_code(CustomErrorCode::new("lower_case")).is_err());
+
+        let err = SisterError::new(code, "cycle detected");
+        assert_eq!(err.severity, Severity::Fatal);
+        assert!(!err.recoverable);
+
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["code"], "TEST_GOAL_CYCLE");
+        let back: SisterError = serde_json::from_value(json).unwrap();
+        assert_eq!(back.code, code);
+
+        // Unregistered codes keep their string on the error and round-trip
+        let json = serde_json::json!({
+            "code": "COMM_CHANNEL_CLOSED",
+            "severity": "error",
+            "message": "channel closed",
+            "recoverable": true,
+        });
+        let unknown: SisterError = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(unknown.code, ErrorCode::Unregistered);
+        assert!(unknown.code.is_custom());
+        assert_eq!(unknown.code_str(), "COMM_CHANNEL_CLOSED");
+        assert_eq!(unknown.to_string(), "[COMM_CHANNEL_CLOSED] channel closed");
+        assert_eq!(serde_json::to_value(&unknown).unwrap(), json);
+        // ...and are not kept anywhere once dropped
+        assert!(registered_error_codes()
+            .iter()
+            .all(|c| c.code != "COMM_CHANNEL_CLOSED"));
+        let bare: ErrorCode = serde_json::from_str("\"COMM_CHANNEL_CLOSED\"").unwrap();
+        assert_eq!(bare, ErrorCode::Unregistered);
+        assert_eq!(ErrorCode::parse("TIMEOUT"), ErrorCode::Timeout);
+        assert!(SisterError::extended("TIMEOUT", "slow")
+            .extended_code
+            .is_none());
+    }
+
+    #[test]
+    fn test_http_and_exit_code_mapping() {
+        assert_eq!(ErrorCode::NotFound.http_status(), 404);
+        assert_eq!(ErrorCode::ResourceExhausted.http_status(), 429);
+        assert_eq!(ErrorCode::MemoryError.http_status(), 500);
+        assert_eq!(ErrorCode::PermissionDenied.exit_code(), 77);
+        assert_eq!(ErrorCode::custom("TEST_EXIT").exit_code(), 1);
+
+        assert_eq!(ErrorCode::from_http_status(200), None);
+        assert_eq!(
+            ErrorCode::from_http_status(418),
+            Some(ErrorCode::InvalidInput)
+        );
+        assert_eq!(
+            ErrorCode::from_http_status(503),
+            Some(ErrorCode::NetworkError)
+        );
+        assert_eq!(ErrorCode::from_exit_code(0), None);
+
+        // Each code's canonical status/exit code maps back to a code with the same mapping
+        for code in ErrorCode::BUILTIN {
+            let back = ErrorCode::from_http_status(code.http_status()).unwrap();
+            assert_eq!(back.http_status(), code.http_status(), "{}", code);
+            let back = ErrorCode::from_exit_code(code.exit_code()).unwrap();
+            if code.exit_code() != 1 {
+                assert_eq!(back.exit_code(), code.exit_code(), "{}", code);
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_catalog() {
+        register_error_code(
+            CustomErrorCode::new("TEST_CATALOG_ENTRY").description("Catalog test code"),
+        )
+        .unwrap();
+        let entries = catalog();
+        assert_eq!(entries[0].code, "NOT_FOUND");
+        assert_eq!(
+            entries.iter().filter(|e| !e.custom).count(),
+            ErrorCode::BUILTIN.len()
+        );
+
+        let internal = entries.iter().find(|e| e.code == "INTERNAL").unwrap();
+        assert_eq!(internal.severity, Severity::Fatal);
+        assert!(!internal.recoverable);
+        assert_eq!(internal.http_status, 500);
+
+        let custom = entries
+            .iter()
+            .find(|e| e.code == "TEST_CATALOG_ENTRY")
+            .unwrap();
+        assert!(custom.custom);
+        assert_eq!(custom.description, "Catalog test code");
+
+        let json = serde_json::to_value(&entries).unwrap();
+        assert_eq!(json[1]["severity"], "error");
+    }
+
+    #[test]
+    fn test_error_group_summary() {
+        let results: Vec<SisterResult<u32>> = vec![
+            Ok(1),
+            Err(SisterError::not_found("a")),
+            Ok(3),
+            Err(SisterError::internal("b")),
+        ];
+        let (ok, group) = ErrorGroup::partition(results);
+        assert_eq!(ok, vec![(0, 1), (2, 3)]);
+        assert_eq!(group.failed_indices(), vec![1, 3]);
+        assert_eq!(group.succeeded(), 2);
+        assert!(!group.is_total_failure());
+
+        let err = group.to_error();
+        assert_eq!(err.code, ErrorCode::NotFound);
+        assert_eq!(err.severity, Severity::Fatal);
+        assert!(!err.recoverable);
+        assert!(err.message.starts_with("2 of 4 items failed"));
+        assert_eq!(
+            err.context_value("failures").unwrap()[1]["code"],
+            "INTERNAL"
+        );
+
+        assert!(ErrorGroup::new(3).into_result().is_ok());
+    }
+
+    #[test]
+    fn test_timeout_and_rate_limit_constructors() {
+        use std::time::Duration;
+        let err = SisterError::timeout("memory_query", Duration::from_millis(2500));
+        assert_eq!(err.code, ErrorCode::Timeout);
+        assert_eq!(err.context_str("operation"), Some("memory_query"));
+        assert_eq!(err.context_u64("elapsed_ms"), Some(2500));
+        assert!(err.to_mcp_message().contains("timed out after 2500ms"));
+
+        let err = SisterError::rate_limited(Duration::from_secs(3));
+        assert_eq!(err.code, ErrorCode::ResourceExhausted);
+        assert_eq!(err.context_u64("retry_after_ms"), Some(3000));
+        assert_eq!(
+            RetryPolicy::new().delay_after(1, &err),
+            Duration::from_secs(3)
+        );
+    }
+
+    #[test]
+    fn test_error_reporter() {
+        #[derive(Default)]
+        struct Collect(std::sync::Mutex<Vec<(ErrorCode, Severity)>>);
+        impl ErrorReporter for Collect {
+            fn report(&self, error: &SisterError) {
+                self.0.lock().unwrap().push((error.code, error.severity));
+            }
+        }
+
+        let collect = Collect::default();
+        let err = SisterError::internal("bug").report_to(&collect);
+        assert_eq!(err.code, ErrorCode::Internal);
+        SisterError::not_found("x").report_to(&collect);
+        assert_eq!(
+            *collect.0.lock().unwrap(),
+            vec![
+                (ErrorCode::Internal, Severity::Fatal),
+                (ErrorCode::NotFound, Severity::Error)
+            ]
+        );
+
+        // The default global reporter is always present
+        SisterError::not_found("quiet").report();
+    }
+
+    #[test]
+    fn test_retry_policy() {
+        use std::time::Duration;
+        let policy = RetryPolicy::new()
+            .max_attempts(4)
+            .backoff(Duration::from_millis(1), Duration::from_millis(5))
+            .jitter(0.0);
+
+        let value = retry(&policy, |attempt| {
+            if attempt < 3 {
+                Err(SisterError::new(ErrorCode::Timeout, "slow"))
+            } else {
+                Ok(attempt)
+            }
+        })
+        .unwrap();
+        assert_eq!(value, 3);
+
+        // Non-transient errors fail on the first attempt
+        let mut calls = 0;
+        let err = retry(&policy, |_| -> SisterResult<()> {
+            calls += 1;
+            Err(SisterError::invalid_input("bad"))
+        })
+        .unwrap_err();
+        assert_eq!(calls, 1);
+        assert!(err.context.is_none());
+
+        let err = retry(&policy, |_| -> SisterResult<()> {
+            Err(SisterError::new(ErrorCode::NetworkError, "reset"))
+        })
+        .unwrap_err();
+        assert_eq!(err.context.unwrap()["attempts"], 4);
+
+        // Suggested delays win; backoff is capped
+        assert_eq!(
+            policy.delay_after(1, &SisterError::storage("disk")),
+            Duration::from_millis(1000)
+        );
+        let timeout = SisterError::new(ErrorCode::Timeout, "slow");
+        assert_eq!(policy.delay_after(2, &timeout), Duration::from_millis(2));
+        assert_eq!(policy.delay_after(10, &timeout), Duration::from_millis(5));
+
+        let only_conflicts = RetryPolicy::new().retry_on(|c| c == ErrorCode::AlreadyExists);
+        assert!(!only_conflicts.should_retry(&timeout));
+    }
+
+    #[tokio::test]
+    async fn test_retry_async() {
+        let policy = RetryPolicy::new().backoff(
+            std::time::Duration::from_millis(1),
+            std::time::Duration::from_millis(1),
+        );
+        let value = retry_async(
+            &policy,
+            |attempt| async move {
+                if attempt == 1 {
+                    Err(SisterError::new(ErrorCode::NetworkError, "reset"))
+                } else {
+                    Ok("done")
+                }
+            },
+            tokio::time::sleep,
+        )
+        .await
+        .unwrap();
+        assert_eq!(value, "done");
+    }
+
     #[test]
     fn test_protocol_error_codes() {
         let err = ProtocolError::tool_not_found("memory_foo");
@@ -590,6 +2191,52 @@ mod tests {
         assert!(msg2.contains("Retry after"));
     }
 
+    #[test]
+    fn test_protocol_error_object() {
+        let err = ProtocolError::tool_not_found("memory_foo")
+            .with_data(serde_json::json!({"tool": "memory_foo"}));
+        let object = err.to_error_object();
+        assert_eq!(object["code"], -32803);
+        assert_eq!(object["data"]["tool"], "memory_foo");
+        assert_eq!(serde_json::to_value(&err).unwrap(), object);
+
+        let response = err.to_response(serde_json::json!(7));
+        assert_eq!(response["jsonrpc"], "2.0");
+        assert_eq!(response["id"], 7);
+
+        let parse: ProtocolError = serde_json::from_str::<serde_json::Value>("{")
+            .unwrap_err()
+            .into();
+        assert_eq!(parse.code, ProtocolErrorCode::ParseError);
+        assert!(parse.to_error_object().get("data").is_none());
+
+        let params: ProtocolError = SisterError::invalid_input("missing claim").into();
+        assert_eq!(params.json_rpc_code(), -32602);
+        assert_eq!(params.data.unwrap()["code"], "INVALID_INPUT");
+        let internal: ProtocolError = SisterError::storage("disk").into();
+        assert_eq!(internal.code, ProtocolErrorCode::InternalError);
+    }
+
+    #[test]
+    fn test_mcp_content() {
+        let err = SisterError::invalid_input("bad level")
+            .with_context("field", "level")
+            .with_context("allowed", ["low", "high"]);
+        let content = err.to_mcp_content();
+        assert_eq!(content["code"], "INVALID_INPUT");
+        assert_eq!(content["context"]["allowed"][1], "high");
+        assert!(content.get("suggested_action").is_none());
+
+        let content = SisterError::storage("disk").to_mcp_content();
+        assert_eq!(content["suggested_action"]["type"], "retry");
+        assert!(content.get("context").is_none());
+
+        let result = err.to_mcp_result();
+        assert_eq!(result["isError"], true);
+        assert_eq!(result["content"][0]["text"], err.to_mcp_message());
+        assert_eq!(result["structuredContent"], err.to_mcp_content());
+    }
+
     #[test]
     fn test_protocol_error_code_values() {
         // Verify exact JSON-RPC error codes per spec
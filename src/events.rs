@@ -3,17 +3,24 @@
 //! All sisters emit standardized events that Hydra can subscribe to
 //! for monitoring, logging, and orchestration.
 
+use crate::clock::{Clock, SystemClock};
 use crate::context::ContextId;
-use crate::errors::SisterError;
+use crate::errors::{SisterError, SisterResult};
 use crate::grounding::EvidenceType;
+use crate::hydra::{RunId, RunRecord, RunStatus, StepId, StepRecord};
+use crate::sister::ConfigDiff;
 use crate::types::{SisterType, Status, UniqueId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+#[cfg(feature = "events")]
 use tokio::sync::broadcast;
 
 /// Unique event identifier.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct EventId(pub UniqueId);
 
 impl EventId {
@@ -36,6 +43,8 @@ impl std::fmt::Display for EventId {
 
 /// Event types that ALL sisters emit.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[serde(tag = "event_type", rename_all = "snake_case")]
 pub enum EventType {
     // ═══════════════════════════════════════════════════════
@@ -47,6 +56,18 @@ pub enum EventType {
     /// Sister shutting down.
     ShuttingDown,
 
+    /// Sister quiesced by `pause()`.
+    Paused,
+
+    /// Sister accepting writes again after `resume()`.
+    Resumed,
+
+    /// Configuration reloaded by `update_config()`.
+    ConfigChanged { changed: Vec<String> },
+
+    /// A `warm_up()` phase finished.
+    WarmUpProgress { phase: String, percent: f64 },
+
     /// Sister status changed.
     StatusChanged { from: Status, to: Status },
 
@@ -62,6 +83,13 @@ pub enum EventType {
     /// Context deleted.
     ContextDeleted { context_id: ContextId },
 
+    /// Context moved out of active storage (to `archive_path`, if any).
+    ContextArchived {
+        context_id: ContextId,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        archive_path: Option<String>,
+    },
+
     // ═══════════════════════════════════════════════════════
     // OPERATION EVENTS
     // ═══════════════════════════════════════════════════════
@@ -75,6 +103,8 @@ pub enum EventType {
     OperationCompleted {
         operation_id: String,
         #[serde(with = "duration_millis")]
+        #[cfg_attr(feature = "schema", schemars(with = "u64"))]
+        #[cfg_attr(feature = "typescript", ts(type = "number"))]
         duration: Duration,
     },
 
@@ -101,6 +131,29 @@ pub enum EventType {
         confidence: f64,
     },
 
+    // ═══════════════════════════════════════════════════════
+    // RUN EVENTS (Hydra runs and steps)
+    // ═══════════════════════════════════════════════════════
+    /// Hydra run started.
+    RunStarted { run_id: RunId, goal: String },
+
+    /// Hydra run reached a terminal status.
+    RunFinished { run_id: RunId, status: RunStatus },
+
+    /// Step of a run started on this sister.
+    StepStarted {
+        run_id: RunId,
+        step_id: StepId,
+        command_type: String,
+    },
+
+    /// Step of a run finished on this sister.
+    StepCompleted {
+        run_id: RunId,
+        step_id: StepId,
+        status: RunStatus,
+    },
+
     // ═══════════════════════════════════════════════════════
     // RESOURCE EVENTS
     // ═══════════════════════════════════════════════════════
@@ -120,8 +173,25 @@ pub enum EventType {
     },
 }
 
+impl EventType {
+    /// Whether this event must be delivered at least once.
+    ///
+    /// Audit-critical events should go through an `AckableEventSink`
+    /// rather than relying on best-effort broadcast alone.
+    pub fn is_audit_critical(&self) -> bool {
+        matches!(
+            self,
+            Self::GroundingPerformed { .. }
+                | Self::ContextDeleted { .. }
+                | Self::ContextArchived { .. }
+        )
+    }
+}
+
 /// Event emitted by a sister.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct SisterEvent {
     /// Unique event ID.
     pub id: EventId,
@@ -144,11 +214,20 @@ pub struct SisterEvent {
 impl SisterEvent {
     /// Create a new event.
     pub fn new(sister_type: SisterType, event_type: EventType) -> Self {
+        Self::new_with_clock(sister_type, event_type, &SystemClock)
+    }
+
+    /// Create a new event stamped by `clock`.
+    pub fn new_with_clock(
+        sister_type: SisterType,
+        event_type: EventType,
+        clock: &dyn Clock,
+    ) -> Self {
         Self {
             id: EventId::new(),
             sister_type,
             event_type,
-            timestamp: Utc::now(),
+            timestamp: clock.now(),
             context_id: None,
         }
     }
@@ -169,6 +248,41 @@ impl SisterEvent {
         Self::new(sister_type, EventType::ShuttingDown)
     }
 
+    pub fn memory_pressure(sister_type: SisterType, usage_percent: f64) -> Self {
+        Self::new(sister_type, EventType::MemoryPressure { usage_percent })
+    }
+
+    pub fn paused(sister_type: SisterType) -> Self {
+        Self::new(sister_type, EventType::Paused)
+    }
+
+    pub fn resumed(sister_type: SisterType) -> Self {
+        Self::new(sister_type, EventType::Resumed)
+    }
+
+    pub fn warm_up_progress(
+        sister_type: SisterType,
+        phase: impl Into<String>,
+        percent: f64,
+    ) -> Self {
+        Self::new(
+            sister_type,
+            EventType::WarmUpProgress {
+                phase: phase.into(),
+                percent,
+            },
+        )
+    }
+
+    pub fn config_changed(sister_type: SisterType, diff: &ConfigDiff) -> Self {
+        Self::new(
+            sister_type,
+            EventType::ConfigChanged {
+                changed: diff.changed.clone(),
+            },
+        )
+    }
+
     pub fn status_changed(sister_type: SisterType, from: Status, to: Status) -> Self {
         Self::new(sister_type, EventType::StatusChanged { from, to })
     }
@@ -181,6 +295,34 @@ impl SisterEvent {
         Self::new(sister_type, EventType::ContextSwitched { from, to })
     }
 
+    pub fn context_deleted(sister_type: SisterType, context_id: ContextId) -> Self {
+        Self::new(sister_type, EventType::ContextDeleted { context_id }).in_context(context_id)
+    }
+
+    pub fn context_archived(
+        sister_type: SisterType,
+        context_id: ContextId,
+        archive_path: Option<String>,
+    ) -> Self {
+        Self::new(
+            sister_type,
+            EventType::ContextArchived {
+                context_id,
+                archive_path,
+            },
+        )
+        .in_context(context_id)
+    }
+
+    /// Storage pressure in one context (e.g., nearing its `ContextQuota`).
+    pub fn storage_pressure(
+        sister_type: SisterType,
+        context_id: ContextId,
+        usage_percent: f64,
+    ) -> Self {
+        Self::new(sister_type, EventType::StoragePressure { usage_percent }).in_context(context_id)
+    }
+
     pub fn operation_started(
         sister_type: SisterType,
         operation_id: impl Into<String>,
@@ -218,7 +360,7 @@ impl SisterEvent {
             sister_type,
             EventType::OperationFailed {
                 operation_id: operation_id.into(),
-                error_code: error.code.to_string(),
+                error_code: error.code_str().to_string(),
                 error_message: error.message.clone(),
             },
         )
@@ -238,6 +380,42 @@ impl SisterEvent {
         )
     }
 
+    pub fn run_started(sister_type: SisterType, run: &RunRecord) -> Self {
+        Self::new(
+            sister_type,
+            EventType::RunStarted {
+                run_id: run.run_id.clone(),
+                goal: run.goal.clone(),
+            },
+        )
+    }
+
+    pub fn run_finished(sister_type: SisterType, run_id: RunId, status: RunStatus) -> Self {
+        Self::new(sister_type, EventType::RunFinished { run_id, status })
+    }
+
+    pub fn step_started(sister_type: SisterType, step: &StepRecord) -> Self {
+        Self::new(
+            sister_type,
+            EventType::StepStarted {
+                run_id: step.run_id.clone(),
+                step_id: step.step_id,
+                command_type: step.command_type.clone(),
+            },
+        )
+    }
+
+    pub fn step_completed(sister_type: SisterType, step: &StepRecord) -> Self {
+        Self::new(
+            sister_type,
+            EventType::StepCompleted {
+                run_id: step.run_id.clone(),
+                step_id: step.step_id,
+                status: step.status,
+            },
+        )
+    }
+
     pub fn grounding_performed(
         sister_type: SisterType,
         grounding_id: impl Into<String>,
@@ -253,6 +431,20 @@ impl SisterEvent {
             },
         )
     }
+
+    pub fn custom(
+        sister_type: SisterType,
+        name: impl Into<String>,
+        data: serde_json::Value,
+    ) -> Self {
+        Self::new(
+            sister_type,
+            EventType::Custom {
+                name: name.into(),
+                data,
+            },
+        )
+    }
 }
 
 /// Filter for subscribing to events.
@@ -302,12 +494,15 @@ impl EventFilter {
 }
 
 /// Event receiver (broadcast channel).
+#[cfg(feature = "events")]
 pub type EventReceiver = broadcast::Receiver<SisterEvent>;
 
 /// Event sender (broadcast channel).
+#[cfg(feature = "events")]
 pub type EventSender = broadcast::Sender<SisterEvent>;
 
 /// Event emitter trait for observability.
+#[cfg(feature = "events")]
 pub trait EventEmitter {
     /// Subscribe to events with optional filter.
     fn subscribe(&self, filter: EventFilter) -> EventReceiver;
@@ -320,7 +515,11 @@ pub trait EventEmitter {
 }
 
 /// Helper struct for managing event emission.
+///
+/// Keeps the most recent events; with the `events` feature it also
+/// broadcasts each one to `subscribe`rs.
 pub struct EventManager {
+    #[cfg(feature = "events")]
     sender: EventSender,
     recent: std::sync::Mutex<Vec<SisterEvent>>,
     max_recent: usize,
@@ -329,8 +528,12 @@ pub struct EventManager {
 impl EventManager {
     /// Create a new event manager.
     pub fn new(capacity: usize) -> Self {
+        #[cfg(feature = "events")]
         let (sender, _) = broadcast::channel(capacity);
+        #[cfg(not(feature = "events"))]
+        let _ = capacity;
         Self {
+            #[cfg(feature = "events")]
             sender,
             recent: std::sync::Mutex::new(Vec::new()),
             max_recent: 100,
@@ -349,10 +552,12 @@ impl EventManager {
         }
 
         // Broadcast (ignore errors if no subscribers)
+        #[cfg(feature = "events")]
         let _ = self.sender.send(event);
     }
 
     /// Subscribe to events.
+    #[cfg(feature = "events")]
     pub fn subscribe(&self) -> EventReceiver {
         self.sender.subscribe()
     }
@@ -370,6 +575,205 @@ impl Default for EventManager {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════
+// AT-LEAST-ONCE DELIVERY
+// ═══════════════════════════════════════════════════════════════════
+
+/// Identifier for a single delivery of an event from an `AckableEventSink`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DeliveryId(pub u64);
+
+impl std::fmt::Display for DeliveryId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "dlv_{}", self.0)
+    }
+}
+
+/// An event handed to a consumer that must be acknowledged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Delivery {
+    /// Delivery identifier (pass this to `ack`)
+    pub delivery_id: DeliveryId,
+
+    /// The delivered event
+    pub event: SisterEvent,
+
+    /// Delivery attempt number (1 for the first delivery)
+    pub attempt: u32,
+
+    /// When this attempt was handed out
+    pub delivered_at: DateTime<Utc>,
+}
+
+/// Event sink with at-least-once delivery semantics.
+///
+/// Broadcast (`EventManager`) is best-effort: a lagging or absent
+/// subscriber silently misses events. Audit-critical events
+/// (`GroundingPerformed`, `ContextDeleted`) go through this sink instead:
+///
+/// 1. `publish` enqueues the event durably
+/// 2. `deliver` hands events out and starts an ack timer
+/// 3. `ack` removes the event for good
+/// 4. Unacked events become deliverable again once the timer expires
+///
+/// Consumers must therefore tolerate duplicates (dedupe on `event.id`).
+pub trait AckableEventSink {
+    /// Enqueue an event for delivery
+    fn publish(&self, event: SisterEvent) -> SisterResult<()>;
+
+    /// Take up to `max` events that are ready for (re)delivery
+    fn deliver(&self, max: usize) -> SisterResult<Vec<Delivery>>;
+
+    /// Acknowledge a delivery. The event will not be delivered again
+    fn ack(&self, delivery_id: DeliveryId) -> SisterResult<()>;
+
+    /// Reject a delivery so it is redelivered immediately
+    fn nack(&self, delivery_id: DeliveryId) -> SisterResult<()>;
+
+    /// Number of events not yet acknowledged (queued + in flight)
+    fn pending(&self) -> usize;
+}
+
+struct InFlight {
+    delivery: Delivery,
+    deadline: Instant,
+}
+
+#[derive(Default)]
+struct AckQueue {
+    ready: VecDeque<(SisterEvent, u32)>,
+    in_flight: HashMap<DeliveryId, InFlight>,
+    next_delivery: u64,
+}
+
+/// In-memory reference implementation of `AckableEventSink`.
+///
+/// Durable only for the lifetime of the process; sisters that need
+/// crash-safe delivery should back the same semantics with storage.
+pub struct InMemoryAckSink {
+    ack_timeout: Duration,
+    queue: std::sync::Mutex<AckQueue>,
+}
+
+impl InMemoryAckSink {
+    /// Create a sink that redelivers events not acked within `ack_timeout`.
+    pub fn new(ack_timeout: Duration) -> Self {
+        Self {
+            ack_timeout,
+            queue: std::sync::Mutex::new(AckQueue::default()),
+        }
+    }
+
+    /// The configured ack timeout.
+    pub fn ack_timeout(&self) -> Duration {
+        self.ack_timeout
+    }
+
+    /// Number of deliveries awaiting acknowledgment.
+    pub fn in_flight(&self) -> usize {
+        self.queue.lock().unwrap().in_flight.len()
+    }
+
+    /// Move expired in-flight deliveries back to the ready queue.
+    /// Returns how many were requeued
+    pub fn requeue_expired(&self) -> usize {
+        let mut queue = self.queue.lock().unwrap();
+        Self::requeue_expired_locked(&mut queue, Instant::now())
+    }
+
+    fn requeue_expired_locked(queue: &mut AckQueue, now: Instant) -> usize {
+        let mut expired: Vec<_> = queue
+            .in_flight
+            .iter()
+            .filter(|(_, f)| f.deadline <= now)
+            .map(|(id, _)| *id)
+            .collect();
+        // Preserve original delivery order when requeueing
+        expired.sort_by_key(|id| id.0);
+
+        for id in &expired {
+            if let Some(f) = queue.in_flight.remove(id) {
+                queue
+                    .ready
+                    .push_back((f.delivery.event, f.delivery.attempt));
+            }
+        }
+        expired.len()
+    }
+}
+
+impl Default for InMemoryAckSink {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(30))
+    }
+}
+
+impl AckableEventSink for InMemoryAckSink {
+    fn publish(&self, event: SisterEvent) -> SisterResult<()> {
+        self.queue.lock().unwrap().ready.push_back((event, 0));
+        Ok(())
+    }
+
+    fn deliver(&self, max: usize) -> SisterResult<Vec<Delivery>> {
+        let mut queue = self.queue.lock().unwrap();
+        let now = Instant::now();
+        Self::requeue_expired_locked(&mut queue, now);
+
+        let mut out = Vec::new();
+        while out.len() < max {
+            let Some((event, attempts)) = queue.ready.pop_front() else {
+                break;
+            };
+            queue.next_delivery += 1;
+            let delivery = Delivery {
+                delivery_id: DeliveryId(queue.next_delivery),
+                event,
+                attempt: attempts + 1,
+                delivered_at: Utc::now(),
+            };
+            queue.in_flight.insert(
+                delivery.delivery_id,
+                InFlight {
+                    delivery: delivery.clone(),
+                    deadline: now + self.ack_timeout,
+                },
+            );
+            out.push(delivery);
+        }
+        Ok(out)
+    }
+
+    fn ack(&self, delivery_id: DeliveryId) -> SisterResult<()> {
+        self.queue
+            .lock()
+            .unwrap()
+            .in_flight
+            .remove(&delivery_id)
+            .map(|_| ())
+            .ok_or_else(|| {
+                SisterError::not_found(format!("Delivery {}", delivery_id))
+                    .with_context("delivery_id", delivery_id.0)
+            })
+    }
+
+    fn nack(&self, delivery_id: DeliveryId) -> SisterResult<()> {
+        let mut queue = self.queue.lock().unwrap();
+        let f = queue.in_flight.remove(&delivery_id).ok_or_else(|| {
+            SisterError::not_found(format!("Delivery {}", delivery_id))
+                .with_context("delivery_id", delivery_id.0)
+        })?;
+        queue
+            .ready
+            .push_front((f.delivery.event, f.delivery.attempt));
+        Ok(())
+    }
+
+    fn pending(&self) -> usize {
+        let queue = self.queue.lock().unwrap();
+        queue.ready.len() + queue.in_flight.len()
+    }
+}
+
 // Duration serialization as milliseconds
 mod duration_millis {
     use serde::{Deserialize, Deserializer, Serializer};
@@ -402,6 +806,23 @@ mod tests {
         assert_eq!(event.sister_type, SisterType::Memory);
     }
 
+    #[test]
+    fn test_run_events() {
+        let mut run = RunRecord::start("summarize repo");
+        let event = SisterEvent::run_started(SisterType::Memory, &run);
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["event_type"], "run_started");
+        assert_eq!(json["run_id"], run.run_id.as_str());
+
+        let step_id = run.start_step(SisterType::Memory, "memory_query").step_id;
+        run.complete_step(step_id, crate::receipts::ActionOutcome::success())
+            .unwrap();
+        let event = SisterEvent::step_completed(SisterType::Memory, run.step(step_id).unwrap());
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["step_id"], 1);
+        assert_eq!(json["status"], "succeeded");
+    }
+
     #[test]
     fn test_event_filter() {
         let event = SisterEvent::ready(SisterType::Memory);
@@ -422,4 +843,61 @@ mod tests {
         let recent = manager.recent(10);
         assert_eq!(recent.len(), 2);
     }
+
+    #[test]
+    fn test_audit_critical_events() {
+        let event = SisterEvent::grounding_performed(SisterType::Memory, "g1", true, 0.9);
+        assert!(event.event_type.is_audit_critical());
+        assert!(!SisterEvent::ready(SisterType::Memory)
+            .event_type
+            .is_audit_critical());
+    }
+
+    #[test]
+    fn test_ack_sink_ack_removes_event() {
+        let sink = InMemoryAckSink::new(Duration::from_secs(60));
+        sink.publish(SisterEvent::ready(SisterType::Memory))
+            .unwrap();
+        sink.publish(SisterEvent::ready(SisterType::Vision))
+            .unwrap();
+
+        let batch = sink.deliver(10).unwrap();
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch[0].attempt, 1);
+        assert!(sink.deliver(10).unwrap().is_empty());
+
+        sink.ack(batch[0].delivery_id).unwrap();
+        assert_eq!(sink.pending(), 1);
+        assert!(sink.ack(batch[0].delivery_id).is_err());
+    }
+
+    #[test]
+    fn test_ack_sink_redelivers_on_timeout() {
+        let sink = InMemoryAckSink::new(Duration::ZERO);
+        sink.publish(SisterEvent::ready(SisterType::Memory))
+            .unwrap();
+
+        let first = sink.deliver(1).unwrap();
+        let second = sink.deliver(1).unwrap();
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].event.id, first[0].event.id);
+        assert_eq!(second[0].attempt, 2);
+        assert_ne!(second[0].delivery_id, first[0].delivery_id);
+    }
+
+    #[test]
+    fn test_ack_sink_nack_requeues_first() {
+        let sink = InMemoryAckSink::new(Duration::from_secs(60));
+        sink.publish(SisterEvent::ready(SisterType::Memory))
+            .unwrap();
+        sink.publish(SisterEvent::ready(SisterType::Vision))
+            .unwrap();
+
+        let first = sink.deliver(1).unwrap();
+        sink.nack(first[0].delivery_id).unwrap();
+
+        let again = sink.deliver(1).unwrap();
+        assert_eq!(again[0].event.sister_type, SisterType::Memory);
+        assert_eq!(again[0].attempt, 2);
+    }
 }
@@ -27,6 +27,48 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+mod atomic;
+#[cfg(feature = "file-io")]
+mod backup;
+#[cfg(feature = "file-io")]
+mod bundle;
+mod compression;
+#[cfg(feature = "file-io")]
+mod container;
+#[cfg(feature = "file-io")]
+mod describe;
+#[cfg(feature = "file-io")]
+mod journal;
+mod json;
+#[cfg(feature = "file-io")]
+mod lock;
+mod migration;
+mod recovery;
+#[cfg(feature = "file-io")]
+mod streaming;
+
+pub mod testing;
+
+pub use atomic::*;
+#[cfg(feature = "file-io")]
+pub use backup::*;
+#[cfg(feature = "file-io")]
+pub use bundle::*;
+pub use compression::*;
+#[cfg(feature = "file-io")]
+pub use container::*;
+#[cfg(feature = "file-io")]
+pub use describe::*;
+#[cfg(feature = "file-io")]
+pub use journal::*;
+pub use json::*;
+#[cfg(feature = "file-io")]
+pub use lock::*;
+pub use migration::*;
+pub use recovery::*;
+#[cfg(feature = "file-io")]
+pub use streaming::*;
+
 /// Information about a file (without loading full content).
 ///
 /// Every sister can produce this from any of its files,
@@ -53,6 +95,10 @@ pub struct FileInfo {
 
     /// The magic bytes or format identifier (e.g., "AMEM", "AVIS", "aid-v1")
     pub format_id: String,
+
+    /// Compression applied to the payload (readers decompress automatically)
+    #[serde(default)]
+    pub compression: CompressionCodec,
 }
 
 /// File format reader trait for all sisters.
@@ -73,15 +119,32 @@ pub trait FileFormatReader: Sized {
     /// Migrate old version data to current format (in memory).
     /// Returns the migrated bytes
     fn migrate(data: &[u8], from_version: Version) -> SisterResult<Vec<u8>>;
+
+    /// Best-effort recovery of a damaged file.
+    ///
+    /// Returns a report of which regions survived and any salvaged
+    /// content. `Err` means the file could not be examined at all;
+    /// an unrecoverable file is still `Ok` with nothing salvaged.
+    fn recover(path: &Path) -> SisterResult<RecoveryReport> {
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            format!("Recovery is not supported for {}", path.display()),
+        ))
+    }
 }
 
 /// File format writer trait for all sisters
 pub trait FileFormatWriter {
-    /// Write to a file path
-    fn write_file(&self, path: &Path) -> SisterResult<()>;
-
     /// Serialize the content to bytes
     fn to_bytes(&self) -> SisterResult<Vec<u8>>;
+
+    /// Write to a file path.
+    ///
+    /// Default: crash-safe `atomic_write` of `to_bytes()`. Sisters that
+    /// override this should keep the temp-file + fsync + rename discipline.
+    fn write_file(&self, path: &Path) -> SisterResult<()> {
+        atomic_write(path, &self.to_bytes()?)
+    }
 }
 
 /// Version compatibility rules.
@@ -134,7 +197,8 @@ pub fn read_magic_bytes(path: &Path) -> SisterResult<[u8; 4]> {
 /// - "ATIM" (0x4154494D) → Time
 /// - "ACON" (0x41434F4E) → Contract
 ///
-/// Returns None for JSON-based formats (Identity) or unknown formats.
+/// Returns None for JSON-based formats (Identity) or unknown formats;
+/// use `identify_sister_by_json` for those.
 pub fn identify_sister_by_magic(magic: &[u8; 4]) -> Option<SisterType> {
     match magic {
         b"AMEM" => Some(SisterType::Memory),
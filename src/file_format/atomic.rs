@@ -0,0 +1,170 @@
+//! Crash-safe file writes.
+//!
+//! Every sister write goes temp file → fsync → rename → fsync parent
+//! directory. A crash at any point leaves either the old file or the new
+//! file on disk, never a half-written one.
+
+use crate::errors::{SisterError, SisterResult};
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Atomically replace `path` with `bytes`.
+pub fn atomic_write(path: &Path, bytes: &[u8]) -> SisterResult<()> {
+    let mut writer = AtomicFileWriter::create(path)?;
+    writer.write_all(bytes)?;
+    writer.commit()
+}
+
+/// Guard that writes to a temp file and atomically renames on `commit()`.
+///
+/// Dropping the guard without committing removes the temp file and
+/// leaves the destination untouched.
+///
+/// ```rust,ignore
+/// let mut w = AtomicFileWriter::create(&path)?;
+/// w.write_all(&header)?;
+/// w.write_all(&payload)?;
+/// w.commit()?;
+/// ```
+#[derive(Debug)]
+pub struct AtomicFileWriter {
+    target: PathBuf,
+    temp: PathBuf,
+    file: Option<File>,
+}
+
+impl AtomicFileWriter {
+    /// Start an atomic write to `path`.
+    ///
+    /// The temp file lives in the same directory so the final rename
+    /// never crosses filesystems.
+    pub fn create(path: &Path) -> SisterResult<Self> {
+        let file_name = path
+            .file_name()
+            .ok_or_else(|| {
+                SisterError::invalid_input(format!(
+                    "Atomic write target has no file name: {}",
+                    path.display()
+                ))
+            })?
+            .to_string_lossy();
+        let temp = path.with_file_name(format!(".{}.{}.tmp", file_name, uuid::Uuid::new_v4()));
+        let file = File::create(&temp)?;
+
+        Ok(Self {
+            target: path.to_path_buf(),
+            temp,
+            file: Some(file),
+        })
+    }
+
+    /// Destination path.
+    pub fn target(&self) -> &Path {
+        &self.target
+    }
+
+    /// Flush, fsync, rename over the target, and fsync the parent directory.
+    ///
+    /// If anything before the rename fails, the temp file is removed.
+    pub fn commit(mut self) -> SisterResult<()> {
+        if let Err(e) = self.persist() {
+            let _ = std::fs::remove_file(&self.temp);
+            return Err(e.into());
+        }
+        sync_parent_dir(&self.target)
+    }
+
+    fn persist(&mut self) -> std::io::Result<()> {
+        if let Some(mut file) = self.file.take() {
+            file.flush()?;
+            file.sync_all()?;
+        }
+        std::fs::rename(&self.temp, &self.target)
+    }
+
+    fn file(&mut self) -> std::io::Result<&mut File> {
+        self.file
+            .as_mut()
+            .ok_or_else(|| std::io::Error::other("atomic writer already committed"))
+    }
+}
+
+impl Write for AtomicFileWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.file()?.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file()?.flush()
+    }
+}
+
+impl Drop for AtomicFileWriter {
+    fn drop(&mut self) {
+        if self.file.take().is_some() {
+            let _ = std::fs::remove_file(&self.temp);
+        }
+    }
+}
+
+/// fsync the directory containing `path` so the rename itself is durable.
+pub fn sync_parent_dir(path: &Path) -> SisterResult<()> {
+    #[cfg(unix)]
+    {
+        let parent = match path.parent() {
+            Some(p) if !p.as_os_str().is_empty() => p,
+            _ => Path::new("."),
+        };
+        File::open(parent)?.sync_all()?;
+    }
+    #[cfg(not(unix))]
+    {
+        // Directory handles can't be fsynced portably; rename is already durable on NTFS
+        let _ = path;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn test_atomic_write_replaces() {
+        let dir = TempDir::new("atomic");
+        let path = dir.join("data.amem");
+        atomic_write(&path, b"v1").unwrap();
+        atomic_write(&path, b"v2").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"v2");
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_uncommitted_writer_leaves_target() {
+        let dir = TempDir::new("atomic");
+        let path = dir.join("data.amem");
+        atomic_write(&path, b"original").unwrap();
+        {
+            let mut writer = AtomicFileWriter::create(&path).unwrap();
+            writer.write_all(b"partial").unwrap();
+            // dropped without commit (simulated crash path)
+        }
+        assert_eq!(std::fs::read(&path).unwrap(), b"original");
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_failed_commit_removes_temp() {
+        let dir = TempDir::new("atomic");
+        // Renaming a file over a non-empty directory fails
+        let path = dir.join("data.amem");
+        std::fs::create_dir_all(path.join("occupied")).unwrap();
+
+        let mut writer = AtomicFileWriter::create(&path).unwrap();
+        writer.write_all(b"data").unwrap();
+        assert!(writer.commit().is_err());
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+    }
+}
@@ -0,0 +1,513 @@
+//! Uniform backup and restore for sister data paths.
+//!
+//! `BackupManager` copies every path in a `SisterConfig` (the primary
+//! `data_path` plus each named `data_paths` entry) into a timestamped
+//! backup directory with a JSON manifest:
+//!
+//! ```text
+//! <backup_root>/
+//!   memory-20260101T120000123Z/
+//!     manifest.json
+//!     data/primary/brain.amem
+//!     data/receipts/2026/01/r1.json
+//! ```
+//!
+//! The manifest records sister type, format version, and a BLAKE3
+//! checksum per file, so `verify` can detect a damaged backup before
+//! `restore` copies it back.
+
+use super::atomic_write;
+use super::bundle::is_contained;
+use crate::errors::{ErrorCode, SisterError, SisterResult};
+use crate::sister::SisterConfig;
+use crate::types::{SisterType, Version};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Source name used for `SisterConfig::data_path`.
+pub const PRIMARY_SOURCE: &str = "primary";
+
+const MANIFEST_FILE: &str = "manifest.json";
+const DATA_DIR: &str = "data";
+
+/// One data path captured in a backup.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupSource {
+    /// "primary" or the `data_paths` key
+    pub name: String,
+
+    /// Where the data lived when the backup was taken
+    pub original_path: PathBuf,
+
+    /// Whether the source was a directory
+    pub is_dir: bool,
+}
+
+/// One file stored in a backup.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupFile {
+    /// Source this file belongs to
+    pub source: String,
+
+    /// Path relative to the source (the file name for single-file sources)
+    pub relative_path: PathBuf,
+
+    /// Size in bytes
+    pub size: u64,
+
+    /// BLAKE3 checksum (hex)
+    pub checksum: String,
+}
+
+/// Backup manifest, stored as `manifest.json` in each backup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// Backup ID (also the directory name)
+    pub id: String,
+
+    /// Sister that owns the data
+    pub sister_type: SisterType,
+
+    /// Sister format version at backup time
+    pub version: Version,
+
+    /// When the backup was taken
+    pub created_at: DateTime<Utc>,
+
+    /// Captured data paths
+    pub sources: Vec<BackupSource>,
+
+    /// Captured files
+    pub files: Vec<BackupFile>,
+}
+
+impl BackupManifest {
+    /// Total size of all captured files.
+    pub fn total_size(&self) -> u64 {
+        self.files.iter().map(|f| f.size).sum()
+    }
+
+    fn source(&self, name: &str) -> Option<&BackupSource> {
+        self.sources.iter().find(|s| s.name == name)
+    }
+}
+
+/// Result of verifying a backup against its manifest.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BackupVerification {
+    /// Files checked
+    pub checked: usize,
+
+    /// Files whose checksum or size did not match
+    pub corrupted: Vec<PathBuf>,
+
+    /// Files listed in the manifest but absent from the backup
+    pub missing: Vec<PathBuf>,
+}
+
+impl BackupVerification {
+    /// Whether every file matched the manifest.
+    pub fn is_valid(&self) -> bool {
+        self.corrupted.is_empty() && self.missing.is_empty()
+    }
+}
+
+/// Creates, lists, verifies, and restores backups for one sister.
+#[derive(Debug, Clone)]
+pub struct BackupManager {
+    root: PathBuf,
+    sister_type: SisterType,
+    version: Version,
+}
+
+impl BackupManager {
+    /// Manage backups under `root` for a sister at format `version`.
+    pub fn new(root: impl Into<PathBuf>, sister_type: SisterType, version: Version) -> Self {
+        Self {
+            root: root.into(),
+            sister_type,
+            version,
+        }
+    }
+
+    /// Backup root directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Directory of the backup with this ID.
+    pub fn backup_dir(&self, id: &str) -> PathBuf {
+        self.root.join(id)
+    }
+
+    /// Back up every existing data path in `config`.
+    ///
+    /// Paths that do not exist yet are skipped; a config with nothing on
+    /// disk is an error. The manifest is written last, so a backup
+    /// without one is incomplete and ignored by `list`.
+    pub fn backup(&self, config: &SisterConfig) -> SisterResult<BackupManifest> {
+        let mut named: Vec<(String, PathBuf)> = config
+            .data_paths
+            .iter()
+            .map(|(name, path)| (name.clone(), path.clone()))
+            .collect();
+        named.sort();
+        if let Some(primary) = &config.data_path {
+            named.insert(0, (PRIMARY_SOURCE.to_string(), primary.clone()));
+        }
+
+        let created_at = Utc::now();
+        let id = format!(
+            "{}-{}",
+            self.sister_type,
+            created_at.format("%Y%m%dT%H%M%S%3fZ")
+        );
+        let dir = self.backup_dir(&id);
+        if dir.exists() {
+            return Err(SisterError::new(
+                ErrorCode::AlreadyExists,
+                format!("Backup {} already exists", id),
+            ));
+        }
+
+        let mut sources = Vec::new();
+        let mut files = Vec::new();
+        for (name, path) in named {
+            if !path.exists() {
+                continue;
+            }
+            validate_component("source", &name)?;
+            let is_dir = path.is_dir();
+            let relative_paths = if is_dir {
+                collect_files(&path)?
+            } else {
+                vec![PathBuf::from(path.file_name().unwrap_or_default())]
+            };
+
+            for relative_path in relative_paths {
+                let from = if is_dir {
+                    path.join(&relative_path)
+                } else {
+                    path.clone()
+                };
+                let bytes = std::fs::read(&from)?;
+                let to = dir.join(DATA_DIR).join(&name).join(&relative_path);
+                if let Some(parent) = to.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                atomic_write(&to, &bytes)?;
+                files.push(BackupFile {
+                    source: name.clone(),
+                    relative_path,
+                    size: bytes.len() as u64,
+                    checksum: blake3::hash(&bytes).to_hex().to_string(),
+                });
+            }
+            sources.push(BackupSource {
+                name,
+                original_path: path,
+                is_dir,
+            });
+        }
+
+        if sources.is_empty() {
+            std::fs::remove_dir_all(&dir).ok();
+            return Err(SisterError::invalid_input(
+                "Nothing to back up: no configured data path exists",
+            ));
+        }
+
+        let manifest = BackupManifest {
+            id,
+            sister_type: self.sister_type,
+            version: self.version.clone(),
+            created_at,
+            sources,
+            files,
+        };
+        std::fs::create_dir_all(&dir)?;
+        atomic_write(
+            &dir.join(MANIFEST_FILE),
+            &serde_json::to_vec_pretty(&manifest)?,
+        )?;
+        Ok(manifest)
+    }
+
+    /// Complete backups for this sister, newest first.
+    pub fn list(&self) -> SisterResult<Vec<BackupManifest>> {
+        let entries = match std::fs::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e.into()),
+        };
+        let mut manifests: Vec<_> = entries
+            .filter_map(|e| e.ok())
+            .filter_map(|e| read_manifest(&e.path().join(MANIFEST_FILE)).ok())
+            .filter(|m| m.sister_type == self.sister_type)
+            .collect();
+        manifests.sort_by_key(|m| std::cmp::Reverse(m.created_at));
+        Ok(manifests)
+    }
+
+    /// Load the manifest of a backup.
+    ///
+    /// Fails with `InvalidInput` if `id` or any path in the manifest
+    /// could point outside the backup (absolute, or containing `..`).
+    pub fn manifest(&self, id: &str) -> SisterResult<BackupManifest> {
+        validate_component("backup id", id)?;
+        let path = self.backup_dir(id).join(MANIFEST_FILE);
+        if !path.exists() {
+            return Err(SisterError::not_found(format!("Backup {}", id)));
+        }
+        let manifest = read_manifest(&path)?;
+        for source in &manifest.sources {
+            validate_component("source", &source.name)?;
+        }
+        for file in &manifest.files {
+            validate_component("source", &file.source)?;
+            if !is_contained(&file.relative_path) {
+                return Err(SisterError::invalid_input(format!(
+                    "Invalid backup file path: {:?} (must be a relative path without \"..\")",
+                    file.relative_path
+                ))
+                .with_context("backup_id", id));
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// Check every file in a backup against the manifest checksums.
+    pub fn verify(&self, id: &str) -> SisterResult<BackupVerification> {
+        let manifest = self.manifest(id)?;
+        let dir = self.backup_dir(id);
+        let mut report = BackupVerification::default();
+
+        for file in &manifest.files {
+            let path = dir
+                .join(DATA_DIR)
+                .join(&file.source)
+                .join(&file.relative_path);
+            report.checked += 1;
+            match std::fs::read(&path) {
+                Ok(bytes) => {
+                    if bytes.len() as u64 != file.size
+                        || blake3::hash(&bytes).to_hex().as_str() != file.checksum
+                    {
+                        report.corrupted.push(path);
+                    }
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => report.missing.push(path),
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(report)
+    }
+
+    /// Verify a backup, then copy it back to the paths in `config`.
+    ///
+    /// Each source is restored to the path configured under the same name
+    /// (falling back to its original path). Files are written atomically;
+    /// files in a restored directory that are not in the backup are left
+    /// in place. Fails with `ChecksumMismatch` without touching anything if
+    /// the backup does not verify.
+    pub fn restore(&self, id: &str, config: &SisterConfig) -> SisterResult<BackupManifest> {
+        let manifest = self.manifest(id)?;
+        if manifest.sister_type != self.sister_type {
+            return Err(SisterError::invalid_input(format!(
+                "Backup {} belongs to {}, not {}",
+                id, manifest.sister_type, self.sister_type
+            )));
+        }
+
+        let verification = self.verify(id)?;
+        if !verification.is_valid() {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                format!("Backup {} failed verification", id),
+            )
+            .with_context("corrupted", verification.corrupted.len())
+            .with_context("missing", verification.missing.len()));
+        }
+
+        let dir = self.backup_dir(id);
+        for file in &manifest.files {
+            let source = manifest.source(&file.source).ok_or_else(|| {
+                SisterError::new(
+                    ErrorCode::VersionMismatch,
+                    format!("Backup manifest has no source named {}", file.source),
+                )
+            })?;
+            let configured = if source.name == PRIMARY_SOURCE {
+                config.data_path.as_ref()
+            } else {
+                config.get_path(&source.name)
+            };
+            let target_root = configured.unwrap_or(&source.original_path);
+            let target = if source.is_dir {
+                target_root.join(&file.relative_path)
+            } else {
+                target_root.clone()
+            };
+
+            let bytes = std::fs::read(
+                dir.join(DATA_DIR)
+                    .join(&file.source)
+                    .join(&file.relative_path),
+            )?;
+            if let Some(parent) = target.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            atomic_write(&target, &bytes)?;
+        }
+        Ok(manifest)
+    }
+}
+
+/// `value` must be a single relative path component.
+fn validate_component(what: &str, value: &str) -> SisterResult<()> {
+    let path = Path::new(value);
+    if is_contained(path) && path.components().count() == 1 {
+        Ok(())
+    } else {
+        Err(SisterError::invalid_input(format!(
+            "Invalid {}: {:?} (must be a single path component)",
+            what, value
+        )))
+    }
+}
+
+fn read_manifest(path: &Path) -> SisterResult<BackupManifest> {
+    Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+}
+
+/// All regular files under `root`, relative to it, in sorted order.
+fn collect_files(root: &Path) -> SisterResult<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else if let Ok(relative) = path.strip_prefix(root) {
+                files.push(relative.to_path_buf());
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn test_backup_and_restore() {
+        let dir = TempDir::new("backup");
+        let data = dir.join("brain.amem");
+        let receipts = dir.join("receipts");
+        std::fs::write(&data, b"memory v1").unwrap();
+        std::fs::create_dir_all(receipts.join("2026")).unwrap();
+        std::fs::write(receipts.join("2026").join("r1.json"), b"{}").unwrap();
+
+        let config = SisterConfig::new(&data).add_path("receipts", &receipts);
+        let manager = BackupManager::new(
+            dir.join("backups"),
+            SisterType::Memory,
+            Version::new(1, 0, 0),
+        );
+        let manifest = manager.backup(&config).unwrap();
+        assert_eq!(manifest.sources.len(), 2);
+        assert_eq!(manifest.files.len(), 2);
+        assert!(manager.verify(&manifest.id).unwrap().is_valid());
+        assert_eq!(manager.list().unwrap().len(), 1);
+
+        std::fs::write(&data, b"memory v2 (bad)").unwrap();
+        std::fs::remove_dir_all(&receipts).unwrap();
+        manager.restore(&manifest.id, &config).unwrap();
+        assert_eq!(std::fs::read(&data).unwrap(), b"memory v1");
+        assert_eq!(
+            std::fs::read(receipts.join("2026").join("r1.json")).unwrap(),
+            b"{}"
+        );
+    }
+
+    #[test]
+    fn test_corrupted_backup_not_restored() {
+        let dir = TempDir::new("backup");
+        let data = dir.join("graph.acb");
+        std::fs::write(&data, b"graph").unwrap();
+
+        let config = SisterConfig::new(&data);
+        let manager = BackupManager::new(
+            dir.join("backups"),
+            SisterType::Codebase,
+            Version::new(1, 0, 0),
+        );
+        let manifest = manager.backup(&config).unwrap();
+        let stored = manager
+            .backup_dir(&manifest.id)
+            .join(DATA_DIR)
+            .join(PRIMARY_SOURCE)
+            .join("graph.acb");
+        std::fs::write(&stored, b"grAph").unwrap();
+
+        let report = manager.verify(&manifest.id).unwrap();
+        assert_eq!(report.corrupted, vec![stored]);
+
+        std::fs::write(&data, b"current").unwrap();
+        let err = manager.restore(&manifest.id, &config).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ChecksumMismatch);
+        assert_eq!(std::fs::read(&data).unwrap(), b"current");
+    }
+
+    #[test]
+    fn test_tampered_manifest_rejected() {
+        let dir = TempDir::new("backup");
+        let data = dir.join("graph.acb");
+        std::fs::write(&data, b"graph").unwrap();
+        let config = SisterConfig::new(&data);
+        let manager = BackupManager::new(
+            dir.join("backups"),
+            SisterType::Codebase,
+            Version::new(1, 0, 0),
+        );
+        let manifest = manager.backup(&config).unwrap();
+        let manifest_path = manager.backup_dir(&manifest.id).join(MANIFEST_FILE);
+
+        let mut tampered = manifest.clone();
+        tampered.files[0].relative_path = PathBuf::from("../../escaped");
+        std::fs::write(&manifest_path, serde_json::to_vec(&tampered).unwrap()).unwrap();
+        let err = manager.restore(&manifest.id, &config).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidInput);
+
+        let mut tampered = manifest.clone();
+        tampered.files[0].source = "..".into();
+        std::fs::write(&manifest_path, serde_json::to_vec(&tampered).unwrap()).unwrap();
+        assert!(manager.verify(&manifest.id).is_err());
+
+        assert_eq!(
+            manager.manifest("../backups").unwrap_err().code,
+            ErrorCode::InvalidInput
+        );
+        assert!(!dir.join("escaped").exists());
+    }
+
+    #[test]
+    fn test_backup_requires_existing_data() {
+        let dir = TempDir::new("backup");
+        let manager = BackupManager::new(dir.path(), SisterType::Time, Version::new(1, 0, 0));
+        let err = manager
+            .backup(&SisterConfig::new(dir.join("missing.atim")))
+            .unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidInput);
+        assert!(manager.list().unwrap().is_empty());
+        assert_eq!(
+            manager.manifest("nope").unwrap_err().code,
+            ErrorCode::NotFound
+        );
+    }
+}
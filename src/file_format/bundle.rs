@@ -0,0 +1,477 @@
+//! `.abundle` multi-sister archives.
+//!
+//! One file holding "everything about this project": the .amem, .acb,
+//! .aid, ... files of several sisters, each with its `FileInfo` and a
+//! BLAKE3 checksum.
+//!
+//! # Layout (little-endian)
+//!
+//! ```text
+//! 0       4     magic "ABND"
+//! 4       1     bundle layout version (currently 1)
+//! 5       3     reserved (zero)
+//! 8       ...   entry data, back to back
+//! ...     N     manifest (JSON `BundleManifest`)
+//! end-12  8     manifest length N (u64)
+//! end-4   4     magic "ABND"
+//! ```
+//!
+//! The manifest is written last so entries can be streamed in without
+//! knowing the final set up front; readers find it via the trailer.
+
+use super::{AtomicFileWriter, FileFormatReader, FileFormatWriter, FileInfo};
+use crate::errors::{ErrorCode, SisterError, SisterResult};
+use crate::types::SisterType;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Component, Path, PathBuf};
+
+/// Bundle file extension.
+pub const BUNDLE_EXTENSION: &str = "abundle";
+
+/// Bundle magic bytes (start and end of file).
+pub const BUNDLE_MAGIC: [u8; 4] = *b"ABND";
+
+/// Current bundle layout version.
+pub const BUNDLE_LAYOUT_VERSION: u8 = 1;
+
+const BUNDLE_HEADER_SIZE: u64 = 8;
+const BUNDLE_TRAILER_SIZE: u64 = 12;
+
+/// One file stored in a bundle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleEntry {
+    /// Relative path inside the bundle (e.g., "memory/brain.amem")
+    pub name: String,
+
+    /// Owning sister, if known
+    pub sister_type: Option<SisterType>,
+
+    /// File info from the owning sister's reader, if known
+    pub info: Option<FileInfo>,
+
+    /// Offset of the entry data in the bundle
+    pub offset: u64,
+
+    /// Entry length in bytes
+    pub len: u64,
+
+    /// BLAKE3 checksum of the entry data (hex)
+    pub checksum: String,
+}
+
+/// Bundle table of contents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleManifest {
+    /// Layout version the bundle was written with
+    pub layout_version: u8,
+
+    /// When the bundle was written
+    pub created_at: DateTime<Utc>,
+
+    /// Stored files, in write order
+    pub entries: Vec<BundleEntry>,
+}
+
+impl BundleManifest {
+    /// Look up an entry by name.
+    pub fn entry(&self, name: &str) -> Option<&BundleEntry> {
+        self.entries.iter().find(|e| e.name == name)
+    }
+
+    /// Entries belonging to one sister.
+    pub fn entries_for(&self, sister_type: SisterType) -> Vec<&BundleEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.sister_type == Some(sister_type))
+            .collect()
+    }
+
+    /// Sisters represented in the bundle.
+    pub fn sister_types(&self) -> Vec<SisterType> {
+        let mut types: Vec<_> = self.entries.iter().filter_map(|e| e.sister_type).collect();
+        types.sort_by_key(|t| t.to_byte());
+        types.dedup();
+        types
+    }
+}
+
+/// Writes an `.abundle` archive. The file appears atomically on `finish()`.
+///
+/// ```rust,ignore
+/// let mut bundle = BundleWriter::create(Path::new("project.abundle"))?;
+/// bundle.add_file::<MemoryFile>("memory/brain.amem", &memory_path)?;
+/// bundle.add_file::<CodebaseGraph>("codebase/graph.acb", &graph_path)?;
+/// let manifest = bundle.finish()?;
+/// ```
+#[derive(Debug)]
+pub struct BundleWriter {
+    out: AtomicFileWriter,
+    offset: u64,
+    entries: Vec<BundleEntry>,
+}
+
+impl BundleWriter {
+    /// Start writing a bundle at `path`.
+    pub fn create(path: &Path) -> SisterResult<Self> {
+        let mut out = AtomicFileWriter::create(path)?;
+        out.write_all(&BUNDLE_MAGIC)?;
+        out.write_all(&[BUNDLE_LAYOUT_VERSION, 0, 0, 0])?;
+        Ok(Self {
+            out,
+            offset: BUNDLE_HEADER_SIZE,
+            entries: Vec::new(),
+        })
+    }
+
+    /// Add a sister file, using `R::can_read` for its `FileInfo`.
+    pub fn add_file<R: FileFormatReader>(
+        &mut self,
+        name: &str,
+        path: &Path,
+    ) -> SisterResult<&BundleEntry> {
+        let info = R::can_read(path)?;
+        let bytes = std::fs::read(path)?;
+        self.add_bytes(name, &bytes, Some(info))
+    }
+
+    /// Add an in-memory sister value, serialized with `to_bytes()`.
+    pub fn add_value<W: FileFormatWriter>(
+        &mut self,
+        name: &str,
+        value: &W,
+        info: Option<FileInfo>,
+    ) -> SisterResult<&BundleEntry> {
+        self.add_bytes(name, &value.to_bytes()?, info)
+    }
+
+    /// Add raw bytes.
+    pub fn add_bytes(
+        &mut self,
+        name: &str,
+        bytes: &[u8],
+        info: Option<FileInfo>,
+    ) -> SisterResult<&BundleEntry> {
+        validate_name(name)?;
+        if self.entries.iter().any(|e| e.name == name) {
+            return Err(SisterError::new(
+                ErrorCode::AlreadyExists,
+                format!("Bundle already contains {}", name),
+            ));
+        }
+
+        self.out.write_all(bytes)?;
+        self.entries.push(BundleEntry {
+            name: name.to_string(),
+            sister_type: info.as_ref().map(|i| i.sister_type),
+            info,
+            offset: self.offset,
+            len: bytes.len() as u64,
+            checksum: blake3::hash(bytes).to_hex().to_string(),
+        });
+        self.offset += bytes.len() as u64;
+        Ok(self.entries.last().expect("entry just pushed"))
+    }
+
+    /// Entries added so far.
+    pub fn entries(&self) -> &[BundleEntry] {
+        &self.entries
+    }
+
+    /// Write the manifest and trailer and commit the file.
+    pub fn finish(mut self) -> SisterResult<BundleManifest> {
+        let manifest = BundleManifest {
+            layout_version: BUNDLE_LAYOUT_VERSION,
+            created_at: Utc::now(),
+            entries: std::mem::take(&mut self.entries),
+        };
+        let json = serde_json::to_vec(&manifest)?;
+        self.out.write_all(&json)?;
+        self.out.write_all(&(json.len() as u64).to_le_bytes())?;
+        self.out.write_all(&BUNDLE_MAGIC)?;
+        self.out.commit()?;
+        Ok(manifest)
+    }
+}
+
+/// Reads an `.abundle` archive. Entry data is read lazily.
+#[derive(Debug)]
+pub struct BundleReader {
+    file: File,
+    manifest: BundleManifest,
+}
+
+impl BundleReader {
+    /// Open a bundle and load its manifest.
+    pub fn open(path: &Path) -> SisterResult<Self> {
+        let mut file = File::open(path)?;
+        let size = file.metadata()?.len();
+        if size < BUNDLE_HEADER_SIZE + BUNDLE_TRAILER_SIZE {
+            return Err(not_a_bundle(path, "file too small"));
+        }
+
+        let mut header = [0u8; BUNDLE_HEADER_SIZE as usize];
+        file.read_exact(&mut header)?;
+        if header[0..4] != BUNDLE_MAGIC {
+            return Err(not_a_bundle(path, "bad magic"));
+        }
+        if header[4] > BUNDLE_LAYOUT_VERSION {
+            return Err(SisterError::new(
+                ErrorCode::VersionMismatch,
+                format!(
+                    "Bundle layout v{} is newer than supported v{}",
+                    header[4], BUNDLE_LAYOUT_VERSION
+                ),
+            ));
+        }
+
+        let mut trailer = [0u8; BUNDLE_TRAILER_SIZE as usize];
+        file.seek(SeekFrom::End(-(BUNDLE_TRAILER_SIZE as i64)))?;
+        file.read_exact(&mut trailer)?;
+        if trailer[8..12] != BUNDLE_MAGIC {
+            return Err(not_a_bundle(path, "missing trailer (truncated?)"));
+        }
+        let mut len = [0u8; 8];
+        len.copy_from_slice(&trailer[0..8]);
+        let manifest_len = u64::from_le_bytes(len);
+        if manifest_len > size - BUNDLE_HEADER_SIZE - BUNDLE_TRAILER_SIZE {
+            return Err(not_a_bundle(path, "manifest length out of range"));
+        }
+
+        let mut json = vec![0u8; manifest_len as usize];
+        file.seek(SeekFrom::Start(size - BUNDLE_TRAILER_SIZE - manifest_len))?;
+        file.read_exact(&mut json)?;
+        let manifest: BundleManifest = serde_json::from_slice(&json)?;
+
+        // Entries must lie in the data region, so a damaged manifest
+        // cannot make `read_entry` allocate or seek past the file
+        let data_end = size - BUNDLE_TRAILER_SIZE - manifest_len;
+        for entry in &manifest.entries {
+            let in_range = entry.offset >= BUNDLE_HEADER_SIZE
+                && entry
+                    .offset
+                    .checked_add(entry.len)
+                    .is_some_and(|end| end <= data_end);
+            if !in_range {
+                return Err(not_a_bundle(
+                    path,
+                    &format!("entry {} out of range", entry.name),
+                ));
+            }
+        }
+
+        Ok(Self { file, manifest })
+    }
+
+    /// The bundle manifest.
+    pub fn manifest(&self) -> &BundleManifest {
+        &self.manifest
+    }
+
+    /// Read and checksum-verify one entry.
+    pub fn read_entry(&mut self, name: &str) -> SisterResult<Vec<u8>> {
+        let entry = self
+            .manifest
+            .entry(name)
+            .ok_or_else(|| SisterError::not_found(format!("Bundle entry {}", name)))?
+            .clone();
+        let mut bytes = vec![0u8; entry.len as usize];
+        self.file.seek(SeekFrom::Start(entry.offset))?;
+        self.file.read_exact(&mut bytes)?;
+        if blake3::hash(&bytes).to_hex().as_str() != entry.checksum {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                format!("Bundle entry {} failed checksum verification", name),
+            )
+            .with_context("entry", name));
+        }
+        Ok(bytes)
+    }
+
+    /// Extract one entry to `dest` (atomically).
+    pub fn extract(&mut self, name: &str, dest: &Path) -> SisterResult<()> {
+        let bytes = self.read_entry(name)?;
+        if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut out = AtomicFileWriter::create(dest)?;
+        out.write_all(&bytes)?;
+        out.commit()
+    }
+
+    /// Extract every entry under `dir`, keeping entry names as relative paths.
+    pub fn extract_all(&mut self, dir: &Path) -> SisterResult<Vec<PathBuf>> {
+        let names: Vec<_> = self
+            .manifest
+            .entries
+            .iter()
+            .map(|e| e.name.clone())
+            .collect();
+        let mut written = Vec::with_capacity(names.len());
+        for name in names {
+            validate_name(&name)?;
+            let dest = dir.join(&name);
+            self.extract(&name, &dest)?;
+            written.push(dest);
+        }
+        Ok(written)
+    }
+
+    /// Load an entry with the owning sister's reader.
+    ///
+    /// `FileFormatReader` works on paths, so the entry is extracted to a
+    /// temporary file that is removed afterwards.
+    pub fn read_as<R: FileFormatReader>(&mut self, name: &str) -> SisterResult<R> {
+        let file_name = Path::new(name).file_name().unwrap_or_default();
+        let dir = std::env::temp_dir().join(format!("abundle_{}", uuid::Uuid::new_v4()));
+        let temp = dir.join(file_name);
+        let result = self.extract(name, &temp).and_then(|_| R::read_file(&temp));
+        std::fs::remove_dir_all(&dir).ok();
+        result
+    }
+
+    /// Names of entries whose data fails checksum verification.
+    pub fn verify(&mut self) -> SisterResult<Vec<String>> {
+        let names: Vec<_> = self
+            .manifest
+            .entries
+            .iter()
+            .map(|e| e.name.clone())
+            .collect();
+        let mut bad = Vec::new();
+        for name in names {
+            match self.read_entry(&name) {
+                Ok(_) => {}
+                Err(e) if e.code == ErrorCode::ChecksumMismatch => bad.push(name),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(bad)
+    }
+}
+
+/// Whether `path` is relative and stays inside whatever directory it
+/// is joined to (no root, prefix, or `..` components).
+pub(super) fn is_contained(path: &Path) -> bool {
+    !path.as_os_str().is_empty()
+        && path
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
+/// Entry names must be relative and stay inside the extraction directory.
+fn validate_name(name: &str) -> SisterResult<()> {
+    if is_contained(Path::new(name)) {
+        Ok(())
+    } else {
+        Err(SisterError::invalid_input(format!(
+            "Invalid bundle entry name: {:?} (must be a relative path without \"..\")",
+            name
+        )))
+    }
+}
+
+fn not_a_bundle(path: &Path, reason: &str) -> SisterError {
+    SisterError::new(
+        ErrorCode::InvalidInput,
+        format!("{} is not a valid bundle: {}", path.display(), reason),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_format::ContainerFile;
+    use crate::test_support::TempDir;
+    use crate::types::Version;
+
+    #[test]
+    fn test_bundle_roundtrip() {
+        let dir = TempDir::new("bundle");
+        let memory = ContainerFile::new(SisterType::Memory, Version::new(1, 0, 0), b"mem".into());
+        let memory_path = dir.join("brain.amem");
+        memory.write_file(&memory_path).unwrap();
+
+        let bundle_path = dir.join("project.abundle");
+        let mut writer = BundleWriter::create(&bundle_path).unwrap();
+        writer
+            .add_file::<ContainerFile>("memory/brain.amem", &memory_path)
+            .unwrap();
+        writer
+            .add_bytes("notes/readme.txt", b"hello", None)
+            .unwrap();
+        assert!(writer.add_bytes("notes/readme.txt", b"x", None).is_err());
+        writer.finish().unwrap();
+
+        let mut reader = BundleReader::open(&bundle_path).unwrap();
+        assert_eq!(reader.manifest().entries.len(), 2);
+        assert_eq!(reader.manifest().sister_types(), vec![SisterType::Memory]);
+        assert_eq!(reader.read_entry("notes/readme.txt").unwrap(), b"hello");
+
+        let loaded: ContainerFile = reader.read_as("memory/brain.amem").unwrap();
+        assert_eq!(loaded, memory);
+
+        let out = dir.join("out");
+        let written = reader.extract_all(&out).unwrap();
+        assert_eq!(written.len(), 2);
+        assert_eq!(
+            std::fs::read(out.join("notes/readme.txt")).unwrap(),
+            b"hello"
+        );
+    }
+
+    #[test]
+    fn test_bundle_detects_damage() {
+        let dir = TempDir::new("bundle");
+        let bundle_path = dir.join("damaged.abundle");
+        let mut writer = BundleWriter::create(&bundle_path).unwrap();
+        writer.add_bytes("a.bin", &[1u8; 32], None).unwrap();
+        writer.add_bytes("b.bin", &[2u8; 32], None).unwrap();
+        writer.finish().unwrap();
+
+        let mut bytes = std::fs::read(&bundle_path).unwrap();
+        bytes[BUNDLE_HEADER_SIZE as usize + 40] ^= 0xFF; // inside b.bin
+        std::fs::write(&bundle_path, &bytes).unwrap();
+
+        let mut reader = BundleReader::open(&bundle_path).unwrap();
+        assert_eq!(reader.verify().unwrap(), vec!["b.bin".to_string()]);
+        assert_eq!(
+            reader.read_entry("b.bin").unwrap_err().code,
+            ErrorCode::ChecksumMismatch
+        );
+
+        // A manifest entry pointing past the data region
+        let manifest_len = u64::from_le_bytes(bytes[bytes.len() - 12..][..8].try_into().unwrap());
+        let manifest_start = bytes.len() - 12 - manifest_len as usize;
+        let mut manifest: BundleManifest =
+            serde_json::from_slice(&bytes[manifest_start..bytes.len() - 12]).unwrap();
+        manifest.entries[1].len = u64::MAX - 8;
+        let json = serde_json::to_vec(&manifest).unwrap();
+        let mut forged = bytes[..manifest_start].to_vec();
+        forged.extend_from_slice(&json);
+        forged.extend_from_slice(&(json.len() as u64).to_le_bytes());
+        forged.extend_from_slice(&BUNDLE_MAGIC);
+        std::fs::write(&bundle_path, &forged).unwrap();
+        assert_eq!(
+            BundleReader::open(&bundle_path).unwrap_err().code,
+            ErrorCode::InvalidInput
+        );
+
+        std::fs::write(&bundle_path, &bytes[..bytes.len() - 4]).unwrap();
+        assert_eq!(
+            BundleReader::open(&bundle_path).unwrap_err().code,
+            ErrorCode::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_bundle_rejects_escaping_names() {
+        let dir = TempDir::new("bundle");
+        let mut writer = BundleWriter::create(&dir.join("x.abundle")).unwrap();
+        assert!(writer.add_bytes("../evil", b"", None).is_err());
+        assert!(writer.add_bytes("/etc/passwd", b"", None).is_err());
+        assert!(writer.add_bytes("ok/file", b"", None).is_ok());
+    }
+}
@@ -0,0 +1,310 @@
+//! Optional compression for sister files.
+//!
+//! `CompressionCodec` is always available so `FileInfo` has a stable
+//! shape, but the Zstd and Lz4 codecs only work with the `compression`
+//! cargo feature enabled. Without it they return `NotImplemented`.
+//!
+//! Compressed output is wrapped in a small frame so readers can
+//! auto-detect it:
+//!
+//! ```text
+//! offset  size  field
+//! 0       4     magic "ACMP"
+//! 4       1     codec flag (CompressionCodec::flag)
+//! 5       8     uncompressed length (u64, little-endian)
+//! 13      ...   compressed bytes
+//! ```
+//!
+//! Bytes without the frame magic are treated as uncompressed, so
+//! existing files keep reading unchanged.
+
+use super::{atomic_write, FileFormatWriter};
+use crate::errors::{ErrorCode, SisterError, SisterResult};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Magic bytes that mark a compressed frame.
+pub const COMPRESSED_MAGIC: [u8; 4] = *b"ACMP";
+
+/// Size of the compressed frame prefix in bytes.
+pub const COMPRESSED_FRAME_HEADER_SIZE: usize = 13;
+
+/// Default zstd level (good ratio, fast enough for large graphs).
+#[cfg(feature = "compression")]
+const ZSTD_LEVEL: i32 = 3;
+
+/// Most an LZ4 block can expand: each length byte adds at most 255.
+#[cfg(feature = "compression")]
+const LZ4_MAX_RATIO: usize = 255;
+
+/// Compression codec for file payloads.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum CompressionCodec {
+    /// Stored as-is
+    #[default]
+    None,
+
+    /// Zstandard (best ratio)
+    Zstd,
+
+    /// LZ4 (fastest)
+    Lz4,
+}
+
+impl CompressionCodec {
+    /// Byte flag stored in frame and container headers.
+    pub fn flag(&self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Zstd => 1,
+            Self::Lz4 => 2,
+        }
+    }
+
+    /// Codec from its byte flag.
+    pub fn from_flag(flag: u8) -> Option<Self> {
+        match flag {
+            0 => Some(Self::None),
+            1 => Some(Self::Zstd),
+            2 => Some(Self::Lz4),
+            _ => None,
+        }
+    }
+
+    /// Whether this codec can be used in the current build.
+    pub fn is_available(&self) -> bool {
+        matches!(self, Self::None) || cfg!(feature = "compression")
+    }
+
+    /// Compress raw bytes (no frame).
+    pub fn compress(&self, data: &[u8]) -> SisterResult<Vec<u8>> {
+        match self {
+            Self::None => Ok(data.to_vec()),
+            #[cfg(feature = "compression")]
+            Self::Zstd => zstd::bulk::compress(data, ZSTD_LEVEL).map_err(|e| {
+                SisterError::new(
+                    ErrorCode::Internal,
+                    format!("zstd compression failed: {}", e),
+                )
+            }),
+            #[cfg(feature = "compression")]
+            Self::Lz4 => Ok(lz4_flex::block::compress(data)),
+            #[cfg(not(feature = "compression"))]
+            _ => Err(self.unavailable()),
+        }
+    }
+
+    /// Decompress raw bytes (no frame) of known uncompressed length.
+    ///
+    /// `uncompressed_len` usually comes from the file, so it is never
+    /// trusted as an allocation size: zstd output grows as it is
+    /// decoded, and LZ4 lengths beyond what `data` could expand to are
+    /// rejected up front.
+    pub fn decompress(&self, data: &[u8], uncompressed_len: usize) -> SisterResult<Vec<u8>> {
+        let out = match self {
+            Self::None => data.to_vec(),
+            #[cfg(feature = "compression")]
+            Self::Zstd => {
+                use std::io::Read;
+                let mut out = Vec::new();
+                zstd::stream::read::Decoder::new(data)
+                    .and_then(|decoder| {
+                        decoder
+                            .take((uncompressed_len as u64).saturating_add(1))
+                            .read_to_end(&mut out)
+                    })
+                    .map_err(|e| {
+                        SisterError::new(
+                            ErrorCode::ChecksumMismatch,
+                            format!("zstd decompression failed: {}", e),
+                        )
+                    })?;
+                out
+            }
+            #[cfg(feature = "compression")]
+            Self::Lz4 if uncompressed_len > data.len().saturating_mul(LZ4_MAX_RATIO) => {
+                return Err(SisterError::new(
+                    ErrorCode::ChecksumMismatch,
+                    format!(
+                        "Declared length {} is more than {} LZ4 bytes can hold",
+                        uncompressed_len,
+                        data.len()
+                    ),
+                ));
+            }
+            #[cfg(feature = "compression")]
+            Self::Lz4 => lz4_flex::block::decompress(data, uncompressed_len).map_err(|e| {
+                SisterError::new(
+                    ErrorCode::ChecksumMismatch,
+                    format!("lz4 decompression failed: {}", e),
+                )
+            })?,
+            #[cfg(not(feature = "compression"))]
+            _ => return Err(self.unavailable()),
+        };
+
+        if out.len() != uncompressed_len {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                format!(
+                    "Decompressed length mismatch: expected {}, got {}",
+                    uncompressed_len,
+                    out.len()
+                ),
+            ));
+        }
+        Ok(out)
+    }
+
+    #[cfg(not(feature = "compression"))]
+    fn unavailable(&self) -> SisterError {
+        SisterError::new(
+            ErrorCode::NotImplemented,
+            format!("{} compression requires the `compression` feature", self),
+        )
+    }
+}
+
+impl std::fmt::Display for CompressionCodec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Zstd => write!(f, "zstd"),
+            Self::Lz4 => write!(f, "lz4"),
+        }
+    }
+}
+
+/// Compress bytes into a self-describing frame.
+///
+/// `CompressionCodec::None` returns the input unchanged (no frame).
+pub fn compress_frame(codec: CompressionCodec, data: &[u8]) -> SisterResult<Vec<u8>> {
+    if codec == CompressionCodec::None {
+        return Ok(data.to_vec());
+    }
+
+    let compressed = codec.compress(data)?;
+    let mut out = Vec::with_capacity(COMPRESSED_FRAME_HEADER_SIZE + compressed.len());
+    out.extend_from_slice(&COMPRESSED_MAGIC);
+    out.push(codec.flag());
+    out.extend_from_slice(&(data.len() as u64).to_le_bytes());
+    out.extend_from_slice(&compressed);
+    Ok(out)
+}
+
+/// Detect the codec of possibly-framed bytes without decompressing.
+pub fn detect_compression(data: &[u8]) -> CompressionCodec {
+    if data.len() >= COMPRESSED_FRAME_HEADER_SIZE && data[0..4] == COMPRESSED_MAGIC {
+        CompressionCodec::from_flag(data[4]).unwrap_or(CompressionCodec::None)
+    } else {
+        CompressionCodec::None
+    }
+}
+
+/// Decompress a frame produced by `compress_frame`.
+///
+/// Unframed input is returned unchanged with `CompressionCodec::None`.
+pub fn decompress_frame(data: &[u8]) -> SisterResult<(Vec<u8>, CompressionCodec)> {
+    if data.len() < COMPRESSED_FRAME_HEADER_SIZE || data[0..4] != COMPRESSED_MAGIC {
+        return Ok((data.to_vec(), CompressionCodec::None));
+    }
+
+    let codec = CompressionCodec::from_flag(data[4]).ok_or_else(|| {
+        SisterError::new(
+            ErrorCode::VersionMismatch,
+            format!("Unknown compression codec flag: {}", data[4]),
+        )
+    })?;
+    let mut len = [0u8; 8];
+    len.copy_from_slice(&data[5..13]);
+    let uncompressed_len = u64::from_le_bytes(len) as usize;
+
+    let out = codec.decompress(&data[COMPRESSED_FRAME_HEADER_SIZE..], uncompressed_len)?;
+    Ok((out, codec))
+}
+
+/// Serialize a writer via `to_bytes()` and compress the result.
+pub fn to_compressed_bytes<W: FileFormatWriter + ?Sized>(
+    writer: &W,
+    codec: CompressionCodec,
+) -> SisterResult<Vec<u8>> {
+    compress_frame(codec, &writer.to_bytes()?)
+}
+
+/// Write a compressed file from any `FileFormatWriter`.
+pub fn write_compressed_file<W: FileFormatWriter + ?Sized>(
+    writer: &W,
+    path: &Path,
+    codec: CompressionCodec,
+) -> SisterResult<()> {
+    atomic_write(path, &to_compressed_bytes(writer, codec)?)
+}
+
+/// Read a file and transparently decompress it if framed.
+pub fn read_decompressed_file(path: &Path) -> SisterResult<(Vec<u8>, CompressionCodec)> {
+    decompress_frame(&std::fs::read(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codec_flags() {
+        for codec in [
+            CompressionCodec::None,
+            CompressionCodec::Zstd,
+            CompressionCodec::Lz4,
+        ] {
+            assert_eq!(CompressionCodec::from_flag(codec.flag()), Some(codec));
+        }
+        assert_eq!(CompressionCodec::from_flag(9), None);
+        assert!(CompressionCodec::None.is_available());
+    }
+
+    #[test]
+    fn test_uncompressed_passthrough() {
+        let data = b"AMEM plain file".to_vec();
+        assert_eq!(compress_frame(CompressionCodec::None, &data).unwrap(), data);
+        assert_eq!(detect_compression(&data), CompressionCodec::None);
+        let (out, codec) = decompress_frame(&data).unwrap();
+        assert_eq!(out, data);
+        assert_eq!(codec, CompressionCodec::None);
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_compressed_roundtrip() {
+        let data = b"graph node ".repeat(1000);
+        for codec in [CompressionCodec::Zstd, CompressionCodec::Lz4] {
+            let framed = compress_frame(codec, &data).unwrap();
+            assert!(framed.len() < data.len());
+            assert_eq!(detect_compression(&framed), codec);
+
+            let (out, detected) = decompress_frame(&framed).unwrap();
+            assert_eq!(out, data);
+            assert_eq!(detected, codec);
+        }
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_oversized_length_rejected() {
+        for codec in [CompressionCodec::Zstd, CompressionCodec::Lz4] {
+            let mut frame = compress_frame(codec, b"tiny").unwrap();
+            frame[5..13].copy_from_slice(&(1u64 << 46).to_le_bytes());
+            let err = decompress_frame(&frame).unwrap_err();
+            assert_eq!(err.code, ErrorCode::ChecksumMismatch);
+        }
+    }
+
+    #[cfg(not(feature = "compression"))]
+    #[test]
+    fn test_codec_requires_feature() {
+        let err = compress_frame(CompressionCodec::Zstd, b"data").unwrap_err();
+        assert_eq!(err.code, ErrorCode::NotImplemented);
+    }
+}
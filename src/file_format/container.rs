@@ -0,0 +1,588 @@
+//! Reference container file format.
+//!
+//! New sisters can embed `ContainerFile` instead of designing their own
+//! header. It gives every file the same layout, the same timestamps and
+//! the same BLAKE3 checksum rules, so checksums never differ subtly
+//! between sisters again.
+//!
+//! # Layout (72-byte header, little-endian)
+//!
+//! ```text
+//! offset  size  field
+//! 0       4     magic (sister-chosen, default "ACTR")
+//! 4       1     container layout version (currently 1)
+//! 5       1     sister type byte (SisterType::to_byte)
+//! 6       3     format version (major, minor, patch)
+//! 9       1     flags (low 4 bits: CompressionCodec::flag)
+//! 10      6     reserved (zero)
+//! 16      8     created_at (unix millis, i64)
+//! 24      8     updated_at (unix millis, i64)
+//! 32      8     stored payload length (u64)
+//! 40      32    BLAKE3 hash of the stored payload
+//! 72      ...   payload (a `compress_frame` frame when compressed)
+//! ```
+//!
+//! The checksum covers the bytes as stored, so corruption is caught
+//! before any decompression is attempted.
+
+use super::{
+    compress_frame, decompress_frame, CompressionCodec, FileFormatReader, FileFormatWriter,
+    FileInfo, RecoveryReport, RegionStatus, VersionCompatibility,
+};
+use crate::errors::{ErrorCode, SisterError, SisterResult};
+use crate::types::{SisterType, Version};
+use chrono::{DateTime, TimeZone, Utc};
+use std::path::Path;
+
+/// Size of the container header in bytes.
+pub const CONTAINER_HEADER_SIZE: usize = 72;
+
+/// Current container layout version.
+pub const CONTAINER_LAYOUT_VERSION: u8 = 1;
+
+/// Default magic for sisters that don't pick their own.
+pub const CONTAINER_MAGIC: [u8; 4] = *b"ACTR";
+
+/// Parsed container header (everything except the payload).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerHeader {
+    /// Magic bytes
+    pub magic: [u8; 4],
+
+    /// Container layout version
+    pub layout_version: u8,
+
+    /// Which sister owns this file
+    pub sister_type: SisterType,
+
+    /// Format version of the payload
+    pub version: Version,
+
+    /// Header flags
+    pub flags: u8,
+
+    /// When the file was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the file was last modified
+    pub updated_at: DateTime<Utc>,
+
+    /// Payload length in bytes
+    pub payload_len: u64,
+
+    /// BLAKE3 hash of the payload
+    pub checksum: [u8; 32],
+}
+
+impl ContainerHeader {
+    /// Parse a header from the first `CONTAINER_HEADER_SIZE` bytes.
+    pub fn parse(bytes: &[u8]) -> SisterResult<Self> {
+        if bytes.len() < CONTAINER_HEADER_SIZE {
+            return Err(SisterError::new(
+                ErrorCode::InvalidInput,
+                format!(
+                    "Container header truncated: {} of {} bytes",
+                    bytes.len(),
+                    CONTAINER_HEADER_SIZE
+                ),
+            ));
+        }
+
+        let layout_version = bytes[4];
+        if layout_version > CONTAINER_LAYOUT_VERSION {
+            return Err(SisterError::new(
+                ErrorCode::VersionMismatch,
+                format!(
+                    "Container layout v{} is newer than supported v{}",
+                    layout_version, CONTAINER_LAYOUT_VERSION
+                ),
+            ));
+        }
+
+        let sister_type = SisterType::from_byte(bytes[5]).ok_or_else(|| {
+            SisterError::new(
+                ErrorCode::InvalidInput,
+                format!("Unknown sister type byte: 0x{:02X}", bytes[5]),
+            )
+        })?;
+
+        let mut magic = [0u8; 4];
+        magic.copy_from_slice(&bytes[0..4]);
+        let mut checksum = [0u8; 32];
+        checksum.copy_from_slice(&bytes[40..72]);
+
+        Ok(Self {
+            magic,
+            layout_version,
+            sister_type,
+            version: Version::new(bytes[6], bytes[7], bytes[8]),
+            flags: bytes[9],
+            created_at: millis_to_datetime(read_i64(&bytes[16..24])),
+            updated_at: millis_to_datetime(read_i64(&bytes[24..32])),
+            payload_len: read_u64(&bytes[32..40]),
+            checksum,
+        })
+    }
+
+    /// Read just the header from a file.
+    pub fn read_from(path: &Path) -> SisterResult<Self> {
+        use std::io::Read;
+        let mut file = std::fs::File::open(path)?;
+        let mut buf = [0u8; CONTAINER_HEADER_SIZE];
+        file.read_exact(&mut buf).map_err(|e| {
+            SisterError::new(
+                ErrorCode::StorageError,
+                format!("Failed to read container header: {}", e),
+            )
+        })?;
+        Self::parse(&buf)
+    }
+
+    /// Serialize the header to its fixed-size byte form.
+    pub fn to_bytes(&self) -> [u8; CONTAINER_HEADER_SIZE] {
+        let mut buf = [0u8; CONTAINER_HEADER_SIZE];
+        buf[0..4].copy_from_slice(&self.magic);
+        buf[4] = self.layout_version;
+        buf[5] = self.sister_type.to_byte();
+        buf[6] = self.version.major;
+        buf[7] = self.version.minor;
+        buf[8] = self.version.patch;
+        buf[9] = self.flags;
+        buf[16..24].copy_from_slice(&self.created_at.timestamp_millis().to_le_bytes());
+        buf[24..32].copy_from_slice(&self.updated_at.timestamp_millis().to_le_bytes());
+        buf[32..40].copy_from_slice(&self.payload_len.to_le_bytes());
+        buf[40..72].copy_from_slice(&self.checksum);
+        buf
+    }
+
+    /// Compression codec recorded in the flags.
+    ///
+    /// An unknown codec flag is a `VersionMismatch`: the file was written
+    /// by a newer build.
+    pub fn compression(&self) -> SisterResult<CompressionCodec> {
+        let flag = self.flags & 0x0F;
+        CompressionCodec::from_flag(flag).ok_or_else(|| {
+            SisterError::new(
+                ErrorCode::VersionMismatch,
+                format!("Unknown compression codec flag: {}", flag),
+            )
+        })
+    }
+
+    /// Convert to a `FileInfo`, flagging migration against `current_version`.
+    pub fn to_file_info(&self, current_version: Option<&Version>) -> SisterResult<FileInfo> {
+        Ok(FileInfo {
+            sister_type: self.sister_type,
+            version: self.version.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            content_length: self.payload_len,
+            needs_migration: current_version
+                .map(|current| VersionCompatibility::needs_migration(current, &self.version))
+                .unwrap_or(false),
+            format_id: String::from_utf8_lossy(&self.magic).into_owned(),
+            compression: self.compression()?,
+        })
+    }
+}
+
+/// Reference container implementing both file-format traits.
+///
+/// Sisters embed this and store their own serialized state in `payload`:
+///
+/// ```rust,ignore
+/// let file = ContainerFile::new(SisterType::Planning, Version::new(1, 0, 0), bytes)
+///     .with_magic(*b"APLN");
+/// file.write_file(&path)?;
+///
+/// let loaded = ContainerFile::read_file(&path)?; // checksum verified
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerFile {
+    /// Magic bytes
+    pub magic: [u8; 4],
+
+    /// Which sister owns this file
+    pub sister_type: SisterType,
+
+    /// Format version of the payload
+    pub version: Version,
+
+    /// When the file was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the file was last modified
+    pub updated_at: DateTime<Utc>,
+
+    /// Sister-specific payload (always uncompressed in memory)
+    pub payload: Vec<u8>,
+
+    /// Compression applied when writing
+    pub compression: CompressionCodec,
+}
+
+impl ContainerFile {
+    /// Create a new container with the default magic.
+    pub fn new(sister_type: SisterType, version: Version, payload: Vec<u8>) -> Self {
+        // Round to millis so a written file reads back identically
+        let now = millis_to_datetime(Utc::now().timestamp_millis());
+        Self {
+            magic: CONTAINER_MAGIC,
+            sister_type,
+            version,
+            created_at: now,
+            updated_at: now,
+            payload,
+            compression: CompressionCodec::None,
+        }
+    }
+
+    /// Compress the payload on write.
+    pub fn with_compression(mut self, codec: CompressionCodec) -> Self {
+        self.compression = codec;
+        self
+    }
+
+    /// Use sister-specific magic bytes.
+    pub fn with_magic(mut self, magic: [u8; 4]) -> Self {
+        self.magic = magic;
+        self
+    }
+
+    /// Replace the payload and bump `updated_at`.
+    pub fn set_payload(&mut self, payload: Vec<u8>) {
+        self.payload = payload;
+        self.updated_at = millis_to_datetime(Utc::now().timestamp_millis());
+    }
+
+    /// Payload bytes as they are stored on disk (compressed if configured).
+    pub fn stored_payload(&self) -> SisterResult<Vec<u8>> {
+        compress_frame(self.compression, &self.payload)
+    }
+
+    /// Build the header for the given stored payload.
+    fn header_for(&self, stored: &[u8]) -> ContainerHeader {
+        ContainerHeader {
+            magic: self.magic,
+            layout_version: CONTAINER_LAYOUT_VERSION,
+            sister_type: self.sister_type,
+            version: self.version.clone(),
+            flags: self.compression.flag(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            payload_len: stored.len() as u64,
+            checksum: *blake3::hash(stored).as_bytes(),
+        }
+    }
+
+    /// Build the header for the current state.
+    pub fn header(&self) -> SisterResult<ContainerHeader> {
+        Ok(self.header_for(&self.stored_payload()?))
+    }
+
+    /// Parse a container from bytes, verifying length and checksum.
+    pub fn from_bytes(bytes: &[u8]) -> SisterResult<Self> {
+        let header = ContainerHeader::parse(bytes)?;
+        let payload = &bytes[CONTAINER_HEADER_SIZE..];
+
+        if payload.len() as u64 != header.payload_len {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                format!(
+                    "Payload length mismatch: header says {}, found {}",
+                    header.payload_len,
+                    payload.len()
+                ),
+            ));
+        }
+
+        if blake3::hash(payload).as_bytes() != &header.checksum {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                "Container payload checksum verification failed",
+            ));
+        }
+
+        let compression = header.compression()?;
+        let payload = if compression == CompressionCodec::None {
+            payload.to_vec()
+        } else {
+            decompress_frame(payload)?.0
+        };
+
+        Ok(Self {
+            magic: header.magic,
+            sister_type: header.sister_type,
+            version: header.version,
+            created_at: header.created_at,
+            updated_at: header.updated_at,
+            payload,
+            compression,
+        })
+    }
+}
+
+impl FileFormatReader for ContainerFile {
+    fn read_file(path: &Path) -> SisterResult<Self> {
+        let bytes = std::fs::read(path)?;
+        Self::from_bytes(&bytes)
+    }
+
+    fn can_read(path: &Path) -> SisterResult<FileInfo> {
+        ContainerHeader::read_from(path)?.to_file_info(None)
+    }
+
+    fn file_version(path: &Path) -> SisterResult<Version> {
+        Ok(ContainerHeader::read_from(path)?.version)
+    }
+
+    /// The container itself has no knowledge of payload semantics, so it
+    /// only passes through data from the same major version.
+    fn migrate(data: &[u8], from_version: Version) -> SisterResult<Vec<u8>> {
+        let header = ContainerHeader::parse(data)?;
+        if VersionCompatibility::is_compatible(&header.version, &from_version) {
+            Ok(data.to_vec())
+        } else {
+            Err(SisterError::new(
+                ErrorCode::NotImplemented,
+                format!(
+                    "No container migration from {} to {}; the owning sister must migrate the payload",
+                    from_version, header.version
+                ),
+            ))
+        }
+    }
+
+    /// Salvages the payload of a damaged container.
+    ///
+    /// The container checksums the payload as a whole, so on mismatch the
+    /// payload is returned as `Suspect`; the owning sister decides which
+    /// records in it are still usable. A damaged header salvages nothing.
+    fn recover(path: &Path) -> SisterResult<RecoveryReport> {
+        let bytes = std::fs::read(path)?;
+        let file_size = bytes.len() as u64;
+        let report = RecoveryReport::new(path, file_size);
+
+        let header = match ContainerHeader::parse(&bytes) {
+            Ok(header) => header,
+            Err(e) => {
+                return Ok(report
+                    .region(0, file_size, RegionStatus::Damaged, "header")
+                    .items(0, 1)
+                    .note(format!("Header unreadable: {}", e.message)));
+            }
+        };
+
+        let start = CONTAINER_HEADER_SIZE as u64;
+        let available = (file_size - start).min(header.payload_len);
+        let stored = &bytes[CONTAINER_HEADER_SIZE..CONTAINER_HEADER_SIZE + available as usize];
+        let report = report.region(0, start, RegionStatus::Intact, "header");
+
+        let compression = match header.compression() {
+            Ok(compression) => compression,
+            Err(e) => {
+                return Ok(report
+                    .region(start, available, RegionStatus::Damaged, "payload")
+                    .items(0, 1)
+                    .note(e.message));
+            }
+        };
+
+        if available == header.payload_len && blake3::hash(stored).as_bytes() == &header.checksum {
+            let payload = if compression == CompressionCodec::None {
+                stored.to_vec()
+            } else {
+                decompress_frame(stored)?.0
+            };
+            return Ok(report
+                .region(start, available, RegionStatus::Intact, "payload")
+                .items(1, 0)
+                .with_data(payload)
+                .note("No damage found"));
+        }
+
+        let mut report = report;
+        let salvaged = if compression == CompressionCodec::None {
+            Some(stored.to_vec())
+        } else {
+            decompress_frame(stored).ok().map(|(payload, _)| payload)
+        };
+        report = match salvaged {
+            Some(payload) => report
+                .region(start, available, RegionStatus::Suspect, "payload")
+                .items(1, 0)
+                .with_data(payload)
+                .note("Payload checksum failed; salvaged bytes are unverified"),
+            None => report
+                .region(start, available, RegionStatus::Damaged, "payload")
+                .items(0, 1)
+                .note("Compressed payload is damaged and cannot be decoded"),
+        };
+        if available < header.payload_len {
+            report = report
+                .region(
+                    start + available,
+                    header.payload_len - available,
+                    RegionStatus::Missing,
+                    "payload (truncated)",
+                )
+                .note(format!(
+                    "File truncated: {} of {} payload bytes present",
+                    available, header.payload_len
+                ));
+        }
+        Ok(report)
+    }
+}
+
+impl FileFormatWriter for ContainerFile {
+    fn to_bytes(&self) -> SisterResult<Vec<u8>> {
+        let stored = self.stored_payload()?;
+        let mut out = Vec::with_capacity(CONTAINER_HEADER_SIZE + stored.len());
+        out.extend_from_slice(&self.header_for(&stored).to_bytes());
+        out.extend_from_slice(&stored);
+        Ok(out)
+    }
+}
+
+fn read_i64(bytes: &[u8]) -> i64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(bytes);
+    i64::from_le_bytes(buf)
+}
+
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(bytes);
+    u64::from_le_bytes(buf)
+}
+
+fn millis_to_datetime(ms: i64) -> DateTime<Utc> {
+    Utc.timestamp_millis_opt(ms)
+        .single()
+        .unwrap_or(DateTime::<Utc>::UNIX_EPOCH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn test_container_roundtrip_bytes() {
+        let file = ContainerFile::new(
+            SisterType::Planning,
+            Version::new(1, 2, 3),
+            b"hello".to_vec(),
+        )
+        .with_magic(*b"APLN");
+        let bytes = file.to_bytes().unwrap();
+        assert_eq!(bytes.len(), CONTAINER_HEADER_SIZE + 5);
+        assert_eq!(&bytes[0..4], b"APLN");
+
+        let parsed = ContainerFile::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, file);
+    }
+
+    #[test]
+    fn test_container_detects_corruption() {
+        let file = ContainerFile::new(SisterType::Memory, Version::new(1, 0, 0), vec![1, 2, 3]);
+        let mut bytes = file.to_bytes().unwrap();
+        *bytes.last_mut().unwrap() ^= 0xFF;
+
+        let err = ContainerFile::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ChecksumMismatch);
+
+        let truncated = &file.to_bytes().unwrap()[..CONTAINER_HEADER_SIZE + 1];
+        let err = ContainerFile::from_bytes(truncated).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ChecksumMismatch);
+    }
+
+    #[test]
+    fn test_container_unknown_codec_flag() {
+        let file = ContainerFile::new(SisterType::Memory, Version::new(1, 0, 0), vec![1, 2, 3]);
+        let mut bytes = file.to_bytes().unwrap();
+        bytes[9] = 0x0F;
+
+        let header = ContainerHeader::parse(&bytes).unwrap();
+        let err = header.compression().unwrap_err();
+        assert_eq!(err.code, ErrorCode::VersionMismatch);
+        assert!(header.to_file_info(None).is_err());
+
+        let err = ContainerFile::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err.code, ErrorCode::VersionMismatch);
+    }
+
+    #[test]
+    fn test_container_recover_truncated() {
+        let dir = TempDir::new("container");
+        let path = dir.join("data.actr");
+        let file = ContainerFile::new(SisterType::Memory, Version::new(1, 0, 0), vec![9u8; 100]);
+        let bytes = file.to_bytes().unwrap();
+        std::fs::write(&path, &bytes[..CONTAINER_HEADER_SIZE + 60]).unwrap();
+
+        let report = ContainerFile::recover(&path).unwrap();
+        assert_eq!(report.recovered_data, Some(vec![9u8; 60]));
+        assert_eq!(
+            report.salvageable_bytes(),
+            CONTAINER_HEADER_SIZE as u64 + 60
+        );
+        assert_eq!(report.lost_bytes(), 40);
+        assert_eq!(report.regions[1].status, RegionStatus::Suspect);
+        assert_eq!(report.regions[2].status, RegionStatus::Missing);
+
+        std::fs::write(&path, &bytes).unwrap();
+        assert!(ContainerFile::recover(&path).unwrap().is_clean());
+
+        std::fs::write(&path, b"garbage").unwrap();
+        let report = ContainerFile::recover(&path).unwrap();
+        assert!(!report.has_recovered_data());
+        assert_eq!(report.lost_bytes(), 7);
+    }
+
+    #[test]
+    fn test_container_file_io() {
+        let dir = TempDir::new("container");
+        let path = dir.join("data.actr");
+        let file = ContainerFile::new(SisterType::Time, Version::new(2, 0, 0), b"payload".to_vec());
+        file.write_file(&path).unwrap();
+
+        let info = ContainerFile::can_read(&path).unwrap();
+        assert_eq!(info.sister_type, SisterType::Time);
+        assert_eq!(info.content_length, 7);
+        assert_eq!(info.format_id, "ACTR");
+        assert_eq!(
+            ContainerFile::file_version(&path).unwrap(),
+            Version::new(2, 0, 0)
+        );
+        assert_eq!(ContainerFile::read_file(&path).unwrap(), file);
+
+        let header = ContainerHeader::read_from(&path).unwrap();
+        assert!(
+            header
+                .to_file_info(Some(&Version::new(3, 0, 0)))
+                .unwrap()
+                .needs_migration
+        );
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_container_compressed() {
+        let payload = b"node ".repeat(500);
+        let file = ContainerFile::new(SisterType::Codebase, Version::new(1, 0, 0), payload.clone())
+            .with_compression(CompressionCodec::Zstd);
+        let bytes = file.to_bytes().unwrap();
+        assert!(bytes.len() < CONTAINER_HEADER_SIZE + payload.len());
+
+        let header = ContainerHeader::parse(&bytes).unwrap();
+        assert_eq!(
+            header.to_file_info(None).unwrap().compression,
+            CompressionCodec::Zstd
+        );
+
+        let parsed = ContainerFile::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed.payload, payload);
+        assert_eq!(parsed.compression, CompressionCodec::Zstd);
+    }
+}
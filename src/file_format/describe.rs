@@ -0,0 +1,327 @@
+//! Uniform file inspection (`agentic inspect`).
+//!
+//! `describe_file` answers "what is this file?" for any .a* file:
+//! which sister owns it, which format and version it is, how big it is,
+//! and whether it needs migration. It tries, in order:
+//!
+//! 1. Formats registered in a `FormatRegistry` (matched by magic)
+//! 2. SDK formats: `ContainerFile`, bundles, journals, compressed frames
+//! 3. JSON files following the `JsonFormatDescriptor` convention
+//! 4. Known sister magic bytes (`identify_sister_by_magic`)
+//! 5. The file extension
+//!
+//! Inspection never fails just because a file is unrecognized; the
+//! result says `FileKind::Unknown` instead.
+
+use super::{
+    identify_sister_by_magic, is_json_format, read_json_file_info, ContainerHeader,
+    FileFormatReader, FileInfo, VersionCompatibility, BUNDLE_MAGIC, COMPRESSED_MAGIC,
+    CONTAINER_HEADER_SIZE, JOURNAL_MAGIC,
+};
+use crate::errors::SisterResult;
+use crate::types::{SisterType, Version};
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// What kind of file was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileKind {
+    /// A sister's own binary format (registered reader or known magic)
+    SisterBinary,
+
+    /// SDK `ContainerFile`
+    Container,
+
+    /// JSON sister file (`JsonFormatDescriptor` convention)
+    Json,
+
+    /// `.abundle` archive
+    Bundle,
+
+    /// Append-only journal
+    Journal,
+
+    /// Bare compressed frame
+    Compressed,
+
+    /// Not recognized
+    Unknown,
+}
+
+/// Result of `describe_file`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileDescription {
+    /// Inspected file
+    pub path: PathBuf,
+
+    /// Size on disk in bytes
+    pub size: u64,
+
+    /// Detected kind
+    pub kind: FileKind,
+
+    /// First four bytes as text, when printable (e.g., "AMEM")
+    pub magic: Option<String>,
+
+    /// Owning sister, if it could be determined
+    pub sister_type: Option<SisterType>,
+
+    /// Full file info, when a reader could parse the header
+    pub info: Option<FileInfo>,
+
+    /// Whether the file needs migration to the current version
+    pub needs_migration: bool,
+
+    /// Why the file could not be fully described, if it couldn't
+    pub warning: Option<String>,
+}
+
+impl FileDescription {
+    /// Whether the file was recognized.
+    pub fn is_recognized(&self) -> bool {
+        self.kind != FileKind::Unknown
+    }
+
+    /// File format version, if known.
+    pub fn version(&self) -> Option<&Version> {
+        self.info.as_ref().map(|i| &i.version)
+    }
+}
+
+type InspectFn = fn(&Path) -> SisterResult<FileInfo>;
+
+#[derive(Debug, Clone)]
+struct RegisteredFormat {
+    sister_type: SisterType,
+    magic: [u8; 4],
+    current_version: Version,
+    inspect: InspectFn,
+}
+
+/// Sister readers known to `describe`.
+///
+/// ```rust,ignore
+/// let mut registry = FormatRegistry::new();
+/// registry.register::<MemoryFile>(SisterType::Memory, *b"AMEM", Version::new(2, 0, 0));
+/// let description = registry.describe(Path::new("brain.amem"))?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FormatRegistry {
+    formats: Vec<RegisteredFormat>,
+}
+
+impl FormatRegistry {
+    /// Empty registry (SDK formats are always recognized).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a sister reader for files starting with `magic`.
+    ///
+    /// `current_version` is the version the sister writes today; it
+    /// drives `needs_migration`.
+    pub fn register<R: FileFormatReader>(
+        &mut self,
+        sister_type: SisterType,
+        magic: [u8; 4],
+        current_version: Version,
+    ) -> &mut Self {
+        self.formats.retain(|f| f.magic != magic);
+        self.formats.push(RegisteredFormat {
+            sister_type,
+            magic,
+            current_version,
+            inspect: R::can_read,
+        });
+        self
+    }
+
+    /// Builder-style `register`.
+    pub fn with<R: FileFormatReader>(
+        mut self,
+        sister_type: SisterType,
+        magic: [u8; 4],
+        current_version: Version,
+    ) -> Self {
+        self.register::<R>(sister_type, magic, current_version);
+        self
+    }
+
+    /// Current version registered for a sister, if any.
+    pub fn current_version(&self, sister_type: SisterType) -> Option<&Version> {
+        self.formats
+            .iter()
+            .find(|f| f.sister_type == sister_type)
+            .map(|f| &f.current_version)
+    }
+
+    /// Describe a file. Errors only if the file cannot be opened.
+    pub fn describe(&self, path: &Path) -> SisterResult<FileDescription> {
+        let size = std::fs::metadata(path)?.len();
+        let mut head = [0u8; 4];
+        let head_len = std::fs::File::open(path)?.read(&mut head)?;
+        let magic = (head_len == 4).then_some(head);
+
+        let mut description = FileDescription {
+            path: path.to_path_buf(),
+            size,
+            kind: FileKind::Unknown,
+            magic: magic
+                .filter(|m| m.iter().all(|b| b.is_ascii_graphic()))
+                .map(|m| String::from_utf8_lossy(&m).into_owned()),
+            sister_type: None,
+            info: None,
+            needs_migration: false,
+            warning: None,
+        };
+
+        if let Some(format) = magic.and_then(|m| self.formats.iter().find(|f| f.magic == m)) {
+            description.kind = FileKind::SisterBinary;
+            description.sister_type = Some(format.sister_type);
+            match (format.inspect)(path) {
+                Ok(info) => description.info = Some(info),
+                Err(e) => description.warning = Some(e.to_string()),
+            }
+        } else if magic == Some(BUNDLE_MAGIC) {
+            description.kind = FileKind::Bundle;
+        } else if magic == Some(JOURNAL_MAGIC) {
+            description.kind = FileKind::Journal;
+        } else if magic == Some(COMPRESSED_MAGIC) {
+            description.kind = FileKind::Compressed;
+        } else if let Some(header) = read_container_header(path, size) {
+            description.kind = FileKind::Container;
+            description.sister_type = Some(header.sister_type);
+            match header.to_file_info(None) {
+                Ok(info) => description.info = Some(info),
+                Err(e) => description.warning = Some(e.to_string()),
+            }
+        } else if is_json_format(path)? {
+            match read_json_file_info(path) {
+                Ok(info) => {
+                    description.kind = FileKind::Json;
+                    description.sister_type = Some(info.sister_type);
+                    description.info = Some(info);
+                }
+                Err(e) => description.warning = Some(e.to_string()),
+            }
+        } else if let Some(sister_type) = magic.and_then(|m| identify_sister_by_magic(&m)) {
+            description.kind = FileKind::SisterBinary;
+            description.sister_type = Some(sister_type);
+            description.warning = Some(format!(
+                "No reader registered for {} files; header not parsed",
+                sister_type
+            ));
+        }
+
+        if description.sister_type.is_none() {
+            description.sister_type = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .and_then(SisterType::from_file_extension);
+        }
+
+        description.needs_migration = match (&description.info, description.sister_type) {
+            (Some(info), Some(sister_type)) => match self.current_version(sister_type) {
+                Some(current) => VersionCompatibility::needs_migration(current, &info.version),
+                None => info.needs_migration,
+            },
+            _ => false,
+        };
+        if let Some(info) = description.info.as_mut() {
+            info.needs_migration = description.needs_migration;
+        }
+
+        Ok(description)
+    }
+}
+
+/// Describe a file using only SDK-known formats.
+///
+/// Use `FormatRegistry::describe` to include sister readers and
+/// accurate migration flags.
+pub fn describe_file(path: &Path) -> SisterResult<FileDescription> {
+    FormatRegistry::new().describe(path)
+}
+
+/// A container header that parses and whose payload length matches the file.
+fn read_container_header(path: &Path, size: u64) -> Option<ContainerHeader> {
+    ContainerHeader::read_from(path)
+        .ok()
+        .filter(|h| h.payload_len == size.saturating_sub(CONTAINER_HEADER_SIZE as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_format::{ContainerFile, FileFormatWriter};
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn test_describe_container_and_json() {
+        let dir = TempDir::new("describe");
+        let path = dir.join("plan.aplan");
+        ContainerFile::new(SisterType::Planning, Version::new(1, 0, 0), b"x".into())
+            .with_magic(*b"APLN")
+            .write_file(&path)
+            .unwrap();
+        let description = describe_file(&path).unwrap();
+        assert_eq!(description.kind, FileKind::Container);
+        assert_eq!(description.sister_type, Some(SisterType::Planning));
+        assert_eq!(description.magic.as_deref(), Some("APLN"));
+        assert_eq!(description.size, CONTAINER_HEADER_SIZE as u64 + 1);
+
+        let json = path.with_file_name("agent.aid");
+        std::fs::write(&json, r#"{"format": "aid-v1", "version": "1.0.0"}"#).unwrap();
+        let description = describe_file(&json).unwrap();
+        assert_eq!(description.kind, FileKind::Json);
+        assert_eq!(description.sister_type, Some(SisterType::Identity));
+        assert_eq!(description.version(), Some(&Version::new(1, 0, 0)));
+    }
+
+    #[test]
+    fn test_registry_sets_migration_flag() {
+        let dir = TempDir::new("describe");
+        let path = dir.join("brain.amem");
+        ContainerFile::new(SisterType::Memory, Version::new(1, 0, 0), vec![])
+            .with_magic(*b"AMEM")
+            .write_file(&path)
+            .unwrap();
+
+        // Without a reader: recognized by magic only
+        let registry = FormatRegistry::new();
+        let description = registry.describe(&path).unwrap();
+        assert_eq!(description.kind, FileKind::Container);
+        assert!(!description.needs_migration);
+
+        let registry = FormatRegistry::new().with::<ContainerFile>(
+            SisterType::Memory,
+            *b"AMEM",
+            Version::new(2, 0, 0),
+        );
+        let description = registry.describe(&path).unwrap();
+        assert_eq!(description.kind, FileKind::SisterBinary);
+        assert!(description.needs_migration);
+        assert!(description.info.unwrap().needs_migration);
+    }
+
+    #[test]
+    fn test_describe_unknown_and_legacy() {
+        let dir = TempDir::new("describe");
+        let path = dir.join("mystery.bin");
+        std::fs::write(&path, b"\x00\x01\x02").unwrap();
+        let description = describe_file(&path).unwrap();
+        assert!(!description.is_recognized());
+        assert_eq!(description.magic, None);
+
+        let legacy = path.with_file_name("old.acb");
+        std::fs::write(&legacy, b"ACDB\x01\x00rest-of-header").unwrap();
+        let description = describe_file(&legacy).unwrap();
+        assert_eq!(description.kind, FileKind::SisterBinary);
+        assert_eq!(description.sister_type, Some(SisterType::Codebase));
+        assert!(description.warning.is_some());
+
+        assert!(describe_file(&path.with_file_name("missing")).is_err());
+    }
+}
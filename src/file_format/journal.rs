@@ -0,0 +1,528 @@
+//! Append-only journal (write-ahead log) for crash-safe sisters.
+//!
+//! Memory and Identity both need durable append logs. `Journal` is the
+//! shared contract; `FileJournal` is a file-backed implementation with
+//! per-entry BLAKE3 checksums and torn-write recovery on open.
+//!
+//! # File layout (little-endian)
+//!
+//! ```text
+//! header (16 bytes):
+//!   0   4   magic "AJNL"
+//!   4   1   journal layout version (currently 1)
+//!   5   3   reserved (zero)
+//!   8   8   base sequence (first sequence stored in this file)
+//!
+//! entry (53-byte prefix + data):
+//!   0   4   data length (u32)
+//!   4   1   kind (0 = data, 1 = checkpoint)
+//!   5   8   sequence (u64)
+//!   13  8   timestamp (unix millis, i64)
+//!   21  32  BLAKE3 of kind + sequence + timestamp + data
+//!   53  ..  data
+//! ```
+//!
+//! A crash mid-append leaves a short or mis-checksummed tail entry.
+//! `FileJournal::open` drops everything from the first bad entry on,
+//! so the journal always ends on the last fully written entry. A failed
+//! append is rolled back in place, and a header cut short while the file
+//! was being created is rewritten.
+
+use super::atomic_write;
+use crate::errors::{ErrorCode, SisterError, SisterResult};
+use chrono::{DateTime, TimeZone, Utc};
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Magic bytes for journal files.
+pub const JOURNAL_MAGIC: [u8; 4] = *b"AJNL";
+
+/// Current journal layout version.
+pub const JOURNAL_LAYOUT_VERSION: u8 = 1;
+
+const HEADER_SIZE: usize = 16;
+const ENTRY_PREFIX_SIZE: usize = 53;
+const KIND_DATA: u8 = 0;
+const KIND_CHECKPOINT: u8 = 1;
+
+/// A single journal entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalEntry {
+    /// Monotonic sequence number
+    pub sequence: u64,
+
+    /// When the entry was appended
+    pub timestamp: DateTime<Utc>,
+
+    /// Entry payload (sister-specific)
+    pub data: Vec<u8>,
+}
+
+/// Iterator over journal entries.
+pub type JournalIter<'a> = Box<dyn Iterator<Item = SisterResult<JournalEntry>> + 'a>;
+
+/// Append-only journal contract.
+///
+/// Sequences are assigned by the journal and strictly increase.
+/// Checkpoints mark a point the sister has durably applied; entries
+/// before a checkpoint can then be truncated away.
+pub trait Journal {
+    /// Append an entry. Returns its sequence number
+    fn append(&mut self, data: &[u8]) -> SisterResult<u64>;
+
+    /// Iterate data entries with `sequence >= from`
+    fn iter_from(&self, from: u64) -> SisterResult<JournalIter<'_>>;
+
+    /// Flush to durable storage and record a checkpoint.
+    /// Returns the checkpoint's sequence number
+    fn checkpoint(&mut self) -> SisterResult<u64>;
+
+    /// Sequence of the most recent checkpoint, if any
+    fn last_checkpoint(&self) -> Option<u64>;
+
+    /// Drop all entries with `sequence < before`
+    fn truncate(&mut self, before: u64) -> SisterResult<()>;
+
+    /// Sequence the next append will receive
+    fn next_sequence(&self) -> u64;
+
+    /// Iterate every retained data entry
+    fn iter(&self) -> SisterResult<JournalIter<'_>> {
+        self.iter_from(0)
+    }
+
+    /// Data entries appended after the last checkpoint (what must be replayed)
+    fn uncheckpointed(&self) -> SisterResult<JournalIter<'_>> {
+        self.iter_from(self.last_checkpoint().map(|c| c + 1).unwrap_or(0))
+    }
+}
+
+/// What `FileJournal::open` found and repaired.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JournalRecovery {
+    /// Valid entries found (data + checkpoints)
+    pub valid_entries: u64,
+
+    /// Bytes discarded from a torn or corrupt tail
+    pub truncated_bytes: u64,
+}
+
+impl JournalRecovery {
+    /// Whether any repair was needed.
+    pub fn was_repaired(&self) -> bool {
+        self.truncated_bytes > 0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RawEntry {
+    kind: u8,
+    entry: JournalEntry,
+}
+
+/// File-backed `Journal`.
+#[derive(Debug)]
+pub struct FileJournal {
+    path: PathBuf,
+    file: File,
+    base_sequence: u64,
+    next_sequence: u64,
+    last_checkpoint: Option<u64>,
+    sync_on_append: bool,
+    recovery: JournalRecovery,
+    /// Length of the file up to the last fully written entry
+    len: u64,
+}
+
+impl FileJournal {
+    /// Open (or create) a journal, repairing any torn tail.
+    pub fn open(path: impl Into<PathBuf>) -> SisterResult<Self> {
+        let path = path.into();
+        let mut file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(&path)?;
+
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+
+        // A file shorter than its header was torn while being created
+        // (truncate rewrites atomically), so start over with base 0
+        let mut truncated_bytes = 0;
+        if bytes.len() < HEADER_SIZE && encode_header(0).starts_with(&bytes) {
+            truncated_bytes = bytes.len() as u64;
+            file.set_len(0)?;
+            file.write_all(&encode_header(0))?;
+            file.sync_data()?;
+            bytes = encode_header(0).to_vec();
+        }
+
+        let base_sequence = decode_header(&bytes)?;
+        let (entries, valid_len) = scan_entries(&bytes);
+
+        let mut recovery = JournalRecovery {
+            valid_entries: entries.len() as u64,
+            truncated_bytes,
+        };
+        if valid_len < bytes.len() {
+            recovery.truncated_bytes = (bytes.len() - valid_len) as u64;
+            file.set_len(valid_len as u64)?;
+            file.sync_data()?;
+        }
+
+        let next_sequence = entries
+            .last()
+            .map(|e| e.entry.sequence + 1)
+            .unwrap_or(base_sequence);
+        let last_checkpoint = entries
+            .iter()
+            .rev()
+            .find(|e| e.kind == KIND_CHECKPOINT)
+            .map(|e| e.entry.sequence);
+
+        Ok(Self {
+            path,
+            file,
+            base_sequence,
+            next_sequence,
+            last_checkpoint,
+            sync_on_append: false,
+            recovery,
+            len: valid_len as u64,
+        })
+    }
+
+    /// fsync after every append (slower, but no entry is ever lost).
+    pub fn sync_on_append(mut self, sync: bool) -> Self {
+        self.sync_on_append = sync;
+        self
+    }
+
+    /// What was repaired when this journal was opened.
+    pub fn recovery(&self) -> &JournalRecovery {
+        &self.recovery
+    }
+
+    /// Path of the journal file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// First sequence retained in the file.
+    pub fn base_sequence(&self) -> u64 {
+        self.base_sequence
+    }
+
+    fn append_raw(&mut self, kind: u8, data: &[u8]) -> SisterResult<u64> {
+        if data.len() > u32::MAX as usize {
+            return Err(SisterError::invalid_input(format!(
+                "Journal entry too large: {} bytes",
+                data.len()
+            )));
+        }
+
+        let sequence = self.next_sequence;
+        let entry = JournalEntry {
+            sequence,
+            timestamp: now_millis(),
+            data: data.to_vec(),
+        };
+        let encoded = encode_entry(kind, &entry);
+        let sync = self.sync_on_append || kind == KIND_CHECKPOINT;
+        let written = self.file.write_all(&encoded).and_then(|_| {
+            if sync {
+                self.file.sync_data()
+            } else {
+                Ok(())
+            }
+        });
+        if let Err(e) = written {
+            // Drop the partial entry so later appends stay reachable
+            let _ = self.file.set_len(self.len);
+            return Err(e.into());
+        }
+        self.len += encoded.len() as u64;
+        self.next_sequence += 1;
+        Ok(sequence)
+    }
+
+    fn read_entries(&self) -> SisterResult<Vec<RawEntry>> {
+        let bytes = std::fs::read(&self.path)?;
+        decode_header(&bytes)?;
+        Ok(scan_entries(&bytes).0)
+    }
+}
+
+impl Journal for FileJournal {
+    fn append(&mut self, data: &[u8]) -> SisterResult<u64> {
+        self.append_raw(KIND_DATA, data)
+    }
+
+    fn iter_from(&self, from: u64) -> SisterResult<JournalIter<'_>> {
+        let entries = self.read_entries()?;
+        Ok(Box::new(
+            entries
+                .into_iter()
+                .filter(move |e| e.kind == KIND_DATA && e.entry.sequence >= from)
+                .map(|e| Ok(e.entry)),
+        ))
+    }
+
+    fn checkpoint(&mut self) -> SisterResult<u64> {
+        let sequence = self.append_raw(KIND_CHECKPOINT, &[])?;
+        self.last_checkpoint = Some(sequence);
+        Ok(sequence)
+    }
+
+    fn last_checkpoint(&self) -> Option<u64> {
+        self.last_checkpoint
+    }
+
+    fn truncate(&mut self, before: u64) -> SisterResult<()> {
+        if before <= self.base_sequence {
+            return Ok(());
+        }
+        let base = before.min(self.next_sequence);
+
+        let mut out = encode_header(base).to_vec();
+        for raw in self.read_entries()? {
+            if raw.entry.sequence >= base {
+                out.extend_from_slice(&encode_entry(raw.kind, &raw.entry));
+            }
+        }
+
+        // Atomic rewrite so a crash leaves either journal intact
+        atomic_write(&self.path, &out)?;
+        self.len = out.len() as u64;
+
+        self.file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .open(&self.path)?;
+        self.base_sequence = base;
+        if self.last_checkpoint.is_some_and(|c| c < base) {
+            self.last_checkpoint = None;
+        }
+        Ok(())
+    }
+
+    fn next_sequence(&self) -> u64 {
+        self.next_sequence
+    }
+}
+
+fn encode_header(base_sequence: u64) -> [u8; HEADER_SIZE] {
+    let mut buf = [0u8; HEADER_SIZE];
+    buf[0..4].copy_from_slice(&JOURNAL_MAGIC);
+    buf[4] = JOURNAL_LAYOUT_VERSION;
+    buf[8..16].copy_from_slice(&base_sequence.to_le_bytes());
+    buf
+}
+
+fn decode_header(bytes: &[u8]) -> SisterResult<u64> {
+    if bytes.len() < HEADER_SIZE || bytes[0..4] != JOURNAL_MAGIC {
+        return Err(SisterError::new(
+            ErrorCode::InvalidInput,
+            "Not a journal file (missing AJNL header)",
+        ));
+    }
+    if bytes[4] > JOURNAL_LAYOUT_VERSION {
+        return Err(SisterError::new(
+            ErrorCode::VersionMismatch,
+            format!(
+                "Journal layout v{} is newer than supported v{}",
+                bytes[4], JOURNAL_LAYOUT_VERSION
+            ),
+        ));
+    }
+    Ok(u64_at(bytes, 8))
+}
+
+fn entry_checksum(kind: u8, entry: &JournalEntry) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&[kind]);
+    hasher.update(&entry.sequence.to_le_bytes());
+    hasher.update(&entry.timestamp.timestamp_millis().to_le_bytes());
+    hasher.update(&entry.data);
+    *hasher.finalize().as_bytes()
+}
+
+fn encode_entry(kind: u8, entry: &JournalEntry) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(ENTRY_PREFIX_SIZE + entry.data.len());
+    buf.extend_from_slice(&(entry.data.len() as u32).to_le_bytes());
+    buf.push(kind);
+    buf.extend_from_slice(&entry.sequence.to_le_bytes());
+    buf.extend_from_slice(&entry.timestamp.timestamp_millis().to_le_bytes());
+    buf.extend_from_slice(&entry_checksum(kind, entry));
+    buf.extend_from_slice(&entry.data);
+    buf
+}
+
+/// Decode entries until the first incomplete or corrupt one.
+/// Returns the valid entries and the byte length they occupy (incl. header).
+fn scan_entries(bytes: &[u8]) -> (Vec<RawEntry>, usize) {
+    let mut entries = Vec::new();
+    let mut pos = HEADER_SIZE;
+
+    while bytes.len() - pos >= ENTRY_PREFIX_SIZE {
+        let len = u32::from_le_bytes([bytes[pos], bytes[pos + 1], bytes[pos + 2], bytes[pos + 3]])
+            as usize;
+        let end = pos + ENTRY_PREFIX_SIZE + len;
+        if end > bytes.len() {
+            break;
+        }
+
+        let kind = bytes[pos + 4];
+        let entry = JournalEntry {
+            sequence: u64_at(bytes, pos + 5),
+            timestamp: millis_to_datetime(u64_at(bytes, pos + 13) as i64),
+            data: bytes[pos + ENTRY_PREFIX_SIZE..end].to_vec(),
+        };
+        if bytes[pos + 21..pos + 53] != entry_checksum(kind, &entry) {
+            break;
+        }
+
+        entries.push(RawEntry { kind, entry });
+        pos = end;
+    }
+
+    (entries, pos)
+}
+
+fn u64_at(bytes: &[u8], pos: usize) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes[pos..pos + 8]);
+    u64::from_le_bytes(buf)
+}
+
+fn now_millis() -> DateTime<Utc> {
+    millis_to_datetime(Utc::now().timestamp_millis())
+}
+
+fn millis_to_datetime(ms: i64) -> DateTime<Utc> {
+    Utc.timestamp_millis_opt(ms)
+        .single()
+        .unwrap_or(DateTime::<Utc>::UNIX_EPOCH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    fn temp_path() -> (TempDir, PathBuf) {
+        let dir = TempDir::new("journal");
+        let path = dir.join("data.ajnl");
+        (dir, path)
+    }
+
+    fn data(journal: &FileJournal, from: u64) -> Vec<Vec<u8>> {
+        journal
+            .iter_from(from)
+            .unwrap()
+            .map(|e| e.unwrap().data)
+            .collect()
+    }
+
+    #[test]
+    fn test_journal_append_and_reopen() {
+        let (_dir, path) = temp_path();
+        {
+            let mut journal = FileJournal::open(&path).unwrap();
+            assert_eq!(journal.append(b"one").unwrap(), 0);
+            assert_eq!(journal.append(b"two").unwrap(), 1);
+            assert_eq!(journal.checkpoint().unwrap(), 2);
+            journal.append(b"three").unwrap();
+        }
+
+        let journal = FileJournal::open(&path).unwrap();
+        assert!(!journal.recovery().was_repaired());
+        assert_eq!(journal.next_sequence(), 4);
+        assert_eq!(journal.last_checkpoint(), Some(2));
+        assert_eq!(data(&journal, 1), vec![b"two".to_vec(), b"three".to_vec()]);
+        let pending: Vec<_> = journal.uncheckpointed().unwrap().collect();
+        assert_eq!(pending.len(), 1);
+    }
+
+    #[test]
+    fn test_journal_recovers_torn_write() {
+        let (_dir, path) = temp_path();
+        {
+            let mut journal = FileJournal::open(&path).unwrap();
+            journal.append(b"kept").unwrap();
+            journal.append(b"torn entry").unwrap();
+        }
+        let len = std::fs::metadata(&path).unwrap().len();
+        OpenOptions::new()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_len(len - 3)
+            .unwrap();
+
+        let mut journal = FileJournal::open(&path).unwrap();
+        assert!(journal.recovery().was_repaired());
+        assert_eq!(journal.recovery().valid_entries, 1);
+        assert_eq!(data(&journal, 0), vec![b"kept".to_vec()]);
+        assert_eq!(journal.append(b"after").unwrap(), 1);
+    }
+
+    #[test]
+    fn test_journal_recovers_short_header() {
+        let (_dir, path) = temp_path();
+        std::fs::write(&path, &JOURNAL_MAGIC[..3]).unwrap();
+
+        let mut journal = FileJournal::open(&path).unwrap();
+        assert_eq!(journal.recovery().truncated_bytes, 3);
+        assert_eq!(journal.base_sequence(), 0);
+        assert_eq!(journal.append(b"first").unwrap(), 0);
+
+        let reopened = FileJournal::open(&path).unwrap();
+        assert!(!reopened.recovery().was_repaired());
+        assert_eq!(data(&reopened, 0), vec![b"first".to_vec()]);
+
+        std::fs::write(&path, b"junk").unwrap();
+        assert!(FileJournal::open(&path).is_err());
+    }
+
+    #[test]
+    fn test_journal_failed_append_rolls_back() {
+        let (_dir, path) = temp_path();
+        let mut journal = FileJournal::open(&path).unwrap();
+        journal.append(b"kept").unwrap();
+        let len = std::fs::metadata(&path).unwrap().len();
+
+        // A read-only handle makes the next write fail
+        journal.file = File::open(&path).unwrap();
+        assert!(journal.append(b"lost").is_err());
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), len);
+
+        journal.file = OpenOptions::new().append(true).open(&path).unwrap();
+        assert_eq!(journal.append(b"after").unwrap(), 1);
+        let reopened = FileJournal::open(&path).unwrap();
+        assert!(!reopened.recovery().was_repaired());
+        assert_eq!(
+            data(&reopened, 0),
+            vec![b"kept".to_vec(), b"after".to_vec()]
+        );
+    }
+
+    #[test]
+    fn test_journal_truncate() {
+        let (_dir, path) = temp_path();
+        let mut journal = FileJournal::open(&path).unwrap();
+        for i in 0..5u8 {
+            journal.append(&[i]).unwrap();
+        }
+        journal.truncate(3).unwrap();
+        assert_eq!(journal.base_sequence(), 3);
+        assert_eq!(data(&journal, 0), vec![vec![3], vec![4]]);
+        assert_eq!(journal.append(&[5]).unwrap(), 5);
+
+        let reopened = FileJournal::open(&path).unwrap();
+        assert_eq!(reopened.base_sequence(), 3);
+        assert_eq!(reopened.next_sequence(), 6);
+    }
+}
@@ -0,0 +1,256 @@
+//! File inspection for JSON-based sister formats.
+//!
+//! Binary sisters are identified by magic bytes. JSON sisters (Identity
+//! .aid files) follow the `JsonFormatDescriptor` convention instead:
+//! every file carries top-level `"format"` and `"version"` keys.
+//!
+//! ```json
+//! {
+//!   "format": "aid-v1",
+//!   "version": "1.0.0",
+//!   "sister_type": "identity",
+//!   "created_at": "2026-01-01T00:00:00Z",
+//!   ...sister-specific content...
+//! }
+//! ```
+//!
+//! Sisters can `#[serde(flatten)]` a `JsonFormatDescriptor` into their
+//! own file struct to get the convention for free.
+
+use super::{FileInfo, VersionCompatibility};
+use crate::errors::{ErrorCode, SisterError, SisterResult};
+use crate::types::{SisterType, Version};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::path::Path;
+
+/// Required top-level keys for JSON sister files.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonFormatDescriptor {
+    /// Format identifier (e.g., "aid-v1")
+    pub format: String,
+
+    /// Format version ("1.0.0" or `{major, minor, patch}`)
+    #[serde(with = "version_string")]
+    pub version: Version,
+
+    /// Owning sister. Optional — inferred from the format prefix if absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sister_type: Option<SisterType>,
+
+    /// When the file was created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// When the file was last modified
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl JsonFormatDescriptor {
+    /// Create a descriptor for a new file.
+    pub fn new(sister_type: SisterType, format: impl Into<String>, version: Version) -> Self {
+        let now = Utc::now();
+        Self {
+            format: format.into(),
+            version,
+            sister_type: Some(sister_type),
+            created_at: Some(now),
+            updated_at: Some(now),
+        }
+    }
+
+    /// Extract the descriptor from a parsed JSON document.
+    pub fn from_value(value: &serde_json::Value) -> SisterResult<Self> {
+        let obj = value.as_object().ok_or_else(|| {
+            SisterError::invalid_input("JSON sister file must be an object at the top level")
+        })?;
+        for key in ["format", "version"] {
+            if !obj.contains_key(key) {
+                return Err(SisterError::invalid_input(format!(
+                    "JSON sister file is missing required \"{}\" key",
+                    key
+                ))
+                .with_context("missing_key", key));
+            }
+        }
+        Ok(serde_json::from_value(value.clone())?)
+    }
+
+    /// Read the descriptor from a JSON file.
+    pub fn read(path: &Path) -> SisterResult<Self> {
+        let bytes = std::fs::read(path)?;
+        let value: serde_json::Value = serde_json::from_slice(&bytes)?;
+        Self::from_value(&value)
+    }
+
+    /// The owning sister: explicit `sister_type`, else inferred from `format`.
+    ///
+    /// "aid-v1" → Identity (matched against `SisterType::file_extension`).
+    pub fn resolved_sister_type(&self) -> Option<SisterType> {
+        self.sister_type.or_else(|| {
+            let prefix = self
+                .format
+                .split(|c: char| !c.is_ascii_alphanumeric())
+                .next()
+                .unwrap_or_default();
+            SisterType::from_file_extension(prefix)
+        })
+    }
+
+    /// Build a `FileInfo`, filling gaps from filesystem metadata.
+    pub fn to_file_info(
+        &self,
+        metadata: &std::fs::Metadata,
+        current_version: Option<&Version>,
+    ) -> SisterResult<FileInfo> {
+        let sister_type = self.resolved_sister_type().ok_or_else(|| {
+            SisterError::new(
+                ErrorCode::InvalidInput,
+                format!(
+                    "Cannot determine sister for JSON format \"{}\"",
+                    self.format
+                ),
+            )
+        })?;
+        let modified = metadata.modified().ok().map(DateTime::<Utc>::from);
+        let created = metadata.created().ok().map(DateTime::<Utc>::from);
+        let fallback = modified.unwrap_or_else(Utc::now);
+
+        Ok(FileInfo {
+            sister_type,
+            version: self.version.clone(),
+            created_at: self.created_at.or(created).unwrap_or(fallback),
+            updated_at: self.updated_at.or(modified).unwrap_or(fallback),
+            content_length: metadata.len(),
+            needs_migration: current_version
+                .map(|current| VersionCompatibility::needs_migration(current, &self.version))
+                .unwrap_or(false),
+            format_id: self.format.clone(),
+            compression: Default::default(),
+        })
+    }
+}
+
+/// Read `FileInfo` from a JSON sister file following the descriptor convention.
+pub fn read_json_file_info(path: &Path) -> SisterResult<FileInfo> {
+    let descriptor = JsonFormatDescriptor::read(path)?;
+    descriptor.to_file_info(&std::fs::metadata(path)?, None)
+}
+
+/// Identify which sister a JSON file belongs to (None if not a descriptor file).
+pub fn identify_sister_by_json(path: &Path) -> Option<SisterType> {
+    JsonFormatDescriptor::read(path)
+        .ok()
+        .and_then(|d| d.resolved_sister_type())
+}
+
+// Version as "major.minor.patch[-pre][+build]", also accepting "major[.minor]"
+// and the struct form on read
+mod version_string {
+    use super::*;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+        Text(String),
+        Struct(Version),
+    }
+
+    pub fn serialize<S>(version: &Version, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&version.to_string())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Version, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Repr::deserialize(deserializer)? {
+            Repr::Struct(v) => Ok(v),
+            Repr::Text(s) => {
+                if let Ok(version) = Version::parse(&s) {
+                    return Ok(version);
+                }
+                let parts: Vec<_> = s
+                    .trim_start_matches('v')
+                    .split('.')
+                    .map(str::parse::<u8>)
+                    .collect();
+                match parts.as_slice() {
+                    [Ok(major), Ok(minor), Ok(patch)] => Ok(Version::new(*major, *minor, *patch)),
+                    [Ok(major), Ok(minor)] => Ok(Version::new(*major, *minor, 0)),
+                    [Ok(major)] => Ok(Version::new(*major, 0, 0)),
+                    _ => Err(serde::de::Error::custom(format!(
+                        "invalid version string: {}",
+                        s
+                    ))),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn test_descriptor_infers_sister() {
+        let value = serde_json::json!({"format": "aid-v1", "version": "1.2.0", "keys": []});
+        let descriptor = JsonFormatDescriptor::from_value(&value).unwrap();
+        assert_eq!(descriptor.version, Version::new(1, 2, 0));
+        assert_eq!(
+            descriptor.resolved_sister_type(),
+            Some(SisterType::Identity)
+        );
+
+        let missing = serde_json::json!({"format": "aid-v1"});
+        let err = JsonFormatDescriptor::from_value(&missing).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidInput);
+    }
+
+    #[test]
+    fn test_read_json_file_info() {
+        let dir = TempDir::new("json");
+        let path = dir.join("agent.aid");
+        std::fs::write(
+            &path,
+            r#"{"format": "aid-v1", "version": {"major": 2, "minor": 0, "patch": 1},
+                "created_at": "2026-01-01T00:00:00Z", "identity": {}}"#,
+        )
+        .unwrap();
+        let info = read_json_file_info(&path).unwrap();
+        assert_eq!(info.sister_type, SisterType::Identity);
+        assert_eq!(info.version, Version::new(2, 0, 1));
+        assert_eq!(info.format_id, "aid-v1");
+        assert_eq!(info.created_at.to_rfc3339(), "2026-01-01T00:00:00+00:00");
+        assert_eq!(identify_sister_by_json(&path), Some(SisterType::Identity));
+    }
+
+    #[test]
+    fn test_descriptor_flatten_roundtrip() {
+        #[derive(Serialize, Deserialize)]
+        struct IdentityFile {
+            #[serde(flatten)]
+            descriptor: JsonFormatDescriptor,
+            name: String,
+        }
+
+        let file = IdentityFile {
+            descriptor: JsonFormatDescriptor::new(
+                SisterType::Identity,
+                "aid-v1",
+                Version::new(1, 0, 0),
+            ),
+            name: "agent".into(),
+        };
+        let value = serde_json::to_value(&file).unwrap();
+        assert_eq!(value["version"], "1.0.0");
+        let descriptor = JsonFormatDescriptor::from_value(&value).unwrap();
+        assert_eq!(descriptor, file.descriptor);
+    }
+}
@@ -0,0 +1,598 @@
+//! Advisory file locking for concurrent sister instances.
+//!
+//! Two MCP clients launching the same sister against one data file will
+//! corrupt it. `FileLock` lets the second instance notice and back off.
+//!
+//! Locks live in a `<file>.lock/` directory next to the data file:
+//!
+//! - `exclusive`      — at most one, held by a writer
+//! - `shared-<id>`    — any number, held by readers
+//!
+//! Each lock file holds a JSON `LockInfo` (PID + heartbeat). A lock whose
+//! process is gone, or whose heartbeat is older than `stale_after`, is
+//! considered stale and is taken over automatically, so a crashed sister
+//! never blocks its successor forever. A lock file whose info cannot be
+//! read is treated as held until it is `stale_after` old.
+//!
+//! Stale files are never deleted by path: they are renamed to a private
+//! tombstone first and checked to still be the stale holder, so of
+//! several processes taking over the same lock only one succeeds.
+//! Heartbeats are written through the holder's own file handle, so they
+//! can't overwrite a lock that was taken over in the meantime.
+//!
+//! These are advisory locks: they only protect against sisters that
+//! also use `FileLock`.
+
+use crate::errors::{ErrorCode, SisterError, SisterResult, SuggestedAction};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Default time after which a lock with no heartbeat is considered stale.
+pub const DEFAULT_STALE_AFTER: Duration = Duration::from_secs(30);
+
+const EXCLUSIVE_FILE: &str = "exclusive";
+const SHARED_PREFIX: &str = "shared-";
+
+/// Lock mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockMode {
+    /// Many readers, no writer
+    Shared,
+
+    /// Single writer, no readers
+    Exclusive,
+}
+
+/// Contents of a lock file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockInfo {
+    /// Process holding the lock
+    pub pid: u32,
+
+    /// Host the process runs on; `pid` is only checked on this host
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+
+    /// Unique holder ID (distinguishes holders within one process)
+    pub holder_id: String,
+
+    /// Lock mode
+    pub mode: LockMode,
+
+    /// When the lock was acquired
+    pub acquired_at: DateTime<Utc>,
+
+    /// Last heartbeat from the holder
+    pub heartbeat_at: DateTime<Utc>,
+}
+
+impl LockInfo {
+    /// Whether this lock should be considered abandoned.
+    ///
+    /// Holders on other hosts (shared storage) are judged by heartbeat
+    /// alone; their PIDs mean nothing here.
+    pub fn is_stale(&self, stale_after: Duration) -> bool {
+        let age = Utc::now()
+            .signed_duration_since(self.heartbeat_at)
+            .to_std()
+            .unwrap_or(Duration::ZERO);
+        age > stale_after || (self.is_local() && !process_alive(self.pid))
+    }
+
+    /// Whether the holder runs on this host.
+    pub fn is_local(&self) -> bool {
+        self.hostname.as_deref() == local_hostname()
+    }
+}
+
+/// A held advisory lock. Released on drop.
+#[derive(Debug)]
+pub struct FileLock {
+    lock_dir: PathBuf,
+    lock_file: PathBuf,
+    file: File,
+    info: LockInfo,
+    stale_after: Duration,
+    released: bool,
+}
+
+impl FileLock {
+    /// Acquire a lock on `target` with the default staleness timeout.
+    pub fn acquire(target: &Path, mode: LockMode) -> SisterResult<Self> {
+        Self::acquire_with(target, mode, DEFAULT_STALE_AFTER)
+    }
+
+    /// Acquire a lock on `target`, treating holders silent for `stale_after` as dead.
+    ///
+    /// Fails immediately (no waiting) with `InvalidState` if a live
+    /// conflicting lock exists.
+    pub fn acquire_with(
+        target: &Path,
+        mode: LockMode,
+        stale_after: Duration,
+    ) -> SisterResult<Self> {
+        let lock_dir = Self::lock_dir_for(target);
+        std::fs::create_dir_all(&lock_dir)?;
+
+        let now = Utc::now();
+        let info = LockInfo {
+            pid: std::process::id(),
+            hostname: local_hostname().map(str::to_string),
+            holder_id: uuid::Uuid::new_v4().simple().to_string(),
+            mode,
+            acquired_at: now,
+            heartbeat_at: now,
+        };
+        let lock_file = match mode {
+            LockMode::Exclusive => lock_dir.join(EXCLUSIVE_FILE),
+            LockMode::Shared => lock_dir.join(format!("{}{}", SHARED_PREFIX, info.holder_id)),
+        };
+
+        // Claim our slot first, then look for conflicts; two racing
+        // claimants both see each other and at least one backs off.
+        let file = match create_lock_file(&lock_file, &info)? {
+            Some(file) => file,
+            None => {
+                let existing = Seen::read(&lock_file);
+                if is_live(&lock_file, existing.info.as_ref(), stale_after) {
+                    return Err(busy(target, existing.info.as_ref()));
+                }
+                let claimed = if remove_if_unchanged(&lock_file, &existing)? {
+                    create_lock_file(&lock_file, &info)?
+                } else {
+                    None
+                };
+                match claimed {
+                    Some(file) => file,
+                    None => return Err(busy(target, read_info(&lock_file).as_ref())),
+                }
+            }
+        };
+
+        let lock = Self {
+            lock_dir,
+            lock_file,
+            file,
+            info,
+            stale_after,
+            released: false,
+        };
+
+        for (path, other) in lock.other_holders()? {
+            let conflicts = mode == LockMode::Exclusive || mode_of(&path) == LockMode::Exclusive;
+            if !conflicts {
+                continue;
+            }
+            // Drop releases our claim
+            if is_live(&path, other.info.as_ref(), stale_after) {
+                return Err(busy(target, other.info.as_ref()));
+            }
+            if !remove_if_unchanged(&path, &other)? {
+                return Err(busy(target, read_info(&path).as_ref()));
+            }
+        }
+
+        Ok(lock)
+    }
+
+    /// The `<file>.lock` directory used for `target`.
+    pub fn lock_dir_for(target: &Path) -> PathBuf {
+        let mut name = target.file_name().unwrap_or_default().to_os_string();
+        name.push(".lock");
+        target.with_file_name(name)
+    }
+
+    /// Inspect current (non-stale) holders of `target`'s lock.
+    pub fn holders(target: &Path) -> SisterResult<Vec<LockInfo>> {
+        Self::holders_with(target, DEFAULT_STALE_AFTER)
+    }
+
+    /// Inspect holders of `target`'s lock not silent for `stale_after`.
+    pub fn holders_with(target: &Path, stale_after: Duration) -> SisterResult<Vec<LockInfo>> {
+        Ok(list_lock_files(&Self::lock_dir_for(target))?
+            .into_iter()
+            .filter_map(|p| read_info(&p))
+            .filter(|i| !i.is_stale(stale_after))
+            .collect())
+    }
+
+    /// Information about this lock.
+    pub fn info(&self) -> &LockInfo {
+        &self.info
+    }
+
+    /// Lock mode.
+    pub fn mode(&self) -> LockMode {
+        self.info.mode
+    }
+
+    /// Refresh the heartbeat. Call more often than `stale_after`.
+    ///
+    /// Fails with `InvalidState` if the lock was taken over as stale.
+    /// The write goes through this lock's own file handle, so even a
+    /// takeover racing with it leaves the new holder's lock file alone.
+    pub fn heartbeat(&mut self) -> SisterResult<()> {
+        if !self.is_ours(&self.lock_file) {
+            return Err(SisterError::new(
+                ErrorCode::InvalidState,
+                format!("Lock {} is no longer held", self.lock_file.display()),
+            )
+            .with_context("holder_id", &self.info.holder_id));
+        }
+        self.info.heartbeat_at = Utc::now();
+        let bytes = serde_json::to_vec(&self.info)?;
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file.write_all(&bytes)?;
+        self.file.set_len(bytes.len() as u64)?;
+        self.file.sync_data()?;
+        Ok(())
+    }
+
+    /// Release the lock explicitly.
+    pub fn release(mut self) -> SisterResult<()> {
+        self.release_inner()
+    }
+
+    fn release_inner(&mut self) -> SisterResult<()> {
+        if self.released {
+            return Ok(());
+        }
+        self.released = true;
+        // Only remove the file if it is still ours (it may have been taken over as stale)
+        let tombstone = tombstone_for(&self.lock_file);
+        match std::fs::rename(&self.lock_file, &tombstone) {
+            Ok(()) if self.is_ours(&tombstone) => std::fs::remove_file(&tombstone)?,
+            Ok(()) => restore(&tombstone, &self.lock_file),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+        // Remove the directory if we were the last holder; ignore "not empty"
+        std::fs::remove_dir(&self.lock_dir).ok();
+        Ok(())
+    }
+
+    /// Whether the lock file at `path` names this holder.
+    fn is_ours(&self, path: &Path) -> bool {
+        read_info(path).is_some_and(|i| i.holder_id == self.info.holder_id)
+    }
+
+    /// Other lock files, with what they held when listed.
+    fn other_holders(&self) -> SisterResult<Vec<(PathBuf, Seen)>> {
+        Ok(list_lock_files(&self.lock_dir)?
+            .into_iter()
+            .filter(|p| p != &self.lock_file)
+            .map(|p| {
+                let seen = Seen::read(&p);
+                (p, seen)
+            })
+            .collect())
+    }
+
+    /// Staleness timeout used by this lock.
+    pub fn stale_after(&self) -> Duration {
+        self.stale_after
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = self.release_inner();
+    }
+}
+
+/// Create `path` holding `info` and return its handle, or `None` if it
+/// already exists.
+///
+/// The info is written to a temporary file first and hard-linked into
+/// place, so the lock file never exists without its contents.
+fn create_lock_file(path: &Path, info: &LockInfo) -> SisterResult<Option<File>> {
+    let tmp = path.with_file_name(format!(".{}.tmp", info.holder_id));
+    let mut file = File::create(&tmp)?;
+    let written = file
+        .write_all(&serde_json::to_vec(info)?)
+        .and_then(|_| file.sync_all());
+    let linked = written.and_then(|_| std::fs::hard_link(&tmp, path));
+    std::fs::remove_file(&tmp).ok();
+    match linked {
+        Ok(()) => Ok(Some(file)),
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// What a lock file held when it was judged stale.
+#[derive(Debug, PartialEq)]
+struct Seen {
+    info: Option<LockInfo>,
+    modified: Option<SystemTime>,
+}
+
+impl Seen {
+    fn read(path: &Path) -> Self {
+        Self {
+            info: read_info(path),
+            modified: std::fs::metadata(path).and_then(|m| m.modified()).ok(),
+        }
+    }
+}
+
+/// Remove the stale lock file at `path` if it still holds `seen`.
+///
+/// The file is renamed to a unique tombstone first, which only one of
+/// several racing callers can do. If the tombstone turns out to be a
+/// newer lock (the stale one was already replaced), it is put back.
+/// Returns whether the stale file is gone.
+fn remove_if_unchanged(path: &Path, seen: &Seen) -> SisterResult<bool> {
+    let tombstone = tombstone_for(path);
+    match std::fs::rename(path, &tombstone) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(true),
+        Err(e) => return Err(e.into()),
+    }
+    if Seen::read(&tombstone) == *seen {
+        std::fs::remove_file(&tombstone)?;
+        Ok(true)
+    } else {
+        restore(&tombstone, path);
+        Ok(false)
+    }
+}
+
+/// Move a lock file renamed by mistake back to `path`, unless a new one
+/// has been created there meanwhile.
+fn restore(tombstone: &Path, path: &Path) {
+    std::fs::hard_link(tombstone, path).ok();
+    std::fs::remove_file(tombstone).ok();
+}
+
+/// A unique name in the lock directory that `list_lock_files` skips.
+fn tombstone_for(path: &Path) -> PathBuf {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!(".{}.{}.stale", name, uuid::Uuid::new_v4().simple()))
+}
+
+/// Whether the lock file at `path` must be respected.
+///
+/// A file whose info cannot be read (damaged, or written by something
+/// else) counts as held until it is `stale_after` old.
+fn is_live(path: &Path, info: Option<&LockInfo>, stale_after: Duration) -> bool {
+    match info {
+        Some(info) => !info.is_stale(stale_after),
+        None => std::fs::metadata(path)
+            .and_then(|m| m.modified())
+            .is_ok_and(|modified| modified.elapsed().unwrap_or(Duration::ZERO) <= stale_after),
+    }
+}
+
+fn mode_of(path: &Path) -> LockMode {
+    if path.file_name().is_some_and(|n| n == EXCLUSIVE_FILE) {
+        LockMode::Exclusive
+    } else {
+        LockMode::Shared
+    }
+}
+
+fn read_info(path: &Path) -> Option<LockInfo> {
+    std::fs::read(path)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+}
+
+fn list_lock_files(dir: &Path) -> SisterResult<Vec<PathBuf>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n == EXCLUSIVE_FILE || n.starts_with(SHARED_PREFIX))
+        })
+        .collect())
+}
+
+fn busy(target: &Path, holder: Option<&LockInfo>) -> SisterError {
+    match holder {
+        Some(holder) => conflict(target, holder),
+        None => SisterError::new(
+            ErrorCode::InvalidState,
+            format!("Lock on {} is being contended", target.display()),
+        ),
+    }
+}
+
+fn conflict(target: &Path, holder: &LockInfo) -> SisterError {
+    SisterError::new(
+        ErrorCode::InvalidState,
+        format!(
+            "{} is locked ({:?}) by process {}",
+            target.display(),
+            holder.mode,
+            holder.pid
+        ),
+    )
+    .with_context("lock_holder_pid", holder.pid)
+    .with_context("lock_mode", holder.mode)
+    .with_suggestion(SuggestedAction::UserAction {
+        description:
+            "Another sister instance is using this data file; stop it or use a different data path"
+                .into(),
+    })
+}
+
+/// This machine's hostname, if it can be determined.
+fn local_hostname() -> Option<&'static str> {
+    static HOSTNAME: std::sync::OnceLock<Option<String>> = std::sync::OnceLock::new();
+    HOSTNAME
+        .get_or_init(|| {
+            std::fs::read_to_string("/proc/sys/kernel/hostname")
+                .or_else(|_| std::fs::read_to_string("/etc/hostname"))
+                .ok()
+                .or_else(|| std::env::var("COMPUTERNAME").ok())
+                .or_else(|| std::env::var("HOSTNAME").ok())
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+        })
+        .as_deref()
+}
+
+/// Best-effort liveness check. Without `/proc` we rely on heartbeats alone.
+fn process_alive(pid: u32) -> bool {
+    if pid == std::process::id() {
+        return true;
+    }
+    let proc_root = Path::new("/proc");
+    if proc_root.is_dir() {
+        proc_root.join(pid.to_string()).exists()
+    } else {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_format::atomic_write;
+    use crate::test_support::TempDir;
+
+    fn target() -> (TempDir, PathBuf) {
+        let dir = TempDir::new("lock");
+        let path = dir.join("data.amem");
+        (dir, path)
+    }
+
+    #[test]
+    fn test_exclusive_lock_conflicts() {
+        let (_dir, path) = target();
+        let lock = FileLock::acquire(&path, LockMode::Exclusive).unwrap();
+        assert_eq!(FileLock::holders(&path).unwrap().len(), 1);
+
+        let err = FileLock::acquire(&path, LockMode::Exclusive).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidState);
+        assert!(FileLock::acquire(&path, LockMode::Shared).is_err());
+
+        lock.release().unwrap();
+        assert!(FileLock::acquire(&path, LockMode::Exclusive).is_ok());
+    }
+
+    #[test]
+    fn test_shared_locks_coexist() {
+        let (_dir, path) = target();
+        let a = FileLock::acquire(&path, LockMode::Shared).unwrap();
+        let b = FileLock::acquire(&path, LockMode::Shared).unwrap();
+        assert_eq!(FileLock::holders(&path).unwrap().len(), 2);
+        assert!(FileLock::acquire(&path, LockMode::Exclusive).is_err());
+
+        drop(a);
+        drop(b);
+        assert!(FileLock::acquire(&path, LockMode::Exclusive).is_ok());
+    }
+
+    #[test]
+    fn test_stale_lock_taken_over() {
+        let (_dir, path) = target();
+        let mut stale = FileLock::acquire(&path, LockMode::Exclusive).unwrap();
+        stale.info.heartbeat_at = Utc::now() - chrono::Duration::seconds(120);
+        atomic_write(&stale.lock_file, &serde_json::to_vec(&stale.info).unwrap()).unwrap();
+
+        let mut fresh = FileLock::acquire(&path, LockMode::Exclusive).unwrap();
+        assert_ne!(fresh.info().holder_id, stale.info().holder_id);
+        fresh.heartbeat().unwrap();
+        assert_eq!(read_info(&fresh.lock_file).unwrap(), fresh.info);
+
+        // The old holder cannot overwrite the new one's lock
+        let err = stale.heartbeat().unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidState);
+        assert_eq!(read_info(&fresh.lock_file).unwrap(), fresh.info);
+
+        // Releasing the stale guard must not remove the new holder's lock
+        stale.release().unwrap();
+        assert_eq!(FileLock::holders(&path).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_racing_stale_takeovers_have_one_winner() {
+        let (_dir, path) = target();
+        let lock_dir = FileLock::lock_dir_for(&path);
+        let old = Utc::now() - chrono::Duration::seconds(120);
+
+        for round in 0..20 {
+            std::fs::create_dir_all(&lock_dir).unwrap();
+            let stale = LockInfo {
+                pid: std::process::id(),
+                hostname: local_hostname().map(str::to_string),
+                holder_id: format!("stale-{}", round),
+                mode: LockMode::Exclusive,
+                acquired_at: old,
+                heartbeat_at: old,
+            };
+            let exclusive = lock_dir.join(EXCLUSIVE_FILE);
+            assert!(create_lock_file(&exclusive, &stale).unwrap().is_some());
+
+            let barrier = std::sync::Barrier::new(4);
+            let winners: Vec<FileLock> = std::thread::scope(|s| {
+                let handles: Vec<_> = (0..4)
+                    .map(|_| {
+                        s.spawn(|| {
+                            barrier.wait();
+                            FileLock::acquire(&path, LockMode::Exclusive).ok()
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .filter_map(|h| h.join().unwrap())
+                    .collect()
+            });
+            assert_eq!(winners.len(), 1, "round {}", round);
+            assert_eq!(read_info(&exclusive).unwrap(), winners[0].info);
+        }
+    }
+
+    #[test]
+    fn test_remote_holder_judged_by_heartbeat() {
+        let now = Utc::now();
+        let mut info = LockInfo {
+            // No such process here
+            pid: u32::MAX,
+            hostname: Some("elsewhere.invalid".into()),
+            holder_id: "remote".into(),
+            mode: LockMode::Exclusive,
+            acquired_at: now,
+            heartbeat_at: now,
+        };
+        assert!(!info.is_local());
+        assert!(!info.is_stale(DEFAULT_STALE_AFTER));
+
+        info.heartbeat_at = now - chrono::Duration::seconds(120);
+        assert!(info.is_stale(DEFAULT_STALE_AFTER));
+    }
+
+    #[test]
+    fn test_unreadable_lock_file_is_held() {
+        let (_dir, path) = target();
+        let lock_dir = FileLock::lock_dir_for(&path);
+        std::fs::create_dir_all(&lock_dir).unwrap();
+        // A lock file caught before its info was written
+        std::fs::write(lock_dir.join(EXCLUSIVE_FILE), b"").unwrap();
+
+        let err = FileLock::acquire(&path, LockMode::Exclusive).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidState);
+        assert!(FileLock::acquire(&path, LockMode::Shared).is_err());
+
+        // Once past the staleness timeout it is taken over
+        std::thread::sleep(Duration::from_millis(20));
+        let lock =
+            FileLock::acquire_with(&path, LockMode::Exclusive, Duration::from_millis(10)).unwrap();
+        assert_eq!(read_info(&lock.lock_file).unwrap(), lock.info);
+    }
+}
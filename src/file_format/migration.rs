@@ -0,0 +1,368 @@
+//! Chained file migrations.
+//!
+//! `FileFormatReader::migrate` gives a sister one function, but real
+//! migrations are chains (v1 → v2 → v3). Sisters register each step once
+//! in a `MigrationRegistry`; the registry finds the shortest path between
+//! any two versions and runs it, producing a `MigrationReport`.
+//!
+//! ```rust,ignore
+//! let mut registry = MigrationRegistry::new(SisterType::Memory);
+//! registry.register(Migrator::new((1, 0, 0), (2, 0, 0), v1_to_v2));
+//! registry.register(Migrator::new((2, 0, 0), (3, 0, 0), v2_to_v3));
+//!
+//! let (bytes, report) = registry.migrate(&old, &Version::new(1, 0, 0), &Version::new(3, 0, 0))?;
+//! ```
+
+use crate::errors::{ErrorCode, SisterError, SisterResult};
+use crate::types::{SisterType, Version};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+type MigrateFn = Box<dyn Fn(&[u8]) -> SisterResult<Vec<u8>> + Send + Sync>;
+
+/// A single migration step from one version to another.
+pub struct Migrator {
+    /// Version this step reads
+    pub from: Version,
+
+    /// Version this step produces
+    pub to: Version,
+
+    /// Human-readable description of what changes
+    pub description: String,
+
+    migrate: MigrateFn,
+}
+
+impl Migrator {
+    /// Create a migration step.
+    pub fn new<F>(from: impl Into<Version>, to: impl Into<Version>, migrate: F) -> Self
+    where
+        F: Fn(&[u8]) -> SisterResult<Vec<u8>> + Send + Sync + 'static,
+    {
+        let from = from.into();
+        let to = to.into();
+        Self {
+            description: format!("{} -> {}", from, to),
+            from,
+            to,
+            migrate: Box::new(migrate),
+        }
+    }
+
+    /// Set the description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Run this step.
+    pub fn migrate(&self, data: &[u8]) -> SisterResult<Vec<u8>> {
+        (self.migrate)(data)
+    }
+}
+
+impl std::fmt::Debug for Migrator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Migrator")
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("description", &self.description)
+            .finish()
+    }
+}
+
+/// Report for one executed migration step.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationStepReport {
+    pub from: Version,
+    pub to: Version,
+    pub description: String,
+    pub input_bytes: usize,
+    pub output_bytes: usize,
+    #[serde(with = "duration_millis")]
+    pub duration: Duration,
+}
+
+/// Outcome of running (or dry-running) a migration chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationReport {
+    /// Sister whose files were migrated
+    pub sister_type: SisterType,
+
+    /// Starting version
+    pub from: Version,
+
+    /// Target version
+    pub to: Version,
+
+    /// Steps that ran, in order
+    pub steps: Vec<MigrationStepReport>,
+
+    /// Whether this was a dry run (output discarded)
+    pub dry_run: bool,
+
+    /// Whether every step succeeded
+    pub success: bool,
+
+    /// The error that stopped the chain (if any)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<SisterError>,
+}
+
+impl MigrationReport {
+    /// Total time spent across all steps.
+    pub fn total_duration(&self) -> Duration {
+        self.steps.iter().map(|s| s.duration).sum()
+    }
+}
+
+/// Registry of migration steps for one sister's file format.
+#[derive(Debug)]
+pub struct MigrationRegistry {
+    sister_type: SisterType,
+    steps: Vec<Migrator>,
+}
+
+impl MigrationRegistry {
+    /// Create an empty registry.
+    pub fn new(sister_type: SisterType) -> Self {
+        Self {
+            sister_type,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Register a migration step.
+    ///
+    /// A later registration for the same `from → to` pair replaces the earlier one.
+    pub fn register(&mut self, migrator: Migrator) -> &mut Self {
+        self.steps
+            .retain(|m| !(m.from == migrator.from && m.to == migrator.to));
+        self.steps.push(migrator);
+        self
+    }
+
+    /// Builder-style registration.
+    pub fn with(mut self, migrator: Migrator) -> Self {
+        self.register(migrator);
+        self
+    }
+
+    /// The sister this registry belongs to.
+    pub fn sister_type(&self) -> SisterType {
+        self.sister_type
+    }
+
+    /// All registered steps.
+    pub fn steps(&self) -> &[Migrator] {
+        &self.steps
+    }
+
+    /// Check whether a path exists between two versions.
+    pub fn can_migrate(&self, from: &Version, to: &Version) -> bool {
+        self.find_path(from, to).is_ok()
+    }
+
+    /// Find the shortest chain of steps from `from` to `to`.
+    ///
+    /// Returns an empty chain when the versions are equal.
+    pub fn find_path(&self, from: &Version, to: &Version) -> SisterResult<Vec<&Migrator>> {
+        if from == to {
+            return Ok(vec![]);
+        }
+
+        // Breadth-first search over versions; remembers which step reached each one
+        let mut came_from: HashMap<&Version, usize> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(current) = queue.pop_front() {
+            for (idx, step) in self.steps.iter().enumerate() {
+                if &step.from != current || step.to == *from || came_from.contains_key(&step.to) {
+                    continue;
+                }
+                came_from.insert(&step.to, idx);
+                if &step.to == to {
+                    let mut path = vec![];
+                    let mut cursor = to;
+                    while let Some(&i) = came_from.get(cursor) {
+                        path.push(&self.steps[i]);
+                        cursor = &self.steps[i].from;
+                    }
+                    path.reverse();
+                    return Ok(path);
+                }
+                queue.push_back(&step.to);
+            }
+        }
+
+        Err(SisterError::new(
+            ErrorCode::VersionMismatch,
+            format!(
+                "No migration path for {} files from {} to {}",
+                self.sister_type, from, to
+            ),
+        )
+        .with_context("from_version", from.to_string())
+        .with_context("to_version", to.to_string()))
+    }
+
+    /// Run the migration chain, returning the migrated bytes and a report.
+    pub fn migrate(
+        &self,
+        data: &[u8],
+        from: &Version,
+        to: &Version,
+    ) -> SisterResult<(Vec<u8>, MigrationReport)> {
+        let (output, report) = self.run(data, from, to, false)?;
+        match report.error {
+            Some(ref e) => Err(e.clone()),
+            None => Ok((output, report)),
+        }
+    }
+
+    /// Run the chain without keeping the output.
+    ///
+    /// Never returns an error for failing steps — the failure is
+    /// recorded in the report so callers can show what would break.
+    pub fn dry_run(&self, data: &[u8], from: &Version, to: &Version) -> MigrationReport {
+        match self.run(data, from, to, true) {
+            Ok((_, report)) => report,
+            Err(e) => MigrationReport {
+                sister_type: self.sister_type,
+                from: from.clone(),
+                to: to.clone(),
+                steps: vec![],
+                dry_run: true,
+                success: false,
+                error: Some(e),
+            },
+        }
+    }
+
+    fn run(
+        &self,
+        data: &[u8],
+        from: &Version,
+        to: &Version,
+        dry_run: bool,
+    ) -> SisterResult<(Vec<u8>, MigrationReport)> {
+        let path = self.find_path(from, to)?;
+        let mut report = MigrationReport {
+            sister_type: self.sister_type,
+            from: from.clone(),
+            to: to.clone(),
+            steps: Vec::with_capacity(path.len()),
+            dry_run,
+            success: true,
+            error: None,
+        };
+
+        let mut current = data.to_vec();
+        for step in path {
+            let started = Instant::now();
+            match step.migrate(&current) {
+                Ok(next) => {
+                    report.steps.push(MigrationStepReport {
+                        from: step.from.clone(),
+                        to: step.to.clone(),
+                        description: step.description.clone(),
+                        input_bytes: current.len(),
+                        output_bytes: next.len(),
+                        duration: started.elapsed(),
+                    });
+                    current = next;
+                }
+                Err(e) => {
+                    report.success = false;
+                    report.error = Some(
+                        e.with_context("failed_step", format!("{} -> {}", step.from, step.to)),
+                    );
+                    break;
+                }
+            }
+        }
+
+        Ok((current, report))
+    }
+}
+
+mod duration_millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(duration.as_millis() as u64)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ms = u64::deserialize(deserializer)?;
+        Ok(Duration::from_millis(ms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn append(tag: u8) -> impl Fn(&[u8]) -> SisterResult<Vec<u8>> + Send + Sync {
+        move |data| {
+            let mut out = data.to_vec();
+            out.push(tag);
+            Ok(out)
+        }
+    }
+
+    fn registry() -> MigrationRegistry {
+        MigrationRegistry::new(SisterType::Memory)
+            .with(Migrator::new((1, 0, 0), (2, 0, 0), append(2)))
+            .with(Migrator::new((2, 0, 0), (3, 0, 0), append(3)))
+            .with(Migrator::new((1, 0, 0), (1, 5, 0), append(15)))
+    }
+
+    #[test]
+    fn test_migration_chain() {
+        let (bytes, report) = registry()
+            .migrate(&[1], &Version::new(1, 0, 0), &Version::new(3, 0, 0))
+            .unwrap();
+
+        assert_eq!(bytes, vec![1, 2, 3]);
+        assert_eq!(report.steps.len(), 2);
+        assert!(report.success);
+        assert!(!report.dry_run);
+    }
+
+    #[test]
+    fn test_migration_no_path() {
+        let reg = registry();
+        assert!(!reg.can_migrate(&Version::new(3, 0, 0), &Version::new(1, 0, 0)));
+        let err = reg
+            .find_path(&Version::new(1, 5, 0), &Version::new(3, 0, 0))
+            .unwrap_err();
+        assert_eq!(err.code, ErrorCode::VersionMismatch);
+        assert!(reg
+            .find_path(&Version::new(2, 0, 0), &Version::new(2, 0, 0))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_migration_dry_run_reports_failure() {
+        let reg = registry().with(Migrator::new((2, 0, 0), (3, 0, 0), |_: &[u8]| {
+            Err(SisterError::invalid_input("bad v2 payload"))
+        }));
+
+        let report = reg.dry_run(&[1], &Version::new(1, 0, 0), &Version::new(3, 0, 0));
+        assert!(report.dry_run);
+        assert!(!report.success);
+        assert_eq!(report.steps.len(), 1);
+        assert_eq!(report.error.unwrap().code, ErrorCode::InvalidInput);
+    }
+}
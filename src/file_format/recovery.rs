@@ -0,0 +1,200 @@
+//! Best-effort recovery of damaged files.
+//!
+//! A failed checksum used to be a dead end: `ChecksumMismatch`, nothing
+//! else. `FileFormatReader::recover` lets a sister describe which byte
+//! regions of a damaged file are still usable and hand back whatever it
+//! could salvage, so the user loses a few records instead of everything.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// State of a byte region in a damaged file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RegionStatus {
+    /// Verified good
+    Intact,
+
+    /// Present but unverifiable; may or may not be correct
+    Suspect,
+
+    /// Present but known to be bad
+    Damaged,
+
+    /// Expected but absent (truncated file)
+    Missing,
+}
+
+impl RegionStatus {
+    /// Whether data in this region can be used.
+    pub fn is_salvageable(&self) -> bool {
+        matches!(self, Self::Intact | Self::Suspect)
+    }
+}
+
+/// A contiguous byte range in a file and its recovery status.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SalvageRegion {
+    /// Start offset in the file
+    pub offset: u64,
+
+    /// Length in bytes
+    pub len: u64,
+
+    /// Region state
+    pub status: RegionStatus,
+
+    /// What the region holds (e.g., "header", "node table", "record 17")
+    pub label: String,
+}
+
+impl SalvageRegion {
+    /// Create a region.
+    pub fn new(offset: u64, len: u64, status: RegionStatus, label: impl Into<String>) -> Self {
+        Self {
+            offset,
+            len,
+            status,
+            label: label.into(),
+        }
+    }
+
+    /// End offset (exclusive).
+    pub fn end(&self) -> u64 {
+        self.offset + self.len
+    }
+}
+
+/// Outcome of `FileFormatReader::recover`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecoveryReport {
+    /// File that was examined
+    pub path: PathBuf,
+
+    /// Size of the file on disk
+    pub file_size: u64,
+
+    /// Byte regions and their state, in file order
+    pub regions: Vec<SalvageRegion>,
+
+    /// Logical items (records, nodes, entries) recovered
+    pub items_recovered: u64,
+
+    /// Logical items known to be lost
+    pub items_lost: u64,
+
+    /// Salvaged content, in the sister's normal serialized form.
+    /// Feed it to the sister's parser or write it to a new file
+    #[serde(skip)]
+    pub recovered_data: Option<Vec<u8>>,
+
+    /// Human-readable notes about what was done
+    #[serde(default)]
+    pub notes: Vec<String>,
+
+    /// When recovery ran
+    pub recovered_at: DateTime<Utc>,
+}
+
+impl RecoveryReport {
+    /// Start an empty report for `path`.
+    pub fn new(path: &Path, file_size: u64) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            file_size,
+            regions: Vec::new(),
+            items_recovered: 0,
+            items_lost: 0,
+            recovered_data: None,
+            notes: Vec::new(),
+            recovered_at: Utc::now(),
+        }
+    }
+
+    /// Record a region.
+    pub fn region(
+        mut self,
+        offset: u64,
+        len: u64,
+        status: RegionStatus,
+        label: impl Into<String>,
+    ) -> Self {
+        self.regions
+            .push(SalvageRegion::new(offset, len, status, label));
+        self
+    }
+
+    /// Record item counts.
+    pub fn items(mut self, recovered: u64, lost: u64) -> Self {
+        self.items_recovered = recovered;
+        self.items_lost = lost;
+        self
+    }
+
+    /// Attach salvaged content.
+    pub fn with_data(mut self, data: Vec<u8>) -> Self {
+        self.recovered_data = Some(data);
+        self
+    }
+
+    /// Add a note.
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    /// Bytes in salvageable regions.
+    pub fn salvageable_bytes(&self) -> u64 {
+        self.regions
+            .iter()
+            .filter(|r| r.status.is_salvageable())
+            .map(|r| r.len)
+            .sum()
+    }
+
+    /// Bytes in damaged or missing regions.
+    pub fn lost_bytes(&self) -> u64 {
+        self.regions
+            .iter()
+            .filter(|r| !r.status.is_salvageable())
+            .map(|r| r.len)
+            .sum()
+    }
+
+    /// Whether anything was salvaged.
+    pub fn has_recovered_data(&self) -> bool {
+        self.recovered_data.is_some()
+    }
+
+    /// Whether every region is intact (nothing was actually wrong).
+    pub fn is_clean(&self) -> bool {
+        self.regions
+            .iter()
+            .all(|r| r.status == RegionStatus::Intact)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_accounting() {
+        let report = RecoveryReport::new(Path::new("brain.amem"), 200)
+            .region(0, 64, RegionStatus::Intact, "header")
+            .region(64, 100, RegionStatus::Suspect, "payload")
+            .region(164, 36, RegionStatus::Damaged, "index")
+            .items(10, 2)
+            .note("index rebuilt from payload");
+
+        assert_eq!(report.salvageable_bytes(), 164);
+        assert_eq!(report.lost_bytes(), 36);
+        assert!(!report.is_clean());
+        assert!(!report.has_recovered_data());
+        assert_eq!(report.regions[2].end(), 200);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["regions"][1]["status"], "suspect");
+    }
+}
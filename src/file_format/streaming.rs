@@ -0,0 +1,264 @@
+//! Partial / streaming file access.
+//!
+//! `FileFormatReader::read_file` loads everything, which is not an option
+//! for a 2 GB .amem file. `StreamingFileReader` lets sisters expose the
+//! header, a record iterator, and raw byte ranges without reading the
+//! whole file into memory.
+
+use super::{CompressionCodec, ContainerHeader, FileInfo, CONTAINER_HEADER_SIZE};
+use crate::errors::{ErrorCode, SisterError, SisterResult};
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// Iterator over records read lazily from a file.
+pub type RecordIter<'a, R> = Box<dyn Iterator<Item = SisterResult<R>> + 'a>;
+
+/// Streaming reader for large sister files.
+///
+/// `records()` yields records one at a time; `read_range()` gives random
+/// access to payload bytes so sisters can page in only what they need.
+pub trait StreamingFileReader: Sized {
+    /// Parsed header type
+    type Header;
+
+    /// Record type yielded by `records()`
+    type Record;
+
+    /// Open a file for streaming (reads nothing beyond what is needed to validate it)
+    fn open(path: &Path) -> SisterResult<Self>;
+
+    /// Read and parse the file header
+    fn read_header(&mut self) -> SisterResult<Self::Header>;
+
+    /// Iterate records from the start of the payload
+    fn records(&mut self) -> SisterResult<RecordIter<'_, Self::Record>>;
+
+    /// Read `len` payload bytes starting at payload `offset`
+    fn read_range(&mut self, offset: u64, len: usize) -> SisterResult<Vec<u8>>;
+
+    /// Quick file info (same shape as `FileFormatReader::can_read`)
+    fn file_info(&mut self) -> SisterResult<FileInfo>;
+}
+
+/// Bounds-checked positional reads over a region of a file.
+///
+/// Sisters implementing `StreamingFileReader` for their own formats can
+/// embed this to get `read_range` for free.
+#[derive(Debug)]
+pub struct ByteRangeReader {
+    file: BufReader<File>,
+    start: u64,
+    len: u64,
+}
+
+impl ByteRangeReader {
+    /// Read the region `[start, start + len)` of the file.
+    pub fn new(file: File, start: u64, len: u64) -> Self {
+        Self {
+            file: BufReader::new(file),
+            start,
+            len,
+        }
+    }
+
+    /// Region length in bytes.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Whether the region is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Read `len` bytes at region-relative `offset`.
+    pub fn read_range(&mut self, offset: u64, len: usize) -> SisterResult<Vec<u8>> {
+        let end = offset.checked_add(len as u64);
+        if end.is_none_or(|end| end > self.len) {
+            return Err(SisterError::invalid_input(format!(
+                "Range {}+{} exceeds payload length {}",
+                offset, len, self.len
+            ))
+            .with_context("offset", offset)
+            .with_context("len", len));
+        }
+        self.file.seek(SeekFrom::Start(self.start + offset))?;
+        let mut buf = vec![0u8; len];
+        self.file.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+/// Streaming reader for `ContainerFile`s.
+///
+/// Records are fixed-size payload chunks. The BLAKE3 checksum is
+/// computed incrementally while iterating and checked after the last
+/// chunk, so corruption still surfaces as `ChecksumMismatch`.
+#[derive(Debug)]
+pub struct ContainerStreamReader {
+    header: ContainerHeader,
+    range: ByteRangeReader,
+    chunk_size: usize,
+}
+
+impl ContainerStreamReader {
+    /// Default chunk size for `records()` (1 MiB).
+    pub const DEFAULT_CHUNK_SIZE: usize = 1024 * 1024;
+
+    /// Set the chunk size used by `records()`.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// The parsed header.
+    pub fn header(&self) -> &ContainerHeader {
+        &self.header
+    }
+}
+
+impl StreamingFileReader for ContainerStreamReader {
+    type Header = ContainerHeader;
+    type Record = Vec<u8>;
+
+    fn open(path: &Path) -> SisterResult<Self> {
+        let header = ContainerHeader::read_from(path)?;
+        let compression = header.compression()?;
+        if compression != CompressionCodec::None {
+            return Err(SisterError::new(
+                ErrorCode::InvalidState,
+                format!(
+                    "{}-compressed containers cannot be streamed; use read_file",
+                    compression
+                ),
+            ));
+        }
+
+        let file = File::open(path)?;
+        let available = file
+            .metadata()?
+            .len()
+            .saturating_sub(CONTAINER_HEADER_SIZE as u64);
+        if available < header.payload_len {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                format!(
+                    "Payload truncated: header says {} bytes, file has {}",
+                    header.payload_len, available
+                ),
+            ));
+        }
+
+        let range = ByteRangeReader::new(file, CONTAINER_HEADER_SIZE as u64, header.payload_len);
+        Ok(Self {
+            header,
+            range,
+            chunk_size: Self::DEFAULT_CHUNK_SIZE,
+        })
+    }
+
+    fn read_header(&mut self) -> SisterResult<ContainerHeader> {
+        Ok(self.header.clone())
+    }
+
+    fn records(&mut self) -> SisterResult<RecordIter<'_, Vec<u8>>> {
+        let total = self.header.payload_len;
+        let expected = self.header.checksum;
+        let chunk_size = self.chunk_size as u64;
+        let mut offset = 0u64;
+        let mut hasher = Some(blake3::Hasher::new());
+        let range = &mut self.range;
+
+        Ok(Box::new(std::iter::from_fn(move || {
+            if offset >= total {
+                // Verify once after the final chunk
+                let hasher = hasher.take()?;
+                return (hasher.finalize().as_bytes() != &expected).then(|| {
+                    Err(SisterError::new(
+                        ErrorCode::ChecksumMismatch,
+                        "Container payload checksum verification failed",
+                    ))
+                });
+            }
+            let len = chunk_size.min(total - offset) as usize;
+            let chunk = range.read_range(offset, len);
+            match chunk {
+                Ok(bytes) => {
+                    offset += len as u64;
+                    if let Some(h) = hasher.as_mut() {
+                        h.update(&bytes);
+                    }
+                    Some(Ok(bytes))
+                }
+                Err(e) => {
+                    offset = total;
+                    hasher = None;
+                    Some(Err(e))
+                }
+            }
+        })))
+    }
+
+    fn read_range(&mut self, offset: u64, len: usize) -> SisterResult<Vec<u8>> {
+        self.range.read_range(offset, len)
+    }
+
+    fn file_info(&mut self) -> SisterResult<FileInfo> {
+        self.header.to_file_info(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_format::{ContainerFile, FileFormatWriter};
+    use crate::test_support::TempDir;
+    use crate::types::{SisterType, Version};
+
+    fn write_container(payload: Vec<u8>) -> (TempDir, std::path::PathBuf) {
+        let dir = TempDir::new("stream");
+        let path = dir.join("data.actr");
+        ContainerFile::new(SisterType::Memory, Version::new(1, 0, 0), payload)
+            .write_file(&path)
+            .unwrap();
+        (dir, path)
+    }
+
+    #[test]
+    fn test_stream_records_and_ranges() {
+        let payload: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        let (_dir, path) = write_container(payload.clone());
+
+        let mut reader = ContainerStreamReader::open(&path)
+            .unwrap()
+            .with_chunk_size(300);
+        assert_eq!(reader.read_header().unwrap().payload_len, 1000);
+
+        let chunks: Vec<_> = reader.records().unwrap().map(|c| c.unwrap()).collect();
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(chunks.concat(), payload);
+
+        assert_eq!(reader.read_range(256, 3).unwrap(), vec![0, 1, 2]);
+        assert!(reader.read_range(999, 2).is_err());
+    }
+
+    #[test]
+    fn test_stream_detects_corruption_at_end() {
+        let (_dir, path) = write_container(vec![7u8; 64]);
+        let mut bytes = std::fs::read(&path).unwrap();
+        *bytes.last_mut().unwrap() = 0;
+        std::fs::write(&path, bytes).unwrap();
+
+        let mut reader = ContainerStreamReader::open(&path)
+            .unwrap()
+            .with_chunk_size(16);
+        let results: Vec<_> = reader.records().unwrap().collect();
+        assert_eq!(results.len(), 5);
+        assert!(results[..4].iter().all(|r| r.is_ok()));
+        assert_eq!(
+            results[4].as_ref().unwrap_err().code,
+            ErrorCode::ChecksumMismatch
+        );
+    }
+}
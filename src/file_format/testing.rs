@@ -0,0 +1,238 @@
+//! Test helpers that enforce the 20-year promise in sister CI.
+//!
+//! ```rust,ignore
+//! use agentic_sdk::file_format::testing::*;
+//!
+//! #[test]
+//! fn memory_file_roundtrips() {
+//!     assert_roundtrip(&sample_memory_file());
+//! }
+//!
+//! #[test]
+//! fn reads_every_released_format() {
+//!     // tests/fixtures/amem/ holds one file written by each past release
+//!     assert_corpus_readable::<MemoryFile>(Path::new("tests/fixtures/amem"), &CURRENT_VERSION);
+//! }
+//! ```
+//!
+//! Every release adds a fixture to the corpus (see `save_fixture`) and
+//! never deletes one, so old files stay readable mechanically rather
+//! than by good intentions.
+
+use super::{FileFormatReader, FileFormatWriter, VersionCompatibility};
+use crate::errors::{ErrorCode, SisterError, SisterResult};
+use crate::types::Version;
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+
+/// Write `value` to a temp file, read it back, and assert nothing changed.
+///
+/// Also checks that `can_read` / `file_version` agree with each other and
+/// that serialization is deterministic. Returns the re-read value.
+pub fn assert_roundtrip<T>(value: &T) -> T
+where
+    T: FileFormatReader + FileFormatWriter + PartialEq + Debug,
+{
+    let dir = std::env::temp_dir().join(format!("roundtrip_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).expect("create temp dir");
+    let path = dir.join("roundtrip.bin");
+
+    let result = (|| -> SisterResult<T> {
+        value.write_file(&path)?;
+        let info = T::can_read(&path)?;
+        let version = T::file_version(&path)?;
+        assert_eq!(
+            info.version, version,
+            "can_read() and file_version() disagree on the file version"
+        );
+
+        let read = T::read_file(&path)?;
+        assert_eq!(&read, value, "value changed across write_file/read_file");
+        assert_eq!(
+            read.to_bytes()?,
+            std::fs::read(&path)?,
+            "re-serializing the read value produced different bytes"
+        );
+        Ok(read)
+    })();
+
+    std::fs::remove_dir_all(&dir).ok();
+    result.unwrap_or_else(|e| panic!("round-trip failed: {}", e))
+}
+
+/// Outcome of reading one corpus fixture.
+#[derive(Debug, Clone)]
+pub struct FixtureResult {
+    /// Fixture file
+    pub path: PathBuf,
+
+    /// Version reported by the file, if it could be read
+    pub version: Option<Version>,
+
+    /// Whether the fixture needs migration to the current version
+    pub needs_migration: bool,
+
+    /// Failure, if any
+    pub error: Option<String>,
+}
+
+impl FixtureResult {
+    /// Whether the fixture was read successfully.
+    pub fn passed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Try to read every fixture in `dir` with `T`.
+///
+/// For each file: `can_read` must succeed, the file's version must be
+/// readable by `current_version`, `read_file` must succeed, and files
+/// from an older major version must survive `migrate`. Hidden files and
+/// subdirectories are skipped. Results are sorted by path.
+pub fn check_corpus<T: FileFormatReader>(
+    dir: &Path,
+    current_version: &Version,
+) -> SisterResult<Vec<FixtureResult>> {
+    let mut paths: Vec<_> = std::fs::read_dir(dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file())
+        .filter(|p| {
+            !p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with('.'))
+        })
+        .collect();
+    paths.sort();
+
+    Ok(paths
+        .into_iter()
+        .map(|path| check_fixture::<T>(path, current_version))
+        .collect())
+}
+
+fn check_fixture<T: FileFormatReader>(path: PathBuf, current_version: &Version) -> FixtureResult {
+    let mut result = FixtureResult {
+        path,
+        version: None,
+        needs_migration: false,
+        error: None,
+    };
+
+    let outcome = (|| -> SisterResult<Option<String>> {
+        let info = T::can_read(&result.path)?;
+        result.version = Some(info.version.clone());
+        if !VersionCompatibility::can_read(current_version, &info.version) {
+            return Ok(Some(format!(
+                "file version {} is newer than reader version {}",
+                info.version, current_version
+            )));
+        }
+        result.needs_migration =
+            VersionCompatibility::needs_migration(current_version, &info.version);
+
+        T::read_file(&result.path)?;
+        if result.needs_migration {
+            T::migrate(&std::fs::read(&result.path)?, info.version)?;
+        }
+        Ok(None)
+    })();
+
+    result.error = match outcome {
+        Ok(problem) => problem,
+        Err(e) => Some(e.to_string()),
+    };
+    result
+}
+
+/// Assert that every fixture in `dir` is readable by `T`.
+///
+/// Panics listing all failing fixtures, and if the corpus is empty (an
+/// empty corpus silently proves nothing).
+pub fn assert_corpus_readable<T: FileFormatReader>(dir: &Path, current_version: &Version) {
+    let results = check_corpus::<T>(dir, current_version)
+        .unwrap_or_else(|e| panic!("cannot read corpus {}: {}", dir.display(), e));
+    assert!(
+        !results.is_empty(),
+        "corpus {} contains no fixtures",
+        dir.display()
+    );
+
+    let failures: Vec<_> = results
+        .iter()
+        .filter(|r| !r.passed())
+        .map(|r| {
+            format!(
+                "  {}: {}",
+                r.path.display(),
+                r.error.as_deref().unwrap_or_default()
+            )
+        })
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "{} of {} fixtures failed:\n{}",
+        failures.len(),
+        results.len(),
+        failures.join("\n")
+    );
+}
+
+/// Write `value` into a corpus directory as a new fixture.
+///
+/// Refuses to overwrite: fixtures are immutable once committed.
+pub fn save_fixture<T: FileFormatWriter>(
+    value: &T,
+    dir: &Path,
+    file_name: &str,
+) -> SisterResult<PathBuf> {
+    let path = dir.join(file_name);
+    if path.exists() {
+        return Err(SisterError::new(
+            ErrorCode::AlreadyExists,
+            format!("Fixture {} already exists", path.display()),
+        ));
+    }
+    std::fs::create_dir_all(dir)?;
+    value.write_file(&path)?;
+    Ok(path)
+}
+
+#[cfg(all(test, feature = "file-io"))]
+mod tests {
+    use super::*;
+    use crate::file_format::ContainerFile;
+    use crate::test_support::TempDir;
+    use crate::types::SisterType;
+
+    #[test]
+    fn test_container_roundtrip() {
+        let file = ContainerFile::new(SisterType::Memory, Version::new(1, 0, 0), b"data".into());
+        assert_eq!(assert_roundtrip(&file), file);
+    }
+
+    #[test]
+    fn test_corpus_checks() {
+        let dir = TempDir::new("corpus");
+        for (name, version) in [
+            ("v1.actr", Version::new(1, 0, 0)),
+            ("v2.actr", Version::new(2, 1, 0)),
+        ] {
+            let file = ContainerFile::new(SisterType::Memory, version, b"x".into());
+            save_fixture(&file, &dir, name).unwrap();
+        }
+        let again = ContainerFile::new(SisterType::Memory, Version::new(1, 0, 0), vec![]);
+        assert!(save_fixture(&again, &dir, "v1.actr").is_err());
+
+        assert_corpus_readable::<ContainerFile>(&dir, &Version::new(2, 1, 0));
+
+        // A reader older than the newest fixture must fail on it
+        let results = check_corpus::<ContainerFile>(&dir, &Version::new(1, 0, 0)).unwrap();
+        assert!(results[0].passed());
+        assert!(!results[1].passed());
+
+        std::fs::write(dir.join("broken.actr"), b"nope").unwrap();
+        let results = check_corpus::<ContainerFile>(&dir, &Version::new(2, 1, 0)).unwrap();
+        assert_eq!(results.iter().filter(|r| !r.passed()).count(), 1);
+    }
+}
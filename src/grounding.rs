@@ -25,6 +25,8 @@ use serde::{Deserialize, Serialize};
 
 /// Status of a grounding check
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[serde(rename_all = "snake_case")]
 pub enum GroundingStatus {
     /// Claim is fully supported by evidence
@@ -51,6 +53,8 @@ impl std::fmt::Display for GroundingStatus {
 ///
 /// Mirrors the actual response shape all sisters return.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct GroundingResult {
     /// Grounding status
     pub status: GroundingStatus,
@@ -150,6 +154,8 @@ impl GroundingResult {
 /// relevant to its domain. Memory returns nodes, Vision returns
 /// observations, Identity returns trust grants + receipts, etc.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct GroundingEvidence {
     /// Evidence type (sister-specific: "memory_node", "observation",
     /// "trust_grant", "receipt", "code_symbol", etc.)
@@ -204,6 +210,8 @@ impl GroundingEvidence {
 /// More detailed than `GroundingEvidence` — includes full content,
 /// timestamps, relationships, etc.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct EvidenceDetail {
     /// Evidence type
     pub evidence_type: String,
@@ -234,6 +242,8 @@ pub struct EvidenceDetail {
 
 /// A suggestion returned when a claim doesn't match exactly
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct GroundingSuggestion {
     /// What type of item this is
     pub item_type: String,
@@ -293,6 +303,8 @@ pub trait Grounding {
 /// Type of evidence (kept for categorization, but no longer used
 /// as the primary lookup mechanism).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[serde(rename_all = "snake_case")]
 pub enum EvidenceType {
     // Memory evidence
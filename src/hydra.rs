@@ -25,11 +25,30 @@
 //! Memory       Vision      Codebase  ...
 //! ```
 
-use crate::context::SessionContext;
-use crate::errors::SisterResult;
-use crate::types::{Metadata, SisterType};
+use crate::codec::Codec;
+use crate::context::{
+    ContextInfo, ContextSummary, SessionContext, SessionManagement, WorkspaceManagement,
+};
+use crate::errors::{ErrorCode, ProtocolError, SisterError, SisterResult};
+#[cfg(feature = "events")]
+use crate::events::EventEmitter;
+use crate::events::{EventManager, SisterEvent};
+use crate::file_format::{
+    FileFormatReader, FileFormatWriter, FileInfo, JsonFormatDescriptor, VersionCompatibility,
+};
+#[cfg(feature = "events")]
+use crate::grounding::Grounding;
+use crate::query::{Query, QueryResult, QueryTypeInfo, Queryable};
+use crate::receipts::{ActionOutcome, ActionRecord, ReceiptId, ReceiptIntegration};
+use crate::sister::{Sister, SisterConfig, SisterInfo};
+use crate::types::{HealthStatus, Metadata, SisterType, Status, UniqueId, Version};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::marker::PhantomData;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 
 // ═══════════════════════════════════════════════════════════════════
 // HYDRA BRIDGE — How sisters connect to Hydra
@@ -60,6 +79,8 @@ pub struct SisterSummary {
 
 /// A command from Hydra to a sister
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct HydraCommand {
     /// Command type (sister interprets this)
     pub command_type: String,
@@ -73,128 +94,3323 @@ pub struct HydraCommand {
 
     /// Step ID within the run
     pub step_id: u64,
+
+    /// Limits the sister should stay within (None = unlimited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<Budget>,
+
+    /// Preview only: validate and report a `DryRunReport` in the
+    /// result data, never mutate state
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+
+    /// Caller-chosen key; retries with the same key return the first
+    /// result instead of re-applying (see `IdempotentBridge`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+
+    /// Scheduling priority (see `CommandQueue`)
+    #[serde(default, skip_serializing_if = "Priority::is_normal")]
+    pub priority: Priority,
+
+    /// Latest useful completion time; stale commands can be dropped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<DateTime<Utc>>,
+
+    /// Must run with no other command in flight on the sister
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub exclusive: bool,
+
+    /// Encodings the caller can read the result in, most preferred
+    /// first (see `Codec::negotiate`); empty means JSON only
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accept: Vec<Codec>,
 }
 
 /// Result of executing a Hydra command
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct CommandResult {
     /// Whether the command succeeded
     pub success: bool,
 
-    /// Result data
-    pub data: serde_json::Value,
+    /// Result data
+    pub data: serde_json::Value,
+
+    /// Error message (if failed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// Evidence IDs produced by this command
+    #[serde(default)]
+    pub evidence_ids: Vec<String>,
+
+    /// Resources the command consumed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<Usage>,
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// BUDGETS — Token, cost, and time limits across a run
+// ═══════════════════════════════════════════════════════════════════
+
+/// Resource limits for a command or run. `None` fields are unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct Budget {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_cost_usd: Option<f64>,
+
+    /// Serialized as milliseconds
+    #[serde(
+        default,
+        with = "opt_duration_millis",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<u64>"))]
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub max_duration: Option<Duration>,
+}
+
+impl Budget {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_tokens(mut self, tokens: u64) -> Self {
+        self.max_tokens = Some(tokens);
+        self
+    }
+
+    pub fn max_cost_usd(mut self, cost: f64) -> Self {
+        self.max_cost_usd = Some(cost);
+        self
+    }
+
+    pub fn max_duration(mut self, duration: Duration) -> Self {
+        self.max_duration = Some(duration);
+        self
+    }
+
+    /// Fail with `ResourceExhausted` if `usage` exceeds any limit.
+    pub fn check(&self, usage: &Usage) -> SisterResult<()> {
+        let exceeded = |resource: &str, used: String, limit: String| {
+            Err(SisterError::new(
+                ErrorCode::ResourceExhausted,
+                format!("Budget exceeded: {} used {} of {}", resource, used, limit),
+            )
+            .with_context("resource", resource)
+            .with_context("used", used)
+            .with_context("limit", limit))
+        };
+        if let Some(max) = self.max_tokens.filter(|max| usage.tokens > *max) {
+            return exceeded("tokens", usage.tokens.to_string(), max.to_string());
+        }
+        if let Some(max) = self.max_cost_usd.filter(|max| usage.cost_usd > *max) {
+            return exceeded(
+                "cost_usd",
+                format!("{:.4}", usage.cost_usd),
+                format!("{:.4}", max),
+            );
+        }
+        if let Some(max) = self.max_duration.filter(|max| usage.duration > *max) {
+            return exceeded(
+                "duration",
+                format!("{}ms", usage.duration.as_millis()),
+                format!("{}ms", max.as_millis()),
+            );
+        }
+        Ok(())
+    }
+
+    /// What is left of this budget after `usage` (never negative).
+    pub fn remaining(&self, usage: &Usage) -> Budget {
+        Budget {
+            max_tokens: self.max_tokens.map(|max| max.saturating_sub(usage.tokens)),
+            max_cost_usd: self.max_cost_usd.map(|max| (max - usage.cost_usd).max(0.0)),
+            max_duration: self
+                .max_duration
+                .map(|max| max.saturating_sub(usage.duration)),
+        }
+    }
+}
+
+/// Resources consumed by a command.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct Usage {
+    #[serde(default)]
+    pub tokens: u64,
+
+    #[serde(default)]
+    pub cost_usd: f64,
+
+    /// Serialized as milliseconds
+    #[serde(default, with = "duration_millis")]
+    #[cfg_attr(feature = "schema", schemars(with = "u64"))]
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub duration: Duration,
+}
+
+impl std::ops::Add for Usage {
+    type Output = Usage;
+
+    fn add(self, other: Usage) -> Usage {
+        Usage {
+            tokens: self.tokens.saturating_add(other.tokens),
+            cost_usd: self.cost_usd + other.cost_usd,
+            duration: self.duration.saturating_add(other.duration),
+        }
+    }
+}
+
+impl std::ops::AddAssign for Usage {
+    fn add_assign(&mut self, other: Usage) {
+        *self = *self + other;
+    }
+}
+
+/// Accumulates usage across the steps of a run against one budget.
+#[derive(Debug, Clone, Default)]
+pub struct BudgetTracker {
+    budget: Budget,
+    used: Usage,
+    steps: u64,
+}
+
+impl BudgetTracker {
+    pub fn new(budget: Budget) -> Self {
+        Self {
+            budget,
+            used: Usage::default(),
+            steps: 0,
+        }
+    }
+
+    /// Add one step's usage. Fails with `ResourceExhausted` once the
+    /// total exceeds the budget (the usage is still recorded).
+    pub fn record(&mut self, usage: &Usage) -> SisterResult<()> {
+        self.used += *usage;
+        self.steps += 1;
+        self.budget
+            .check(&self.used)
+            .map_err(|e| e.with_context("steps", self.steps))
+    }
+
+    /// Add the usage reported in a command result, if any.
+    pub fn record_result(&mut self, result: &CommandResult) -> SisterResult<()> {
+        match &result.usage {
+            Some(usage) => self.record(usage),
+            None => Ok(()),
+        }
+    }
+
+    pub fn budget(&self) -> &Budget {
+        &self.budget
+    }
+
+    pub fn used(&self) -> &Usage {
+        &self.used
+    }
+
+    /// Steps recorded so far.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Budget for the next command.
+    pub fn remaining(&self) -> Budget {
+        self.budget.remaining(&self.used)
+    }
+
+    /// Whether any limit is exceeded or used up.
+    pub fn is_exhausted(&self) -> bool {
+        let left = self.remaining();
+        self.budget.check(&self.used).is_err()
+            || left.max_tokens == Some(0)
+            || left.max_cost_usd == Some(0.0)
+            || left.max_duration == Some(Duration::ZERO)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// RUNS AND STEPS — Shared vocabulary for Hydra's unit of work
+// ═══════════════════════════════════════════════════════════════════
+
+/// Hydra run identifier (the `run_id` in `HydraCommand`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(transparent)]
+pub struct RunId(pub String);
+
+impl RunId {
+    /// Fresh run ID ("run_<uuid>").
+    pub fn new() -> Self {
+        Self(format!("run_{}", uuid::Uuid::new_v4().simple()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for RunId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for RunId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for RunId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+
+impl From<&str> for RunId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+
+/// Step number within a run (the `step_id` in `HydraCommand`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(transparent)]
+pub struct StepId(pub u64);
+
+impl std::fmt::Display for StepId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "step_{}", self.0)
+    }
+}
+
+/// Lifecycle status of a run or one of its steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum RunStatus {
+    /// Planned, not started
+    Pending,
+
+    /// In progress
+    Running,
+
+    /// Finished successfully
+    Succeeded,
+
+    /// Finished with an error
+    Failed,
+
+    /// Stopped before finishing
+    Cancelled,
+}
+
+impl RunStatus {
+    /// Whether the status is final.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed | Self::Cancelled)
+    }
+}
+
+impl std::fmt::Display for RunStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        };
+        f.write_str(name)
+    }
+}
+
+/// One step of a run: a command sent to one sister.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepRecord {
+    pub run_id: RunId,
+    pub step_id: StepId,
+
+    /// Sister that ran the step
+    pub sister_type: SisterType,
+
+    /// `HydraCommand::command_type`
+    pub command_type: String,
+
+    pub status: RunStatus,
+    pub started_at: DateTime<Utc>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+
+    /// How the step ended
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outcome: Option<ActionOutcome>,
+
+    /// Receipts produced by the step
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub receipt_ids: Vec<ReceiptId>,
+}
+
+impl StepRecord {
+    /// The command for this step.
+    pub fn command(&self, params: Metadata) -> HydraCommand {
+        HydraCommand {
+            command_type: self.command_type.clone(),
+            params,
+            run_id: self.run_id.0.clone(),
+            step_id: self.step_id.0,
+            budget: None,
+            dry_run: false,
+            idempotency_key: None,
+            priority: Priority::Normal,
+            deadline: None,
+            exclusive: false,
+            accept: Vec::new(),
+        }
+    }
+}
+
+/// A Hydra run and its steps.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub run_id: RunId,
+
+    /// What the run is trying to achieve
+    pub goal: String,
+
+    pub status: RunStatus,
+    pub started_at: DateTime<Utc>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+
+    /// Steps in start order
+    #[serde(default)]
+    pub steps: Vec<StepRecord>,
+
+    #[serde(default)]
+    pub metadata: Metadata,
+}
+
+impl RunRecord {
+    /// Start a run.
+    pub fn start(goal: impl Into<String>) -> Self {
+        Self {
+            run_id: RunId::new(),
+            goal: goal.into(),
+            status: RunStatus::Running,
+            started_at: Utc::now(),
+            finished_at: None,
+            steps: vec![],
+            metadata: Metadata::new(),
+        }
+    }
+
+    /// Begin the next step. Step IDs count up from 1.
+    pub fn start_step(
+        &mut self,
+        sister_type: SisterType,
+        command_type: impl Into<String>,
+    ) -> &mut StepRecord {
+        let step_id = StepId(self.steps.len() as u64 + 1);
+        self.steps.push(StepRecord {
+            run_id: self.run_id.clone(),
+            step_id,
+            sister_type,
+            command_type: command_type.into(),
+            status: RunStatus::Running,
+            started_at: Utc::now(),
+            finished_at: None,
+            outcome: None,
+            receipt_ids: vec![],
+        });
+        self.steps.last_mut().expect("step just pushed")
+    }
+
+    /// Finish a step with its outcome.
+    pub fn complete_step(&mut self, step_id: StepId, outcome: ActionOutcome) -> SisterResult<()> {
+        let step = self.step_mut(step_id)?;
+        step.status = if outcome.is_failure() {
+            RunStatus::Failed
+        } else {
+            RunStatus::Succeeded
+        };
+        step.outcome = Some(outcome);
+        step.finished_at = Some(Utc::now());
+        Ok(())
+    }
+
+    /// Finish the run. Steps still running are cancelled.
+    pub fn finish(&mut self, status: RunStatus) {
+        let now = Utc::now();
+        for step in self.steps.iter_mut().filter(|s| !s.status.is_terminal()) {
+            step.status = RunStatus::Cancelled;
+            step.finished_at = Some(now);
+        }
+        self.status = status;
+        self.finished_at = Some(now);
+    }
+
+    pub fn step(&self, step_id: StepId) -> Option<&StepRecord> {
+        self.steps.iter().find(|s| s.step_id == step_id)
+    }
+
+    fn step_mut(&mut self, step_id: StepId) -> SisterResult<&mut StepRecord> {
+        let run_id = self.run_id.clone();
+        self.steps
+            .iter_mut()
+            .find(|s| s.step_id == step_id)
+            .ok_or_else(|| SisterError::not_found(format!("Step {} of {}", step_id, run_id)))
+    }
+
+    /// Wall-clock time so far, or in total once finished.
+    pub fn duration(&self) -> chrono::Duration {
+        self.finished_at.unwrap_or_else(Utc::now) - self.started_at
+    }
+}
+
+/// The bridge between Hydra and individual sisters.
+///
+/// This is a PLACEHOLDER trait. Sisters should not implement it yet;
+/// `BridgeAdapter` provides it for any sister with the traits below.
+/// It establishes the expected contract shape for when Hydra arrives.
+///
+/// When Hydra is built, this trait will require:
+/// `Sister + SessionManagement/WorkspaceManagement + Grounding + EventEmitter + Queryable`
+pub trait HydraBridge {
+    /// Get a token-efficient summary of current sister state.
+    /// Hydra calls this to build its context window
+    fn session_context(&self) -> SisterResult<SessionContext>;
+
+    /// Restore sister state from a previous session context.
+    /// Used when Hydra resumes a run
+    fn restore_session(&mut self, context: SessionContext) -> SisterResult<()>;
+
+    /// Get a brief summary for Hydra's context
+    fn summary(&self) -> SisterResult<SisterSummary>;
+
+    /// Execute a command from Hydra.
+    /// This is the escape hatch for Hydra-specific operations
+    fn execute(&mut self, command: HydraCommand) -> SisterResult<CommandResult>;
+
+    /// Execute a command, reporting progress as it runs.
+    ///
+    /// Long-running sisters override this to call `on_progress`
+    /// periodically and stop with `CommandResult::cancelled` when it
+    /// returns `ProgressControl::Cancel`. The default runs `execute`
+    /// and reports completion once.
+    fn execute_streaming(
+        &mut self,
+        command: HydraCommand,
+        on_progress: &mut ProgressCallback<'_>,
+    ) -> SisterResult<CommandResult> {
+        let result = self.execute(command)?;
+        on_progress(CommandProgress::complete());
+        Ok(result)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// PROGRESS — Streaming updates from long-running commands
+// ═══════════════════════════════════════════════════════════════════
+
+/// `CommandResult::error` of a command stopped by its progress callback.
+pub const COMMAND_CANCELLED: &str = "cancelled";
+
+/// Progress update from a running command.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandProgress {
+    /// Completion, 0.0-100.0
+    pub percent: f64,
+
+    /// Human-readable status ("indexed 1200/5000 files")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// Results available so far
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partial_data: Option<serde_json::Value>,
+}
+
+impl CommandProgress {
+    /// Progress at `percent` (clamped to 0-100).
+    pub fn new(percent: f64) -> Self {
+        Self {
+            percent: percent.clamp(0.0, 100.0),
+            message: None,
+            partial_data: None,
+        }
+    }
+
+    pub fn complete() -> Self {
+        Self::new(100.0)
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn partial_data(mut self, data: serde_json::Value) -> Self {
+        self.partial_data = Some(data);
+        self
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.percent >= 100.0
+    }
+}
+
+/// What the caller wants after a progress update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressControl {
+    Continue,
+    Cancel,
+}
+
+/// Callback receiving `execute_streaming` progress.
+pub type ProgressCallback<'a> = dyn FnMut(CommandProgress) -> ProgressControl + 'a;
+
+impl CommandResult {
+    /// Result of a command cancelled mid-run, keeping any partial data.
+    pub fn cancelled(partial_data: Option<serde_json::Value>) -> Self {
+        Self {
+            success: false,
+            data: partial_data.unwrap_or(serde_json::Value::Null),
+            error: Some(COMMAND_CANCELLED.to_string()),
+            evidence_ids: vec![],
+            usage: None,
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        !self.success && self.error.as_deref() == Some(COMMAND_CANCELLED)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// DRY RUNS — Previewing a command's effects before committing
+// ═══════════════════════════════════════════════════════════════════
+//
+// A command with `dry_run: true` is validated exactly as a real one
+// (unknown commands and bad params still fail), then answered with
+// `CommandResult::dry_run`: success means "this would succeed", and
+// `data` is a `DryRunReport` listing what would change. The sister
+// must not write state, emit receipts, or spend budget beyond what
+// estimating needs.
+
+/// One change a dry-run command would make.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlannedChange {
+    /// Kind of change ("create", "update", "delete", ...)
+    pub kind: String,
+
+    /// What is changed (node ID, file path, ...)
+    pub target: String,
+
+    /// Sister-specific detail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<serde_json::Value>,
+}
+
+impl PlannedChange {
+    pub fn new(kind: impl Into<String>, target: impl Into<String>) -> Self {
+        Self {
+            kind: kind.into(),
+            target: target.into(),
+            detail: None,
+        }
+    }
+
+    pub fn detail(mut self, detail: serde_json::Value) -> Self {
+        self.detail = Some(detail);
+        self
+    }
+}
+
+/// What a dry-run command would do. Carried in `CommandResult::data`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DryRunReport {
+    /// Changes the command would make
+    #[serde(default)]
+    pub changes: Vec<PlannedChange>,
+
+    /// Assessed risk of the real command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<RiskLevel>,
+
+    /// Expected resource use of the real command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_usage: Option<Usage>,
+}
+
+impl DryRunReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn change(mut self, change: PlannedChange) -> Self {
+        self.changes.push(change);
+        self
+    }
+
+    pub fn risk_level(mut self, level: RiskLevel) -> Self {
+        self.risk_level = Some(level);
+        self
+    }
+
+    pub fn estimated_usage(mut self, usage: Usage) -> Self {
+        self.estimated_usage = Some(usage);
+        self
+    }
+}
+
+impl HydraCommand {
+    /// Mark the command as a dry run.
+    pub fn preview(mut self) -> Self {
+        self.dry_run = true;
+        self
+    }
+}
+
+impl CommandResult {
+    /// Successful dry-run result carrying `report`.
+    pub fn dry_run(report: DryRunReport) -> Self {
+        Self {
+            success: true,
+            data: serde_json::to_value(&report).unwrap_or_default(),
+            error: None,
+            evidence_ids: vec![],
+            usage: None,
+        }
+    }
+
+    /// The `DryRunReport` in `data`, if this is a dry-run result.
+    pub fn dry_run_report(&self) -> Option<DryRunReport> {
+        self.data.get("changes")?;
+        serde_json::from_value(self.data.clone()).ok()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// HEARTBEATS — Noticing a hung sister
+// ═══════════════════════════════════════════════════════════════════
+
+/// Custom event name emitted when a sister misses its heartbeat window.
+pub const SISTER_UNRESPONSIVE_EVENT: &str = "sister_unresponsive";
+
+/// Periodic liveness signal from a sister to Hydra.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Heartbeat {
+    pub sister_type: SisterType,
+
+    /// Status at send time
+    pub status: Status,
+
+    /// Monotonic per-sister counter; lower or repeated values are stale
+    pub sequence: u64,
+
+    pub sent_at: DateTime<Utc>,
+}
+
+impl Heartbeat {
+    pub fn new(sister_type: SisterType, status: Status, sequence: u64) -> Self {
+        Self {
+            sister_type,
+            status,
+            sequence,
+            sent_at: Utc::now(),
+        }
+    }
+}
+
+/// What `HeartbeatMonitor` knows about one sister.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SisterLiveness {
+    pub sister_type: SisterType,
+
+    /// Status from the last heartbeat
+    pub status: Status,
+
+    /// Sequence of the last heartbeat
+    pub sequence: u64,
+
+    /// When the last heartbeat was received
+    pub last_seen: DateTime<Utc>,
+
+    /// Whether the last heartbeat is within the timeout
+    pub responsive: bool,
+}
+
+/// Tracks the last heartbeat per sister and flags ones that go quiet.
+///
+/// `check` emits one `sister_unresponsive` event per silence; the next
+/// heartbeat from that sister re-arms it.
+pub struct HeartbeatMonitor {
+    timeout: Duration,
+    events: Option<Arc<EventManager>>,
+    sisters: std::sync::Mutex<HashMap<SisterType, SisterLiveness>>,
+}
+
+impl HeartbeatMonitor {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            events: None,
+            sisters: std::sync::Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Also emit unresponsive events to `events`.
+    pub fn with_events(mut self, events: Arc<EventManager>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Record a heartbeat. Returns false if it was stale and ignored.
+    pub fn record(&self, heartbeat: &Heartbeat) -> bool {
+        let mut sisters = self.sisters.lock().unwrap();
+        if let Some(known) = sisters.get(&heartbeat.sister_type) {
+            if heartbeat.sequence <= known.sequence {
+                return false;
+            }
+        }
+        sisters.insert(
+            heartbeat.sister_type,
+            SisterLiveness {
+                sister_type: heartbeat.sister_type,
+                status: heartbeat.status,
+                sequence: heartbeat.sequence,
+                last_seen: Utc::now(),
+                responsive: true,
+            },
+        );
+        true
+    }
+
+    /// Flag sisters silent for longer than the timeout.
+    pub fn check(&self) -> Vec<SisterEvent> {
+        self.check_at(Utc::now())
+    }
+
+    /// `check` as of `now`. Returns the newly emitted events.
+    pub fn check_at(&self, now: DateTime<Utc>) -> Vec<SisterEvent> {
+        let timeout = chrono::Duration::from_std(self.timeout).unwrap_or(chrono::Duration::MAX);
+        let mut events = Vec::new();
+        for liveness in self.sisters.lock().unwrap().values_mut() {
+            if liveness.responsive && now - liveness.last_seen > timeout {
+                liveness.responsive = false;
+                events.push(SisterEvent::custom(
+                    liveness.sister_type,
+                    SISTER_UNRESPONSIVE_EVENT,
+                    serde_json::json!({
+                        "last_seen": liveness.last_seen,
+                        "sequence": liveness.sequence,
+                        "timeout_ms": self.timeout.as_millis() as u64,
+                    }),
+                ));
+            }
+        }
+        if let Some(manager) = &self.events {
+            for event in &events {
+                manager.emit(event.clone());
+            }
+        }
+        events
+    }
+
+    /// Whether `sister_type` has heartbeated and is not flagged.
+    pub fn is_alive(&self, sister_type: SisterType) -> bool {
+        self.sisters
+            .lock()
+            .unwrap()
+            .get(&sister_type)
+            .is_some_and(|l| l.responsive)
+    }
+
+    /// Liveness of every sister seen so far.
+    pub fn fleet(&self) -> Vec<SisterLiveness> {
+        let mut fleet: Vec<_> = self.sisters.lock().unwrap().values().cloned().collect();
+        fleet.sort_by_key(|l| l.sister_type.to_string());
+        fleet
+    }
+}
+
+impl std::fmt::Debug for HeartbeatMonitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HeartbeatMonitor")
+            .field("timeout", &self.timeout)
+            .field("sisters", &self.sisters.lock().unwrap().len())
+            .finish()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// IDEMPOTENCY — Retries must not double-apply
+// ═══════════════════════════════════════════════════════════════════
+
+/// How long `IdempotentBridge` remembers a result by default.
+pub const DEFAULT_IDEMPOTENCY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Remembers command results by idempotency key.
+pub trait IdempotencyStore: Send + Sync {
+    /// Cached result for `key`, if present and not expired
+    fn get(&self, key: &str) -> SisterResult<Option<CommandResult>>;
+
+    /// Remember `result` under `key` for `ttl`
+    fn put(&self, key: &str, result: &CommandResult, ttl: Duration) -> SisterResult<()>;
+}
+
+/// In-memory `IdempotencyStore`. Expired entries are dropped on access.
+#[derive(Debug, Default)]
+pub struct InMemoryIdempotencyStore {
+    entries: std::sync::Mutex<HashMap<String, (CommandResult, DateTime<Utc>)>>,
+}
+
+impl InMemoryIdempotencyStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of unexpired entries.
+    pub fn len(&self) -> usize {
+        let now = Utc::now();
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, (_, expires_at)| *expires_at > now);
+        entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl IdempotencyStore for InMemoryIdempotencyStore {
+    fn get(&self, key: &str) -> SisterResult<Option<CommandResult>> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(key) {
+            Some((result, expires_at)) if *expires_at > Utc::now() => Ok(Some(result.clone())),
+            Some(_) => {
+                entries.remove(key);
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn put(&self, key: &str, result: &CommandResult, ttl: Duration) -> SisterResult<()> {
+        let expires_at =
+            Utc::now() + chrono::Duration::from_std(ttl).unwrap_or(chrono::Duration::MAX);
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), (result.clone(), expires_at));
+        Ok(())
+    }
+}
+
+/// `HydraBridge` wrapper that makes keyed commands idempotent.
+///
+/// A command with an `idempotency_key` seen within the TTL gets the
+/// cached result without reaching the sister. Only `Ok` results are
+/// cached, so a command that errored can be retried; dry runs and
+/// unkeyed commands always pass through.
+pub struct IdempotentBridge<B, St> {
+    bridge: B,
+    store: St,
+    ttl: Duration,
+}
+
+impl<B: HydraBridge, St: IdempotencyStore> IdempotentBridge<B, St> {
+    pub fn new(bridge: B, store: St) -> Self {
+        Self {
+            bridge,
+            store,
+            ttl: DEFAULT_IDEMPOTENCY_TTL,
+        }
+    }
+
+    /// How long results are remembered.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn bridge(&self) -> &B {
+        &self.bridge
+    }
+
+    pub fn store(&self) -> &St {
+        &self.store
+    }
+
+    pub fn into_inner(self) -> B {
+        self.bridge
+    }
+}
+
+impl<B: HydraBridge, St: IdempotencyStore> HydraBridge for IdempotentBridge<B, St> {
+    fn session_context(&self) -> SisterResult<SessionContext> {
+        self.bridge.session_context()
+    }
+
+    fn restore_session(&mut self, context: SessionContext) -> SisterResult<()> {
+        self.bridge.restore_session(context)
+    }
+
+    fn summary(&self) -> SisterResult<SisterSummary> {
+        self.bridge.summary()
+    }
+
+    fn execute(&mut self, command: HydraCommand) -> SisterResult<CommandResult> {
+        let key = match &command.idempotency_key {
+            Some(key) if !command.dry_run => key.clone(),
+            _ => return self.bridge.execute(command),
+        };
+        if let Some(cached) = self.store.get(&key)? {
+            return Ok(cached);
+        }
+        let result = self.bridge.execute(command)?;
+        self.store.put(&key, &result, self.ttl)?;
+        Ok(result)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// SCHEDULING — Interactive calls ahead of heavy background work
+// ═══════════════════════════════════════════════════════════════════
+
+/// Scheduling priority of a `HydraCommand`.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+    /// Background work (reindexing, compaction)
+    Low,
+
+    #[default]
+    Normal,
+
+    /// A user is waiting (grounding, queries)
+    High,
+
+    /// Must run next (shutdown, cancellation)
+    Critical,
+}
+
+impl Priority {
+    pub fn is_normal(&self) -> bool {
+        *self == Self::Normal
+    }
+}
+
+impl HydraCommand {
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_deadline(mut self, deadline: DateTime<Utc>) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    pub fn exclusive(mut self) -> Self {
+        self.exclusive = true;
+        self
+    }
+
+    pub fn is_past_deadline(&self) -> bool {
+        self.deadline.is_some_and(|d| d <= Utc::now())
+    }
+}
+
+/// Pending commands for one sister, released in scheduling order.
+///
+/// Order: higher `priority` first, then earlier `deadline` (commands
+/// without one last), then arrival. An `exclusive` command waits for
+/// everything in flight to `finish` and blocks the queue until it
+/// finishes itself; later commands never overtake it.
+#[derive(Debug, Default)]
+pub struct CommandQueue {
+    pending: Vec<(u64, HydraCommand)>,
+    next_seq: u64,
+    in_flight: usize,
+    exclusive_in_flight: bool,
+}
+
+impl CommandQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, command: HydraCommand) {
+        self.pending.push((self.next_seq, command));
+        self.next_seq += 1;
+    }
+
+    /// Next command to run, or None if the queue is empty or blocked.
+    pub fn pop(&mut self) -> Option<HydraCommand> {
+        if self.exclusive_in_flight {
+            return None;
+        }
+        let (index, (_, next)) = self.pending.iter().enumerate().min_by(|(_, a), (_, b)| {
+            b.1.priority
+                .cmp(&a.1.priority)
+                .then_with(|| match (a.1.deadline, b.1.deadline) {
+                    (Some(x), Some(y)) => x.cmp(&y),
+                    (Some(_), None) => std::cmp::Ordering::Less,
+                    (None, Some(_)) => std::cmp::Ordering::Greater,
+                    (None, None) => std::cmp::Ordering::Equal,
+                })
+                .then_with(|| a.0.cmp(&b.0))
+        })?;
+        if next.exclusive && self.in_flight > 0 {
+            return None;
+        }
+        let (_, command) = self.pending.remove(index);
+        self.in_flight += 1;
+        self.exclusive_in_flight = command.exclusive;
+        Some(command)
+    }
+
+    /// Mark a command returned by `pop` as done.
+    pub fn finish(&mut self, command: &HydraCommand) {
+        self.in_flight = self.in_flight.saturating_sub(1);
+        if command.exclusive {
+            self.exclusive_in_flight = false;
+        }
+    }
+
+    /// Remove and return pending commands whose deadline has passed.
+    pub fn drain_expired(&mut self) -> Vec<HydraCommand> {
+        let (expired, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition(|(_, c)| c.is_past_deadline());
+        self.pending = pending;
+        expired.into_iter().map(|(_, c)| c).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Commands popped but not yet finished.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// SISTER POOLS — Several instances of one sister type
+// ═══════════════════════════════════════════════════════════════════
+
+/// Command/query param naming the pool instance to route to.
+pub const POOL_KEY_PARAM: &str = "pool_key";
+
+/// Instances of one sister type keyed by a pool key (e.g. one Codebase
+/// per repository).
+///
+/// Commands and queries route by their `pool_key` param, falling back
+/// to the default instance. Instances marked unavailable (manually or
+/// by `refresh_health`) are not routed to.
+pub struct SisterPool<S> {
+    sister_type: SisterType,
+    instances: BTreeMap<String, S>,
+    default_key: Option<String>,
+    unavailable: BTreeSet<String>,
+}
+
+impl<S> SisterPool<S> {
+    pub fn new(sister_type: SisterType) -> Self {
+        Self {
+            sister_type,
+            instances: BTreeMap::new(),
+            default_key: None,
+            unavailable: BTreeSet::new(),
+        }
+    }
+
+    pub fn sister_type(&self) -> SisterType {
+        self.sister_type
+    }
+
+    /// Add an instance, returning any it replaced. The first instance
+    /// becomes the default.
+    pub fn insert(&mut self, key: impl Into<String>, sister: S) -> Option<S> {
+        let key = key.into();
+        self.default_key.get_or_insert_with(|| key.clone());
+        self.unavailable.remove(&key);
+        self.instances.insert(key, sister)
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<S> {
+        self.unavailable.remove(key);
+        if self.default_key.as_deref() == Some(key) {
+            self.default_key = None;
+        }
+        self.instances.remove(key)
+    }
+
+    /// Route unkeyed commands to `key`.
+    pub fn set_default(&mut self, key: impl Into<String>) -> SisterResult<()> {
+        let key = key.into();
+        if !self.instances.contains_key(&key) {
+            return Err(SisterError::not_found(format!("pool instance {}", key)));
+        }
+        self.default_key = Some(key);
+        Ok(())
+    }
+
+    pub fn default_key(&self) -> Option<&str> {
+        self.default_key.as_deref()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&S> {
+        self.instances.get(key)
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut S> {
+        self.instances.get_mut(key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.instances.keys().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.instances.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instances.is_empty()
+    }
+
+    /// Stop routing to `key` until `mark_available`.
+    pub fn mark_unavailable(&mut self, key: impl Into<String>) {
+        self.unavailable.insert(key.into());
+    }
+
+    pub fn mark_available(&mut self, key: &str) {
+        self.unavailable.remove(key);
+    }
+
+    pub fn is_available(&self, key: &str) -> bool {
+        self.instances.contains_key(key) && !self.unavailable.contains(key)
+    }
+
+    /// Instance key for `params`: its `pool_key`, else the default.
+    ///
+    /// Fails with `NotFound` for unknown keys and `InvalidState` for
+    /// unavailable ones.
+    pub fn route(&self, params: &Metadata) -> SisterResult<String> {
+        let key = match params.get(POOL_KEY_PARAM) {
+            Some(value) => value
+                .as_str()
+                .ok_or_else(|| {
+                    SisterError::invalid_input(format!("{} must be a string", POOL_KEY_PARAM))
+                })?
+                .to_string(),
+            None => self.default_key.clone().ok_or_else(|| {
+                SisterError::invalid_input(format!(
+                    "{} pool has no default instance; set {}",
+                    self.sister_type, POOL_KEY_PARAM
+                ))
+            })?,
+        };
+        if !self.instances.contains_key(&key) {
+            return Err(SisterError::not_found(format!("pool instance {}", key))
+                .with_context(POOL_KEY_PARAM, &key));
+        }
+        if self.unavailable.contains(&key) {
+            return Err(SisterError::new(
+                ErrorCode::InvalidState,
+                format!("pool instance {} is unavailable", key),
+            )
+            .with_context(POOL_KEY_PARAM, &key));
+        }
+        Ok(key)
+    }
+
+    fn default_instance(&self) -> SisterResult<&S> {
+        let key = self.route(&Metadata::new())?;
+        Ok(&self.instances[&key])
+    }
+}
+
+impl<S: Sister> SisterPool<S> {
+    /// Health of every instance. Unhealthy instances become unavailable,
+    /// healthy ones available again.
+    pub fn refresh_health(&mut self) -> BTreeMap<String, HealthStatus> {
+        let health: BTreeMap<_, _> = self
+            .instances
+            .iter()
+            .map(|(key, sister)| (key.clone(), sister.health()))
+            .collect();
+        for (key, status) in &health {
+            if status.healthy {
+                self.unavailable.remove(key);
+            } else {
+                self.unavailable.insert(key.clone());
+            }
+        }
+        health
+    }
+}
+
+impl<S: Queryable> SisterPool<S> {
+    /// Run `query` on the instance named by its `pool_key` param.
+    pub fn query(&self, query: Query) -> SisterResult<QueryResult> {
+        let params: Metadata = query.params.clone().into_iter().collect();
+        let key = self.route(&params)?;
+        self.instances[&key].query(query)
+    }
+
+    /// Run `query` on every available instance.
+    pub fn query_all(&self, query: &Query) -> BTreeMap<String, SisterResult<QueryResult>> {
+        self.instances
+            .iter()
+            .filter(|(key, _)| !self.unavailable.contains(*key))
+            .map(|(key, sister)| (key.clone(), sister.query(query.clone())))
+            .collect()
+    }
+}
+
+impl<S: HydraBridge> HydraBridge for SisterPool<S> {
+    /// Context of the default instance.
+    fn session_context(&self) -> SisterResult<SessionContext> {
+        self.default_instance()?.session_context()
+    }
+
+    /// Restores the default instance.
+    fn restore_session(&mut self, context: SessionContext) -> SisterResult<()> {
+        let key = self.route(&Metadata::new())?;
+        self.instances
+            .get_mut(&key)
+            .unwrap()
+            .restore_session(context)
+    }
+
+    /// Sums item counts; per-instance summaries go in `metadata`.
+    fn summary(&self) -> SisterResult<SisterSummary> {
+        let mut item_count = 0;
+        let mut metadata = Metadata::new();
+        for (key, sister) in &self.instances {
+            if self.unavailable.contains(key) {
+                metadata.insert(key.clone(), serde_json::json!({ "available": false }));
+                continue;
+            }
+            let summary = sister.summary()?;
+            item_count += summary.item_count;
+            metadata.insert(key.clone(), serde_json::to_value(&summary)?);
+        }
+        Ok(SisterSummary {
+            sister_type: self.sister_type,
+            status_line: format!(
+                "{} instances ({} available), {} items",
+                self.instances.len(),
+                self.keys().filter(|k| self.is_available(k)).count(),
+                item_count
+            ),
+            item_count,
+            active_context: self.default_key.clone(),
+            metadata,
+        })
+    }
+
+    fn execute(&mut self, command: HydraCommand) -> SisterResult<CommandResult> {
+        let key = self.route(&command.params)?;
+        self.instances.get_mut(&key).unwrap().execute(command)
+    }
+}
+
+impl<S> std::fmt::Debug for SisterPool<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SisterPool")
+            .field("sister_type", &self.sister_type)
+            .field("instances", &self.instances.keys().collect::<Vec<_>>())
+            .field("default_key", &self.default_key)
+            .field("unavailable", &self.unavailable)
+            .finish()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// BRIDGE ADAPTER — HydraBridge for any fully-equipped sister
+// ═══════════════════════════════════════════════════════════════════
+
+/// How `BridgeAdapter` reaches a sister's active context.
+///
+/// Implemented by the `Sessions` and `Workspaces` markers, so sisters
+/// of either context model get a bridge without writing any code.
+pub trait ContextAccess<S> {
+    /// Info for the active session/workspace
+    fn active_info(sister: &S) -> SisterResult<ContextInfo>;
+
+    /// Make `context` the active one again
+    fn restore(sister: &mut S, context: &SessionContext) -> SisterResult<()>;
+}
+
+/// `ContextAccess` for session-based sisters (Memory, Vision, Identity).
+///
+/// Sessions can't be reopened, so restoring starts a fresh session
+/// under the same name unless that session is still current.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sessions;
+
+impl<S: SessionManagement> ContextAccess<S> for Sessions {
+    fn active_info(sister: &S) -> SisterResult<ContextInfo> {
+        sister.current_session_info()
+    }
+
+    fn restore(sister: &mut S, context: &SessionContext) -> SisterResult<()> {
+        if sister.current_session() != Some(context.context_id) {
+            sister.start_session(&context.context_name)?;
+        }
+        Ok(())
+    }
+}
+
+/// `ContextAccess` for workspace-based sisters (Codebase).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Workspaces;
+
+impl<S: WorkspaceManagement> ContextAccess<S> for Workspaces {
+    fn active_info(sister: &S) -> SisterResult<ContextInfo> {
+        sister.current_workspace_info()
+    }
+
+    fn restore(sister: &mut S, context: &SessionContext) -> SisterResult<()> {
+        sister.switch_workspace(context.context_id)
+    }
+}
+
+/// Generic `HydraBridge` over a sister's standard traits.
+///
+/// `execute` routes these command types:
+/// - `ground` — `{"claim": str}` → `GroundingResult`
+/// - `evidence` — `{"query": str, "max_results"?: n}` → `[EvidenceDetail]`
+/// - `query` — params are a `Query` → `QueryResult`
+/// - `health` — `HealthStatus`
+/// - `recent_events` — `{"limit"?: n}` → `[SisterEvent]`
+///
+/// Anything else fails with `InvalidInput`; wrap the adapter for
+/// sister-specific commands.
+pub struct BridgeAdapter<S, C = Sessions> {
+    sister: S,
+    recent_items: usize,
+    _context: PhantomData<C>,
+}
+
+impl<S> BridgeAdapter<S, Sessions> {
+    /// Bridge a session-based sister.
+    pub fn sessions(sister: S) -> Self {
+        Self::new(sister)
+    }
+}
+
+impl<S> BridgeAdapter<S, Workspaces> {
+    /// Bridge a workspace-based sister.
+    pub fn workspaces(sister: S) -> Self {
+        Self::new(sister)
+    }
+}
+
+impl<S, C> BridgeAdapter<S, C> {
+    fn new(sister: S) -> Self {
+        Self {
+            sister,
+            recent_items: 5,
+            _context: PhantomData,
+        }
+    }
+
+    /// How many recent items `session_context` includes (default 5).
+    pub fn recent_items(mut self, count: usize) -> Self {
+        self.recent_items = count;
+        self
+    }
+
+    pub fn sister(&self) -> &S {
+        &self.sister
+    }
+
+    pub fn sister_mut(&mut self) -> &mut S {
+        &mut self.sister
+    }
+
+    pub fn into_inner(self) -> S {
+        self.sister
+    }
+}
+
+/// Render a query result row as a short line for LLM context.
+#[cfg(feature = "events")]
+fn recent_item_line(value: &serde_json::Value) -> String {
+    if let Some(text) = value.as_str() {
+        return text.to_string();
+    }
+    ["summary", "content", "name"]
+        .iter()
+        .find_map(|key| value.get(key).and_then(|v| v.as_str()))
+        .map(str::to_string)
+        .unwrap_or_else(|| value.to_string())
+}
+
+#[cfg(feature = "events")]
+fn command_params<T: serde::de::DeserializeOwned>(command: &HydraCommand) -> SisterResult<T> {
+    serde_json::from_value(serde_json::to_value(&command.params)?).map_err(|e| {
+        SisterError::invalid_input(format!(
+            "Invalid params for {}: {}",
+            command.command_type, e
+        ))
+        .with_context("command_type", command.command_type.clone())
+    })
+}
+
+#[cfg(feature = "events")]
+impl<S, C> HydraBridge for BridgeAdapter<S, C>
+where
+    S: Sister + Grounding + EventEmitter + Queryable,
+    C: ContextAccess<S>,
+{
+    fn session_context(&self) -> SisterResult<SessionContext> {
+        let info = C::active_info(&self.sister)?;
+        let recent_items = if self.recent_items > 0 && self.sister.supports_query("recent") {
+            self.sister
+                .recent(self.recent_items)?
+                .results
+                .iter()
+                .map(recent_item_line)
+                .collect()
+        } else {
+            vec![]
+        };
+        Ok(SessionContext {
+            sister_type: self.sister.sister_type(),
+            context_id: info.id,
+            context_name: info.name.clone(),
+            summary: format!("{} ({} items)", info.name, info.item_count),
+            recent_items,
+            metadata: info.metadata,
+        })
+    }
+
+    fn restore_session(&mut self, context: SessionContext) -> SisterResult<()> {
+        C::restore(&mut self.sister, &context)
+    }
+
+    fn summary(&self) -> SisterResult<SisterSummary> {
+        let info = C::active_info(&self.sister)?;
+        let health = self.sister.health();
+        Ok(SisterSummary {
+            sister_type: self.sister.sister_type(),
+            status_line: format!(
+                "{} items in {}, {}",
+                info.item_count,
+                info.name,
+                if health.healthy {
+                    "healthy"
+                } else {
+                    "unhealthy"
+                }
+            ),
+            item_count: info.item_count,
+            active_context: Some(info.name),
+            metadata: Metadata::new(),
+        })
+    }
+
+    fn execute(&mut self, command: HydraCommand) -> SisterResult<CommandResult> {
+        #[derive(Deserialize)]
+        struct Claim {
+            claim: String,
+        }
+        #[derive(Deserialize)]
+        struct EvidenceParams {
+            query: String,
+            #[serde(default = "default_max_results")]
+            max_results: usize,
+        }
+        #[derive(Deserialize)]
+        struct Limit {
+            #[serde(default = "default_max_results")]
+            limit: usize,
+        }
+        fn default_max_results() -> usize {
+            10
+        }
+
+        let (data, evidence_ids) = match command.command_type.as_str() {
+            "ground" => {
+                let Claim { claim } = command_params(&command)?;
+                let result = self.sister.ground(&claim)?;
+                let ids = result.evidence.iter().map(|e| e.id.clone()).collect();
+                (serde_json::to_value(result)?, ids)
+            }
+            "evidence" => {
+                let params: EvidenceParams = command_params(&command)?;
+                let details = self.sister.evidence(&params.query, params.max_results)?;
+                let ids = details.iter().map(|e| e.id.clone()).collect();
+                (serde_json::to_value(details)?, ids)
+            }
+            "query" => {
+                let query: Query = command_params(&command)?;
+                (serde_json::to_value(self.sister.query(query)?)?, vec![])
+            }
+            "health" => (serde_json::to_value(self.sister.health())?, vec![]),
+            "recent_events" => {
+                let Limit { limit } = command_params(&command)?;
+                (
+                    serde_json::to_value(self.sister.recent_events(limit))?,
+                    vec![],
+                )
+            }
+            other => {
+                return Err(SisterError::invalid_input(format!(
+                    "Unknown command type for {}: {}",
+                    self.sister.sister_type(),
+                    other
+                ))
+                .with_context("command_type", other))
+            }
+        };
+
+        Ok(CommandResult {
+            success: true,
+            data,
+            error: None,
+            evidence_ids,
+            usage: None,
+        })
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// DISCOVERY — What Hydra's fleet overview shows for each sister
+// ═══════════════════════════════════════════════════════════════════
+
+/// One sister's entry in Hydra's fleet overview: identity, health,
+/// query surface, active context, and (redacted) config in one document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct DiscoveryDocument {
+    #[serde(flatten)]
+    pub info: SisterInfo,
+
+    pub health: HealthStatus,
+
+    /// Everything `query()` accepts
+    #[serde(default)]
+    pub query_types: Vec<QueryTypeInfo>,
+
+    /// Active session/workspace; `None` if there is none or it failed to load
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<ContextSummary>,
+
+    /// Config in effect; secrets serialize redacted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<SisterConfig>,
+
+    pub generated_at: DateTime<Utc>,
+}
+
+impl DiscoveryDocument {
+    /// Document for a session-based sister (Memory, Vision, Identity).
+    pub fn from<S>(sister: &S) -> Self
+    where
+        S: Sister + Queryable + SessionManagement,
+    {
+        Self::from_with::<S, Sessions>(sister)
+    }
+
+    /// Document for a workspace-based sister (Codebase).
+    pub fn from_workspaces<S>(sister: &S) -> Self
+    where
+        S: Sister + Queryable + WorkspaceManagement,
+    {
+        Self::from_with::<S, Workspaces>(sister)
+    }
+
+    /// Document reaching the active context through `C`.
+    pub fn from_with<S, C>(sister: &S) -> Self
+    where
+        S: Sister + Queryable,
+        C: ContextAccess<S>,
+    {
+        Self {
+            info: SisterInfo::from_sister(sister),
+            health: sister.health(),
+            query_types: sister.query_types(),
+            context: C::active_info(sister).ok().map(ContextSummary::from),
+            config: sister.current_config(),
+            generated_at: Utc::now(),
+        }
+    }
+
+    pub fn sister_type(&self) -> SisterType {
+        self.info.sister_type
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// COMMAND ROUTING — Per-command handlers instead of one giant match
+// ═══════════════════════════════════════════════════════════════════
+
+/// JSON type expected for a command parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParamKind {
+    String,
+    Integer,
+    Number,
+    Boolean,
+    Array,
+    Object,
+    Any,
+}
+
+impl ParamKind {
+    /// Whether `value` has this type.
+    pub fn matches(&self, value: &serde_json::Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::Integer => value.is_i64() || value.is_u64(),
+            Self::Number => value.is_number(),
+            Self::Boolean => value.is_boolean(),
+            Self::Array => value.is_array(),
+            Self::Object => value.is_object(),
+            Self::Any => true,
+        }
+    }
+}
+
+/// One parameter of a routed command.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParamSpec {
+    pub name: String,
+    pub kind: ParamKind,
+    pub required: bool,
+}
+
+/// Parameter schema for one `command_type`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommandSchema {
+    pub command_type: String,
+    pub description: String,
+    #[serde(default)]
+    pub params: Vec<ParamSpec>,
+}
+
+impl CommandSchema {
+    pub fn new(command_type: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            command_type: command_type.into(),
+            description: description.into(),
+            params: vec![],
+        }
+    }
+
+    pub fn required(mut self, name: impl Into<String>, kind: ParamKind) -> Self {
+        self.params.push(ParamSpec {
+            name: name.into(),
+            kind,
+            required: true,
+        });
+        self
+    }
+
+    pub fn optional(mut self, name: impl Into<String>, kind: ParamKind) -> Self {
+        self.params.push(ParamSpec {
+            name: name.into(),
+            kind,
+            required: false,
+        });
+        self
+    }
+
+    /// Check `params` against the schema.
+    ///
+    /// Fails with `InvalidInput` (InvalidParams at the protocol layer)
+    /// naming the offending parameter. Unknown parameters are allowed.
+    pub fn validate(&self, params: &Metadata) -> SisterResult<()> {
+        for spec in &self.params {
+            let problem = match params.get(&spec.name) {
+                None | Some(serde_json::Value::Null) if spec.required => "is required",
+                Some(value) if !value.is_null() && !spec.kind.matches(value) => {
+                    "has the wrong type"
+                }
+                _ => continue,
+            };
+            return Err(SisterError::invalid_input(format!(
+                "Parameter \"{}\" of {} {} (expected {:?})",
+                spec.name, self.command_type, problem, spec.kind
+            ))
+            .with_context("command_type", self.command_type.clone())
+            .with_context("param", spec.name.clone()));
+        }
+        Ok(())
+    }
+}
+
+impl CommandResult {
+    /// ToolNotFound-style result for a command nobody handles.
+    ///
+    /// `data` holds the JSON-RPC error object (code -32803).
+    pub fn unknown_command(command_type: &str) -> Self {
+        let error = ProtocolError::tool_not_found(command_type);
+        Self {
+            success: false,
+            data: error.to_error_object(),
+            error: Some(error.message),
+            evidence_ids: vec![],
+            usage: None,
+        }
+    }
+}
+
+/// Handler for one routed command.
+pub type CommandHandler<S> =
+    Box<dyn Fn(&mut S, HydraCommand) -> SisterResult<CommandResult> + Send + Sync>;
+
+/// Routes `HydraCommand`s to handlers registered per `command_type`.
+///
+/// Params are validated against the handler's `CommandSchema` before
+/// it runs. Unrouted commands go to the `unknown_command` fallback,
+/// or produce `CommandResult::unknown_command`.
+pub struct CommandRouter<S> {
+    routes: BTreeMap<String, (CommandSchema, CommandHandler<S>)>,
+    fallback: Option<CommandHandler<S>>,
+}
+
+impl<S> CommandRouter<S> {
+    pub fn new() -> Self {
+        Self {
+            routes: BTreeMap::new(),
+            fallback: None,
+        }
+    }
+
+    /// Register a handler. A later route for the same command replaces it.
+    pub fn route<F>(mut self, schema: CommandSchema, handler: F) -> Self
+    where
+        F: Fn(&mut S, HydraCommand) -> SisterResult<CommandResult> + Send + Sync + 'static,
+    {
+        self.routes
+            .insert(schema.command_type.clone(), (schema, Box::new(handler)));
+        self
+    }
+
+    /// Handler for commands with no route.
+    pub fn unknown_command<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&mut S, HydraCommand) -> SisterResult<CommandResult> + Send + Sync + 'static,
+    {
+        self.fallback = Some(Box::new(handler));
+        self
+    }
+
+    /// Whether `command_type` has a route.
+    pub fn handles(&self, command_type: &str) -> bool {
+        self.routes.contains_key(command_type)
+    }
+
+    /// Schemas of every routed command, sorted by command type.
+    pub fn schemas(&self) -> Vec<&CommandSchema> {
+        self.routes.values().map(|(schema, _)| schema).collect()
+    }
+
+    /// Validate and run a command.
+    pub fn dispatch(&self, sister: &mut S, command: HydraCommand) -> SisterResult<CommandResult> {
+        match self.routes.get(&command.command_type) {
+            Some((schema, handler)) => {
+                schema.validate(&command.params)?;
+                handler(sister, command)
+            }
+            None => match &self.fallback {
+                Some(fallback) => fallback(sister, command),
+                None => Ok(CommandResult::unknown_command(&command.command_type)),
+            },
+        }
+    }
+}
+
+impl<S> Default for CommandRouter<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// SAGAS — Multi-sister commands that must not half-complete
+// ═══════════════════════════════════════════════════════════════════
+
+/// One step of a saga: a command for one sister and how to undo it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SagaStep {
+    /// Sister that runs the command
+    pub sister_type: SisterType,
+
+    /// Forward command
+    pub command: HydraCommand,
+
+    /// Command that undoes `command` (None = nothing to undo)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compensation: Option<HydraCommand>,
+}
+
+impl SagaStep {
+    pub fn new(sister_type: SisterType, command: HydraCommand) -> Self {
+        Self {
+            sister_type,
+            command,
+            compensation: None,
+        }
+    }
+
+    pub fn compensate_with(mut self, command: HydraCommand) -> Self {
+        self.compensation = Some(command);
+        self
+    }
+}
+
+/// What happened to one saga step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SagaStepStatus {
+    /// Ran and stayed applied
+    Completed,
+
+    /// Ran, then was undone by its compensation
+    Compensated,
+
+    /// Ran, but its compensation failed (sister left inconsistent)
+    CompensationFailed,
+
+    /// The step that failed
+    Failed,
+}
+
+/// Per-step record in a saga's consolidated result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SagaStepRecord {
+    pub sister_type: SisterType,
+    pub command_type: String,
+    pub status: SagaStepStatus,
+
+    /// Receipt for the forward command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_id: Option<ReceiptId>,
+
+    /// Receipt for the compensation, if one ran
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compensation_receipt_id: Option<ReceiptId>,
+
+    /// Error message for failed steps
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Runs commands across sisters in order, compensating completed steps
+/// in reverse when one fails.
+///
+/// ```rust,ignore
+/// let result = SagaExecutor::new()
+///     .sister(SisterType::Memory, &mut memory_bridge)
+///     .sister(SisterType::Codebase, &mut codebase_bridge)
+///     .receipts(&identity)
+///     .step(SagaStep::new(SisterType::Memory, add).compensate_with(remove))
+///     .step(SagaStep::new(SisterType::Codebase, update))
+///     .run()?;
+/// ```
+///
+/// A step fails if `execute` errors, returns `success: false`, or its
+/// receipt can't be created. The consolidated `CommandResult` carries
+/// every `SagaStepRecord` in `data["steps"]` and the summed `usage` of
+/// the forward commands.
+#[derive(Default)]
+pub struct SagaExecutor<'a> {
+    sisters: HashMap<SisterType, &'a mut dyn HydraBridge>,
+    steps: Vec<SagaStep>,
+    receipts: Option<&'a dyn ReceiptIntegration>,
+}
+
+impl<'a> SagaExecutor<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the bridge that runs commands for `sister_type`.
+    pub fn sister(mut self, sister_type: SisterType, bridge: &'a mut dyn HydraBridge) -> Self {
+        self.sisters.insert(sister_type, bridge);
+        self
+    }
+
+    /// Append a step.
+    pub fn step(mut self, step: SagaStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Record a receipt for every forward and compensating command.
+    pub fn receipts(mut self, receipts: &'a dyn ReceiptIntegration) -> Self {
+        self.receipts = Some(receipts);
+        self
+    }
+
+    /// Run every step, or none of them.
+    ///
+    /// `Err` only if a step names an unregistered sister, in which case
+    /// nothing ran. Step failures produce `success: false`.
+    pub fn run(&mut self) -> SisterResult<CommandResult> {
+        if let Some(step) = self
+            .steps
+            .iter()
+            .find(|s| !self.sisters.contains_key(&s.sister_type))
+        {
+            return Err(SisterError::invalid_input(format!(
+                "Saga step {} targets unregistered sister {}",
+                step.command.command_type, step.sister_type
+            ))
+            .with_context("command_type", step.command.command_type.clone()));
+        }
+
+        let saga_id = format!("saga_{}", uuid::Uuid::new_v4().simple());
+        let mut records: Vec<SagaStepRecord> = Vec::new();
+        let mut evidence_ids = Vec::new();
+        let mut usage: Option<Usage> = None;
+        let mut failure = None;
+
+        for index in 0..self.steps.len() {
+            let step = self.steps[index].clone();
+            let outcome = self.run_command(step.sister_type, &step.command, None);
+            let (status, receipt_id, error) = match outcome {
+                Ok((result, receipt_id)) => {
+                    evidence_ids.extend(result.evidence_ids);
+                    if let Some(step_usage) = result.usage {
+                        *usage.get_or_insert_with(Usage::default) += step_usage;
+                    }
+                    (SagaStepStatus::Completed, receipt_id, None)
+                }
+                Err((error, receipt_id)) => (SagaStepStatus::Failed, receipt_id, Some(error)),
+            };
+            records.push(SagaStepRecord {
+                sister_type: step.sister_type,
+                command_type: step.command.command_type.clone(),
+                status,
+                receipt_id,
+                compensation_receipt_id: None,
+                error: error.clone(),
+            });
+            if let Some(error) = error {
+                failure = Some((index, error));
+                break;
+            }
+        }
+
+        let Some((failed_index, error)) = failure else {
+            return Ok(CommandResult {
+                success: true,
+                data: serde_json::json!({ "saga_id": saga_id, "steps": records }),
+                error: None,
+                evidence_ids,
+                usage,
+            });
+        };
+
+        // A step whose forward command failed may still have been
+        // partially applied, so it is compensated too.
+        for index in (0..=failed_index).rev() {
+            let step = self.steps[index].clone();
+            let Some(compensation) = &step.compensation else {
+                continue;
+            };
+            let outcome = self.run_command(
+                step.sister_type,
+                compensation,
+                Some(&step.command.command_type),
+            );
+            let record = &mut records[index];
+            match outcome {
+                Ok((_, receipt_id)) => {
+                    record.compensation_receipt_id = receipt_id;
+                    if record.status == SagaStepStatus::Completed {
+                        record.status = SagaStepStatus::Compensated;
+                    }
+                }
+                Err((_, receipt_id)) => {
+                    record.compensation_receipt_id = receipt_id;
+                    if record.status == SagaStepStatus::Completed {
+                        record.status = SagaStepStatus::CompensationFailed;
+                    }
+                }
+            }
+        }
+
+        let failed = &self.steps[failed_index];
+        Ok(CommandResult {
+            success: false,
+            data: serde_json::json!({
+                "saga_id": saga_id,
+                "failed_step": failed_index,
+                "steps": records,
+            }),
+            error: Some(format!(
+                "Saga step {} ({}) failed: {}",
+                failed_index, failed.command.command_type, error
+            )),
+            evidence_ids,
+            usage,
+        })
+    }
+
+    /// Execute one command and receipt it. On failure returns the
+    /// error message and the receipt recording the failure, if any.
+    fn run_command(
+        &mut self,
+        sister_type: SisterType,
+        command: &HydraCommand,
+        compensates: Option<&str>,
+    ) -> Result<(CommandResult, Option<ReceiptId>), (String, Option<ReceiptId>)> {
+        let bridge = self
+            .sisters
+            .get_mut(&sister_type)
+            .expect("sisters checked before running");
+        let outcome = match bridge.execute(command.clone()) {
+            Ok(result) if result.success => Ok(result),
+            Ok(result) => Err((
+                "COMMAND_FAILED".to_string(),
+                result
+                    .error
+                    .unwrap_or_else(|| "command reported failure".to_string()),
+            )),
+            Err(e) => Err((e.code_str().to_string(), e.message)),
+        };
+
+        let Some(receipts) = self.receipts else {
+            return outcome
+                .map(|r| (r, None))
+                .map_err(|(_, message)| (message, None));
+        };
+        let action_outcome = match &outcome {
+            Ok(_) => ActionOutcome::success(),
+            Err((code, message)) => ActionOutcome::failure(code.clone(), message.clone()),
+        };
+        let mut action =
+            ActionRecord::new(sister_type, command.command_type.clone(), action_outcome)
+                .for_command(command);
+        if let Some(original) = compensates {
+            action = action.param("compensates", original);
+        }
+        let receipt = receipts.create_receipt(action);
+
+        match (outcome, receipt) {
+            (Ok(result), Ok(id)) => Ok((result, Some(id))),
+            (Ok(_), Err(e)) => Err((format!("receipt failed: {}", e.message), None)),
+            (Err((_, message)), receipt) => Err((message, receipt.ok())),
+        }
+    }
+}
+
+impl std::fmt::Debug for SagaExecutor<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SagaExecutor")
+            .field("sisters", &self.sisters.keys().collect::<Vec<_>>())
+            .field("steps", &self.steps)
+            .field("has_receipts", &self.receipts.is_some())
+            .finish()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// EXECUTION GATE — Hydra's safety core (placeholder types)
+// ═══════════════════════════════════════════════════════════════════
+
+/// Risk level for an action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskLevel {
+    /// Low risk (0.0-0.3): auto-approve
+    Low,
+
+    /// Medium risk (0.3-0.6): log and proceed
+    Medium,
+
+    /// High risk (0.6-0.8): require confirmation
+    High,
+
+    /// Critical risk (0.8-1.0): block and escalate
+    Critical,
+}
+
+/// An action that needs to pass through the execution gate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GatedAction {
+    /// What sister is requesting this action
+    pub sister_type: SisterType,
+
+    /// Action type
+    pub action_type: String,
+
+    /// Assessed risk level
+    pub risk_level: RiskLevel,
+
+    /// Risk score (0.0-1.0)
+    pub risk_score: f64,
+
+    /// Required capability
+    pub capability: String,
+
+    /// When the action was requested
+    pub requested_at: DateTime<Utc>,
+
+    /// Action parameters
+    #[serde(default)]
+    pub params: Metadata,
+
+    /// Scoped token authorizing `capability`, if the caller holds one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<CapabilityToken>,
+
+    /// Preview only: the gate decides as usual but must not queue
+    /// approvals or consume grants; the action will not run
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+}
+
+/// Result of passing through the execution gate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateDecision {
+    /// Whether the action is approved
+    pub approved: bool,
+
+    /// Reason for the decision
+    pub reason: String,
+
+    /// Approval ID (for receipt chain)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approval_id: Option<String>,
+
+    /// Conditions imposed on the action
+    #[serde(default)]
+    pub conditions: Vec<String>,
+}
+
+/// The Execution Gate trait (placeholder).
+///
+/// Hydra implements this, NOT sisters. Sisters submit actions
+/// to the gate; Hydra decides whether to approve.
+pub trait ExecutionGate {
+    /// Submit an action for approval
+    fn check(&self, action: GatedAction) -> SisterResult<GateDecision>;
+
+    /// Quick check if a capability is available
+    fn has_capability(&self, capability: &str) -> bool;
+
+    /// Get current risk threshold
+    fn risk_threshold(&self) -> RiskLevel;
+
+    /// Whether tokens from `issuer` are honoured. None are by default;
+    /// gates that accept tokens list the issuers they trust.
+    fn trusts_issuer(&self, _issuer: &str) -> bool {
+        false
+    }
+
+    /// Validate the action's capability and token.
+    ///
+    /// The capability must pass `has_capability` whether or not a token
+    /// is attached; a token only narrows the grant, and must come from
+    /// an issuer the gate trusts.
+    fn validate_token(&self, action: &GatedAction) -> SisterResult<()> {
+        validate_gated_token(
+            action,
+            |capability| self.has_capability(capability),
+            |issuer| self.trusts_issuer(issuer),
+        )
+    }
+}
+
+/// Shared body of `ExecutionGate::validate_token` and its async twin.
+fn validate_gated_token(
+    action: &GatedAction,
+    has_capability: impl Fn(&str) -> bool,
+    trusts_issuer: impl Fn(&str) -> bool,
+) -> SisterResult<()> {
+    if !has_capability(&action.capability) {
+        return Err(SisterError::permission_denied(format!(
+            "capability {} not granted",
+            action.capability
+        ))
+        .with_context("capability", &action.capability));
+    }
+    match &action.token {
+        Some(token) if !trusts_issuer(&token.issuer) => Err(SisterError::permission_denied(
+            format!("token issuer {} is not trusted", token.issuer),
+        )
+        .with_context("capability", &action.capability)
+        .with_context("reason", "issuer")),
+        Some(token) => token.validate(action),
+        None => Ok(()),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// CAPABILITY TOKENS — Scoped, expiring grants for gated actions
+// ═══════════════════════════════════════════════════════════════════
+
+/// `GatedAction` params key matched against `TokenConstraints::context_id`.
+pub const TOKEN_CONTEXT_PARAM: &str = "context_id";
+
+/// Limits on what a `CapabilityToken` authorizes. Empty means unrestricted.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TokenConstraints {
+    /// Highest risk level the token covers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_risk: Option<RiskLevel>,
+
+    /// Sisters allowed to use the token
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sister_types: Vec<SisterType>,
+
+    /// Action types the token covers
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub action_types: Vec<String>,
+
+    /// Context (session/workspace) the action must run in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_id: Option<String>,
+}
+
+/// A scoped, expiring grant of a capability.
+///
+/// `capability` is a pattern: an exact name, a prefix ending in `*`
+/// (`"codebase:*"`), or `"*"` for everything.
+///
+/// Tokens are not signed: a gate honours one only if
+/// `ExecutionGate::trusts_issuer` accepts its `issuer`, and only within
+/// what `has_capability` already allows.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CapabilityToken {
+    /// Capability pattern
+    pub capability: String,
+
+    /// Who issued the token
+    pub issuer: String,
+
+    /// When the token was issued
+    pub issued_at: DateTime<Utc>,
+
+    /// When the token stops being valid (never, if absent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// Scope restrictions
+    #[serde(default)]
+    pub constraints: TokenConstraints,
+}
+
+impl CapabilityToken {
+    pub fn new(capability: impl Into<String>, issuer: impl Into<String>) -> Self {
+        Self {
+            capability: capability.into(),
+            issuer: issuer.into(),
+            issued_at: Utc::now(),
+            expires_at: None,
+            constraints: TokenConstraints::default(),
+        }
+    }
+
+    /// Expire `ttl` after issue.
+    pub fn expires_in(mut self, ttl: Duration) -> Self {
+        self.expires_at =
+            Some(self.issued_at + chrono::Duration::from_std(ttl).unwrap_or(chrono::Duration::MAX));
+        self
+    }
+
+    pub fn max_risk(mut self, level: RiskLevel) -> Self {
+        self.constraints.max_risk = Some(level);
+        self
+    }
+
+    pub fn for_sister(mut self, sister_type: SisterType) -> Self {
+        self.constraints.sister_types.push(sister_type);
+        self
+    }
+
+    pub fn for_action(mut self, action_type: impl Into<String>) -> Self {
+        self.constraints.action_types.push(action_type.into());
+        self
+    }
+
+    pub fn in_context(mut self, context_id: impl Into<String>) -> Self {
+        self.constraints.context_id = Some(context_id.into());
+        self
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|at| at <= Utc::now())
+    }
+
+    /// Whether the pattern covers `capability`.
+    pub fn grants(&self, capability: &str) -> bool {
+        match self.capability.strip_suffix('*') {
+            Some(prefix) => capability.starts_with(prefix),
+            None => self.capability == capability,
+        }
+    }
+
+    /// Check the token authorizes `action`.
+    ///
+    /// Returns `PermissionDenied` naming the failed check in the
+    /// `reason` context.
+    pub fn validate(&self, action: &GatedAction) -> SisterResult<()> {
+        let c = &self.constraints;
+        let denied = if self.is_expired() {
+            Some("expired")
+        } else if !self.grants(&action.capability) {
+            Some("capability")
+        } else if c.max_risk.is_some_and(|max| action.risk_level > max) {
+            Some("max_risk")
+        } else if !c.sister_types.is_empty() && !c.sister_types.contains(&action.sister_type) {
+            Some("sister_type")
+        } else if !c.action_types.is_empty() && !c.action_types.contains(&action.action_type) {
+            Some("action_type")
+        } else if c.context_id.as_ref().is_some_and(|id| {
+            action
+                .params
+                .get(TOKEN_CONTEXT_PARAM)
+                .and_then(|v| v.as_str())
+                != Some(id)
+        }) {
+            Some("context")
+        } else {
+            None
+        };
+
+        match denied {
+            None => Ok(()),
+            Some(reason) => Err(SisterError::permission_denied(format!(
+                "token from {} does not authorize {} ({})",
+                self.issuer, action.action_type, reason
+            ))
+            .with_context("capability", &action.capability)
+            .with_context("reason", reason)),
+        }
+    }
+}
+
+impl GatedAction {
+    /// Attach a capability token.
+    pub fn with_token(mut self, token: CapabilityToken) -> Self {
+        self.token = Some(token);
+        self
+    }
+}
+
+impl GateDecision {
+    /// Decision that blocks until a user resolves `pending`.
+    pub fn awaiting_approval(pending: &PendingApproval) -> Self {
+        Self {
+            approved: false,
+            reason: format!(
+                "{} requires user approval (expires {})",
+                pending.action.action_type, pending.expires_at
+            ),
+            approval_id: Some(pending.id.to_string()),
+            conditions: vec![REQUIRES_APPROVAL_CONDITION.to_string()],
+        }
+    }
+
+    /// Whether the action is blocked on a user approval.
+    pub fn is_awaiting_approval(&self) -> bool {
+        !self.approved
+            && self
+                .conditions
+                .iter()
+                .any(|c| c == REQUIRES_APPROVAL_CONDITION)
+    }
+}
+
+/// `action_type` of receipts written by `ReceiptedGate`.
+pub const GATE_DECISION_ACTION: &str = "gate_decision";
+
+/// `ExecutionGate` wrapper that puts every decision on the receipt chain.
+///
+/// Each `check` writes a `gate_decision` receipt for the requesting
+/// sister with the gated action type, risk, capability, approval ID,
+/// and conditions as parameters. Fails closed: if the receipt cannot
+/// be written the decision is not returned.
+///
+/// Dry runs are passed through without a receipt: the action will not
+/// run, so there is nothing to audit.
+pub struct ReceiptedGate<G, R> {
+    gate: G,
+    receipts: R,
+}
+
+impl<G: ExecutionGate, R: ReceiptIntegration> ReceiptedGate<G, R> {
+    pub fn new(gate: G, receipts: R) -> Self {
+        Self { gate, receipts }
+    }
+
+    pub fn gate(&self) -> &G {
+        &self.gate
+    }
+
+    pub fn receipts(&self) -> &R {
+        &self.receipts
+    }
+
+    /// Check an action, returning the decision and its receipt (`None`
+    /// for dry runs, which are not recorded).
+    pub fn check_with_receipt(
+        &self,
+        action: GatedAction,
+    ) -> SisterResult<(GateDecision, Option<ReceiptId>)> {
+        if action.dry_run {
+            return Ok((self.gate.check(action)?, None));
+        }
+        let record = ActionRecord::new(
+            action.sister_type,
+            GATE_DECISION_ACTION,
+            ActionOutcome::success(),
+        )
+        .param("action_type", &action.action_type)
+        .param("risk_level", action.risk_level)
+        .param("risk_score", action.risk_score)
+        .param("capability", &action.capability);
+
+        match self.gate.check(action) {
+            Ok(decision) => {
+                let record = ActionRecord {
+                    outcome: ActionOutcome::success_with(serde_json::json!({
+                        "approved": decision.approved,
+                    })),
+                    ..record
+                }
+                .param("approved", decision.approved)
+                .param("reason", &decision.reason)
+                .param("approval_id", &decision.approval_id)
+                .param("conditions", &decision.conditions);
+                let receipt_id = self.receipts.create_receipt(record)?;
+                Ok((decision, Some(receipt_id)))
+            }
+            Err(error) => {
+                let record = ActionRecord {
+                    outcome: ActionOutcome::failure(
+                        error.code_str().to_string(),
+                        error.message.clone(),
+                    ),
+                    ..record
+                };
+                // The gate error is what the caller needs; a failed
+                // receipt for it is secondary.
+                let _ = self.receipts.create_receipt(record);
+                Err(error)
+            }
+        }
+    }
+}
+
+impl<G: ExecutionGate, R: ReceiptIntegration> ExecutionGate for ReceiptedGate<G, R> {
+    fn check(&self, action: GatedAction) -> SisterResult<GateDecision> {
+        self.check_with_receipt(action)
+            .map(|(decision, _)| decision)
+    }
+
+    fn has_capability(&self, capability: &str) -> bool {
+        self.gate.has_capability(capability)
+    }
+
+    fn risk_threshold(&self) -> RiskLevel {
+        self.gate.risk_threshold()
+    }
+
+    fn trusts_issuer(&self, issuer: &str) -> bool {
+        self.gate.trusts_issuer(issuer)
+    }
+
+    fn validate_token(&self, action: &GatedAction) -> SisterResult<()> {
+        self.gate.validate_token(action)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// APPROVAL WORKFLOW — Blocked high-risk actions awaiting a user
+// ═══════════════════════════════════════════════════════════════════
+
+/// `GateDecision::conditions` entry marking a decision as awaiting approval.
+pub const REQUIRES_APPROVAL_CONDITION: &str = "requires_user_approval";
+
+/// Unique approval request identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ApprovalId(pub UniqueId);
+
+impl ApprovalId {
+    pub fn new() -> Self {
+        Self(UniqueId::new())
+    }
+}
+
+impl Default for ApprovalId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for ApprovalId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "appr_{}", self.0)
+    }
+}
+
+/// A gated action waiting for a user to approve or deny it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingApproval {
+    /// Approval ID (also `GateDecision::approval_id`)
+    pub id: ApprovalId,
+
+    /// The blocked action
+    pub action: GatedAction,
+
+    /// When approval was requested
+    pub requested_at: DateTime<Utc>,
+
+    /// After this the action is treated as denied
+    pub expires_at: DateTime<Utc>,
+}
+
+impl PendingApproval {
+    /// Request approval for `action`, valid for `ttl`.
+    pub fn new(action: GatedAction, ttl: std::time::Duration) -> Self {
+        let requested_at = Utc::now();
+        Self {
+            id: ApprovalId::new(),
+            action,
+            requested_at,
+            expires_at: requested_at
+                + chrono::Duration::from_std(ttl).unwrap_or(chrono::Duration::MAX),
+        }
+    }
+
+    /// Whether the request has lapsed at `now`.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// How a pending approval ended.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ApprovalResolution {
+    /// A user approved the action
+    Approved {
+        by: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        note: Option<String>,
+    },
+
+    /// A user denied the action
+    Denied { by: String, reason: String },
+
+    /// Nobody answered before `expires_at`
+    Expired,
+}
+
+impl ApprovalResolution {
+    pub fn approved(by: impl Into<String>) -> Self {
+        Self::Approved {
+            by: by.into(),
+            note: None,
+        }
+    }
+
+    pub fn denied(by: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::Denied {
+            by: by.into(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn is_approved(&self) -> bool {
+        matches!(self, Self::Approved { .. })
+    }
+}
+
+/// A finished approval request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolvedApproval {
+    pub approval: PendingApproval,
+    pub resolution: ApprovalResolution,
+    pub resolved_at: DateTime<Utc>,
+}
+
+impl ResolvedApproval {
+    /// Final gate decision for the blocked action.
+    pub fn to_decision(&self) -> GateDecision {
+        let reason = match &self.resolution {
+            ApprovalResolution::Approved { by, .. } => format!("Approved by {}", by),
+            ApprovalResolution::Denied { by, reason } => format!("Denied by {}: {}", by, reason),
+            ApprovalResolution::Expired => "Approval request expired".to_string(),
+        };
+        GateDecision {
+            approved: self.resolution.is_approved(),
+            reason,
+            approval_id: Some(self.approval.id.to_string()),
+            conditions: vec![],
+        }
+    }
+}
+
+/// Where the gate parks actions awaiting approval.
+pub trait ApprovalStore {
+    /// Park an action. Returns its approval ID
+    fn submit(&self, pending: PendingApproval) -> SisterResult<ApprovalId>;
+
+    /// Approve or deny a pending action.
+    /// Fails with `InvalidState` if it already expired
+    fn resolve(
+        &self,
+        id: ApprovalId,
+        resolution: ApprovalResolution,
+    ) -> SisterResult<ResolvedApproval>;
+
+    /// Outcome of a request, once resolved (the gate polls this to resume)
+    fn resolution(&self, id: ApprovalId) -> SisterResult<Option<ResolvedApproval>>;
+
+    /// Requests still awaiting a decision
+    fn list_pending(&self) -> SisterResult<Vec<PendingApproval>>;
+}
+
+/// Notification hook so UIs can prompt the user and the gate can resume.
+pub trait ApprovalCallback: Send + Sync {
+    /// A new request needs a decision
+    fn on_pending(&self, pending: &PendingApproval);
+
+    /// A request was approved, denied, or expired
+    fn on_resolved(&self, resolved: &ResolvedApproval) {
+        let _ = resolved;
+    }
+}
+
+#[derive(Default)]
+struct ApprovalState {
+    pending: HashMap<ApprovalId, PendingApproval>,
+    resolved: HashMap<ApprovalId, ResolvedApproval>,
+}
+
+/// In-process `ApprovalStore` that notifies registered callbacks.
+///
+/// Expired requests are resolved as `Expired` lazily, whenever the
+/// store is accessed.
+#[derive(Default)]
+pub struct InMemoryApprovalStore {
+    state: std::sync::Mutex<ApprovalState>,
+    callbacks: Vec<Arc<dyn ApprovalCallback>>,
+}
+
+impl InMemoryApprovalStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a callback.
+    pub fn with_callback(mut self, callback: Arc<dyn ApprovalCallback>) -> Self {
+        self.callbacks.push(callback);
+        self
+    }
+
+    /// Resolve every lapsed request as `Expired`. Returns how many expired
+    pub fn expire_due(&self) -> usize {
+        let expired = {
+            let mut state = self.state.lock().unwrap();
+            Self::expire_locked(&mut state, Utc::now())
+        };
+        for resolved in &expired {
+            self.notify_resolved(resolved);
+        }
+        expired.len()
+    }
+
+    fn expire_locked(state: &mut ApprovalState, now: DateTime<Utc>) -> Vec<ResolvedApproval> {
+        let due: Vec<_> = state
+            .pending
+            .values()
+            .filter(|p| p.is_expired(now))
+            .map(|p| p.id)
+            .collect();
+        due.into_iter()
+            .filter_map(|id| state.pending.remove(&id))
+            .map(|approval| {
+                let resolved = ResolvedApproval {
+                    approval,
+                    resolution: ApprovalResolution::Expired,
+                    resolved_at: now,
+                };
+                state
+                    .resolved
+                    .insert(resolved.approval.id, resolved.clone());
+                resolved
+            })
+            .collect()
+    }
+
+    fn notify_resolved(&self, resolved: &ResolvedApproval) {
+        for callback in &self.callbacks {
+            callback.on_resolved(resolved);
+        }
+    }
+}
+
+impl ApprovalStore for InMemoryApprovalStore {
+    fn submit(&self, pending: PendingApproval) -> SisterResult<ApprovalId> {
+        let id = pending.id;
+        {
+            let mut state = self.state.lock().unwrap();
+            if state.pending.contains_key(&id) || state.resolved.contains_key(&id) {
+                return Err(SisterError::new(
+                    ErrorCode::AlreadyExists,
+                    format!("Approval {} already submitted", id),
+                ));
+            }
+            state.pending.insert(id, pending.clone());
+        }
+        for callback in &self.callbacks {
+            callback.on_pending(&pending);
+        }
+        Ok(id)
+    }
+
+    fn resolve(
+        &self,
+        id: ApprovalId,
+        resolution: ApprovalResolution,
+    ) -> SisterResult<ResolvedApproval> {
+        self.expire_due();
+        let resolved = {
+            let mut state = self.state.lock().unwrap();
+            let Some(approval) = state.pending.remove(&id) else {
+                return Err(match state.resolved.get(&id) {
+                    Some(done) => SisterError::new(
+                        ErrorCode::InvalidState,
+                        format!("Approval {} is already {:?}", id, done.resolution),
+                    ),
+                    None => SisterError::not_found(format!("Approval {}", id)),
+                });
+            };
+            let resolved = ResolvedApproval {
+                approval,
+                resolution,
+                resolved_at: Utc::now(),
+            };
+            state.resolved.insert(id, resolved.clone());
+            resolved
+        };
+        self.notify_resolved(&resolved);
+        Ok(resolved)
+    }
+
+    fn resolution(&self, id: ApprovalId) -> SisterResult<Option<ResolvedApproval>> {
+        self.expire_due();
+        Ok(self.state.lock().unwrap().resolved.get(&id).cloned())
+    }
+
+    fn list_pending(&self) -> SisterResult<Vec<PendingApproval>> {
+        self.expire_due();
+        let mut pending: Vec<_> = self
+            .state
+            .lock()
+            .unwrap()
+            .pending
+            .values()
+            .cloned()
+            .collect();
+        pending.sort_by_key(|p| p.requested_at);
+        Ok(pending)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// GATE AUDIT LOG — Every decision, queryable for compliance review
+// ═══════════════════════════════════════════════════════════════════
+
+/// Format identifier of gate audit exports.
+pub const GATE_AUDIT_FORMAT: &str = "agate-v1";
+
+/// File extension of gate audit exports.
+pub const GATE_AUDIT_EXTENSION: &str = "agate";
+
+/// Current `.agate` format version.
+pub const GATE_AUDIT_VERSION: Version = Version::new(1, 0, 0);
+
+/// How a gate check ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GateOutcome {
+    Approved,
+    Denied,
+    AwaitingApproval,
+}
+
+impl GateOutcome {
+    pub fn of(decision: &GateDecision) -> Self {
+        if decision.approved {
+            Self::Approved
+        } else if decision.is_awaiting_approval() {
+            Self::AwaitingApproval
+        } else {
+            Self::Denied
+        }
+    }
+}
+
+/// One gate check: the action, the decision, and when it was made.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateAuditEntry {
+    pub action: GatedAction,
+    pub decision: GateDecision,
+    pub decided_at: DateTime<Utc>,
+}
+
+impl GateAuditEntry {
+    pub fn outcome(&self) -> GateOutcome {
+        GateOutcome::of(&self.decision)
+    }
+}
+
+/// Query over a `GateAuditLog`. Unset fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct GateAuditFilter {
+    pub sister_type: Option<SisterType>,
+    pub capability: Option<String>,
+    /// Minimum risk level (inclusive)
+    pub min_risk: Option<RiskLevel>,
+    pub outcome: Option<GateOutcome>,
+    /// Decided at or after
+    pub since: Option<DateTime<Utc>>,
+    /// Decided before
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl GateAuditFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn for_sister(mut self, sister_type: SisterType) -> Self {
+        self.sister_type = Some(sister_type);
+        self
+    }
+
+    pub fn capability(mut self, capability: impl Into<String>) -> Self {
+        self.capability = Some(capability.into());
+        self
+    }
+
+    pub fn min_risk(mut self, level: RiskLevel) -> Self {
+        self.min_risk = Some(level);
+        self
+    }
+
+    pub fn outcome(mut self, outcome: GateOutcome) -> Self {
+        self.outcome = Some(outcome);
+        self
+    }
+
+    pub fn between(mut self, since: DateTime<Utc>, until: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self.until = Some(until);
+        self
+    }
+
+    pub fn matches(&self, entry: &GateAuditEntry) -> bool {
+        self.sister_type
+            .is_none_or(|t| entry.action.sister_type == t)
+            && self
+                .capability
+                .as_ref()
+                .is_none_or(|c| &entry.action.capability == c)
+            && self.min_risk.is_none_or(|r| entry.action.risk_level >= r)
+            && self.outcome.is_none_or(|o| entry.outcome() == o)
+            && self.since.is_none_or(|t| entry.decided_at >= t)
+            && self.until.is_none_or(|t| entry.decided_at < t)
+    }
+}
+
+/// First line of an `.agate` file (`JsonFormatDescriptor` convention).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GateAuditHeader {
+    #[serde(flatten)]
+    pub descriptor: JsonFormatDescriptor,
+
+    /// Number of entry lines following the header
+    pub entry_count: u64,
+}
+
+/// Append-only record of gate decisions.
+///
+/// Shareable across threads so a gate can `record` from `check(&self)`.
+/// Exports as JSONL (header line, then one entry per line) through
+/// `FileFormatWriter`; the file is owned by the Contract sister, which
+/// holds policy.
+#[derive(Debug, Default)]
+pub struct GateAuditLog {
+    entries: std::sync::Mutex<Vec<GateAuditEntry>>,
+}
+
+impl GateAuditLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a decision made now.
+    pub fn record(&self, action: GatedAction, decision: GateDecision) {
+        self.push(GateAuditEntry {
+            action,
+            decision,
+            decided_at: Utc::now(),
+        });
+    }
+
+    /// Append an existing entry (e.g. when merging logs).
+    pub fn push(&self, entry: GateAuditEntry) {
+        self.entries.lock().unwrap().push(entry);
+    }
+
+    /// Entries matching `filter`, oldest first.
+    pub fn query(&self, filter: &GateAuditFilter) -> Vec<GateAuditEntry> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|e| filter.matches(e))
+            .cloned()
+            .collect()
+    }
+
+    /// Decision counts per outcome for entries matching `filter`.
+    pub fn outcome_counts(&self, filter: &GateAuditFilter) -> HashMap<GateOutcome, usize> {
+        let mut counts = HashMap::new();
+        for entry in self
+            .entries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|e| filter.matches(e))
+        {
+            *counts.entry(entry.outcome()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Parse an `.agate` export.
+    pub fn from_bytes(data: &[u8]) -> SisterResult<Self> {
+        let text = std::str::from_utf8(data).map_err(|e| {
+            SisterError::invalid_input(format!("Gate audit log is not UTF-8: {}", e))
+        })?;
+        let mut lines = text.lines().filter(|l| !l.trim().is_empty());
+        let header: GateAuditHeader = serde_json::from_str(
+            lines
+                .next()
+                .ok_or_else(|| SisterError::invalid_input("Gate audit log is empty"))?,
+        )?;
+        Self::check_version(&header.descriptor.version)?;
+
+        let entries = lines
+            .map(|line| serde_json::from_str(line).map_err(SisterError::from))
+            .collect::<SisterResult<Vec<GateAuditEntry>>>()?;
+        if entries.len() as u64 != header.entry_count {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                format!(
+                    "Gate audit log declares {} entries but contains {}",
+                    header.entry_count,
+                    entries.len()
+                ),
+            ));
+        }
+        Ok(Self {
+            entries: std::sync::Mutex::new(entries),
+        })
+    }
+
+    fn read_header(path: &Path) -> SisterResult<GateAuditHeader> {
+        use std::io::BufRead;
+        let mut line = String::new();
+        std::io::BufReader::new(std::fs::File::open(path)?).read_line(&mut line)?;
+        Ok(serde_json::from_str(&line)?)
+    }
+
+    fn check_version(version: &Version) -> SisterResult<()> {
+        if VersionCompatibility::can_read(&GATE_AUDIT_VERSION, version) {
+            Ok(())
+        } else {
+            Err(SisterError::new(
+                ErrorCode::VersionMismatch,
+                format!(
+                    "Gate audit log version {} is newer than supported {}",
+                    version, GATE_AUDIT_VERSION
+                ),
+            ))
+        }
+    }
+}
+
+impl FileFormatWriter for GateAuditLog {
+    fn to_bytes(&self) -> SisterResult<Vec<u8>> {
+        let entries = self.entries.lock().unwrap();
+        let header = GateAuditHeader {
+            descriptor: JsonFormatDescriptor::new(
+                SisterType::Contract,
+                GATE_AUDIT_FORMAT,
+                GATE_AUDIT_VERSION,
+            ),
+            entry_count: entries.len() as u64,
+        };
+        let mut out = serde_json::to_vec(&header)?;
+        out.push(b'\n');
+        for entry in entries.iter() {
+            serde_json::to_writer(&mut out, entry)?;
+            out.push(b'\n');
+        }
+        Ok(out)
+    }
+}
+
+impl FileFormatReader for GateAuditLog {
+    fn read_file(path: &Path) -> SisterResult<Self> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
 
-    /// Error message (if failed)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<String>,
+    fn can_read(path: &Path) -> SisterResult<FileInfo> {
+        let header = Self::read_header(path)?;
+        header
+            .descriptor
+            .to_file_info(&std::fs::metadata(path)?, Some(&GATE_AUDIT_VERSION))
+    }
 
-    /// Evidence IDs produced by this command
-    #[serde(default)]
-    pub evidence_ids: Vec<String>,
+    fn file_version(path: &Path) -> SisterResult<Version> {
+        Ok(Self::read_header(path)?.descriptor.version)
+    }
+
+    /// Only one log version exists; same-major data passes through.
+    fn migrate(data: &[u8], from_version: Version) -> SisterResult<Vec<u8>> {
+        if VersionCompatibility::is_compatible(&GATE_AUDIT_VERSION, &from_version) {
+            Ok(data.to_vec())
+        } else {
+            Err(SisterError::new(
+                ErrorCode::NotImplemented,
+                format!(
+                    "No gate audit log migration from {} to {}",
+                    from_version, GATE_AUDIT_VERSION
+                ),
+            ))
+        }
+    }
 }
 
-/// The bridge between Hydra and individual sisters.
-///
-/// This is a PLACEHOLDER trait. Sisters should not implement it yet.
-/// It establishes the expected contract shape for when Hydra arrives.
+// ═══════════════════════════════════════════════════════════════════
+// ASYNC VARIANTS — For gates and bridges that do I/O (feature: async-traits)
+// ═══════════════════════════════════════════════════════════════════
+
+/// Async counterpart of `HydraBridge`.
 ///
-/// When Hydra is built, this trait will require:
-/// `Sister + SessionManagement/WorkspaceManagement + Grounding + EventEmitter + Queryable`
-pub trait HydraBridge {
-    /// Get a token-efficient summary of current sister state.
-    /// Hydra calls this to build its context window
-    fn session_context(&self) -> SisterResult<SessionContext>;
+/// Futures are `Send` so Hydra can drive sisters from a multi-threaded
+/// runtime. Wrap a sync bridge in `SyncAdapter` to get one for free.
+#[cfg(feature = "async-traits")]
+pub trait AsyncHydraBridge: Send {
+    /// Get a token-efficient summary of current sister state
+    fn session_context(
+        &self,
+    ) -> impl std::future::Future<Output = SisterResult<SessionContext>> + Send;
 
-    /// Restore sister state from a previous session context.
-    /// Used when Hydra resumes a run
-    fn restore_session(&mut self, context: SessionContext) -> SisterResult<()>;
+    /// Restore sister state from a previous session context
+    fn restore_session(
+        &mut self,
+        context: SessionContext,
+    ) -> impl std::future::Future<Output = SisterResult<()>> + Send;
 
     /// Get a brief summary for Hydra's context
-    fn summary(&self) -> SisterResult<SisterSummary>;
+    fn summary(&self) -> impl std::future::Future<Output = SisterResult<SisterSummary>> + Send;
 
-    /// Execute a command from Hydra.
-    /// This is the escape hatch for Hydra-specific operations
-    fn execute(&mut self, command: HydraCommand) -> SisterResult<CommandResult>;
+    /// Execute a command from Hydra
+    fn execute(
+        &mut self,
+        command: HydraCommand,
+    ) -> impl std::future::Future<Output = SisterResult<CommandResult>> + Send;
 }
 
-// ═══════════════════════════════════════════════════════════════════
-// EXECUTION GATE — Hydra's safety core (placeholder types)
-// ═══════════════════════════════════════════════════════════════════
+/// Async counterpart of `ExecutionGate`.
+///
+/// Only `check` is async: it may call out to an approval service.
+/// Capability, issuer and threshold lookups are expected to be local.
+#[cfg(feature = "async-traits")]
+pub trait AsyncExecutionGate: Send + Sync {
+    /// Submit an action for approval
+    fn check(
+        &self,
+        action: GatedAction,
+    ) -> impl std::future::Future<Output = SisterResult<GateDecision>> + Send;
 
-/// Risk level for an action
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum RiskLevel {
-    /// Low risk (0.0-0.3): auto-approve
-    Low,
+    /// Quick check if a capability is available
+    fn has_capability(&self, capability: &str) -> bool;
 
-    /// Medium risk (0.3-0.6): log and proceed
-    Medium,
+    /// Get current risk threshold
+    fn risk_threshold(&self) -> RiskLevel;
 
-    /// High risk (0.6-0.8): require confirmation
-    High,
+    /// Whether tokens from `issuer` are honoured. None are by default.
+    fn trusts_issuer(&self, _issuer: &str) -> bool {
+        false
+    }
 
-    /// Critical risk (0.8-1.0): block and escalate
-    Critical,
+    /// Validate the action's capability and token, as
+    /// `ExecutionGate::validate_token` does.
+    fn validate_token(&self, action: &GatedAction) -> SisterResult<()> {
+        validate_gated_token(
+            action,
+            |capability| self.has_capability(capability),
+            |issuer| self.trusts_issuer(issuer),
+        )
+    }
 }
 
-/// An action that needs to pass through the execution gate
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GatedAction {
-    /// What sister is requesting this action
-    pub sister_type: SisterType,
+/// Runs a sync `HydraBridge` or `ExecutionGate` behind the async traits.
+///
+/// Calls complete inline; nothing is moved to a blocking thread pool,
+/// so only wrap implementations that are cheap to call.
+#[cfg(feature = "async-traits")]
+#[derive(Debug, Clone, Default)]
+pub struct SyncAdapter<T>(pub T);
 
-    /// Action type
-    pub action_type: String,
+#[cfg(feature = "async-traits")]
+impl<T> SyncAdapter<T> {
+    pub fn new(inner: T) -> Self {
+        Self(inner)
+    }
 
-    /// Assessed risk level
-    pub risk_level: RiskLevel,
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
 
-    /// Risk score (0.0-1.0)
-    pub risk_score: f64,
+#[cfg(feature = "async-traits")]
+impl<B: HydraBridge + Send + Sync> AsyncHydraBridge for SyncAdapter<B> {
+    async fn session_context(&self) -> SisterResult<SessionContext> {
+        self.0.session_context()
+    }
 
-    /// Required capability
-    pub capability: String,
+    async fn restore_session(&mut self, context: SessionContext) -> SisterResult<()> {
+        self.0.restore_session(context)
+    }
 
-    /// When the action was requested
-    pub requested_at: DateTime<Utc>,
+    async fn summary(&self) -> SisterResult<SisterSummary> {
+        self.0.summary()
+    }
 
-    /// Action parameters
-    #[serde(default)]
-    pub params: Metadata,
+    async fn execute(&mut self, command: HydraCommand) -> SisterResult<CommandResult> {
+        self.0.execute(command)
+    }
 }
 
-/// Result of passing through the execution gate
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GateDecision {
-    /// Whether the action is approved
-    pub approved: bool,
+#[cfg(feature = "async-traits")]
+impl<G: ExecutionGate + Send + Sync> AsyncExecutionGate for SyncAdapter<G> {
+    async fn check(&self, action: GatedAction) -> SisterResult<GateDecision> {
+        self.0.check(action)
+    }
 
-    /// Reason for the decision
-    pub reason: String,
+    fn has_capability(&self, capability: &str) -> bool {
+        self.0.has_capability(capability)
+    }
 
-    /// Approval ID (for receipt chain)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub approval_id: Option<String>,
+    fn risk_threshold(&self) -> RiskLevel {
+        self.0.risk_threshold()
+    }
 
-    /// Conditions imposed on the action
-    #[serde(default)]
-    pub conditions: Vec<String>,
+    fn trusts_issuer(&self, issuer: &str) -> bool {
+        self.0.trusts_issuer(issuer)
+    }
+
+    fn validate_token(&self, action: &GatedAction) -> SisterResult<()> {
+        self.0.validate_token(action)
+    }
 }
 
-/// The Execution Gate trait (placeholder).
-///
-/// Hydra implements this, NOT sisters. Sisters submit actions
-/// to the gate; Hydra decides whether to approve.
-pub trait ExecutionGate {
-    /// Submit an action for approval
-    fn check(&self, action: GatedAction) -> SisterResult<GateDecision>;
+// Duration serialization as milliseconds
+mod duration_millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
 
-    /// Quick check if a capability is available
-    fn has_capability(&self, capability: &str) -> bool;
+    pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(duration.as_millis() as u64)
+    }
 
-    /// Get current risk threshold
-    fn risk_threshold(&self) -> RiskLevel;
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Duration::from_millis(u64::deserialize(deserializer)?))
+    }
+}
+
+mod opt_duration_millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match duration {
+            Some(d) => serializer.serialize_some(&(d.as_millis() as u64)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<u64>::deserialize(deserializer)?.map(Duration::from_millis))
+    }
 }
 
 #[cfg(test)]
@@ -229,6 +3445,7 @@ mod tests {
             data: serde_json::json!({"added": 5}),
             error: None,
             evidence_ids: vec!["ev_1".into()],
+            usage: None,
         };
 
         assert!(result.success);
@@ -246,4 +3463,798 @@ mod tests {
 
         assert!(decision.approved);
     }
+
+    fn gated(action_type: &str) -> GatedAction {
+        GatedAction {
+            sister_type: SisterType::Codebase,
+            action_type: action_type.into(),
+            risk_level: RiskLevel::High,
+            risk_score: 0.7,
+            capability: "codebase_write".into(),
+            requested_at: Utc::now(),
+            params: Metadata::new(),
+            token: None,
+            dry_run: false,
+        }
+    }
+
+    #[derive(Default)]
+    struct Recorder(std::sync::Mutex<Vec<String>>);
+
+    impl ApprovalCallback for Recorder {
+        fn on_pending(&self, pending: &PendingApproval) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("pending {}", pending.action.action_type));
+        }
+
+        fn on_resolved(&self, resolved: &ResolvedApproval) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("resolved {}", resolved.to_decision().approved));
+        }
+    }
+
+    #[test]
+    fn test_approval_workflow() {
+        let recorder = Arc::new(Recorder::default());
+        let store = InMemoryApprovalStore::new().with_callback(recorder.clone());
+
+        let pending =
+            PendingApproval::new(gated("delete_branch"), std::time::Duration::from_secs(60));
+        let decision = GateDecision::awaiting_approval(&pending);
+        assert!(decision.is_awaiting_approval());
+
+        let id = store.submit(pending).unwrap();
+        assert_eq!(store.list_pending().unwrap().len(), 1);
+        assert!(store.resolution(id).unwrap().is_none());
+
+        let resolved = store
+            .resolve(id, ApprovalResolution::approved("alice"))
+            .unwrap();
+        assert!(resolved.to_decision().approved);
+        assert_eq!(resolved.to_decision().approval_id, decision.approval_id);
+        assert!(store.list_pending().unwrap().is_empty());
+        assert!(store
+            .resolve(id, ApprovalResolution::approved("bob"))
+            .is_err());
+
+        // Zero TTL lapses immediately
+        let lapsed = store
+            .submit(PendingApproval::new(
+                gated("force_push"),
+                std::time::Duration::ZERO,
+            ))
+            .unwrap();
+        let expired = store.resolution(lapsed).unwrap().unwrap();
+        assert_eq!(expired.resolution, ApprovalResolution::Expired);
+        assert!(!expired.to_decision().approved);
+
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            vec![
+                "pending delete_branch",
+                "resolved true",
+                "pending force_push",
+                "resolved false"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_command_router() {
+        let router = CommandRouter::<Vec<String>>::new().route(
+            CommandSchema::new("remember", "Store a note")
+                .required("text", ParamKind::String)
+                .optional("priority", ParamKind::Integer),
+            |notes, command| {
+                notes.push(command.params["text"].as_str().unwrap().to_string());
+                Ok(CommandResult {
+                    success: true,
+                    data: serde_json::json!({"count": notes.len()}),
+                    error: None,
+                    evidence_ids: vec![],
+                    usage: None,
+                })
+            },
+        );
+        let command = |command_type: &str, params: serde_json::Value| HydraCommand {
+            command_type: command_type.into(),
+            params: serde_json::from_value(params).unwrap(),
+            run_id: "run_1".into(),
+            step_id: 0,
+            budget: None,
+            dry_run: false,
+            idempotency_key: None,
+            priority: Priority::Normal,
+            deadline: None,
+            exclusive: false,
+            accept: vec![],
+        };
+        let mut notes = vec![];
+
+        let result = router
+            .dispatch(
+                &mut notes,
+                command("remember", serde_json::json!({"text": "hi"})),
+            )
+            .unwrap();
+        assert_eq!(result.data["count"], 1);
+
+        let err = router
+            .dispatch(
+                &mut notes,
+                command("remember", serde_json::json!({"priority": "high"})),
+            )
+            .unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidInput);
+        assert_eq!(
+            ProtocolError::from(err).code,
+            crate::errors::ProtocolErrorCode::InvalidParams
+        );
+
+        let result = router
+            .dispatch(&mut notes, command("forget", serde_json::json!({})))
+            .unwrap();
+        assert!(!result.success);
+        assert_eq!(result.data["code"], -32803);
+        assert_eq!(router.schemas().len(), 1);
+    }
+
+    struct Counter(i64);
+
+    impl HydraBridge for Counter {
+        fn session_context(&self) -> SisterResult<SessionContext> {
+            Err(SisterError::new(ErrorCode::NotImplemented, "counter"))
+        }
+
+        fn restore_session(&mut self, _context: SessionContext) -> SisterResult<()> {
+            Ok(())
+        }
+
+        fn summary(&self) -> SisterResult<SisterSummary> {
+            Err(SisterError::new(ErrorCode::NotImplemented, "counter"))
+        }
+
+        fn execute(&mut self, command: HydraCommand) -> SisterResult<CommandResult> {
+            match command.command_type.as_str() {
+                "inc" => self.0 += 1,
+                "dec" => self.0 -= 1,
+                _ => return Err(SisterError::invalid_input("boom")),
+            }
+            Ok(CommandResult {
+                success: true,
+                data: serde_json::json!(self.0),
+                error: None,
+                evidence_ids: vec![],
+                usage: None,
+            })
+        }
+    }
+
+    #[derive(Default)]
+    struct Ledger(std::sync::Mutex<Vec<crate::receipts::Receipt>>);
+
+    impl ReceiptIntegration for Ledger {
+        fn create_receipt(&self, action: ActionRecord) -> SisterResult<ReceiptId> {
+            let mut receipts = self.0.lock().unwrap();
+            let receipt = crate::receipts::Receipt {
+                id: ReceiptId::new(),
+                action,
+                signature: String::new(),
+                chain_position: receipts.len() as u64,
+                previous_hash: String::new(),
+                hash: String::new(),
+                created_at: Utc::now(),
+                anchor: None,
+            };
+            let id = receipt.id;
+            receipts.push(receipt);
+            Ok(id)
+        }
+
+        fn get_receipt(&self, id: ReceiptId) -> SisterResult<crate::receipts::Receipt> {
+            self.0
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|r| r.id == id)
+                .cloned()
+                .ok_or_else(|| SisterError::not_found(id.to_string()))
+        }
+
+        fn list_receipts(
+            &self,
+            filter: crate::receipts::ReceiptFilter,
+        ) -> SisterResult<Vec<crate::receipts::Receipt>> {
+            Ok(filter.apply(self.0.lock().unwrap().iter().cloned()))
+        }
+    }
+
+    #[test]
+    fn test_saga_compensates_on_failure() {
+        let command = |command_type: &str, step_id: u64| HydraCommand {
+            command_type: command_type.into(),
+            params: Metadata::new(),
+            run_id: "run_1".into(),
+            step_id,
+            budget: None,
+            dry_run: false,
+            idempotency_key: None,
+            priority: Priority::Normal,
+            deadline: None,
+            exclusive: false,
+            accept: vec![],
+        };
+        let (mut memory, mut codebase) = (Counter(0), Counter(0));
+        let ledger = Ledger::default();
+
+        let result = SagaExecutor::new()
+            .sister(SisterType::Memory, &mut memory)
+            .sister(SisterType::Codebase, &mut codebase)
+            .receipts(&ledger)
+            .step(
+                SagaStep::new(SisterType::Memory, command("inc", 1))
+                    .compensate_with(command("dec", 1)),
+            )
+            .step(
+                SagaStep::new(SisterType::Codebase, command("inc", 2))
+                    .compensate_with(command("dec", 2)),
+            )
+            .step(SagaStep::new(SisterType::Memory, command("explode", 3)))
+            .run()
+            .unwrap();
+
+        assert!(!result.success);
+        assert_eq!(result.data["failed_step"], 2);
+        let statuses: Vec<_> = result.data["steps"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| s["status"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(statuses, vec!["compensated", "compensated", "failed"]);
+        assert_eq!((memory.0, codebase.0), (0, 0));
+
+        // 3 forward receipts + 2 compensations, all tagged with the run
+        let receipts = ledger.0.lock().unwrap();
+        assert_eq!(receipts.len(), 5);
+        assert!(receipts[2].action.outcome.is_failure());
+        assert_eq!(receipts[3].action.parameters["compensates"], "inc");
+        assert!(receipts
+            .iter()
+            .all(|r| r.action.run_id.as_deref() == Some("run_1")));
+    }
+
+    #[test]
+    fn test_saga_rejects_unregistered_sister() {
+        let mut memory = Counter(0);
+        let mut saga = SagaExecutor::new()
+            .sister(SisterType::Memory, &mut memory)
+            .step(SagaStep::new(
+                SisterType::Vision,
+                HydraCommand {
+                    command_type: "inc".into(),
+                    params: Metadata::new(),
+                    run_id: "run_1".into(),
+                    step_id: 1,
+                    budget: None,
+                    dry_run: false,
+                    idempotency_key: None,
+                    priority: Priority::Normal,
+                    deadline: None,
+                    exclusive: false,
+                    accept: vec![],
+                },
+            ));
+        assert!(saga.run().is_err());
+    }
+
+    #[test]
+    fn test_run_lifecycle() {
+        let mut run = RunRecord::start("fix auth bug");
+        let step_id = run.start_step(SisterType::Memory, "memory_add").step_id;
+        run.start_step(SisterType::Codebase, "impact_analysis");
+        run.complete_step(step_id, ActionOutcome::success())
+            .unwrap();
+        assert!(run
+            .complete_step(StepId(9), ActionOutcome::success())
+            .is_err());
+
+        run.finish(RunStatus::Failed);
+        assert_eq!(run.step(step_id).unwrap().status, RunStatus::Succeeded);
+        assert_eq!(run.step(StepId(2)).unwrap().status, RunStatus::Cancelled);
+        assert!(run.status.is_terminal());
+
+        let command = run.steps[0].command(Metadata::new());
+        assert_eq!(command.run_id, run.run_id.as_str());
+        assert_eq!(command.step_id, 1);
+
+        let json = serde_json::to_value(&run).unwrap();
+        assert_eq!(json["status"], "failed");
+        assert_eq!(json["steps"][0]["step_id"], 1);
+    }
+
+    #[test]
+    fn test_budget_tracker() {
+        let budget = Budget::new()
+            .max_tokens(1_000)
+            .max_duration(Duration::from_secs(10));
+        let mut tracker = BudgetTracker::new(budget);
+
+        let step = Usage {
+            tokens: 400,
+            cost_usd: 0.01,
+            duration: Duration::from_secs(2),
+        };
+        tracker.record(&step).unwrap();
+        tracker.record(&step).unwrap();
+        assert_eq!(tracker.remaining().max_tokens, Some(200));
+        assert_eq!(tracker.remaining().max_cost_usd, None);
+        assert!(!tracker.is_exhausted());
+
+        let err = tracker.record(&step).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ResourceExhausted);
+        assert_eq!(err.context_str("resource"), Some("tokens"));
+        assert_eq!(tracker.used().tokens, 1_200);
+        assert!(tracker.is_exhausted());
+
+        let json = serde_json::to_value(budget).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"max_tokens": 1000, "max_duration": 10000})
+        );
+    }
+
+    struct ThresholdGate;
+
+    impl ExecutionGate for ThresholdGate {
+        fn check(&self, action: GatedAction) -> SisterResult<GateDecision> {
+            if action.capability.is_empty() {
+                return Err(SisterError::invalid_input("capability required"));
+            }
+            Ok(GateDecision {
+                approved: action.risk_level < self.risk_threshold(),
+                reason: format!("risk {:?}", action.risk_level),
+                approval_id: Some("approval_1".into()),
+                conditions: vec![],
+            })
+        }
+
+        fn has_capability(&self, capability: &str) -> bool {
+            !capability.starts_with("admin")
+        }
+
+        fn risk_threshold(&self) -> RiskLevel {
+            RiskLevel::High
+        }
+
+        fn trusts_issuer(&self, issuer: &str) -> bool {
+            issuer == "user"
+        }
+    }
+
+    #[test]
+    fn test_receipted_gate() {
+        let gate = ReceiptedGate::new(ThresholdGate, Ledger::default());
+
+        let (decision, receipt_id) = gate.check_with_receipt(gated("delete_branch")).unwrap();
+        assert!(!decision.approved);
+        let receipt = gate.receipts().get_receipt(receipt_id.unwrap()).unwrap();
+        assert_eq!(receipt.action.action_type, GATE_DECISION_ACTION);
+        assert_eq!(receipt.action.sister_type, SisterType::Codebase);
+        assert_eq!(receipt.action.parameters["action_type"], "delete_branch");
+        assert_eq!(receipt.action.parameters["risk_level"], "high");
+        assert_eq!(receipt.action.parameters["approval_id"], "approval_1");
+        assert_eq!(receipt.action.parameters["approved"], false);
+
+        let mut action = gated("read_file");
+        action.capability.clear();
+        assert!(gate.check(action).is_err());
+        assert_eq!(gate.receipts().receipt_count().unwrap(), 2);
+
+        // Dry runs are decided as usual but leave no receipt
+        let mut preview = gated("delete_branch");
+        preview.dry_run = true;
+        let (decision, receipt_id) = gate.check_with_receipt(preview.clone()).unwrap();
+        assert!(!decision.approved);
+        assert!(receipt_id.is_none());
+        preview.capability.clear();
+        assert!(gate.check(preview).is_err());
+        assert_eq!(gate.receipts().receipt_count().unwrap(), 2);
+
+        let trusted = gated("write_file").with_token(CapabilityToken::new("*", "user"));
+        assert!(gate.validate_token(&trusted).is_ok());
+        let forged = gated("write_file").with_token(CapabilityToken::new("*", "me"));
+        assert!(gate.validate_token(&forged).is_err());
+    }
+
+    #[test]
+    fn test_capability_token_validation() {
+        let token = CapabilityToken::new("codebase_*", "user")
+            .max_risk(RiskLevel::High)
+            .for_sister(SisterType::Codebase)
+            .in_context("ws_1");
+        let mut action = gated("write_file").with_token(token.clone());
+        action
+            .params
+            .insert(TOKEN_CONTEXT_PARAM.into(), "ws_1".into());
+        assert!(ThresholdGate.validate_token(&action).is_ok());
+
+        let reason = |action: &GatedAction| {
+            let err = ThresholdGate.validate_token(action).unwrap_err();
+            assert_eq!(err.code, ErrorCode::PermissionDenied);
+            err.context.unwrap()["reason"].clone()
+        };
+
+        let mut risky = action.clone();
+        risky.risk_level = RiskLevel::Critical;
+        assert_eq!(reason(&risky), "max_risk");
+
+        let mut elsewhere = action.clone();
+        elsewhere
+            .params
+            .insert(TOKEN_CONTEXT_PARAM.into(), "ws_2".into());
+        assert_eq!(reason(&elsewhere), "context");
+
+        let mut expired = token.clone();
+        expired.expires_at = Some(Utc::now() - chrono::Duration::seconds(1));
+        assert_eq!(reason(&action.clone().with_token(expired)), "expired");
+
+        let other = CapabilityToken::new("memory_read", "user");
+        assert_eq!(reason(&action.clone().with_token(other)), "capability");
+
+        let forged = CapabilityToken::new("*", "me");
+        assert_eq!(reason(&action.clone().with_token(forged)), "issuer");
+
+        let mut admin = action.with_token(CapabilityToken::new("*", "user"));
+        admin.capability = "admin_purge".into();
+        let err = ThresholdGate.validate_token(&admin).unwrap_err();
+        assert_eq!(err.code, ErrorCode::PermissionDenied);
+    }
+
+    #[cfg(feature = "async-traits")]
+    #[tokio::test]
+    async fn test_sync_adapter() {
+        let gate = SyncAdapter::new(ThresholdGate);
+        assert_eq!(AsyncExecutionGate::risk_threshold(&gate), RiskLevel::High);
+        let decision = AsyncExecutionGate::check(&gate, gated("write_file")).await;
+        assert!(!decision.unwrap().approved);
+        let trusted = gated("write_file").with_token(CapabilityToken::new("*", "user"));
+        assert!(AsyncExecutionGate::validate_token(&gate, &trusted).is_ok());
+        let forged = gated("write_file").with_token(CapabilityToken::new("*", "me"));
+        assert!(AsyncExecutionGate::validate_token(&gate, &forged).is_err());
+
+        let mut bridge = SyncAdapter::new(Counter(0));
+        let command = HydraCommand {
+            command_type: "inc".into(),
+            params: Metadata::new(),
+            run_id: "run_1".into(),
+            step_id: 1,
+            budget: None,
+            dry_run: false,
+            idempotency_key: None,
+            priority: Priority::Normal,
+            deadline: None,
+            exclusive: false,
+            accept: vec![],
+        };
+        let result = bridge.execute(command).await.unwrap();
+        assert!(result.success);
+        assert_eq!(bridge.into_inner().0, 1);
+    }
+
+    #[test]
+    fn test_heartbeat_monitor() {
+        let events = Arc::new(EventManager::new(16));
+        let monitor = HeartbeatMonitor::new(Duration::from_secs(30)).with_events(events.clone());
+        assert!(monitor.record(&Heartbeat::new(SisterType::Memory, Status::Ready, 1)));
+        assert!(monitor.record(&Heartbeat::new(SisterType::Vision, Status::Busy, 1)));
+        assert!(!monitor.record(&Heartbeat::new(SisterType::Memory, Status::Ready, 1)));
+        assert!(monitor.check().is_empty());
+
+        let later = Utc::now() + chrono::Duration::seconds(31);
+        let flagged = monitor.check_at(later);
+        assert_eq!(flagged.len(), 2);
+        assert!(matches!(
+            &flagged[0].event_type,
+            crate::events::EventType::Custom { name, .. } if name == SISTER_UNRESPONSIVE_EVENT
+        ));
+        assert!(monitor.check_at(later).is_empty());
+        assert_eq!(events.recent(10).len(), 2);
+
+        monitor.record(&Heartbeat::new(SisterType::Memory, Status::Ready, 2));
+        assert!(monitor.is_alive(SisterType::Memory));
+        assert!(!monitor.is_alive(SisterType::Vision));
+        assert_eq!(monitor.fleet().len(), 2);
+    }
+
+    struct Reindexer;
+
+    impl HydraBridge for Reindexer {
+        fn session_context(&self) -> SisterResult<SessionContext> {
+            Err(SisterError::new(ErrorCode::NotImplemented, "reindexer"))
+        }
+
+        fn restore_session(&mut self, _context: SessionContext) -> SisterResult<()> {
+            Ok(())
+        }
+
+        fn summary(&self) -> SisterResult<SisterSummary> {
+            Err(SisterError::new(ErrorCode::NotImplemented, "reindexer"))
+        }
+
+        fn execute(&mut self, command: HydraCommand) -> SisterResult<CommandResult> {
+            self.execute_streaming(command, &mut |_| ProgressControl::Continue)
+        }
+
+        fn execute_streaming(
+            &mut self,
+            _command: HydraCommand,
+            on_progress: &mut ProgressCallback<'_>,
+        ) -> SisterResult<CommandResult> {
+            for done in 1..=4 {
+                let progress = CommandProgress::new(done as f64 * 25.0)
+                    .partial_data(serde_json::json!({ "files": done * 100 }));
+                if on_progress(progress) == ProgressControl::Cancel {
+                    return Ok(CommandResult::cancelled(Some(serde_json::json!({
+                        "files": done * 100
+                    }))));
+                }
+            }
+            Ok(CommandResult {
+                success: true,
+                data: serde_json::json!({ "files": 400 }),
+                error: None,
+                evidence_ids: vec![],
+                usage: None,
+            })
+        }
+    }
+
+    #[test]
+    fn test_execute_streaming() {
+        let command = |command_type: &str| HydraCommand {
+            command_type: command_type.into(),
+            params: Metadata::new(),
+            run_id: "run_1".into(),
+            step_id: 1,
+            budget: None,
+            dry_run: false,
+            idempotency_key: None,
+            priority: Priority::Normal,
+            deadline: None,
+            exclusive: false,
+            accept: vec![],
+        };
+
+        let mut seen = vec![];
+        let result = Reindexer
+            .execute_streaming(command("reindex"), &mut |p| {
+                seen.push(p.percent);
+                if p.percent >= 50.0 {
+                    ProgressControl::Cancel
+                } else {
+                    ProgressControl::Continue
+                }
+            })
+            .unwrap();
+        assert!(result.is_cancelled());
+        assert_eq!(result.data["files"], 200);
+        assert_eq!(seen, vec![25.0, 50.0]);
+
+        let mut updates = vec![];
+        let result = Counter(0)
+            .execute_streaming(command("inc"), &mut |p| {
+                updates.push(p);
+                ProgressControl::Continue
+            })
+            .unwrap();
+        assert!(result.success);
+        assert!(updates.len() == 1 && updates[0].is_complete());
+    }
+
+    #[test]
+    fn test_dry_run_report() {
+        let command = HydraCommand {
+            command_type: "memory_delete".into(),
+            params: Metadata::new(),
+            run_id: "run_1".into(),
+            step_id: 1,
+            budget: None,
+            dry_run: false,
+            idempotency_key: None,
+            priority: Priority::Normal,
+            deadline: None,
+            exclusive: false,
+            accept: vec![],
+        };
+        assert!(serde_json::to_value(&command)
+            .unwrap()
+            .get("dry_run")
+            .is_none());
+        let command = command.preview();
+        assert_eq!(serde_json::to_value(&command).unwrap()["dry_run"], true);
+
+        let report = DryRunReport::new()
+            .change(PlannedChange::new("delete", "node_42"))
+            .risk_level(RiskLevel::Medium);
+        let result = CommandResult::dry_run(report.clone());
+        assert!(result.success);
+        assert_eq!(result.dry_run_report(), Some(report));
+
+        let mut plain = result;
+        plain.data = serde_json::json!({ "files": 400 });
+        assert_eq!(plain.dry_run_report(), None);
+    }
+
+    #[test]
+    fn test_idempotent_bridge() {
+        let mut bridge = IdempotentBridge::new(Counter(0), InMemoryIdempotencyStore::new());
+        let command = |key: Option<&str>| HydraCommand {
+            command_type: "inc".into(),
+            params: Metadata::new(),
+            run_id: "run_1".into(),
+            step_id: 1,
+            budget: None,
+            dry_run: false,
+            idempotency_key: key.map(String::from),
+            priority: Priority::Normal,
+            deadline: None,
+            exclusive: false,
+            accept: vec![],
+        };
+
+        let first = bridge.execute(command(Some("k1"))).unwrap();
+        let retry = bridge.execute(command(Some("k1"))).unwrap();
+        assert_eq!(first.data, retry.data);
+        assert_eq!(bridge.bridge().0, 1);
+
+        bridge.execute(command(None)).unwrap();
+        bridge.execute(command(Some("k2"))).unwrap();
+        assert_eq!(bridge.bridge().0, 3);
+        assert_eq!(bridge.store().len(), 2);
+
+        let mut bridge = bridge.ttl(Duration::ZERO);
+        bridge.execute(command(Some("k3"))).unwrap();
+        bridge.execute(command(Some("k3"))).unwrap();
+        assert_eq!(bridge.into_inner().0, 5);
+    }
+
+    #[test]
+    fn test_gate_audit_log() {
+        let log = GateAuditLog::new();
+        let decide = |approved: bool| GateDecision {
+            approved,
+            reason: "policy".into(),
+            approval_id: None,
+            conditions: vec![],
+        };
+        log.record(gated("write_file"), decide(true));
+        let mut low = gated("read_file");
+        low.risk_level = RiskLevel::Low;
+        low.sister_type = SisterType::Memory;
+        log.record(low, decide(false));
+        let pending = PendingApproval::new(gated("delete_branch"), Duration::from_secs(60));
+        log.record(
+            pending.action.clone(),
+            GateDecision::awaiting_approval(&pending),
+        );
+
+        let codebase = GateAuditFilter::new().for_sister(SisterType::Codebase);
+        assert_eq!(log.query(&codebase).len(), 2);
+        let risky_denials = GateAuditFilter::new()
+            .min_risk(RiskLevel::High)
+            .outcome(GateOutcome::Denied);
+        assert!(log.query(&risky_denials).is_empty());
+        let counts = log.outcome_counts(&GateAuditFilter::new());
+        assert_eq!(counts[&GateOutcome::AwaitingApproval], 1);
+
+        let dir = crate::test_support::TempDir::new("gate_audit");
+        let path = dir.join("audit.agate");
+        log.write_file(&path).unwrap();
+        assert_eq!(
+            GateAuditLog::can_read(&path).unwrap().format_id,
+            GATE_AUDIT_FORMAT
+        );
+        let restored = GateAuditLog::read_file(&path).unwrap();
+        assert_eq!(restored.len(), 3);
+        assert_eq!(
+            restored.query(&codebase)[1].outcome(),
+            GateOutcome::AwaitingApproval
+        );
+    }
+
+    #[test]
+    fn test_command_queue_ordering() {
+        let command = |command_type: &str| HydraCommand {
+            command_type: command_type.into(),
+            params: Metadata::new(),
+            run_id: "run_1".into(),
+            step_id: 1,
+            budget: None,
+            dry_run: false,
+            idempotency_key: None,
+            priority: Priority::Normal,
+            deadline: None,
+            exclusive: false,
+            accept: vec![],
+        };
+        let soon = Utc::now() + chrono::Duration::seconds(5);
+
+        let mut queue = CommandQueue::new();
+        queue.push(command("reindex").with_priority(Priority::Low).exclusive());
+        queue.push(command("query"));
+        queue.push(command("ground").with_priority(Priority::High));
+        queue.push(command("summarize").with_deadline(soon));
+        queue.push(command("stale").with_deadline(Utc::now() - chrono::Duration::seconds(1)));
+        assert_eq!(queue.drain_expired().len(), 1);
+
+        let ground = queue.pop().unwrap();
+        assert_eq!(ground.command_type, "ground");
+        let summarize = queue.pop().unwrap();
+        assert_eq!(summarize.command_type, "summarize");
+        let query = queue.pop().unwrap();
+        assert_eq!(query.command_type, "query");
+
+        // Exclusive reindex waits for in-flight commands
+        queue.finish(&ground);
+        assert!(queue.pop().is_none());
+        queue.finish(&query);
+        queue.finish(&summarize);
+        assert_eq!(queue.in_flight(), 0);
+        let reindex = queue.pop().unwrap();
+        queue.push(command("query"));
+        assert!(queue.pop().is_none());
+        queue.finish(&reindex);
+        assert_eq!(queue.pop().unwrap().command_type, "query");
+    }
+
+    #[test]
+    fn test_sister_pool_routing() {
+        let mut pool = SisterPool::new(SisterType::Codebase);
+        pool.insert("repo_a", Counter(0));
+        pool.insert("repo_b", Counter(10));
+        let command = |key: Option<&str>| {
+            let mut params = Metadata::new();
+            if let Some(key) = key {
+                params.insert(POOL_KEY_PARAM.into(), key.into());
+            }
+            HydraCommand {
+                command_type: "inc".into(),
+                params,
+                run_id: "run_1".into(),
+                step_id: 1,
+                budget: None,
+                dry_run: false,
+                idempotency_key: None,
+                priority: Priority::Normal,
+                deadline: None,
+                exclusive: false,
+                accept: vec![],
+            }
+        };
+
+        pool.execute(command(Some("repo_b"))).unwrap();
+        pool.execute(command(None)).unwrap();
+        assert_eq!(pool.get("repo_a").unwrap().0, 1);
+        assert_eq!(pool.get("repo_b").unwrap().0, 11);
+        assert_eq!(
+            pool.execute(command(Some("repo_c"))).unwrap_err().code,
+            ErrorCode::NotFound
+        );
+
+        pool.mark_unavailable("repo_b");
+        assert_eq!(
+            pool.execute(command(Some("repo_b"))).unwrap_err().code,
+            ErrorCode::InvalidState
+        );
+        pool.set_default("repo_b").unwrap();
+        assert!(pool.execute(command(None)).is_err());
+        pool.mark_available("repo_b");
+        pool.execute(command(None)).unwrap();
+        assert_eq!(pool.get("repo_b").unwrap().0, 12);
+    }
 }
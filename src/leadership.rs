@@ -0,0 +1,299 @@
+//! Single-writer coordination for sisters sharing storage.
+//!
+//! When several processes open the same `.amem` or `.acb` file, exactly
+//! one of them may write. Leadership is a lease: the holder must `renew`
+//! before it expires, and anyone may take over an expired lease.
+//!
+//! ```rust,ignore
+//! let coord = FileLeaseCoordinator::new(config.data_path.unwrap(), node_id);
+//! match coord.acquire_leadership()? {
+//!     Some(lease) => run_as_writer(&coord, lease)?,
+//!     None => run_as_reader(coord.leader()?),
+//! }
+//! ```
+//!
+//! Every change of leader increments `term`. Writers should stamp writes
+//! with their term so a deposed leader's late writes can be told apart
+//! from the current leader's.
+
+use crate::errors::{ErrorCode, SisterError, SisterResult};
+use crate::file_format::{atomic_write, FileLock, LockInfo, LockMode};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Default lease length; renew at roughly a third of this.
+pub const DEFAULT_LEASE_TTL: Duration = Duration::from_secs(15);
+
+const LEADER_FILE: &str = "leader";
+
+/// A leadership lease.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lease {
+    /// Who holds it (node or process name)
+    pub holder: String,
+
+    /// Incremented on every change of leader
+    pub term: u64,
+
+    pub acquired_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Lease {
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(Utc::now())
+    }
+
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Time left before expiry (zero once expired).
+    pub fn remaining(&self) -> Duration {
+        (self.expires_at - Utc::now()).to_std().unwrap_or_default()
+    }
+}
+
+/// Leader election between processes sharing storage.
+pub trait Coordination: Send + Sync {
+    /// Become leader if nobody holds a live lease (or we already do).
+    ///
+    /// Returns `None` when another holder's lease is still live.
+    fn acquire_leadership(&self) -> SisterResult<Option<Lease>>;
+
+    /// Extend a lease we hold.
+    ///
+    /// Fails with `InvalidState` if leadership was lost (lease expired,
+    /// taken over, or resigned).
+    fn renew(&self, lease: &Lease) -> SisterResult<Lease>;
+
+    /// Give up leadership. No-op if the lease is no longer ours.
+    fn resign(&self, lease: &Lease) -> SisterResult<()>;
+
+    /// The current live lease, if any.
+    fn leader(&self) -> SisterResult<Option<Lease>>;
+
+    /// Whether `lease` is still the live leadership lease.
+    fn is_leader(&self, lease: &Lease) -> SisterResult<bool> {
+        Ok(self
+            .leader()?
+            .is_some_and(|current| current.holder == lease.holder && current.term == lease.term))
+    }
+}
+
+/// Who holds the exclusive lock, and in which term.
+///
+/// Stored as `<file>.lock/leader` next to the lock files; it outlives the
+/// lock so the next leader can continue the term sequence.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LeaderRecord {
+    holder: String,
+    lock_id: String,
+    term: u64,
+}
+
+/// Reference `Coordination` built on an exclusive `FileLock`.
+///
+/// The leader holds the data file's exclusive lock and renews by
+/// heartbeating it; a lock silent for longer than the TTL is stale and
+/// the next `acquire_leadership` takes it over.
+#[derive(Debug)]
+pub struct FileLeaseCoordinator {
+    target: PathBuf,
+    holder: String,
+    ttl: Duration,
+    lock: Mutex<Option<FileLock>>,
+}
+
+impl FileLeaseCoordinator {
+    /// Coordinate writers of `target` (the shared data file).
+    pub fn new(target: impl Into<PathBuf>, holder: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            holder: holder.into(),
+            ttl: DEFAULT_LEASE_TTL,
+            lock: Mutex::new(None),
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn target(&self) -> &Path {
+        &self.target
+    }
+
+    pub fn holder(&self) -> &str {
+        &self.holder
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    fn record_path(&self) -> PathBuf {
+        FileLock::lock_dir_for(&self.target).join(LEADER_FILE)
+    }
+
+    fn read_record(&self) -> Option<LeaderRecord> {
+        std::fs::read(self.record_path())
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+    }
+
+    fn lease_for(&self, record: &LeaderRecord, info: &LockInfo) -> Lease {
+        Lease {
+            holder: record.holder.clone(),
+            term: record.term,
+            acquired_at: info.acquired_at,
+            expires_at: info.heartbeat_at
+                + chrono::Duration::from_std(self.ttl).unwrap_or(chrono::Duration::MAX),
+        }
+    }
+
+    fn lost(lease: &Lease) -> SisterError {
+        SisterError::new(ErrorCode::InvalidState, "leadership lost")
+            .with_context("holder", &lease.holder)
+            .with_context("term", lease.term)
+    }
+}
+
+impl Coordination for FileLeaseCoordinator {
+    fn acquire_leadership(&self) -> SisterResult<Option<Lease>> {
+        let mut held = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(lock) = held.as_mut() {
+            if let Some(record) = self
+                .read_record()
+                .filter(|r| r.lock_id == lock.info().holder_id)
+            {
+                match lock.heartbeat() {
+                    Ok(()) => return Ok(Some(self.lease_for(&record, lock.info()))),
+                    // Taken over as stale; try again from scratch
+                    Err(e) if e.code == ErrorCode::InvalidState => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            *held = None;
+        }
+
+        let lock = match FileLock::acquire_with(&self.target, LockMode::Exclusive, self.ttl) {
+            Ok(lock) => lock,
+            Err(e) if e.code == ErrorCode::InvalidState => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let record = LeaderRecord {
+            holder: self.holder.clone(),
+            lock_id: lock.info().holder_id.clone(),
+            term: self.read_record().map_or(0, |r| r.term) + 1,
+        };
+        atomic_write(&self.record_path(), &serde_json::to_vec(&record)?)?;
+        let lease = self.lease_for(&record, lock.info());
+        *held = Some(lock);
+        Ok(Some(lease))
+    }
+
+    fn renew(&self, lease: &Lease) -> SisterResult<Lease> {
+        let mut held = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let lock = held.as_mut().ok_or_else(|| Self::lost(lease))?;
+        let ours = self
+            .read_record()
+            .filter(|r| r.lock_id == lock.info().holder_id && r.term == lease.term);
+        match ours {
+            Some(record) if !lease.is_expired() => match lock.heartbeat() {
+                Ok(()) => Ok(self.lease_for(&record, lock.info())),
+                Err(e) if e.code == ErrorCode::InvalidState => {
+                    *held = None;
+                    Err(Self::lost(lease))
+                }
+                Err(e) => Err(e),
+            },
+            _ => {
+                *held = None;
+                Err(Self::lost(lease))
+            }
+        }
+    }
+
+    fn resign(&self, lease: &Lease) -> SisterResult<()> {
+        let mut held = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let ours = self
+            .read_record()
+            .is_some_and(|r| r.term == lease.term && r.holder == lease.holder);
+        match held.take() {
+            Some(lock) if ours => lock.release(),
+            // Not ours any more; dropping only removes our own lock file.
+            _ => Ok(()),
+        }
+    }
+
+    fn leader(&self) -> SisterResult<Option<Lease>> {
+        let Some(record) = self.read_record() else {
+            return Ok(None);
+        };
+        Ok(FileLock::holders_with(&self.target, self.ttl)?
+            .into_iter()
+            .find(|info| info.mode == LockMode::Exclusive && info.holder_id == record.lock_id)
+            .map(|info| self.lease_for(&record, &info))
+            .filter(|lease| !lease.is_expired()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    fn target() -> (TempDir, PathBuf) {
+        let dir = TempDir::new("leader");
+        let path = dir.join("data.amem");
+        (dir, path)
+    }
+
+    #[test]
+    fn test_single_leader() {
+        let (_dir, path) = target();
+        let a = FileLeaseCoordinator::new(&path, "node-a");
+        let b = FileLeaseCoordinator::new(&path, "node-b");
+
+        let lease = a.acquire_leadership().unwrap().unwrap();
+        assert_eq!(lease.term, 1);
+        assert!(b.acquire_leadership().unwrap().is_none());
+        assert_eq!(b.leader().unwrap().unwrap().holder, "node-a");
+
+        let renewed = a.renew(&lease).unwrap();
+        assert_eq!(renewed.term, 1);
+        assert!(b.is_leader(&renewed).unwrap());
+
+        a.resign(&renewed).unwrap();
+        assert!(b.leader().unwrap().is_none());
+        let taken = b.acquire_leadership().unwrap().unwrap();
+        assert_eq!((taken.holder.as_str(), taken.term), ("node-b", 2));
+
+        let err = a.renew(&renewed).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidState);
+    }
+
+    #[test]
+    fn test_expired_lease_taken_over() {
+        let (_dir, path) = target();
+        let ttl = Duration::from_millis(20);
+        let a = FileLeaseCoordinator::new(&path, "node-a").with_ttl(ttl);
+        let b = FileLeaseCoordinator::new(&path, "node-b").with_ttl(ttl);
+
+        let stale = a.acquire_leadership().unwrap().unwrap();
+        std::thread::sleep(ttl * 3);
+        assert!(b.leader().unwrap().is_none());
+
+        let lease = b.acquire_leadership().unwrap().unwrap();
+        assert_eq!(lease.term, stale.term + 1);
+        assert_eq!(a.renew(&stale).unwrap_err().code, ErrorCode::InvalidState);
+        // a's stale guard must not disturb b's leadership
+        a.resign(&stale).unwrap();
+        assert!(b.is_leader(&lease).unwrap());
+    }
+}
@@ -7,12 +7,29 @@
 //!
 //! - **Sister trait**: Core lifecycle management
 //! - **SessionManagement / WorkspaceManagement**: Context handling (split in v0.2.0)
+//! - **ContextCoordinator**: All-or-nothing context switches across sisters
+//! - **Coordination trait** (`file-io` feature): Lease-based single-writer election over shared storage
 //! - **Grounding trait**: Query-based evidence verification (rewritten in v0.2.0)
-//! - **EventEmitter trait**: Observability events
+//! - **EventEmitter trait** (`events` feature): Observability events
 //! - **Queryable trait**: Standard query interface
+//! - **ReadOnly<S>**: Enforces read-only deployments by rejecting mutating calls
+//! - **Diagnostics**: `Sister::self_test` with structured findings
+//! - **BudgetGuard**: Enforces `memory_budget_mb` with pressure events
+//! - **Codec**: JSON, MessagePack (`msgpack`), or CBOR (`cbor`) payloads with content-type negotiation
+//! - **Clock trait**: Injectable time source (`MockClock` for deterministic tests)
+//! - **Metrics trait**: Counters, gauges, histograms with a Prometheus text exporter
 //! - **FileFormat traits**: 20-year compatible file I/O (trait-based in v0.2.0)
 //! - **Errors**: Two-layer error model — ProtocolError + SisterError (new in v0.2.0)
 //! - **Hydra**: Placeholder traits for orchestrator integration (new in v0.2.0)
+//! - **Fleet config** (`config` feature): one `agenticos.toml` for every sister
+//! - **JSON Schemas** (`schema` feature): `schemas::all()` for non-Rust sisters
+//! - **TypeScript** (`typescript` feature): `.d.ts` declarations for the Hydra UI
+//! - **Protobuf** (`proto` feature): gRPC wire types with `From`/`TryFrom` conversions
+//! - **Chaos testing** (`testing` feature): `FlakySister`, `SlowQueryable`, `CorruptingWriter` fault injection
+//! - **Trace record/replay** (`testing` feature): `TraceRecorder` logs trait calls as JSONL, `TraceReplayer` re-runs and diffs them
+//! - **Mock sisters** (`mocks` feature): in-memory Memory, Codebase, Identity, Time, Contract
+//! - **Property testing** (`proptest` feature): `Arbitrary` for the core DTOs
+//! - **Resource probe** (`resource-probe` feature): real `ResourceUsage` for `health()`
 //!
 //! ## What changed in v0.2.0
 //!
@@ -54,27 +71,67 @@
 //! - ANY sister can work with ANY other sister
 //! - ANY file format will be readable in 20 years
 
+pub mod clock;
+pub mod codec;
+#[cfg(feature = "config")]
+pub mod config;
 pub mod context;
+pub mod coordinator;
+pub mod diagnostics;
 pub mod errors;
 pub mod events;
 pub mod file_format;
 pub mod grounding;
 pub mod hydra;
+#[cfg(feature = "file-io")]
+pub mod leadership;
+pub mod memory_budget;
+pub mod metrics;
+#[cfg(feature = "mocks")]
+pub mod mocks;
+#[cfg(feature = "proto")]
+pub mod proto;
 pub mod query;
+pub mod read_only;
 pub mod receipts;
+#[cfg(feature = "resource-probe")]
+pub mod resource_probe;
+#[cfg(feature = "schema")]
+pub mod schemas;
 pub mod sister;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+#[cfg(test)]
+mod test_support;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod types;
+#[cfg(feature = "typescript")]
+pub mod typescript;
 
 // Re-export everything in prelude for convenience
 pub mod prelude {
+    pub use crate::clock::*;
+    pub use crate::codec::*;
+    #[cfg(feature = "config")]
+    pub use crate::config::*;
     pub use crate::context::*;
+    pub use crate::coordinator::*;
+    pub use crate::diagnostics::*;
     pub use crate::errors::*;
     pub use crate::events::*;
     pub use crate::file_format::*;
     pub use crate::grounding::*;
     pub use crate::hydra::*;
+    #[cfg(feature = "file-io")]
+    pub use crate::leadership::*;
+    pub use crate::memory_budget::*;
+    pub use crate::metrics::*;
     pub use crate::query::*;
+    pub use crate::read_only::*;
     pub use crate::receipts::*;
+    #[cfg(feature = "resource-probe")]
+    pub use crate::resource_probe::*;
     pub use crate::sister::*;
     pub use crate::types::*;
 }
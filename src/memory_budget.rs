@@ -0,0 +1,189 @@
+//! Enforcing `SisterConfig::memory_budget_mb`.
+//!
+//! Sisters account for large allocations (index pages, embedding
+//! batches, caches) through a `BudgetGuard`:
+//!
+//! ```rust,ignore
+//! let budget = BudgetGuard::from_config(SisterType::Memory, &config).with_events(events.clone());
+//!
+//! budget.try_reserve(batch.len() as u64 * EMBEDDING_BYTES)?;
+//! let embeddings = embed(batch);
+//! // ... when the embeddings are dropped:
+//! budget.release(batch.len() as u64 * EMBEDDING_BYTES);
+//! ```
+//!
+//! Crossing a pressure threshold upwards emits `MemoryPressure` once;
+//! it fires again only after usage drops back below that threshold.
+
+use crate::errors::{ErrorCode, SisterError, SisterResult, SuggestedAction};
+use crate::events::{EventManager, SisterEvent};
+use crate::sister::SisterConfig;
+use crate::types::SisterType;
+use std::sync::{Arc, Mutex};
+
+/// Usage percentages at which `MemoryPressure` is emitted.
+pub const DEFAULT_PRESSURE_THRESHOLDS: [f64; 2] = [80.0, 95.0];
+
+const BYTES_PER_MB: u64 = 1024 * 1024;
+
+#[derive(Debug, Default)]
+struct BudgetState {
+    used: u64,
+    /// Thresholds currently at or above
+    crossed: usize,
+}
+
+/// Tracks reserved bytes against a memory budget.
+pub struct BudgetGuard {
+    sister_type: SisterType,
+    limit: Option<u64>,
+    thresholds: Vec<f64>,
+    events: Option<Arc<EventManager>>,
+    state: Mutex<BudgetState>,
+}
+
+impl BudgetGuard {
+    /// A guard allowing `limit` bytes (`None` = unlimited, tracking only).
+    pub fn new(sister_type: SisterType, limit: Option<u64>) -> Self {
+        Self {
+            sister_type,
+            limit,
+            thresholds: DEFAULT_PRESSURE_THRESHOLDS.to_vec(),
+            events: None,
+            state: Mutex::new(BudgetState::default()),
+        }
+    }
+
+    /// A guard for `config.memory_budget_mb`.
+    pub fn from_config(sister_type: SisterType, config: &SisterConfig) -> Self {
+        Self::new(
+            sister_type,
+            config.memory_budget_mb.map(|mb| mb as u64 * BYTES_PER_MB),
+        )
+    }
+
+    /// Emit `MemoryPressure` events to `events`.
+    pub fn with_events(mut self, events: Arc<EventManager>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Replace the pressure thresholds (percent of the budget).
+    pub fn with_thresholds(mut self, mut thresholds: Vec<f64>) -> Self {
+        thresholds.sort_by(f64::total_cmp);
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Reserve `bytes`, or fail with `ResourceExhausted` leaving usage unchanged.
+    pub fn try_reserve(&self, bytes: u64) -> SisterResult<()> {
+        let mut state = self.lock();
+        let requested = state.used.saturating_add(bytes);
+        if let Some(limit) = self.limit.filter(|limit| requested > *limit) {
+            return Err(SisterError::new(
+                ErrorCode::ResourceExhausted,
+                format!(
+                    "Memory budget exceeded: {} + {} bytes > {} bytes",
+                    state.used, bytes, limit
+                ),
+            )
+            .with_context("requested_bytes", bytes)
+            .with_context("used_bytes", state.used)
+            .with_context("limit_bytes", limit)
+            .with_suggestion(SuggestedAction::CheckConfig {
+                key: "memory_budget_mb".into(),
+            }));
+        }
+        state.used = requested;
+
+        let crossed = self.crossed_at(state.used);
+        if crossed > state.crossed {
+            if let (Some(events), Some(percent)) = (&self.events, self.percent_of(state.used)) {
+                events.emit(SisterEvent::memory_pressure(self.sister_type, percent));
+            }
+        }
+        state.crossed = crossed;
+        Ok(())
+    }
+
+    /// Return `bytes` to the budget (saturating at zero).
+    pub fn release(&self, bytes: u64) {
+        let mut state = self.lock();
+        state.used = state.used.saturating_sub(bytes);
+        state.crossed = self.crossed_at(state.used);
+    }
+
+    pub fn used(&self) -> u64 {
+        self.lock().used
+    }
+
+    pub fn limit(&self) -> Option<u64> {
+        self.limit
+    }
+
+    /// Bytes left (`None` when unlimited).
+    pub fn available(&self) -> Option<u64> {
+        let used = self.used();
+        self.limit.map(|limit| limit.saturating_sub(used))
+    }
+
+    /// Usage as a percentage of the budget (`None` when unlimited).
+    pub fn usage_percent(&self) -> Option<f64> {
+        self.percent_of(self.used())
+    }
+
+    fn percent_of(&self, used: u64) -> Option<f64> {
+        self.limit
+            .filter(|limit| *limit > 0)
+            .map(|limit| used as f64 / limit as f64 * 100.0)
+    }
+
+    fn crossed_at(&self, used: u64) -> usize {
+        self.percent_of(used).map_or(0, |percent| {
+            self.thresholds.iter().filter(|t| percent >= **t).count()
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BudgetState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventType;
+
+    #[test]
+    fn test_budget_guard() {
+        let events = Arc::new(EventManager::new(16));
+        let config = SisterConfig::stateless().memory_budget(1);
+        let budget =
+            BudgetGuard::from_config(SisterType::Memory, &config).with_events(events.clone());
+        assert_eq!(budget.limit(), Some(BYTES_PER_MB));
+
+        budget.try_reserve(BYTES_PER_MB / 2).unwrap();
+        assert!(events.recent(10).is_empty());
+        budget.try_reserve(BYTES_PER_MB * 3 / 10).unwrap();
+        budget.try_reserve(BYTES_PER_MB / 100).unwrap();
+        assert_eq!(events.recent(10).len(), 1);
+
+        let err = budget.try_reserve(BYTES_PER_MB).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ResourceExhausted);
+        assert_eq!(err.context.unwrap()["limit_bytes"], BYTES_PER_MB);
+        assert!(matches!(
+            err.suggested_action,
+            Some(SuggestedAction::CheckConfig { ref key }) if key == "memory_budget_mb"
+        ));
+
+        budget.release(BYTES_PER_MB / 2);
+        budget.try_reserve(BYTES_PER_MB / 2).unwrap();
+        let recent = events.recent(10);
+        assert_eq!(recent.len(), 2);
+        assert!(matches!(
+            recent[0].event_type,
+            EventType::MemoryPressure { usage_percent } if usage_percent > 80.0
+        ));
+        assert_eq!(budget.available(), Some(BYTES_PER_MB - budget.used()));
+    }
+}
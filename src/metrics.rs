@@ -0,0 +1,324 @@
+//! Metrics contract for uniform scraping.
+//!
+//! Sisters implement `Metrics` to expose counters, gauges, and
+//! histograms; `render_prometheus` turns any sister's metrics into the
+//! Prometheus text exposition format so Hydra and ops tooling can
+//! scrape every sister the same way.
+//!
+//! Names follow Prometheus conventions (`snake_case`, unit suffix,
+//! `_total` for counters). Use `metric_name` to prefix them with the
+//! sister, e.g. `agentic_memory_queries_total`.
+
+use crate::types::SisterType;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Queries answered (counter).
+pub const METRIC_QUERIES_TOTAL: &str = "queries_total";
+
+/// Grounding call latency (histogram, seconds).
+pub const METRIC_GROUNDING_LATENCY_SECONDS: &str = "grounding_latency_seconds";
+
+/// Bytes on disk (gauge).
+pub const METRIC_STORAGE_BYTES: &str = "storage_bytes";
+
+/// Default histogram bucket bounds for latencies in seconds.
+pub const DEFAULT_LATENCY_BUCKETS: [f64; 8] = [0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 10.0];
+
+/// `agentic_<sister>_<name>`.
+pub fn metric_name(sister_type: SisterType, name: &str) -> String {
+    format!("agentic_{}_{}", sister_type.mcp_prefix(), name)
+}
+
+/// Kind of metric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricKind {
+    /// Monotonically increasing count
+    Counter,
+
+    /// Value that can go up and down
+    Gauge,
+
+    /// Distribution of observations in buckets
+    Histogram,
+}
+
+impl std::fmt::Display for MetricKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Counter => write!(f, "counter"),
+            Self::Gauge => write!(f, "gauge"),
+            Self::Histogram => write!(f, "histogram"),
+        }
+    }
+}
+
+/// One histogram bucket: observations `<= le` (cumulative).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HistogramBucket {
+    pub le: f64,
+    pub count: u64,
+}
+
+/// Metric value by kind.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MetricValue {
+    Counter {
+        value: f64,
+    },
+    Gauge {
+        value: f64,
+    },
+    Histogram {
+        /// Cumulative buckets in ascending `le` order (no +Inf bucket)
+        buckets: Vec<HistogramBucket>,
+        sum: f64,
+        count: u64,
+    },
+}
+
+/// A single labelled metric sample.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Metric {
+    pub name: String,
+    pub help: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+    pub value: MetricValue,
+}
+
+impl Metric {
+    pub fn counter(name: impl Into<String>, help: impl Into<String>, value: f64) -> Self {
+        Self::new(name, help, MetricValue::Counter { value })
+    }
+
+    pub fn gauge(name: impl Into<String>, help: impl Into<String>, value: f64) -> Self {
+        Self::new(name, help, MetricValue::Gauge { value })
+    }
+
+    /// Histogram over `observations` with the given bucket upper bounds.
+    pub fn histogram(
+        name: impl Into<String>,
+        help: impl Into<String>,
+        bounds: &[f64],
+        observations: &[f64],
+    ) -> Self {
+        let buckets = bounds
+            .iter()
+            .map(|&le| HistogramBucket {
+                le,
+                count: observations.iter().filter(|&&x| x <= le).count() as u64,
+            })
+            .collect();
+        Self::new(
+            name,
+            help,
+            MetricValue::Histogram {
+                buckets,
+                sum: observations.iter().sum(),
+                count: observations.len() as u64,
+            },
+        )
+    }
+
+    fn new(name: impl Into<String>, help: impl Into<String>, value: MetricValue) -> Self {
+        Self {
+            name: name.into(),
+            help: help.into(),
+            labels: BTreeMap::new(),
+            value,
+        }
+    }
+
+    /// Add a label.
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn kind(&self) -> MetricKind {
+        match self.value {
+            MetricValue::Counter { .. } => MetricKind::Counter,
+            MetricValue::Gauge { .. } => MetricKind::Gauge,
+            MetricValue::Histogram { .. } => MetricKind::Histogram,
+        }
+    }
+}
+
+/// Sisters that expose metrics.
+pub trait Metrics {
+    /// Current value of every metric.
+    fn metrics(&self) -> Vec<Metric>;
+
+    /// Metrics in Prometheus text format.
+    fn render_metrics(&self) -> String {
+        render_prometheus(&self.metrics())
+    }
+}
+
+/// Render metrics in the Prometheus text exposition format (v0.0.4).
+///
+/// Samples sharing a name get one `# HELP`/`# TYPE` header (the first
+/// sample's help text wins).
+pub fn render_prometheus(metrics: &[Metric]) -> String {
+    let mut families: Vec<(&str, Vec<&Metric>)> = Vec::new();
+    for metric in metrics {
+        match families.iter_mut().find(|(name, _)| *name == metric.name) {
+            Some((_, samples)) => samples.push(metric),
+            None => families.push((&metric.name, vec![metric])),
+        }
+    }
+
+    let mut out = String::new();
+    for (name, samples) in families {
+        let first = samples[0];
+        let _ = writeln!(out, "# HELP {} {}", name, escape_help(&first.help));
+        let _ = writeln!(out, "# TYPE {} {}", name, first.kind());
+        for metric in samples {
+            match &metric.value {
+                MetricValue::Counter { value } | MetricValue::Gauge { value } => {
+                    let _ = writeln!(
+                        out,
+                        "{}{} {}",
+                        name,
+                        labels(&metric.labels, None),
+                        number(*value)
+                    );
+                }
+                MetricValue::Histogram {
+                    buckets,
+                    sum,
+                    count,
+                } => {
+                    for bucket in buckets {
+                        let le = number(bucket.le);
+                        let _ = writeln!(
+                            out,
+                            "{}_bucket{} {}",
+                            name,
+                            labels(&metric.labels, Some(&le)),
+                            bucket.count
+                        );
+                    }
+                    let _ = writeln!(
+                        out,
+                        "{}_bucket{} {}",
+                        name,
+                        labels(&metric.labels, Some("+Inf")),
+                        count
+                    );
+                    let _ = writeln!(
+                        out,
+                        "{}_sum{} {}",
+                        name,
+                        labels(&metric.labels, None),
+                        number(*sum)
+                    );
+                    let _ = writeln!(
+                        out,
+                        "{}_count{} {}",
+                        name,
+                        labels(&metric.labels, None),
+                        count
+                    );
+                }
+            }
+        }
+    }
+    out
+}
+
+fn labels(labels: &BTreeMap<String, String>, le: Option<&str>) -> String {
+    let mut pairs: Vec<String> = labels
+        .iter()
+        .map(|(k, v)| format!("{}=\"{}\"", k, escape_label(v)))
+        .collect();
+    if let Some(le) = le {
+        pairs.push(format!("le=\"{}\"", le));
+    }
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", pairs.join(","))
+    }
+}
+
+fn number(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".into()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.into()
+    } else {
+        value.to_string()
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn escape_help(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Memory;
+
+    impl Metrics for Memory {
+        fn metrics(&self) -> Vec<Metric> {
+            let queries = metric_name(SisterType::Memory, METRIC_QUERIES_TOTAL);
+            vec![
+                Metric::counter(&queries, "Queries answered", 12.0).label("query_type", "search"),
+                Metric::counter(&queries, "Queries answered", 3.0).label("query_type", "recent"),
+                Metric::gauge(
+                    metric_name(SisterType::Memory, METRIC_STORAGE_BYTES),
+                    "Bytes on disk",
+                    4096.0,
+                ),
+                Metric::histogram(
+                    metric_name(SisterType::Memory, METRIC_GROUNDING_LATENCY_SECONDS),
+                    "Grounding latency",
+                    &[0.01, 0.1],
+                    &[0.005, 0.05, 0.5],
+                ),
+            ]
+        }
+    }
+
+    #[test]
+    fn test_render_prometheus() {
+        let text = Memory.render_metrics();
+        let expected = "\
+# HELP agentic_memory_queries_total Queries answered
+# TYPE agentic_memory_queries_total counter
+agentic_memory_queries_total{query_type=\"search\"} 12
+agentic_memory_queries_total{query_type=\"recent\"} 3
+# HELP agentic_memory_storage_bytes Bytes on disk
+# TYPE agentic_memory_storage_bytes gauge
+agentic_memory_storage_bytes 4096
+# HELP agentic_memory_grounding_latency_seconds Grounding latency
+# TYPE agentic_memory_grounding_latency_seconds histogram
+agentic_memory_grounding_latency_seconds_bucket{le=\"0.01\"} 1
+agentic_memory_grounding_latency_seconds_bucket{le=\"0.1\"} 2
+agentic_memory_grounding_latency_seconds_bucket{le=\"+Inf\"} 3
+agentic_memory_grounding_latency_seconds_sum 0.555
+agentic_memory_grounding_latency_seconds_count 3
+";
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    fn test_label_escaping() {
+        let metric = Metric::gauge("g", "help", 1.0).label("path", "C:\\data \"x\"");
+        assert!(render_prometheus(&[metric]).contains(r#"g{path="C:\\data \"x\""} 1"#));
+    }
+}
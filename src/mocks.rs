@@ -0,0 +1,1570 @@
+//! Realistic fake sisters for integration tests (`mocks` feature).
+//!
+//! Hydra, gateways and other consumers test against these instead of
+//! spinning up real sisters. Each mock keeps its state in memory and
+//! mirrors the trait composition of the sister it stands in for:
+//!
+//! - `MockMemory`:   Sister + SessionManagement + Checkpointing + Grounding + Queryable + EventEmitter
+//! - `MockCodebase`: Sister + WorkspaceManagement + ContextTagging + Grounding + Queryable
+//! - `MockIdentity`: Sister + SessionManagement + Grounding + ReceiptIntegration + ReceiptWatcher
+//! - `MockTime`:     Sister + Queryable (stateless — no sessions, no grounding)
+//! - `MockContract`: Sister + SessionManagement + Grounding + Queryable + ReceiptIntegration + EventEmitter
+//!
+//! ```rust,ignore
+//! let mut memory = MockMemory::init(SisterConfig::stateless())?;
+//! memory.start_session("test")?;
+//! memory.add_node("The sky is blue");
+//! assert_eq!(memory.ground("sky is blue")?.status, GroundingStatus::Verified);
+//! ```
+
+use crate::prelude::*;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+// ═══════════════════════════════════════════════════════════════════
+// MOCK MEMORY — Session-based sister with grounding
+// ═══════════════════════════════════════════════════════════════════
+
+type MemoryNodes = Vec<(u64, String)>; // (id, content)
+
+/// Memory: sessions of text nodes, grounded by substring match.
+pub struct MockMemory {
+    start_time: Instant,
+    session_id: Mutex<Option<ContextId>>,
+    sessions: Mutex<Vec<ContextSummary>>,
+    events: EventManager,
+    nodes: Mutex<MemoryNodes>,
+    next_id: Mutex<u64>,
+    checkpoints: Mutex<Vec<(CheckpointInfo, MemoryNodes)>>,
+    paused: Mutex<bool>,
+    config: SisterConfig,
+}
+
+impl MockMemory {
+    pub fn new(config: SisterConfig) -> SisterResult<Self> {
+        Ok(Self {
+            start_time: Instant::now(),
+            session_id: Mutex::new(None),
+            sessions: Mutex::new(vec![]),
+            events: EventManager::new(256),
+            nodes: Mutex::new(vec![]),
+            next_id: Mutex::new(1),
+            checkpoints: Mutex::new(vec![]),
+            paused: Mutex::new(false),
+            config,
+        })
+    }
+
+    /// Store a memory node in the current session, returning its ID.
+    pub fn add_node(&self, content: &str) -> u64 {
+        let mut nodes = self.nodes.lock().unwrap();
+        let mut next = self.next_id.lock().unwrap();
+        let id = *next;
+        *next += 1;
+        nodes.push((id, content.to_string()));
+        id
+    }
+}
+
+impl Sister for MockMemory {
+    const SISTER_TYPE: SisterType = SisterType::Memory;
+    const FILE_EXTENSION: &'static str = "amem";
+
+    fn init(config: SisterConfig) -> SisterResult<Self>
+    where
+        Self: Sized,
+    {
+        MockMemory::new(config)
+    }
+
+    fn health(&self) -> HealthStatus {
+        let paused = *self.paused.lock().unwrap();
+        HealthStatus {
+            healthy: true,
+            live: true,
+            ready: !paused,
+            status: if paused {
+                Status::Paused
+            } else {
+                Status::Ready
+            },
+            uptime: self.start_time.elapsed(),
+            resources: ResourceUsage::default(),
+            warnings: vec![],
+            last_error: None,
+            components: vec![],
+        }
+    }
+
+    fn version(&self) -> Version {
+        Version::new(0, 2, 0)
+    }
+
+    fn shutdown(&mut self) -> SisterResult<()> {
+        self.events
+            .emit(SisterEvent::shutting_down(SisterType::Memory));
+        Ok(())
+    }
+
+    fn current_config(&self) -> Option<SisterConfig> {
+        Some(self.config.clone())
+    }
+
+    fn apply_config(&mut self, config: SisterConfig, diff: &ConfigDiff) -> SisterResult<()> {
+        self.config = config;
+        self.events
+            .emit(SisterEvent::config_changed(SisterType::Memory, diff));
+        Ok(())
+    }
+
+    fn pause(&mut self) -> SisterResult<()> {
+        let mut paused = self.paused.lock().unwrap();
+        if *paused {
+            return Err(SisterError::new(ErrorCode::InvalidState, "Already paused"));
+        }
+        *paused = true;
+        self.events.emit(SisterEvent::paused(SisterType::Memory));
+        Ok(())
+    }
+
+    fn resume(&mut self) -> SisterResult<()> {
+        let mut paused = self.paused.lock().unwrap();
+        if !*paused {
+            return Err(SisterError::new(ErrorCode::InvalidState, "Not paused"));
+        }
+        *paused = false;
+        self.events.emit(SisterEvent::resumed(SisterType::Memory));
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Vec<Capability> {
+        self.config.enabled_capabilities(vec![
+            Capability::new("memory_add", "Add cognitive events to graph"),
+            Capability::new("memory_query", "Query memory by filters"),
+            Capability::new("memory_ground", "Verify claims against stored memories"),
+            Capability::new("memory_similar", "Find semantically similar memories"),
+        ])
+    }
+}
+
+impl SessionManagement for MockMemory {
+    fn start_session(&mut self, name: &str) -> SisterResult<ContextId> {
+        let id = ContextId::new();
+        *self.session_id.lock().unwrap() = Some(id);
+
+        let summary = ContextSummary {
+            id,
+            name: name.to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            item_count: 0,
+            size_bytes: 0,
+            tags: Vec::new(),
+        };
+        self.sessions.lock().unwrap().push(summary);
+
+        self.events.emit(SisterEvent::context_created(
+            SisterType::Memory,
+            id,
+            name.to_string(),
+        ));
+        Ok(id)
+    }
+
+    fn end_session(&mut self) -> SisterResult<()> {
+        *self.session_id.lock().unwrap() = None;
+        Ok(())
+    }
+
+    fn current_session(&self) -> Option<ContextId> {
+        *self.session_id.lock().unwrap()
+    }
+
+    fn current_session_info(&self) -> SisterResult<ContextInfo> {
+        let id = self
+            .current_session()
+            .ok_or_else(|| SisterError::new(ErrorCode::InvalidState, "No active session"))?;
+
+        Ok(ContextInfo {
+            id,
+            name: "active".to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            item_count: self.nodes.lock().unwrap().len(),
+            size_bytes: 0,
+            tags: Vec::new(),
+            metadata: Metadata::new(),
+            parent_id: None,
+            child_ids: Vec::new(),
+            quota: None,
+        })
+    }
+
+    fn list_sessions(&self) -> SisterResult<Vec<ContextSummary>> {
+        Ok(self.sessions.lock().unwrap().clone())
+    }
+
+    fn export_session(&self, _id: ContextId) -> SisterResult<ContextSnapshot> {
+        let info = self.current_session_info()?;
+        let data = serde_json::to_vec(&self.nodes.lock().unwrap().clone())
+            .map_err(|e| SisterError::new(ErrorCode::Internal, e.to_string()))?;
+        let checksum = *blake3::hash(&data).as_bytes();
+
+        Ok(ContextSnapshot {
+            snapshot_format: SNAPSHOT_FORMAT,
+            sister_type: SisterType::Memory,
+            version: Version::new(0, 2, 0),
+            context_info: info,
+            data,
+            checksum,
+            snapshot_at: Utc::now(),
+            compression: Default::default(),
+            signer: None,
+            signature: None,
+        })
+    }
+
+    fn import_session(&mut self, snapshot: ContextSnapshot) -> SisterResult<ContextId> {
+        if !snapshot.verify()? {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                "Snapshot checksum verification failed",
+            ));
+        }
+        self.start_session(&snapshot.context_info.name)
+    }
+
+    fn archive_session(&mut self, id: ContextId) -> SisterResult<()> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let before = sessions.len();
+        sessions.retain(|s| s.id != id);
+        if sessions.len() == before {
+            return Err(SisterError::context_not_found(id.to_string()));
+        }
+        Ok(())
+    }
+
+    fn on_session_event(&self, event: EventType) {
+        self.events
+            .emit(SisterEvent::new(SisterType::Memory, event));
+    }
+}
+
+impl Checkpointing for MockMemory {
+    fn checkpoint(&mut self) -> SisterResult<CheckpointId> {
+        let context_id = self
+            .current_session()
+            .ok_or_else(|| SisterError::new(ErrorCode::InvalidState, "No active session"))?;
+        let nodes = self.nodes.lock().unwrap().clone();
+        let info = CheckpointInfo {
+            id: CheckpointId::new(),
+            context_id,
+            created_at: Utc::now(),
+            item_count: nodes.len(),
+            size_bytes: nodes.iter().map(|(_, c)| c.len()).sum(),
+        };
+        let id = info.id;
+        self.checkpoints.lock().unwrap().push((info, nodes));
+        Ok(id)
+    }
+
+    fn restore_checkpoint(&mut self, id: CheckpointId) -> SisterResult<()> {
+        let checkpoints = self.checkpoints.lock().unwrap();
+        let (_, nodes) = checkpoints
+            .iter()
+            .find(|(info, _)| info.id == id)
+            .ok_or_else(|| SisterError::not_found(id.to_string()))?;
+        *self.nodes.lock().unwrap() = nodes.clone();
+        Ok(())
+    }
+
+    fn list_checkpoints(&self) -> SisterResult<Vec<CheckpointInfo>> {
+        let current = self.current_session();
+        Ok(self
+            .checkpoints
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(info, _)| Some(info.context_id) == current)
+            .map(|(info, _)| info.clone())
+            .collect())
+    }
+}
+
+impl Grounding for MockMemory {
+    fn ground(&self, claim: &str) -> SisterResult<GroundingResult> {
+        self.config.ensure_capability_enabled("memory_ground")?;
+        let nodes = self.nodes.lock().unwrap();
+        let claim_lower = claim.to_lowercase();
+
+        let matches: Vec<_> = nodes
+            .iter()
+            .filter(|(_, content)| content.to_lowercase().contains(&claim_lower))
+            .collect();
+
+        if matches.is_empty() {
+            Ok(
+                GroundingResult::ungrounded(claim, "No matching memories found")
+                    .with_suggestions(nodes.iter().take(3).map(|(_, c)| c.clone()).collect()),
+            )
+        } else {
+            // BM25-like: best match score matters, not ratio
+            let best_score = matches
+                .iter()
+                .map(|(_, content)| {
+                    let claim_words: Vec<&str> = claim_lower.split_whitespace().collect();
+                    let content_lower = content.to_lowercase();
+                    let matched = claim_words
+                        .iter()
+                        .filter(|w| content_lower.contains(**w))
+                        .count();
+                    matched as f64 / claim_words.len().max(1) as f64
+                })
+                .fold(0.0f64, |a, b| a.max(b));
+
+            let evidence = matches
+                .iter()
+                .map(|(id, content)| {
+                    GroundingEvidence::new(
+                        "memory_node",
+                        format!("node_{}", id),
+                        best_score,
+                        content,
+                    )
+                })
+                .collect();
+
+            if best_score > 0.5 {
+                Ok(GroundingResult::verified(claim, best_score)
+                    .with_evidence(evidence)
+                    .with_reason("Found matching memories"))
+            } else {
+                Ok(GroundingResult::partial(claim, best_score)
+                    .with_evidence(evidence)
+                    .with_reason("Some evidence found"))
+            }
+        }
+    }
+
+    fn evidence(&self, query: &str, max_results: usize) -> SisterResult<Vec<EvidenceDetail>> {
+        let nodes = self.nodes.lock().unwrap();
+        let query_lower = query.to_lowercase();
+
+        Ok(nodes
+            .iter()
+            .filter(|(_, content)| content.to_lowercase().contains(&query_lower))
+            .take(max_results)
+            .map(|(id, content)| EvidenceDetail {
+                evidence_type: "memory_node".to_string(),
+                id: format!("node_{}", id),
+                score: 0.8,
+                created_at: Utc::now(),
+                source_sister: SisterType::Memory,
+                content: content.clone(),
+                data: Metadata::new(),
+            })
+            .collect())
+    }
+
+    fn suggest(&self, query: &str, limit: usize) -> SisterResult<Vec<GroundingSuggestion>> {
+        let nodes = self.nodes.lock().unwrap();
+        let _query_lower = query.to_lowercase();
+
+        Ok(nodes
+            .iter()
+            .take(limit)
+            .map(|(id, content)| GroundingSuggestion {
+                item_type: "memory_node".to_string(),
+                id: format!("node_{}", id),
+                relevance_score: 0.5,
+                description: content.clone(),
+                data: Metadata::new(),
+            })
+            .collect())
+    }
+}
+
+impl Queryable for MockMemory {
+    fn query(&self, query: Query) -> SisterResult<QueryResult> {
+        let start = Instant::now();
+        let nodes = self.nodes.lock().unwrap();
+
+        let results: Vec<serde_json::Value> = match query.query_type.as_str() {
+            "list" => nodes
+                .iter()
+                .skip(query.offset.unwrap_or(0))
+                .take(query.limit.unwrap_or(20))
+                .map(|(id, content)| serde_json::json!({"id": id, "content": content}))
+                .collect(),
+            "search" => {
+                let text = query.get_string("text").unwrap_or_default().to_lowercase();
+                nodes
+                    .iter()
+                    .filter(|(_, content)| content.to_lowercase().contains(&text))
+                    .take(query.limit.unwrap_or(20))
+                    .map(|(id, content)| serde_json::json!({"id": id, "content": content}))
+                    .collect()
+            }
+            "recent" => nodes
+                .iter()
+                .rev()
+                .take(query.limit.unwrap_or(10))
+                .map(|(id, content)| serde_json::json!({"id": id, "content": content}))
+                .collect(),
+            _ => vec![],
+        };
+
+        Ok(QueryResult::new(query, results, start.elapsed()))
+    }
+
+    fn supports_query(&self, query_type: &str) -> bool {
+        matches!(
+            query_type,
+            "list" | "search" | "recent" | "related" | "temporal"
+        )
+    }
+
+    fn query_types(&self) -> Vec<QueryTypeInfo> {
+        vec![
+            QueryTypeInfo::new("list", "List all memory nodes"),
+            QueryTypeInfo::new("search", "Search memories by text").required(vec!["text"]),
+            QueryTypeInfo::new("recent", "Get most recent memories"),
+        ]
+    }
+}
+
+impl EventEmitter for MockMemory {
+    fn subscribe(&self, _filter: EventFilter) -> EventReceiver {
+        self.events.subscribe()
+    }
+
+    fn recent_events(&self, limit: usize) -> Vec<SisterEvent> {
+        self.events.recent(limit)
+    }
+
+    fn emit(&self, event: SisterEvent) {
+        self.events.emit(event);
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// MOCK CODEBASE — Workspace-based sister
+// ═══════════════════════════════════════════════════════════════════
+
+type SymbolList = Vec<(String, String)>; // (name, kind)
+type WorkspaceData = (String, SymbolList); // (workspace_name, symbols)
+
+/// Codebase: named workspaces of symbols, with tags and metadata.
+pub struct MockCodebase {
+    start_time: Instant,
+    current_workspace: Mutex<ContextId>,
+    workspaces: Mutex<HashMap<ContextId, WorkspaceData>>,
+    tags: Mutex<HashMap<ContextId, Vec<String>>>,
+    metadata: Mutex<HashMap<ContextId, Metadata>>,
+}
+
+impl MockCodebase {
+    pub fn new(_config: SisterConfig) -> SisterResult<Self> {
+        let default_id = ContextId::default_context();
+        let mut workspaces = HashMap::new();
+        workspaces.insert(default_id, ("default".to_string(), vec![]));
+
+        Ok(Self {
+            start_time: Instant::now(),
+            current_workspace: Mutex::new(default_id),
+            workspaces: Mutex::new(workspaces),
+            tags: Mutex::new(HashMap::new()),
+            metadata: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Add a symbol to the current workspace.
+    pub fn add_symbol(&self, name: &str, kind: &str) {
+        let ws_id = *self.current_workspace.lock().unwrap();
+        let mut workspaces = self.workspaces.lock().unwrap();
+        if let Some((_, symbols)) = workspaces.get_mut(&ws_id) {
+            symbols.push((name.to_string(), kind.to_string()));
+        }
+    }
+}
+
+impl Sister for MockCodebase {
+    const SISTER_TYPE: SisterType = SisterType::Codebase;
+    const FILE_EXTENSION: &'static str = "acb";
+
+    fn init(config: SisterConfig) -> SisterResult<Self>
+    where
+        Self: Sized,
+    {
+        MockCodebase::new(config)
+    }
+
+    fn health(&self) -> HealthStatus {
+        HealthStatus {
+            healthy: true,
+            live: true,
+            ready: true,
+            status: Status::Ready,
+            uptime: self.start_time.elapsed(),
+            resources: ResourceUsage::default(),
+            warnings: vec![],
+            last_error: None,
+            components: vec![],
+        }
+    }
+
+    fn version(&self) -> Version {
+        Version::new(0, 2, 0)
+    }
+
+    fn shutdown(&mut self) -> SisterResult<()> {
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Vec<Capability> {
+        vec![
+            Capability::new("symbol_lookup", "Look up symbols by name"),
+            Capability::new("impact_analysis", "Analyse change impact"),
+            Capability::new("codebase_ground", "Verify code claims"),
+        ]
+    }
+}
+
+impl WorkspaceManagement for MockCodebase {
+    fn create_workspace(&mut self, name: &str) -> SisterResult<ContextId> {
+        let id = ContextId::new();
+        self.workspaces
+            .lock()
+            .unwrap()
+            .insert(id, (name.to_string(), vec![]));
+        Ok(id)
+    }
+
+    fn switch_workspace(&mut self, id: ContextId) -> SisterResult<()> {
+        let workspaces = self.workspaces.lock().unwrap();
+        if !workspaces.contains_key(&id) {
+            return Err(SisterError::context_not_found(id.to_string()));
+        }
+        drop(workspaces);
+        *self.current_workspace.lock().unwrap() = id;
+        Ok(())
+    }
+
+    fn current_workspace(&self) -> ContextId {
+        *self.current_workspace.lock().unwrap()
+    }
+
+    fn current_workspace_info(&self) -> SisterResult<ContextInfo> {
+        let ws_id = self.current_workspace();
+        let workspaces = self.workspaces.lock().unwrap();
+        let (name, symbols) = workspaces
+            .get(&ws_id)
+            .ok_or_else(|| SisterError::context_not_found(ws_id.to_string()))?;
+
+        Ok(ContextInfo {
+            id: ws_id,
+            name: name.clone(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            item_count: symbols.len(),
+            size_bytes: 0,
+            tags: Vec::new(),
+            metadata: self
+                .metadata
+                .lock()
+                .unwrap()
+                .get(&ws_id)
+                .cloned()
+                .unwrap_or_default(),
+            parent_id: None,
+            child_ids: Vec::new(),
+            quota: None,
+        })
+    }
+
+    fn list_workspaces(&self) -> SisterResult<Vec<ContextSummary>> {
+        let workspaces = self.workspaces.lock().unwrap();
+        let tags = self.tags.lock().unwrap();
+        Ok(workspaces
+            .iter()
+            .map(|(id, (name, symbols))| ContextSummary {
+                id: *id,
+                name: name.clone(),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                item_count: symbols.len(),
+                size_bytes: 0,
+                tags: tags.get(id).cloned().unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    fn delete_workspace(&mut self, id: ContextId) -> SisterResult<()> {
+        let current = *self.current_workspace.lock().unwrap();
+        if id == current {
+            return Err(SisterError::new(
+                ErrorCode::InvalidState,
+                "Cannot delete active workspace. Switch first",
+            ));
+        }
+        self.workspaces.lock().unwrap().remove(&id);
+        Ok(())
+    }
+
+    fn rename_workspace(&mut self, id: ContextId, new_name: &str) -> SisterResult<()> {
+        let mut workspaces = self.workspaces.lock().unwrap();
+        if let Some((name, _)) = workspaces.get_mut(&id) {
+            *name = new_name.to_string();
+            Ok(())
+        } else {
+            Err(SisterError::context_not_found(id.to_string()))
+        }
+    }
+
+    fn set_context_metadata(
+        &mut self,
+        id: ContextId,
+        key: &str,
+        value: serde_json::Value,
+    ) -> SisterResult<()> {
+        if !self.workspaces.lock().unwrap().contains_key(&id) {
+            return Err(SisterError::context_not_found(id.to_string()));
+        }
+        self.metadata
+            .lock()
+            .unwrap()
+            .entry(id)
+            .or_default()
+            .insert(key.to_string(), value);
+        Ok(())
+    }
+
+    fn remove_context_metadata(&mut self, id: ContextId, key: &str) -> SisterResult<()> {
+        if let Some(metadata) = self.metadata.lock().unwrap().get_mut(&id) {
+            metadata.remove(key);
+        }
+        Ok(())
+    }
+
+    fn export_workspace(&self, id: ContextId) -> SisterResult<ContextSnapshot> {
+        let workspaces = self.workspaces.lock().unwrap();
+        let (name, symbols) = workspaces
+            .get(&id)
+            .ok_or_else(|| SisterError::context_not_found(id.to_string()))?;
+
+        let data = serde_json::to_vec(&symbols)
+            .map_err(|e| SisterError::new(ErrorCode::Internal, e.to_string()))?;
+        let checksum = *blake3::hash(&data).as_bytes();
+
+        Ok(ContextSnapshot {
+            snapshot_format: SNAPSHOT_FORMAT,
+            sister_type: SisterType::Codebase,
+            version: Version::new(0, 2, 0),
+            context_info: ContextInfo {
+                id,
+                name: name.clone(),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                item_count: symbols.len(),
+                size_bytes: data.len(),
+                tags: Vec::new(),
+                metadata: Metadata::new(),
+                parent_id: None,
+                child_ids: Vec::new(),
+                quota: None,
+            },
+            data,
+            checksum,
+            snapshot_at: Utc::now(),
+            compression: Default::default(),
+            signer: None,
+            signature: None,
+        })
+    }
+
+    fn import_workspace(&mut self, snapshot: ContextSnapshot) -> SisterResult<ContextId> {
+        if !snapshot.verify()? {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                "Snapshot checksum failed",
+            ));
+        }
+        self.create_workspace(&snapshot.context_info.name)
+    }
+}
+
+impl Grounding for MockCodebase {
+    fn ground(&self, claim: &str) -> SisterResult<GroundingResult> {
+        let ws_id = self.current_workspace();
+        let workspaces = self.workspaces.lock().unwrap();
+        let (_, symbols) = workspaces
+            .get(&ws_id)
+            .ok_or_else(|| SisterError::context_not_found(ws_id.to_string()))?;
+
+        let claim_lower = claim.to_lowercase();
+        let matches: Vec<_> = symbols
+            .iter()
+            .filter(|(name, _)| claim_lower.contains(&name.to_lowercase()))
+            .collect();
+
+        if matches.is_empty() {
+            Ok(GroundingResult::ungrounded(
+                claim,
+                "Symbol not found in graph",
+            ))
+        } else {
+            let evidence = matches
+                .iter()
+                .map(|(name, kind)| {
+                    GroundingEvidence::new("code_symbol", name, 0.9, format!("{}: {}", kind, name))
+                })
+                .collect();
+            Ok(GroundingResult::verified(claim, 0.9)
+                .with_evidence(evidence)
+                .with_reason("Symbol found in code graph"))
+        }
+    }
+
+    fn evidence(&self, query: &str, max_results: usize) -> SisterResult<Vec<EvidenceDetail>> {
+        let ws_id = self.current_workspace();
+        let workspaces = self.workspaces.lock().unwrap();
+        let (_, symbols) = workspaces
+            .get(&ws_id)
+            .ok_or_else(|| SisterError::context_not_found(ws_id.to_string()))?;
+
+        let query_lower = query.to_lowercase();
+        Ok(symbols
+            .iter()
+            .filter(|(name, _)| name.to_lowercase().contains(&query_lower))
+            .take(max_results)
+            .map(|(name, kind)| EvidenceDetail {
+                evidence_type: "code_symbol".to_string(),
+                id: name.clone(),
+                score: 0.9,
+                created_at: Utc::now(),
+                source_sister: SisterType::Codebase,
+                content: format!("{} {}", kind, name),
+                data: Metadata::new(),
+            })
+            .collect())
+    }
+
+    fn suggest(&self, query: &str, limit: usize) -> SisterResult<Vec<GroundingSuggestion>> {
+        let ws_id = self.current_workspace();
+        let workspaces = self.workspaces.lock().unwrap();
+        let (_, symbols) = workspaces
+            .get(&ws_id)
+            .ok_or_else(|| SisterError::context_not_found(ws_id.to_string()))?;
+
+        let _query_lower = query.to_lowercase();
+        Ok(symbols
+            .iter()
+            .take(limit)
+            .map(|(name, kind)| GroundingSuggestion {
+                item_type: "code_symbol".to_string(),
+                id: name.clone(),
+                relevance_score: 0.5,
+                description: format!("{}: {}", kind, name),
+                data: Metadata::new(),
+            })
+            .collect())
+    }
+}
+
+impl ContextTagging for MockCodebase {
+    fn tag_context(&mut self, id: ContextId, tags: &[&str]) -> SisterResult<()> {
+        if !self.workspace_exists(id) {
+            return Err(SisterError::context_not_found(id.to_string()));
+        }
+        let mut all = self.tags.lock().unwrap();
+        let entry = all.entry(id).or_default();
+        for tag in tags {
+            if !entry.iter().any(|t| t == tag) {
+                entry.push(tag.to_string());
+            }
+        }
+        Ok(())
+    }
+
+    fn untag_context(&mut self, id: ContextId, tags: &[&str]) -> SisterResult<()> {
+        if let Some(entry) = self.tags.lock().unwrap().get_mut(&id) {
+            entry.retain(|t| !tags.contains(&t.as_str()));
+        }
+        Ok(())
+    }
+
+    fn context_tags(&self, id: ContextId) -> SisterResult<Vec<String>> {
+        Ok(self
+            .tags
+            .lock()
+            .unwrap()
+            .get(&id)
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+impl Queryable for MockCodebase {
+    fn query(&self, query: Query) -> SisterResult<QueryResult> {
+        let start = Instant::now();
+        let ws_id = self.current_workspace();
+        let workspaces = self.workspaces.lock().unwrap();
+        let (_, symbols) = workspaces
+            .get(&ws_id)
+            .ok_or_else(|| SisterError::context_not_found(ws_id.to_string()))?;
+
+        let results: Vec<serde_json::Value> = match query.query_type.as_str() {
+            "list" => symbols
+                .iter()
+                .take(query.limit.unwrap_or(50))
+                .map(|(name, kind)| serde_json::json!({"name": name, "kind": kind}))
+                .collect(),
+            "search" => {
+                let text = query.get_string("text").unwrap_or_default().to_lowercase();
+                symbols
+                    .iter()
+                    .filter(|(name, _)| name.to_lowercase().contains(&text))
+                    .take(query.limit.unwrap_or(20))
+                    .map(|(name, kind)| serde_json::json!({"name": name, "kind": kind}))
+                    .collect()
+            }
+            _ => vec![],
+        };
+
+        Ok(QueryResult::new(query, results, start.elapsed()))
+    }
+
+    fn supports_query(&self, query_type: &str) -> bool {
+        matches!(query_type, "list" | "search" | "get")
+    }
+
+    fn query_types(&self) -> Vec<QueryTypeInfo> {
+        vec![
+            QueryTypeInfo::new("list", "List all code symbols"),
+            QueryTypeInfo::new("search", "Search symbols by name").required(vec!["text"]),
+        ]
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// MOCK IDENTITY — Session-based with receipts
+// ═══════════════════════════════════════════════════════════════════
+
+/// Identity: a mock receipt hash chain with watchers and compaction.
+pub struct MockIdentity {
+    start_time: Instant,
+    session_id: Mutex<Option<ContextId>>,
+    receipts: Mutex<Vec<Receipt>>,
+    chain_position: Mutex<u64>,
+    segments: Mutex<Vec<CompactedSegment>>,
+    watchers: ReceiptBroadcaster,
+}
+
+impl MockIdentity {
+    pub fn new(_config: SisterConfig) -> SisterResult<Self> {
+        Ok(Self {
+            start_time: Instant::now(),
+            session_id: Mutex::new(None),
+            receipts: Mutex::new(vec![]),
+            chain_position: Mutex::new(0),
+            segments: Mutex::new(vec![]),
+            watchers: ReceiptBroadcaster::default(),
+        })
+    }
+}
+
+impl Sister for MockIdentity {
+    const SISTER_TYPE: SisterType = SisterType::Identity;
+    const FILE_EXTENSION: &'static str = "aid";
+
+    fn init(config: SisterConfig) -> SisterResult<Self>
+    where
+        Self: Sized,
+    {
+        MockIdentity::new(config)
+    }
+
+    fn health(&self) -> HealthStatus {
+        HealthStatus {
+            healthy: true,
+            live: true,
+            ready: true,
+            status: Status::Ready,
+            uptime: self.start_time.elapsed(),
+            resources: ResourceUsage::default(),
+            warnings: vec![],
+            last_error: None,
+            components: vec![],
+        }
+    }
+
+    fn version(&self) -> Version {
+        Version::new(0, 2, 0)
+    }
+
+    fn shutdown(&mut self) -> SisterResult<()> {
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Vec<Capability> {
+        vec![
+            Capability::new("identity_create", "Create cryptographic identity"),
+            Capability::new("action_sign", "Sign actions with receipt chain"),
+            Capability::new("trust_grant", "Grant trust to other identities"),
+        ]
+    }
+}
+
+impl SessionManagement for MockIdentity {
+    fn start_session(&mut self, _name: &str) -> SisterResult<ContextId> {
+        let id = ContextId::new();
+        *self.session_id.lock().unwrap() = Some(id);
+        Ok(id)
+    }
+
+    fn end_session(&mut self) -> SisterResult<()> {
+        *self.session_id.lock().unwrap() = None;
+        Ok(())
+    }
+
+    fn current_session(&self) -> Option<ContextId> {
+        *self.session_id.lock().unwrap()
+    }
+
+    fn current_session_info(&self) -> SisterResult<ContextInfo> {
+        let id = self
+            .current_session()
+            .ok_or_else(|| SisterError::new(ErrorCode::InvalidState, "No active session"))?;
+        Ok(ContextInfo {
+            id,
+            name: "identity_session".to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            item_count: self.receipts.lock().unwrap().len(),
+            size_bytes: 0,
+            tags: Vec::new(),
+            metadata: Metadata::new(),
+            parent_id: None,
+            child_ids: Vec::new(),
+            quota: None,
+        })
+    }
+
+    fn list_sessions(&self) -> SisterResult<Vec<ContextSummary>> {
+        Ok(vec![])
+    }
+
+    fn export_session(&self, _id: ContextId) -> SisterResult<ContextSnapshot> {
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            "Identity does not support session export",
+        ))
+    }
+
+    fn import_session(&mut self, _snapshot: ContextSnapshot) -> SisterResult<ContextId> {
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            "Identity does not support session import",
+        ))
+    }
+}
+
+// Mock hash chain: position 0 is the genesis placeholder
+fn chain_hash(position: u64) -> String {
+    if position == 0 {
+        "0000000000000000".to_string()
+    } else {
+        format!("hash_{}", position)
+    }
+}
+
+impl ReceiptIntegration for MockIdentity {
+    fn create_receipt(&self, action: ActionRecord) -> SisterResult<ReceiptId> {
+        let receipt_id = ReceiptId::new();
+        let mut position = self.chain_position.lock().unwrap();
+        *position += 1;
+
+        let receipt = Receipt {
+            id: receipt_id,
+            action,
+            signature: "mock_ed25519_signature".to_string(),
+            chain_position: *position,
+            previous_hash: chain_hash(*position - 1),
+            hash: chain_hash(*position),
+            created_at: Utc::now(),
+            anchor: None,
+        };
+
+        self.watchers.publish(&receipt);
+        self.receipts.lock().unwrap().push(receipt);
+        Ok(receipt_id)
+    }
+
+    fn create_receipts(&self, actions: Vec<ActionRecord>) -> SisterResult<Vec<ReceiptId>> {
+        // Hold the position lock for the whole batch so it stays contiguous
+        let mut position = self.chain_position.lock().unwrap();
+        let mut receipts = self.receipts.lock().unwrap();
+        let ids = actions
+            .into_iter()
+            .map(|action| {
+                *position += 1;
+                let receipt = Receipt {
+                    id: ReceiptId::new(),
+                    action,
+                    signature: "mock_ed25519_signature".to_string(),
+                    chain_position: *position,
+                    previous_hash: chain_hash(*position - 1),
+                    hash: chain_hash(*position),
+                    created_at: Utc::now(),
+                    anchor: None,
+                };
+                let id = receipt.id;
+                self.watchers.publish(&receipt);
+                receipts.push(receipt);
+                id
+            })
+            .collect();
+        Ok(ids)
+    }
+
+    fn get_receipt(&self, id: ReceiptId) -> SisterResult<Receipt> {
+        self.receipts
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|r| r.id == id)
+            .cloned()
+            .ok_or_else(|| SisterError::not_found(format!("Receipt {}", id)))
+    }
+
+    fn list_receipts(&self, filter: ReceiptFilter) -> SisterResult<Vec<Receipt>> {
+        let receipts = self.receipts.lock().unwrap();
+        Ok(filter.apply(receipts.iter().cloned()))
+    }
+
+    fn compact_receipts(&self, before: DateTime<Utc>) -> SisterResult<Option<CompactedSegment>> {
+        let mut receipts = self.receipts.lock().unwrap();
+        let archived = compaction_prefix(&receipts, before);
+        if archived.is_empty() {
+            return Ok(None);
+        }
+        let segment = CompactedSegment::from_receipts(archived)?.signed("mock_segment_signature");
+        let count = archived.len();
+        receipts.drain(..count);
+        self.segments.lock().unwrap().push(segment.clone());
+        Ok(Some(segment))
+    }
+
+    fn compacted_segments(&self) -> SisterResult<Vec<CompactedSegment>> {
+        Ok(self.segments.lock().unwrap().clone())
+    }
+}
+
+impl ReceiptWatcher for MockIdentity {
+    fn subscribe_receipts(&self, filter: ReceiptFilter) -> ReceiptReceiver {
+        self.watchers.subscribe(filter)
+    }
+}
+
+impl Grounding for MockIdentity {
+    fn ground(&self, claim: &str) -> SisterResult<GroundingResult> {
+        let receipts = self.receipts.lock().unwrap();
+        let claim_lower = claim.to_lowercase();
+
+        let matches: Vec<_> = receipts
+            .iter()
+            .filter(|r| {
+                r.action.action_type.to_lowercase().contains(&claim_lower)
+                    || claim_lower.contains(&r.action.action_type.to_lowercase())
+            })
+            .collect();
+
+        if matches.is_empty() {
+            Ok(GroundingResult::ungrounded(claim, "No matching receipts"))
+        } else {
+            let evidence = matches
+                .iter()
+                .map(|r| GroundingEvidence::from(*r))
+                .collect();
+            Ok(GroundingResult::verified(claim, 0.9).with_evidence(evidence))
+        }
+    }
+
+    fn evidence(&self, query: &str, max_results: usize) -> SisterResult<Vec<EvidenceDetail>> {
+        let receipts = self.receipts.lock().unwrap();
+        let query_lower = query.to_lowercase();
+
+        Ok(receipts
+            .iter()
+            .filter(|r| r.action.action_type.to_lowercase().contains(&query_lower))
+            .take(max_results)
+            .map(Receipt::to_evidence_detail)
+            .collect())
+    }
+
+    fn suggest(&self, _query: &str, limit: usize) -> SisterResult<Vec<GroundingSuggestion>> {
+        let receipts = self.receipts.lock().unwrap();
+        Ok(receipts
+            .iter()
+            .take(limit)
+            .map(|r| GroundingSuggestion {
+                item_type: "receipt".to_string(),
+                id: r.id.to_string(),
+                relevance_score: 0.5,
+                description: format!("Action: {}", r.action.action_type),
+                data: Metadata::new(),
+            })
+            .collect())
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// MOCK TIME — Stateless sister (no sessions, no grounding)
+// ═══════════════════════════════════════════════════════════════════
+
+/// Time: stateless; answers `current_time` queries.
+pub struct MockTime {
+    start_time: Instant,
+}
+
+impl Sister for MockTime {
+    const SISTER_TYPE: SisterType = SisterType::Time;
+    const FILE_EXTENSION: &'static str = "atime";
+
+    fn init(_config: SisterConfig) -> SisterResult<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            start_time: Instant::now(),
+        })
+    }
+
+    fn health(&self) -> HealthStatus {
+        HealthStatus {
+            healthy: true,
+            live: true,
+            ready: true,
+            status: Status::Ready,
+            uptime: self.start_time.elapsed(),
+            resources: ResourceUsage::default(),
+            warnings: vec![],
+            last_error: None,
+            components: vec![],
+        }
+    }
+
+    fn version(&self) -> Version {
+        Version::new(0, 2, 0)
+    }
+
+    fn shutdown(&mut self) -> SisterResult<()> {
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Vec<Capability> {
+        vec![
+            Capability::new("time_now", "Get current time in any timezone"),
+            Capability::new("time_duration", "Calculate duration between events"),
+        ]
+    }
+}
+
+// Time is stateless — no SessionManagement, no WorkspaceManagement, no Grounding
+
+impl Queryable for MockTime {
+    fn query(&self, query: Query) -> SisterResult<QueryResult> {
+        let start = Instant::now();
+        let results = match query.query_type.as_str() {
+            "current_time" => {
+                vec![serde_json::json!({"time": Utc::now().to_rfc3339()})]
+            }
+            _ => vec![],
+        };
+        Ok(QueryResult::new(query, results, start.elapsed()))
+    }
+
+    fn supports_query(&self, query_type: &str) -> bool {
+        matches!(query_type, "current_time" | "duration")
+    }
+
+    fn query_types(&self) -> Vec<QueryTypeInfo> {
+        vec![QueryTypeInfo::new("current_time", "Get current UTC time")]
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// MOCK CONTRACT — Full-featured sister (sessions, grounding, queries, receipts, events)
+// ═══════════════════════════════════════════════════════════════════
+
+/// Contract: sessions of policies, plus receipts and events.
+pub struct MockContract {
+    start_time: Instant,
+    session_id: Mutex<Option<ContextId>>,
+    sessions: Mutex<Vec<ContextSummary>>,
+    events: EventManager,
+    policies: Mutex<Vec<(u64, String, String)>>, // (id, label, scope)
+    next_id: Mutex<u64>,
+    receipts: Mutex<Vec<Receipt>>,
+    chain_position: Mutex<u64>,
+}
+
+impl MockContract {
+    pub fn new(_config: SisterConfig) -> SisterResult<Self> {
+        Ok(Self {
+            start_time: Instant::now(),
+            session_id: Mutex::new(None),
+            sessions: Mutex::new(vec![]),
+            events: EventManager::new(256),
+            policies: Mutex::new(vec![]),
+            next_id: Mutex::new(1),
+            receipts: Mutex::new(vec![]),
+            chain_position: Mutex::new(0),
+        })
+    }
+
+    /// Add a policy rule, returning its ID.
+    pub fn add_policy(&self, label: &str, scope: &str) -> u64 {
+        let mut policies = self.policies.lock().unwrap();
+        let mut next = self.next_id.lock().unwrap();
+        let id = *next;
+        *next += 1;
+        policies.push((id, label.to_string(), scope.to_string()));
+        id
+    }
+}
+
+impl Sister for MockContract {
+    const SISTER_TYPE: SisterType = SisterType::Contract;
+    const FILE_EXTENSION: &'static str = "acon";
+
+    fn init(config: SisterConfig) -> SisterResult<Self>
+    where
+        Self: Sized,
+    {
+        MockContract::new(config)
+    }
+
+    fn health(&self) -> HealthStatus {
+        HealthStatus {
+            healthy: true,
+            live: true,
+            ready: true,
+            status: Status::Ready,
+            uptime: self.start_time.elapsed(),
+            resources: ResourceUsage::default(),
+            warnings: vec![],
+            last_error: None,
+            components: vec![],
+        }
+    }
+
+    fn version(&self) -> Version {
+        Version::new(0, 2, 0)
+    }
+
+    fn shutdown(&mut self) -> SisterResult<()> {
+        self.events
+            .emit(SisterEvent::shutting_down(SisterType::Contract));
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Vec<Capability> {
+        vec![
+            Capability::new("policy_add", "Add a policy rule governing agent behavior"),
+            Capability::new(
+                "policy_check",
+                "Check if an action is allowed under policies",
+            ),
+            Capability::new("risk_limit_set", "Set a risk limit threshold"),
+            Capability::new(
+                "approval_request",
+                "Request approval for a controlled action",
+            ),
+            Capability::new("violation_report", "Report a contract or policy violation"),
+        ]
+    }
+}
+
+impl SessionManagement for MockContract {
+    fn start_session(&mut self, name: &str) -> SisterResult<ContextId> {
+        let id = ContextId::new();
+        *self.session_id.lock().unwrap() = Some(id);
+
+        let summary = ContextSummary {
+            id,
+            name: name.to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            item_count: 0,
+            size_bytes: 0,
+            tags: Vec::new(),
+        };
+        self.sessions.lock().unwrap().push(summary);
+
+        self.events.emit(SisterEvent::context_created(
+            SisterType::Contract,
+            id,
+            name.to_string(),
+        ));
+        Ok(id)
+    }
+
+    fn end_session(&mut self) -> SisterResult<()> {
+        *self.session_id.lock().unwrap() = None;
+        Ok(())
+    }
+
+    fn current_session(&self) -> Option<ContextId> {
+        *self.session_id.lock().unwrap()
+    }
+
+    fn current_session_info(&self) -> SisterResult<ContextInfo> {
+        let id = self
+            .current_session()
+            .ok_or_else(|| SisterError::new(ErrorCode::InvalidState, "No active session"))?;
+
+        Ok(ContextInfo {
+            id,
+            name: "contract_session".to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            item_count: self.policies.lock().unwrap().len(),
+            size_bytes: 0,
+            tags: Vec::new(),
+            metadata: Metadata::new(),
+            parent_id: None,
+            child_ids: Vec::new(),
+            quota: None,
+        })
+    }
+
+    fn list_sessions(&self) -> SisterResult<Vec<ContextSummary>> {
+        Ok(self.sessions.lock().unwrap().clone())
+    }
+
+    fn export_session(&self, _id: ContextId) -> SisterResult<ContextSnapshot> {
+        let info = self.current_session_info()?;
+        let data = serde_json::to_vec(&self.policies.lock().unwrap().clone())
+            .map_err(|e| SisterError::new(ErrorCode::Internal, e.to_string()))?;
+        let checksum = *blake3::hash(&data).as_bytes();
+
+        Ok(ContextSnapshot {
+            snapshot_format: SNAPSHOT_FORMAT,
+            sister_type: SisterType::Contract,
+            version: Version::new(0, 2, 0),
+            context_info: info,
+            data,
+            checksum,
+            snapshot_at: Utc::now(),
+            compression: Default::default(),
+            signer: None,
+            signature: None,
+        })
+    }
+
+    fn import_session(&mut self, snapshot: ContextSnapshot) -> SisterResult<ContextId> {
+        if !snapshot.verify()? {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                "Snapshot checksum verification failed",
+            ));
+        }
+        self.start_session(&snapshot.context_info.name)
+    }
+}
+
+impl Grounding for MockContract {
+    fn ground(&self, claim: &str) -> SisterResult<GroundingResult> {
+        let policies = self.policies.lock().unwrap();
+        let claim_lower = claim.to_lowercase();
+
+        let matches: Vec<_> = policies
+            .iter()
+            .filter(|(_, label, _)| label.to_lowercase().contains(&claim_lower))
+            .collect();
+
+        if matches.is_empty() {
+            Ok(
+                GroundingResult::ungrounded(claim, "No matching policies found")
+                    .with_suggestions(policies.iter().take(3).map(|(_, l, _)| l.clone()).collect()),
+            )
+        } else {
+            let best_score = matches
+                .iter()
+                .map(|(_, label, _)| {
+                    let claim_words: Vec<&str> = claim_lower.split_whitespace().collect();
+                    let label_lower = label.to_lowercase();
+                    let matched = claim_words
+                        .iter()
+                        .filter(|w| label_lower.contains(**w))
+                        .count();
+                    matched as f64 / claim_words.len().max(1) as f64
+                })
+                .fold(0.0f64, |a, b| a.max(b));
+
+            let evidence = matches
+                .iter()
+                .map(|(id, label, scope)| {
+                    GroundingEvidence::new(
+                        "policy",
+                        format!("policy_{}", id),
+                        best_score,
+                        format!("{} [{}]", label, scope),
+                    )
+                })
+                .collect();
+
+            if best_score > 0.5 {
+                Ok(GroundingResult::verified(claim, best_score)
+                    .with_evidence(evidence)
+                    .with_reason("Found matching policies"))
+            } else {
+                Ok(GroundingResult::partial(claim, best_score)
+                    .with_evidence(evidence)
+                    .with_reason("Some policy evidence found"))
+            }
+        }
+    }
+
+    fn evidence(&self, query: &str, max_results: usize) -> SisterResult<Vec<EvidenceDetail>> {
+        let policies = self.policies.lock().unwrap();
+        let query_lower = query.to_lowercase();
+
+        Ok(policies
+            .iter()
+            .filter(|(_, label, _)| label.to_lowercase().contains(&query_lower))
+            .take(max_results)
+            .map(|(id, label, scope)| EvidenceDetail {
+                evidence_type: "policy".to_string(),
+                id: format!("policy_{}", id),
+                score: 0.8,
+                created_at: Utc::now(),
+                source_sister: SisterType::Contract,
+                content: format!("{} [{}]", label, scope),
+                data: Metadata::new(),
+            })
+            .collect())
+    }
+
+    fn suggest(&self, _query: &str, limit: usize) -> SisterResult<Vec<GroundingSuggestion>> {
+        let policies = self.policies.lock().unwrap();
+
+        Ok(policies
+            .iter()
+            .take(limit)
+            .map(|(id, label, scope)| GroundingSuggestion {
+                item_type: "policy".to_string(),
+                id: format!("policy_{}", id),
+                relevance_score: 0.5,
+                description: format!("{} [{}]", label, scope),
+                data: Metadata::new(),
+            })
+            .collect())
+    }
+}
+
+impl Queryable for MockContract {
+    fn query(&self, query: Query) -> SisterResult<QueryResult> {
+        let start = Instant::now();
+        let policies = self.policies.lock().unwrap();
+
+        let results: Vec<serde_json::Value> = match query.query_type.as_str() {
+            "list" => policies
+                .iter()
+                .skip(query.offset.unwrap_or(0))
+                .take(query.limit.unwrap_or(20))
+                .map(|(id, label, scope)| {
+                    serde_json::json!({"id": id, "label": label, "scope": scope})
+                })
+                .collect(),
+            "search" => {
+                let text = query.get_string("text").unwrap_or_default().to_lowercase();
+                policies
+                    .iter()
+                    .filter(|(_, label, _)| label.to_lowercase().contains(&text))
+                    .take(query.limit.unwrap_or(20))
+                    .map(|(id, label, scope)| {
+                        serde_json::json!({"id": id, "label": label, "scope": scope})
+                    })
+                    .collect()
+            }
+            "recent" => policies
+                .iter()
+                .rev()
+                .take(query.limit.unwrap_or(10))
+                .map(|(id, label, scope)| {
+                    serde_json::json!({"id": id, "label": label, "scope": scope})
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        Ok(QueryResult::new(query, results, start.elapsed()))
+    }
+
+    fn supports_query(&self, query_type: &str) -> bool {
+        matches!(query_type, "list" | "search" | "recent" | "get")
+    }
+
+    fn query_types(&self) -> Vec<QueryTypeInfo> {
+        vec![
+            QueryTypeInfo::new("list", "List all policies"),
+            QueryTypeInfo::new("search", "Search policies by label").required(vec!["text"]),
+            QueryTypeInfo::new("recent", "Get most recent policies"),
+        ]
+    }
+}
+
+impl ReceiptIntegration for MockContract {
+    fn create_receipt(&self, action: ActionRecord) -> SisterResult<ReceiptId> {
+        let receipt_id = ReceiptId::new();
+        let mut position = self.chain_position.lock().unwrap();
+        *position += 1;
+
+        let receipt = Receipt {
+            id: receipt_id,
+            action,
+            signature: "mock_ed25519_signature".to_string(),
+            chain_position: *position,
+            previous_hash: "0000000000000000".to_string(),
+            hash: format!("hash_{}", position),
+            created_at: Utc::now(),
+            anchor: None,
+        };
+
+        self.receipts.lock().unwrap().push(receipt);
+        Ok(receipt_id)
+    }
+
+    fn get_receipt(&self, id: ReceiptId) -> SisterResult<Receipt> {
+        self.receipts
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|r| r.id == id)
+            .cloned()
+            .ok_or_else(|| SisterError::not_found(format!("Receipt {}", id)))
+    }
+
+    fn list_receipts(&self, filter: ReceiptFilter) -> SisterResult<Vec<Receipt>> {
+        let receipts = self.receipts.lock().unwrap();
+        Ok(filter.apply(receipts.iter().cloned()))
+    }
+}
+
+impl EventEmitter for MockContract {
+    fn subscribe(&self, _filter: EventFilter) -> EventReceiver {
+        self.events.subscribe()
+    }
+
+    fn recent_events(&self, limit: usize) -> Vec<SisterEvent> {
+        self.events.recent(limit)
+    }
+
+    fn emit(&self, event: SisterEvent) {
+        self.events.emit(event);
+    }
+}
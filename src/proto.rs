@@ -0,0 +1,973 @@
+//! Protobuf wire types for a gRPC Hydra transport (`proto` feature).
+//!
+//! The messages mirror `proto/agentic/sdk/v1/sdk.proto` (shipped as
+//! [`PROTO`]) and are declared with `prost` derives rather than generated
+//! at build time, so building the SDK never needs `protoc`. Keep the two
+//! in sync: same field names, tags, and types.
+//!
+//! Each DTO converts with `From` (SDK → proto) and `TryFrom` (proto →
+//! SDK). The reverse direction fails with `InvalidInput` on malformed
+//! IDs, JSON, timestamps, or unknown sister bytes.
+//!
+//! ```rust,ignore
+//! let wire = proto::Query::from(Query::search("auth").limit(10));
+//! let bytes = prost::Message::encode_to_vec(&wire);
+//! let query = Query::try_from(proto::Query::decode(bytes.as_slice())?)?;
+//! ```
+
+use crate::codec::Codec;
+use crate::context::ContextId;
+use crate::errors::{SisterError, SisterResult};
+use crate::events::{EventId, EventType};
+use crate::grounding;
+use crate::hydra;
+use crate::query;
+use crate::receipts::{self, ReceiptId};
+use crate::types::{Metadata, SisterType, UniqueId};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// The `.proto` source these messages mirror.
+pub const PROTO: &str = include_str!("../proto/agentic/sdk/v1/sdk.proto");
+
+/// Protobuf package of the messages.
+pub const PACKAGE: &str = "agentic.sdk.v1";
+
+pub use prost_types::Timestamp;
+
+// ═══════════════════════════════════════════════════════════════════
+// MESSAGES
+// ═══════════════════════════════════════════════════════════════════
+
+/// Distinguishes "no list" from an empty list.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ContextIds {
+    #[prost(string, repeated, tag = "1")]
+    pub ids: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Query {
+    #[prost(string, tag = "1")]
+    pub query_type: String,
+    /// Values are JSON
+    #[prost(map = "string, string", tag = "2")]
+    pub params: HashMap<String, String>,
+    #[prost(uint64, optional, tag = "3")]
+    pub limit: Option<u64>,
+    #[prost(uint64, optional, tag = "4")]
+    pub offset: Option<u64>,
+    #[prost(string, optional, tag = "5")]
+    pub context_id: Option<String>,
+    #[prost(message, optional, tag = "6")]
+    pub context_ids: Option<ContextIds>,
+    #[prost(bool, tag = "7")]
+    pub merge_results: bool,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryResult {
+    #[prost(message, optional, tag = "1")]
+    pub query: Option<Query>,
+    /// Each result is JSON
+    #[prost(string, repeated, tag = "2")]
+    pub results: Vec<String>,
+    #[prost(uint64, optional, tag = "3")]
+    pub total_count: Option<u64>,
+    #[prost(bool, tag = "4")]
+    pub has_more: bool,
+    #[prost(uint64, tag = "5")]
+    pub query_time_ms: u64,
+    #[prost(message, optional, tag = "6")]
+    pub queried_contexts: Option<ContextIds>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum GroundingStatus {
+    Unspecified = 0,
+    Verified = 1,
+    Partial = 2,
+    Ungrounded = 3,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GroundingEvidence {
+    #[prost(string, tag = "1")]
+    pub evidence_type: String,
+    #[prost(string, tag = "2")]
+    pub id: String,
+    #[prost(double, tag = "3")]
+    pub score: f64,
+    #[prost(string, tag = "4")]
+    pub summary: String,
+    /// Values are JSON
+    #[prost(map = "string, string", tag = "5")]
+    pub data: HashMap<String, String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GroundingResult {
+    #[prost(enumeration = "GroundingStatus", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub claim: String,
+    #[prost(double, tag = "3")]
+    pub confidence: f64,
+    #[prost(message, repeated, tag = "4")]
+    pub evidence: Vec<GroundingEvidence>,
+    #[prost(string, tag = "5")]
+    pub reason: String,
+    #[prost(string, repeated, tag = "6")]
+    pub suggestions: Vec<String>,
+    #[prost(message, optional, tag = "7")]
+    pub timestamp: Option<Timestamp>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SisterEvent {
+    #[prost(string, tag = "1")]
+    pub id: String,
+    #[prost(uint32, tag = "2")]
+    pub sister_type: u32,
+    /// serde tag of the event ("ready", "context_created", ...)
+    #[prost(string, tag = "3")]
+    pub event_type: String,
+    /// JSON object with the event's fields; empty for field-less events
+    #[prost(string, tag = "4")]
+    pub payload: String,
+    #[prost(message, optional, tag = "5")]
+    pub timestamp: Option<Timestamp>,
+    #[prost(string, optional, tag = "6")]
+    pub context_id: Option<String>,
+}
+
+/// Nested messages of `ActionOutcome`.
+pub mod action_outcome {
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Success {
+        /// JSON
+        #[prost(string, optional, tag = "1")]
+        pub result: Option<String>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Failure {
+        #[prost(string, tag = "1")]
+        pub error_code: String,
+        #[prost(string, tag = "2")]
+        pub error_message: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Partial {
+        /// JSON
+        #[prost(string, optional, tag = "1")]
+        pub result: Option<String>,
+        #[prost(string, repeated, tag = "2")]
+        pub warnings: Vec<String>,
+        /// JSON `ErrorGroup`
+        #[prost(string, optional, tag = "3")]
+        pub errors: Option<String>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Outcome {
+        #[prost(message, tag = "1")]
+        Success(Success),
+        #[prost(message, tag = "2")]
+        Failure(Failure),
+        #[prost(message, tag = "3")]
+        Partial(Partial),
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ActionOutcome {
+    #[prost(oneof = "action_outcome::Outcome", tags = "1, 2, 3")]
+    pub outcome: Option<action_outcome::Outcome>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ActionRecord {
+    #[prost(uint32, tag = "1")]
+    pub sister_type: u32,
+    #[prost(string, tag = "2")]
+    pub action_type: String,
+    /// Values are JSON
+    #[prost(map = "string, string", tag = "3")]
+    pub parameters: HashMap<String, String>,
+    #[prost(message, optional, tag = "4")]
+    pub outcome: Option<ActionOutcome>,
+    #[prost(string, repeated, tag = "5")]
+    pub evidence_ids: Vec<String>,
+    #[prost(string, optional, tag = "6")]
+    pub context_id: Option<String>,
+    #[prost(string, optional, tag = "7")]
+    pub run_id: Option<String>,
+    #[prost(uint64, optional, tag = "8")]
+    pub step_id: Option<u64>,
+    #[prost(message, optional, tag = "9")]
+    pub timestamp: Option<Timestamp>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReceiptAnchor {
+    #[prost(string, tag = "1")]
+    pub provider: String,
+    #[prost(string, tag = "2")]
+    pub anchored_hash: String,
+    #[prost(uint64, tag = "3")]
+    pub chain_position: u64,
+    #[prost(message, optional, tag = "4")]
+    pub anchored_at: Option<Timestamp>,
+    #[prost(string, tag = "5")]
+    pub proof: String,
+    /// Values are JSON
+    #[prost(map = "string, string", tag = "6")]
+    pub metadata: HashMap<String, String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Receipt {
+    #[prost(string, tag = "1")]
+    pub id: String,
+    #[prost(message, optional, tag = "2")]
+    pub action: Option<ActionRecord>,
+    #[prost(string, tag = "3")]
+    pub signature: String,
+    #[prost(uint64, tag = "4")]
+    pub chain_position: u64,
+    #[prost(string, tag = "5")]
+    pub previous_hash: String,
+    #[prost(string, tag = "6")]
+    pub hash: String,
+    #[prost(message, optional, tag = "7")]
+    pub created_at: Option<Timestamp>,
+    #[prost(message, optional, tag = "8")]
+    pub anchor: Option<ReceiptAnchor>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum Priority {
+    /// Reads as `Normal`
+    Unspecified = 0,
+    Low = 1,
+    Normal = 2,
+    High = 3,
+    Critical = 4,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Budget {
+    #[prost(uint64, optional, tag = "1")]
+    pub max_tokens: Option<u64>,
+    #[prost(double, optional, tag = "2")]
+    pub max_cost_usd: Option<f64>,
+    #[prost(uint64, optional, tag = "3")]
+    pub max_duration_ms: Option<u64>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct HydraCommand {
+    #[prost(string, tag = "1")]
+    pub command_type: String,
+    /// Values are JSON
+    #[prost(map = "string, string", tag = "2")]
+    pub params: HashMap<String, String>,
+    #[prost(string, tag = "3")]
+    pub run_id: String,
+    #[prost(uint64, tag = "4")]
+    pub step_id: u64,
+    #[prost(message, optional, tag = "5")]
+    pub budget: Option<Budget>,
+    #[prost(bool, tag = "6")]
+    pub dry_run: bool,
+    #[prost(string, optional, tag = "7")]
+    pub idempotency_key: Option<String>,
+    #[prost(enumeration = "Priority", tag = "8")]
+    pub priority: i32,
+    #[prost(message, optional, tag = "9")]
+    pub deadline: Option<Timestamp>,
+    #[prost(bool, tag = "10")]
+    pub exclusive: bool,
+    /// Content types the caller can read the result in, most preferred first
+    #[prost(string, repeated, tag = "11")]
+    pub accept: Vec<String>,
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// FIELD HELPERS
+// ═══════════════════════════════════════════════════════════════════
+
+fn invalid(field: &str, reason: impl std::fmt::Display) -> SisterError {
+    SisterError::invalid_input(format!("Invalid protobuf field {}: {}", field, reason))
+        .with_context("field", field)
+}
+
+fn missing(field: &str) -> SisterError {
+    invalid(field, "missing")
+}
+
+fn json_text(value: &serde_json::Value) -> String {
+    value.to_string()
+}
+
+fn json_value(field: &str, text: &str) -> SisterResult<serde_json::Value> {
+    serde_json::from_str(text).map_err(|e| invalid(field, e))
+}
+
+fn metadata_to_proto(metadata: &Metadata) -> HashMap<String, String> {
+    metadata
+        .iter()
+        .map(|(k, v)| (k.clone(), json_text(v)))
+        .collect()
+}
+
+fn metadata_from_proto(field: &str, map: HashMap<String, String>) -> SisterResult<Metadata> {
+    map.into_iter()
+        .map(|(k, v)| Ok((k, json_value(field, &v)?)))
+        .collect()
+}
+
+fn timestamp(at: DateTime<Utc>) -> Timestamp {
+    Timestamp {
+        seconds: at.timestamp(),
+        nanos: at.timestamp_subsec_nanos() as i32,
+    }
+}
+
+fn datetime(field: &str, ts: Option<Timestamp>) -> SisterResult<DateTime<Utc>> {
+    let ts = ts.ok_or_else(|| missing(field))?;
+    u32::try_from(ts.nanos)
+        .ok()
+        .and_then(|nanos| DateTime::from_timestamp(ts.seconds, nanos))
+        .ok_or_else(|| {
+            invalid(
+                field,
+                format!("timestamp {}.{} out of range", ts.seconds, ts.nanos),
+            )
+        })
+}
+
+fn sister_type(field: &str, byte: u32) -> SisterResult<SisterType> {
+    u8::try_from(byte)
+        .ok()
+        .and_then(SisterType::from_byte)
+        .ok_or_else(|| invalid(field, format!("unknown sister type {:#04x}", byte)))
+}
+
+fn unique_id(field: &str, text: &str) -> SisterResult<UniqueId> {
+    UniqueId::parse(text).map_err(|e| invalid(field, e.message))
+}
+
+fn context_id(field: &str, text: &str) -> SisterResult<ContextId> {
+    unique_id(field, text).map(ContextId)
+}
+
+fn context_ids_to_proto(ids: Vec<ContextId>) -> ContextIds {
+    ContextIds {
+        ids: ids.into_iter().map(|id| id.0.to_string()).collect(),
+    }
+}
+
+fn context_ids_from_proto(field: &str, ids: ContextIds) -> SisterResult<Vec<ContextId>> {
+    ids.ids.iter().map(|id| context_id(field, id)).collect()
+}
+
+fn usize_from(field: &str, value: u64) -> SisterResult<usize> {
+    usize::try_from(value).map_err(|e| invalid(field, e))
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// CONVERSIONS
+// ═══════════════════════════════════════════════════════════════════
+
+impl From<query::Query> for Query {
+    fn from(q: query::Query) -> Self {
+        Self {
+            query_type: q.query_type,
+            params: metadata_to_proto(&q.params),
+            limit: q.limit.map(|n| n as u64),
+            offset: q.offset.map(|n| n as u64),
+            context_id: q.context_id.map(|id| id.0.to_string()),
+            context_ids: q.context_ids.map(context_ids_to_proto),
+            merge_results: q.merge_results,
+        }
+    }
+}
+
+impl TryFrom<Query> for query::Query {
+    type Error = SisterError;
+
+    fn try_from(q: Query) -> SisterResult<Self> {
+        Ok(Self {
+            query_type: q.query_type,
+            params: metadata_from_proto("params", q.params)?,
+            limit: q.limit.map(|n| usize_from("limit", n)).transpose()?,
+            offset: q.offset.map(|n| usize_from("offset", n)).transpose()?,
+            context_id: q
+                .context_id
+                .map(|id| context_id("context_id", &id))
+                .transpose()?,
+            context_ids: q
+                .context_ids
+                .map(|ids| context_ids_from_proto("context_ids", ids))
+                .transpose()?,
+            merge_results: q.merge_results,
+        })
+    }
+}
+
+impl From<query::QueryResult> for QueryResult {
+    fn from(r: query::QueryResult) -> Self {
+        Self {
+            query: Some(r.query.into()),
+            results: r.results.iter().map(json_text).collect(),
+            total_count: r.total_count.map(|n| n as u64),
+            has_more: r.has_more,
+            query_time_ms: r.query_time.as_millis() as u64,
+            queried_contexts: r.queried_contexts.map(context_ids_to_proto),
+        }
+    }
+}
+
+impl TryFrom<QueryResult> for query::QueryResult {
+    type Error = SisterError;
+
+    fn try_from(r: QueryResult) -> SisterResult<Self> {
+        Ok(Self {
+            query: r.query.ok_or_else(|| missing("query"))?.try_into()?,
+            results: r
+                .results
+                .iter()
+                .map(|text| json_value("results", text))
+                .collect::<SisterResult<_>>()?,
+            total_count: r
+                .total_count
+                .map(|n| usize_from("total_count", n))
+                .transpose()?,
+            has_more: r.has_more,
+            query_time: Duration::from_millis(r.query_time_ms),
+            queried_contexts: r
+                .queried_contexts
+                .map(|ids| context_ids_from_proto("queried_contexts", ids))
+                .transpose()?,
+        })
+    }
+}
+
+impl From<grounding::GroundingStatus> for GroundingStatus {
+    fn from(status: grounding::GroundingStatus) -> Self {
+        match status {
+            grounding::GroundingStatus::Verified => Self::Verified,
+            grounding::GroundingStatus::Partial => Self::Partial,
+            grounding::GroundingStatus::Ungrounded => Self::Ungrounded,
+        }
+    }
+}
+
+impl From<grounding::GroundingEvidence> for GroundingEvidence {
+    fn from(e: grounding::GroundingEvidence) -> Self {
+        Self {
+            data: metadata_to_proto(&e.data),
+            evidence_type: e.evidence_type,
+            id: e.id,
+            score: e.score,
+            summary: e.summary,
+        }
+    }
+}
+
+impl TryFrom<GroundingEvidence> for grounding::GroundingEvidence {
+    type Error = SisterError;
+
+    fn try_from(e: GroundingEvidence) -> SisterResult<Self> {
+        Ok(Self {
+            evidence_type: e.evidence_type,
+            id: e.id,
+            score: e.score,
+            summary: e.summary,
+            data: metadata_from_proto("evidence.data", e.data)?,
+        })
+    }
+}
+
+impl From<grounding::GroundingResult> for GroundingResult {
+    fn from(r: grounding::GroundingResult) -> Self {
+        Self {
+            status: GroundingStatus::from(r.status) as i32,
+            claim: r.claim,
+            confidence: r.confidence,
+            evidence: r.evidence.into_iter().map(Into::into).collect(),
+            reason: r.reason,
+            suggestions: r.suggestions,
+            timestamp: Some(timestamp(r.timestamp)),
+        }
+    }
+}
+
+impl TryFrom<GroundingResult> for grounding::GroundingResult {
+    type Error = SisterError;
+
+    fn try_from(r: GroundingResult) -> SisterResult<Self> {
+        let status = match GroundingStatus::try_from(r.status) {
+            Ok(GroundingStatus::Verified) => grounding::GroundingStatus::Verified,
+            Ok(GroundingStatus::Partial) => grounding::GroundingStatus::Partial,
+            Ok(GroundingStatus::Ungrounded) => grounding::GroundingStatus::Ungrounded,
+            _ => return Err(invalid("status", format!("unknown status {}", r.status))),
+        };
+        Ok(Self {
+            status,
+            claim: r.claim,
+            confidence: r.confidence,
+            evidence: r
+                .evidence
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<SisterResult<_>>()?,
+            reason: r.reason,
+            suggestions: r.suggestions,
+            timestamp: datetime("timestamp", r.timestamp)?,
+        })
+    }
+}
+
+impl From<crate::events::SisterEvent> for SisterEvent {
+    fn from(e: crate::events::SisterEvent) -> Self {
+        // The serde form is `{"event_type": tag, ...fields}`; split the tag off.
+        let mut fields = match serde_json::to_value(&e.event_type) {
+            Ok(serde_json::Value::Object(fields)) => fields,
+            _ => serde_json::Map::new(),
+        };
+        let event_type = match fields.remove("event_type") {
+            Some(serde_json::Value::String(tag)) => tag,
+            _ => String::new(),
+        };
+        let payload = if fields.is_empty() {
+            String::new()
+        } else {
+            json_text(&serde_json::Value::Object(fields))
+        };
+        Self {
+            id: e.id.0.to_string(),
+            sister_type: e.sister_type.to_byte() as u32,
+            event_type,
+            payload,
+            timestamp: Some(timestamp(e.timestamp)),
+            context_id: e.context_id.map(|id| id.0.to_string()),
+        }
+    }
+}
+
+impl TryFrom<SisterEvent> for crate::events::SisterEvent {
+    type Error = SisterError;
+
+    fn try_from(e: SisterEvent) -> SisterResult<Self> {
+        let mut fields = match e.payload.as_str() {
+            "" => serde_json::Map::new(),
+            text => match json_value("payload", text)? {
+                serde_json::Value::Object(fields) => fields,
+                _ => return Err(invalid("payload", "not a JSON object")),
+            },
+        };
+        fields.insert("event_type".into(), e.event_type.into());
+        let event_type: EventType =
+            serde_json::from_value(fields.into()).map_err(|err| invalid("event_type", err))?;
+        Ok(Self {
+            id: EventId(unique_id("id", &e.id)?),
+            sister_type: sister_type("sister_type", e.sister_type)?,
+            event_type,
+            timestamp: datetime("timestamp", e.timestamp)?,
+            context_id: e
+                .context_id
+                .map(|id| context_id("context_id", &id))
+                .transpose()?,
+        })
+    }
+}
+
+impl From<receipts::ActionOutcome> for ActionOutcome {
+    fn from(outcome: receipts::ActionOutcome) -> Self {
+        use action_outcome::{Failure, Outcome, Partial, Success};
+        let outcome = match outcome {
+            receipts::ActionOutcome::Success { result } => Outcome::Success(Success {
+                result: result.as_ref().map(json_text),
+            }),
+            receipts::ActionOutcome::Failure {
+                error_code,
+                error_message,
+            } => Outcome::Failure(Failure {
+                error_code,
+                error_message,
+            }),
+            receipts::ActionOutcome::Partial {
+                result,
+                warnings,
+                errors,
+            } => Outcome::Partial(Partial {
+                result: result.as_ref().map(json_text),
+                warnings,
+                errors: errors.and_then(|group| serde_json::to_string(&group).ok()),
+            }),
+        };
+        Self {
+            outcome: Some(outcome),
+        }
+    }
+}
+
+impl TryFrom<ActionOutcome> for receipts::ActionOutcome {
+    type Error = SisterError;
+
+    fn try_from(outcome: ActionOutcome) -> SisterResult<Self> {
+        use action_outcome::Outcome;
+        let result = |text: Option<String>| {
+            text.map(|text| json_value("outcome.result", &text))
+                .transpose()
+        };
+        Ok(match outcome.outcome.ok_or_else(|| missing("outcome"))? {
+            Outcome::Success(s) => Self::Success {
+                result: result(s.result)?,
+            },
+            Outcome::Failure(f) => Self::Failure {
+                error_code: f.error_code,
+                error_message: f.error_message,
+            },
+            Outcome::Partial(p) => Self::Partial {
+                result: result(p.result)?,
+                warnings: p.warnings,
+                errors: p
+                    .errors
+                    .map(|text| {
+                        serde_json::from_str(&text).map_err(|e| invalid("outcome.errors", e))
+                    })
+                    .transpose()?,
+            },
+        })
+    }
+}
+
+impl From<receipts::ActionRecord> for ActionRecord {
+    fn from(a: receipts::ActionRecord) -> Self {
+        Self {
+            sister_type: a.sister_type.to_byte() as u32,
+            parameters: metadata_to_proto(&a.parameters),
+            action_type: a.action_type,
+            outcome: Some(a.outcome.into()),
+            evidence_ids: a.evidence_ids,
+            context_id: a.context_id.map(|id| id.0.to_string()),
+            run_id: a.run_id,
+            step_id: a.step_id,
+            timestamp: Some(timestamp(a.timestamp)),
+        }
+    }
+}
+
+impl TryFrom<ActionRecord> for receipts::ActionRecord {
+    type Error = SisterError;
+
+    fn try_from(a: ActionRecord) -> SisterResult<Self> {
+        Ok(Self {
+            sister_type: sister_type("action.sister_type", a.sister_type)?,
+            action_type: a.action_type,
+            parameters: metadata_from_proto("action.parameters", a.parameters)?,
+            outcome: a.outcome.ok_or_else(|| missing("outcome"))?.try_into()?,
+            evidence_ids: a.evidence_ids,
+            context_id: a
+                .context_id
+                .map(|id| context_id("action.context_id", &id))
+                .transpose()?,
+            run_id: a.run_id,
+            step_id: a.step_id,
+            timestamp: datetime("action.timestamp", a.timestamp)?,
+        })
+    }
+}
+
+impl From<receipts::ReceiptAnchor> for ReceiptAnchor {
+    fn from(a: receipts::ReceiptAnchor) -> Self {
+        Self {
+            metadata: metadata_to_proto(&a.metadata),
+            provider: a.provider,
+            anchored_hash: a.anchored_hash,
+            chain_position: a.chain_position,
+            anchored_at: Some(timestamp(a.anchored_at)),
+            proof: a.proof,
+        }
+    }
+}
+
+impl TryFrom<ReceiptAnchor> for receipts::ReceiptAnchor {
+    type Error = SisterError;
+
+    fn try_from(a: ReceiptAnchor) -> SisterResult<Self> {
+        Ok(Self {
+            provider: a.provider,
+            anchored_hash: a.anchored_hash,
+            chain_position: a.chain_position,
+            anchored_at: datetime("anchor.anchored_at", a.anchored_at)?,
+            proof: a.proof,
+            metadata: metadata_from_proto("anchor.metadata", a.metadata)?,
+        })
+    }
+}
+
+impl From<receipts::Receipt> for Receipt {
+    fn from(r: receipts::Receipt) -> Self {
+        Self {
+            id: r.id.0.to_string(),
+            action: Some(r.action.into()),
+            signature: r.signature,
+            chain_position: r.chain_position,
+            previous_hash: r.previous_hash,
+            hash: r.hash,
+            created_at: Some(timestamp(r.created_at)),
+            anchor: r.anchor.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<Receipt> for receipts::Receipt {
+    type Error = SisterError;
+
+    fn try_from(r: Receipt) -> SisterResult<Self> {
+        Ok(Self {
+            id: ReceiptId(unique_id("id", &r.id)?),
+            action: r.action.ok_or_else(|| missing("action"))?.try_into()?,
+            signature: r.signature,
+            chain_position: r.chain_position,
+            previous_hash: r.previous_hash,
+            hash: r.hash,
+            created_at: datetime("created_at", r.created_at)?,
+            anchor: r.anchor.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl From<hydra::Priority> for Priority {
+    fn from(priority: hydra::Priority) -> Self {
+        match priority {
+            hydra::Priority::Low => Self::Low,
+            hydra::Priority::Normal => Self::Normal,
+            hydra::Priority::High => Self::High,
+            hydra::Priority::Critical => Self::Critical,
+        }
+    }
+}
+
+impl From<hydra::Budget> for Budget {
+    fn from(b: hydra::Budget) -> Self {
+        Self {
+            max_tokens: b.max_tokens,
+            max_cost_usd: b.max_cost_usd,
+            max_duration_ms: b.max_duration.map(|d| d.as_millis() as u64),
+        }
+    }
+}
+
+impl From<Budget> for hydra::Budget {
+    fn from(b: Budget) -> Self {
+        Self {
+            max_tokens: b.max_tokens,
+            max_cost_usd: b.max_cost_usd,
+            max_duration: b.max_duration_ms.map(Duration::from_millis),
+        }
+    }
+}
+
+impl From<hydra::HydraCommand> for HydraCommand {
+    fn from(c: hydra::HydraCommand) -> Self {
+        Self {
+            params: metadata_to_proto(&c.params),
+            command_type: c.command_type,
+            run_id: c.run_id,
+            step_id: c.step_id,
+            budget: c.budget.map(Into::into),
+            dry_run: c.dry_run,
+            idempotency_key: c.idempotency_key,
+            priority: Priority::from(c.priority) as i32,
+            deadline: c.deadline.map(timestamp),
+            exclusive: c.exclusive,
+            accept: c
+                .accept
+                .iter()
+                .map(|codec| codec.content_type().to_string())
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<HydraCommand> for hydra::HydraCommand {
+    type Error = SisterError;
+
+    fn try_from(c: HydraCommand) -> SisterResult<Self> {
+        let priority = match Priority::try_from(c.priority) {
+            Ok(Priority::Unspecified | Priority::Normal) => hydra::Priority::Normal,
+            Ok(Priority::Low) => hydra::Priority::Low,
+            Ok(Priority::High) => hydra::Priority::High,
+            Ok(Priority::Critical) => hydra::Priority::Critical,
+            Err(_) => {
+                return Err(invalid(
+                    "priority",
+                    format!("unknown priority {}", c.priority),
+                ))
+            }
+        };
+        Ok(Self {
+            command_type: c.command_type,
+            params: metadata_from_proto("params", c.params)?,
+            run_id: c.run_id,
+            step_id: c.step_id,
+            budget: c.budget.map(Into::into),
+            dry_run: c.dry_run,
+            idempotency_key: c.idempotency_key,
+            priority,
+            deadline: c
+                .deadline
+                .map(|ts| datetime("deadline", Some(ts)))
+                .transpose()?,
+            exclusive: c.exclusive,
+            // Hints only: skip content types this SDK doesn't know
+            accept: c
+                .accept
+                .iter()
+                .filter_map(|mime| Codec::from_content_type(mime))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::{ErrorCode, ErrorGroup};
+    use prost::Message;
+
+    /// Encode, decode, convert back.
+    fn round_trip<T, P>(value: T) -> T
+    where
+        P: From<T> + Message + Default,
+        T: TryFrom<P, Error = SisterError>,
+    {
+        let bytes = P::from(value).encode_to_vec();
+        T::try_from(P::decode(bytes.as_slice()).unwrap()).unwrap()
+    }
+
+    fn json<T: serde::Serialize>(value: &T) -> serde_json::Value {
+        serde_json::to_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_query_round_trip() {
+        let q = query::Query::search("auth flow")
+            .param("depth", 3)
+            .param("filters", serde_json::json!({"kind": ["fn", "struct"]}))
+            .limit(10)
+            .offset(20)
+            .in_contexts(vec![ContextId::new(), ContextId::new()]);
+        assert_eq!(json(&round_trip::<_, Query>(q.clone())), json(&q));
+
+        let r = query::QueryResult::new(
+            q,
+            vec![serde_json::json!({"id": 1}), serde_json::json!("two")],
+            Duration::from_millis(42),
+        )
+        .with_pagination(50, true);
+        assert_eq!(json(&round_trip::<_, QueryResult>(r.clone())), json(&r));
+    }
+
+    #[test]
+    fn test_grounding_and_event_round_trip() {
+        let mut g = grounding::GroundingResult::verified("uses JWT", 0.9);
+        g.evidence.push(
+            grounding::GroundingEvidence::new("memory_node", "n42", 0.8, "auth note")
+                .with_data("tokens", 12),
+        );
+        g.suggestions.push("OAuth".into());
+        assert_eq!(json(&round_trip::<_, GroundingResult>(g.clone())), json(&g));
+
+        let events = [
+            crate::events::SisterEvent::ready(SisterType::Memory),
+            crate::events::SisterEvent::new(
+                SisterType::Codebase,
+                EventType::Custom {
+                    name: "reindexed".into(),
+                    data: serde_json::json!({"files": 3}),
+                },
+            )
+            .in_context(ContextId::new()),
+        ];
+        for e in events {
+            assert_eq!(json(&round_trip::<_, SisterEvent>(e.clone())), json(&e));
+        }
+    }
+
+    #[test]
+    fn test_receipt_and_command_round_trip() {
+        let mut errors = ErrorGroup::new(3);
+        errors.push(1, SisterError::not_found("item 1"));
+        let outcome = receipts::ActionOutcome::Partial {
+            result: Some(serde_json::json!({"ok": 2})),
+            warnings: vec!["slow".into()],
+            errors: Some(errors),
+        };
+        let action = receipts::ActionRecord::new(SisterType::Identity, "grant", outcome)
+            .param("scope", "read")
+            .in_run("run_1")
+            .at_step(7);
+        let receipt = receipts::Receipt {
+            id: ReceiptId::new(),
+            action,
+            signature: "sig".into(),
+            chain_position: 4,
+            previous_hash: "aa".into(),
+            hash: "bb".into(),
+            created_at: Utc::now(),
+            anchor: None,
+        };
+        assert_eq!(
+            json(&round_trip::<_, Receipt>(receipt.clone())),
+            json(&receipt)
+        );
+
+        let cmd = hydra::HydraCommand {
+            command_type: "memory_add".into(),
+            params: Metadata::from([("text".into(), "hello".into())]),
+            run_id: "run_1".into(),
+            step_id: 7,
+            budget: Some(hydra::Budget::new().max_tokens(1000)),
+            dry_run: false,
+            idempotency_key: Some("k1".into()),
+            priority: hydra::Priority::Normal,
+            deadline: None,
+            exclusive: true,
+            accept: vec![Codec::Cbor, Codec::Json],
+        }
+        .with_priority(hydra::Priority::High)
+        .with_deadline(Utc::now());
+        assert_eq!(
+            json(&round_trip::<_, HydraCommand>(cmd.clone())),
+            json(&cmd)
+        );
+    }
+
+    #[test]
+    fn test_malformed_proto_rejected() {
+        let mut wire = SisterEvent::from(crate::events::SisterEvent::ready(SisterType::Time));
+        wire.sister_type = 0xEE;
+        let err = crate::events::SisterEvent::try_from(wire).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidInput);
+
+        let wire = Query {
+            params: HashMap::from([("x".into(), "{not json".into())]),
+            ..Query::from(query::Query::list())
+        };
+        let err = query::Query::try_from(wire).unwrap_err();
+        assert_eq!(err.context.unwrap()["field"], "params");
+
+        assert!(PROTO.contains(&format!("package {};", PACKAGE)));
+    }
+}
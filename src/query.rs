@@ -11,6 +11,8 @@ use std::time::Duration;
 
 /// Standard query request.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct Query {
     /// Query type (e.g., "list", "search", "recent", "related", "temporal").
     pub query_type: String,
@@ -145,6 +147,8 @@ impl Query {
 
 /// Query result.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct QueryResult {
     /// The query that produced this result.
     pub query: Query,
@@ -161,6 +165,8 @@ pub struct QueryResult {
 
     /// Query execution time.
     #[serde(with = "duration_millis")]
+    #[cfg_attr(feature = "schema", schemars(with = "u64"))]
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
     pub query_time: Duration,
 
     /// Which contexts were queried.
@@ -227,6 +233,8 @@ impl QueryResult {
 
 /// Information about a supported query type.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct QueryTypeInfo {
     /// Query type name.
     pub name: String,
@@ -0,0 +1,483 @@
+//! Read-only enforcement at the contract layer.
+//!
+//! `SisterConfig::read_only` is advisory: a sister is trusted to honour
+//! it. `ReadOnly<S>` makes it binding. It implements the same traits as
+//! the sister it wraps, delegating reads and rejecting every mutating
+//! method with `PermissionDenied` before the sister sees the call.
+//!
+//! ```rust,ignore
+//! let memory = ReadOnly::<AgenticMemory>::init(config)?;
+//! memory.query(Query::search("auth"))?;        // delegated
+//! memory.start_session("x").unwrap_err();      // PermissionDenied
+//! ```
+//!
+//! Switching workspaces is allowed: it changes which data is read, not
+//! the data itself.
+
+use crate::context::{
+    CheckpointId, CheckpointInfo, Checkpointing, ContextFilter, ContextId, ContextInfo,
+    ContextSnapshot, ContextStats, ContextSummary, ContextTagging, PurgeReport, RetentionPolicy,
+    SessionManagement, SnapshotChunk, SnapshotChunks, SnapshotDelta, WorkspaceManagement,
+};
+use crate::diagnostics::{DiagnosticLevel, DiagnosticReport};
+use crate::errors::{SisterError, SisterResult};
+use crate::events::EventType;
+#[cfg(feature = "events")]
+use crate::events::{EventEmitter, EventFilter, EventReceiver, SisterEvent};
+use crate::grounding::{EvidenceDetail, Grounding, GroundingResult, GroundingSuggestion};
+use crate::metrics::{Metric, Metrics};
+use crate::query::{Query, QueryResult, QueryTypeInfo, Queryable};
+use crate::receipts::{
+    ActionRecord, AnchorProvider, AnchorTicket, CompactedSegment, Receipt, ReceiptCursor,
+    ReceiptFilter, ReceiptId, ReceiptIntegration, ReceiptIter, ReceiptPage, ReceiptProof,
+};
+#[cfg(feature = "events")]
+use crate::receipts::{ReceiptReceiver, ReceiptWatcher};
+use crate::sister::{
+    ConfigDiff, ConfigRequirements, Sister, SisterConfig, SisterRequirement, WarmUpReport,
+};
+use crate::types::{Capability, HealthStatus, Metadata, Version};
+use chrono::{DateTime, Utc};
+use std::path::Path;
+
+/// Wraps a sister so that no mutating contract method reaches it.
+#[derive(Debug, Clone, Default)]
+pub struct ReadOnly<S> {
+    inner: S,
+}
+
+impl<S> ReadOnly<S> {
+    pub fn new(inner: S) -> Self {
+        Self { inner }
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+fn denied(operation: &str) -> SisterError {
+    SisterError::permission_denied(format!("{} is not allowed: sister is read-only", operation))
+        .with_context("operation", operation)
+}
+
+impl<S: Sister> Sister for ReadOnly<S> {
+    const SISTER_TYPE: crate::types::SisterType = S::SISTER_TYPE;
+    const FILE_EXTENSION: &'static str = S::FILE_EXTENSION;
+
+    /// Initializes the inner sister with `read_only` forced on.
+    fn init(config: SisterConfig) -> SisterResult<Self> {
+        S::init(config.read_only(true)).map(Self::new)
+    }
+
+    fn health(&self) -> HealthStatus {
+        self.inner.health()
+    }
+
+    fn version(&self) -> Version {
+        self.inner.version()
+    }
+
+    fn shutdown(&mut self) -> SisterResult<()> {
+        self.inner.shutdown()
+    }
+
+    fn capabilities(&self) -> Vec<Capability> {
+        self.inner.capabilities()
+    }
+
+    fn warm_up(&mut self) -> SisterResult<WarmUpReport> {
+        self.inner.warm_up()
+    }
+
+    fn self_test(&self, level: DiagnosticLevel) -> SisterResult<DiagnosticReport> {
+        self.inner.self_test(level)
+    }
+
+    fn current_config(&self) -> Option<SisterConfig> {
+        self.inner.current_config()
+    }
+
+    fn apply_config(&mut self, config: SisterConfig, diff: &ConfigDiff) -> SisterResult<()> {
+        if !config.read_only {
+            return Err(denied("apply_config"));
+        }
+        self.inner.apply_config(config, diff)
+    }
+
+    fn pause(&mut self) -> SisterResult<()> {
+        self.inner.pause()
+    }
+
+    fn resume(&mut self) -> SisterResult<()> {
+        self.inner.resume()
+    }
+
+    fn config_requirements() -> ConfigRequirements {
+        S::config_requirements()
+    }
+
+    fn requires(&self) -> Vec<SisterRequirement> {
+        self.inner.requires()
+    }
+
+    fn optional_dependencies(&self) -> Vec<SisterRequirement> {
+        self.inner.optional_dependencies()
+    }
+}
+
+impl<S: SessionManagement> SessionManagement for ReadOnly<S> {
+    fn start_session(&mut self, _name: &str) -> SisterResult<ContextId> {
+        Err(denied("start_session"))
+    }
+
+    fn start_session_with_metadata(
+        &mut self,
+        _name: &str,
+        _metadata: Metadata,
+    ) -> SisterResult<ContextId> {
+        Err(denied("start_session"))
+    }
+
+    fn end_session(&mut self) -> SisterResult<()> {
+        Err(denied("end_session"))
+    }
+
+    fn current_session(&self) -> Option<ContextId> {
+        self.inner.current_session()
+    }
+
+    fn current_session_info(&self) -> SisterResult<ContextInfo> {
+        self.inner.current_session_info()
+    }
+
+    fn list_sessions(&self) -> SisterResult<Vec<ContextSummary>> {
+        self.inner.list_sessions()
+    }
+
+    fn find_sessions(&self, filter: &ContextFilter) -> SisterResult<Vec<ContextSummary>> {
+        self.inner.find_sessions(filter)
+    }
+
+    fn get_session_info(&self, id: ContextId) -> SisterResult<ContextInfo> {
+        self.inner.get_session_info(id)
+    }
+
+    fn session_stats(&self, id: ContextId) -> SisterResult<ContextStats> {
+        self.inner.session_stats(id)
+    }
+
+    fn set_context_metadata(
+        &mut self,
+        _id: ContextId,
+        _key: &str,
+        _value: serde_json::Value,
+    ) -> SisterResult<()> {
+        Err(denied("set_context_metadata"))
+    }
+
+    fn remove_context_metadata(&mut self, _id: ContextId, _key: &str) -> SisterResult<()> {
+        Err(denied("remove_context_metadata"))
+    }
+
+    fn fork_session(&mut self, _from: ContextId, _name: &str) -> SisterResult<ContextId> {
+        Err(denied("fork_session"))
+    }
+
+    fn export_session(&self, id: ContextId) -> SisterResult<ContextSnapshot> {
+        self.inner.export_session(id)
+    }
+
+    fn import_session(&mut self, _snapshot: ContextSnapshot) -> SisterResult<ContextId> {
+        Err(denied("import_session"))
+    }
+
+    fn export_session_delta(
+        &self,
+        id: ContextId,
+        since: &ContextSnapshot,
+    ) -> SisterResult<SnapshotDelta> {
+        self.inner.export_session_delta(id, since)
+    }
+
+    fn archive_session(&mut self, _id: ContextId) -> SisterResult<()> {
+        Err(denied("archive_session"))
+    }
+
+    fn purge_sessions(&mut self, _policy: &RetentionPolicy) -> SisterResult<PurgeReport> {
+        Err(denied("purge_sessions"))
+    }
+
+    fn on_session_event(&self, event: EventType) {
+        self.inner.on_session_event(event)
+    }
+
+    fn apply_session_delta(&mut self, _delta: &SnapshotDelta) -> SisterResult<()> {
+        Err(denied("apply_session_delta"))
+    }
+}
+
+impl<S: WorkspaceManagement> WorkspaceManagement for ReadOnly<S> {
+    fn create_workspace(&mut self, _name: &str) -> SisterResult<ContextId> {
+        Err(denied("create_workspace"))
+    }
+
+    fn create_workspace_with_metadata(
+        &mut self,
+        _name: &str,
+        _metadata: Metadata,
+    ) -> SisterResult<ContextId> {
+        Err(denied("create_workspace"))
+    }
+
+    fn switch_workspace(&mut self, id: ContextId) -> SisterResult<()> {
+        self.inner.switch_workspace(id)
+    }
+
+    fn current_workspace(&self) -> ContextId {
+        self.inner.current_workspace()
+    }
+
+    fn current_workspace_info(&self) -> SisterResult<ContextInfo> {
+        self.inner.current_workspace_info()
+    }
+
+    fn list_workspaces(&self) -> SisterResult<Vec<ContextSummary>> {
+        self.inner.list_workspaces()
+    }
+
+    fn find_workspaces(&self, filter: &ContextFilter) -> SisterResult<Vec<ContextSummary>> {
+        self.inner.find_workspaces(filter)
+    }
+
+    fn delete_workspace(&mut self, _id: ContextId) -> SisterResult<()> {
+        Err(denied("delete_workspace"))
+    }
+
+    fn rename_workspace(&mut self, _id: ContextId, _new_name: &str) -> SisterResult<()> {
+        Err(denied("rename_workspace"))
+    }
+
+    fn set_context_metadata(
+        &mut self,
+        _id: ContextId,
+        _key: &str,
+        _value: serde_json::Value,
+    ) -> SisterResult<()> {
+        Err(denied("set_context_metadata"))
+    }
+
+    fn remove_context_metadata(&mut self, _id: ContextId, _key: &str) -> SisterResult<()> {
+        Err(denied("remove_context_metadata"))
+    }
+
+    fn export_workspace(&self, id: ContextId) -> SisterResult<ContextSnapshot> {
+        self.inner.export_workspace(id)
+    }
+
+    fn import_workspace(&mut self, _snapshot: ContextSnapshot) -> SisterResult<ContextId> {
+        Err(denied("import_workspace"))
+    }
+
+    fn export_workspace_delta(
+        &self,
+        id: ContextId,
+        since: &ContextSnapshot,
+    ) -> SisterResult<SnapshotDelta> {
+        self.inner.export_workspace_delta(id, since)
+    }
+
+    fn export_workspace_chunked(
+        &self,
+        id: ContextId,
+        chunk_size: usize,
+    ) -> SisterResult<SnapshotChunks> {
+        self.inner.export_workspace_chunked(id, chunk_size)
+    }
+
+    fn import_workspace_chunked(
+        &mut self,
+        _chunks: &mut dyn Iterator<Item = SnapshotChunk>,
+    ) -> SisterResult<ContextId> {
+        Err(denied("import_workspace"))
+    }
+
+    fn apply_workspace_delta(&mut self, _delta: &SnapshotDelta) -> SisterResult<()> {
+        Err(denied("apply_workspace_delta"))
+    }
+
+    fn get_workspace_info(&self, id: ContextId) -> SisterResult<ContextInfo> {
+        self.inner.get_workspace_info(id)
+    }
+
+    fn workspace_exists(&self, id: ContextId) -> bool {
+        self.inner.workspace_exists(id)
+    }
+}
+
+impl<S: ContextTagging> ContextTagging for ReadOnly<S> {
+    fn tag_context(&mut self, _id: ContextId, _tags: &[&str]) -> SisterResult<()> {
+        Err(denied("tag_context"))
+    }
+
+    fn untag_context(&mut self, _id: ContextId, _tags: &[&str]) -> SisterResult<()> {
+        Err(denied("untag_context"))
+    }
+
+    fn context_tags(&self, id: ContextId) -> SisterResult<Vec<String>> {
+        self.inner.context_tags(id)
+    }
+}
+
+impl<S: Checkpointing> Checkpointing for ReadOnly<S> {
+    fn checkpoint(&mut self) -> SisterResult<CheckpointId> {
+        Err(denied("checkpoint"))
+    }
+
+    fn restore_checkpoint(&mut self, _id: CheckpointId) -> SisterResult<()> {
+        Err(denied("restore_checkpoint"))
+    }
+
+    fn list_checkpoints(&self) -> SisterResult<Vec<CheckpointInfo>> {
+        self.inner.list_checkpoints()
+    }
+
+    fn latest_checkpoint(&self) -> SisterResult<Option<CheckpointInfo>> {
+        self.inner.latest_checkpoint()
+    }
+}
+
+impl<S: ReceiptIntegration> ReceiptIntegration for ReadOnly<S> {
+    fn create_receipt(&self, _action: ActionRecord) -> SisterResult<ReceiptId> {
+        Err(denied("create_receipt"))
+    }
+
+    fn create_receipts(&self, _actions: Vec<ActionRecord>) -> SisterResult<Vec<ReceiptId>> {
+        Err(denied("create_receipt"))
+    }
+
+    fn get_receipt(&self, id: ReceiptId) -> SisterResult<Receipt> {
+        self.inner.get_receipt(id)
+    }
+
+    fn list_receipts(&self, filter: ReceiptFilter) -> SisterResult<Vec<Receipt>> {
+        self.inner.list_receipts(filter)
+    }
+
+    fn receipt_count(&self) -> SisterResult<u64> {
+        self.inner.receipt_count()
+    }
+
+    fn receipts_for_action(&self, action_type: &str) -> SisterResult<Vec<Receipt>> {
+        self.inner.receipts_for_action(action_type)
+    }
+
+    fn prove(&self, id: ReceiptId) -> SisterResult<ReceiptProof> {
+        self.inner.prove(id)
+    }
+
+    fn list_receipts_page(
+        &self,
+        filter: ReceiptFilter,
+        cursor: Option<ReceiptCursor>,
+        page_size: usize,
+    ) -> SisterResult<ReceiptPage> {
+        self.inner.list_receipts_page(filter, cursor, page_size)
+    }
+
+    fn receipt_stream(&self, filter: ReceiptFilter, page_size: usize) -> ReceiptIter<'_> {
+        self.inner.receipt_stream(filter, page_size)
+    }
+
+    fn anchor_head(&self, provider: &dyn AnchorProvider) -> SisterResult<AnchorTicket> {
+        self.inner.anchor_head(provider)
+    }
+
+    fn compact_receipts(&self, _before: DateTime<Utc>) -> SisterResult<Option<CompactedSegment>> {
+        Err(denied("compact_receipts"))
+    }
+
+    fn compacted_segments(&self) -> SisterResult<Vec<CompactedSegment>> {
+        self.inner.compacted_segments()
+    }
+
+    fn verify_chain(&self) -> SisterResult<()> {
+        self.inner.verify_chain()
+    }
+
+    fn export_receipts(&self, filter: ReceiptFilter, path: &Path) -> SisterResult<usize> {
+        self.inner.export_receipts(filter, path)
+    }
+}
+
+#[cfg(feature = "events")]
+impl<S: ReceiptWatcher> ReceiptWatcher for ReadOnly<S> {
+    fn subscribe_receipts(&self, filter: ReceiptFilter) -> ReceiptReceiver {
+        self.inner.subscribe_receipts(filter)
+    }
+}
+
+impl<S: Grounding> Grounding for ReadOnly<S> {
+    fn ground(&self, claim: &str) -> SisterResult<GroundingResult> {
+        self.inner.ground(claim)
+    }
+
+    fn evidence(&self, query: &str, max_results: usize) -> SisterResult<Vec<EvidenceDetail>> {
+        self.inner.evidence(query, max_results)
+    }
+
+    fn suggest(&self, query: &str, limit: usize) -> SisterResult<Vec<GroundingSuggestion>> {
+        self.inner.suggest(query, limit)
+    }
+}
+
+impl<S: Queryable> Queryable for ReadOnly<S> {
+    fn query(&self, query: Query) -> SisterResult<QueryResult> {
+        self.inner.query(query)
+    }
+
+    fn supports_query(&self, query_type: &str) -> bool {
+        self.inner.supports_query(query_type)
+    }
+
+    fn query_types(&self) -> Vec<QueryTypeInfo> {
+        self.inner.query_types()
+    }
+
+    fn search(&self, text: &str) -> SisterResult<QueryResult> {
+        self.inner.search(text)
+    }
+
+    fn recent(&self, count: usize) -> SisterResult<QueryResult> {
+        self.inner.recent(count)
+    }
+
+    fn list(&self, limit: usize, offset: usize) -> SisterResult<QueryResult> {
+        self.inner.list(limit, offset)
+    }
+}
+
+#[cfg(feature = "events")]
+impl<S: EventEmitter> EventEmitter for ReadOnly<S> {
+    fn subscribe(&self, filter: EventFilter) -> EventReceiver {
+        self.inner.subscribe(filter)
+    }
+
+    fn recent_events(&self, limit: usize) -> Vec<SisterEvent> {
+        self.inner.recent_events(limit)
+    }
+
+    fn emit(&self, event: SisterEvent) {
+        self.inner.emit(event)
+    }
+}
+
+impl<S: Metrics> Metrics for ReadOnly<S> {
+    fn metrics(&self) -> Vec<Metric> {
+        self.inner.metrics()
+    }
+}
@@ -3,14 +3,28 @@
 //! Identity is the receipt system. All sisters that create auditable
 //! actions use Identity for receipts. Hydra queries Identity for receipts.
 
+use crate::clock::{Clock, SystemClock};
 use crate::context::ContextId;
-use crate::errors::SisterResult;
-use crate::types::{Metadata, SisterType, UniqueId};
+use crate::errors::{ErrorCode, ErrorGroup, SisterError, SisterResult};
+use crate::file_format::JsonFormatDescriptor;
+#[cfg(feature = "hashing")]
+use crate::file_format::{FileFormatReader, FileFormatWriter, FileInfo, VersionCompatibility};
+use crate::grounding::{EvidenceDetail, GroundingEvidence};
+use crate::hydra::HydraCommand;
+use crate::types::{Metadata, SisterType, UniqueId, Version};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+#[cfg(feature = "events")]
+use tokio::sync::broadcast;
+
+/// `evidence_type` of grounding evidence built from receipts.
+pub const RECEIPT_EVIDENCE_TYPE: &str = "receipt";
 
 /// Unique receipt identifier.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct ReceiptId(pub UniqueId);
 
 impl ReceiptId {
@@ -33,6 +47,8 @@ impl std::fmt::Display for ReceiptId {
 
 /// Action outcome.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[serde(tag = "status", rename_all = "snake_case")]
 pub enum ActionOutcome {
     /// Action succeeded.
@@ -52,6 +68,9 @@ pub enum ActionOutcome {
         #[serde(skip_serializing_if = "Option::is_none")]
         result: Option<serde_json::Value>,
         warnings: Vec<String>,
+        /// Per-item failures, for batch actions
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        errors: Option<ErrorGroup>,
     },
 }
 
@@ -77,6 +96,28 @@ impl ActionOutcome {
         Self::Partial {
             result: None,
             warnings,
+            errors: None,
+        }
+    }
+
+    /// Batch action where some items failed. Warnings list each failure.
+    pub fn partial_failure(errors: &ErrorGroup) -> Self {
+        Self::Partial {
+            result: None,
+            warnings: errors
+                .errors
+                .iter()
+                .map(|e| format!("item {}: [{}] {}", e.index, e.error.code, e.error.message))
+                .collect(),
+            errors: Some(errors.clone()),
+        }
+    }
+
+    /// Per-item failures, if this is a partial outcome that carries them.
+    pub fn errors(&self) -> Option<&ErrorGroup> {
+        match self {
+            Self::Partial { errors, .. } => errors.as_ref(),
+            _ => None,
         }
     }
 
@@ -87,10 +128,21 @@ impl ActionOutcome {
     pub fn is_failure(&self) -> bool {
         matches!(self, Self::Failure { .. })
     }
+
+    /// Serialized status tag: "success", "failure", or "partial".
+    pub fn status(&self) -> &'static str {
+        match self {
+            Self::Success { .. } => "success",
+            Self::Failure { .. } => "failure",
+            Self::Partial { .. } => "partial",
+        }
+    }
 }
 
 /// Action record to be receipted.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct ActionRecord {
     /// What sister performed this.
     pub sister_type: SisterType,
@@ -113,6 +165,14 @@ pub struct ActionRecord {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub context_id: Option<ContextId>,
 
+    /// Hydra run this action belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_id: Option<String>,
+
+    /// Step within the Hydra run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub step_id: Option<u64>,
+
     /// Timestamp.
     pub timestamp: DateTime<Utc>,
 }
@@ -123,6 +183,16 @@ impl ActionRecord {
         sister_type: SisterType,
         action_type: impl Into<String>,
         outcome: ActionOutcome,
+    ) -> Self {
+        Self::new_with_clock(sister_type, action_type, outcome, &SystemClock)
+    }
+
+    /// Create a new action record stamped by `clock`.
+    pub fn new_with_clock(
+        sister_type: SisterType,
+        action_type: impl Into<String>,
+        outcome: ActionOutcome,
+        clock: &dyn Clock,
     ) -> Self {
         Self {
             sister_type,
@@ -131,7 +201,9 @@ impl ActionRecord {
             outcome,
             evidence_ids: vec![],
             context_id: None,
-            timestamp: Utc::now(),
+            run_id: None,
+            step_id: None,
+            timestamp: clock.now(),
         }
     }
 
@@ -154,10 +226,130 @@ impl ActionRecord {
         self.context_id = Some(context_id);
         self
     }
+
+    /// Set the Hydra run.
+    pub fn in_run(mut self, run_id: impl Into<String>) -> Self {
+        self.run_id = Some(run_id.into());
+        self
+    }
+
+    /// Set the step within the Hydra run.
+    pub fn at_step(mut self, step_id: u64) -> Self {
+        self.step_id = Some(step_id);
+        self
+    }
+
+    /// Set run and step from the Hydra command that caused this action.
+    pub fn for_command(self, command: &HydraCommand) -> Self {
+        self.in_run(command.run_id.clone()).at_step(command.step_id)
+    }
+
+    /// Canonical byte form for signing and hashing.
+    ///
+    /// RFC 8785 (JCS) JSON: compact, object keys sorted by UTF-16 code
+    /// units at every level, and numbers in ECMAScript form, so any JCS
+    /// implementation reproduces the bytes. Integers beyond 2^53 are
+    /// canonicalized as the nearest double, as JCS does; send exact
+    /// large values as strings. Fails if the record cannot be
+    /// serialized, rather than signing a placeholder.
+    pub fn canonical_bytes(&self) -> SisterResult<Vec<u8>> {
+        canonical_json(self)
+    }
+}
+
+/// Largest integer magnitude a double holds exactly.
+const MAX_EXACT_INT: u64 = 1 << 53;
+
+/// RFC 8785 canonical JSON.
+fn canonical_json(value: &impl Serialize) -> SisterResult<Vec<u8>> {
+    let value = serde_json::to_value(value)?;
+    let mut out = String::new();
+    write_canonical_json(&value, &mut out);
+    Ok(out.into_bytes())
+}
+
+fn write_canonical_json(value: &serde_json::Value, out: &mut String) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<_> = map.keys().collect();
+            keys.sort_by(|a, b| a.encode_utf16().cmp(b.encode_utf16()));
+            out.push('{');
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&serde_json::Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical_json(&map[key], out);
+            }
+            out.push('}');
+        }
+        serde_json::Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical_json(item, out);
+            }
+            out.push(']');
+        }
+        serde_json::Value::Number(number) => {
+            let exact = number
+                .as_u64()
+                .or_else(|| number.as_i64().map(i64::unsigned_abs))
+                .is_some_and(|n| n <= MAX_EXACT_INT);
+            if exact {
+                out.push_str(&number.to_string());
+            } else {
+                // Floats, and integers a double cannot hold exactly
+                out.push_str(&ecmascript_number(number.as_f64().unwrap_or_default()));
+            }
+        }
+        other => out.push_str(&other.to_string()),
+    }
+}
+
+/// ECMAScript `Number.prototype.toString`, the number form RFC 8785 uses.
+fn ecmascript_number(value: f64) -> String {
+    if value == 0.0 {
+        return "0".into();
+    }
+    // Shortest round-trip digits and exponent, e.g. "1.25e-7"
+    let scientific = format!("{:e}", value.abs());
+    let (mantissa, exponent) = scientific
+        .split_once('e')
+        .expect("LowerExp always writes an exponent");
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let k = digits.len() as i32;
+    let n = exponent
+        .parse::<i32>()
+        .expect("LowerExp exponent is an integer")
+        + 1;
+    let body = if k <= n && n <= 21 {
+        format!("{digits}{}", "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        let (int, frac) = digits.split_at(n as usize);
+        format!("{int}.{frac}")
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{digits}", "0".repeat(-n as usize))
+    } else {
+        let sign = if n > 0 { '+' } else { '-' };
+        let (first, rest) = digits.split_at(1);
+        let dot = if rest.is_empty() { "" } else { "." };
+        format!("{first}{dot}{rest}e{sign}{}", (n - 1).abs())
+    };
+    if value < 0.0 {
+        format!("-{body}")
+    } else {
+        body
+    }
 }
 
 /// A receipt (signed action record).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct Receipt {
     /// Receipt ID.
     pub id: ReceiptId,
@@ -179,17 +371,117 @@ pub struct Receipt {
 
     /// When the receipt was created.
     pub created_at: DateTime<Utc>,
+
+    /// External timestamp proof, once this receipt's hash was anchored.
+    /// Not covered by `canonical_bytes`, so anchoring never changes a
+    /// receipt's identity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<ReceiptAnchor>,
 }
 
 impl Receipt {
+    /// Verify the receipt signature against a 32-byte ed25519 public key.
+    ///
+    /// The signature must be the hex encoding produced by `sign_action`
+    /// over `ActionRecord::canonical_bytes()`.
+    #[cfg(feature = "crypto")]
+    pub fn verify_signature(&self, public_key: &[u8]) -> bool {
+        VerifyingKey::try_from(public_key)
+            .map(|key| key.verify_action(&self.action, &self.signature))
+            .unwrap_or(false)
+    }
+
     /// Verify the receipt signature (requires Identity).
-    /// This is a placeholder - actual verification happens via Identity sister.
+    /// Without the `crypto` feature this only checks that a signature is
+    /// present; enable `crypto` for real ed25519 verification.
+    #[cfg(not(feature = "crypto"))]
     pub fn verify_signature(&self, _public_key: &[u8]) -> bool {
-        // In practice, this would use ed25519 verification
-        // For now, return true as placeholder
         !self.signature.is_empty()
     }
 
+    /// Canonical byte form of the receipt (see `ActionRecord::canonical_bytes`).
+    ///
+    /// Covers every field except `anchor`.
+    pub fn canonical_bytes(&self) -> SisterResult<Vec<u8>> {
+        if self.anchor.is_none() {
+            return canonical_json(self);
+        }
+        canonical_json(&Self {
+            anchor: None,
+            ..self.clone()
+        })
+    }
+
+    /// Whether an external anchor proof is attached.
+    pub fn is_anchored(&self) -> bool {
+        self.anchor.is_some()
+    }
+
+    /// Merkle leaf hash of this receipt.
+    #[cfg(feature = "hashing")]
+    pub fn leaf_hash(&self) -> SisterResult<[u8; 32]> {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&[MERKLE_LEAF_PREFIX]);
+        hasher.update(&self.canonical_bytes()?);
+        Ok(*hasher.finalize().as_bytes())
+    }
+
+    /// One-line summary used for grounding evidence.
+    pub fn evidence_summary(&self) -> String {
+        format!(
+            "Receipt for {} ({}, chain pos {})",
+            self.action.action_type,
+            self.action.outcome.status(),
+            self.chain_position
+        )
+    }
+
+    /// Structured `data` shared by `GroundingEvidence` and `EvidenceDetail`.
+    fn evidence_data(&self) -> Metadata {
+        let action = &self.action;
+        let mut data = Metadata::new();
+        data.insert("sister_type".into(), serde_json::json!(action.sister_type));
+        data.insert("action_type".into(), serde_json::json!(action.action_type));
+        data.insert("outcome".into(), serde_json::json!(action.outcome.status()));
+        data.insert(
+            "chain_position".into(),
+            serde_json::json!(self.chain_position),
+        );
+        data.insert("hash".into(), serde_json::json!(self.hash));
+        if let Some(context_id) = action.context_id {
+            data.insert("context_id".into(), serde_json::json!(context_id));
+        }
+        if let Some(run_id) = &action.run_id {
+            data.insert("run_id".into(), serde_json::json!(run_id));
+        }
+        if let Some(step_id) = action.step_id {
+            data.insert("step_id".into(), serde_json::json!(step_id));
+        }
+        if !action.evidence_ids.is_empty() {
+            data.insert(
+                "evidence_ids".into(),
+                serde_json::json!(action.evidence_ids),
+            );
+        }
+        data
+    }
+
+    /// Full evidence record for `Grounding::evidence`.
+    ///
+    /// Receipts are always served by Identity, so `source_sister` is
+    /// Identity; the acting sister is in `data["sister_type"]`.
+    pub fn to_evidence_detail(&self) -> EvidenceDetail {
+        EvidenceDetail {
+            evidence_type: RECEIPT_EVIDENCE_TYPE.to_string(),
+            id: self.id.to_string(),
+            score: 1.0,
+            created_at: self.created_at,
+            source_sister: SisterType::Identity,
+            content: self.evidence_summary(),
+            data: self.evidence_data(),
+        }
+    }
+
     /// Get the action type.
     pub fn action_type(&self) -> &str {
         &self.action.action_type
@@ -201,6 +493,771 @@ impl Receipt {
     }
 }
 
+/// Evidence with score 1.0: a receipt is an exact record, so callers
+/// only lower the score when the claim matches it loosely.
+impl From<&Receipt> for GroundingEvidence {
+    fn from(receipt: &Receipt) -> Self {
+        GroundingEvidence {
+            evidence_type: RECEIPT_EVIDENCE_TYPE.to_string(),
+            id: receipt.id.to_string(),
+            score: 1.0,
+            summary: receipt.evidence_summary(),
+            data: receipt.evidence_data(),
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// ed25519 signing (feature "crypto")
+// ═══════════════════════════════════════════════════════════════════
+
+/// ed25519 private key for signing receipts.
+#[cfg(feature = "crypto")]
+#[derive(Clone)]
+pub struct SigningKey(ed25519_dalek::SigningKey);
+
+#[cfg(feature = "crypto")]
+impl SigningKey {
+    /// Generate a key from the OS random source.
+    pub fn generate() -> Self {
+        Self(ed25519_dalek::SigningKey::generate(&mut rand_core::OsRng))
+    }
+
+    /// Key from its 32-byte secret.
+    pub fn from_bytes(secret: &[u8; 32]) -> Self {
+        Self(ed25519_dalek::SigningKey::from_bytes(secret))
+    }
+
+    /// The 32-byte secret. Handle with care.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    /// Matching public key.
+    pub fn verifying_key(&self) -> VerifyingKey {
+        VerifyingKey(self.0.verifying_key())
+    }
+}
+
+#[cfg(feature = "crypto")]
+impl std::fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SigningKey")
+            .field(&self.verifying_key().to_string())
+            .finish()
+    }
+}
+
+/// ed25519 public key for verifying receipts. Displays as hex.
+#[cfg(feature = "crypto")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifyingKey(ed25519_dalek::VerifyingKey);
+
+#[cfg(feature = "crypto")]
+impl VerifyingKey {
+    /// Key from its 32-byte encoding.
+    pub fn from_bytes(bytes: &[u8; 32]) -> SisterResult<Self> {
+        ed25519_dalek::VerifyingKey::from_bytes(bytes)
+            .map(Self)
+            .map_err(|e| SisterError::invalid_input(format!("Invalid ed25519 public key: {}", e)))
+    }
+
+    /// Key from 64 hex characters.
+    pub fn from_hex(hex_key: &str) -> SisterResult<Self> {
+        let bytes = hex::decode(hex_key)
+            .map_err(|e| SisterError::invalid_input(format!("Invalid public key hex: {}", e)))?;
+        Self::try_from(bytes.as_slice())
+    }
+
+    /// 32-byte encoding.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    /// Check a hex signature from `sign_action` over `action`.
+    pub fn verify_action(&self, action: &ActionRecord, signature_hex: &str) -> bool {
+        action
+            .canonical_bytes()
+            .is_ok_and(|message| self.verify_hex(&message, signature_hex))
+    }
+
+    /// Check a compacted segment's signature from `sign_segment`.
+    pub fn verify_segment(&self, segment: &CompactedSegment) -> bool {
+        segment
+            .canonical_bytes()
+            .is_ok_and(|message| self.verify_hex(&message, &segment.signature))
+    }
+
+    fn verify_hex(&self, message: &[u8], signature_hex: &str) -> bool {
+        let Ok(bytes) = hex::decode(signature_hex) else {
+            return false;
+        };
+        let Ok(signature) = ed25519_dalek::Signature::from_slice(&bytes) else {
+            return false;
+        };
+        self.0.verify_strict(message, &signature).is_ok()
+    }
+}
+
+#[cfg(feature = "crypto")]
+impl TryFrom<&[u8]> for VerifyingKey {
+    type Error = SisterError;
+
+    fn try_from(bytes: &[u8]) -> SisterResult<Self> {
+        let bytes: &[u8; 32] = bytes.try_into().map_err(|_| {
+            SisterError::invalid_input(format!(
+                "ed25519 public key must be 32 bytes, got {}",
+                bytes.len()
+            ))
+        })?;
+        Self::from_bytes(bytes)
+    }
+}
+
+#[cfg(feature = "crypto")]
+impl std::fmt::Display for VerifyingKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", hex::encode(self.to_bytes()))
+    }
+}
+
+/// Sign an action record, returning the hex signature for `Receipt::signature`.
+#[cfg(feature = "crypto")]
+pub fn sign_action(action: &ActionRecord, key: &SigningKey) -> SisterResult<String> {
+    use ed25519_dalek::Signer;
+    Ok(hex::encode(
+        key.0.sign(&action.canonical_bytes()?).to_bytes(),
+    ))
+}
+
+/// Sign a compacted segment, returning the hex signature for
+/// `CompactedSegment::signature`.
+#[cfg(feature = "crypto")]
+pub fn sign_segment(segment: &CompactedSegment, key: &SigningKey) -> SisterResult<String> {
+    use ed25519_dalek::Signer;
+    Ok(hex::encode(
+        key.0.sign(&segment.canonical_bytes()?).to_bytes(),
+    ))
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// Merkle inclusion proofs
+// ═══════════════════════════════════════════════════════════════════
+
+// Domain separation between leaves and interior nodes (as in RFC 6962),
+// so a leaf can never be passed off as an interior node.
+#[cfg(feature = "hashing")]
+const MERKLE_LEAF_PREFIX: u8 = 0x00;
+#[cfg(feature = "hashing")]
+const MERKLE_NODE_PREFIX: u8 = 0x01;
+
+#[cfg(feature = "hashing")]
+fn merkle_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&[MERKLE_NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    *hasher.finalize().as_bytes()
+}
+
+/// Which side a sibling hash sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum ProofSide {
+    Left,
+    Right,
+}
+
+/// One sibling on the path from a leaf to the root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct ProofStep {
+    /// Sibling hash (hex)
+    pub hash: String,
+
+    /// Side the sibling is on
+    pub side: ProofSide,
+}
+
+/// Proof that one receipt is part of a batch with a given Merkle root.
+///
+/// Lets a holder show "this action happened" by sharing one receipt and
+/// a logarithmic number of hashes instead of the whole chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct ReceiptProof {
+    /// Receipt being proven
+    pub receipt_id: ReceiptId,
+
+    /// Leaf hash of that receipt (hex, `Receipt::leaf_hash`)
+    pub leaf_hash: String,
+
+    /// Position of the receipt in the batch
+    pub leaf_index: usize,
+
+    /// Number of receipts in the batch
+    pub leaf_count: usize,
+
+    /// Siblings from the leaf up to the root
+    pub path: Vec<ProofStep>,
+
+    /// Root of the batch (hex)
+    pub root: String,
+}
+
+#[cfg(feature = "hashing")]
+impl ReceiptProof {
+    /// Build a proof for `receipts[index]`. Receipts are hashed in the
+    /// order given; callers should order them by `chain_position`.
+    pub fn build(receipts: &[Receipt], index: usize) -> SisterResult<Self> {
+        let receipt = receipts.get(index).ok_or_else(|| {
+            SisterError::invalid_input(format!(
+                "Receipt index {} out of range for batch of {}",
+                index,
+                receipts.len()
+            ))
+        })?;
+
+        let mut level = receipts
+            .iter()
+            .map(Receipt::leaf_hash)
+            .collect::<SisterResult<Vec<_>>>()?;
+        let leaf_hash = level[index];
+        let mut position = index;
+        let mut path = Vec::new();
+
+        while level.len() > 1 {
+            let sibling = position ^ 1;
+            if sibling < level.len() {
+                path.push(ProofStep {
+                    hash: hex::encode(level[sibling]),
+                    side: if sibling < position {
+                        ProofSide::Left
+                    } else {
+                        ProofSide::Right
+                    },
+                });
+            }
+            level = merkle_level_up(&level);
+            position /= 2;
+        }
+
+        Ok(Self {
+            receipt_id: receipt.id,
+            leaf_hash: hex::encode(leaf_hash),
+            leaf_index: index,
+            leaf_count: receipts.len(),
+            path,
+            root: hex::encode(level[0]),
+        })
+    }
+
+    /// Whether this proof is about `receipt` (its leaf hash matches).
+    pub fn matches(&self, receipt: &Receipt) -> bool {
+        receipt.id == self.receipt_id
+            && receipt
+                .leaf_hash()
+                .is_ok_and(|hash| hex::encode(hash) == self.leaf_hash)
+    }
+}
+
+/// Pair up one tree level; an odd last node is carried up unchanged.
+#[cfg(feature = "hashing")]
+fn merkle_level_up(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => merkle_node(left, right),
+            [single] => *single,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Merkle root (hex) over a batch of receipts, in the order given.
+///
+/// `None` for an empty batch.
+#[cfg(feature = "hashing")]
+pub fn receipt_merkle_root(receipts: &[Receipt]) -> SisterResult<Option<String>> {
+    let mut level = receipts
+        .iter()
+        .map(Receipt::leaf_hash)
+        .collect::<SisterResult<Vec<_>>>()?;
+    while level.len() > 1 {
+        level = merkle_level_up(&level);
+    }
+    Ok(level.first().map(hex::encode))
+}
+
+/// Check that `proof` leads from its leaf to `root` (hex).
+///
+/// Pair with `ReceiptProof::matches` to also tie the proof to a receipt
+/// you hold.
+#[cfg(feature = "hashing")]
+pub fn verify_proof(root: &str, proof: &ReceiptProof) -> bool {
+    let decode = |h: &str| -> Option<[u8; 32]> { hex::decode(h).ok()?.try_into().ok() };
+    let Some(mut hash) = decode(&proof.leaf_hash) else {
+        return false;
+    };
+    for step in &proof.path {
+        let Some(sibling) = decode(&step.hash) else {
+            return false;
+        };
+        hash = match step.side {
+            ProofSide::Left => merkle_node(&sibling, &hash),
+            ProofSide::Right => merkle_node(&hash, &sibling),
+        };
+    }
+    hex::encode(hash) == root && proof.root == root
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// Export / import (.arcpt)
+// ═══════════════════════════════════════════════════════════════════
+
+/// Format identifier of `.arcpt` receipt exports.
+pub const RECEIPT_ARCHIVE_FORMAT: &str = "arcpt-v1";
+
+/// File extension of receipt exports.
+pub const RECEIPT_ARCHIVE_EXTENSION: &str = "arcpt";
+
+/// Current `.arcpt` format version.
+pub const RECEIPT_ARCHIVE_VERSION: Version = Version::new(1, 0, 0);
+
+/// First line of an `.arcpt` file.
+///
+/// Follows the `JsonFormatDescriptor` convention, so generic JSON
+/// inspection recognises the file from its first line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReceiptArchiveHeader {
+    #[serde(flatten)]
+    pub descriptor: JsonFormatDescriptor,
+
+    /// Who signed the receipts (e.g. hex ed25519 public key)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer: Option<String>,
+
+    /// Merkle root over the exported receipts (`receipt_merkle_root`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_root: Option<String>,
+
+    /// Number of receipt records following the header
+    pub receipt_count: u64,
+
+    /// External anchors covering the exported chain
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub anchors: Vec<ReceiptAnchor>,
+}
+
+/// A receipt chain export: header line, then one receipt per line (JSONL).
+///
+/// Lets receipt chains survive a sister migration; re-verify on import
+/// with `verify_proof` or `Receipt::verify_signature`.
+#[derive(Debug, Clone)]
+pub struct ReceiptArchive {
+    pub header: ReceiptArchiveHeader,
+    pub receipts: Vec<Receipt>,
+}
+
+#[cfg(feature = "hashing")]
+impl ReceiptArchive {
+    /// Create an archive. Receipts are ordered by `chain_position`.
+    pub fn new(mut receipts: Vec<Receipt>) -> SisterResult<Self> {
+        receipts.sort_by_key(|r| r.chain_position);
+        let header = ReceiptArchiveHeader {
+            descriptor: JsonFormatDescriptor::new(
+                SisterType::Identity,
+                RECEIPT_ARCHIVE_FORMAT,
+                RECEIPT_ARCHIVE_VERSION,
+            ),
+            signer: None,
+            chain_root: receipt_merkle_root(&receipts)?,
+            receipt_count: receipts.len() as u64,
+            anchors: receipts.iter().filter_map(|r| r.anchor.clone()).collect(),
+        };
+        Ok(Self { header, receipts })
+    }
+
+    /// Record who signed the receipts.
+    pub fn signer(mut self, signer: impl Into<String>) -> Self {
+        self.header.signer = Some(signer.into());
+        self
+    }
+
+    /// Parse and verify an archive (count and chain root must match).
+    pub fn from_bytes(data: &[u8]) -> SisterResult<Self> {
+        let text = std::str::from_utf8(data).map_err(|e| {
+            SisterError::invalid_input(format!("Receipt archive is not UTF-8: {}", e))
+        })?;
+        let mut lines = text.lines().filter(|l| !l.trim().is_empty());
+        let header: ReceiptArchiveHeader = serde_json::from_str(
+            lines
+                .next()
+                .ok_or_else(|| SisterError::invalid_input("Receipt archive is empty"))?,
+        )?;
+        Self::check_version(&header.descriptor.version)?;
+
+        let receipts = lines
+            .map(|line| serde_json::from_str(line).map_err(SisterError::from))
+            .collect::<SisterResult<Vec<Receipt>>>()?;
+
+        if receipts.len() as u64 != header.receipt_count {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                format!(
+                    "Receipt archive declares {} receipts but contains {}",
+                    header.receipt_count,
+                    receipts.len()
+                ),
+            ));
+        }
+        let root = receipt_merkle_root(&receipts)?;
+        if root != header.chain_root {
+            return Err(SisterError::new(
+                ErrorCode::ChecksumMismatch,
+                "Receipt archive chain root does not match its receipts",
+            )
+            .with_context("expected", header.chain_root.clone().unwrap_or_default())
+            .with_context("actual", root.unwrap_or_default()));
+        }
+
+        Ok(Self { header, receipts })
+    }
+
+    fn read_header(path: &Path) -> SisterResult<ReceiptArchiveHeader> {
+        use std::io::BufRead;
+        let mut line = String::new();
+        std::io::BufReader::new(std::fs::File::open(path)?).read_line(&mut line)?;
+        Ok(serde_json::from_str(&line)?)
+    }
+
+    fn check_version(version: &Version) -> SisterResult<()> {
+        if VersionCompatibility::can_read(&RECEIPT_ARCHIVE_VERSION, version) {
+            Ok(())
+        } else {
+            Err(SisterError::new(
+                ErrorCode::VersionMismatch,
+                format!(
+                    "Receipt archive version {} is newer than supported {}",
+                    version, RECEIPT_ARCHIVE_VERSION
+                ),
+            ))
+        }
+    }
+}
+
+#[cfg(feature = "hashing")]
+impl FileFormatWriter for ReceiptArchive {
+    fn to_bytes(&self) -> SisterResult<Vec<u8>> {
+        let mut out = serde_json::to_vec(&self.header)?;
+        out.push(b'\n');
+        for receipt in &self.receipts {
+            serde_json::to_writer(&mut out, receipt)?;
+            out.push(b'\n');
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(feature = "hashing")]
+impl FileFormatReader for ReceiptArchive {
+    fn read_file(path: &Path) -> SisterResult<Self> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    fn can_read(path: &Path) -> SisterResult<FileInfo> {
+        let header = Self::read_header(path)?;
+        header
+            .descriptor
+            .to_file_info(&std::fs::metadata(path)?, Some(&RECEIPT_ARCHIVE_VERSION))
+    }
+
+    fn file_version(path: &Path) -> SisterResult<Version> {
+        Ok(Self::read_header(path)?.descriptor.version)
+    }
+
+    /// Only one archive version exists; same-major data passes through.
+    fn migrate(data: &[u8], from_version: Version) -> SisterResult<Vec<u8>> {
+        if VersionCompatibility::is_compatible(&RECEIPT_ARCHIVE_VERSION, &from_version) {
+            Ok(data.to_vec())
+        } else {
+            Err(SisterError::new(
+                ErrorCode::NotImplemented,
+                format!(
+                    "No receipt archive migration from {} to {}",
+                    from_version, RECEIPT_ARCHIVE_VERSION
+                ),
+            ))
+        }
+    }
+}
+
+/// Read and verify an `.arcpt` export.
+#[cfg(feature = "hashing")]
+pub fn import_receipts(path: &Path) -> SisterResult<ReceiptArchive> {
+    ReceiptArchive::read_file(path)
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// External anchoring
+// ═══════════════════════════════════════════════════════════════════
+
+/// Proof that a chain head hash existed at a point in time, issued by
+/// a third party (RFC 3161 TSA, transparency log, ...).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct ReceiptAnchor {
+    /// Provider that issued the proof (`AnchorProvider::name`)
+    pub provider: String,
+
+    /// Chain head hash that was anchored
+    pub anchored_hash: String,
+
+    /// Chain position of that head
+    pub chain_position: u64,
+
+    /// Time asserted by the provider
+    pub anchored_at: DateTime<Utc>,
+
+    /// Provider-specific proof (e.g. base64 RFC 3161 token, log inclusion proof)
+    pub proof: String,
+
+    /// Provider-specific extras (log index, TSA policy OID, ...)
+    #[serde(default, skip_serializing_if = "Metadata::is_empty")]
+    pub metadata: Metadata,
+}
+
+impl ReceiptAnchor {
+    /// Whether this anchor covers `receipt` (same hash and position).
+    pub fn covers(&self, receipt: &Receipt) -> bool {
+        self.anchored_hash == receipt.hash && self.chain_position == receipt.chain_position
+    }
+}
+
+/// Handle for an anchor request that may complete later.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnchorTicket {
+    /// Provider the hash was submitted to
+    pub provider: String,
+
+    /// Chain head hash that was submitted
+    pub anchored_hash: String,
+
+    /// Chain position of that head
+    pub chain_position: u64,
+
+    /// When it was submitted
+    pub submitted_at: DateTime<Utc>,
+
+    /// Provider-specific request handle
+    pub reference: String,
+}
+
+/// Pluggable third-party timestamping for receipt chains.
+///
+/// Anchoring the chain head transitively timestamps every receipt
+/// before it, so providers only ever see one hash per anchor.
+pub trait AnchorProvider: Send + Sync {
+    /// Provider name recorded in `ReceiptAnchor::provider`
+    fn name(&self) -> &str;
+
+    /// Submit a chain head hash for anchoring
+    fn submit(&self, head_hash: &str, chain_position: u64) -> SisterResult<AnchorTicket>;
+
+    /// Fetch the proof for a submission. `None` while still pending
+    fn retrieve(&self, ticket: &AnchorTicket) -> SisterResult<Option<ReceiptAnchor>>;
+
+    /// Check a proof against the provider (signature, log inclusion, ...)
+    fn verify(&self, anchor: &ReceiptAnchor) -> SisterResult<bool> {
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            format!("Anchor provider {} cannot verify proofs", anchor.provider),
+        ))
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// Compaction
+// ═══════════════════════════════════════════════════════════════════
+//
+// Chain-continuity rules. A chain is zero or more compacted segments
+// followed by live receipts, where:
+// 1. Chain positions are contiguous across the whole chain.
+// 2. Each live receipt's `previous_hash` is the `hash` of the receipt
+//    before it.
+// 3. A segment's `previous_hash` is the `last_hash` of the segment
+//    before it, and the first live receipt's `previous_hash` is the
+//    `last_hash` of the final segment.
+// Only a prefix of the live chain can be compacted, so these rules
+// keep holding after every compaction.
+
+/// Signed summary standing in for a compacted run of receipts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompactedSegment {
+    /// Chain position of the first compacted receipt
+    pub first_position: u64,
+
+    /// Chain position of the last compacted receipt
+    pub last_position: u64,
+
+    /// `previous_hash` of the first compacted receipt
+    pub previous_hash: String,
+
+    /// `hash` of the last compacted receipt
+    pub last_hash: String,
+
+    /// Merkle root over the compacted receipts (`receipt_merkle_root`)
+    pub merkle_root: String,
+
+    /// `created_at` of the first compacted receipt
+    pub first_created_at: DateTime<Utc>,
+
+    /// `created_at` of the last compacted receipt
+    pub last_created_at: DateTime<Utc>,
+
+    /// When the segment was produced
+    pub compacted_at: DateTime<Utc>,
+
+    /// Signature over `canonical_bytes` (from Identity)
+    #[serde(default)]
+    pub signature: String,
+}
+
+impl CompactedSegment {
+    /// Summarize a contiguous, linked run of receipts (unsigned).
+    #[cfg(feature = "hashing")]
+    pub fn from_receipts(receipts: &[Receipt]) -> SisterResult<Self> {
+        let (Some(first), Some(last)) = (receipts.first(), receipts.last()) else {
+            return Err(SisterError::invalid_input(
+                "Cannot compact an empty run of receipts",
+            ));
+        };
+        check_links(receipts)?;
+        Ok(Self {
+            first_position: first.chain_position,
+            last_position: last.chain_position,
+            previous_hash: first.previous_hash.clone(),
+            last_hash: last.hash.clone(),
+            merkle_root: receipt_merkle_root(receipts)?.unwrap_or_default(),
+            first_created_at: first.created_at,
+            last_created_at: last.created_at,
+            compacted_at: Utc::now(),
+            signature: String::new(),
+        })
+    }
+
+    /// Attach a signature.
+    pub fn signed(mut self, signature: impl Into<String>) -> Self {
+        self.signature = signature.into();
+        self
+    }
+
+    /// Canonical byte form for signing (every field except `signature`).
+    pub fn canonical_bytes(&self) -> SisterResult<Vec<u8>> {
+        canonical_json(&Self {
+            signature: String::new(),
+            ..self.clone()
+        })
+    }
+
+    /// Number of receipts summarized.
+    pub fn receipt_count(&self) -> u64 {
+        self.last_position - self.first_position + 1
+    }
+
+    /// Whether `chain_position` falls inside this segment.
+    pub fn contains(&self, chain_position: u64) -> bool {
+        (self.first_position..=self.last_position).contains(&chain_position)
+    }
+
+    /// Whether `proof` shows a receipt was part of this segment.
+    #[cfg(feature = "hashing")]
+    pub fn verify_proof(&self, proof: &ReceiptProof) -> bool {
+        proof.leaf_count as u64 == self.receipt_count() && verify_proof(&self.merkle_root, proof)
+    }
+}
+
+/// The prefix of `live` (in chain order) eligible for compaction:
+/// receipts created before `before`, stopping at the first that isn't.
+pub fn compaction_prefix(live: &[Receipt], before: DateTime<Utc>) -> &[Receipt] {
+    let end = live
+        .iter()
+        .position(|r| r.created_at >= before)
+        .unwrap_or(live.len());
+    &live[..end]
+}
+
+fn chain_break(position: u64, message: String) -> SisterError {
+    SisterError::new(ErrorCode::ChecksumMismatch, message).with_context("chain_position", position)
+}
+
+fn check_links(receipts: &[Receipt]) -> SisterResult<()> {
+    for pair in receipts.windows(2) {
+        let (prev, next) = (&pair[0], &pair[1]);
+        if next.chain_position != prev.chain_position + 1 {
+            return Err(chain_break(
+                next.chain_position,
+                format!(
+                    "Receipt chain gap between positions {} and {}",
+                    prev.chain_position, next.chain_position
+                ),
+            ));
+        }
+        if next.previous_hash != prev.hash {
+            return Err(chain_break(
+                next.chain_position,
+                format!(
+                    "Receipt at position {} does not link to its predecessor",
+                    next.chain_position
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check the chain-continuity rules across compacted segments and the
+/// live receipts that follow them (both in chain order).
+///
+/// Signatures and receipt hashes are not checked here; use
+/// `VerifyingKey::verify_segment` and `Receipt::verify_signature`.
+pub fn verify_chain_continuity(
+    segments: &[CompactedSegment],
+    live: &[Receipt],
+) -> SisterResult<()> {
+    for pair in segments.windows(2) {
+        let (prev, next) = (&pair[0], &pair[1]);
+        if next.first_position != prev.last_position + 1 || next.previous_hash != prev.last_hash {
+            return Err(chain_break(
+                next.first_position,
+                format!(
+                    "Compacted segment starting at {} does not continue the previous segment",
+                    next.first_position
+                ),
+            ));
+        }
+    }
+    if let (Some(segment), Some(first)) = (segments.last(), live.first()) {
+        if first.chain_position != segment.last_position + 1
+            || first.previous_hash != segment.last_hash
+        {
+            return Err(chain_break(
+                first.chain_position,
+                format!(
+                    "Live receipt at position {} does not continue the compacted chain",
+                    first.chain_position
+                ),
+            ));
+        }
+    }
+    check_links(live)
+}
+
 /// Filter for querying receipts.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ReceiptFilter {
@@ -216,6 +1273,14 @@ pub struct ReceiptFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub context_id: Option<ContextId>,
 
+    /// Filter by Hydra run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_id: Option<String>,
+
+    /// Filter by step within the run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub step_id: Option<u64>,
+
     /// Filter by time (after).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub after: Option<DateTime<Utc>>,
@@ -257,6 +1322,16 @@ impl ReceiptFilter {
         self
     }
 
+    pub fn in_run(mut self, run_id: impl Into<String>) -> Self {
+        self.run_id = Some(run_id.into());
+        self
+    }
+
+    pub fn at_step(mut self, step_id: u64) -> Self {
+        self.step_id = Some(step_id);
+        self
+    }
+
     pub fn after(mut self, time: DateTime<Utc>) -> Self {
         self.after = Some(time);
         self
@@ -276,8 +1351,91 @@ impl ReceiptFilter {
         self.limit = Some(limit);
         self
     }
+
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Whether a receipt passes every criterion (ignores `limit`/`offset`).
+    ///
+    /// Time bounds compare `created_at`, both exclusive. `outcome` is
+    /// compared with `ActionOutcome::status`.
+    pub fn matches(&self, receipt: &Receipt) -> bool {
+        let action = &receipt.action;
+        self.sister_type.is_none_or(|st| action.sister_type == st)
+            && self
+                .action_type
+                .as_deref()
+                .is_none_or(|at| action.action_type == at)
+            && self
+                .context_id
+                .is_none_or(|id| action.context_id == Some(id))
+            && self
+                .run_id
+                .as_deref()
+                .is_none_or(|run| action.run_id.as_deref() == Some(run))
+            && self.step_id.is_none_or(|step| action.step_id == Some(step))
+            && self.after.is_none_or(|t| receipt.created_at > t)
+            && self.before.is_none_or(|t| receipt.created_at < t)
+            && self
+                .outcome
+                .as_deref()
+                .is_none_or(|o| action.outcome.status() == o)
+    }
+
+    /// Filter receipts, then apply `offset` and `limit`.
+    ///
+    /// A complete `list_receipts` for stores that can iterate their receipts.
+    pub fn apply(&self, receipts: impl IntoIterator<Item = Receipt>) -> Vec<Receipt> {
+        receipts
+            .into_iter()
+            .filter(|r| self.matches(r))
+            .skip(self.offset.unwrap_or(0))
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+/// Position in the receipt chain to continue listing from.
+///
+/// Opaque to callers; pass back the `next_cursor` of the previous page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ReceiptCursor(pub u64);
+
+impl ReceiptCursor {
+    /// Continue after the receipt at `chain_position`.
+    pub fn after(chain_position: u64) -> Self {
+        Self(chain_position)
+    }
+
+    /// Chain position of the last receipt already seen.
+    pub fn chain_position(&self) -> u64 {
+        self.0
+    }
 }
 
+/// One page of receipts, in chain order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptPage {
+    pub receipts: Vec<Receipt>,
+
+    /// Cursor for the next page; `None` when the chain is exhausted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<ReceiptCursor>,
+}
+
+impl ReceiptPage {
+    /// Whether more receipts follow.
+    pub fn has_more(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+}
+
+/// Iterator over receipts, fetched a page at a time.
+pub type ReceiptIter<'a> = Box<dyn Iterator<Item = SisterResult<Receipt>> + 'a>;
+
 /// Receipt integration trait.
 ///
 /// Sisters that create auditable actions implement this trait to
@@ -286,6 +1444,19 @@ pub trait ReceiptIntegration {
     /// Create a receipt for an action (via Identity).
     fn create_receipt(&self, action: ActionRecord) -> SisterResult<ReceiptId>;
 
+    /// Create receipts for several actions in one call.
+    ///
+    /// Implementations must be all-or-nothing and allocate consecutive
+    /// chain positions, in input order, without interleaving other
+    /// writers. The default calls `create_receipt` per action and so
+    /// gives neither guarantee; Identity-backed sisters should override.
+    fn create_receipts(&self, actions: Vec<ActionRecord>) -> SisterResult<Vec<ReceiptId>> {
+        actions
+            .into_iter()
+            .map(|action| self.create_receipt(action))
+            .collect()
+    }
+
     /// Get receipt by ID (from Identity).
     fn get_receipt(&self, id: ReceiptId) -> SisterResult<Receipt>;
 
@@ -302,6 +1473,214 @@ pub trait ReceiptIntegration {
     fn receipts_for_action(&self, action_type: &str) -> SisterResult<Vec<Receipt>> {
         self.list_receipts(ReceiptFilter::new().action(action_type))
     }
+
+    /// Inclusion proof for a receipt.
+    ///
+    /// Default: the batch is every receipt from `list_receipts`, ordered
+    /// by `chain_position`. Sisters that anchor fixed batches should
+    /// override to prove against the batch that was anchored.
+    #[cfg(feature = "hashing")]
+    fn prove(&self, id: ReceiptId) -> SisterResult<ReceiptProof> {
+        let mut receipts = self.list_receipts(ReceiptFilter::new())?;
+        receipts.sort_by_key(|r| r.chain_position);
+        let index = receipts
+            .iter()
+            .position(|r| r.id == id)
+            .ok_or_else(|| SisterError::not_found(format!("Receipt {}", id)))?;
+        ReceiptProof::build(&receipts, index)
+    }
+
+    #[cfg(not(feature = "hashing"))]
+    fn prove(&self, id: ReceiptId) -> SisterResult<ReceiptProof> {
+        let _ = id;
+        Err(SisterError::feature_disabled("hashing"))
+    }
+
+    /// List one page of matching receipts after `cursor`.
+    ///
+    /// `limit`/`offset` on the filter are ignored; paging is by cursor.
+    /// Default: filters the whole `list_receipts` result, so stores with
+    /// large chains should override with an indexed seek.
+    fn list_receipts_page(
+        &self,
+        filter: ReceiptFilter,
+        cursor: Option<ReceiptCursor>,
+        page_size: usize,
+    ) -> SisterResult<ReceiptPage> {
+        if page_size == 0 {
+            return Err(SisterError::invalid_input("page_size must be at least 1"));
+        }
+        let mut receipts = self.list_receipts(ReceiptFilter {
+            limit: None,
+            offset: None,
+            ..filter
+        })?;
+        if let Some(cursor) = cursor {
+            receipts.retain(|r| r.chain_position > cursor.chain_position());
+        }
+        receipts.sort_by_key(|r| r.chain_position);
+        let has_more = receipts.len() > page_size;
+        receipts.truncate(page_size);
+        let next_cursor = receipts
+            .last()
+            .filter(|_| has_more)
+            .map(|r| ReceiptCursor::after(r.chain_position));
+        Ok(ReceiptPage {
+            receipts,
+            next_cursor,
+        })
+    }
+
+    /// Walk every matching receipt in chain order, `page_size` at a time.
+    ///
+    /// Stops after yielding the first error.
+    fn receipt_stream(&self, filter: ReceiptFilter, page_size: usize) -> ReceiptIter<'_> {
+        let mut cursor = None;
+        let mut buffer = std::collections::VecDeque::new();
+        let mut done = false;
+        Box::new(std::iter::from_fn(move || loop {
+            if let Some(receipt) = buffer.pop_front() {
+                return Some(Ok(receipt));
+            }
+            if done {
+                return None;
+            }
+            match self.list_receipts_page(filter.clone(), cursor, page_size) {
+                Ok(page) => {
+                    cursor = page.next_cursor;
+                    done = cursor.is_none();
+                    buffer.extend(page.receipts);
+                }
+                Err(e) => {
+                    done = true;
+                    return Some(Err(e));
+                }
+            }
+        }))
+    }
+
+    /// Submit the current chain head to an anchor provider.
+    fn anchor_head(&self, provider: &dyn AnchorProvider) -> SisterResult<AnchorTicket> {
+        let head = self
+            .list_receipts(ReceiptFilter::new())?
+            .into_iter()
+            .max_by_key(|r| r.chain_position)
+            .ok_or_else(|| SisterError::new(ErrorCode::InvalidState, "Receipt chain is empty"))?;
+        provider.submit(&head.hash, head.chain_position)
+    }
+
+    /// Replace receipts created before `before` with a signed
+    /// `CompactedSegment` (see the chain-continuity rules above).
+    ///
+    /// Only the eligible prefix of the live chain is compacted
+    /// (`compaction_prefix`). Returns `None` if nothing was eligible.
+    fn compact_receipts(&self, before: DateTime<Utc>) -> SisterResult<Option<CompactedSegment>> {
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            format!("Receipt compaction is not supported (before {})", before),
+        ))
+    }
+
+    /// Compacted segments, in chain order.
+    fn compacted_segments(&self) -> SisterResult<Vec<CompactedSegment>> {
+        Ok(vec![])
+    }
+
+    /// Check continuity across compacted segments and live receipts.
+    fn verify_chain(&self) -> SisterResult<()> {
+        let mut live = self.list_receipts(ReceiptFilter::new())?;
+        live.sort_by_key(|r| r.chain_position);
+        verify_chain_continuity(&self.compacted_segments()?, &live)
+    }
+
+    /// Export matching receipts to an `.arcpt` file.
+    ///
+    /// Returns the number of receipts written.
+    #[cfg(feature = "hashing")]
+    fn export_receipts(&self, filter: ReceiptFilter, path: &Path) -> SisterResult<usize> {
+        let archive = ReceiptArchive::new(self.list_receipts(filter)?)?;
+        archive.write_file(path)?;
+        Ok(archive.receipts.len())
+    }
+
+    #[cfg(not(feature = "hashing"))]
+    fn export_receipts(&self, filter: ReceiptFilter, path: &Path) -> SisterResult<usize> {
+        let _ = (filter, path);
+        Err(SisterError::feature_disabled("hashing"))
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// Live subscription
+// ═══════════════════════════════════════════════════════════════════
+
+/// Receiver for newly created receipts (broadcast channel).
+#[cfg(feature = "events")]
+pub type ReceiptReceiver = broadcast::Receiver<Receipt>;
+
+/// Push notification of new receipts, for monitors and the Execution
+/// Gate that would otherwise poll `list_receipts`.
+#[cfg(feature = "events")]
+pub trait ReceiptWatcher {
+    /// Receive every receipt created from now on that matches `filter`
+    /// (`limit`/`offset` are ignored).
+    fn subscribe_receipts(&self, filter: ReceiptFilter) -> ReceiptReceiver;
+}
+
+/// Helper for implementing `ReceiptWatcher`: per-subscriber filtered
+/// broadcast channels.
+#[cfg(feature = "events")]
+pub struct ReceiptBroadcaster {
+    subscribers: std::sync::Mutex<Vec<(ReceiptFilter, broadcast::Sender<Receipt>)>>,
+    capacity: usize,
+}
+
+#[cfg(feature = "events")]
+impl ReceiptBroadcaster {
+    /// Create a broadcaster; `capacity` bounds each subscriber's backlog.
+    ///
+    /// A capacity of 0 is raised to 1, the smallest backlog a channel allows.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            subscribers: std::sync::Mutex::new(Vec::new()),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Subscribe with a filter.
+    pub fn subscribe(&self, filter: ReceiptFilter) -> ReceiptReceiver {
+        let (sender, receiver) = broadcast::channel(self.capacity);
+        self.subscribers.lock().unwrap().push((filter, sender));
+        receiver
+    }
+
+    /// Deliver a new receipt to matching subscribers.
+    ///
+    /// Returns how many subscribers received it. Subscribers whose
+    /// receivers were dropped are pruned.
+    pub fn publish(&self, receipt: &Receipt) -> usize {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.retain(|(_, sender)| sender.receiver_count() > 0);
+        subscribers
+            .iter()
+            .filter(|(filter, _)| filter.matches(receipt))
+            .filter(|(_, sender)| sender.send(receipt.clone()).is_ok())
+            .count()
+    }
+
+    /// Number of live subscriptions.
+    pub fn subscriber_count(&self) -> usize {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.retain(|(_, sender)| sender.receiver_count() > 0);
+        subscribers.len()
+    }
+}
+
+#[cfg(feature = "events")]
+impl Default for ReceiptBroadcaster {
+    fn default() -> Self {
+        Self::new(256)
+    }
 }
 
 /// Helper for creating action records easily.
@@ -339,7 +1718,7 @@ impl ActionBuilder {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "hashing"))]
 mod tests {
     use super::*;
 
@@ -377,4 +1756,454 @@ mod tests {
         assert_eq!(filter.outcome, Some("success".to_string()));
         assert_eq!(filter.limit, Some(10));
     }
+
+    #[test]
+    fn test_canonical_bytes_sort_keys() {
+        let mut a = ActionRecord::new(SisterType::Memory, "memory_add", ActionOutcome::success());
+        let mut b = a.clone();
+        a = a
+            .param("zeta", 1)
+            .param("alpha", serde_json::json!({"y": 2, "x": 1}));
+        b = b
+            .param("alpha", serde_json::json!({"x": 1, "y": 2}))
+            .param("zeta", 1);
+
+        let bytes = a.canonical_bytes().unwrap();
+        assert_eq!(bytes, b.canonical_bytes().unwrap());
+        let text = String::from_utf8(bytes).unwrap();
+        assert!(text.contains(r#""parameters":{"alpha":{"x":1,"y":2},"zeta":1}"#));
+
+        // Values JSON cannot represent fail instead of signing `null`
+        let unrepresentable = std::collections::HashMap::from([((1u8, 2u8), 0u8)]);
+        assert!(canonical_json(&unrepresentable).is_err());
+    }
+
+    #[test]
+    fn test_canonical_bytes_match_jcs() {
+        // Keys sort by UTF-16 code unit: U+1F600 (surrogate D83D) sorts
+        // before U+FB01, unlike a UTF-8 byte order
+        let record = ActionRecord::new(SisterType::Memory, "memory_add", ActionOutcome::success())
+            .param("\u{FB01}", 1)
+            .param("\u{1F600}", -2)
+            .param("max", MAX_EXACT_INT)
+            .param("score", 0.25);
+        let text = String::from_utf8(record.canonical_bytes().unwrap()).unwrap();
+        assert!(text.contains(
+            "\"parameters\":{\"max\":9007199254740992,\"score\":0.25,\"\u{1F600}\":-2,\"\u{FB01}\":1}"
+        ));
+
+        // RFC 8785 appendix B, plus a few more
+        let cases = [
+            (serde_json::json!(0.0), "0"),
+            (serde_json::json!(-0.0), "0"),
+            (serde_json::json!(1.0), "1"),
+            (serde_json::json!(-4.5), "-4.5"),
+            (serde_json::json!(5e-324), "5e-324"),
+            (serde_json::json!(-5e-324), "-5e-324"),
+            (
+                serde_json::json!(1.7976931348623157e308),
+                "1.7976931348623157e+308",
+            ),
+            (serde_json::json!(9007199254740993u64), "9007199254740992"),
+            (
+                serde_json::json!(295147905179352830000.0),
+                "295147905179352830000",
+            ),
+            (
+                serde_json::json!(9.999999999999997e22),
+                "9.999999999999997e+22",
+            ),
+            (serde_json::json!(1e23), "1e+23"),
+            (serde_json::json!(1e21), "1e+21"),
+            (
+                serde_json::json!(999999999999999700000.0),
+                "999999999999999700000",
+            ),
+            (serde_json::json!(0.000001), "0.000001"),
+            (serde_json::json!(1e-7), "1e-7"),
+            (serde_json::json!(333333333.3333332), "333333333.3333332"),
+            (serde_json::json!(i64::MIN), "-9223372036854776000"),
+        ];
+        for (value, expected) in cases {
+            let bytes = canonical_json(&value).unwrap();
+            assert_eq!(String::from_utf8(bytes).unwrap(), expected, "{value}");
+        }
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn test_ed25519_receipt_signature() {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let action = ActionBuilder::new(SisterType::Memory, "memory_add").success();
+        let receipt = Receipt {
+            id: ReceiptId::new(),
+            signature: sign_action(&action, &key).unwrap(),
+            action,
+            chain_position: 0,
+            previous_hash: String::new(),
+            hash: String::new(),
+            created_at: Utc::now(),
+            anchor: None,
+        };
+
+        let public = key.verifying_key();
+        assert!(receipt.verify_signature(&public.to_bytes()));
+        assert_eq!(VerifyingKey::from_hex(&public.to_string()).unwrap(), public);
+
+        let other = SigningKey::generate().verifying_key();
+        assert!(!receipt.verify_signature(&other.to_bytes()));
+        assert!(!receipt.verify_signature(b"short"));
+
+        let mut tampered = receipt;
+        tampered.action.action_type = "memory_delete".into();
+        assert!(!tampered.verify_signature(&public.to_bytes()));
+    }
+
+    #[test]
+    fn test_merkle_proofs() {
+        let receipts: Vec<Receipt> = (0..5)
+            .map(|i| Receipt {
+                id: ReceiptId::new(),
+                action: ActionBuilder::new(SisterType::Memory, format!("action_{}", i)).success(),
+                signature: String::new(),
+                chain_position: i,
+                previous_hash: String::new(),
+                hash: format!("hash_{}", i),
+                created_at: Utc::now(),
+                anchor: None,
+            })
+            .collect();
+        let root = receipt_merkle_root(&receipts).unwrap().unwrap();
+
+        for (i, receipt) in receipts.iter().enumerate() {
+            let proof = ReceiptProof::build(&receipts, i).unwrap();
+            assert!(verify_proof(&root, &proof), "leaf {}", i);
+            assert!(proof.matches(receipt));
+        }
+
+        let mut proof = ReceiptProof::build(&receipts, 2).unwrap();
+        assert!(!proof.matches(&receipts[3]));
+        proof.path[0].hash = hex::encode([0u8; 32]);
+        assert!(!verify_proof(&root, &proof));
+
+        assert!(ReceiptProof::build(&receipts, 5).is_err());
+        assert!(receipt_merkle_root(&[]).unwrap().is_none());
+        let single = ReceiptProof::build(&receipts[..1], 0).unwrap();
+        assert!(verify_proof(&single.root.clone(), &single));
+    }
+
+    #[test]
+    fn test_receipt_archive_roundtrip() {
+        let receipts: Vec<Receipt> = (0..3)
+            .rev()
+            .map(|i| Receipt {
+                id: ReceiptId::new(),
+                action: ActionBuilder::new(SisterType::Memory, "memory_add").success(),
+                signature: "sig".into(),
+                chain_position: i,
+                previous_hash: String::new(),
+                hash: format!("hash_{}", i),
+                created_at: Utc::now(),
+                anchor: None,
+            })
+            .collect();
+        let archive = ReceiptArchive::new(receipts).unwrap().signer("abcd");
+        let dir = crate::test_support::TempDir::new("receipts");
+        let path = dir.join("receipts.arcpt");
+        archive.write_file(&path).unwrap();
+
+        let imported = import_receipts(&path).unwrap();
+        assert_eq!(imported.header, archive.header);
+        assert_eq!(imported.receipts[0].chain_position, 0);
+        assert_eq!(
+            ReceiptArchive::can_read(&path).unwrap().format_id,
+            RECEIPT_ARCHIVE_FORMAT
+        );
+
+        // Dropping a receipt line breaks the count check
+        let bytes = std::fs::read(&path).unwrap();
+        let text = String::from_utf8(bytes).unwrap();
+        let truncated: Vec<&str> = text.lines().take(3).collect();
+        let err = ReceiptArchive::from_bytes(truncated.join("\n").as_bytes()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ChecksumMismatch);
+    }
+
+    struct InstantAnchor;
+
+    impl AnchorProvider for InstantAnchor {
+        fn name(&self) -> &str {
+            "instant"
+        }
+
+        fn submit(&self, head_hash: &str, chain_position: u64) -> SisterResult<AnchorTicket> {
+            Ok(AnchorTicket {
+                provider: self.name().into(),
+                anchored_hash: head_hash.into(),
+                chain_position,
+                submitted_at: Utc::now(),
+                reference: "req-1".into(),
+            })
+        }
+
+        fn retrieve(&self, ticket: &AnchorTicket) -> SisterResult<Option<ReceiptAnchor>> {
+            Ok(Some(ReceiptAnchor {
+                provider: ticket.provider.clone(),
+                anchored_hash: ticket.anchored_hash.clone(),
+                chain_position: ticket.chain_position,
+                anchored_at: ticket.submitted_at,
+                proof: "tsr".into(),
+                metadata: Metadata::new(),
+            }))
+        }
+    }
+
+    #[test]
+    fn test_anchor_does_not_change_leaf_hash() {
+        let mut receipt = Receipt {
+            id: ReceiptId::new(),
+            action: ActionBuilder::new(SisterType::Memory, "memory_add").success(),
+            signature: String::new(),
+            chain_position: 7,
+            previous_hash: String::new(),
+            hash: "head".into(),
+            created_at: Utc::now(),
+            anchor: None,
+        };
+        let before = receipt.leaf_hash().unwrap();
+
+        let provider = InstantAnchor;
+        let ticket = provider
+            .submit(&receipt.hash, receipt.chain_position)
+            .unwrap();
+        let anchor = provider.retrieve(&ticket).unwrap().unwrap();
+        assert!(anchor.covers(&receipt));
+        assert!(provider.verify(&anchor).is_err());
+
+        receipt.anchor = Some(anchor);
+        assert!(receipt.is_anchored());
+        assert_eq!(receipt.leaf_hash().unwrap(), before);
+        assert_eq!(
+            ReceiptArchive::new(vec![receipt])
+                .unwrap()
+                .header
+                .anchors
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_receipt_filter_matches() {
+        let context = ContextId::new();
+        let start = Utc::now();
+        let receipt = Receipt {
+            id: ReceiptId::new(),
+            action: ActionBuilder::new(SisterType::Vision, "vision_capture")
+                .failure("TIMEOUT", "camera timed out")
+                .in_context(context),
+            signature: String::new(),
+            chain_position: 1,
+            previous_hash: String::new(),
+            hash: String::new(),
+            created_at: start,
+            anchor: None,
+        };
+
+        assert!(ReceiptFilter::new().matches(&receipt));
+        assert!(ReceiptFilter::new()
+            .for_sister(SisterType::Vision)
+            .action("vision_capture")
+            .in_context(context)
+            .matches(&receipt));
+        assert!(!ReceiptFilter::new().successful_only().matches(&receipt));
+        assert!(!ReceiptFilter::new()
+            .in_context(ContextId::new())
+            .matches(&receipt));
+        assert!(!ReceiptFilter::new().after(start).matches(&receipt));
+        assert!(!ReceiptFilter::new().in_run("run_1").matches(&receipt));
+        assert!(ReceiptFilter::new()
+            .before(start + chrono::Duration::seconds(1))
+            .matches(&receipt));
+
+        let page = ReceiptFilter::new().offset(1).limit(1).apply(vec![
+            receipt.clone(),
+            receipt.clone(),
+            receipt,
+        ]);
+        assert_eq!(page.len(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "events")]
+    fn test_receipt_broadcaster_filters() {
+        let broadcaster = ReceiptBroadcaster::new(8);
+        let mut vision = broadcaster.subscribe(ReceiptFilter::new().for_sister(SisterType::Vision));
+        let mut all = broadcaster.subscribe(ReceiptFilter::new());
+        drop(broadcaster.subscribe(ReceiptFilter::new()));
+
+        let receipt = Receipt {
+            id: ReceiptId::new(),
+            action: ActionBuilder::new(SisterType::Memory, "memory_add").success(),
+            signature: String::new(),
+            chain_position: 1,
+            previous_hash: String::new(),
+            hash: String::new(),
+            created_at: Utc::now(),
+            anchor: None,
+        };
+        assert_eq!(broadcaster.publish(&receipt), 1);
+        assert_eq!(broadcaster.subscriber_count(), 2);
+        assert_eq!(all.try_recv().unwrap().id, receipt.id);
+        assert!(vision.try_recv().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "events")]
+    fn test_receipt_broadcaster_zero_capacity() {
+        let broadcaster = ReceiptBroadcaster::new(0);
+        let mut receiver = broadcaster.subscribe(ReceiptFilter::new());
+        let receipt = Receipt {
+            id: ReceiptId::new(),
+            action: ActionBuilder::new(SisterType::Memory, "memory_add").success(),
+            signature: String::new(),
+            chain_position: 1,
+            previous_hash: String::new(),
+            hash: String::new(),
+            created_at: Utc::now(),
+            anchor: None,
+        };
+        assert_eq!(broadcaster.publish(&receipt), 1);
+        assert_eq!(receiver.try_recv().unwrap().id, receipt.id);
+    }
+
+    #[test]
+    fn test_action_run_step() {
+        let command = HydraCommand {
+            command_type: "memory_add".into(),
+            params: Metadata::new(),
+            run_id: "run_42".into(),
+            step_id: 3,
+            budget: None,
+            dry_run: false,
+            idempotency_key: None,
+            priority: crate::hydra::Priority::Normal,
+            deadline: None,
+            exclusive: false,
+            accept: vec![],
+        };
+        let action = ActionBuilder::new(SisterType::Memory, "memory_add")
+            .success()
+            .for_command(&command);
+        assert_eq!(action.run_id.as_deref(), Some("run_42"));
+        assert_eq!(action.step_id, Some(3));
+
+        // Unset run/step stay out of the signed bytes
+        let plain = ActionBuilder::new(SisterType::Memory, "memory_add").success();
+        assert!(!String::from_utf8(plain.canonical_bytes().unwrap())
+            .unwrap()
+            .contains("run_id"));
+
+        let receipt = Receipt {
+            id: ReceiptId::new(),
+            action,
+            signature: String::new(),
+            chain_position: 1,
+            previous_hash: String::new(),
+            hash: String::new(),
+            created_at: Utc::now(),
+            anchor: None,
+        };
+        assert!(ReceiptFilter::new()
+            .in_run("run_42")
+            .at_step(3)
+            .matches(&receipt));
+        assert!(!ReceiptFilter::new().at_step(4).matches(&receipt));
+    }
+
+    fn linked_chain(len: u64) -> Vec<Receipt> {
+        let start = Utc::now() - chrono::Duration::seconds(len as i64);
+        let mut previous = "genesis".to_string();
+        (1..=len)
+            .map(|i| {
+                let receipt = Receipt {
+                    id: ReceiptId::new(),
+                    action: ActionBuilder::new(SisterType::Memory, "memory_add").success(),
+                    signature: String::new(),
+                    chain_position: i,
+                    previous_hash: previous.clone(),
+                    hash: format!("hash_{}", i),
+                    created_at: start + chrono::Duration::seconds(i as i64),
+                    anchor: None,
+                };
+                previous = receipt.hash.clone();
+                receipt
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_compaction_continuity() {
+        let chain = linked_chain(6);
+        let cutoff = chain[3].created_at;
+        let archived = compaction_prefix(&chain, cutoff);
+        assert_eq!(archived.len(), 3);
+
+        let segment = CompactedSegment::from_receipts(archived).unwrap();
+        assert_eq!(segment.receipt_count(), 3);
+        assert!(segment.contains(2));
+        let proof = ReceiptProof::build(archived, 1).unwrap();
+        assert!(segment.verify_proof(&proof));
+
+        let live = &chain[3..];
+        verify_chain_continuity(std::slice::from_ref(&segment), live).unwrap();
+
+        // Dropping a live receipt leaves a gap
+        let err = verify_chain_continuity(std::slice::from_ref(&segment), &chain[4..]).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ChecksumMismatch);
+
+        // The signature is not part of the signed bytes
+        let bytes = segment.canonical_bytes().unwrap();
+        assert_eq!(segment.signed("sig").canonical_bytes().unwrap(), bytes);
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn test_segment_signature() {
+        let key = SigningKey::from_bytes(&[9u8; 32]);
+        let segment = CompactedSegment::from_receipts(&linked_chain(2)).unwrap();
+        let signed = segment
+            .clone()
+            .signed(sign_segment(&segment, &key).unwrap());
+        assert!(key.verifying_key().verify_segment(&signed));
+        assert!(!key.verifying_key().verify_segment(&segment));
+    }
+
+    #[test]
+    fn test_receipt_evidence() {
+        let receipt = Receipt {
+            id: ReceiptId::new(),
+            action: ActionBuilder::new(SisterType::Vision, "vision_capture")
+                .success()
+                .in_run("run_1"),
+            signature: String::new(),
+            chain_position: 4,
+            previous_hash: String::new(),
+            hash: "hash_4".into(),
+            created_at: Utc::now(),
+            anchor: None,
+        };
+
+        let evidence = GroundingEvidence::from(&receipt);
+        assert_eq!(evidence.evidence_type, "receipt");
+        assert_eq!(evidence.id, receipt.id.to_string());
+        assert_eq!(evidence.data["chain_position"], 4);
+        assert_eq!(evidence.data["outcome"], "success");
+        assert_eq!(evidence.data["run_id"], "run_1");
+        assert!(!evidence.data.contains_key("step_id"));
+
+        let detail = receipt.to_evidence_detail();
+        assert_eq!(detail.source_sister, SisterType::Identity);
+        assert_eq!(detail.data["sister_type"], "vision");
+        assert_eq!(detail.content, evidence.summary);
+    }
 }
@@ -0,0 +1,114 @@
+//! Real `ResourceUsage` numbers for `Sister::health()`.
+//!
+//! Enabled by the `resource-probe` cargo feature. Process memory and
+//! handle counts come from `/proc/self` and are only available on
+//! Linux; elsewhere they read as zero. Disk usage works everywhere.
+//!
+//! ```rust,ignore
+//! fn health(&self) -> HealthStatus {
+//!     HealthStatus {
+//!         resources: ResourceUsage::probe(&self.config),
+//!         ..HealthStatus::with_status(Status::Ready)
+//!     }
+//! }
+//! ```
+
+use crate::sister::SisterConfig;
+use crate::types::ResourceUsage;
+use std::path::Path;
+
+impl ResourceUsage {
+    /// Sample process RSS, open handles, and the size of `config`'s data paths.
+    pub fn probe(config: &SisterConfig) -> Self {
+        Self {
+            memory_bytes: process_rss_bytes().unwrap_or(0),
+            disk_bytes: config_disk_bytes(config) as usize,
+            open_handles: open_handles().unwrap_or(0),
+        }
+    }
+}
+
+/// Resident set size of this process (Linux only).
+pub fn process_rss_bytes() -> Option<usize> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kb: usize = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kb * 1024)
+}
+
+/// Open file descriptors of this process (Linux only).
+pub fn open_handles() -> Option<usize> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    // The directory handle used for listing is counted too
+    let count = std::fs::read_dir("/proc/self/fd").ok()?.count();
+    Some(count.saturating_sub(1))
+}
+
+/// Total bytes under `path` (a file, or a directory walked recursively).
+///
+/// Unreadable entries and symlinks are skipped; a missing path is 0.
+pub fn path_size(path: &Path) -> u64 {
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if metadata.is_file() {
+        return metadata.len();
+    }
+    if !metadata.is_dir() {
+        return 0;
+    }
+    std::fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| path_size(&entry.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+/// Total bytes under `data_path` and every named `data_paths` entry.
+pub fn config_disk_bytes(config: &SisterConfig) -> u64 {
+    config
+        .data_path
+        .iter()
+        .chain(config.data_paths.values())
+        .map(|path| path_size(path))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn test_probe_config_paths() {
+        let dir = TempDir::new("probe");
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::write(dir.join("data.amem"), vec![0u8; 100]).unwrap();
+        std::fs::write(dir.join("nested/receipts.log"), vec![0u8; 50]).unwrap();
+
+        let config =
+            SisterConfig::new(dir.join("data.amem")).add_path("receipts", dir.join("nested"));
+        let usage = ResourceUsage::probe(&config);
+        assert_eq!(usage.disk_bytes, 150);
+        assert_eq!(path_size(&dir), 150);
+        assert_eq!(path_size(&dir.join("missing")), 0);
+        if cfg!(target_os = "linux") {
+            assert!(usage.memory_bytes > 0);
+            assert!(usage.open_handles > 0);
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! JSON Schemas for the wire DTOs (`schema` feature).
+//!
+//! Non-Rust sisters generate bindings or validate messages from these
+//! instead of reverse-engineering the serde output. The schemas follow
+//! the serde attributes, so they describe exactly what goes over the
+//! wire.
+//!
+//! ```rust,ignore
+//! for (name, schema) in agentic_sdk::schemas::all() {
+//!     std::fs::write(format!("schemas/{name}.json"), serde_json::to_string_pretty(&schema)?)?;
+//! }
+//! ```
+
+use schemars::{schema_for, Schema};
+use std::collections::BTreeMap;
+
+/// Every published DTO schema, keyed by type name.
+pub fn all() -> BTreeMap<&'static str, Schema> {
+    use crate::context::{ContextInfo, ContextSnapshot, SnapshotDelta};
+    use crate::diagnostics::DiagnosticReport;
+    use crate::errors::{ProtocolError, SisterError};
+    use crate::events::SisterEvent;
+    use crate::grounding::{EvidenceDetail, GroundingResult, GroundingSuggestion};
+    use crate::hydra::{CommandResult, DiscoveryDocument, HydraCommand};
+    use crate::query::{Query, QueryResult};
+    use crate::receipts::{ActionRecord, Receipt, ReceiptProof};
+    use crate::sister::{SisterConfig, SisterInfo};
+    use crate::types::{Capability, HealthStatus, SisterType, Version};
+
+    BTreeMap::from([
+        ("ActionRecord", schema_for!(ActionRecord)),
+        ("Capability", schema_for!(Capability)),
+        ("CommandResult", schema_for!(CommandResult)),
+        ("ContextInfo", schema_for!(ContextInfo)),
+        ("ContextSnapshot", schema_for!(ContextSnapshot)),
+        ("DiagnosticReport", schema_for!(DiagnosticReport)),
+        ("DiscoveryDocument", schema_for!(DiscoveryDocument)),
+        ("EvidenceDetail", schema_for!(EvidenceDetail)),
+        ("GroundingResult", schema_for!(GroundingResult)),
+        ("GroundingSuggestion", schema_for!(GroundingSuggestion)),
+        ("HealthStatus", schema_for!(HealthStatus)),
+        ("HydraCommand", schema_for!(HydraCommand)),
+        ("ProtocolError", schema_for!(ProtocolError)),
+        ("Query", schema_for!(Query)),
+        ("QueryResult", schema_for!(QueryResult)),
+        ("Receipt", schema_for!(Receipt)),
+        ("ReceiptProof", schema_for!(ReceiptProof)),
+        ("SisterConfig", schema_for!(SisterConfig)),
+        ("SisterError", schema_for!(SisterError)),
+        ("SisterEvent", schema_for!(SisterEvent)),
+        ("SisterInfo", schema_for!(SisterInfo)),
+        ("SisterType", schema_for!(SisterType)),
+        ("SnapshotDelta", schema_for!(SnapshotDelta)),
+        ("Version", schema_for!(Version)),
+    ])
+}
+
+/// Schema for one DTO by type name.
+pub fn get(name: &str) -> Option<Schema> {
+    all().remove(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GOLDEN: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden/schemas.json");
+
+    /// Fails on any wire-format change. If the change is intended,
+    /// regenerate with `UPDATE_GOLDEN=1 cargo test --features schema`.
+    #[test]
+    fn test_schemas_match_golden() {
+        let actual = serde_json::to_string_pretty(&all()).unwrap() + "\n";
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            std::fs::create_dir_all(std::path::Path::new(GOLDEN).parent().unwrap()).unwrap();
+            std::fs::write(GOLDEN, &actual).unwrap();
+            return;
+        }
+        let expected = std::fs::read_to_string(GOLDEN).unwrap_or_default();
+        assert!(
+            actual == expected,
+            "wire format changed; rerun with UPDATE_GOLDEN=1 if intended"
+        );
+    }
+
+    #[test]
+    fn test_schema_lookup() {
+        let schema = get("SisterError").unwrap();
+        let json = serde_json::to_value(&schema).unwrap();
+        assert_eq!(json["properties"]["code"]["$ref"], "#/$defs/ErrorCode");
+        assert_eq!(json["$defs"]["ErrorCode"]["type"], "string");
+        assert!(get("NoSuchType").is_none());
+    }
+}
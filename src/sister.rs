@@ -1,10 +1,20 @@
 //! Core Sister trait that all sisters must implement.
 
-use crate::errors::SisterResult;
-use crate::types::{Capability, HealthStatus, SisterType, Version};
+use crate::context::{CheckpointPolicy, ContextQuota, RetentionPolicy};
+use crate::diagnostics::{DiagnosticFinding, DiagnosticLevel, DiagnosticReport, CHECK_HEALTH};
+use crate::errors::{ErrorCode, Severity, SisterError, SisterResult};
+use crate::events::{EventManager, SisterEvent};
+#[cfg(feature = "file-io")]
+use crate::file_format::{FileLock, LockMode};
+use crate::hydra::{ParamKind, ParamSpec};
+use crate::types::{
+    Capability, HealthStatus, IdGenerator, SecretValue, SisterType, Status, Version,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 /// Configuration for initializing a sister.
 ///
@@ -14,7 +24,12 @@ use std::path::PathBuf;
 /// - Identity: multiple directories (`data_paths`)
 /// - Codebase: multiple graph files loaded dynamically
 /// - Time: single data file
+///
+/// Missing fields deserialize to their `Default` values.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(default)]
 pub struct SisterConfig {
     /// Primary data file/directory path.
     /// Used by sisters with a single data location (Memory, Vision, Time)
@@ -39,9 +54,40 @@ pub struct SisterConfig {
     /// Memory budget in megabytes (optional)
     pub memory_budget_mb: Option<usize>,
 
+    /// Take an advisory lock on the data path at init, so a second
+    /// instance pointed at the same file fails fast instead of corrupting it
+    #[serde(default)]
+    pub exclusive_lock: bool,
+
+    /// Session retention (sisters with `SessionManagement` apply it via `purge_sessions`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retention: Option<RetentionPolicy>,
+
+    /// Per-context size limits (sisters enforce them with `ContextQuota::enforce`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quota: Option<ContextQuota>,
+
+    /// Automatic checkpointing (sisters with `Checkpointing` apply it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint: Option<CheckpointPolicy>,
+
     /// Custom options (sister-specific)
     #[serde(default)]
     pub options: HashMap<String, serde_json::Value>,
+
+    /// Sensitive options (API keys); redacted in Debug and serialization
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub secrets: HashMap<String, SecretValue>,
+
+    /// Capabilities switched off by the operator (e.g. `trust_grant`).
+    /// Sisters omit them from `capabilities()` and refuse to run them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled_capabilities: Vec<String>,
+
+    /// ID generator for this process; hosts apply it with
+    /// `set_id_generator` at startup (it is process-wide, not per sister)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_generator: Option<IdGenerator>,
 }
 
 impl Default for SisterConfig {
@@ -52,7 +98,14 @@ impl Default for SisterConfig {
             create_if_missing: true,
             read_only: false,
             memory_budget_mb: None,
+            exclusive_lock: false,
+            retention: None,
+            quota: None,
+            checkpoint: None,
             options: HashMap::new(),
+            secrets: HashMap::new(),
+            disabled_capabilities: Vec::new(),
+            id_generator: None,
         }
     }
 }
@@ -113,6 +166,78 @@ impl SisterConfig {
         self
     }
 
+    /// Require an advisory lock on the data path
+    pub fn exclusive_lock(mut self, exclusive: bool) -> Self {
+        self.exclusive_lock = exclusive;
+        self
+    }
+
+    /// Set the session retention policy
+    pub fn retention(mut self, policy: RetentionPolicy) -> Self {
+        self.retention = Some(policy);
+        self
+    }
+
+    /// Set per-context size limits
+    pub fn quota(mut self, quota: ContextQuota) -> Self {
+        self.quota = Some(quota);
+        self
+    }
+
+    /// Set the automatic checkpoint policy
+    pub fn checkpoint(mut self, policy: CheckpointPolicy) -> Self {
+        self.checkpoint = Some(policy);
+        self
+    }
+
+    /// Acquire the lock requested by `exclusive_lock`, if any.
+    ///
+    /// `init()` implementations call this and keep the guard for the
+    /// sister's lifetime. Read-only configs take a shared lock so several
+    /// readers can coexist; otherwise the lock is exclusive.
+    /// Returns `None` when locking is disabled or there is no data path.
+    #[cfg(feature = "file-io")]
+    pub fn acquire_lock(&self) -> SisterResult<Option<FileLock>> {
+        let Some(path) = self.data_path.as_ref().filter(|_| self.exclusive_lock) else {
+            return Ok(None);
+        };
+        let mode = if self.read_only {
+            LockMode::Shared
+        } else {
+            LockMode::Exclusive
+        };
+        FileLock::acquire(path, mode).map(Some)
+    }
+
+    /// Disable a capability without recompiling the sister
+    pub fn disable_capability(mut self, name: impl Into<String>) -> Self {
+        self.disabled_capabilities.push(name.into());
+        self
+    }
+
+    pub fn is_capability_enabled(&self, name: &str) -> bool {
+        !self.disabled_capabilities.iter().any(|c| c == name)
+    }
+
+    /// `capabilities` without the disabled ones (for `Sister::capabilities`)
+    pub fn enabled_capabilities(&self, capabilities: Vec<Capability>) -> Vec<Capability> {
+        capabilities
+            .into_iter()
+            .filter(|c| self.is_capability_enabled(&c.name))
+            .collect()
+    }
+
+    /// `PermissionDenied` if `name` is disabled; call before running it
+    pub fn ensure_capability_enabled(&self, name: &str) -> SisterResult<()> {
+        if self.is_capability_enabled(name) {
+            return Ok(());
+        }
+        Err(
+            SisterError::permission_denied(format!("Capability {} is disabled", name))
+                .with_context("capability", name),
+        )
+    }
+
     /// Add a custom option
     pub fn option(mut self, key: impl Into<String>, value: impl Serialize) -> Self {
         if let Ok(v) = serde_json::to_value(value) {
@@ -127,6 +252,355 @@ impl SisterConfig {
             .get(key)
             .and_then(|v| serde_json::from_value(v.clone()).ok())
     }
+
+    /// Add a sensitive option
+    pub fn secret(mut self, key: impl Into<String>, value: impl Into<SecretValue>) -> Self {
+        self.secrets.insert(key.into(), value.into());
+        self
+    }
+
+    /// Get a sensitive option.
+    ///
+    /// Falls back to a string in `options`, so configs written before
+    /// `secrets` existed keep working.
+    pub fn secret_option(&self, key: &str) -> Option<SecretValue> {
+        self.secrets.get(key).cloned().or_else(|| {
+            self.options
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(SecretValue::from)
+        })
+    }
+}
+
+impl SisterConfig {
+    /// Load a config from `<PREFIX>_*` environment variables.
+    ///
+    /// | Variable | Field |
+    /// |----------|-------|
+    /// | `<PREFIX>_DATA_PATH` | `data_path` |
+    /// | `<PREFIX>_PATH_<NAME>` | `data_paths["<name>"]` |
+    /// | `<PREFIX>_READ_ONLY` | `read_only` |
+    /// | `<PREFIX>_CREATE_IF_MISSING` | `create_if_missing` |
+    /// | `<PREFIX>_EXCLUSIVE_LOCK` | `exclusive_lock` |
+    /// | `<PREFIX>_MEMORY_BUDGET_MB` | `memory_budget_mb` |
+    /// | `<PREFIX>_DISABLED_CAPABILITIES` | `disabled_capabilities` (comma-separated) |
+    /// | `<PREFIX>_ID_GENERATOR` | `id_generator` (`random` or `sortable`) |
+    /// | `<PREFIX>_OPT_<KEY>` | `options["<key>"]` |
+    /// | `<PREFIX>_SECRET_<KEY>` | `secrets["<key>"]` |
+    ///
+    /// Names after the prefix are lowercased. Booleans accept
+    /// `true/false/1/0/yes/no/on/off`; option values become a bool,
+    /// integer, float, or JSON array/object when they parse as one and
+    /// a string otherwise. Unparseable fields fail with `InvalidInput`
+    /// naming the variable. Unset variables keep the defaults.
+    pub fn from_env(prefix: &str) -> SisterResult<Self> {
+        Self::from_vars(prefix, std::env::vars())
+    }
+
+    /// `from_env` over an explicit set of variables.
+    pub fn from_vars(
+        prefix: &str,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> SisterResult<Self> {
+        let prefix = format!("{}_", prefix.trim_end_matches('_').to_uppercase());
+        let mut config = Self::default();
+        let mut vars: Vec<(String, String)> = vars
+            .into_iter()
+            .filter(|(name, _)| name.starts_with(&prefix))
+            .collect();
+        vars.sort();
+
+        for (name, value) in vars {
+            let key = &name[prefix.len()..];
+            let invalid = |expected: &str| {
+                SisterError::invalid_input(format!(
+                    "{} must be {}, got \"{}\"",
+                    name, expected, value
+                ))
+                .with_context("variable", &name)
+            };
+            let parse_bool = || match value.trim().to_lowercase().as_str() {
+                "true" | "1" | "yes" | "on" => Ok(true),
+                "false" | "0" | "no" | "off" => Ok(false),
+                _ => Err(invalid("a boolean")),
+            };
+
+            match key {
+                "DATA_PATH" => config.data_path = Some(PathBuf::from(&value)),
+                "READ_ONLY" => config.read_only = parse_bool()?,
+                "CREATE_IF_MISSING" => config.create_if_missing = parse_bool()?,
+                "EXCLUSIVE_LOCK" => config.exclusive_lock = parse_bool()?,
+                "DISABLED_CAPABILITIES" => {
+                    config.disabled_capabilities = value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|c| !c.is_empty())
+                        .map(String::from)
+                        .collect()
+                }
+                "ID_GENERATOR" => {
+                    config.id_generator = Some(
+                        value
+                            .parse()
+                            .map_err(|_| invalid("\"random\" or \"sortable\""))?,
+                    )
+                }
+                "MEMORY_BUDGET_MB" => {
+                    config.memory_budget_mb = Some(
+                        value
+                            .trim()
+                            .parse()
+                            .map_err(|_| invalid("a whole number"))?,
+                    )
+                }
+                _ => {
+                    if let Some(path_name) = key.strip_prefix("PATH_") {
+                        config
+                            .data_paths
+                            .insert(path_name.to_lowercase(), PathBuf::from(&value));
+                    } else if let Some(secret) = key.strip_prefix("SECRET_") {
+                        config
+                            .secrets
+                            .insert(secret.to_lowercase(), SecretValue::new(value.clone()));
+                    } else if let Some(option) = key.strip_prefix("OPT_") {
+                        config
+                            .options
+                            .insert(option.to_lowercase(), coerce_env_value(&value));
+                    }
+                }
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Best-effort typed value for an environment option.
+fn coerce_env_value(value: &str) -> serde_json::Value {
+    let trimmed = value.trim();
+    match trimmed.to_lowercase().as_str() {
+        "true" => return serde_json::Value::Bool(true),
+        "false" => return serde_json::Value::Bool(false),
+        _ => {}
+    }
+    if let Ok(n) = trimmed.parse::<i64>() {
+        return n.into();
+    }
+    if let Ok(x) = trimmed.parse::<f64>() {
+        if x.is_finite() {
+            return x.into();
+        }
+    }
+    if trimmed.starts_with('[') || trimmed.starts_with('{') {
+        if let Ok(json) = serde_json::from_str(trimmed) {
+            return json;
+        }
+    }
+    serde_json::Value::String(value.to_string())
+}
+
+/// What a sister needs from its `SisterConfig` (see `SisterConfig::validate`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigRequirements {
+    /// `data_path` must be set
+    #[serde(default)]
+    pub data_path: bool,
+
+    /// Named `data_paths` that must be set
+    #[serde(default)]
+    pub named_paths: Vec<String>,
+
+    /// Typed custom options
+    #[serde(default)]
+    pub options: Vec<ParamSpec>,
+}
+
+impl ConfigRequirements {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn data_path(mut self) -> Self {
+        self.data_path = true;
+        self
+    }
+
+    pub fn named_path(mut self, name: impl Into<String>) -> Self {
+        self.named_paths.push(name.into());
+        self
+    }
+
+    pub fn required_option(mut self, name: impl Into<String>, kind: ParamKind) -> Self {
+        self.options.push(ParamSpec {
+            name: name.into(),
+            kind,
+            required: true,
+        });
+        self
+    }
+
+    pub fn optional_option(mut self, name: impl Into<String>, kind: ParamKind) -> Self {
+        self.options.push(ParamSpec {
+            name: name.into(),
+            kind,
+            required: false,
+        });
+        self
+    }
+}
+
+/// One problem found by `SisterConfig::validate`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigViolation {
+    /// Offending field (`data_path`, `data_paths.<name>`, `options.<key>`)
+    pub field: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+impl SisterConfig {
+    /// Check the config against `requirements` and general consistency.
+    ///
+    /// Besides the declared requirements: paths that don't exist are
+    /// violations unless `create_if_missing` is set, and a read-only
+    /// config cannot create them either. Returns every violation, not
+    /// just the first.
+    pub fn validate(&self, requirements: &ConfigRequirements) -> Vec<ConfigViolation> {
+        let mut violations = Vec::new();
+        let mut violate = |field: String, message: String| {
+            violations.push(ConfigViolation { field, message });
+        };
+
+        if requirements.data_path && self.data_path.is_none() {
+            violate("data_path".into(), "required but not set".into());
+        }
+        for name in &requirements.named_paths {
+            if !self.data_paths.contains_key(name) {
+                violate(
+                    format!("data_paths.{}", name),
+                    "required but not set".into(),
+                );
+            }
+        }
+
+        let mut paths: Vec<(String, &PathBuf)> = self
+            .data_paths
+            .iter()
+            .map(|(name, path)| (format!("data_paths.{}", name), path))
+            .collect();
+        paths.sort();
+        if let Some(path) = &self.data_path {
+            paths.insert(0, ("data_path".into(), path));
+        }
+        for (field, path) in paths {
+            if path.exists() {
+                continue;
+            }
+            if self.read_only {
+                violate(
+                    field,
+                    format!(
+                        "{} does not exist and read_only prevents creating it",
+                        path.display()
+                    ),
+                );
+            } else if !self.create_if_missing {
+                violate(
+                    field,
+                    format!(
+                        "{} does not exist and create_if_missing is off",
+                        path.display()
+                    ),
+                );
+            }
+        }
+
+        for spec in &requirements.options {
+            let field = format!("options.{}", spec.name);
+            match self.options.get(&spec.name) {
+                None if spec.required => violate(field, "required but not set".into()),
+                Some(value) if !spec.kind.matches(value) => {
+                    violate(field, format!("expected {:?}, got {}", spec.kind, value))
+                }
+                _ => {}
+            }
+        }
+        violations
+    }
+}
+
+/// `SisterConfig` fields that cannot change without a restart.
+pub const IMMUTABLE_CONFIG_FIELDS: [&str; 4] =
+    ["data_path", "data_paths", "read_only", "exclusive_lock"];
+
+/// Fields that differ between two `SisterConfig`s.
+///
+/// Top-level field names, except custom options and secrets which are
+/// listed per key as `options.<key>` / `secrets.<key>`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigDiff {
+    pub changed: Vec<String>,
+}
+
+impl ConfigDiff {
+    pub fn between(old: &SisterConfig, new: &SisterConfig) -> Self {
+        let as_map = |config: &SisterConfig| match serde_json::to_value(config) {
+            Ok(serde_json::Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        };
+        let (old_map, new_map) = (as_map(old), as_map(new));
+        let mut keys: Vec<&String> = old_map.keys().chain(new_map.keys()).collect();
+        keys.sort();
+        keys.dedup();
+
+        let mut changed: Vec<String> = keys
+            .into_iter()
+            .filter(|k| *k != "options" && *k != "secrets" && old_map.get(*k) != new_map.get(*k))
+            .cloned()
+            .collect();
+        let mut options: Vec<&String> = old.options.keys().chain(new.options.keys()).collect();
+        options.sort();
+        options.dedup();
+        changed.extend(
+            options
+                .into_iter()
+                .filter(|k| old.options.get(*k) != new.options.get(*k))
+                .map(|k| format!("options.{}", k)),
+        );
+        // Secrets serialize redacted, so compare them directly
+        let mut secrets: Vec<&String> = old.secrets.keys().chain(new.secrets.keys()).collect();
+        secrets.sort();
+        secrets.dedup();
+        changed.extend(
+            secrets
+                .into_iter()
+                .filter(|k| old.secrets.get(*k) != new.secrets.get(*k))
+                .map(|k| format!("secrets.{}", k)),
+        );
+        Self { changed }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty()
+    }
+
+    pub fn contains(&self, field: &str) -> bool {
+        self.changed.iter().any(|f| f == field)
+    }
+
+    /// Changed fields listed in `IMMUTABLE_CONFIG_FIELDS`.
+    pub fn immutable(&self) -> Vec<&str> {
+        self.changed
+            .iter()
+            .map(String::as_str)
+            .filter(|f| IMMUTABLE_CONFIG_FIELDS.contains(f))
+            .collect()
+    }
 }
 
 /// The core trait that ALL sisters must implement.
@@ -154,9 +628,127 @@ pub trait Sister: Send + Sync {
     /// Shutdown gracefully
     fn shutdown(&mut self) -> SisterResult<()>;
 
-    /// Get capabilities this sister provides
+    /// Get capabilities this sister provides.
+    ///
+    /// Must omit capabilities in `SisterConfig::disabled_capabilities`
+    /// (see `SisterConfig::enabled_capabilities`), and invoking a
+    /// disabled one must fail with `PermissionDenied`
+    /// (`SisterConfig::ensure_capability_enabled`).
     fn capabilities(&self) -> Vec<Capability>;
 
+    /// Load indices and caches after `init`.
+    ///
+    /// Contract: a sister with slow loading returns from `init` quickly
+    /// in `Status::Starting` (live, not ready). Hydra calls `warm_up`
+    /// next; the sister emits `WarmUpProgress` per phase and, on
+    /// success, moves to `Status::Ready` emitting `StatusChanged`
+    /// (`WarmUp` does both). Queries before that may be refused or slow.
+    ///
+    /// Default: nothing to load, an empty report.
+    fn warm_up(&mut self) -> SisterResult<WarmUpReport> {
+        Ok(WarmUpReport::default())
+    }
+
+    /// Run diagnostics at `level` and report structured findings.
+    ///
+    /// Quick checks must be cheap enough to run on a serving sister;
+    /// sisters add index checks at `Standard` and checksum sampling at
+    /// `Deep`. Only fails if the self-test itself cannot run.
+    ///
+    /// Default: health plus storage accessibility of `current_config()`.
+    fn self_test(&self, level: DiagnosticLevel) -> SisterResult<DiagnosticReport> {
+        let mut report = DiagnosticReport::new(self.sister_type(), level);
+        let health = self.health();
+        if health.healthy {
+            report.check(CHECK_HEALTH, || Ok(()));
+        } else {
+            let message = health
+                .last_error
+                .unwrap_or_else(|| "sister reports unhealthy".into());
+            report.finding(DiagnosticFinding::new(
+                CHECK_HEALTH,
+                Severity::Error,
+                message,
+            ));
+        }
+        if let Some(config) = self.current_config() {
+            report.check_storage(&config);
+        }
+        Ok(report.finish())
+    }
+
+    /// The configuration currently in effect, if the sister keeps it.
+    ///
+    /// Needed by the default `update_config`.
+    fn current_config(&self) -> Option<SisterConfig> {
+        None
+    }
+
+    /// Apply a validated configuration change (called by `update_config`).
+    ///
+    /// `diff` never touches `IMMUTABLE_CONFIG_FIELDS`. Default: `NotImplemented`.
+    fn apply_config(&mut self, config: SisterConfig, diff: &ConfigDiff) -> SisterResult<()> {
+        let _ = (config, diff);
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            format!("{} does not support config reload", self.name()),
+        ))
+    }
+
+    /// Reload configuration without a restart.
+    ///
+    /// Default: diff against `current_config`, reject changes to
+    /// immutable fields (data paths, read-only, locking) with
+    /// `InvalidInput`, then hand the rest to `apply_config`. On success
+    /// the sister should emit `SisterEvent::config_changed`.
+    fn update_config(&mut self, config: SisterConfig) -> SisterResult<ConfigDiff> {
+        let current = self.current_config().ok_or_else(|| {
+            SisterError::new(
+                ErrorCode::NotImplemented,
+                format!("{} does not support config reload", self.name()),
+            )
+        })?;
+        let diff = ConfigDiff::between(&current, &config);
+        let immutable = diff.immutable();
+        if !immutable.is_empty() {
+            return Err(SisterError::invalid_input(format!(
+                "Cannot change {} without a restart",
+                immutable.join(", ")
+            ))
+            .with_context("fields", immutable.join(",")));
+        }
+        if !diff.is_empty() {
+            self.apply_config(config, &diff)?;
+        }
+        Ok(diff)
+    }
+
+    /// Quiesce the sister (e.g. for a backup) without shutting down.
+    ///
+    /// Before returning: in-flight writes have finished, data files are
+    /// flushed and consistent on disk, and `health().status` reports
+    /// `Status::Paused`. Until `resume`, writes fail with `InvalidState`;
+    /// reads may continue. Emit `SisterEvent::paused`.
+    ///
+    /// Default: `NotImplemented`.
+    fn pause(&mut self) -> SisterResult<()> {
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            format!("{} does not support pause", self.name()),
+        ))
+    }
+
+    /// Leave the paused state and accept writes again. Emit
+    /// `SisterEvent::resumed`.
+    ///
+    /// Default: `NotImplemented`.
+    fn resume(&mut self) -> SisterResult<()> {
+        Err(SisterError::new(
+            ErrorCode::NotImplemented,
+            format!("{} does not support resume", self.name()),
+        ))
+    }
+
     // ═══════════════════════════════════════════════════════
     // DEFAULT IMPLEMENTATIONS
     // ═══════════════════════════════════════════════════════
@@ -171,11 +763,30 @@ pub trait Sister: Send + Sync {
         Self::FILE_EXTENSION
     }
 
+    /// What this sister needs from its config, for `SisterConfig::validate`
+    fn config_requirements() -> ConfigRequirements
+    where
+        Self: Sized,
+    {
+        ConfigRequirements::default()
+    }
+
     /// Check if the sister is healthy
     fn is_healthy(&self) -> bool {
         self.health().healthy
     }
 
+    /// Readiness probe: can take traffic now (false during warm-up)
+    fn check_ready(&self) -> bool {
+        let health = self.health();
+        health.live && health.ready
+    }
+
+    /// Liveness probe: false means the sister should be restarted
+    fn check_live(&self) -> bool {
+        self.health().live
+    }
+
     /// Get a human-readable name
     fn name(&self) -> String {
         format!("Agentic{:?}", Self::SISTER_TYPE)
@@ -185,16 +796,240 @@ pub trait Sister: Send + Sync {
     fn mcp_prefix(&self) -> &'static str {
         Self::SISTER_TYPE.mcp_prefix()
     }
+
+    /// Sisters this one cannot run without
+    fn requires(&self) -> Vec<SisterRequirement> {
+        vec![]
+    }
+
+    /// Sisters this one uses when present (e.g. Identity for receipts)
+    fn optional_dependencies(&self) -> Vec<SisterRequirement> {
+        vec![]
+    }
+}
+
+/// One completed warm-up phase.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WarmUpPhase {
+    pub name: String,
+    #[serde(with = "duration_millis")]
+    pub duration: Duration,
+    /// Items loaded (index entries, graph nodes, ...)
+    pub items: usize,
+}
+
+/// What `Sister::warm_up` did.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WarmUpReport {
+    #[serde(default)]
+    pub phases: Vec<WarmUpPhase>,
+    #[serde(with = "duration_millis")]
+    pub duration: Duration,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl WarmUpReport {
+    /// Items loaded across all phases.
+    pub fn items_loaded(&self) -> usize {
+        self.phases.iter().map(|p| p.items).sum()
+    }
+}
+
+/// Runs warm-up phases, timing each and emitting progress events.
+///
+/// ```rust,ignore
+/// fn warm_up(&mut self) -> SisterResult<WarmUpReport> {
+///     let mut warm = WarmUp::new(SisterType::Codebase, 2).with_events(&self.events);
+///     warm.phase("symbols", || self.load_symbols())?;
+///     warm.phase("call_graph", || self.load_call_graph())?;
+///     self.status = Status::Ready;
+///     Ok(warm.finish())
+/// }
+/// ```
+pub struct WarmUp<'a> {
+    sister_type: SisterType,
+    planned: usize,
+    events: Option<&'a EventManager>,
+    started: Instant,
+    report: WarmUpReport,
+}
+
+impl<'a> WarmUp<'a> {
+    /// Start warming up with `planned` phases (for progress percentages).
+    pub fn new(sister_type: SisterType, planned: usize) -> Self {
+        Self {
+            sister_type,
+            planned: planned.max(1),
+            events: None,
+            started: Instant::now(),
+            report: WarmUpReport::default(),
+        }
+    }
+
+    pub fn with_events(mut self, events: &'a EventManager) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Run one phase; `load` returns the number of items loaded.
+    pub fn phase(
+        &mut self,
+        name: impl Into<String>,
+        load: impl FnOnce() -> SisterResult<usize>,
+    ) -> SisterResult<usize> {
+        let name = name.into();
+        let started = Instant::now();
+        let items = load().map_err(|e| e.with_context("warm_up_phase", &name))?;
+        self.report.phases.push(WarmUpPhase {
+            name: name.clone(),
+            duration: started.elapsed(),
+            items,
+        });
+        if let Some(events) = self.events {
+            let percent =
+                (self.report.phases.len() as f64 / self.planned as f64 * 100.0).min(100.0);
+            events.emit(SisterEvent::warm_up_progress(
+                self.sister_type,
+                name,
+                percent,
+            ));
+        }
+        Ok(items)
+    }
+
+    /// Record a non-fatal problem.
+    pub fn warn(&mut self, warning: impl Into<String>) {
+        self.report.warnings.push(warning.into());
+    }
+
+    /// Finish: emits `StatusChanged { Starting → Ready }` and returns the report.
+    pub fn finish(mut self) -> WarmUpReport {
+        self.report.duration = self.started.elapsed();
+        if let Some(events) = self.events {
+            events.emit(SisterEvent::status_changed(
+                self.sister_type,
+                Status::Starting,
+                Status::Ready,
+            ));
+        }
+        self.report
+    }
+}
+
+impl std::fmt::Debug for WarmUp<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WarmUp")
+            .field("sister_type", &self.sister_type)
+            .field("planned", &self.planned)
+            .field("report", &self.report)
+            .finish()
+    }
+}
+
+/// Current status with validated, event-emitting transitions.
+///
+/// Starts in `Starting`. Every accepted transition emits
+/// `StatusChanged { from, to }`, so subscribers see changes in order and
+/// never see one the state machine forbids (`Status::can_transition_to`).
+///
+/// ```rust,ignore
+/// self.status.transition(Status::Busy)?;
+/// let result = self.run_query(query);
+/// self.status.transition(Status::Ready)?;
+/// ```
+pub struct StatusTracker {
+    sister_type: SisterType,
+    events: Option<Arc<EventManager>>,
+    current: Mutex<Status>,
+}
+
+impl StatusTracker {
+    pub fn new(sister_type: SisterType) -> Self {
+        Self {
+            sister_type,
+            events: None,
+            current: Mutex::new(Status::Starting),
+        }
+    }
+
+    pub fn with_events(mut self, events: Arc<EventManager>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    pub fn status(&self) -> Status {
+        *self.current.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Move to `next`, emitting `StatusChanged`.
+    ///
+    /// Returns `Ok(false)` (and emits nothing) if already in `next`;
+    /// fails with `InvalidState` if the transition is not allowed.
+    pub fn transition(&self, next: Status) -> SisterResult<bool> {
+        let mut current = self.current.lock().unwrap_or_else(|e| e.into_inner());
+        let from = *current;
+        if from == next {
+            return Ok(false);
+        }
+        if !from.can_transition_to(next) {
+            return Err(SisterError::new(
+                ErrorCode::InvalidState,
+                format!("Cannot change status from {} to {}", from, next),
+            )
+            .with_context("from", from.to_string())
+            .with_context("to", next.to_string()));
+        }
+        *current = next;
+        // Emit under the lock so concurrent transitions are seen in order
+        if let Some(events) = &self.events {
+            events.emit(SisterEvent::status_changed(self.sister_type, from, next));
+        }
+        Ok(true)
+    }
+
+    /// `HealthStatus` with probes derived from the current status.
+    pub fn health(&self) -> HealthStatus {
+        HealthStatus::with_status(self.status())
+    }
+}
+
+impl std::fmt::Debug for StatusTracker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StatusTracker")
+            .field("sister_type", &self.sister_type)
+            .field("status", &self.status())
+            .finish()
+    }
+}
+
+mod duration_millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_millis() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Ok(Duration::from_millis(u64::deserialize(deserializer)?))
+    }
 }
 
 /// Information about a sister (for discovery)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct SisterInfo {
     pub sister_type: SisterType,
     pub version: Version,
     pub file_extension: String,
     pub capabilities: Vec<Capability>,
     pub mcp_prefix: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub requires: Vec<SisterRequirement>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub optional_dependencies: Vec<SisterRequirement>,
 }
 
 impl SisterInfo {
@@ -206,10 +1041,231 @@ impl SisterInfo {
             file_extension: S::FILE_EXTENSION.to_string(),
             capabilities: sister.capabilities(),
             mcp_prefix: S::SISTER_TYPE.mcp_prefix().to_string(),
+            requires: sister.requires(),
+            optional_dependencies: sister.optional_dependencies(),
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════
+// DEPENDENCIES
+// ═══════════════════════════════════════════════════════
+
+/// A dependency on another sister.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct SisterRequirement {
+    pub sister_type: SisterType,
+
+    /// Capability names the dependency must provide
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub capabilities: Vec<String>,
+
+    /// Oldest acceptable version; the major version must match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_version: Option<Version>,
+}
+
+impl SisterRequirement {
+    pub fn new(sister_type: SisterType) -> Self {
+        Self {
+            sister_type,
+            capabilities: vec![],
+            min_version: None,
+        }
+    }
+
+    pub fn capability(mut self, name: impl Into<String>) -> Self {
+        self.capabilities.push(name.into());
+        self
+    }
+
+    pub fn min_version(mut self, version: impl Into<Version>) -> Self {
+        self.min_version = Some(version.into());
+        self
+    }
+
+    /// Why `info` does not satisfy this requirement, if it doesn't.
+    pub fn unmet_by(&self, info: &SisterInfo) -> Option<String> {
+        if let Some(min) = &self.min_version {
+            let v = &info.version;
+            if !v.is_compatible_with(min) || v < min {
+                return Some(format!("version {} does not satisfy {}", v, min));
+            }
+        }
+        let missing: Vec<&str> = self
+            .capabilities
+            .iter()
+            .filter(|c| !info.capabilities.iter().any(|have| &have.name == *c))
+            .map(String::as_str)
+            .collect();
+        if missing.is_empty() {
+            None
+        } else {
+            Some(format!("missing capabilities: {}", missing.join(", ")))
         }
     }
 }
 
+/// A requirement the fleet does not meet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnmetRequirement {
+    /// Sister declaring the requirement
+    pub dependent: SisterType,
+    pub requirement: SisterRequirement,
+    pub reason: String,
+    /// Whether it came from `optional_dependencies`
+    pub optional: bool,
+}
+
+/// Outcome of checking a fleet's declared dependencies.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FleetResolution {
+    /// Start order: every sister after the sisters it requires
+    pub startup_order: Vec<SisterType>,
+
+    /// Unmet requirements (required and optional)
+    pub unmet: Vec<UnmetRequirement>,
+}
+
+impl FleetResolution {
+    /// Fail with `InvalidState` if any required dependency is unmet.
+    pub fn validate(&self) -> SisterResult<()> {
+        let required: Vec<String> = self
+            .unmet
+            .iter()
+            .filter(|u| !u.optional)
+            .map(|u| {
+                format!(
+                    "{} requires {}: {}",
+                    u.dependent, u.requirement.sister_type, u.reason
+                )
+            })
+            .collect();
+        if required.is_empty() {
+            Ok(())
+        } else {
+            Err(SisterError::new(
+                ErrorCode::InvalidState,
+                format!("Fleet has unmet dependencies: {}", required.join("; ")),
+            )
+            .with_context("unmet", required.len()))
+        }
+    }
+
+    /// Optional dependencies that are absent or unsuitable.
+    pub fn degraded(&self) -> impl Iterator<Item = &UnmetRequirement> {
+        self.unmet.iter().filter(|u| u.optional)
+    }
+}
+
+/// Check every sister's requirements against the rest of the fleet.
+///
+/// Fails with `InvalidState` only on a required-dependency cycle (no
+/// valid start order); unmet requirements are reported in the result.
+pub fn resolve_fleet(fleet: &[SisterInfo]) -> SisterResult<FleetResolution> {
+    let by_type: HashMap<SisterType, &SisterInfo> =
+        fleet.iter().map(|info| (info.sister_type, info)).collect();
+    let mut unmet = Vec::new();
+    for info in fleet {
+        let declared = info
+            .requires
+            .iter()
+            .map(|r| (r, false))
+            .chain(info.optional_dependencies.iter().map(|r| (r, true)));
+        for (requirement, optional) in declared {
+            let reason = match by_type.get(&requirement.sister_type) {
+                None => Some("not in fleet".to_string()),
+                Some(dep) => requirement.unmet_by(dep),
+            };
+            if let Some(reason) = reason {
+                unmet.push(UnmetRequirement {
+                    dependent: info.sister_type,
+                    requirement: requirement.clone(),
+                    reason,
+                    optional,
+                });
+            }
+        }
+    }
+
+    // Depth-first topological sort over required edges within the fleet
+    fn visit(
+        sister: SisterType,
+        by_type: &HashMap<SisterType, &SisterInfo>,
+        visiting: &mut Vec<SisterType>,
+        order: &mut Vec<SisterType>,
+    ) -> SisterResult<()> {
+        if order.contains(&sister) {
+            return Ok(());
+        }
+        if visiting.contains(&sister) {
+            return Err(SisterError::new(
+                ErrorCode::InvalidState,
+                format!("Dependency cycle through {}", sister),
+            )
+            .with_context("sister_type", sister.to_string()));
+        }
+        visiting.push(sister);
+        for requirement in &by_type[&sister].requires {
+            if by_type.contains_key(&requirement.sister_type) {
+                visit(requirement.sister_type, by_type, visiting, order)?;
+            }
+        }
+        visiting.pop();
+        order.push(sister);
+        Ok(())
+    }
+
+    let mut startup_order = Vec::new();
+    for info in fleet {
+        visit(
+            info.sister_type,
+            &by_type,
+            &mut Vec::new(),
+            &mut startup_order,
+        )?;
+    }
+    Ok(FleetResolution {
+        startup_order,
+        unmet,
+    })
+}
+
+/// Sisters in `fleet` offering `capability`, best first.
+///
+/// Ranked by `Capability::priority`; ties keep fleet order.
+pub fn resolve_capability(fleet: &[SisterInfo], capability: &str) -> Vec<SisterType> {
+    resolve_capability_for(fleet, capability, &[])
+}
+
+/// `resolve_capability`, preferring sisters whose capability `tags`
+/// match more of `hints` before comparing priority.
+pub fn resolve_capability_for(
+    fleet: &[SisterInfo],
+    capability: &str,
+    hints: &[&str],
+) -> Vec<SisterType> {
+    let mut matches: Vec<(usize, i32, SisterType)> = fleet
+        .iter()
+        .filter_map(|info| {
+            let cap = info.capabilities.iter().find(|c| c.name == capability)?;
+            let hits = cap
+                .tags
+                .iter()
+                .filter(|t| hints.contains(&t.as_str()))
+                .count();
+            Some((hits, cap.priority, info.sister_type))
+        })
+        .collect();
+    matches.sort_by_key(|&(hits, priority, _)| std::cmp::Reverse((hits, priority)));
+    matches
+        .into_iter()
+        .map(|(_, _, sister_type)| sister_type)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -248,10 +1304,277 @@ mod tests {
         assert_eq!(config.data_paths.len(), 3);
     }
 
+    #[test]
+    #[cfg(feature = "file-io")]
+    fn test_config_exclusive_lock() {
+        let dir = crate::test_support::TempDir::new("cfg_lock");
+        let config = SisterConfig::new(dir.join("data.amem")).exclusive_lock(true);
+
+        let guard = config.acquire_lock().unwrap();
+        assert!(guard.is_some());
+        assert!(config.acquire_lock().is_err());
+        assert!(SisterConfig::stateless().acquire_lock().unwrap().is_none());
+
+        drop(guard);
+    }
+
     #[test]
     fn test_config_stateless() {
         let config = SisterConfig::stateless();
         assert!(config.data_path.is_none());
         assert!(config.data_paths.is_empty());
     }
+
+    fn info(sister_type: SisterType, version: (u8, u8, u8), caps: &[&str]) -> SisterInfo {
+        SisterInfo {
+            sister_type,
+            version: version.into(),
+            file_extension: sister_type.file_extension().into(),
+            capabilities: caps.iter().map(|c| Capability::new(*c, "")).collect(),
+            mcp_prefix: sister_type.mcp_prefix().into(),
+            requires: vec![],
+            optional_dependencies: vec![],
+        }
+    }
+
+    #[test]
+    fn test_resolve_fleet() {
+        let mut memory = info(SisterType::Memory, (0, 2, 0), &[]);
+        memory.requires = vec![SisterRequirement::new(SisterType::Time)];
+        memory.optional_dependencies = vec![SisterRequirement::new(SisterType::Identity)
+            .capability("receipts")
+            .min_version((0, 3, 0))];
+        let time = info(SisterType::Time, (0, 1, 0), &[]);
+        let identity = info(SisterType::Identity, (0, 2, 5), &["receipts"]);
+
+        let resolution = resolve_fleet(&[memory.clone(), time, identity]).unwrap();
+        assert_eq!(
+            resolution.startup_order,
+            vec![SisterType::Time, SisterType::Memory, SisterType::Identity]
+        );
+        assert!(resolution.validate().is_ok());
+        let degraded: Vec<_> = resolution.degraded().collect();
+        assert_eq!(degraded.len(), 1);
+        assert!(degraded[0].reason.contains("version"));
+
+        let resolution = resolve_fleet(std::slice::from_ref(&memory)).unwrap();
+        assert_eq!(
+            resolution.validate().unwrap_err().code,
+            ErrorCode::InvalidState
+        );
+
+        let mut time = info(SisterType::Time, (0, 1, 0), &[]);
+        time.requires = vec![SisterRequirement::new(SisterType::Memory)];
+        assert!(resolve_fleet(&[memory, time]).is_err());
+    }
+
+    #[test]
+    fn test_resolve_capability() {
+        let mut memory = info(SisterType::Memory, (0, 2, 0), &[]);
+        memory.capabilities = vec![Capability::new("grounding", "").tag("conversation")];
+        let mut codebase = info(SisterType::Codebase, (0, 2, 0), &[]);
+        codebase.capabilities = vec![Capability::new("grounding", "").priority(1).tag("code")];
+        let vision = info(SisterType::Vision, (0, 2, 0), &["capture"]);
+        let fleet = [memory, codebase, vision];
+
+        assert_eq!(
+            resolve_capability(&fleet, "grounding"),
+            vec![SisterType::Codebase, SisterType::Memory]
+        );
+        assert_eq!(
+            resolve_capability_for(&fleet, "grounding", &["conversation"]),
+            vec![SisterType::Memory, SisterType::Codebase]
+        );
+        assert!(resolve_capability(&fleet, "planning").is_empty());
+    }
+
+    #[test]
+    fn test_config_diff() {
+        let old = SisterConfig::new("/data/memory").option("dim", 384);
+        let new = old
+            .clone()
+            .memory_budget(256)
+            .option("dim", 768)
+            .option("fast", true);
+        let diff = ConfigDiff::between(&old, &new);
+        assert_eq!(
+            diff.changed,
+            vec!["memory_budget_mb", "options.dim", "options.fast"]
+        );
+        assert!(diff.immutable().is_empty());
+
+        let moved = ConfigDiff::between(&old, &SisterConfig::new("/elsewhere").option("dim", 384));
+        assert_eq!(moved.immutable(), vec!["data_path"]);
+        assert!(ConfigDiff::between(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn test_config_validate() {
+        let requirements = ConfigRequirements::new()
+            .named_path("receipts")
+            .required_option("dimension", ParamKind::Integer)
+            .optional_option("model", ParamKind::String);
+        let missing = std::env::temp_dir().join(format!("nope_{}", uuid::Uuid::new_v4()));
+
+        let config = SisterConfig::new(&missing)
+            .read_only(true)
+            .option("model", 3);
+        let fields: Vec<String> = config
+            .validate(&requirements)
+            .into_iter()
+            .map(|v| v.field)
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                "data_paths.receipts",
+                "data_path",
+                "options.dimension",
+                "options.model"
+            ]
+        );
+
+        let config = SisterConfig::new(&missing)
+            .add_path("receipts", std::env::temp_dir())
+            .option("dimension", 384);
+        assert!(config.validate(&requirements).is_empty());
+        assert_eq!(
+            config
+                .clone()
+                .create_if_missing(false)
+                .validate(&requirements)
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_config_from_env() {
+        let vars = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<Vec<_>>()
+        };
+        let config = SisterConfig::from_vars(
+            "memory",
+            vars(&[
+                ("MEMORY_DATA_PATH", "/data/brain.amem"),
+                ("MEMORY_READ_ONLY", "yes"),
+                ("MEMORY_MEMORY_BUDGET_MB", "512"),
+                ("MEMORY_PATH_ARCHIVE", "/data/archive"),
+                ("MEMORY_OPT_DIMENSION", "384"),
+                ("MEMORY_OPT_MODEL", "mini-lm"),
+                ("MEMORY_OPT_TAGS", "[\"a\",\"b\"]"),
+                ("MEMORY_DISABLED_CAPABILITIES", "memory_add, memory_similar"),
+                ("MEMORY_ID_GENERATOR", "sortable"),
+                ("VISION_READ_ONLY", "maybe"),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(config.primary_path(), PathBuf::from("/data/brain.amem"));
+        assert!(config.read_only);
+        assert_eq!(config.memory_budget_mb, Some(512));
+        assert_eq!(config.id_generator, Some(IdGenerator::Sortable));
+        assert_eq!(
+            config.get_path("archive"),
+            Some(&PathBuf::from("/data/archive"))
+        );
+        assert_eq!(config.get_option::<u32>("dimension"), Some(384));
+        assert_eq!(
+            config.get_option::<String>("model").as_deref(),
+            Some("mini-lm")
+        );
+        assert_eq!(config.get_option::<Vec<String>>("tags").unwrap().len(), 2);
+        assert!(!config.is_capability_enabled("memory_similar"));
+        assert!(config.is_capability_enabled("memory_query"));
+
+        let err =
+            SisterConfig::from_vars("MEMORY_", vars(&[("MEMORY_READ_ONLY", "maybe")])).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidInput);
+        assert_eq!(err.context.unwrap()["variable"], "MEMORY_READ_ONLY");
+    }
+
+    #[test]
+    fn test_config_secrets() {
+        let config = SisterConfig::stateless()
+            .secret("api_key", "sk-live-123")
+            .option("legacy_token", "tok-9");
+        assert!(!format!("{:?}", config).contains("sk-live-123"));
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["secrets"]["api_key"], "***");
+        assert_eq!(
+            config.secret_option("api_key").unwrap().expose(),
+            "sk-live-123"
+        );
+        assert_eq!(
+            config.secret_option("legacy_token").unwrap().expose(),
+            "tok-9"
+        );
+
+        let rotated = config.clone().secret("api_key", "sk-live-456");
+        assert_eq!(
+            ConfigDiff::between(&config, &rotated).changed,
+            vec!["secrets.api_key"]
+        );
+    }
+
+    #[test]
+    fn test_warm_up_phases() {
+        let events = EventManager::new(16);
+        let mut warm = WarmUp::new(SisterType::Codebase, 2).with_events(&events);
+        warm.phase("symbols", || Ok(120)).unwrap();
+        let err = warm
+            .phase("call_graph", || Err(SisterError::storage("index corrupt")))
+            .unwrap_err();
+        assert_eq!(err.context.unwrap()["warm_up_phase"], "call_graph");
+        warm.phase("call_graph", || Ok(30)).unwrap();
+        let report = warm.finish();
+
+        assert_eq!(report.items_loaded(), 150);
+        let recent = events.recent(3);
+        assert!(matches!(
+            recent[0].event_type,
+            crate::events::EventType::StatusChanged {
+                to: Status::Ready,
+                ..
+            }
+        ));
+        assert!(matches!(
+            &recent[1].event_type,
+            crate::events::EventType::WarmUpProgress { percent, .. } if *percent == 100.0
+        ));
+    }
+
+    #[test]
+    fn test_status_tracker() {
+        let events = Arc::new(EventManager::new(16));
+        let tracker = StatusTracker::new(SisterType::Memory).with_events(events.clone());
+        assert_eq!(tracker.status(), Status::Starting);
+
+        assert!(tracker.transition(Status::Ready).unwrap());
+        assert!(!tracker.transition(Status::Ready).unwrap());
+        let err = tracker.transition(Status::Starting).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidState);
+        assert_eq!(tracker.status(), Status::Ready);
+        assert!(tracker.health().ready);
+
+        tracker.transition(Status::Busy).unwrap();
+        let changes: Vec<_> = events
+            .recent(16)
+            .into_iter()
+            .rev()
+            .filter_map(|e| match e.event_type {
+                crate::events::EventType::StatusChanged { from, to } => Some((from, to)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (Status::Starting, Status::Ready),
+                (Status::Ready, Status::Busy)
+            ]
+        );
+    }
 }
@@ -0,0 +1,568 @@
+//! proptest strategies for the wire DTOs (`proptest` feature).
+//!
+//! Sisters property-test their handlers and serializers without writing
+//! generators: `any::<Query>()`, `any::<SisterEvent>()` and friends come
+//! from the `Arbitrary` impls here, and the building blocks (`json_value`,
+//! `timestamp`, ...) are public for composing bigger inputs.
+//!
+//! ```rust,ignore
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     #[test]
+//!     fn query_never_panics(query in any::<Query>()) {
+//!         let _ = my_sister.query(query);
+//!     }
+//! }
+//! ```
+//!
+//! Every generated value survives a JSON round trip unchanged, and every
+//! generated snapshot passes `verify()`.
+
+use crate::clock::MockClock;
+use crate::context::{ContextId, ContextInfo, ContextSnapshot};
+use crate::errors::{ErrorCode, Severity, SisterError, SuggestedAction};
+use crate::events::{EventId, EventType, SisterEvent};
+use crate::grounding::{EvidenceType, GroundingEvidence, GroundingResult, GroundingStatus};
+use crate::hydra::{RunId, RunStatus, StepId};
+use crate::query::Query;
+use crate::receipts::{ActionOutcome, ActionRecord, Receipt, ReceiptAnchor, ReceiptId};
+use crate::types::{Metadata, SisterType, Status, UniqueId, Version};
+use chrono::{DateTime, TimeZone, Utc};
+use proptest::prelude::*;
+use std::time::Duration;
+
+/// Short identifier-like text (`"search"`, `"node_42"`).
+pub fn name() -> impl Strategy<Value = String> {
+    "[a-z][a-z0-9_]{0,15}"
+}
+
+/// Free text: printable, any script.
+pub fn text() -> impl Strategy<Value = String> {
+    "\\PC{0,40}"
+}
+
+/// A UTC timestamp between 2000 and 2100, with nanoseconds.
+pub fn timestamp() -> impl Strategy<Value = DateTime<Utc>> {
+    (946_684_800i64..4_102_444_800, 0u32..1_000_000_000)
+        .prop_map(|(secs, nanos)| Utc.timestamp_opt(secs, nanos).unwrap())
+}
+
+/// A score or confidence in `0.0..=1.0`.
+pub fn unit_interval() -> impl Strategy<Value = f64> {
+    0.0..=1.0f64
+}
+
+/// Any JSON value, nested up to four levels. Numbers are finite.
+pub fn json_value() -> impl Strategy<Value = serde_json::Value> {
+    use serde_json::Value;
+    let leaf = prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::Bool),
+        any::<i64>().prop_map(Value::from),
+        (-1e9..1e9f64).prop_map(Value::from),
+        text().prop_map(Value::String),
+    ];
+    leaf.prop_recursive(4, 32, 6, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..6).prop_map(Value::Array),
+            prop::collection::btree_map(name(), inner, 0..6)
+                .prop_map(|map| Value::Object(map.into_iter().collect())),
+        ]
+    })
+}
+
+/// A JSON value other than `null` (which reads back as `None` when
+/// stored in an `Option`).
+pub fn json_payload() -> impl Strategy<Value = serde_json::Value> {
+    json_value().prop_filter("null payload", |v| !v.is_null())
+}
+
+/// Up to eight metadata entries.
+pub fn metadata() -> impl Strategy<Value = Metadata> {
+    prop::collection::hash_map(name(), json_value(), 0..8)
+}
+
+pub fn unique_id() -> impl Strategy<Value = UniqueId> {
+    any::<u128>().prop_map(|n| UniqueId::from_uuid(uuid::Uuid::from_u128(n)))
+}
+
+pub fn context_id() -> impl Strategy<Value = ContextId> {
+    unique_id().prop_map(ContextId)
+}
+
+/// Any built-in sister type.
+pub fn sister_type() -> impl Strategy<Value = SisterType> {
+    prop::sample::select(SisterType::ALL.to_vec())
+}
+
+pub fn version() -> impl Strategy<Value = Version> {
+    any::<(u8, u8, u8)>().prop_map(|(major, minor, patch)| Version::new(major, minor, patch))
+}
+
+pub fn query() -> impl Strategy<Value = Query> {
+    (
+        name(),
+        metadata(),
+        prop::option::of(0usize..10_000),
+        prop::option::of(0usize..10_000),
+        prop::option::of(context_id()),
+        prop::option::of(prop::collection::vec(context_id(), 0..4)),
+        any::<bool>(),
+    )
+        .prop_map(
+            |(query_type, params, limit, offset, context_id, context_ids, merge_results)| Query {
+                query_type,
+                params,
+                limit,
+                offset,
+                context_id,
+                context_ids,
+                merge_results,
+            },
+        )
+}
+
+pub fn grounding_evidence() -> impl Strategy<Value = GroundingEvidence> {
+    (name(), name(), unit_interval(), text(), metadata()).prop_map(
+        |(evidence_type, id, score, summary, data)| GroundingEvidence {
+            evidence_type,
+            id,
+            score,
+            summary,
+            data,
+        },
+    )
+}
+
+pub fn grounding_result() -> impl Strategy<Value = GroundingResult> {
+    let status = prop_oneof![
+        Just(GroundingStatus::Verified),
+        Just(GroundingStatus::Partial),
+        Just(GroundingStatus::Ungrounded),
+    ];
+    (
+        status,
+        text(),
+        unit_interval(),
+        prop::collection::vec(grounding_evidence(), 0..4),
+        text(),
+        prop::collection::vec(text(), 0..3),
+        timestamp(),
+    )
+        .prop_map(
+            |(status, claim, confidence, evidence, reason, suggestions, timestamp)| {
+                GroundingResult {
+                    status,
+                    claim,
+                    confidence,
+                    evidence,
+                    reason,
+                    suggestions,
+                    timestamp,
+                }
+            },
+        )
+}
+
+fn status() -> impl Strategy<Value = Status> {
+    prop::sample::select(vec![
+        Status::Starting,
+        Status::Ready,
+        Status::Busy,
+        Status::Degraded,
+        Status::Paused,
+        Status::ShuttingDown,
+        Status::Error,
+    ])
+}
+
+fn run_status() -> impl Strategy<Value = RunStatus> {
+    prop::sample::select(vec![
+        RunStatus::Pending,
+        RunStatus::Running,
+        RunStatus::Succeeded,
+        RunStatus::Failed,
+        RunStatus::Cancelled,
+    ])
+}
+
+fn evidence_type() -> impl Strategy<Value = EvidenceType> {
+    prop_oneof![
+        Just(EvidenceType::MemoryNode),
+        Just(EvidenceType::CodeNode),
+        Just(EvidenceType::Receipt),
+        Just(EvidenceType::TimelineEvent),
+        Just(EvidenceType::PolicyCheck),
+        name().prop_map(EvidenceType::Custom),
+    ]
+}
+
+/// Any event type except `ContextCreated`, `ContextDeleted` and
+/// `ContextArchived`: their `context_id` field collides with
+/// `SisterEvent::context_id` once flattened, so they do not survive a
+/// JSON round trip.
+pub fn event_type() -> impl Strategy<Value = EventType> {
+    let run_id = || name().prop_map(|n| RunId(format!("run_{n}")));
+    let step_id = || any::<u64>().prop_map(StepId);
+    prop_oneof![
+        Just(EventType::Ready),
+        Just(EventType::ShuttingDown),
+        Just(EventType::Paused),
+        Just(EventType::Resumed),
+        prop::collection::vec(name(), 0..4)
+            .prop_map(|changed| EventType::ConfigChanged { changed }),
+        (name(), 0.0..=100.0f64)
+            .prop_map(|(phase, percent)| EventType::WarmUpProgress { phase, percent }),
+        (status(), status()).prop_map(|(from, to)| EventType::StatusChanged { from, to }),
+        (context_id(), context_id()).prop_map(|(from, to)| EventType::ContextSwitched { from, to }),
+        (name(), name()).prop_map(|(operation_id, operation_type)| {
+            EventType::OperationStarted {
+                operation_id,
+                operation_type,
+            }
+        }),
+        (name(), any::<u32>()).prop_map(|(operation_id, ms)| EventType::OperationCompleted {
+            operation_id,
+            duration: Duration::from_millis(ms.into()),
+        }),
+        (
+            name(),
+            prop::sample::select(ErrorCode::BUILTIN.to_vec()),
+            text()
+        )
+            .prop_map(
+                |(operation_id, code, error_message)| EventType::OperationFailed {
+                    operation_id,
+                    error_code: code.to_string(),
+                    error_message,
+                }
+            ),
+        (name(), evidence_type()).prop_map(|(evidence_id, evidence_type)| {
+            EventType::EvidenceCreated {
+                evidence_id,
+                evidence_type,
+            }
+        }),
+        (name(), any::<bool>(), unit_interval()).prop_map(
+            |(grounding_id, grounded, confidence)| {
+                EventType::GroundingPerformed {
+                    grounding_id,
+                    grounded,
+                    confidence,
+                }
+            }
+        ),
+        (run_id(), text()).prop_map(|(run_id, goal)| EventType::RunStarted { run_id, goal }),
+        (run_id(), run_status())
+            .prop_map(|(run_id, status)| EventType::RunFinished { run_id, status }),
+        (run_id(), step_id(), name()).prop_map(|(run_id, step_id, command_type)| {
+            EventType::StepStarted {
+                run_id,
+                step_id,
+                command_type,
+            }
+        }),
+        (run_id(), step_id(), run_status()).prop_map(|(run_id, step_id, status)| {
+            EventType::StepCompleted {
+                run_id,
+                step_id,
+                status,
+            }
+        }),
+        (0.0..=100.0f64).prop_map(|usage_percent| EventType::MemoryPressure { usage_percent }),
+        (0.0..=100.0f64).prop_map(|usage_percent| EventType::StoragePressure { usage_percent }),
+        (name(), json_value()).prop_map(|(name, data)| EventType::Custom { name, data }),
+    ]
+}
+
+pub fn sister_event() -> impl Strategy<Value = SisterEvent> {
+    (
+        unique_id(),
+        sister_type(),
+        event_type(),
+        timestamp(),
+        prop::option::of(context_id()),
+    )
+        .prop_map(
+            |(id, sister_type, event_type, timestamp, context_id)| SisterEvent {
+                id: EventId(id),
+                sister_type,
+                event_type,
+                timestamp,
+                context_id,
+            },
+        )
+}
+
+pub fn context_info() -> impl Strategy<Value = ContextInfo> {
+    (
+        context_id(),
+        name(),
+        timestamp(),
+        timestamp(),
+        0usize..1_000_000,
+        0usize..1_000_000_000,
+        prop::collection::vec(name(), 0..3),
+        metadata(),
+        prop::option::of(context_id()),
+        prop::collection::vec(context_id(), 0..3),
+    )
+        .prop_map(
+            |(
+                id,
+                name,
+                created_at,
+                updated_at,
+                item_count,
+                size_bytes,
+                tags,
+                metadata,
+                parent_id,
+                child_ids,
+            )| ContextInfo {
+                id,
+                name,
+                created_at,
+                updated_at,
+                item_count,
+                size_bytes,
+                tags,
+                metadata,
+                parent_id,
+                child_ids,
+                quota: None,
+            },
+        )
+}
+
+/// An uncompressed, unsigned snapshot of up to 4 KiB whose checksum
+/// matches its data.
+pub fn context_snapshot() -> impl Strategy<Value = ContextSnapshot> {
+    (
+        sister_type(),
+        version(),
+        context_info(),
+        prop::collection::vec(any::<u8>(), 0..4096),
+        timestamp(),
+    )
+        .prop_map(|(sister_type, version, info, data, at)| {
+            ContextSnapshot::new_with_clock(sister_type, version, info, data, &MockClock::new(at))
+        })
+}
+
+pub fn action_outcome() -> impl Strategy<Value = ActionOutcome> {
+    prop_oneof![
+        prop::option::of(json_payload()).prop_map(|result| ActionOutcome::Success { result }),
+        (prop::sample::select(ErrorCode::BUILTIN.to_vec()), text()).prop_map(
+            |(code, error_message)| ActionOutcome::Failure {
+                error_code: code.to_string(),
+                error_message,
+            }
+        ),
+        (
+            prop::option::of(json_payload()),
+            prop::collection::vec(text(), 0..3)
+        )
+            .prop_map(|(result, warnings)| ActionOutcome::Partial {
+                result,
+                warnings,
+                errors: None,
+            }),
+    ]
+}
+
+pub fn action_record() -> impl Strategy<Value = ActionRecord> {
+    (
+        sister_type(),
+        name(),
+        metadata(),
+        action_outcome(),
+        prop::collection::vec(name(), 0..4),
+        prop::option::of(context_id()),
+        prop::option::of(name().prop_map(|n| format!("run_{n}"))),
+        prop::option::of(any::<u64>()),
+        timestamp(),
+    )
+        .prop_map(
+            |(
+                sister_type,
+                action_type,
+                parameters,
+                outcome,
+                evidence_ids,
+                context_id,
+                run_id,
+                step_id,
+                timestamp,
+            )| ActionRecord {
+                sister_type,
+                action_type,
+                parameters,
+                outcome,
+                evidence_ids,
+                context_id,
+                run_id,
+                step_id,
+                timestamp,
+            },
+        )
+}
+
+/// A lowercase hex string of `len` bytes.
+pub fn hex_bytes(len: usize) -> impl Strategy<Value = String> {
+    prop::collection::vec(any::<u8>(), len).prop_map(hex::encode)
+}
+
+fn receipt_anchor() -> impl Strategy<Value = ReceiptAnchor> {
+    (
+        name(),
+        hex_bytes(32),
+        any::<u64>(),
+        timestamp(),
+        text(),
+        metadata(),
+    )
+        .prop_map(
+            |(provider, anchored_hash, chain_position, anchored_at, proof, metadata)| {
+                ReceiptAnchor {
+                    provider,
+                    anchored_hash,
+                    chain_position,
+                    anchored_at,
+                    proof,
+                    metadata,
+                }
+            },
+        )
+}
+
+/// A structurally valid receipt. The signature and hashes are random
+/// hex, so `verify_signature` and chain checks fail on it.
+pub fn receipt() -> impl Strategy<Value = Receipt> {
+    (
+        unique_id(),
+        action_record(),
+        hex_bytes(64),
+        any::<u64>(),
+        hex_bytes(32),
+        hex_bytes(32),
+        timestamp(),
+        prop::option::of(receipt_anchor()),
+    )
+        .prop_map(
+            |(id, action, signature, chain_position, previous_hash, hash, created_at, anchor)| {
+                Receipt {
+                    id: ReceiptId(id),
+                    action,
+                    signature,
+                    chain_position,
+                    previous_hash,
+                    hash,
+                    created_at,
+                    anchor,
+                }
+            },
+        )
+}
+
+fn suggested_action() -> impl Strategy<Value = SuggestedAction> {
+    prop_oneof![
+        (0u64..60_000).prop_map(|after_ms| SuggestedAction::Retry { after_ms }),
+        text().prop_map(|description| SuggestedAction::Alternative { description }),
+        text().prop_map(|description| SuggestedAction::UserAction { description }),
+        Just(SuggestedAction::Restart),
+        name().prop_map(|key| SuggestedAction::CheckConfig { key }),
+        Just(SuggestedAction::ReportBug),
+    ]
+}
+
+/// An error with a built-in code and any severity, context and
+/// suggestion.
+pub fn sister_error() -> impl Strategy<Value = SisterError> {
+    let severity = prop::sample::select(vec![
+        Severity::Info,
+        Severity::Warning,
+        Severity::Error,
+        Severity::Fatal,
+    ]);
+    (
+        prop::sample::select(ErrorCode::BUILTIN.to_vec()),
+        severity,
+        text(),
+        prop::option::of(metadata()),
+        any::<bool>(),
+        prop::option::of(suggested_action()),
+    )
+        .prop_map(
+            |(code, severity, message, context, recoverable, suggested_action)| SisterError {
+                code,
+                severity,
+                message,
+                context,
+                recoverable,
+                suggested_action,
+                extended_code: None,
+            },
+        )
+}
+
+macro_rules! arbitrary {
+    ($($ty:ty => $strategy:ident),* $(,)?) => {$(
+        impl Arbitrary for $ty {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                $strategy().boxed()
+            }
+        }
+    )*};
+}
+
+arbitrary! {
+    Query => query,
+    GroundingResult => grounding_result,
+    SisterEvent => sister_event,
+    ContextSnapshot => context_snapshot,
+    Receipt => receipt,
+    SisterError => sister_error,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+
+    fn round_trips<T: Serialize + DeserializeOwned>(value: &T) -> bool {
+        let json = serde_json::to_value(value).unwrap();
+        let back: T = serde_json::from_value(json.clone()).unwrap();
+        serde_json::to_value(&back).unwrap() == json
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn test_generated_values_round_trip(
+            query in any::<Query>(),
+            grounding in any::<GroundingResult>(),
+            event in any::<SisterEvent>(),
+            receipt in any::<Receipt>(),
+            error in any::<SisterError>(),
+        ) {
+            prop_assert!(round_trips(&query));
+            prop_assert!(round_trips(&grounding));
+            prop_assert!(round_trips(&event));
+            prop_assert!(round_trips(&receipt));
+            prop_assert!(round_trips(&error));
+        }
+
+        #[test]
+        fn test_generated_snapshots_verify(snapshot in any::<ContextSnapshot>()) {
+            prop_assert!(snapshot.verify().unwrap());
+            prop_assert!(round_trips(&snapshot));
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Helpers shared by the crate's unit tests.
+
+use std::path::{Path, PathBuf};
+
+/// A fresh directory under the system temp dir, removed on drop (even
+/// when the test panics).
+///
+/// Derefs to `Path`, so `dir.join(..)` and `&dir` work where a path
+/// is expected.
+#[derive(Debug)]
+pub(crate) struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// Create `<temp>/<prefix>_<uuid>`; panics if that fails.
+    pub(crate) fn new(prefix: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("{}_{}", prefix, uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&path)
+            .unwrap_or_else(|e| panic!("create temp dir {}: {}", path.display(), e));
+        Self { path }
+    }
+
+    /// The directory.
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl std::ops::Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl AsRef<Path> for TempDir {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+#[test]
+fn test_temp_dir_removed_on_drop() {
+    let dir = TempDir::new("temp");
+    std::fs::write(dir.join("file"), b"x").unwrap();
+    let path = dir.path().to_path_buf();
+    assert!(path.is_dir());
+    drop(dir);
+    assert!(!path.exists());
+
+    let path = std::panic::catch_unwind(|| {
+        let dir = TempDir::new("temp");
+        let path = dir.path().to_path_buf();
+        if path.is_dir() {
+            std::panic::resume_unwind(Box::new(path));
+        }
+        path
+    })
+    .unwrap_err()
+    .downcast::<PathBuf>()
+    .unwrap();
+    assert!(!path.exists());
+}
@@ -0,0 +1,8 @@
+//! Utilities for testing code that talks to sisters.
+//!
+//! Sister-side file-format checks live in `file_format::testing`; this
+//! module is for the consumer side (Hydra, gateways) and for sisters
+//! testing how they cope with each other.
+
+pub mod chaos;
+pub mod trace;
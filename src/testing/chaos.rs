@@ -0,0 +1,953 @@
+//! Fault injection for testing retry and degradation logic.
+//!
+//! The wrappers here misbehave the way real sisters and disks do, using
+//! the contract error model, so Hydra and sisters can check that they
+//! retry what is recoverable and degrade on what is not:
+//!
+//! - `FlakySister<S>` fails contract calls with injected errors
+//!   (`Timeout`, `StorageError`, ...) at configurable rates
+//! - `SlowQueryable<Q>` delays every query, optionally on a `MockClock`
+//! - `CorruptingWriter<W>` flips bits in, or truncates, what is written
+//!
+//! ```rust,ignore
+//! let faults = FaultInjector::new().seed(7).timeouts(0.3).storage_errors(0.1);
+//! let memory = FlakySister::new(AgenticMemory::init(config)?, faults);
+//! let result = retry(&RetryPolicy::new().max_attempts(5), |_| memory.query(query.clone()));
+//! ```
+//!
+//! Faults come from a seeded generator, so a given seed and call order
+//! always fail the same calls.
+
+use crate::clock::MockClock;
+use crate::context::{
+    CheckpointId, CheckpointInfo, Checkpointing, ContextFilter, ContextId, ContextInfo,
+    ContextSnapshot, ContextStats, ContextSummary, ContextTagging, PurgeReport, RetentionPolicy,
+    SessionManagement, SnapshotChunk, SnapshotChunks, SnapshotDelta, WorkspaceManagement,
+};
+use crate::diagnostics::{DiagnosticLevel, DiagnosticReport};
+use crate::errors::{ErrorCode, SisterError, SisterResult};
+use crate::events::EventType;
+#[cfg(feature = "events")]
+use crate::events::{EventEmitter, EventFilter, EventReceiver, SisterEvent};
+use crate::grounding::{EvidenceDetail, Grounding, GroundingResult, GroundingSuggestion};
+use crate::metrics::{Metric, Metrics};
+use crate::query::{Query, QueryResult, QueryTypeInfo, Queryable};
+use crate::receipts::{
+    ActionRecord, AnchorProvider, AnchorTicket, CompactedSegment, Receipt, ReceiptCursor,
+    ReceiptFilter, ReceiptId, ReceiptIntegration, ReceiptIter, ReceiptPage, ReceiptProof,
+};
+#[cfg(feature = "events")]
+use crate::receipts::{ReceiptReceiver, ReceiptWatcher};
+use crate::sister::{
+    ConfigDiff, ConfigRequirements, Sister, SisterConfig, SisterRequirement, WarmUpReport,
+};
+use crate::types::{Capability, HealthStatus, Metadata, Version};
+use chrono::{DateTime, Utc};
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Seeded splitmix64 generator; no RNG crate needed.
+#[derive(Debug)]
+struct Rng(Mutex<u64>);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(Mutex::new(seed))
+    }
+
+    fn next_u64(&self) -> u64 {
+        let mut state = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = *state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1).
+    fn next_fraction(&self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Decides which calls fail, and with what.
+///
+/// Each call draws once; rates are cumulative, so `timeouts(0.2)` plus
+/// `storage_errors(0.1)` fails 30% of calls. Without any rates nothing
+/// fails.
+#[derive(Debug)]
+pub struct FaultInjector {
+    faults: Vec<(ErrorCode, f64)>,
+    rng: Rng,
+    injected: AtomicUsize,
+}
+
+impl Default for FaultInjector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FaultInjector {
+    /// No faults, seed 0.
+    pub fn new() -> Self {
+        Self {
+            faults: Vec::new(),
+            rng: Rng::new(0),
+            injected: AtomicUsize::new(0),
+        }
+    }
+
+    /// Seed for the fault schedule.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng = Rng::new(seed);
+        self
+    }
+
+    /// Fail a fraction (0.0–1.0) of calls with `code`.
+    pub fn fail_with(mut self, code: ErrorCode, rate: f64) -> Self {
+        self.faults.push((code, rate.clamp(0.0, 1.0)));
+        self
+    }
+
+    /// Fail a fraction of calls with `Timeout`.
+    pub fn timeouts(self, rate: f64) -> Self {
+        self.fail_with(ErrorCode::Timeout, rate)
+    }
+
+    /// Fail a fraction of calls with `StorageError`.
+    pub fn storage_errors(self, rate: f64) -> Self {
+        self.fail_with(ErrorCode::StorageError, rate)
+    }
+
+    /// How many faults have been injected so far.
+    pub fn injected(&self) -> usize {
+        self.injected.load(Ordering::Relaxed)
+    }
+
+    /// Draw for one call to `operation`: `Err` if it should fail.
+    ///
+    /// Injected errors carry `operation` and `injected: true` context and
+    /// the code's usual severity and recoverability.
+    pub fn check(&self, operation: &str) -> SisterResult<()> {
+        if self.faults.is_empty() {
+            return Ok(());
+        }
+        let roll = self.rng.next_fraction();
+        let mut threshold = 0.0;
+        for (code, rate) in &self.faults {
+            threshold += rate;
+            if roll < threshold {
+                self.injected.fetch_add(1, Ordering::Relaxed);
+                return Err(
+                    SisterError::new(*code, format!("Injected {} in {}", code, operation))
+                        .with_context("operation", operation)
+                        .with_context("injected", true),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Wraps a sister so that its fallible contract methods fail at the
+/// rates of a `FaultInjector`. Calls that are not failed are delegated
+/// unchanged; infallible methods always are.
+#[derive(Debug, Default)]
+pub struct FlakySister<S> {
+    inner: S,
+    faults: FaultInjector,
+}
+
+impl<S> FlakySister<S> {
+    pub fn new(inner: S, faults: FaultInjector) -> Self {
+        Self { inner, faults }
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    pub fn faults(&self) -> &FaultInjector {
+        &self.faults
+    }
+}
+
+impl<S: Sister> Sister for FlakySister<S> {
+    const SISTER_TYPE: crate::types::SisterType = S::SISTER_TYPE;
+    const FILE_EXTENSION: &'static str = S::FILE_EXTENSION;
+
+    /// Initializes the inner sister with no faults; use `new` to inject.
+    fn init(config: SisterConfig) -> SisterResult<Self> {
+        S::init(config).map(|inner| Self::new(inner, FaultInjector::new()))
+    }
+
+    fn health(&self) -> HealthStatus {
+        self.inner.health()
+    }
+
+    fn version(&self) -> Version {
+        self.inner.version()
+    }
+
+    fn shutdown(&mut self) -> SisterResult<()> {
+        self.faults.check("shutdown")?;
+        self.inner.shutdown()
+    }
+
+    fn capabilities(&self) -> Vec<Capability> {
+        self.inner.capabilities()
+    }
+
+    fn warm_up(&mut self) -> SisterResult<WarmUpReport> {
+        self.faults.check("warm_up")?;
+        self.inner.warm_up()
+    }
+
+    fn self_test(&self, level: DiagnosticLevel) -> SisterResult<DiagnosticReport> {
+        self.faults.check("self_test")?;
+        self.inner.self_test(level)
+    }
+
+    fn current_config(&self) -> Option<SisterConfig> {
+        self.inner.current_config()
+    }
+
+    fn apply_config(&mut self, config: SisterConfig, diff: &ConfigDiff) -> SisterResult<()> {
+        self.faults.check("apply_config")?;
+        self.inner.apply_config(config, diff)
+    }
+
+    fn pause(&mut self) -> SisterResult<()> {
+        self.faults.check("pause")?;
+        self.inner.pause()
+    }
+
+    fn resume(&mut self) -> SisterResult<()> {
+        self.faults.check("resume")?;
+        self.inner.resume()
+    }
+
+    fn config_requirements() -> ConfigRequirements {
+        S::config_requirements()
+    }
+
+    fn requires(&self) -> Vec<SisterRequirement> {
+        self.inner.requires()
+    }
+
+    fn optional_dependencies(&self) -> Vec<SisterRequirement> {
+        self.inner.optional_dependencies()
+    }
+}
+
+impl<S: SessionManagement> SessionManagement for FlakySister<S> {
+    fn start_session(&mut self, name: &str) -> SisterResult<ContextId> {
+        self.faults.check("start_session")?;
+        self.inner.start_session(name)
+    }
+
+    fn start_session_with_metadata(
+        &mut self,
+        name: &str,
+        metadata: Metadata,
+    ) -> SisterResult<ContextId> {
+        self.faults.check("start_session")?;
+        self.inner.start_session_with_metadata(name, metadata)
+    }
+
+    fn end_session(&mut self) -> SisterResult<()> {
+        self.faults.check("end_session")?;
+        self.inner.end_session()
+    }
+
+    fn current_session(&self) -> Option<ContextId> {
+        self.inner.current_session()
+    }
+
+    fn current_session_info(&self) -> SisterResult<ContextInfo> {
+        self.faults.check("current_session_info")?;
+        self.inner.current_session_info()
+    }
+
+    fn list_sessions(&self) -> SisterResult<Vec<ContextSummary>> {
+        self.faults.check("list_sessions")?;
+        self.inner.list_sessions()
+    }
+
+    fn find_sessions(&self, filter: &ContextFilter) -> SisterResult<Vec<ContextSummary>> {
+        self.faults.check("find_sessions")?;
+        self.inner.find_sessions(filter)
+    }
+
+    fn get_session_info(&self, id: ContextId) -> SisterResult<ContextInfo> {
+        self.faults.check("get_session_info")?;
+        self.inner.get_session_info(id)
+    }
+
+    fn session_stats(&self, id: ContextId) -> SisterResult<ContextStats> {
+        self.faults.check("session_stats")?;
+        self.inner.session_stats(id)
+    }
+
+    fn set_context_metadata(
+        &mut self,
+        id: ContextId,
+        key: &str,
+        value: serde_json::Value,
+    ) -> SisterResult<()> {
+        self.faults.check("set_context_metadata")?;
+        SessionManagement::set_context_metadata(&mut self.inner, id, key, value)
+    }
+
+    fn remove_context_metadata(&mut self, id: ContextId, key: &str) -> SisterResult<()> {
+        self.faults.check("remove_context_metadata")?;
+        SessionManagement::remove_context_metadata(&mut self.inner, id, key)
+    }
+
+    fn fork_session(&mut self, from: ContextId, name: &str) -> SisterResult<ContextId> {
+        self.faults.check("fork_session")?;
+        self.inner.fork_session(from, name)
+    }
+
+    fn export_session(&self, id: ContextId) -> SisterResult<ContextSnapshot> {
+        self.faults.check("export_session")?;
+        self.inner.export_session(id)
+    }
+
+    fn import_session(&mut self, snapshot: ContextSnapshot) -> SisterResult<ContextId> {
+        self.faults.check("import_session")?;
+        self.inner.import_session(snapshot)
+    }
+
+    fn export_session_delta(
+        &self,
+        id: ContextId,
+        since: &ContextSnapshot,
+    ) -> SisterResult<SnapshotDelta> {
+        self.faults.check("export_session_delta")?;
+        self.inner.export_session_delta(id, since)
+    }
+
+    fn archive_session(&mut self, id: ContextId) -> SisterResult<()> {
+        self.faults.check("archive_session")?;
+        self.inner.archive_session(id)
+    }
+
+    fn purge_sessions(&mut self, policy: &RetentionPolicy) -> SisterResult<PurgeReport> {
+        self.faults.check("purge_sessions")?;
+        self.inner.purge_sessions(policy)
+    }
+
+    fn on_session_event(&self, event: EventType) {
+        self.inner.on_session_event(event)
+    }
+
+    fn apply_session_delta(&mut self, delta: &SnapshotDelta) -> SisterResult<()> {
+        self.faults.check("apply_session_delta")?;
+        self.inner.apply_session_delta(delta)
+    }
+}
+
+impl<S: WorkspaceManagement> WorkspaceManagement for FlakySister<S> {
+    fn create_workspace(&mut self, name: &str) -> SisterResult<ContextId> {
+        self.faults.check("create_workspace")?;
+        self.inner.create_workspace(name)
+    }
+
+    fn create_workspace_with_metadata(
+        &mut self,
+        name: &str,
+        metadata: Metadata,
+    ) -> SisterResult<ContextId> {
+        self.faults.check("create_workspace")?;
+        self.inner.create_workspace_with_metadata(name, metadata)
+    }
+
+    fn switch_workspace(&mut self, id: ContextId) -> SisterResult<()> {
+        self.faults.check("switch_workspace")?;
+        self.inner.switch_workspace(id)
+    }
+
+    fn current_workspace(&self) -> ContextId {
+        self.inner.current_workspace()
+    }
+
+    fn current_workspace_info(&self) -> SisterResult<ContextInfo> {
+        self.faults.check("current_workspace_info")?;
+        self.inner.current_workspace_info()
+    }
+
+    fn list_workspaces(&self) -> SisterResult<Vec<ContextSummary>> {
+        self.faults.check("list_workspaces")?;
+        self.inner.list_workspaces()
+    }
+
+    fn find_workspaces(&self, filter: &ContextFilter) -> SisterResult<Vec<ContextSummary>> {
+        self.faults.check("find_workspaces")?;
+        self.inner.find_workspaces(filter)
+    }
+
+    fn delete_workspace(&mut self, id: ContextId) -> SisterResult<()> {
+        self.faults.check("delete_workspace")?;
+        self.inner.delete_workspace(id)
+    }
+
+    fn rename_workspace(&mut self, id: ContextId, new_name: &str) -> SisterResult<()> {
+        self.faults.check("rename_workspace")?;
+        self.inner.rename_workspace(id, new_name)
+    }
+
+    fn set_context_metadata(
+        &mut self,
+        id: ContextId,
+        key: &str,
+        value: serde_json::Value,
+    ) -> SisterResult<()> {
+        self.faults.check("set_context_metadata")?;
+        WorkspaceManagement::set_context_metadata(&mut self.inner, id, key, value)
+    }
+
+    fn remove_context_metadata(&mut self, id: ContextId, key: &str) -> SisterResult<()> {
+        self.faults.check("remove_context_metadata")?;
+        WorkspaceManagement::remove_context_metadata(&mut self.inner, id, key)
+    }
+
+    fn export_workspace(&self, id: ContextId) -> SisterResult<ContextSnapshot> {
+        self.faults.check("export_workspace")?;
+        self.inner.export_workspace(id)
+    }
+
+    fn import_workspace(&mut self, snapshot: ContextSnapshot) -> SisterResult<ContextId> {
+        self.faults.check("import_workspace")?;
+        self.inner.import_workspace(snapshot)
+    }
+
+    fn export_workspace_delta(
+        &self,
+        id: ContextId,
+        since: &ContextSnapshot,
+    ) -> SisterResult<SnapshotDelta> {
+        self.faults.check("export_workspace_delta")?;
+        self.inner.export_workspace_delta(id, since)
+    }
+
+    fn export_workspace_chunked(
+        &self,
+        id: ContextId,
+        chunk_size: usize,
+    ) -> SisterResult<SnapshotChunks> {
+        self.faults.check("export_workspace_chunked")?;
+        self.inner.export_workspace_chunked(id, chunk_size)
+    }
+
+    fn import_workspace_chunked(
+        &mut self,
+        chunks: &mut dyn Iterator<Item = SnapshotChunk>,
+    ) -> SisterResult<ContextId> {
+        self.faults.check("import_workspace_chunked")?;
+        self.inner.import_workspace_chunked(chunks)
+    }
+
+    fn apply_workspace_delta(&mut self, delta: &SnapshotDelta) -> SisterResult<()> {
+        self.faults.check("apply_workspace_delta")?;
+        self.inner.apply_workspace_delta(delta)
+    }
+
+    fn get_workspace_info(&self, id: ContextId) -> SisterResult<ContextInfo> {
+        self.faults.check("get_workspace_info")?;
+        self.inner.get_workspace_info(id)
+    }
+
+    fn workspace_exists(&self, id: ContextId) -> bool {
+        self.inner.workspace_exists(id)
+    }
+}
+
+impl<S: ContextTagging> ContextTagging for FlakySister<S> {
+    fn tag_context(&mut self, id: ContextId, tags: &[&str]) -> SisterResult<()> {
+        self.faults.check("tag_context")?;
+        self.inner.tag_context(id, tags)
+    }
+
+    fn untag_context(&mut self, id: ContextId, tags: &[&str]) -> SisterResult<()> {
+        self.faults.check("untag_context")?;
+        self.inner.untag_context(id, tags)
+    }
+
+    fn context_tags(&self, id: ContextId) -> SisterResult<Vec<String>> {
+        self.faults.check("context_tags")?;
+        self.inner.context_tags(id)
+    }
+}
+
+impl<S: Checkpointing> Checkpointing for FlakySister<S> {
+    fn checkpoint(&mut self) -> SisterResult<CheckpointId> {
+        self.faults.check("checkpoint")?;
+        self.inner.checkpoint()
+    }
+
+    fn restore_checkpoint(&mut self, id: CheckpointId) -> SisterResult<()> {
+        self.faults.check("restore_checkpoint")?;
+        self.inner.restore_checkpoint(id)
+    }
+
+    fn list_checkpoints(&self) -> SisterResult<Vec<CheckpointInfo>> {
+        self.faults.check("list_checkpoints")?;
+        self.inner.list_checkpoints()
+    }
+
+    fn latest_checkpoint(&self) -> SisterResult<Option<CheckpointInfo>> {
+        self.faults.check("latest_checkpoint")?;
+        self.inner.latest_checkpoint()
+    }
+}
+
+impl<S: ReceiptIntegration> ReceiptIntegration for FlakySister<S> {
+    fn create_receipt(&self, action: ActionRecord) -> SisterResult<ReceiptId> {
+        self.faults.check("create_receipt")?;
+        self.inner.create_receipt(action)
+    }
+
+    fn create_receipts(&self, actions: Vec<ActionRecord>) -> SisterResult<Vec<ReceiptId>> {
+        self.faults.check("create_receipts")?;
+        self.inner.create_receipts(actions)
+    }
+
+    fn get_receipt(&self, id: ReceiptId) -> SisterResult<Receipt> {
+        self.faults.check("get_receipt")?;
+        self.inner.get_receipt(id)
+    }
+
+    fn list_receipts(&self, filter: ReceiptFilter) -> SisterResult<Vec<Receipt>> {
+        self.faults.check("list_receipts")?;
+        self.inner.list_receipts(filter)
+    }
+
+    fn receipt_count(&self) -> SisterResult<u64> {
+        self.faults.check("receipt_count")?;
+        self.inner.receipt_count()
+    }
+
+    fn receipts_for_action(&self, action_type: &str) -> SisterResult<Vec<Receipt>> {
+        self.faults.check("receipts_for_action")?;
+        self.inner.receipts_for_action(action_type)
+    }
+
+    fn prove(&self, id: ReceiptId) -> SisterResult<ReceiptProof> {
+        self.faults.check("prove")?;
+        self.inner.prove(id)
+    }
+
+    fn list_receipts_page(
+        &self,
+        filter: ReceiptFilter,
+        cursor: Option<ReceiptCursor>,
+        page_size: usize,
+    ) -> SisterResult<ReceiptPage> {
+        self.faults.check("list_receipts_page")?;
+        self.inner.list_receipts_page(filter, cursor, page_size)
+    }
+
+    fn receipt_stream(&self, filter: ReceiptFilter, page_size: usize) -> ReceiptIter<'_> {
+        self.inner.receipt_stream(filter, page_size)
+    }
+
+    fn anchor_head(&self, provider: &dyn AnchorProvider) -> SisterResult<AnchorTicket> {
+        self.faults.check("anchor_head")?;
+        self.inner.anchor_head(provider)
+    }
+
+    fn compact_receipts(&self, before: DateTime<Utc>) -> SisterResult<Option<CompactedSegment>> {
+        self.faults.check("compact_receipts")?;
+        self.inner.compact_receipts(before)
+    }
+
+    fn compacted_segments(&self) -> SisterResult<Vec<CompactedSegment>> {
+        self.faults.check("compacted_segments")?;
+        self.inner.compacted_segments()
+    }
+
+    fn verify_chain(&self) -> SisterResult<()> {
+        self.faults.check("verify_chain")?;
+        self.inner.verify_chain()
+    }
+
+    fn export_receipts(&self, filter: ReceiptFilter, path: &Path) -> SisterResult<usize> {
+        self.faults.check("export_receipts")?;
+        self.inner.export_receipts(filter, path)
+    }
+}
+
+#[cfg(feature = "events")]
+impl<S: ReceiptWatcher> ReceiptWatcher for FlakySister<S> {
+    fn subscribe_receipts(&self, filter: ReceiptFilter) -> ReceiptReceiver {
+        self.inner.subscribe_receipts(filter)
+    }
+}
+
+impl<S: Grounding> Grounding for FlakySister<S> {
+    fn ground(&self, claim: &str) -> SisterResult<GroundingResult> {
+        self.faults.check("ground")?;
+        self.inner.ground(claim)
+    }
+
+    fn evidence(&self, query: &str, max_results: usize) -> SisterResult<Vec<EvidenceDetail>> {
+        self.faults.check("evidence")?;
+        self.inner.evidence(query, max_results)
+    }
+
+    fn suggest(&self, query: &str, limit: usize) -> SisterResult<Vec<GroundingSuggestion>> {
+        self.faults.check("suggest")?;
+        self.inner.suggest(query, limit)
+    }
+}
+
+impl<S: Queryable> Queryable for FlakySister<S> {
+    fn query(&self, query: Query) -> SisterResult<QueryResult> {
+        self.faults.check("query")?;
+        self.inner.query(query)
+    }
+
+    fn supports_query(&self, query_type: &str) -> bool {
+        self.inner.supports_query(query_type)
+    }
+
+    fn query_types(&self) -> Vec<QueryTypeInfo> {
+        self.inner.query_types()
+    }
+
+    fn search(&self, text: &str) -> SisterResult<QueryResult> {
+        self.faults.check("search")?;
+        self.inner.search(text)
+    }
+
+    fn recent(&self, count: usize) -> SisterResult<QueryResult> {
+        self.faults.check("recent")?;
+        self.inner.recent(count)
+    }
+
+    fn list(&self, limit: usize, offset: usize) -> SisterResult<QueryResult> {
+        self.faults.check("list")?;
+        self.inner.list(limit, offset)
+    }
+}
+
+#[cfg(feature = "events")]
+impl<S: EventEmitter> EventEmitter for FlakySister<S> {
+    fn subscribe(&self, filter: EventFilter) -> EventReceiver {
+        self.inner.subscribe(filter)
+    }
+
+    fn recent_events(&self, limit: usize) -> Vec<SisterEvent> {
+        self.inner.recent_events(limit)
+    }
+
+    fn emit(&self, event: SisterEvent) {
+        self.inner.emit(event)
+    }
+}
+
+impl<S: Metrics> Metrics for FlakySister<S> {
+    fn metrics(&self) -> Vec<Metric> {
+        self.inner.metrics()
+    }
+}
+
+/// Wraps a `Queryable` so that every query takes at least `delay`
+/// (plus up to `jitter`).
+///
+/// By default the calling thread sleeps. With `on_clock` the delay
+/// advances a `MockClock` instead, so deadline logic driven by that
+/// clock sees slow queries without the test waiting for them.
+#[derive(Debug)]
+pub struct SlowQueryable<Q> {
+    inner: Q,
+    delay: Duration,
+    jitter: Duration,
+    clock: Option<MockClock>,
+    rng: Rng,
+}
+
+impl<Q> SlowQueryable<Q> {
+    pub fn new(inner: Q, delay: Duration) -> Self {
+        Self {
+            inner,
+            delay,
+            jitter: Duration::ZERO,
+            clock: None,
+            rng: Rng::new(0),
+        }
+    }
+
+    /// Add a random extra delay of up to `jitter`, drawn from `seed`.
+    pub fn with_jitter(mut self, jitter: Duration, seed: u64) -> Self {
+        self.jitter = jitter;
+        self.rng = Rng::new(seed);
+        self
+    }
+
+    /// Advance `clock` instead of sleeping.
+    pub fn on_clock(mut self, clock: MockClock) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    pub fn inner(&self) -> &Q {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> Q {
+        self.inner
+    }
+
+    fn wait(&self) {
+        let delay = self.delay + self.jitter.mul_f64(self.rng.next_fraction());
+        match &self.clock {
+            Some(clock) => clock.advance(delay),
+            None => std::thread::sleep(delay),
+        }
+    }
+}
+
+impl<Q: Queryable> Queryable for SlowQueryable<Q> {
+    fn query(&self, query: Query) -> SisterResult<QueryResult> {
+        self.wait();
+        self.inner.query(query)
+    }
+
+    fn supports_query(&self, query_type: &str) -> bool {
+        self.inner.supports_query(query_type)
+    }
+
+    fn query_types(&self) -> Vec<QueryTypeInfo> {
+        self.inner.query_types()
+    }
+
+    fn search(&self, text: &str) -> SisterResult<QueryResult> {
+        self.wait();
+        self.inner.search(text)
+    }
+
+    fn recent(&self, count: usize) -> SisterResult<QueryResult> {
+        self.wait();
+        self.inner.recent(count)
+    }
+
+    fn list(&self, limit: usize, offset: usize) -> SisterResult<QueryResult> {
+        self.wait();
+        self.inner.list(limit, offset)
+    }
+}
+
+/// Wraps a writer and damages what passes through it, the way bad disks
+/// and crashes do: random bit flips, a flip at a chosen offset, or a torn
+/// write that silently drops everything past a length.
+///
+/// Writes always report full success, so the code under test only finds
+/// out when it reads the data back.
+#[derive(Debug)]
+pub struct CorruptingWriter<W> {
+    inner: W,
+    flip_rate: f64,
+    flip_at: Vec<u64>,
+    truncate_at: Option<u64>,
+    offset: u64,
+    flipped: usize,
+    rng: Rng,
+}
+
+impl<W: Write> CorruptingWriter<W> {
+    /// Passes bytes through unchanged until configured otherwise.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            flip_rate: 0.0,
+            flip_at: Vec::new(),
+            truncate_at: None,
+            offset: 0,
+            flipped: 0,
+            rng: Rng::new(0),
+        }
+    }
+
+    /// Seed for the random bit flips.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng = Rng::new(seed);
+        self
+    }
+
+    /// Flip one random bit in a fraction (0.0–1.0) of bytes.
+    pub fn flip_bits(mut self, rate: f64) -> Self {
+        self.flip_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Flip the lowest bit of the byte at `offset`.
+    pub fn flip_at(mut self, offset: u64) -> Self {
+        self.flip_at.push(offset);
+        self
+    }
+
+    /// Drop every byte from `len` on.
+    pub fn truncate_at(mut self, len: u64) -> Self {
+        self.truncate_at = Some(len);
+        self
+    }
+
+    /// How many bytes were altered so far.
+    pub fn flipped(&self) -> usize {
+        self.flipped
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for CorruptingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let keep = match self.truncate_at {
+            Some(len) => len.saturating_sub(self.offset).min(buf.len() as u64) as usize,
+            None => buf.len(),
+        };
+        let mut out = buf[..keep].to_vec();
+        for (i, byte) in out.iter_mut().enumerate() {
+            let offset = self.offset + i as u64;
+            let mut mask = 0u8;
+            if self.flip_at.contains(&offset) {
+                mask ^= 1;
+            }
+            if self.flip_rate > 0.0 && self.rng.next_fraction() < self.flip_rate {
+                mask ^= 1 << (self.rng.next_u64() % 8);
+            }
+            if mask != 0 {
+                *byte ^= mask;
+                self.flipped += 1;
+            }
+        }
+        self.inner.write_all(&out)?;
+        self.offset += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::{retry, RetryPolicy};
+
+    struct Echo;
+
+    impl Queryable for Echo {
+        fn query(&self, query: Query) -> SisterResult<QueryResult> {
+            Ok(QueryResult::new(query, vec![], Duration::ZERO))
+        }
+
+        fn supports_query(&self, _query_type: &str) -> bool {
+            true
+        }
+
+        fn query_types(&self) -> Vec<QueryTypeInfo> {
+            vec![]
+        }
+    }
+
+    #[test]
+    fn test_fault_injection_is_seeded() {
+        let run = |seed| {
+            let flaky = FlakySister::new(
+                Echo,
+                FaultInjector::new()
+                    .seed(seed)
+                    .timeouts(0.2)
+                    .storage_errors(0.1),
+            );
+            let codes: Vec<_> = (0..1000)
+                .map(|_| flaky.query(Query::new("list")).err().map(|e| e.code))
+                .collect();
+            (codes, flaky.faults().injected())
+        };
+        let (codes, injected) = run(7);
+        assert_eq!(run(7).0, codes);
+        assert!((200..400).contains(&injected), "{injected} faults");
+
+        let timeouts = codes.iter().flatten().filter(|c| **c == ErrorCode::Timeout);
+        let storage = codes
+            .iter()
+            .flatten()
+            .filter(|c| **c == ErrorCode::StorageError);
+        assert!(timeouts.count() > storage.count());
+
+        let clean = FlakySister::new(Echo, FaultInjector::new());
+        assert!((0..100).all(|_| clean.query(Query::new("list")).is_ok()));
+    }
+
+    #[test]
+    fn test_retry_survives_injected_timeouts() {
+        let flaky = FlakySister::new(Echo, FaultInjector::new().seed(3).timeouts(0.5));
+        let policy = RetryPolicy::new()
+            .max_attempts(20)
+            .backoff(Duration::ZERO, Duration::ZERO);
+        for _ in 0..10 {
+            retry(&policy, |_| flaky.query(Query::new("list"))).unwrap();
+        }
+        assert!(flaky.faults().injected() > 0);
+
+        let err = FaultInjector::new()
+            .timeouts(1.0)
+            .check("ground")
+            .unwrap_err();
+        assert_eq!(err.code, ErrorCode::Timeout);
+        assert!(err.recoverable);
+        assert_eq!(err.context.unwrap()["operation"], "ground");
+    }
+
+    #[test]
+    fn test_slow_queryable_on_mock_clock() {
+        let clock = MockClock::default();
+        let start = crate::clock::Clock::now(&clock);
+        let slow = SlowQueryable::new(Echo, Duration::from_secs(5))
+            .with_jitter(Duration::from_secs(1), 1)
+            .on_clock(clock.clone());
+        slow.query(Query::new("list")).unwrap();
+        slow.search("x").unwrap();
+        let elapsed = (crate::clock::Clock::now(&clock) - start).to_std().unwrap();
+        assert!(elapsed >= Duration::from_secs(10) && elapsed < Duration::from_secs(12));
+    }
+
+    #[test]
+    fn test_corrupting_writer() {
+        let data: Vec<u8> = (0..=255).collect();
+
+        let mut writer = CorruptingWriter::new(Vec::new())
+            .flip_at(10)
+            .truncate_at(200);
+        writer.write_all(&data[..100]).unwrap();
+        writer.write_all(&data[100..]).unwrap();
+        assert_eq!(writer.flipped(), 1);
+        let out = writer.into_inner();
+        assert_eq!(out.len(), 200);
+        assert_eq!(out[10], data[10] ^ 1);
+        assert_eq!(out[11..], data[11..200]);
+
+        let mut writer = CorruptingWriter::new(Vec::new()).seed(9).flip_bits(0.1);
+        writer.write_all(&data).unwrap();
+        let flipped = writer.flipped();
+        let out = writer.into_inner();
+        let differing = out.iter().zip(&data).filter(|(a, b)| a != b).count();
+        assert_eq!(differing, flipped);
+        assert!(flipped > 0);
+    }
+}
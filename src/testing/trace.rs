@@ -0,0 +1,1293 @@
+//! Recording sister interactions and replaying them.
+//!
+//! "Why did grounding disagree yesterday?" needs yesterday's call
+//! sequence. `TraceRecorder<S, W>` wraps a sister and writes every
+//! contract call (method, arguments, result or error, timing) to `W` as
+//! one JSON line. `TraceReplayer` later drives a sister through the same
+//! calls and reports where its answers differ.
+//!
+//! ```rust,ignore
+//! // Record in production, or in a failing test
+//! let file = std::fs::File::create("memory.trace.jsonl")?;
+//! let memory = TraceRecorder::new(AgenticMemory::init(config)?, file);
+//!
+//! // Replay against a build with the suspected fix
+//! let entries = read_trace(BufReader::new(File::open("memory.trace.jsonl")?))?;
+//! let report = TraceReplayer::new().grounding().queryable().replay(&mut memory, &entries);
+//! for diff in &report.diffs {
+//!     println!("#{} {}: {:?}", diff.seq, diff.method, diff.paths);
+//! }
+//! ```
+//!
+//! Replay is only meaningful against a sister in the state the recording
+//! started from (restore the same snapshot or checkpoint first). Calls
+//! whose arguments cannot be serialized (chunk iterators, anchor
+//! providers, receipt streams) and event subscriptions pass through
+//! unrecorded.
+
+use crate::context::{
+    CheckpointId, CheckpointInfo, Checkpointing, ContextFilter, ContextId, ContextInfo,
+    ContextSnapshot, ContextStats, ContextSummary, ContextTagging, PurgeReport, RetentionPolicy,
+    SessionManagement, SnapshotChunk, SnapshotChunks, SnapshotDelta, WorkspaceManagement,
+};
+use crate::diagnostics::{DiagnosticLevel, DiagnosticReport};
+use crate::errors::{SisterError, SisterResult};
+use crate::events::EventType;
+#[cfg(feature = "events")]
+use crate::events::{EventEmitter, EventFilter, EventReceiver, SisterEvent};
+use crate::grounding::{EvidenceDetail, Grounding, GroundingResult, GroundingSuggestion};
+use crate::metrics::{Metric, Metrics};
+use crate::query::{Query, QueryResult, QueryTypeInfo, Queryable};
+use crate::receipts::{
+    ActionRecord, AnchorProvider, AnchorTicket, CompactedSegment, Receipt, ReceiptCursor,
+    ReceiptFilter, ReceiptId, ReceiptIntegration, ReceiptIter, ReceiptPage, ReceiptProof,
+};
+#[cfg(feature = "events")]
+use crate::receipts::{ReceiptReceiver, ReceiptWatcher};
+use crate::sister::{
+    ConfigDiff, ConfigRequirements, Sister, SisterConfig, SisterRequirement, WarmUpReport,
+};
+use crate::types::{Capability, HealthStatus, Metadata, SisterType, Version};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeSet, HashMap};
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// One recorded call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceEntry {
+    /// Position in the trace, from 0
+    pub seq: u64,
+
+    pub sister_type: SisterType,
+
+    /// Trait method name (`"ground"`, `"query"`, ...)
+    pub method: String,
+
+    /// Arguments by parameter name
+    #[serde(default)]
+    pub args: Value,
+
+    pub started_at: DateTime<Utc>,
+    pub duration_us: u64,
+
+    /// Return value, if the call succeeded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+
+    /// Error, if the call failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<SisterError>,
+}
+
+impl TraceEntry {
+    /// Result or error as one value (`{"ok": ...}` / `{"err": ...}`).
+    pub fn outcome(&self) -> Value {
+        outcome(self.result.clone(), self.error.as_ref())
+    }
+}
+
+fn outcome(result: Option<Value>, error: Option<&SisterError>) -> Value {
+    match error {
+        Some(e) => json!({ "err": e }),
+        None => json!({ "ok": result.unwrap_or(Value::Null) }),
+    }
+}
+
+/// Parse a JSONL trace written by `TraceRecorder`. Blank lines are
+/// skipped.
+pub fn read_trace(reader: impl BufRead) -> SisterResult<Vec<TraceEntry>> {
+    let mut entries = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry = serde_json::from_str(&line).map_err(|e| {
+            SisterError::invalid_input(format!("Invalid trace line {}: {}", index + 1, e))
+                .with_context("line", index + 1)
+        })?;
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// The writing half of a recorder, kept apart from the sister so a
+/// call can borrow both.
+#[derive(Debug)]
+struct TraceLog<W> {
+    sister_type: SisterType,
+    sink: Mutex<(u64, W)>,
+}
+
+impl<W: Write> TraceLog<W> {
+    fn call<T: Serialize>(
+        &self,
+        method: &str,
+        args: Value,
+        call: impl FnOnce() -> SisterResult<T>,
+    ) -> SisterResult<T> {
+        let started_at = Utc::now();
+        let start = Instant::now();
+        let result = call();
+        let (value, error) = match &result {
+            Ok(value) => (Some(to_json(value)), None),
+            Err(e) => (None, Some(e.clone())),
+        };
+        self.write(method, args, started_at, start, value, error);
+        result
+    }
+
+    fn value<T: Serialize>(&self, method: &str, args: Value, call: impl FnOnce() -> T) -> T {
+        let started_at = Utc::now();
+        let start = Instant::now();
+        let value = call();
+        self.write(method, args, started_at, start, Some(to_json(&value)), None);
+        value
+    }
+
+    /// Append one line. A failing sink never fails the sister call.
+    fn write(
+        &self,
+        method: &str,
+        args: Value,
+        started_at: DateTime<Utc>,
+        start: Instant,
+        result: Option<Value>,
+        error: Option<SisterError>,
+    ) {
+        let duration_us = start.elapsed().as_micros() as u64;
+        let mut sink = self.sink.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = TraceEntry {
+            seq: sink.0,
+            sister_type: self.sister_type,
+            method: method.to_string(),
+            args,
+            started_at,
+            duration_us,
+            result,
+            error,
+        };
+        sink.0 += 1;
+        if let Ok(mut line) = serde_json::to_vec(&entry) {
+            line.push(b'\n');
+            let _ = sink.1.write_all(&line).and_then(|_| sink.1.flush());
+        }
+    }
+}
+
+fn to_json(value: &impl Serialize) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+/// Wraps a sister and logs every contract call to `W` as JSONL.
+///
+/// Recording never changes behaviour: results and errors are returned
+/// exactly as the inner sister produced them, and write failures on the
+/// sink are ignored.
+#[derive(Debug)]
+pub struct TraceRecorder<S, W> {
+    inner: S,
+    log: TraceLog<W>,
+}
+
+impl<S: Sister, W: Write> TraceRecorder<S, W> {
+    pub fn new(inner: S, sink: W) -> Self {
+        Self {
+            inner,
+            log: TraceLog {
+                sister_type: S::SISTER_TYPE,
+                sink: Mutex::new((0, sink)),
+            },
+        }
+    }
+}
+
+impl<S, W> TraceRecorder<S, W> {
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// The sister and the sink, e.g. to read back an in-memory trace.
+    pub fn into_parts(self) -> (S, W) {
+        let (_, sink) = self
+            .log
+            .sink
+            .into_inner()
+            .unwrap_or_else(|e| e.into_inner());
+        (self.inner, sink)
+    }
+}
+
+impl<S: Sister, W: Write + Send> Sister for TraceRecorder<S, W> {
+    const SISTER_TYPE: SisterType = S::SISTER_TYPE;
+    const FILE_EXTENSION: &'static str = S::FILE_EXTENSION;
+
+    /// Not supported: a recorder needs a sink. Use `new`.
+    fn init(_config: SisterConfig) -> SisterResult<Self> {
+        Err(SisterError::invalid_input(
+            "TraceRecorder needs a sink; use TraceRecorder::new",
+        ))
+    }
+
+    fn health(&self) -> HealthStatus {
+        self.log.value("health", json!({}), || self.inner.health())
+    }
+
+    fn version(&self) -> Version {
+        self.log
+            .value("version", json!({}), || self.inner.version())
+    }
+
+    fn shutdown(&mut self) -> SisterResult<()> {
+        self.log
+            .call("shutdown", json!({}), || self.inner.shutdown())
+    }
+
+    fn capabilities(&self) -> Vec<Capability> {
+        self.log
+            .value("capabilities", json!({}), || self.inner.capabilities())
+    }
+
+    fn warm_up(&mut self) -> SisterResult<WarmUpReport> {
+        self.log.call("warm_up", json!({}), || self.inner.warm_up())
+    }
+
+    fn self_test(&self, level: DiagnosticLevel) -> SisterResult<DiagnosticReport> {
+        self.log.call("self_test", json!({ "level": level }), || {
+            self.inner.self_test(level)
+        })
+    }
+
+    fn current_config(&self) -> Option<SisterConfig> {
+        self.inner.current_config()
+    }
+
+    fn apply_config(&mut self, config: SisterConfig, diff: &ConfigDiff) -> SisterResult<()> {
+        let args = json!({ "config": config, "diff": diff });
+        self.log.call("apply_config", args, || {
+            self.inner.apply_config(config, diff)
+        })
+    }
+
+    fn pause(&mut self) -> SisterResult<()> {
+        self.log.call("pause", json!({}), || self.inner.pause())
+    }
+
+    fn resume(&mut self) -> SisterResult<()> {
+        self.log.call("resume", json!({}), || self.inner.resume())
+    }
+
+    fn config_requirements() -> ConfigRequirements {
+        S::config_requirements()
+    }
+
+    fn requires(&self) -> Vec<SisterRequirement> {
+        self.inner.requires()
+    }
+
+    fn optional_dependencies(&self) -> Vec<SisterRequirement> {
+        self.inner.optional_dependencies()
+    }
+}
+
+impl<S: SessionManagement, W: Write> SessionManagement for TraceRecorder<S, W> {
+    fn start_session(&mut self, name: &str) -> SisterResult<ContextId> {
+        self.log.call("start_session", json!({ "name": name }), || {
+            self.inner.start_session(name)
+        })
+    }
+
+    fn start_session_with_metadata(
+        &mut self,
+        name: &str,
+        metadata: Metadata,
+    ) -> SisterResult<ContextId> {
+        let args = json!({ "name": name, "metadata": metadata });
+        self.log.call("start_session_with_metadata", args, || {
+            self.inner.start_session_with_metadata(name, metadata)
+        })
+    }
+
+    fn end_session(&mut self) -> SisterResult<()> {
+        self.log
+            .call("end_session", json!({}), || self.inner.end_session())
+    }
+
+    fn current_session(&self) -> Option<ContextId> {
+        self.log.value("current_session", json!({}), || {
+            self.inner.current_session()
+        })
+    }
+
+    fn current_session_info(&self) -> SisterResult<ContextInfo> {
+        self.log.call("current_session_info", json!({}), || {
+            self.inner.current_session_info()
+        })
+    }
+
+    fn list_sessions(&self) -> SisterResult<Vec<ContextSummary>> {
+        self.log
+            .call("list_sessions", json!({}), || self.inner.list_sessions())
+    }
+
+    fn find_sessions(&self, filter: &ContextFilter) -> SisterResult<Vec<ContextSummary>> {
+        self.log
+            .call("find_sessions", json!({ "filter": filter }), || {
+                self.inner.find_sessions(filter)
+            })
+    }
+
+    fn get_session_info(&self, id: ContextId) -> SisterResult<ContextInfo> {
+        self.log.call("get_session_info", json!({ "id": id }), || {
+            self.inner.get_session_info(id)
+        })
+    }
+
+    fn session_stats(&self, id: ContextId) -> SisterResult<ContextStats> {
+        self.log.call("session_stats", json!({ "id": id }), || {
+            self.inner.session_stats(id)
+        })
+    }
+
+    fn set_context_metadata(&mut self, id: ContextId, key: &str, value: Value) -> SisterResult<()> {
+        let args = json!({ "id": id, "key": key, "value": value });
+        self.log.call("set_context_metadata", args, || {
+            SessionManagement::set_context_metadata(&mut self.inner, id, key, value)
+        })
+    }
+
+    fn remove_context_metadata(&mut self, id: ContextId, key: &str) -> SisterResult<()> {
+        let args = json!({ "id": id, "key": key });
+        self.log.call("remove_context_metadata", args, || {
+            SessionManagement::remove_context_metadata(&mut self.inner, id, key)
+        })
+    }
+
+    fn fork_session(&mut self, from: ContextId, name: &str) -> SisterResult<ContextId> {
+        let args = json!({ "from": from, "name": name });
+        self.log
+            .call("fork_session", args, || self.inner.fork_session(from, name))
+    }
+
+    fn export_session(&self, id: ContextId) -> SisterResult<ContextSnapshot> {
+        self.log.call("export_session", json!({ "id": id }), || {
+            self.inner.export_session(id)
+        })
+    }
+
+    fn import_session(&mut self, snapshot: ContextSnapshot) -> SisterResult<ContextId> {
+        let args = json!({ "snapshot": snapshot });
+        self.log.call("import_session", args, || {
+            self.inner.import_session(snapshot)
+        })
+    }
+
+    fn export_session_delta(
+        &self,
+        id: ContextId,
+        since: &ContextSnapshot,
+    ) -> SisterResult<SnapshotDelta> {
+        let args = json!({ "id": id, "since": since });
+        self.log.call("export_session_delta", args, || {
+            self.inner.export_session_delta(id, since)
+        })
+    }
+
+    fn archive_session(&mut self, id: ContextId) -> SisterResult<()> {
+        self.log.call("archive_session", json!({ "id": id }), || {
+            self.inner.archive_session(id)
+        })
+    }
+
+    fn purge_sessions(&mut self, policy: &RetentionPolicy) -> SisterResult<PurgeReport> {
+        self.log
+            .call("purge_sessions", json!({ "policy": policy }), || {
+                self.inner.purge_sessions(policy)
+            })
+    }
+
+    fn on_session_event(&self, event: EventType) {
+        self.inner.on_session_event(event)
+    }
+
+    fn apply_session_delta(&mut self, delta: &SnapshotDelta) -> SisterResult<()> {
+        self.log
+            .call("apply_session_delta", json!({ "delta": delta }), || {
+                self.inner.apply_session_delta(delta)
+            })
+    }
+}
+
+impl<S: WorkspaceManagement, W: Write> WorkspaceManagement for TraceRecorder<S, W> {
+    fn create_workspace(&mut self, name: &str) -> SisterResult<ContextId> {
+        self.log
+            .call("create_workspace", json!({ "name": name }), || {
+                self.inner.create_workspace(name)
+            })
+    }
+
+    fn create_workspace_with_metadata(
+        &mut self,
+        name: &str,
+        metadata: Metadata,
+    ) -> SisterResult<ContextId> {
+        let args = json!({ "name": name, "metadata": metadata });
+        self.log.call("create_workspace_with_metadata", args, || {
+            self.inner.create_workspace_with_metadata(name, metadata)
+        })
+    }
+
+    fn switch_workspace(&mut self, id: ContextId) -> SisterResult<()> {
+        self.log.call("switch_workspace", json!({ "id": id }), || {
+            self.inner.switch_workspace(id)
+        })
+    }
+
+    fn current_workspace(&self) -> ContextId {
+        self.log.value("current_workspace", json!({}), || {
+            self.inner.current_workspace()
+        })
+    }
+
+    fn current_workspace_info(&self) -> SisterResult<ContextInfo> {
+        self.log.call("current_workspace_info", json!({}), || {
+            self.inner.current_workspace_info()
+        })
+    }
+
+    fn list_workspaces(&self) -> SisterResult<Vec<ContextSummary>> {
+        self.log.call("list_workspaces", json!({}), || {
+            self.inner.list_workspaces()
+        })
+    }
+
+    fn find_workspaces(&self, filter: &ContextFilter) -> SisterResult<Vec<ContextSummary>> {
+        self.log
+            .call("find_workspaces", json!({ "filter": filter }), || {
+                self.inner.find_workspaces(filter)
+            })
+    }
+
+    fn delete_workspace(&mut self, id: ContextId) -> SisterResult<()> {
+        self.log.call("delete_workspace", json!({ "id": id }), || {
+            self.inner.delete_workspace(id)
+        })
+    }
+
+    fn rename_workspace(&mut self, id: ContextId, new_name: &str) -> SisterResult<()> {
+        let args = json!({ "id": id, "new_name": new_name });
+        self.log.call("rename_workspace", args, || {
+            self.inner.rename_workspace(id, new_name)
+        })
+    }
+
+    fn set_context_metadata(&mut self, id: ContextId, key: &str, value: Value) -> SisterResult<()> {
+        let args = json!({ "id": id, "key": key, "value": value });
+        self.log.call("set_context_metadata", args, || {
+            WorkspaceManagement::set_context_metadata(&mut self.inner, id, key, value)
+        })
+    }
+
+    fn remove_context_metadata(&mut self, id: ContextId, key: &str) -> SisterResult<()> {
+        let args = json!({ "id": id, "key": key });
+        self.log.call("remove_context_metadata", args, || {
+            WorkspaceManagement::remove_context_metadata(&mut self.inner, id, key)
+        })
+    }
+
+    fn export_workspace(&self, id: ContextId) -> SisterResult<ContextSnapshot> {
+        self.log.call("export_workspace", json!({ "id": id }), || {
+            self.inner.export_workspace(id)
+        })
+    }
+
+    fn import_workspace(&mut self, snapshot: ContextSnapshot) -> SisterResult<ContextId> {
+        let args = json!({ "snapshot": snapshot });
+        self.log.call("import_workspace", args, || {
+            self.inner.import_workspace(snapshot)
+        })
+    }
+
+    fn export_workspace_delta(
+        &self,
+        id: ContextId,
+        since: &ContextSnapshot,
+    ) -> SisterResult<SnapshotDelta> {
+        let args = json!({ "id": id, "since": since });
+        self.log.call("export_workspace_delta", args, || {
+            self.inner.export_workspace_delta(id, since)
+        })
+    }
+
+    fn export_workspace_chunked(
+        &self,
+        id: ContextId,
+        chunk_size: usize,
+    ) -> SisterResult<SnapshotChunks> {
+        self.inner.export_workspace_chunked(id, chunk_size)
+    }
+
+    fn import_workspace_chunked(
+        &mut self,
+        chunks: &mut dyn Iterator<Item = SnapshotChunk>,
+    ) -> SisterResult<ContextId> {
+        self.inner.import_workspace_chunked(chunks)
+    }
+
+    fn apply_workspace_delta(&mut self, delta: &SnapshotDelta) -> SisterResult<()> {
+        self.log
+            .call("apply_workspace_delta", json!({ "delta": delta }), || {
+                self.inner.apply_workspace_delta(delta)
+            })
+    }
+
+    fn get_workspace_info(&self, id: ContextId) -> SisterResult<ContextInfo> {
+        self.log
+            .call("get_workspace_info", json!({ "id": id }), || {
+                self.inner.get_workspace_info(id)
+            })
+    }
+
+    fn workspace_exists(&self, id: ContextId) -> bool {
+        self.log.value("workspace_exists", json!({ "id": id }), || {
+            self.inner.workspace_exists(id)
+        })
+    }
+}
+
+impl<S: ContextTagging, W: Write> ContextTagging for TraceRecorder<S, W> {
+    fn tag_context(&mut self, id: ContextId, tags: &[&str]) -> SisterResult<()> {
+        let args = json!({ "id": id, "tags": tags });
+        self.log
+            .call("tag_context", args, || self.inner.tag_context(id, tags))
+    }
+
+    fn untag_context(&mut self, id: ContextId, tags: &[&str]) -> SisterResult<()> {
+        let args = json!({ "id": id, "tags": tags });
+        self.log
+            .call("untag_context", args, || self.inner.untag_context(id, tags))
+    }
+
+    fn context_tags(&self, id: ContextId) -> SisterResult<Vec<String>> {
+        self.log.call("context_tags", json!({ "id": id }), || {
+            self.inner.context_tags(id)
+        })
+    }
+}
+
+impl<S: Checkpointing, W: Write> Checkpointing for TraceRecorder<S, W> {
+    fn checkpoint(&mut self) -> SisterResult<CheckpointId> {
+        self.log
+            .call("checkpoint", json!({}), || self.inner.checkpoint())
+    }
+
+    fn restore_checkpoint(&mut self, id: CheckpointId) -> SisterResult<()> {
+        self.log
+            .call("restore_checkpoint", json!({ "id": id }), || {
+                self.inner.restore_checkpoint(id)
+            })
+    }
+
+    fn list_checkpoints(&self) -> SisterResult<Vec<CheckpointInfo>> {
+        self.log.call("list_checkpoints", json!({}), || {
+            self.inner.list_checkpoints()
+        })
+    }
+
+    fn latest_checkpoint(&self) -> SisterResult<Option<CheckpointInfo>> {
+        self.log.call("latest_checkpoint", json!({}), || {
+            self.inner.latest_checkpoint()
+        })
+    }
+}
+
+impl<S: ReceiptIntegration, W: Write + Send> ReceiptIntegration for TraceRecorder<S, W> {
+    fn create_receipt(&self, action: ActionRecord) -> SisterResult<ReceiptId> {
+        let args = json!({ "action": action });
+        self.log
+            .call("create_receipt", args, || self.inner.create_receipt(action))
+    }
+
+    fn create_receipts(&self, actions: Vec<ActionRecord>) -> SisterResult<Vec<ReceiptId>> {
+        let args = json!({ "actions": actions });
+        self.log.call("create_receipts", args, || {
+            self.inner.create_receipts(actions)
+        })
+    }
+
+    fn get_receipt(&self, id: ReceiptId) -> SisterResult<Receipt> {
+        self.log.call("get_receipt", json!({ "id": id }), || {
+            self.inner.get_receipt(id)
+        })
+    }
+
+    fn list_receipts(&self, filter: ReceiptFilter) -> SisterResult<Vec<Receipt>> {
+        let args = json!({ "filter": filter });
+        self.log
+            .call("list_receipts", args, || self.inner.list_receipts(filter))
+    }
+
+    fn receipt_count(&self) -> SisterResult<u64> {
+        self.log
+            .call("receipt_count", json!({}), || self.inner.receipt_count())
+    }
+
+    fn receipts_for_action(&self, action_type: &str) -> SisterResult<Vec<Receipt>> {
+        let args = json!({ "action_type": action_type });
+        self.log.call("receipts_for_action", args, || {
+            self.inner.receipts_for_action(action_type)
+        })
+    }
+
+    fn prove(&self, id: ReceiptId) -> SisterResult<ReceiptProof> {
+        self.log
+            .call("prove", json!({ "id": id }), || self.inner.prove(id))
+    }
+
+    fn list_receipts_page(
+        &self,
+        filter: ReceiptFilter,
+        cursor: Option<ReceiptCursor>,
+        page_size: usize,
+    ) -> SisterResult<ReceiptPage> {
+        let args = json!({ "filter": filter, "cursor": cursor, "page_size": page_size });
+        self.log.call("list_receipts_page", args, || {
+            self.inner.list_receipts_page(filter, cursor, page_size)
+        })
+    }
+
+    fn receipt_stream(&self, filter: ReceiptFilter, page_size: usize) -> ReceiptIter<'_> {
+        self.inner.receipt_stream(filter, page_size)
+    }
+
+    fn anchor_head(&self, provider: &dyn AnchorProvider) -> SisterResult<AnchorTicket> {
+        self.inner.anchor_head(provider)
+    }
+
+    fn compact_receipts(&self, before: DateTime<Utc>) -> SisterResult<Option<CompactedSegment>> {
+        self.log
+            .call("compact_receipts", json!({ "before": before }), || {
+                self.inner.compact_receipts(before)
+            })
+    }
+
+    fn compacted_segments(&self) -> SisterResult<Vec<CompactedSegment>> {
+        self.log.call("compacted_segments", json!({}), || {
+            self.inner.compacted_segments()
+        })
+    }
+
+    fn verify_chain(&self) -> SisterResult<()> {
+        self.log
+            .call("verify_chain", json!({}), || self.inner.verify_chain())
+    }
+
+    fn export_receipts(&self, filter: ReceiptFilter, path: &Path) -> SisterResult<usize> {
+        let args = json!({ "filter": filter, "path": path });
+        self.log.call("export_receipts", args, || {
+            self.inner.export_receipts(filter, path)
+        })
+    }
+}
+
+#[cfg(feature = "events")]
+impl<S: ReceiptWatcher, W: Write + Send> ReceiptWatcher for TraceRecorder<S, W> {
+    fn subscribe_receipts(&self, filter: ReceiptFilter) -> ReceiptReceiver {
+        self.inner.subscribe_receipts(filter)
+    }
+}
+
+impl<S: Grounding, W: Write> Grounding for TraceRecorder<S, W> {
+    fn ground(&self, claim: &str) -> SisterResult<GroundingResult> {
+        self.log.call("ground", json!({ "claim": claim }), || {
+            self.inner.ground(claim)
+        })
+    }
+
+    fn evidence(&self, query: &str, max_results: usize) -> SisterResult<Vec<EvidenceDetail>> {
+        let args = json!({ "query": query, "max_results": max_results });
+        self.log
+            .call("evidence", args, || self.inner.evidence(query, max_results))
+    }
+
+    fn suggest(&self, query: &str, limit: usize) -> SisterResult<Vec<GroundingSuggestion>> {
+        let args = json!({ "query": query, "limit": limit });
+        self.log
+            .call("suggest", args, || self.inner.suggest(query, limit))
+    }
+}
+
+impl<S: Queryable, W: Write> Queryable for TraceRecorder<S, W> {
+    fn query(&self, query: Query) -> SisterResult<QueryResult> {
+        let args = json!({ "query": query });
+        self.log.call("query", args, || self.inner.query(query))
+    }
+
+    fn supports_query(&self, query_type: &str) -> bool {
+        let args = json!({ "query_type": query_type });
+        self.log.value("supports_query", args, || {
+            self.inner.supports_query(query_type)
+        })
+    }
+
+    fn query_types(&self) -> Vec<QueryTypeInfo> {
+        self.log
+            .value("query_types", json!({}), || self.inner.query_types())
+    }
+
+    fn search(&self, text: &str) -> SisterResult<QueryResult> {
+        self.log.call("search", json!({ "text": text }), || {
+            self.inner.search(text)
+        })
+    }
+
+    fn recent(&self, count: usize) -> SisterResult<QueryResult> {
+        self.log.call("recent", json!({ "count": count }), || {
+            self.inner.recent(count)
+        })
+    }
+
+    fn list(&self, limit: usize, offset: usize) -> SisterResult<QueryResult> {
+        let args = json!({ "limit": limit, "offset": offset });
+        self.log
+            .call("list", args, || self.inner.list(limit, offset))
+    }
+}
+
+#[cfg(feature = "events")]
+impl<S: EventEmitter, W: Write + Send> EventEmitter for TraceRecorder<S, W> {
+    fn subscribe(&self, filter: EventFilter) -> EventReceiver {
+        self.inner.subscribe(filter)
+    }
+
+    fn recent_events(&self, limit: usize) -> Vec<SisterEvent> {
+        self.inner.recent_events(limit)
+    }
+
+    fn emit(&self, event: SisterEvent) {
+        self.inner.emit(event)
+    }
+}
+
+impl<S: Metrics, W> Metrics for TraceRecorder<S, W> {
+    fn metrics(&self) -> Vec<Metric> {
+        self.inner.metrics()
+    }
+}
+
+/// Calls one method on `S` from recorded arguments.
+pub type ReplayHandler<S> = fn(&mut S, &Value) -> SisterResult<Value>;
+
+/// Field names ignored by default when comparing outcomes: timestamps
+/// and timings that differ on every run.
+pub const VOLATILE_FIELDS: [&str; 7] = [
+    "timestamp",
+    "created_at",
+    "updated_at",
+    "snapshot_at",
+    "started_at",
+    "query_time",
+    "uptime",
+];
+
+/// Drives a sister through a recorded trace.
+///
+/// Methods are replayed only if a handler is registered for them: enable
+/// whole traits with `lifecycle()`, `sessions()`, `grounding()`, ... or
+/// add sister-specific ones with `on`. Other entries are skipped.
+pub struct TraceReplayer<S> {
+    handlers: HashMap<&'static str, ReplayHandler<S>>,
+    ignore: BTreeSet<String>,
+}
+
+impl<S> Default for TraceReplayer<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Where a replayed call disagreed with the recording.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayDiff {
+    pub seq: u64,
+    pub method: String,
+
+    /// JSON pointers (`/ok/confidence`) at which the outcomes differ
+    pub paths: Vec<String>,
+
+    /// Recorded outcome (`{"ok": ...}` / `{"err": ...}`)
+    pub expected: Value,
+
+    /// Outcome of the replayed call
+    pub actual: Value,
+}
+
+/// Result of `TraceReplayer::replay`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReplayReport {
+    /// Calls replayed
+    pub replayed: usize,
+
+    /// `seq` of entries without a handler
+    pub skipped: Vec<u64>,
+
+    pub diffs: Vec<ReplayDiff>,
+}
+
+impl ReplayReport {
+    /// Every replayed call matched its recording.
+    pub fn is_clean(&self) -> bool {
+        self.diffs.is_empty()
+    }
+}
+
+/// Deserialize argument `name`; `InvalidInput` if missing or malformed.
+pub fn arg<T: DeserializeOwned>(args: &Value, name: &str) -> SisterResult<T> {
+    let value = args.get(name).cloned().unwrap_or(Value::Null);
+    serde_json::from_value(value).map_err(|e| {
+        SisterError::invalid_input(format!("Bad trace argument {:?}: {}", name, e))
+            .with_context("argument", name)
+    })
+}
+
+fn ok<T: Serialize>(result: SisterResult<T>) -> SisterResult<Value> {
+    result.map(|value| to_json(&value))
+}
+
+impl<S> TraceReplayer<S> {
+    /// No handlers; ignores `VOLATILE_FIELDS`.
+    pub fn new() -> Self {
+        Self {
+            handlers: HashMap::new(),
+            ignore: VOLATILE_FIELDS.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    /// Replay `method` with `handler`.
+    pub fn on(mut self, method: &'static str, handler: ReplayHandler<S>) -> Self {
+        self.handlers.insert(method, handler);
+        self
+    }
+
+    /// Also ignore fields named `field` (at any depth) when comparing.
+    pub fn ignore(mut self, field: impl Into<String>) -> Self {
+        self.ignore.insert(field.into());
+        self
+    }
+
+    /// Call every entry with a handler, in order, and diff the outcomes.
+    pub fn replay(&self, sister: &mut S, entries: &[TraceEntry]) -> ReplayReport {
+        let mut report = ReplayReport::default();
+        for entry in entries {
+            let Some(handler) = self.handlers.get(entry.method.as_str()) else {
+                report.skipped.push(entry.seq);
+                continue;
+            };
+            report.replayed += 1;
+            let actual = match handler(sister, &entry.args) {
+                Ok(value) => outcome(Some(value), None),
+                Err(e) => outcome(None, Some(&e)),
+            };
+            let expected = entry.outcome();
+            let mut paths = Vec::new();
+            diff_json(&expected, &actual, &self.ignore, String::new(), &mut paths);
+            if !paths.is_empty() {
+                report.diffs.push(ReplayDiff {
+                    seq: entry.seq,
+                    method: entry.method.clone(),
+                    paths,
+                    expected,
+                    actual,
+                });
+            }
+        }
+        report
+    }
+}
+
+impl<S: Sister> TraceReplayer<S> {
+    /// `Sister` methods except `apply_config`.
+    pub fn lifecycle(self) -> Self {
+        self.on("health", |s, _| Ok(to_json(&s.health())))
+            .on("version", |s, _| Ok(to_json(&s.version())))
+            .on("capabilities", |s, _| Ok(to_json(&s.capabilities())))
+            .on("shutdown", |s, _| ok(s.shutdown()))
+            .on("warm_up", |s, _| ok(s.warm_up()))
+            .on("self_test", |s, a| ok(s.self_test(arg(a, "level")?)))
+            .on("pause", |s, _| ok(s.pause()))
+            .on("resume", |s, _| ok(s.resume()))
+    }
+}
+
+impl<S: SessionManagement> TraceReplayer<S> {
+    pub fn sessions(self) -> Self {
+        self.on("start_session", |s, a| {
+            ok(s.start_session(&arg::<String>(a, "name")?))
+        })
+        .on("start_session_with_metadata", |s, a| {
+            ok(s.start_session_with_metadata(&arg::<String>(a, "name")?, arg(a, "metadata")?))
+        })
+        .on("end_session", |s, _| ok(s.end_session()))
+        .on("current_session", |s, _| Ok(to_json(&s.current_session())))
+        .on("current_session_info", |s, _| ok(s.current_session_info()))
+        .on("list_sessions", |s, _| ok(s.list_sessions()))
+        .on("find_sessions", |s, a| {
+            ok(s.find_sessions(&arg(a, "filter")?))
+        })
+        .on("get_session_info", |s, a| {
+            ok(s.get_session_info(arg(a, "id")?))
+        })
+        .on("session_stats", |s, a| ok(s.session_stats(arg(a, "id")?)))
+        .on("set_context_metadata", |s, a| {
+            ok(SessionManagement::set_context_metadata(
+                s,
+                arg(a, "id")?,
+                &arg::<String>(a, "key")?,
+                arg(a, "value")?,
+            ))
+        })
+        .on("remove_context_metadata", |s, a| {
+            ok(SessionManagement::remove_context_metadata(
+                s,
+                arg(a, "id")?,
+                &arg::<String>(a, "key")?,
+            ))
+        })
+        .on("fork_session", |s, a| {
+            ok(s.fork_session(arg(a, "from")?, &arg::<String>(a, "name")?))
+        })
+        .on("export_session", |s, a| ok(s.export_session(arg(a, "id")?)))
+        .on("import_session", |s, a| {
+            ok(s.import_session(arg(a, "snapshot")?))
+        })
+        .on("export_session_delta", |s, a| {
+            ok(s.export_session_delta(arg(a, "id")?, &arg(a, "since")?))
+        })
+        .on("archive_session", |s, a| {
+            ok(s.archive_session(arg(a, "id")?))
+        })
+        .on("purge_sessions", |s, a| {
+            ok(s.purge_sessions(&arg(a, "policy")?))
+        })
+        .on("apply_session_delta", |s, a| {
+            ok(s.apply_session_delta(&arg(a, "delta")?))
+        })
+    }
+}
+
+impl<S: WorkspaceManagement> TraceReplayer<S> {
+    /// `WorkspaceManagement` methods except the chunked transfers.
+    pub fn workspaces(self) -> Self {
+        self.on("create_workspace", |s, a| {
+            ok(s.create_workspace(&arg::<String>(a, "name")?))
+        })
+        .on("create_workspace_with_metadata", |s, a| {
+            ok(s.create_workspace_with_metadata(&arg::<String>(a, "name")?, arg(a, "metadata")?))
+        })
+        .on("switch_workspace", |s, a| {
+            ok(s.switch_workspace(arg(a, "id")?))
+        })
+        .on("current_workspace", |s, _| {
+            Ok(to_json(&s.current_workspace()))
+        })
+        .on("current_workspace_info", |s, _| {
+            ok(s.current_workspace_info())
+        })
+        .on("list_workspaces", |s, _| ok(s.list_workspaces()))
+        .on("find_workspaces", |s, a| {
+            ok(s.find_workspaces(&arg(a, "filter")?))
+        })
+        .on("delete_workspace", |s, a| {
+            ok(s.delete_workspace(arg(a, "id")?))
+        })
+        .on("rename_workspace", |s, a| {
+            ok(s.rename_workspace(arg(a, "id")?, &arg::<String>(a, "new_name")?))
+        })
+        .on("set_context_metadata", |s, a| {
+            ok(WorkspaceManagement::set_context_metadata(
+                s,
+                arg(a, "id")?,
+                &arg::<String>(a, "key")?,
+                arg(a, "value")?,
+            ))
+        })
+        .on("remove_context_metadata", |s, a| {
+            ok(WorkspaceManagement::remove_context_metadata(
+                s,
+                arg(a, "id")?,
+                &arg::<String>(a, "key")?,
+            ))
+        })
+        .on("export_workspace", |s, a| {
+            ok(s.export_workspace(arg(a, "id")?))
+        })
+        .on("import_workspace", |s, a| {
+            ok(s.import_workspace(arg(a, "snapshot")?))
+        })
+        .on("export_workspace_delta", |s, a| {
+            ok(s.export_workspace_delta(arg(a, "id")?, &arg(a, "since")?))
+        })
+        .on("apply_workspace_delta", |s, a| {
+            ok(s.apply_workspace_delta(&arg(a, "delta")?))
+        })
+        .on("get_workspace_info", |s, a| {
+            ok(s.get_workspace_info(arg(a, "id")?))
+        })
+        .on("workspace_exists", |s, a| {
+            Ok(to_json(&s.workspace_exists(arg(a, "id")?)))
+        })
+    }
+}
+
+impl<S: ContextTagging> TraceReplayer<S> {
+    pub fn tagging(self) -> Self {
+        fn tags(args: &Value) -> SisterResult<Vec<String>> {
+            arg(args, "tags")
+        }
+        self.on("tag_context", |s, a| {
+            let tags = tags(a)?;
+            let tags: Vec<&str> = tags.iter().map(String::as_str).collect();
+            ok(s.tag_context(arg(a, "id")?, &tags))
+        })
+        .on("untag_context", |s, a| {
+            let tags = tags(a)?;
+            let tags: Vec<&str> = tags.iter().map(String::as_str).collect();
+            ok(s.untag_context(arg(a, "id")?, &tags))
+        })
+        .on("context_tags", |s, a| ok(s.context_tags(arg(a, "id")?)))
+    }
+}
+
+impl<S: Checkpointing> TraceReplayer<S> {
+    pub fn checkpoints(self) -> Self {
+        self.on("checkpoint", |s, _| ok(s.checkpoint()))
+            .on("restore_checkpoint", |s, a| {
+                ok(s.restore_checkpoint(arg(a, "id")?))
+            })
+            .on("list_checkpoints", |s, _| ok(s.list_checkpoints()))
+            .on("latest_checkpoint", |s, _| ok(s.latest_checkpoint()))
+    }
+}
+
+impl<S: ReceiptIntegration> TraceReplayer<S> {
+    /// `ReceiptIntegration` methods except streams and anchoring.
+    pub fn receipts(self) -> Self {
+        self.on("create_receipt", |s, a| {
+            ok(s.create_receipt(arg(a, "action")?))
+        })
+        .on("create_receipts", |s, a| {
+            ok(s.create_receipts(arg(a, "actions")?))
+        })
+        .on("get_receipt", |s, a| ok(s.get_receipt(arg(a, "id")?)))
+        .on("list_receipts", |s, a| {
+            ok(s.list_receipts(arg(a, "filter")?))
+        })
+        .on("receipt_count", |s, _| ok(s.receipt_count()))
+        .on("receipts_for_action", |s, a| {
+            ok(s.receipts_for_action(&arg::<String>(a, "action_type")?))
+        })
+        .on("prove", |s, a| ok(s.prove(arg(a, "id")?)))
+        .on("list_receipts_page", |s, a| {
+            ok(s.list_receipts_page(arg(a, "filter")?, arg(a, "cursor")?, arg(a, "page_size")?))
+        })
+        .on("compact_receipts", |s, a| {
+            ok(s.compact_receipts(arg(a, "before")?))
+        })
+        .on("compacted_segments", |s, _| ok(s.compacted_segments()))
+        .on("verify_chain", |s, _| ok(s.verify_chain()))
+        .on("export_receipts", |s, a| {
+            ok(s.export_receipts(arg(a, "filter")?, &arg::<PathBuf>(a, "path")?))
+        })
+    }
+}
+
+impl<S: Grounding> TraceReplayer<S> {
+    pub fn grounding(self) -> Self {
+        self.on("ground", |s, a| ok(s.ground(&arg::<String>(a, "claim")?)))
+            .on("evidence", |s, a| {
+                ok(s.evidence(&arg::<String>(a, "query")?, arg(a, "max_results")?))
+            })
+            .on("suggest", |s, a| {
+                ok(s.suggest(&arg::<String>(a, "query")?, arg(a, "limit")?))
+            })
+    }
+}
+
+impl<S: Queryable> TraceReplayer<S> {
+    pub fn queryable(self) -> Self {
+        self.on("query", |s, a| ok(s.query(arg(a, "query")?)))
+            .on("supports_query", |s, a| {
+                Ok(to_json(&s.supports_query(&arg::<String>(a, "query_type")?)))
+            })
+            .on("query_types", |s, _| Ok(to_json(&s.query_types())))
+            .on("search", |s, a| ok(s.search(&arg::<String>(a, "text")?)))
+            .on("recent", |s, a| ok(s.recent(arg(a, "count")?)))
+            .on("list", |s, a| {
+                ok(s.list(arg(a, "limit")?, arg(a, "offset")?))
+            })
+    }
+}
+
+/// Collect JSON pointers where `expected` and `actual` differ, skipping
+/// object fields named in `ignore`.
+fn diff_json(
+    expected: &Value,
+    actual: &Value,
+    ignore: &BTreeSet<String>,
+    path: String,
+    out: &mut Vec<String>,
+) {
+    match (expected, actual) {
+        (Value::Object(a), Value::Object(b)) => {
+            let keys: BTreeSet<&String> = a.keys().chain(b.keys()).collect();
+            for key in keys.into_iter().filter(|k| !ignore.contains(*k)) {
+                let null = Value::Null;
+                diff_json(
+                    a.get(key).unwrap_or(&null),
+                    b.get(key).unwrap_or(&null),
+                    ignore,
+                    format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1")),
+                    out,
+                );
+            }
+        }
+        (Value::Array(a), Value::Array(b)) if a.len() == b.len() => {
+            for (i, (x, y)) in a.iter().zip(b).enumerate() {
+                diff_json(x, y, ignore, format!("{}/{}", path, i), out);
+            }
+        }
+        (a, b) if a != b => out.push(if path.is_empty() { "/".into() } else { path }),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ErrorCode;
+    use std::time::Duration;
+
+    /// Grounds claims against a fixed fact list; queries echo.
+    struct Facts {
+        facts: Vec<&'static str>,
+    }
+
+    impl Sister for Facts {
+        const SISTER_TYPE: SisterType = SisterType::Memory;
+        const FILE_EXTENSION: &'static str = "amem";
+
+        fn init(_config: SisterConfig) -> SisterResult<Self> {
+            Ok(Self {
+                facts: vec!["the sky is blue"],
+            })
+        }
+
+        fn health(&self) -> HealthStatus {
+            HealthStatus::default()
+        }
+
+        fn version(&self) -> Version {
+            Version::new(0, 2, 0)
+        }
+
+        fn shutdown(&mut self) -> SisterResult<()> {
+            Ok(())
+        }
+
+        fn capabilities(&self) -> Vec<Capability> {
+            vec![]
+        }
+    }
+
+    impl Grounding for Facts {
+        fn ground(&self, claim: &str) -> SisterResult<GroundingResult> {
+            if claim.is_empty() {
+                return Err(SisterError::invalid_input("empty claim"));
+            }
+            Ok(if self.facts.contains(&claim) {
+                GroundingResult::verified(claim, 0.9)
+            } else {
+                GroundingResult::ungrounded(claim, "no such fact")
+            })
+        }
+
+        fn evidence(&self, _query: &str, _max: usize) -> SisterResult<Vec<EvidenceDetail>> {
+            Ok(vec![])
+        }
+
+        fn suggest(&self, _query: &str, _limit: usize) -> SisterResult<Vec<GroundingSuggestion>> {
+            Ok(vec![])
+        }
+    }
+
+    impl Queryable for Facts {
+        fn query(&self, query: Query) -> SisterResult<QueryResult> {
+            let results = self.facts.iter().map(|f| json!(f)).collect();
+            Ok(QueryResult::new(query, results, Duration::from_millis(3)))
+        }
+
+        fn supports_query(&self, _query_type: &str) -> bool {
+            true
+        }
+
+        fn query_types(&self) -> Vec<QueryTypeInfo> {
+            vec![]
+        }
+    }
+
+    fn record() -> Vec<TraceEntry> {
+        let facts = Facts::init(SisterConfig::stateless()).unwrap();
+        let recorder = TraceRecorder::new(facts, Vec::new());
+        recorder.ground("the sky is blue").unwrap();
+        recorder.ground("").unwrap_err();
+        recorder.query(Query::new("list")).unwrap();
+        recorder.health();
+        let (_, sink) = recorder.into_parts();
+        read_trace(sink.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_recorder_writes_jsonl() {
+        let entries = record();
+        let methods: Vec<_> = entries.iter().map(|e| e.method.as_str()).collect();
+        assert_eq!(methods, ["ground", "ground", "query", "health"]);
+        assert_eq!(entries[1].seq, 1);
+        assert_eq!(entries[0].args, json!({ "claim": "the sky is blue" }));
+        assert_eq!(entries[0].result.as_ref().unwrap()["status"], "verified");
+        assert_eq!(
+            entries[1].error.as_ref().unwrap().code,
+            ErrorCode::InvalidInput
+        );
+        assert_eq!(entries[2].args["query"]["query_type"], "list");
+
+        let err = read_trace("{}\n".as_bytes()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidInput);
+    }
+
+    #[test]
+    fn test_replay_diffs_changed_answers() {
+        let entries = record();
+        let replayer = TraceReplayer::new().grounding().queryable();
+
+        let mut same = Facts::init(SisterConfig::stateless()).unwrap();
+        let report = replayer.replay(&mut same, &entries);
+        assert!(report.is_clean(), "{:?}", report.diffs);
+        assert_eq!(report.replayed, 3);
+        assert_eq!(report.skipped, [3]);
+
+        let mut changed = Facts {
+            facts: vec!["the sky is green"],
+        };
+        let report = replayer.replay(&mut changed, &entries);
+        assert_eq!(report.diffs.len(), 2);
+        assert_eq!(report.diffs[0].method, "ground");
+        assert!(report.diffs[0].paths.contains(&"/ok/status".to_string()));
+        assert_eq!(report.diffs[1].paths, ["/ok/results/0"]);
+    }
+}
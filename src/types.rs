@@ -1,12 +1,16 @@
 //! Shared types used across all sisters.
 
+use crate::clock::Clock;
+use crate::errors::{ErrorCode, SisterError, SisterResult};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 
 /// All sister types in the ecosystem.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[serde(rename_all = "snake_case")]
 pub enum SisterType {
     // Foundation sisters
@@ -35,9 +39,44 @@ pub enum SisterType {
     Conscience,
     Meta,
     Duration,
+
+    /// Sister defined outside the SDK, obtained from `register_sister_type`.
+    /// Serialized as `{"extension": {"namespace": ..., "id": ...}}`; like
+    /// `from_byte`, deserializing only accepts registered extensions.
+    Extension(ExtensionType),
 }
 
 impl SisterType {
+    /// Every built-in sister type, in byte-identifier order.
+    pub const ALL: [SisterType; 21] = [
+        Self::Memory,
+        Self::Vision,
+        Self::Codebase,
+        Self::Identity,
+        Self::Time,
+        Self::Contract,
+        Self::Comm,
+        Self::Planning,
+        Self::Cognition,
+        Self::Reality,
+        Self::Attention,
+        Self::Affect,
+        Self::Motivation,
+        Self::Learning,
+        Self::Bond,
+        Self::Meaning,
+        Self::Wonder,
+        Self::Imagination,
+        Self::Conscience,
+        Self::Meta,
+        Self::Duration,
+    ];
+
+    /// Whether this is an extension (non-built-in) sister type.
+    pub fn is_extension(&self) -> bool {
+        matches!(self, Self::Extension { .. })
+    }
+
     /// Get the file extension for this sister type.
     pub fn file_extension(&self) -> &'static str {
         match self {
@@ -62,9 +101,23 @@ impl SisterType {
             Self::Conscience => "acons",
             Self::Meta => "ameta",
             Self::Duration => "adur",
+            Self::Extension(ext) => ext.definition().file_extension,
         }
     }
 
+    /// Get sister type from file extension (without dot).
+    pub fn from_file_extension(ext: &str) -> Option<Self> {
+        let ext = ext.trim_start_matches('.');
+        Self::ALL
+            .into_iter()
+            .chain(
+                registered_sister_types()
+                    .iter()
+                    .map(ExtensionSister::sister_type),
+            )
+            .find(|s| s.file_extension().eq_ignore_ascii_case(ext))
+    }
+
     /// Get the MCP tool prefix for this sister type.
     pub fn mcp_prefix(&self) -> &'static str {
         match self {
@@ -89,6 +142,7 @@ impl SisterType {
             Self::Conscience => "conscience",
             Self::Meta => "meta",
             Self::Duration => "duration",
+            Self::Extension(ext) => ext.definition().mcp_prefix,
         }
     }
 
@@ -116,10 +170,13 @@ impl SisterType {
             Self::Conscience => 0x13,
             Self::Meta => 0x14,
             Self::Duration => 0x15,
+            Self::Extension(ext) => ext.id,
         }
     }
 
     /// Get sister type from byte identifier.
+    ///
+    /// Bytes in `EXTENSION_ID_RANGE` resolve only once registered.
     pub fn from_byte(byte: u8) -> Option<Self> {
         match byte {
             0x01 => Some(Self::Memory),
@@ -143,7 +200,7 @@ impl SisterType {
             0x13 => Some(Self::Conscience),
             0x14 => Some(Self::Meta),
             0x15 => Some(Self::Duration),
-            _ => None,
+            _ => registered_sister(byte).map(|def| def.sister_type()),
         }
     }
 }
@@ -154,23 +211,380 @@ impl std::fmt::Display for SisterType {
     }
 }
 
+/// File-header bytes reserved for extension sisters.
+pub const EXTENSION_ID_RANGE: std::ops::RangeInclusive<u8> = 0x80..=0xFF;
+
+/// A registered extension sister, as held by `SisterType::Extension`.
+///
+/// Only the registry hands these out, so the id is always in
+/// `EXTENSION_ID_RANGE` and never collides with a built-in byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct ExtensionType {
+    /// Unique name, snake_case
+    namespace: &'static str,
+    /// File-header byte, in `EXTENSION_ID_RANGE`
+    id: u8,
+}
+
+impl ExtensionType {
+    /// Unique name, snake_case (e.g., "acme_notes").
+    pub fn namespace(&self) -> &'static str {
+        self.namespace
+    }
+
+    /// File-header byte.
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// The registered definition.
+    pub fn definition(&self) -> ExtensionSister {
+        registered_sister(self.id).unwrap_or_else(|| ExtensionSister::new(self.namespace, self.id))
+    }
+}
+
+/// Definition of a sister outside the built-in set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ExtensionSister {
+    /// Unique name, snake_case (e.g., "acme_notes")
+    pub namespace: &'static str,
+
+    /// File-header byte, in `EXTENSION_ID_RANGE`
+    pub id: u8,
+
+    /// File extension without dot (e.g., "anote")
+    pub file_extension: &'static str,
+
+    /// MCP tool prefix (e.g., "notes")
+    pub mcp_prefix: &'static str,
+}
+
+impl ExtensionSister {
+    /// Definition using `namespace` as both file extension and MCP prefix.
+    pub const fn new(namespace: &'static str, id: u8) -> Self {
+        Self {
+            namespace,
+            id,
+            file_extension: namespace,
+            mcp_prefix: namespace,
+        }
+    }
+
+    pub const fn file_extension(mut self, file_extension: &'static str) -> Self {
+        self.file_extension = file_extension;
+        self
+    }
+
+    pub const fn mcp_prefix(mut self, mcp_prefix: &'static str) -> Self {
+        self.mcp_prefix = mcp_prefix;
+        self
+    }
+
+    /// Only for definitions already in the registry.
+    fn sister_type(&self) -> SisterType {
+        SisterType::Extension(ExtensionType {
+            namespace: self.namespace,
+            id: self.id,
+        })
+    }
+}
+
+fn sister_registry() -> &'static std::sync::RwLock<Vec<ExtensionSister>> {
+    static REGISTRY: std::sync::OnceLock<std::sync::RwLock<Vec<ExtensionSister>>> =
+        std::sync::OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+fn registered_sister(id: u8) -> Option<ExtensionSister> {
+    sister_registry()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .find(|def| def.id == id)
+        .copied()
+}
+
+/// Register an extension sister so its byte, file extension, and MCP
+/// prefix resolve everywhere a built-in's would.
+///
+/// Registering the same definition twice is a no-op. Fails with
+/// `InvalidInput` for ids outside `EXTENSION_ID_RANGE` or a malformed
+/// namespace, and `AlreadyExists` when the id, namespace, file extension
+/// or MCP prefix is taken by a built-in or another extension.
+///
+/// ```rust,ignore
+/// const NOTES: ExtensionSister = ExtensionSister::new("acme_notes", 0x80).file_extension("anote");
+/// let sister_type = register_sister_type(NOTES)?;
+/// ```
+pub fn register_sister_type(definition: ExtensionSister) -> SisterResult<SisterType> {
+    let namespace = definition.namespace;
+    let well_formed = namespace.starts_with(|c: char| c.is_ascii_lowercase())
+        && namespace
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !well_formed {
+        return Err(SisterError::invalid_input(format!(
+            "Sister namespace {:?} must be snake_case",
+            namespace
+        )));
+    }
+    if !EXTENSION_ID_RANGE.contains(&definition.id) {
+        return Err(SisterError::invalid_input(format!(
+            "Extension sister id {:#04x} is outside {:#04x}..={:#04x}",
+            definition.id,
+            EXTENSION_ID_RANGE.start(),
+            EXTENSION_ID_RANGE.end()
+        ))
+        .with_context("id", definition.id));
+    }
+
+    let mut registry = sister_registry().write().unwrap_or_else(|e| e.into_inner());
+    if registry.contains(&definition) {
+        return Ok(definition.sister_type());
+    }
+    let taken = |what: &str, value: &dyn std::fmt::Display| {
+        SisterError::new(
+            ErrorCode::AlreadyExists,
+            format!("Sister {} {} is already in use", what, value),
+        )
+        .with_context("namespace", namespace)
+    };
+    let builtin = |f: fn(&SisterType) -> &'static str, value: &str| {
+        SisterType::ALL
+            .iter()
+            .any(|s| f(s).eq_ignore_ascii_case(value))
+    };
+    if builtin(SisterType::mcp_prefix, namespace)
+        || registry.iter().any(|d| d.namespace == namespace)
+    {
+        return Err(taken("namespace", &namespace));
+    }
+    if registry.iter().any(|d| d.id == definition.id) {
+        return Err(taken("id", &format_args!("{:#04x}", definition.id)));
+    }
+    if builtin(SisterType::file_extension, definition.file_extension)
+        || registry.iter().any(|d| {
+            d.file_extension
+                .eq_ignore_ascii_case(definition.file_extension)
+        })
+    {
+        return Err(taken("file extension", &definition.file_extension));
+    }
+    if builtin(SisterType::mcp_prefix, definition.mcp_prefix)
+        || registry
+            .iter()
+            .any(|d| d.mcp_prefix == definition.mcp_prefix)
+    {
+        return Err(taken("MCP prefix", &definition.mcp_prefix));
+    }
+    registry.push(definition);
+    Ok(definition.sister_type())
+}
+
+/// All registered extension sisters, by id.
+pub fn registered_sister_types() -> Vec<ExtensionSister> {
+    let mut defs = sister_registry()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    defs.sort_by_key(|d| d.id);
+    defs
+}
+
+impl<'de> Deserialize<'de> for SisterType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Mirrors `SisterType` variant for variant, so formats that
+        // encode enums by index (bincode, postcard) decode as they encode.
+        #[derive(Deserialize)]
+        #[serde(rename = "SisterType", rename_all = "snake_case")]
+        enum Wire {
+            Memory,
+            Vision,
+            Codebase,
+            Identity,
+            Time,
+            Contract,
+            Comm,
+            Planning,
+            Cognition,
+            Reality,
+            Attention,
+            Affect,
+            Motivation,
+            Learning,
+            Bond,
+            Meaning,
+            Wonder,
+            Imagination,
+            Conscience,
+            Meta,
+            Duration,
+            Extension(WireExtension),
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename = "ExtensionType")]
+        struct WireExtension {
+            namespace: String,
+            id: u8,
+        }
+
+        let (namespace, id) = match Wire::deserialize(deserializer)? {
+            Wire::Memory => return Ok(Self::Memory),
+            Wire::Vision => return Ok(Self::Vision),
+            Wire::Codebase => return Ok(Self::Codebase),
+            Wire::Identity => return Ok(Self::Identity),
+            Wire::Time => return Ok(Self::Time),
+            Wire::Contract => return Ok(Self::Contract),
+            Wire::Comm => return Ok(Self::Comm),
+            Wire::Planning => return Ok(Self::Planning),
+            Wire::Cognition => return Ok(Self::Cognition),
+            Wire::Reality => return Ok(Self::Reality),
+            Wire::Attention => return Ok(Self::Attention),
+            Wire::Affect => return Ok(Self::Affect),
+            Wire::Motivation => return Ok(Self::Motivation),
+            Wire::Learning => return Ok(Self::Learning),
+            Wire::Bond => return Ok(Self::Bond),
+            Wire::Meaning => return Ok(Self::Meaning),
+            Wire::Wonder => return Ok(Self::Wonder),
+            Wire::Imagination => return Ok(Self::Imagination),
+            Wire::Conscience => return Ok(Self::Conscience),
+            Wire::Meta => return Ok(Self::Meta),
+            Wire::Duration => return Ok(Self::Duration),
+            Wire::Extension(WireExtension { namespace, id }) => (namespace, id),
+        };
+        if !EXTENSION_ID_RANGE.contains(&id) {
+            return Err(serde::de::Error::custom(format!(
+                "extension sister id {:#04x} is outside {:#04x}..={:#04x}",
+                id,
+                EXTENSION_ID_RANGE.start(),
+                EXTENSION_ID_RANGE.end()
+            )));
+        }
+        // The namespace must come from the registry: `SisterType` is
+        // `Copy` and holds a `&'static str`, and interning whatever a
+        // peer sends would grow without bound.
+        registered_sister(id)
+            .filter(|def| def.namespace == namespace)
+            .map(|def| def.sister_type())
+            .ok_or_else(|| {
+                serde::de::Error::custom(format!(
+                    "unregistered extension sister {:?} ({:#04x})",
+                    namespace, id
+                ))
+            })
+    }
+}
+
 /// Semantic version.
+///
+/// Pre-release and build metadata follow semver: `0.2.0-rc.1 < 0.2.0`,
+/// and build metadata does not affect compatibility. Both are omitted
+/// from the wire format when empty, so plain versions serialize exactly
+/// as before. Binary file headers carry only major/minor/patch.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct Version {
     pub major: u8,
     pub minor: u8,
     pub patch: u8,
+
+    /// Pre-release identifiers (e.g. "rc.1"), empty for a release
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub pre: String,
+
+    /// Build metadata (e.g. "git.abc123"), ignored for precedence
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub build: String,
 }
 
 impl Version {
-    pub fn new(major: u8, minor: u8, patch: u8) -> Self {
+    pub const fn new(major: u8, minor: u8, patch: u8) -> Self {
         Self {
             major,
             minor,
             patch,
+            pre: String::new(),
+            build: String::new(),
         }
     }
 
+    /// Parse "MAJOR.MINOR.PATCH[-PRE][+BUILD]" (a leading `v` is allowed).
+    ///
+    /// Fails with `InvalidInput` for malformed versions and for
+    /// components above 255.
+    pub fn parse(text: &str) -> SisterResult<Self> {
+        let invalid = |reason: String| {
+            SisterError::invalid_input(format!("Invalid version {:?}: {}", text, reason))
+                .with_context("version", text)
+        };
+        let trimmed = text.trim();
+        let rest = trimmed.strip_prefix('v').unwrap_or(trimmed);
+        let (rest, build) = match rest.split_once('+') {
+            Some((rest, build)) => (rest, Some(build)),
+            None => (rest, None),
+        };
+        let (core, pre) = match rest.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (rest, None),
+        };
+
+        let mut parts = core.split('.');
+        let (Some(major), Some(minor), Some(patch), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid("expected MAJOR.MINOR.PATCH".into()));
+        };
+        let component = |name: &str, value: &str| {
+            if !is_numeric(value) || (value.len() > 1 && value.starts_with('0')) {
+                return Err(invalid(format!("invalid {} component {:?}", name, value)));
+            }
+            value
+                .parse::<u8>()
+                .map_err(|_| invalid(format!("{} component {} exceeds 255", name, value)))
+        };
+
+        let mut version = Self::new(
+            component("major", major)?,
+            component("minor", minor)?,
+            component("patch", patch)?,
+        );
+        if let Some(pre) = pre {
+            let valid = identifiers_valid(pre)
+                && pre
+                    .split('.')
+                    .all(|id| !(is_numeric(id) && id.len() > 1 && id.starts_with('0')));
+            if !valid {
+                return Err(invalid(format!("invalid pre-release {:?}", pre)));
+            }
+            version.pre = pre.to_string();
+        }
+        if let Some(build) = build {
+            if !identifiers_valid(build) {
+                return Err(invalid(format!("invalid build metadata {:?}", build)));
+            }
+            version.build = build.to_string();
+        }
+        Ok(version)
+    }
+
+    pub fn with_pre(mut self, pre: impl Into<String>) -> Self {
+        self.pre = pre.into();
+        self
+    }
+
+    pub fn with_build(mut self, build: impl Into<String>) -> Self {
+        self.build = build.into();
+        self
+    }
+
+    pub fn is_prerelease(&self) -> bool {
+        !self.pre.is_empty()
+    }
+
     /// Check if this version is compatible with another.
     /// Compatible means same major version.
     pub fn is_compatible_with(&self, other: &Version) -> bool {
@@ -186,32 +600,177 @@ impl Version {
 
 impl std::fmt::Display for Version {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if !self.pre.is_empty() {
+            write!(f, "-{}", self.pre)?;
+        }
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Version {
+    type Err = SisterError;
+
+    fn from_str(text: &str) -> SisterResult<Self> {
+        Self::parse(text)
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| cmp_prerelease(&self.pre, &other.pre))
+            .then_with(|| self.build.cmp(&other.build))
+    }
+}
+
+/// Semver precedence of pre-release strings: a release sorts after its
+/// pre-releases, numeric identifiers compare numerically and before
+/// alphanumeric ones, and a longer identifier list wins a tie.
+fn cmp_prerelease(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => {}
+    }
+    let (mut left, mut right) = (a.split('.'), b.split('.'));
+    loop {
+        let ordering = match (left.next(), right.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => match (is_numeric(x), is_numeric(y)) {
+                // Length first so numbers of any size compare correctly
+                (true, true) => (x.len(), x).cmp(&(y.len(), y)),
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => x.cmp(y),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+fn is_numeric(text: &str) -> bool {
+    !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Dot-separated, non-empty `[0-9A-Za-z-]` identifiers.
+fn identifiers_valid(text: &str) -> bool {
+    text.split('.')
+        .all(|id| !id.is_empty() && id.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-'))
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl From<(u8, u8, u8)> for Version {
     fn from((major, minor, patch): (u8, u8, u8)) -> Self {
-        Self {
-            major,
-            minor,
-            patch,
+        Self::new(major, minor, patch)
+    }
+}
+
+#[cfg(feature = "semver")]
+impl TryFrom<semver::Version> for Version {
+    type Error = SisterError;
+
+    fn try_from(v: semver::Version) -> SisterResult<Self> {
+        let component = |name: &str, value: u64| {
+            u8::try_from(value).map_err(|_| {
+                SisterError::invalid_input(format!("{} component {} exceeds 255", name, value))
+            })
+        };
+        Ok(Self {
+            major: component("major", v.major)?,
+            minor: component("minor", v.minor)?,
+            patch: component("patch", v.patch)?,
+            pre: v.pre.to_string(),
+            build: v.build.to_string(),
+        })
+    }
+}
+
+#[cfg(feature = "semver")]
+impl From<&Version> for semver::Version {
+    fn from(v: &Version) -> Self {
+        semver::Version {
+            major: v.major.into(),
+            minor: v.minor.into(),
+            patch: v.patch.into(),
+            pre: semver::Prerelease::new(&v.pre).unwrap_or(semver::Prerelease::EMPTY),
+            build: semver::BuildMetadata::new(&v.build).unwrap_or(semver::BuildMetadata::EMPTY),
         }
     }
 }
 
 /// Sister status.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[serde(rename_all = "snake_case")]
 pub enum Status {
     Starting,
     Ready,
     Busy,
     Degraded,
+    Paused,
     ShuttingDown,
     Error,
 }
 
+impl Status {
+    /// Default liveness for the status: false only for `Error`.
+    pub fn is_live(&self) -> bool {
+        !matches!(self, Self::Error)
+    }
+
+    /// Default readiness for the status.
+    ///
+    /// `Degraded` still serves (with reduced capability or speed), so
+    /// it is ready; `Starting` (warm-up), `Paused`, `ShuttingDown`, and
+    /// `Error` are not, and orchestrators should hold traffic.
+    pub fn is_ready(&self) -> bool {
+        matches!(self, Self::Ready | Self::Busy | Self::Degraded)
+    }
+
+    /// Whether a sister may move from this status to `next`.
+    ///
+    /// - `Starting` → `Ready`, `Degraded` (warm-up done)
+    /// - `Ready`, `Busy`, `Degraded`, `Paused` → each other, except
+    ///   `Paused` → `Busy` (resume to `Ready` first)
+    /// - `Error` → `Starting`, `Ready`, `Degraded` (restart or recovery)
+    /// - anything → `Error`, and anything but `Error` → `ShuttingDown`
+    /// - `ShuttingDown` → `Error` only
+    ///
+    /// Staying in the same status is not a transition.
+    pub fn can_transition_to(&self, next: Status) -> bool {
+        use Status::*;
+        match (*self, next) {
+            (from, to) if from == to => false,
+            (_, Error) => true,
+            (ShuttingDown, _) => false,
+            (_, ShuttingDown) => true,
+            (Starting, to) => matches!(to, Ready | Degraded),
+            (Error, to) => matches!(to, Starting | Ready | Degraded),
+            (_, Starting) => false,
+            (Paused, Busy) => false,
+            (Ready | Busy | Degraded | Paused, _) => true,
+        }
+    }
+}
+
 impl std::fmt::Display for Status {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -219,6 +778,7 @@ impl std::fmt::Display for Status {
             Self::Ready => write!(f, "ready"),
             Self::Busy => write!(f, "busy"),
             Self::Degraded => write!(f, "degraded"),
+            Self::Paused => write!(f, "paused"),
             Self::ShuttingDown => write!(f, "shutting_down"),
             Self::Error => write!(f, "error"),
         }
@@ -227,9 +787,20 @@ impl std::fmt::Display for Status {
 
 /// Capability that a sister provides.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct Capability {
     pub name: String,
     pub description: String,
+
+    /// Preference when several sisters offer this capability (higher wins)
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub priority: i32,
+
+    /// What this sister is best at for the capability (e.g. "code",
+    /// "conversation"); planners match these against task hints
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 impl Capability {
@@ -237,12 +808,34 @@ impl Capability {
         Self {
             name: name.into(),
             description: description.into(),
+            priority: 0,
+            tags: vec![],
         }
     }
+
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn is_zero(value: &i32) -> bool {
+    *value == 0
 }
 
 /// Resource usage metrics.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct ResourceUsage {
     pub memory_bytes: usize,
     pub disk_bytes: usize,
@@ -251,15 +844,29 @@ pub struct ResourceUsage {
 
 /// Health status returned by all sisters.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct HealthStatus {
     /// Is the sister operational?
     pub healthy: bool,
 
+    /// Liveness probe: the process is up and not wedged. A failing live
+    /// probe means restart it.
+    #[serde(default = "default_true")]
+    pub live: bool,
+
+    /// Readiness probe: it can take traffic now. Not ready while warming
+    /// up or paused; route elsewhere or wait, but do not restart.
+    #[serde(default = "default_true")]
+    pub ready: bool,
+
     /// Current status.
     pub status: Status,
 
     /// Time since initialization.
     #[serde(with = "duration_serde")]
+    #[cfg_attr(feature = "schema", schemars(with = "f64"))]
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
     pub uptime: std::time::Duration,
 
     /// Resource usage.
@@ -270,33 +877,555 @@ pub struct HealthStatus {
 
     /// Last error if any.
     pub last_error: Option<String>,
+
+    /// Per-subsystem health (storage, index, receipt store, ...)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<ComponentHealth>,
 }
 
 impl Default for HealthStatus {
     fn default() -> Self {
         Self {
             healthy: true,
+            live: true,
+            ready: true,
             status: Status::Ready,
             uptime: std::time::Duration::ZERO,
             resources: ResourceUsage::default(),
             warnings: vec![],
             last_error: None,
+            components: vec![],
+        }
+    }
+}
+
+impl HealthStatus {
+    /// Healthy status with probes set from `status` defaults.
+    pub fn with_status(status: Status) -> Self {
+        Self {
+            healthy: status != Status::Error,
+            live: status.is_live(),
+            ready: status.is_ready(),
+            status,
+            ..Default::default()
+        }
+    }
+
+    /// Add a component and fold it into the aggregate (see `aggregate`).
+    pub fn with_component(mut self, component: ComponentHealth) -> Self {
+        self.components.push(component);
+        self.aggregate()
+    }
+
+    pub fn component(&self, name: &str) -> Option<&ComponentHealth> {
+        self.components.iter().find(|c| c.name == name)
+    }
+
+    /// Worst component status (`None` without components).
+    pub fn worst_component(&self) -> Option<ComponentStatus> {
+        self.components.iter().map(|c| c.status).max()
+    }
+
+    /// Components that are not `Healthy`.
+    pub fn failing_components(&self) -> impl Iterator<Item = &ComponentHealth> {
+        self.components
+            .iter()
+            .filter(|c| c.status != ComponentStatus::Healthy)
+    }
+
+    /// Derive the overall status from the components.
+    ///
+    /// A `Degraded` or `Unhealthy` component turns `Ready`/`Busy` into
+    /// `Degraded` (the sister still serves what it can); an `Unhealthy`
+    /// one also clears `healthy`. Other statuses (`Starting`, `Paused`,
+    /// `Error`, ...) say more than the components and are kept. Probes
+    /// follow the resulting status.
+    pub fn aggregate(mut self) -> Self {
+        let Some(worst) = self.worst_component() else {
+            return self;
+        };
+        if worst > ComponentStatus::Healthy && matches!(self.status, Status::Ready | Status::Busy) {
+            self.status = Status::Degraded;
+            self.ready = self.status.is_ready();
+        }
+        if worst == ComponentStatus::Unhealthy {
+            self.healthy = false;
+        }
+        self
+    }
+}
+
+/// Health of one subsystem.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentStatus {
+    #[default]
+    Healthy,
+
+    /// Working, but slow or partially failing
+    Degraded,
+
+    /// Not working
+    Unhealthy,
+}
+
+impl std::fmt::Display for ComponentStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Healthy => write!(f, "healthy"),
+            Self::Degraded => write!(f, "degraded"),
+            Self::Unhealthy => write!(f, "unhealthy"),
+        }
+    }
+}
+
+/// One entry of `HealthStatus::components`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct ComponentHealth {
+    /// Subsystem name (e.g. "receipt_store")
+    pub name: String,
+    pub status: ComponentStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub last_checked: DateTime<Utc>,
+}
+
+impl ComponentHealth {
+    pub fn new(name: impl Into<String>, status: ComponentStatus) -> Self {
+        Self {
+            name: name.into(),
+            status,
+            message: None,
+            last_checked: Utc::now(),
         }
     }
+
+    pub fn healthy(name: impl Into<String>) -> Self {
+        Self::new(name, ComponentStatus::Healthy)
+    }
+
+    pub fn degraded(name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(name, ComponentStatus::Degraded).with_message(message)
+    }
+
+    pub fn unhealthy(name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(name, ComponentStatus::Unhealthy).with_message(message)
+    }
+
+    /// `Healthy` on `Ok`, `Unhealthy` with the error message on `Err`.
+    pub fn from_check<T>(name: impl Into<String>, result: &SisterResult<T>) -> Self {
+        match result {
+            Ok(_) => Self::healthy(name),
+            Err(e) => Self::unhealthy(name, e.message.clone()),
+        }
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn checked_at(mut self, at: DateTime<Utc>) -> Self {
+        self.last_checked = at;
+        self
+    }
 }
 
 /// Generic metadata map.
+///
+/// Prefer [`Meta`] in new code; it converts to and from this map.
 pub type Metadata = HashMap<String, serde_json::Value>;
 
+/// Most keys a `Meta` may hold.
+pub const META_MAX_KEYS: usize = 256;
+
+/// Largest serialized size of a single `Meta` value, in bytes.
+pub const META_MAX_VALUE_BYTES: usize = 64 * 1024;
+
+/// Separator between a key's namespace and its name (`"hydra.run_id"`).
+pub const META_NAMESPACE_SEPARATOR: char = '.';
+
+/// Typed wrapper around `Metadata`.
+///
+/// Reads go through typed accessors instead of matching on
+/// `serde_json::Value`; keys may be namespaced (`"hydra.run_id"`) so
+/// sisters and Hydra do not trample each other. Serializes exactly like
+/// the raw map.
+///
+/// ```rust,ignore
+/// let meta = Meta::new()
+///     .set_in("hydra", "run_id", run_id.to_string())
+///     .set("attempt", 2);
+/// let attempt = meta.get_i64("attempt").unwrap_or(1);
+/// ```
+///
+/// The builder `set` never fails; `insert` (and `validate` for maps
+/// received from elsewhere) enforce `META_MAX_KEYS` and
+/// `META_MAX_VALUE_BYTES`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Meta(Metadata);
+
+impl Meta {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `"namespace.key"`.
+    pub fn key(namespace: &str, key: &str) -> String {
+        format!("{}{}{}", namespace, META_NAMESPACE_SEPARATOR, key)
+    }
+
+    /// Set a value (builder style, unchecked).
+    pub fn set(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.0.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set a namespaced value (builder style, unchecked).
+    pub fn set_in(self, namespace: &str, key: &str, value: impl Into<serde_json::Value>) -> Self {
+        self.set(Self::key(namespace, key), value)
+    }
+
+    /// Insert a value, enforcing the size limits.
+    pub fn insert(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> SisterResult<Option<serde_json::Value>> {
+        let key = key.into();
+        let value = value.into();
+        if !self.0.contains_key(&key) && self.0.len() >= META_MAX_KEYS {
+            return Err(SisterError::invalid_input(format!(
+                "Metadata is full ({} keys)",
+                META_MAX_KEYS
+            ))
+            .with_context("key", &key));
+        }
+        check_meta_value(&key, &value)?;
+        Ok(self.0.insert(key, value))
+    }
+
+    /// Serialize `value` and insert it, enforcing the size limits.
+    pub fn insert_as<T: Serialize>(
+        &mut self,
+        key: impl Into<String>,
+        value: &T,
+    ) -> SisterResult<Option<serde_json::Value>> {
+        let value = serde_json::to_value(value)?;
+        self.insert(key, value)
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<serde_json::Value> {
+        self.0.remove(key)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
+        self.0.get(key)
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    /// String value; `None` if missing or not a string.
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(|v| v.as_str())
+    }
+
+    /// Integer value; `None` if missing or not an integer that fits.
+    pub fn get_i64(&self, key: &str) -> Option<i64> {
+        self.get(key).and_then(|v| v.as_i64())
+    }
+
+    pub fn get_f64(&self, key: &str) -> Option<f64> {
+        self.get(key).and_then(|v| v.as_f64())
+    }
+
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key).and_then(|v| v.as_bool())
+    }
+
+    /// Deserialize a value.
+    ///
+    /// `Ok(None)` if the key is missing; `InvalidInput` if it is present
+    /// but does not have the shape of `T`, so a wrong type is not
+    /// mistaken for absence.
+    pub fn get_as<T: serde::de::DeserializeOwned>(&self, key: &str) -> SisterResult<Option<T>> {
+        self.get(key)
+            .map(|v| {
+                T::deserialize(v).map_err(|e| {
+                    SisterError::invalid_input(format!("Metadata {:?}: {}", key, e))
+                        .with_context("key", key)
+                })
+            })
+            .transpose()
+    }
+
+    /// Entries under `namespace`, with the prefix stripped.
+    pub fn namespace<'a>(
+        &'a self,
+        namespace: &'a str,
+    ) -> impl Iterator<Item = (&'a str, &'a serde_json::Value)> {
+        self.0.iter().filter_map(move |(k, v)| {
+            k.strip_prefix(namespace)
+                .and_then(|rest| rest.strip_prefix(META_NAMESPACE_SEPARATOR))
+                .map(|name| (name, v))
+        })
+    }
+
+    /// Check the size limits (for maps built with `set` or received raw).
+    pub fn validate(&self) -> SisterResult<()> {
+        if self.0.len() > META_MAX_KEYS {
+            return Err(SisterError::invalid_input(format!(
+                "Metadata has {} keys (max {})",
+                self.0.len(),
+                META_MAX_KEYS
+            )));
+        }
+        self.0.iter().try_for_each(|(k, v)| check_meta_value(k, v))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &serde_json::Value)> {
+        self.0.iter()
+    }
+
+    pub fn as_map(&self) -> &Metadata {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> Metadata {
+        self.0
+    }
+}
+
+fn check_meta_value(key: &str, value: &serde_json::Value) -> SisterResult<()> {
+    let size = serde_json::to_vec(value).map_or(usize::MAX, |bytes| bytes.len());
+    if size > META_MAX_VALUE_BYTES {
+        return Err(SisterError::invalid_input(format!(
+            "Metadata value {:?} is {} bytes (max {})",
+            key, size, META_MAX_VALUE_BYTES
+        ))
+        .with_context("key", key));
+    }
+    Ok(())
+}
+
+impl From<Metadata> for Meta {
+    fn from(map: Metadata) -> Self {
+        Self(map)
+    }
+}
+
+impl From<Meta> for Metadata {
+    fn from(meta: Meta) -> Self {
+        meta.0
+    }
+}
+
+impl<K: Into<String>, V: Into<serde_json::Value>> FromIterator<(K, V)> for Meta {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        )
+    }
+}
+
+impl IntoIterator for Meta {
+    type Item = (String, serde_json::Value);
+    type IntoIter = std::collections::hash_map::IntoIter<String, serde_json::Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// What `SecretValue` shows instead of its contents.
+pub const REDACTED: &str = "***";
+
+/// A sensitive string (API key, token) that never shows up in logs.
+///
+/// `Debug`, `Display`, and `Serialize` all print `"***"`, so configs and
+/// snapshots containing it are safe to dump. Read the real value with
+/// `expose`; to persist it, opt in with
+/// `#[serde(serialize_with = "SecretValue::serialize_exposed")]`.
+/// Deserializes from a plain string, except the `"***"` placeholder
+/// itself: reading back a redacted dump is an error, not a secret.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct SecretValue(String);
+
+impl SecretValue {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// The secret itself. Keep it out of logs.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_exposed(self) -> String {
+        self.0
+    }
+
+    /// Serializer writing the real value, for `serialize_with`.
+    pub fn serialize_exposed<S: serde::Serializer>(
+        value: &SecretValue,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.0)
+    }
+}
+
+impl Serialize for SecretValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(REDACTED)
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretValue {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        if value == REDACTED {
+            return Err(serde::de::Error::custom(
+                "secret is the redacted placeholder; persist it with serialize_exposed",
+            ));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl std::fmt::Debug for SecretValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecretValue({:?})", REDACTED)
+    }
+}
+
+impl std::fmt::Display for SecretValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl From<String> for SecretValue {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for SecretValue {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+/// How `UniqueId::new` generates identifiers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum IdGenerator {
+    /// UUIDv4: fully random
+    #[default]
+    Random,
+
+    /// UUIDv7: millisecond timestamp prefix, so IDs sort by creation time
+    /// and index well
+    Sortable,
+}
+
+impl std::fmt::Display for IdGenerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Random => write!(f, "random"),
+            Self::Sortable => write!(f, "sortable"),
+        }
+    }
+}
+
+impl std::str::FromStr for IdGenerator {
+    type Err = SisterError;
+
+    fn from_str(text: &str) -> SisterResult<Self> {
+        match text.trim().to_lowercase().as_str() {
+            "random" | "v4" | "uuid_v4" => Ok(Self::Random),
+            "sortable" | "v7" | "uuid_v7" => Ok(Self::Sortable),
+            _ => Err(SisterError::invalid_input(format!(
+                "Unknown ID generator {:?} (expected \"random\" or \"sortable\")",
+                text
+            ))),
+        }
+    }
+}
+
+static ID_GENERATOR: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// Choose the process-wide generator used by `UniqueId::new`.
+///
+/// Existing IDs are unaffected; both kinds parse and compare alike.
+pub fn set_id_generator(generator: IdGenerator) {
+    ID_GENERATOR.store(
+        generator == IdGenerator::Sortable,
+        std::sync::atomic::Ordering::Relaxed,
+    );
+}
+
+/// The process-wide ID generator.
+pub fn id_generator() -> IdGenerator {
+    if ID_GENERATOR.load(std::sync::atomic::Ordering::Relaxed) {
+        IdGenerator::Sortable
+    } else {
+        IdGenerator::Random
+    }
+}
+
 /// Unique identifier (UUID-based).
+///
+/// Random (v4) by default; see `set_id_generator` for time-sortable (v7)
+/// IDs. Any UUID version parses and deserializes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct UniqueId(pub Uuid);
 
 impl UniqueId {
+    /// New ID from the process-wide generator.
     pub fn new() -> Self {
+        match id_generator() {
+            IdGenerator::Random => Self::new_random(),
+            IdGenerator::Sortable => Self::new_sortable(),
+        }
+    }
+
+    /// New random (UUIDv4) ID.
+    pub fn new_random() -> Self {
         Self(Uuid::new_v4())
     }
 
+    /// New time-ordered (UUIDv7) ID; later IDs from this process compare
+    /// greater.
+    pub fn new_sortable() -> Self {
+        Self(Uuid::now_v7())
+    }
+
     pub fn from_uuid(uuid: Uuid) -> Self {
         Self(uuid)
     }
@@ -304,6 +1433,28 @@ impl UniqueId {
     pub fn nil() -> Self {
         Self(Uuid::nil())
     }
+
+    /// Parse any UUID string (hyphenated, simple, braced, or URN).
+    pub fn parse(text: &str) -> SisterResult<Self> {
+        Uuid::parse_str(text.trim()).map(Self).map_err(|e| {
+            SisterError::invalid_input(format!("Invalid id {:?}: {}", text, e))
+                .with_context("id", text)
+        })
+    }
+
+    /// Whether this is a time-ordered (v7) ID.
+    pub fn is_sortable(&self) -> bool {
+        self.0.get_version_num() == 7
+    }
+
+    /// Creation time embedded in a sortable ID (millisecond precision).
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        if !self.is_sortable() {
+            return None;
+        }
+        let (secs, nanos) = self.0.get_timestamp()?.to_unix();
+        DateTime::from_timestamp(secs as i64, nanos)
+    }
 }
 
 impl Default for UniqueId {
@@ -324,6 +1475,14 @@ impl From<Uuid> for UniqueId {
     }
 }
 
+impl std::str::FromStr for UniqueId {
+    type Err = SisterError;
+
+    fn from_str(text: &str) -> SisterResult<Self> {
+        Self::parse(text)
+    }
+}
+
 /// Timestamp wrapper for consistency.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Timestamp(pub DateTime<Utc>);
@@ -333,6 +1492,10 @@ impl Timestamp {
         Self(Utc::now())
     }
 
+    pub fn now_with_clock(clock: &dyn Clock) -> Self {
+        Self(clock.now())
+    }
+
     pub fn from_datetime(dt: DateTime<Utc>) -> Self {
         Self(dt)
     }
@@ -389,6 +1552,79 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sister_type_from_extension() {
+        assert_eq!(
+            SisterType::from_file_extension("aid"),
+            Some(SisterType::Identity)
+        );
+        assert_eq!(
+            SisterType::from_file_extension(".acb"),
+            Some(SisterType::Codebase)
+        );
+        assert_eq!(SisterType::from_file_extension("txt"), None);
+    }
+
+    #[test]
+    fn test_extension_sister_type() {
+        const NOTES: ExtensionSister = ExtensionSister::new("acme_notes", 0xF0)
+            .file_extension("anote")
+            .mcp_prefix("notes");
+        // Unregistered: byte unknown
+        assert_eq!(SisterType::from_byte(0xF0), None);
+
+        let notes = register_sister_type(NOTES).unwrap();
+        assert!(notes.is_extension());
+        assert_eq!(notes.to_byte(), 0xF0);
+        assert_eq!(notes.file_extension(), "anote");
+        assert_eq!(register_sister_type(NOTES).unwrap(), notes);
+        assert_eq!(SisterType::from_byte(0xF0), Some(notes));
+        assert_eq!(SisterType::from_file_extension("anote"), Some(notes));
+        assert_eq!(notes.to_string(), "notes");
+
+        let json = serde_json::to_value(notes).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"extension": {"namespace": "acme_notes", "id": 240}})
+        );
+        assert_eq!(serde_json::from_value::<SisterType>(json).unwrap(), notes);
+        for bad in [
+            // Built-in header byte
+            serde_json::json!({"extension": {"namespace": "evil", "id": 1}}),
+            // Registered id, different namespace
+            serde_json::json!({"extension": {"namespace": "evil", "id": 240}}),
+            // Unregistered
+            serde_json::json!({"extension": {"namespace": "acme_other", "id": 241}}),
+        ] {
+            assert!(serde_json::from_value::<SisterType>(bad).is_err());
+        }
+        assert_eq!(serde_json::to_value(SisterType::Memory).unwrap(), "memory");
+        assert_eq!(
+            serde_json::from_str::<SisterType>("\"comm\"").unwrap(),
+            SisterType::Comm
+        );
+
+        // Formats that encode variants by index (bincode, postcard) decode too
+        use serde::de::IntoDeserializer;
+        let by_index = |index: u32| {
+            SisterType::deserialize(
+                IntoDeserializer::<serde::de::value::Error>::into_deserializer(index),
+            )
+        };
+        assert_eq!(by_index(0).unwrap(), SisterType::Memory);
+        assert_eq!(by_index(20).unwrap(), SisterType::Duration);
+        assert!(by_index(22).is_err());
+
+        let err = register_sister_type(ExtensionSister::new("acme_tasks", 0x10)).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidInput);
+        let err =
+            register_sister_type(ExtensionSister::new("acme_tasks", 0xF1).file_extension("amem"))
+                .unwrap_err();
+        assert_eq!(err.code, ErrorCode::AlreadyExists);
+        let err = register_sister_type(ExtensionSister::new("other_notes", 0xF0)).unwrap_err();
+        assert_eq!(err.code, ErrorCode::AlreadyExists);
+    }
+
     #[test]
     fn test_version_compatibility() {
         let v1 = Version::new(1, 0, 0);
@@ -400,4 +1636,242 @@ mod tests {
         assert!(v2.can_read(&v1));
         assert!(!v1.can_read(&v2));
     }
+
+    #[test]
+    fn test_version_semver() {
+        let rc: Version = "v0.2.0-rc.1+git.abc".parse().unwrap();
+        assert_eq!(
+            rc,
+            Version::new(0, 2, 0).with_pre("rc.1").with_build("git.abc")
+        );
+        assert_eq!(rc.to_string(), "0.2.0-rc.1+git.abc");
+        assert!(rc < Version::new(0, 2, 0));
+        assert!(Version::parse("0.2.0-rc.2").unwrap() > Version::parse("0.2.0-rc.1").unwrap());
+        assert!(Version::parse("0.2.0-rc.10").unwrap() > Version::parse("0.2.0-rc.9").unwrap());
+        assert!(Version::new(0, 1, 9) < rc);
+
+        assert_eq!(
+            Version::parse("1.300.0").unwrap_err().code,
+            ErrorCode::InvalidInput
+        );
+        assert!(Version::parse("1.2").is_err());
+        for bad in [
+            "01.2.3",
+            "1.2.3-",
+            "1.2.3-rc..1",
+            "1.2.3-01",
+            "1.2.3+",
+            "1.2.3+a_b",
+            "vv1.0.0",
+        ] {
+            assert!(Version::parse(bad).is_err(), "{bad}");
+        }
+        let mut ordered: Vec<Version> = [
+            "1.0.0",
+            "1.0.0-rc.1",
+            "1.0.0-beta.11",
+            "1.0.0-beta.2",
+            "1.0.0-beta",
+            "1.0.0-alpha.beta",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha",
+        ]
+        .iter()
+        .map(|v| v.parse().unwrap())
+        .collect();
+        ordered.sort();
+        let sorted: Vec<String> = ordered.iter().map(Version::to_string).collect();
+        assert_eq!(
+            sorted,
+            [
+                "1.0.0-alpha",
+                "1.0.0-alpha.1",
+                "1.0.0-alpha.beta",
+                "1.0.0-beta",
+                "1.0.0-beta.2",
+                "1.0.0-beta.11",
+                "1.0.0-rc.1",
+                "1.0.0",
+            ]
+        );
+
+        // Plain versions keep the original wire format
+        let json = serde_json::to_value(Version::new(0, 2, 0)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"major": 0, "minor": 2, "patch": 0})
+        );
+        let back: Version = serde_json::from_value(serde_json::to_value(&rc).unwrap()).unwrap();
+        assert_eq!(back, rc);
+    }
+
+    #[test]
+    fn test_health_probes() {
+        let starting = HealthStatus::with_status(Status::Starting);
+        assert!(starting.live && !starting.ready);
+        let degraded = HealthStatus::with_status(Status::Degraded);
+        assert!(degraded.live && degraded.ready);
+        assert!(!HealthStatus::with_status(Status::Error).live);
+
+        // Payloads from before the probes existed still parse
+        let json = serde_json::json!({
+            "healthy": true,
+            "status": "ready",
+            "uptime": 0,
+            "resources": {"memory_bytes": 0, "disk_bytes": 0, "open_handles": 0},
+            "warnings": [],
+            "last_error": null
+        });
+        let parsed: HealthStatus = serde_json::from_value(json).unwrap();
+        assert!(parsed.live && parsed.ready);
+    }
+
+    #[test]
+    fn test_secret_value_redacted() {
+        let secret = SecretValue::new("sk-live-123");
+        assert_eq!(format!("{:?}", secret), "SecretValue(\"***\")");
+        assert_eq!(secret.to_string(), REDACTED);
+        assert_eq!(serde_json::to_value(&secret).unwrap(), REDACTED);
+        assert_eq!(secret.expose(), "sk-live-123");
+
+        #[derive(Serialize)]
+        struct Persisted {
+            #[serde(serialize_with = "SecretValue::serialize_exposed")]
+            key: SecretValue,
+        }
+        let json = serde_json::to_value(Persisted { key: secret }).unwrap();
+        assert_eq!(json["key"], "sk-live-123");
+        let parsed: SecretValue = serde_json::from_value(json["key"].clone()).unwrap();
+        assert_eq!(parsed.expose(), "sk-live-123");
+
+        let redacted = serde_json::to_value(SecretValue::new("sk-live-123")).unwrap();
+        assert!(serde_json::from_value::<SecretValue>(redacted).is_err());
+    }
+
+    #[test]
+    fn test_meta_typed_access() {
+        let mut meta = Meta::new()
+            .set_in("hydra", "run_id", "run-7")
+            .set("attempt", 2)
+            .set("dry_run", true)
+            .set("tags", serde_json::json!(["a", "b"]));
+        assert_eq!(meta.get_str(&Meta::key("hydra", "run_id")), Some("run-7"));
+        assert_eq!(meta.get_i64("attempt"), Some(2));
+        assert_eq!(meta.get_str("attempt"), None);
+        assert_eq!(meta.get_bool("dry_run"), Some(true));
+        assert_eq!(
+            meta.get_as::<Vec<String>>("tags").unwrap(),
+            Some(vec!["a".to_string(), "b".to_string()])
+        );
+        assert_eq!(meta.get_as::<u32>("missing").unwrap(), None);
+        assert!(meta.get_as::<u32>("tags").is_err());
+        assert_eq!(
+            meta.namespace("hydra").collect::<Vec<_>>(),
+            vec![("run_id", &serde_json::json!("run-7"))]
+        );
+
+        let big = "x".repeat(META_MAX_VALUE_BYTES);
+        assert_eq!(
+            meta.insert("blob", big).unwrap_err().code,
+            ErrorCode::InvalidInput
+        );
+        assert!(meta.validate().is_ok());
+
+        // Same wire format as the raw map, both ways
+        let raw: Metadata = meta.clone().into();
+        assert_eq!(
+            serde_json::to_value(&meta).unwrap(),
+            serde_json::to_value(&raw).unwrap()
+        );
+        assert_eq!(Meta::from(raw), meta);
+    }
+
+    #[test]
+    fn test_sortable_ids() {
+        let ids: Vec<UniqueId> = (0..100).map(|_| UniqueId::new_sortable()).collect();
+        assert!(ids.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(ids[0].is_sortable());
+        let age = Utc::now() - ids[0].created_at().unwrap();
+        assert!(age < chrono::Duration::seconds(5));
+
+        let random = UniqueId::new_random();
+        assert!(!random.is_sortable());
+        assert_eq!(random.created_at(), None);
+
+        // Existing v4 IDs keep parsing, in every accepted form
+        let text = random.to_string();
+        assert_eq!(text.parse::<UniqueId>().unwrap(), random);
+        assert_eq!(
+            UniqueId::parse(&random.0.simple().to_string()).unwrap(),
+            random
+        );
+        let json = serde_json::to_string(&random).unwrap();
+        assert_eq!(serde_json::from_str::<UniqueId>(&json).unwrap(), random);
+        assert!(UniqueId::parse("not-an-id").is_err());
+
+        assert_eq!("v7".parse::<IdGenerator>().unwrap(), IdGenerator::Sortable);
+        assert!("snowflake".parse::<IdGenerator>().is_err());
+    }
+
+    #[test]
+    fn test_status_transition_graph() {
+        use Status::*;
+        let all = [Starting, Ready, Busy, Degraded, Paused, ShuttingDown, Error];
+        let allowed: &[(Status, &[Status])] = &[
+            (Starting, &[Ready, Degraded, ShuttingDown, Error]),
+            (Ready, &[Busy, Degraded, Paused, ShuttingDown, Error]),
+            (Busy, &[Ready, Degraded, Paused, ShuttingDown, Error]),
+            (Degraded, &[Ready, Busy, Paused, ShuttingDown, Error]),
+            (Paused, &[Ready, Degraded, ShuttingDown, Error]),
+            (ShuttingDown, &[Error]),
+            (Error, &[Starting, Ready, Degraded, ShuttingDown]),
+        ];
+        for (from, targets) in allowed {
+            for to in all {
+                assert_eq!(
+                    from.can_transition_to(to),
+                    targets.contains(&to),
+                    "{} -> {}",
+                    from,
+                    to
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_component_health_aggregate() {
+        let health = HealthStatus::with_status(Status::Ready)
+            .with_component(ComponentHealth::healthy("index"))
+            .with_component(ComponentHealth::degraded("receipt_store", "slow fsync"));
+        assert_eq!(health.status, Status::Degraded);
+        assert!(health.healthy && health.ready);
+        assert_eq!(health.worst_component(), Some(ComponentStatus::Degraded));
+
+        let failing = health.with_component(ComponentHealth::from_check::<()>(
+            "storage",
+            &Err(SisterError::storage("disk full")),
+        ));
+        assert!(!failing.healthy);
+        assert_eq!(
+            failing
+                .failing_components()
+                .map(|c| c.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["receipt_store", "storage"]
+        );
+        assert_eq!(
+            failing.component("storage").unwrap().message.as_deref(),
+            Some("disk full")
+        );
+
+        // Paused says more than the components
+        let paused = HealthStatus::with_status(Status::Paused)
+            .with_component(ComponentHealth::unhealthy("index", "rebuilding"));
+        assert_eq!(paused.status, Status::Paused);
+
+        // No components: unchanged wire format
+        let json = serde_json::to_value(HealthStatus::default()).unwrap();
+        assert!(json.get("components").is_none());
+    }
 }
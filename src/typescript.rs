@@ -0,0 +1,124 @@
+//! TypeScript declarations for the wire DTOs (`typescript` feature).
+//!
+//! The Hydra UI imports the generated `bindings/agentic-sdk.d.ts` instead
+//! of hand-maintaining interfaces. Declarations follow the serde
+//! attributes, so they describe the JSON the sisters actually send. A
+//! test fails whenever the committed file is stale; regenerate it with
+//! `UPDATE_GOLDEN=1 cargo test --features typescript`.
+
+use std::any::TypeId;
+use std::collections::{BTreeMap, HashSet};
+use ts_rs::{TypeVisitor, TS};
+
+/// Header of the generated file.
+pub const HEADER: &str = "// Generated by agentic-sdk (`typescript` feature). Do not edit.\n";
+
+/// Every published DTO and the types they reference, as one `.d.ts`.
+///
+/// Declarations are sorted by name. 64-bit integers are declared as
+/// `number` (ts-rs says `bigint`), since that is what `JSON.parse` yields.
+pub fn declarations() -> String {
+    use crate::codec::Codec;
+    use crate::context::{ContextInfo, ContextSnapshot, SnapshotDelta};
+    use crate::diagnostics::DiagnosticReport;
+    use crate::errors::{ProtocolError, SisterError};
+    use crate::events::SisterEvent;
+    use crate::grounding::{EvidenceDetail, GroundingResult, GroundingSuggestion};
+    use crate::hydra::{CommandResult, DiscoveryDocument, HydraCommand};
+    use crate::query::{Query, QueryResult};
+    use crate::receipts::{ActionRecord, Receipt, ReceiptProof};
+    use crate::sister::{SisterConfig, SisterInfo};
+    use crate::types::{Capability, HealthStatus, SisterType, Version};
+
+    let mut collector = Collector::default();
+    collector.visit::<ActionRecord>();
+    collector.visit::<Capability>();
+    collector.visit::<Codec>();
+    collector.visit::<CommandResult>();
+    collector.visit::<ContextInfo>();
+    collector.visit::<ContextSnapshot>();
+    collector.visit::<DiagnosticReport>();
+    collector.visit::<DiscoveryDocument>();
+    collector.visit::<EvidenceDetail>();
+    collector.visit::<GroundingResult>();
+    collector.visit::<GroundingSuggestion>();
+    collector.visit::<HealthStatus>();
+    collector.visit::<HydraCommand>();
+    collector.visit::<ProtocolError>();
+    collector.visit::<Query>();
+    collector.visit::<QueryResult>();
+    collector.visit::<Receipt>();
+    collector.visit::<ReceiptProof>();
+    collector.visit::<SisterConfig>();
+    collector.visit::<SisterError>();
+    collector.visit::<SisterEvent>();
+    collector.visit::<SisterInfo>();
+    collector.visit::<SisterType>();
+    collector.visit::<SnapshotDelta>();
+    collector.visit::<Version>();
+
+    let mut out = String::from(HEADER);
+    for decl in collector.decls.values() {
+        out.push('\n');
+        out.push_str(decl);
+        out.push('\n');
+    }
+    out
+}
+
+/// Walks dependencies, keeping one declaration per named type.
+#[derive(Default)]
+struct Collector {
+    seen: HashSet<TypeId>,
+    decls: BTreeMap<String, String>,
+}
+
+impl TypeVisitor for Collector {
+    fn visit<T: TS + 'static + ?Sized>(&mut self) {
+        if !self.seen.insert(TypeId::of::<T>()) {
+            return;
+        }
+        // Only declared (derived) types have an output path; primitives
+        // and containers are inlined.
+        if T::output_path().is_some() {
+            let mut decl = T::docs().unwrap_or_default();
+            decl.push_str("export ");
+            decl.push_str(&T::decl().replace("bigint", "number"));
+            self.decls.insert(T::ident(), decl);
+        }
+        T::visit_dependencies(self);
+        T::visit_generics(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GOLDEN: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/bindings/agentic-sdk.d.ts");
+
+    /// Fails when a DTO changed without regenerating the bindings.
+    #[test]
+    fn test_bindings_are_current() {
+        let actual = declarations();
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            std::fs::create_dir_all(std::path::Path::new(GOLDEN).parent().unwrap()).unwrap();
+            std::fs::write(GOLDEN, &actual).unwrap();
+            return;
+        }
+        let expected = std::fs::read_to_string(GOLDEN).unwrap_or_default();
+        assert!(
+            actual == expected,
+            "bindings/agentic-sdk.d.ts is stale; rerun with UPDATE_GOLDEN=1"
+        );
+    }
+
+    #[test]
+    fn test_declarations_follow_serde() {
+        let out = declarations();
+        assert!(out.contains("export type ErrorCode = string;"));
+        assert!(out.contains("export type JsonValue ="));
+        assert!(!out.contains("bigint"));
+        assert!(out.contains("export type Codec = \"json\" | \"msgpack\" | \"cbor\";"));
+    }
+}
@@ -0,0 +1,226 @@
+//! Compatibility corpus: serialized samples from every release.
+//!
+//! `tests/fixtures/compat/<version>/` holds what that release wrote:
+//! a snapshot, a receipt and an event as JSON, plus the binary forms it
+//! supported (an `.actr` container, MessagePack and CBOR snapshots).
+//! Every fixture must still load with this SDK; one that stops loading
+//! is a breaking change, not a fixture to delete.
+//!
+//! Releasing a new version records its fixtures:
+//!
+//! ```text
+//! COMPAT_RECORD=1 cargo test --all-features --test compat
+//! ```
+//!
+//! Recording only adds missing files, so fixtures from earlier releases
+//! are never rewritten.
+
+#![cfg(all(feature = "hashing", feature = "file-io"))]
+
+use agentic_sdk::file_format::{FileFormatWriter, CONTAINER_MAGIC};
+use agentic_sdk::prelude::*;
+use chrono::{DateTime, TimeZone, Utc};
+use std::path::{Path, PathBuf};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/compat");
+
+fn fixture_dirs() -> Vec<PathBuf> {
+    let mut dirs: Vec<_> = std::fs::read_dir(FIXTURES)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn uuid(n: u128) -> UniqueId {
+    UniqueId::from_uuid(uuid::Uuid::from_u128(n))
+}
+
+fn at(secs: i64) -> DateTime<Utc> {
+    Utc.timestamp_opt(secs, 0).unwrap()
+}
+
+/// The samples recorded for the current version. Fixed IDs and times,
+/// so recording twice produces the same bytes.
+fn samples() -> Vec<(&'static str, Vec<u8>)> {
+    let clock = MockClock::new(at(1_735_689_600));
+    let context_id = ContextId(uuid(1));
+    let info = ContextInfo {
+        id: context_id,
+        name: "main".into(),
+        created_at: clock.now(),
+        updated_at: clock.now(),
+        item_count: 3,
+        size_bytes: 64,
+        tags: vec!["compat".into()],
+        metadata: Metadata::new(),
+        parent_id: None,
+        child_ids: Vec::new(),
+        quota: None,
+    };
+    let data: Vec<u8> = (0..64u8).collect();
+    let snapshot = ContextSnapshot::new_with_clock(
+        SisterType::Memory,
+        Version::new(0, 2, 0),
+        info,
+        data.clone(),
+        &clock,
+    );
+
+    let mut action = ActionRecord::new_with_clock(
+        SisterType::Contract,
+        "approve",
+        ActionOutcome::Success {
+            result: Some(serde_json::json!({"approved": true})),
+        },
+        &clock,
+    );
+    action.context_id = Some(context_id);
+    action.evidence_ids = vec!["node:42".into()];
+    action.run_id = Some("run-1".into());
+    action.step_id = Some(7);
+    let receipt = Receipt {
+        id: ReceiptId(uuid(2)),
+        action,
+        signature: "00".repeat(64),
+        chain_position: 1,
+        previous_hash: "00".repeat(32),
+        hash: "11".repeat(32),
+        created_at: clock.now(),
+        anchor: Some(ReceiptAnchor {
+            provider: "rfc3161".into(),
+            anchored_hash: "11".repeat(32),
+            chain_position: 1,
+            anchored_at: at(1_735_689_660),
+            proof: "cHJvb2Y=".into(),
+            metadata: Metadata::new(),
+        }),
+    };
+
+    let mut event = SisterEvent::new_with_clock(
+        SisterType::Memory,
+        EventType::ContextSwitched {
+            from: ContextId(uuid(4)),
+            to: context_id,
+        },
+        &clock,
+    );
+    event.id = EventId(uuid(3));
+    event.context_id = Some(context_id);
+
+    let mut container = ContainerFile::new(SisterType::Memory, Version::new(0, 2, 0), data);
+    container.created_at = clock.now();
+    container.updated_at = clock.now();
+
+    let mut out = vec![
+        (
+            "snapshot.json",
+            serde_json::to_vec_pretty(&snapshot).unwrap(),
+        ),
+        ("receipt.json", serde_json::to_vec_pretty(&receipt).unwrap()),
+        ("event.json", serde_json::to_vec_pretty(&event).unwrap()),
+        ("container.actr", container.to_bytes().unwrap()),
+    ];
+    for codec in Codec::available().into_iter().skip(1) {
+        let name = match codec {
+            Codec::MsgPack => "snapshot.msgpack",
+            _ => "snapshot.cbor",
+        };
+        out.push((name, snapshot.encode(codec).unwrap()));
+    }
+    out
+}
+
+/// `json` parses as `T` and serializes back to the same value, so
+/// hashes and signatures over it still check out.
+fn assert_lossless<T: serde::Serialize + serde::de::DeserializeOwned>(path: &Path, json: &[u8]) {
+    let parsed: T = serde_json::from_slice(json)
+        .unwrap_or_else(|e| panic!("{} no longer deserializes: {e}", path.display()));
+    let original: serde_json::Value = serde_json::from_slice(json).unwrap();
+    assert_eq!(
+        serde_json::to_value(&parsed).unwrap(),
+        original,
+        "{} does not round-trip",
+        path.display()
+    );
+}
+
+fn check_fixture(path: &Path) {
+    let bytes = std::fs::read(path).unwrap();
+    let name = path.file_name().unwrap().to_str().unwrap();
+    let codec = match path.extension().and_then(|e| e.to_str()) {
+        Some("msgpack") => Codec::MsgPack,
+        Some("cbor") => Codec::Cbor,
+        _ => Codec::Json,
+    };
+    if !codec.is_available() {
+        return;
+    }
+
+    match name {
+        "snapshot.json" => {
+            let snapshot = migrate_snapshot(&bytes)
+                .unwrap_or_else(|e| panic!("{} no longer migrates: {e}", path.display()));
+            assert!(
+                snapshot.verify().unwrap(),
+                "{} fails its checksum",
+                path.display()
+            );
+            assert!(!snapshot.needs_migration());
+        }
+        "snapshot.msgpack" | "snapshot.cbor" => {
+            let snapshot = ContextSnapshot::decode(codec, &bytes)
+                .unwrap_or_else(|e| panic!("{} no longer decodes: {e}", path.display()));
+            assert!(
+                snapshot.verify().unwrap(),
+                "{} fails its checksum",
+                path.display()
+            );
+        }
+        "receipt.json" => assert_lossless::<Receipt>(path, &bytes),
+        "event.json" => assert_lossless::<SisterEvent>(path, &bytes),
+        "container.actr" => {
+            let container = ContainerFile::from_bytes(&bytes)
+                .unwrap_or_else(|e| panic!("{} no longer parses: {e}", path.display()));
+            assert_eq!(container.magic, CONTAINER_MAGIC);
+            assert_eq!(container.to_bytes().unwrap(), bytes);
+        }
+        _ => panic!("unknown fixture kind: {}", path.display()),
+    }
+}
+
+#[test]
+fn test_fixtures_still_load() {
+    let mut checked = 0;
+    for dir in fixture_dirs() {
+        for entry in std::fs::read_dir(&dir).unwrap() {
+            check_fixture(&entry.unwrap().path());
+            checked += 1;
+        }
+    }
+    assert!(checked > 0, "no fixtures under {FIXTURES}");
+}
+
+/// Bumping the crate version fails here until its fixtures are recorded.
+#[test]
+fn test_current_release_has_fixtures() {
+    let current = Path::new(FIXTURES).join(env!("CARGO_PKG_VERSION"));
+    if std::env::var_os("COMPAT_RECORD").is_some() {
+        std::fs::create_dir_all(&current).unwrap();
+        for (name, bytes) in samples() {
+            let path = current.join(name);
+            if !path.exists() {
+                std::fs::write(path, bytes).unwrap();
+            }
+        }
+    }
+    for (name, _) in samples() {
+        assert!(
+            current.join(name).exists(),
+            "missing compat fixture {}/{name}; rerun with COMPAT_RECORD=1",
+            env!("CARGO_PKG_VERSION")
+        );
+    }
+}
@@ -1,1330 +1,14 @@
-//! Mock sister implementations validating v0.2.0 contracts.
+//! Contract tests against the mock sisters in `agentic_sdk::mocks`.
 //!
-//! These mocks prove that every trait in agentic-sdk
-//! can be implemented by real sisters. Each mock mirrors
-//! the actual pattern used by that sister type.
-//!
-//! Pattern coverage:
-//! - MockMemory:   Sister + SessionManagement + Grounding + Queryable + EventEmitter
-//! - MockCodebase: Sister + WorkspaceManagement + Grounding + Queryable
-//! - MockIdentity: Sister + SessionManagement + Grounding + ReceiptIntegration
-//! - MockTime:     Sister only (stateless — no sessions, no grounding)
-//! - MockContract: Sister + SessionManagement + Grounding + Queryable + ReceiptIntegration + EventEmitter
+//! These prove that every trait in agentic-sdk can be implemented by
+//! real sisters: each mock mirrors the pattern used by that sister type.
+//! Run with `cargo test --features mocks` (`required-features` in
+//! Cargo.toml).
 
+use agentic_sdk::mocks::*;
 use agentic_sdk::prelude::*;
 use chrono::Utc;
 use std::collections::HashMap;
-use std::sync::Mutex;
-use std::time::Instant;
-
-// ═══════════════════════════════════════════════════════════════════
-// MOCK MEMORY — Session-based sister with grounding
-// ═══════════════════════════════════════════════════════════════════
-
-struct MockMemory {
-    start_time: Instant,
-    session_id: Mutex<Option<ContextId>>,
-    sessions: Mutex<Vec<ContextSummary>>,
-    events: EventManager,
-    nodes: Mutex<Vec<(u64, String)>>, // (id, content)
-    next_id: Mutex<u64>,
-}
-
-impl MockMemory {
-    fn new(_config: SisterConfig) -> SisterResult<Self> {
-        Ok(Self {
-            start_time: Instant::now(),
-            session_id: Mutex::new(None),
-            sessions: Mutex::new(vec![]),
-            events: EventManager::new(256),
-            nodes: Mutex::new(vec![]),
-            next_id: Mutex::new(1),
-        })
-    }
-
-    fn add_node(&self, content: &str) -> u64 {
-        let mut nodes = self.nodes.lock().unwrap();
-        let mut next = self.next_id.lock().unwrap();
-        let id = *next;
-        *next += 1;
-        nodes.push((id, content.to_string()));
-        id
-    }
-}
-
-impl Sister for MockMemory {
-    const SISTER_TYPE: SisterType = SisterType::Memory;
-    const FILE_EXTENSION: &'static str = "amem";
-
-    fn init(config: SisterConfig) -> SisterResult<Self>
-    where
-        Self: Sized,
-    {
-        MockMemory::new(config)
-    }
-
-    fn health(&self) -> HealthStatus {
-        HealthStatus {
-            healthy: true,
-            status: Status::Ready,
-            uptime: self.start_time.elapsed(),
-            resources: ResourceUsage::default(),
-            warnings: vec![],
-            last_error: None,
-        }
-    }
-
-    fn version(&self) -> Version {
-        Version::new(0, 2, 0)
-    }
-
-    fn shutdown(&mut self) -> SisterResult<()> {
-        self.events
-            .emit(SisterEvent::shutting_down(SisterType::Memory));
-        Ok(())
-    }
-
-    fn capabilities(&self) -> Vec<Capability> {
-        vec![
-            Capability::new("memory_add", "Add cognitive events to graph"),
-            Capability::new("memory_query", "Query memory by filters"),
-            Capability::new("memory_ground", "Verify claims against stored memories"),
-            Capability::new("memory_similar", "Find semantically similar memories"),
-        ]
-    }
-}
-
-impl SessionManagement for MockMemory {
-    fn start_session(&mut self, name: &str) -> SisterResult<ContextId> {
-        let id = ContextId::new();
-        *self.session_id.lock().unwrap() = Some(id);
-
-        let summary = ContextSummary {
-            id,
-            name: name.to_string(),
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            item_count: 0,
-            size_bytes: 0,
-        };
-        self.sessions.lock().unwrap().push(summary);
-
-        self.events.emit(SisterEvent::context_created(
-            SisterType::Memory,
-            id,
-            name.to_string(),
-        ));
-        Ok(id)
-    }
-
-    fn end_session(&mut self) -> SisterResult<()> {
-        *self.session_id.lock().unwrap() = None;
-        Ok(())
-    }
-
-    fn current_session(&self) -> Option<ContextId> {
-        *self.session_id.lock().unwrap()
-    }
-
-    fn current_session_info(&self) -> SisterResult<ContextInfo> {
-        let id = self
-            .current_session()
-            .ok_or_else(|| SisterError::new(ErrorCode::InvalidState, "No active session"))?;
-
-        Ok(ContextInfo {
-            id,
-            name: "active".to_string(),
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            item_count: self.nodes.lock().unwrap().len(),
-            size_bytes: 0,
-            metadata: Metadata::new(),
-        })
-    }
-
-    fn list_sessions(&self) -> SisterResult<Vec<ContextSummary>> {
-        Ok(self.sessions.lock().unwrap().clone())
-    }
-
-    fn export_session(&self, _id: ContextId) -> SisterResult<ContextSnapshot> {
-        let info = self.current_session_info()?;
-        let data = serde_json::to_vec(&self.nodes.lock().unwrap().clone())
-            .map_err(|e| SisterError::new(ErrorCode::Internal, e.to_string()))?;
-        let checksum = *blake3::hash(&data).as_bytes();
-
-        Ok(ContextSnapshot {
-            sister_type: SisterType::Memory,
-            version: Version::new(0, 2, 0),
-            context_info: info,
-            data,
-            checksum,
-            snapshot_at: Utc::now(),
-        })
-    }
-
-    fn import_session(&mut self, snapshot: ContextSnapshot) -> SisterResult<ContextId> {
-        if !snapshot.verify() {
-            return Err(SisterError::new(
-                ErrorCode::ChecksumMismatch,
-                "Snapshot checksum verification failed",
-            ));
-        }
-        self.start_session(&snapshot.context_info.name)
-    }
-}
-
-impl Grounding for MockMemory {
-    fn ground(&self, claim: &str) -> SisterResult<GroundingResult> {
-        let nodes = self.nodes.lock().unwrap();
-        let claim_lower = claim.to_lowercase();
-
-        let matches: Vec<_> = nodes
-            .iter()
-            .filter(|(_, content)| content.to_lowercase().contains(&claim_lower))
-            .collect();
-
-        if matches.is_empty() {
-            Ok(
-                GroundingResult::ungrounded(claim, "No matching memories found")
-                    .with_suggestions(nodes.iter().take(3).map(|(_, c)| c.clone()).collect()),
-            )
-        } else {
-            // BM25-like: best match score matters, not ratio
-            let best_score = matches
-                .iter()
-                .map(|(_, content)| {
-                    let claim_words: Vec<&str> = claim_lower.split_whitespace().collect();
-                    let content_lower = content.to_lowercase();
-                    let matched = claim_words
-                        .iter()
-                        .filter(|w| content_lower.contains(**w))
-                        .count();
-                    matched as f64 / claim_words.len().max(1) as f64
-                })
-                .fold(0.0f64, |a, b| a.max(b));
-
-            let evidence = matches
-                .iter()
-                .map(|(id, content)| {
-                    GroundingEvidence::new(
-                        "memory_node",
-                        format!("node_{}", id),
-                        best_score,
-                        content,
-                    )
-                })
-                .collect();
-
-            if best_score > 0.5 {
-                Ok(GroundingResult::verified(claim, best_score)
-                    .with_evidence(evidence)
-                    .with_reason("Found matching memories"))
-            } else {
-                Ok(GroundingResult::partial(claim, best_score)
-                    .with_evidence(evidence)
-                    .with_reason("Some evidence found"))
-            }
-        }
-    }
-
-    fn evidence(&self, query: &str, max_results: usize) -> SisterResult<Vec<EvidenceDetail>> {
-        let nodes = self.nodes.lock().unwrap();
-        let query_lower = query.to_lowercase();
-
-        Ok(nodes
-            .iter()
-            .filter(|(_, content)| content.to_lowercase().contains(&query_lower))
-            .take(max_results)
-            .map(|(id, content)| EvidenceDetail {
-                evidence_type: "memory_node".to_string(),
-                id: format!("node_{}", id),
-                score: 0.8,
-                created_at: Utc::now(),
-                source_sister: SisterType::Memory,
-                content: content.clone(),
-                data: Metadata::new(),
-            })
-            .collect())
-    }
-
-    fn suggest(&self, query: &str, limit: usize) -> SisterResult<Vec<GroundingSuggestion>> {
-        let nodes = self.nodes.lock().unwrap();
-        let _query_lower = query.to_lowercase();
-
-        Ok(nodes
-            .iter()
-            .take(limit)
-            .map(|(id, content)| GroundingSuggestion {
-                item_type: "memory_node".to_string(),
-                id: format!("node_{}", id),
-                relevance_score: 0.5,
-                description: content.clone(),
-                data: Metadata::new(),
-            })
-            .collect())
-    }
-}
-
-impl Queryable for MockMemory {
-    fn query(&self, query: Query) -> SisterResult<QueryResult> {
-        let start = Instant::now();
-        let nodes = self.nodes.lock().unwrap();
-
-        let results: Vec<serde_json::Value> = match query.query_type.as_str() {
-            "list" => nodes
-                .iter()
-                .skip(query.offset.unwrap_or(0))
-                .take(query.limit.unwrap_or(20))
-                .map(|(id, content)| serde_json::json!({"id": id, "content": content}))
-                .collect(),
-            "search" => {
-                let text = query.get_string("text").unwrap_or_default().to_lowercase();
-                nodes
-                    .iter()
-                    .filter(|(_, content)| content.to_lowercase().contains(&text))
-                    .take(query.limit.unwrap_or(20))
-                    .map(|(id, content)| serde_json::json!({"id": id, "content": content}))
-                    .collect()
-            }
-            "recent" => nodes
-                .iter()
-                .rev()
-                .take(query.limit.unwrap_or(10))
-                .map(|(id, content)| serde_json::json!({"id": id, "content": content}))
-                .collect(),
-            _ => vec![],
-        };
-
-        Ok(QueryResult::new(query, results, start.elapsed()))
-    }
-
-    fn supports_query(&self, query_type: &str) -> bool {
-        matches!(
-            query_type,
-            "list" | "search" | "recent" | "related" | "temporal"
-        )
-    }
-
-    fn query_types(&self) -> Vec<QueryTypeInfo> {
-        vec![
-            QueryTypeInfo::new("list", "List all memory nodes"),
-            QueryTypeInfo::new("search", "Search memories by text").required(vec!["text"]),
-            QueryTypeInfo::new("recent", "Get most recent memories"),
-        ]
-    }
-}
-
-impl EventEmitter for MockMemory {
-    fn subscribe(&self, _filter: EventFilter) -> EventReceiver {
-        self.events.subscribe()
-    }
-
-    fn recent_events(&self, limit: usize) -> Vec<SisterEvent> {
-        self.events.recent(limit)
-    }
-
-    fn emit(&self, event: SisterEvent) {
-        self.events.emit(event);
-    }
-}
-
-// ═══════════════════════════════════════════════════════════════════
-// MOCK CODEBASE — Workspace-based sister
-// ═══════════════════════════════════════════════════════════════════
-
-type SymbolList = Vec<(String, String)>; // (name, kind)
-type WorkspaceData = (String, SymbolList); // (workspace_name, symbols)
-
-struct MockCodebase {
-    start_time: Instant,
-    current_workspace: Mutex<ContextId>,
-    workspaces: Mutex<HashMap<ContextId, WorkspaceData>>,
-}
-
-impl MockCodebase {
-    fn new(_config: SisterConfig) -> SisterResult<Self> {
-        let default_id = ContextId::default_context();
-        let mut workspaces = HashMap::new();
-        workspaces.insert(default_id, ("default".to_string(), vec![]));
-
-        Ok(Self {
-            start_time: Instant::now(),
-            current_workspace: Mutex::new(default_id),
-            workspaces: Mutex::new(workspaces),
-        })
-    }
-
-    fn add_symbol(&self, name: &str, kind: &str) {
-        let ws_id = *self.current_workspace.lock().unwrap();
-        let mut workspaces = self.workspaces.lock().unwrap();
-        if let Some((_, symbols)) = workspaces.get_mut(&ws_id) {
-            symbols.push((name.to_string(), kind.to_string()));
-        }
-    }
-}
-
-impl Sister for MockCodebase {
-    const SISTER_TYPE: SisterType = SisterType::Codebase;
-    const FILE_EXTENSION: &'static str = "acb";
-
-    fn init(config: SisterConfig) -> SisterResult<Self>
-    where
-        Self: Sized,
-    {
-        MockCodebase::new(config)
-    }
-
-    fn health(&self) -> HealthStatus {
-        HealthStatus {
-            healthy: true,
-            status: Status::Ready,
-            uptime: self.start_time.elapsed(),
-            resources: ResourceUsage::default(),
-            warnings: vec![],
-            last_error: None,
-        }
-    }
-
-    fn version(&self) -> Version {
-        Version::new(0, 2, 0)
-    }
-
-    fn shutdown(&mut self) -> SisterResult<()> {
-        Ok(())
-    }
-
-    fn capabilities(&self) -> Vec<Capability> {
-        vec![
-            Capability::new("symbol_lookup", "Look up symbols by name"),
-            Capability::new("impact_analysis", "Analyse change impact"),
-            Capability::new("codebase_ground", "Verify code claims"),
-        ]
-    }
-}
-
-impl WorkspaceManagement for MockCodebase {
-    fn create_workspace(&mut self, name: &str) -> SisterResult<ContextId> {
-        let id = ContextId::new();
-        self.workspaces
-            .lock()
-            .unwrap()
-            .insert(id, (name.to_string(), vec![]));
-        Ok(id)
-    }
-
-    fn switch_workspace(&mut self, id: ContextId) -> SisterResult<()> {
-        let workspaces = self.workspaces.lock().unwrap();
-        if !workspaces.contains_key(&id) {
-            return Err(SisterError::context_not_found(id.to_string()));
-        }
-        drop(workspaces);
-        *self.current_workspace.lock().unwrap() = id;
-        Ok(())
-    }
-
-    fn current_workspace(&self) -> ContextId {
-        *self.current_workspace.lock().unwrap()
-    }
-
-    fn current_workspace_info(&self) -> SisterResult<ContextInfo> {
-        let ws_id = self.current_workspace();
-        let workspaces = self.workspaces.lock().unwrap();
-        let (name, symbols) = workspaces
-            .get(&ws_id)
-            .ok_or_else(|| SisterError::context_not_found(ws_id.to_string()))?;
-
-        Ok(ContextInfo {
-            id: ws_id,
-            name: name.clone(),
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            item_count: symbols.len(),
-            size_bytes: 0,
-            metadata: Metadata::new(),
-        })
-    }
-
-    fn list_workspaces(&self) -> SisterResult<Vec<ContextSummary>> {
-        let workspaces = self.workspaces.lock().unwrap();
-        Ok(workspaces
-            .iter()
-            .map(|(id, (name, symbols))| ContextSummary {
-                id: *id,
-                name: name.clone(),
-                created_at: Utc::now(),
-                updated_at: Utc::now(),
-                item_count: symbols.len(),
-                size_bytes: 0,
-            })
-            .collect())
-    }
-
-    fn delete_workspace(&mut self, id: ContextId) -> SisterResult<()> {
-        let current = *self.current_workspace.lock().unwrap();
-        if id == current {
-            return Err(SisterError::new(
-                ErrorCode::InvalidState,
-                "Cannot delete active workspace. Switch first",
-            ));
-        }
-        self.workspaces.lock().unwrap().remove(&id);
-        Ok(())
-    }
-
-    fn rename_workspace(&mut self, id: ContextId, new_name: &str) -> SisterResult<()> {
-        let mut workspaces = self.workspaces.lock().unwrap();
-        if let Some((name, _)) = workspaces.get_mut(&id) {
-            *name = new_name.to_string();
-            Ok(())
-        } else {
-            Err(SisterError::context_not_found(id.to_string()))
-        }
-    }
-
-    fn export_workspace(&self, id: ContextId) -> SisterResult<ContextSnapshot> {
-        let workspaces = self.workspaces.lock().unwrap();
-        let (name, symbols) = workspaces
-            .get(&id)
-            .ok_or_else(|| SisterError::context_not_found(id.to_string()))?;
-
-        let data = serde_json::to_vec(&symbols)
-            .map_err(|e| SisterError::new(ErrorCode::Internal, e.to_string()))?;
-        let checksum = *blake3::hash(&data).as_bytes();
-
-        Ok(ContextSnapshot {
-            sister_type: SisterType::Codebase,
-            version: Version::new(0, 2, 0),
-            context_info: ContextInfo {
-                id,
-                name: name.clone(),
-                created_at: Utc::now(),
-                updated_at: Utc::now(),
-                item_count: symbols.len(),
-                size_bytes: data.len(),
-                metadata: Metadata::new(),
-            },
-            data,
-            checksum,
-            snapshot_at: Utc::now(),
-        })
-    }
-
-    fn import_workspace(&mut self, snapshot: ContextSnapshot) -> SisterResult<ContextId> {
-        if !snapshot.verify() {
-            return Err(SisterError::new(
-                ErrorCode::ChecksumMismatch,
-                "Snapshot checksum failed",
-            ));
-        }
-        self.create_workspace(&snapshot.context_info.name)
-    }
-}
-
-impl Grounding for MockCodebase {
-    fn ground(&self, claim: &str) -> SisterResult<GroundingResult> {
-        let ws_id = self.current_workspace();
-        let workspaces = self.workspaces.lock().unwrap();
-        let (_, symbols) = workspaces
-            .get(&ws_id)
-            .ok_or_else(|| SisterError::context_not_found(ws_id.to_string()))?;
-
-        let claim_lower = claim.to_lowercase();
-        let matches: Vec<_> = symbols
-            .iter()
-            .filter(|(name, _)| claim_lower.contains(&name.to_lowercase()))
-            .collect();
-
-        if matches.is_empty() {
-            Ok(GroundingResult::ungrounded(
-                claim,
-                "Symbol not found in graph",
-            ))
-        } else {
-            let evidence = matches
-                .iter()
-                .map(|(name, kind)| {
-                    GroundingEvidence::new("code_symbol", name, 0.9, format!("{}: {}", kind, name))
-                })
-                .collect();
-            Ok(GroundingResult::verified(claim, 0.9)
-                .with_evidence(evidence)
-                .with_reason("Symbol found in code graph"))
-        }
-    }
-
-    fn evidence(&self, query: &str, max_results: usize) -> SisterResult<Vec<EvidenceDetail>> {
-        let ws_id = self.current_workspace();
-        let workspaces = self.workspaces.lock().unwrap();
-        let (_, symbols) = workspaces
-            .get(&ws_id)
-            .ok_or_else(|| SisterError::context_not_found(ws_id.to_string()))?;
-
-        let query_lower = query.to_lowercase();
-        Ok(symbols
-            .iter()
-            .filter(|(name, _)| name.to_lowercase().contains(&query_lower))
-            .take(max_results)
-            .map(|(name, kind)| EvidenceDetail {
-                evidence_type: "code_symbol".to_string(),
-                id: name.clone(),
-                score: 0.9,
-                created_at: Utc::now(),
-                source_sister: SisterType::Codebase,
-                content: format!("{} {}", kind, name),
-                data: Metadata::new(),
-            })
-            .collect())
-    }
-
-    fn suggest(&self, query: &str, limit: usize) -> SisterResult<Vec<GroundingSuggestion>> {
-        let ws_id = self.current_workspace();
-        let workspaces = self.workspaces.lock().unwrap();
-        let (_, symbols) = workspaces
-            .get(&ws_id)
-            .ok_or_else(|| SisterError::context_not_found(ws_id.to_string()))?;
-
-        let _query_lower = query.to_lowercase();
-        Ok(symbols
-            .iter()
-            .take(limit)
-            .map(|(name, kind)| GroundingSuggestion {
-                item_type: "code_symbol".to_string(),
-                id: name.clone(),
-                relevance_score: 0.5,
-                description: format!("{}: {}", kind, name),
-                data: Metadata::new(),
-            })
-            .collect())
-    }
-}
-
-impl Queryable for MockCodebase {
-    fn query(&self, query: Query) -> SisterResult<QueryResult> {
-        let start = Instant::now();
-        let ws_id = self.current_workspace();
-        let workspaces = self.workspaces.lock().unwrap();
-        let (_, symbols) = workspaces
-            .get(&ws_id)
-            .ok_or_else(|| SisterError::context_not_found(ws_id.to_string()))?;
-
-        let results: Vec<serde_json::Value> = match query.query_type.as_str() {
-            "list" => symbols
-                .iter()
-                .take(query.limit.unwrap_or(50))
-                .map(|(name, kind)| serde_json::json!({"name": name, "kind": kind}))
-                .collect(),
-            "search" => {
-                let text = query.get_string("text").unwrap_or_default().to_lowercase();
-                symbols
-                    .iter()
-                    .filter(|(name, _)| name.to_lowercase().contains(&text))
-                    .take(query.limit.unwrap_or(20))
-                    .map(|(name, kind)| serde_json::json!({"name": name, "kind": kind}))
-                    .collect()
-            }
-            _ => vec![],
-        };
-
-        Ok(QueryResult::new(query, results, start.elapsed()))
-    }
-
-    fn supports_query(&self, query_type: &str) -> bool {
-        matches!(query_type, "list" | "search" | "get")
-    }
-
-    fn query_types(&self) -> Vec<QueryTypeInfo> {
-        vec![
-            QueryTypeInfo::new("list", "List all code symbols"),
-            QueryTypeInfo::new("search", "Search symbols by name").required(vec!["text"]),
-        ]
-    }
-}
-
-// ═══════════════════════════════════════════════════════════════════
-// MOCK IDENTITY — Session-based with receipts
-// ═══════════════════════════════════════════════════════════════════
-
-struct MockIdentity {
-    start_time: Instant,
-    session_id: Mutex<Option<ContextId>>,
-    receipts: Mutex<Vec<Receipt>>,
-    chain_position: Mutex<u64>,
-}
-
-impl MockIdentity {
-    fn new(_config: SisterConfig) -> SisterResult<Self> {
-        Ok(Self {
-            start_time: Instant::now(),
-            session_id: Mutex::new(None),
-            receipts: Mutex::new(vec![]),
-            chain_position: Mutex::new(0),
-        })
-    }
-}
-
-impl Sister for MockIdentity {
-    const SISTER_TYPE: SisterType = SisterType::Identity;
-    const FILE_EXTENSION: &'static str = "aid";
-
-    fn init(config: SisterConfig) -> SisterResult<Self>
-    where
-        Self: Sized,
-    {
-        MockIdentity::new(config)
-    }
-
-    fn health(&self) -> HealthStatus {
-        HealthStatus {
-            healthy: true,
-            status: Status::Ready,
-            uptime: self.start_time.elapsed(),
-            resources: ResourceUsage::default(),
-            warnings: vec![],
-            last_error: None,
-        }
-    }
-
-    fn version(&self) -> Version {
-        Version::new(0, 2, 0)
-    }
-
-    fn shutdown(&mut self) -> SisterResult<()> {
-        Ok(())
-    }
-
-    fn capabilities(&self) -> Vec<Capability> {
-        vec![
-            Capability::new("identity_create", "Create cryptographic identity"),
-            Capability::new("action_sign", "Sign actions with receipt chain"),
-            Capability::new("trust_grant", "Grant trust to other identities"),
-        ]
-    }
-}
-
-impl SessionManagement for MockIdentity {
-    fn start_session(&mut self, _name: &str) -> SisterResult<ContextId> {
-        let id = ContextId::new();
-        *self.session_id.lock().unwrap() = Some(id);
-        Ok(id)
-    }
-
-    fn end_session(&mut self) -> SisterResult<()> {
-        *self.session_id.lock().unwrap() = None;
-        Ok(())
-    }
-
-    fn current_session(&self) -> Option<ContextId> {
-        *self.session_id.lock().unwrap()
-    }
-
-    fn current_session_info(&self) -> SisterResult<ContextInfo> {
-        let id = self
-            .current_session()
-            .ok_or_else(|| SisterError::new(ErrorCode::InvalidState, "No active session"))?;
-        Ok(ContextInfo {
-            id,
-            name: "identity_session".to_string(),
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            item_count: self.receipts.lock().unwrap().len(),
-            size_bytes: 0,
-            metadata: Metadata::new(),
-        })
-    }
-
-    fn list_sessions(&self) -> SisterResult<Vec<ContextSummary>> {
-        Ok(vec![])
-    }
-
-    fn export_session(&self, _id: ContextId) -> SisterResult<ContextSnapshot> {
-        Err(SisterError::new(
-            ErrorCode::NotImplemented,
-            "Identity does not support session export",
-        ))
-    }
-
-    fn import_session(&mut self, _snapshot: ContextSnapshot) -> SisterResult<ContextId> {
-        Err(SisterError::new(
-            ErrorCode::NotImplemented,
-            "Identity does not support session import",
-        ))
-    }
-}
-
-impl ReceiptIntegration for MockIdentity {
-    fn create_receipt(&self, action: ActionRecord) -> SisterResult<ReceiptId> {
-        let receipt_id = ReceiptId::new();
-        let mut position = self.chain_position.lock().unwrap();
-        *position += 1;
-
-        let receipt = Receipt {
-            id: receipt_id,
-            action,
-            signature: "mock_ed25519_signature".to_string(),
-            chain_position: *position,
-            previous_hash: "0000000000000000".to_string(),
-            hash: format!("hash_{}", position),
-            created_at: Utc::now(),
-        };
-
-        self.receipts.lock().unwrap().push(receipt);
-        Ok(receipt_id)
-    }
-
-    fn get_receipt(&self, id: ReceiptId) -> SisterResult<Receipt> {
-        self.receipts
-            .lock()
-            .unwrap()
-            .iter()
-            .find(|r| r.id == id)
-            .cloned()
-            .ok_or_else(|| SisterError::not_found(format!("Receipt {}", id)))
-    }
-
-    fn list_receipts(&self, filter: ReceiptFilter) -> SisterResult<Vec<Receipt>> {
-        let receipts = self.receipts.lock().unwrap();
-        let mut results: Vec<_> = receipts
-            .iter()
-            .filter(|r| {
-                if let Some(ref st) = filter.sister_type {
-                    if r.action.sister_type != *st {
-                        return false;
-                    }
-                }
-                if let Some(ref at) = filter.action_type {
-                    if r.action.action_type != *at {
-                        return false;
-                    }
-                }
-                true
-            })
-            .cloned()
-            .collect();
-
-        if let Some(limit) = filter.limit {
-            results.truncate(limit);
-        }
-        Ok(results)
-    }
-}
-
-impl Grounding for MockIdentity {
-    fn ground(&self, claim: &str) -> SisterResult<GroundingResult> {
-        let receipts = self.receipts.lock().unwrap();
-        let claim_lower = claim.to_lowercase();
-
-        let matches: Vec<_> = receipts
-            .iter()
-            .filter(|r| {
-                r.action.action_type.to_lowercase().contains(&claim_lower)
-                    || claim_lower.contains(&r.action.action_type.to_lowercase())
-            })
-            .collect();
-
-        if matches.is_empty() {
-            Ok(GroundingResult::ungrounded(claim, "No matching receipts"))
-        } else {
-            let evidence = matches
-                .iter()
-                .map(|r| {
-                    GroundingEvidence::new(
-                        "receipt",
-                        r.id.to_string(),
-                        0.9,
-                        format!("Receipt for {}", r.action.action_type),
-                    )
-                })
-                .collect();
-            Ok(GroundingResult::verified(claim, 0.9).with_evidence(evidence))
-        }
-    }
-
-    fn evidence(&self, query: &str, max_results: usize) -> SisterResult<Vec<EvidenceDetail>> {
-        let receipts = self.receipts.lock().unwrap();
-        let query_lower = query.to_lowercase();
-
-        Ok(receipts
-            .iter()
-            .filter(|r| r.action.action_type.to_lowercase().contains(&query_lower))
-            .take(max_results)
-            .map(|r| EvidenceDetail {
-                evidence_type: "receipt".to_string(),
-                id: r.id.to_string(),
-                score: 0.9,
-                created_at: r.created_at,
-                source_sister: SisterType::Identity,
-                content: format!("{} (chain pos {})", r.action.action_type, r.chain_position),
-                data: Metadata::new(),
-            })
-            .collect())
-    }
-
-    fn suggest(&self, _query: &str, limit: usize) -> SisterResult<Vec<GroundingSuggestion>> {
-        let receipts = self.receipts.lock().unwrap();
-        Ok(receipts
-            .iter()
-            .take(limit)
-            .map(|r| GroundingSuggestion {
-                item_type: "receipt".to_string(),
-                id: r.id.to_string(),
-                relevance_score: 0.5,
-                description: format!("Action: {}", r.action.action_type),
-                data: Metadata::new(),
-            })
-            .collect())
-    }
-}
-
-// ═══════════════════════════════════════════════════════════════════
-// MOCK TIME — Stateless sister (no sessions, no grounding)
-// ═══════════════════════════════════════════════════════════════════
-
-struct MockTime {
-    start_time: Instant,
-}
-
-impl Sister for MockTime {
-    const SISTER_TYPE: SisterType = SisterType::Time;
-    const FILE_EXTENSION: &'static str = "atime";
-
-    fn init(_config: SisterConfig) -> SisterResult<Self>
-    where
-        Self: Sized,
-    {
-        Ok(Self {
-            start_time: Instant::now(),
-        })
-    }
-
-    fn health(&self) -> HealthStatus {
-        HealthStatus {
-            healthy: true,
-            status: Status::Ready,
-            uptime: self.start_time.elapsed(),
-            resources: ResourceUsage::default(),
-            warnings: vec![],
-            last_error: None,
-        }
-    }
-
-    fn version(&self) -> Version {
-        Version::new(0, 2, 0)
-    }
-
-    fn shutdown(&mut self) -> SisterResult<()> {
-        Ok(())
-    }
-
-    fn capabilities(&self) -> Vec<Capability> {
-        vec![
-            Capability::new("time_now", "Get current time in any timezone"),
-            Capability::new("time_duration", "Calculate duration between events"),
-        ]
-    }
-}
-
-// Time is stateless — no SessionManagement, no WorkspaceManagement, no Grounding
-
-impl Queryable for MockTime {
-    fn query(&self, query: Query) -> SisterResult<QueryResult> {
-        let start = Instant::now();
-        let results = match query.query_type.as_str() {
-            "current_time" => {
-                vec![serde_json::json!({"time": Utc::now().to_rfc3339()})]
-            }
-            _ => vec![],
-        };
-        Ok(QueryResult::new(query, results, start.elapsed()))
-    }
-
-    fn supports_query(&self, query_type: &str) -> bool {
-        matches!(query_type, "current_time" | "duration")
-    }
-
-    fn query_types(&self) -> Vec<QueryTypeInfo> {
-        vec![QueryTypeInfo::new("current_time", "Get current UTC time")]
-    }
-}
-
-// ═══════════════════════════════════════════════════════════════════
-// MOCK CONTRACT — Full-featured sister (sessions, grounding, queries, receipts, events)
-// ═══════════════════════════════════════════════════════════════════
-
-struct MockContract {
-    start_time: Instant,
-    session_id: Mutex<Option<ContextId>>,
-    sessions: Mutex<Vec<ContextSummary>>,
-    events: EventManager,
-    policies: Mutex<Vec<(u64, String, String)>>, // (id, label, scope)
-    next_id: Mutex<u64>,
-    receipts: Mutex<Vec<Receipt>>,
-    chain_position: Mutex<u64>,
-}
-
-impl MockContract {
-    fn new(_config: SisterConfig) -> SisterResult<Self> {
-        Ok(Self {
-            start_time: Instant::now(),
-            session_id: Mutex::new(None),
-            sessions: Mutex::new(vec![]),
-            events: EventManager::new(256),
-            policies: Mutex::new(vec![]),
-            next_id: Mutex::new(1),
-            receipts: Mutex::new(vec![]),
-            chain_position: Mutex::new(0),
-        })
-    }
-
-    fn add_policy(&self, label: &str, scope: &str) -> u64 {
-        let mut policies = self.policies.lock().unwrap();
-        let mut next = self.next_id.lock().unwrap();
-        let id = *next;
-        *next += 1;
-        policies.push((id, label.to_string(), scope.to_string()));
-        id
-    }
-}
-
-impl Sister for MockContract {
-    const SISTER_TYPE: SisterType = SisterType::Contract;
-    const FILE_EXTENSION: &'static str = "acon";
-
-    fn init(config: SisterConfig) -> SisterResult<Self>
-    where
-        Self: Sized,
-    {
-        MockContract::new(config)
-    }
-
-    fn health(&self) -> HealthStatus {
-        HealthStatus {
-            healthy: true,
-            status: Status::Ready,
-            uptime: self.start_time.elapsed(),
-            resources: ResourceUsage::default(),
-            warnings: vec![],
-            last_error: None,
-        }
-    }
-
-    fn version(&self) -> Version {
-        Version::new(0, 2, 0)
-    }
-
-    fn shutdown(&mut self) -> SisterResult<()> {
-        self.events
-            .emit(SisterEvent::shutting_down(SisterType::Contract));
-        Ok(())
-    }
-
-    fn capabilities(&self) -> Vec<Capability> {
-        vec![
-            Capability::new("policy_add", "Add a policy rule governing agent behavior"),
-            Capability::new(
-                "policy_check",
-                "Check if an action is allowed under policies",
-            ),
-            Capability::new("risk_limit_set", "Set a risk limit threshold"),
-            Capability::new(
-                "approval_request",
-                "Request approval for a controlled action",
-            ),
-            Capability::new("violation_report", "Report a contract or policy violation"),
-        ]
-    }
-}
-
-impl SessionManagement for MockContract {
-    fn start_session(&mut self, name: &str) -> SisterResult<ContextId> {
-        let id = ContextId::new();
-        *self.session_id.lock().unwrap() = Some(id);
-
-        let summary = ContextSummary {
-            id,
-            name: name.to_string(),
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            item_count: 0,
-            size_bytes: 0,
-        };
-        self.sessions.lock().unwrap().push(summary);
-
-        self.events.emit(SisterEvent::context_created(
-            SisterType::Contract,
-            id,
-            name.to_string(),
-        ));
-        Ok(id)
-    }
-
-    fn end_session(&mut self) -> SisterResult<()> {
-        *self.session_id.lock().unwrap() = None;
-        Ok(())
-    }
-
-    fn current_session(&self) -> Option<ContextId> {
-        *self.session_id.lock().unwrap()
-    }
-
-    fn current_session_info(&self) -> SisterResult<ContextInfo> {
-        let id = self
-            .current_session()
-            .ok_or_else(|| SisterError::new(ErrorCode::InvalidState, "No active session"))?;
-
-        Ok(ContextInfo {
-            id,
-            name: "contract_session".to_string(),
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            item_count: self.policies.lock().unwrap().len(),
-            size_bytes: 0,
-            metadata: Metadata::new(),
-        })
-    }
-
-    fn list_sessions(&self) -> SisterResult<Vec<ContextSummary>> {
-        Ok(self.sessions.lock().unwrap().clone())
-    }
-
-    fn export_session(&self, _id: ContextId) -> SisterResult<ContextSnapshot> {
-        let info = self.current_session_info()?;
-        let data = serde_json::to_vec(&self.policies.lock().unwrap().clone())
-            .map_err(|e| SisterError::new(ErrorCode::Internal, e.to_string()))?;
-        let checksum = *blake3::hash(&data).as_bytes();
-
-        Ok(ContextSnapshot {
-            sister_type: SisterType::Contract,
-            version: Version::new(0, 2, 0),
-            context_info: info,
-            data,
-            checksum,
-            snapshot_at: Utc::now(),
-        })
-    }
-
-    fn import_session(&mut self, snapshot: ContextSnapshot) -> SisterResult<ContextId> {
-        if !snapshot.verify() {
-            return Err(SisterError::new(
-                ErrorCode::ChecksumMismatch,
-                "Snapshot checksum verification failed",
-            ));
-        }
-        self.start_session(&snapshot.context_info.name)
-    }
-}
-
-impl Grounding for MockContract {
-    fn ground(&self, claim: &str) -> SisterResult<GroundingResult> {
-        let policies = self.policies.lock().unwrap();
-        let claim_lower = claim.to_lowercase();
-
-        let matches: Vec<_> = policies
-            .iter()
-            .filter(|(_, label, _)| label.to_lowercase().contains(&claim_lower))
-            .collect();
-
-        if matches.is_empty() {
-            Ok(
-                GroundingResult::ungrounded(claim, "No matching policies found")
-                    .with_suggestions(policies.iter().take(3).map(|(_, l, _)| l.clone()).collect()),
-            )
-        } else {
-            let best_score = matches
-                .iter()
-                .map(|(_, label, _)| {
-                    let claim_words: Vec<&str> = claim_lower.split_whitespace().collect();
-                    let label_lower = label.to_lowercase();
-                    let matched = claim_words
-                        .iter()
-                        .filter(|w| label_lower.contains(**w))
-                        .count();
-                    matched as f64 / claim_words.len().max(1) as f64
-                })
-                .fold(0.0f64, |a, b| a.max(b));
-
-            let evidence = matches
-                .iter()
-                .map(|(id, label, scope)| {
-                    GroundingEvidence::new(
-                        "policy",
-                        format!("policy_{}", id),
-                        best_score,
-                        format!("{} [{}]", label, scope),
-                    )
-                })
-                .collect();
-
-            if best_score > 0.5 {
-                Ok(GroundingResult::verified(claim, best_score)
-                    .with_evidence(evidence)
-                    .with_reason("Found matching policies"))
-            } else {
-                Ok(GroundingResult::partial(claim, best_score)
-                    .with_evidence(evidence)
-                    .with_reason("Some policy evidence found"))
-            }
-        }
-    }
-
-    fn evidence(&self, query: &str, max_results: usize) -> SisterResult<Vec<EvidenceDetail>> {
-        let policies = self.policies.lock().unwrap();
-        let query_lower = query.to_lowercase();
-
-        Ok(policies
-            .iter()
-            .filter(|(_, label, _)| label.to_lowercase().contains(&query_lower))
-            .take(max_results)
-            .map(|(id, label, scope)| EvidenceDetail {
-                evidence_type: "policy".to_string(),
-                id: format!("policy_{}", id),
-                score: 0.8,
-                created_at: Utc::now(),
-                source_sister: SisterType::Contract,
-                content: format!("{} [{}]", label, scope),
-                data: Metadata::new(),
-            })
-            .collect())
-    }
-
-    fn suggest(&self, _query: &str, limit: usize) -> SisterResult<Vec<GroundingSuggestion>> {
-        let policies = self.policies.lock().unwrap();
-
-        Ok(policies
-            .iter()
-            .take(limit)
-            .map(|(id, label, scope)| GroundingSuggestion {
-                item_type: "policy".to_string(),
-                id: format!("policy_{}", id),
-                relevance_score: 0.5,
-                description: format!("{} [{}]", label, scope),
-                data: Metadata::new(),
-            })
-            .collect())
-    }
-}
-
-impl Queryable for MockContract {
-    fn query(&self, query: Query) -> SisterResult<QueryResult> {
-        let start = Instant::now();
-        let policies = self.policies.lock().unwrap();
-
-        let results: Vec<serde_json::Value> = match query.query_type.as_str() {
-            "list" => policies
-                .iter()
-                .skip(query.offset.unwrap_or(0))
-                .take(query.limit.unwrap_or(20))
-                .map(|(id, label, scope)| {
-                    serde_json::json!({"id": id, "label": label, "scope": scope})
-                })
-                .collect(),
-            "search" => {
-                let text = query.get_string("text").unwrap_or_default().to_lowercase();
-                policies
-                    .iter()
-                    .filter(|(_, label, _)| label.to_lowercase().contains(&text))
-                    .take(query.limit.unwrap_or(20))
-                    .map(|(id, label, scope)| {
-                        serde_json::json!({"id": id, "label": label, "scope": scope})
-                    })
-                    .collect()
-            }
-            "recent" => policies
-                .iter()
-                .rev()
-                .take(query.limit.unwrap_or(10))
-                .map(|(id, label, scope)| {
-                    serde_json::json!({"id": id, "label": label, "scope": scope})
-                })
-                .collect(),
-            _ => vec![],
-        };
-
-        Ok(QueryResult::new(query, results, start.elapsed()))
-    }
-
-    fn supports_query(&self, query_type: &str) -> bool {
-        matches!(query_type, "list" | "search" | "recent" | "get")
-    }
-
-    fn query_types(&self) -> Vec<QueryTypeInfo> {
-        vec![
-            QueryTypeInfo::new("list", "List all policies"),
-            QueryTypeInfo::new("search", "Search policies by label").required(vec!["text"]),
-            QueryTypeInfo::new("recent", "Get most recent policies"),
-        ]
-    }
-}
-
-impl ReceiptIntegration for MockContract {
-    fn create_receipt(&self, action: ActionRecord) -> SisterResult<ReceiptId> {
-        let receipt_id = ReceiptId::new();
-        let mut position = self.chain_position.lock().unwrap();
-        *position += 1;
-
-        let receipt = Receipt {
-            id: receipt_id,
-            action,
-            signature: "mock_ed25519_signature".to_string(),
-            chain_position: *position,
-            previous_hash: "0000000000000000".to_string(),
-            hash: format!("hash_{}", position),
-            created_at: Utc::now(),
-        };
-
-        self.receipts.lock().unwrap().push(receipt);
-        Ok(receipt_id)
-    }
-
-    fn get_receipt(&self, id: ReceiptId) -> SisterResult<Receipt> {
-        self.receipts
-            .lock()
-            .unwrap()
-            .iter()
-            .find(|r| r.id == id)
-            .cloned()
-            .ok_or_else(|| SisterError::not_found(format!("Receipt {}", id)))
-    }
-
-    fn list_receipts(&self, filter: ReceiptFilter) -> SisterResult<Vec<Receipt>> {
-        let receipts = self.receipts.lock().unwrap();
-        let mut results: Vec<_> = receipts
-            .iter()
-            .filter(|r| {
-                if let Some(ref st) = filter.sister_type {
-                    if r.action.sister_type != *st {
-                        return false;
-                    }
-                }
-                if let Some(ref at) = filter.action_type {
-                    if r.action.action_type != *at {
-                        return false;
-                    }
-                }
-                true
-            })
-            .cloned()
-            .collect();
-
-        if let Some(limit) = filter.limit {
-            results.truncate(limit);
-        }
-        Ok(results)
-    }
-}
-
-impl EventEmitter for MockContract {
-    fn subscribe(&self, _filter: EventFilter) -> EventReceiver {
-        self.events.subscribe()
-    }
-
-    fn recent_events(&self, limit: usize) -> Vec<SisterEvent> {
-        self.events.recent(limit)
-    }
-
-    fn emit(&self, event: SisterEvent) {
-        self.events.emit(event);
-    }
-}
 
 // ═══════════════════════════════════════════════════════════════════
 // TESTS — Validate all trait compositions compile and work
@@ -1362,6 +46,12 @@ fn test_memory_sessions() {
     let info = memory.current_session_info().unwrap();
     assert_eq!(info.id, session_id);
 
+    // Default stats come from the session summary
+    let stats = memory.session_stats(session_id).unwrap();
+    assert_eq!(stats.context_id, session_id);
+    assert!(stats.last_activity.is_some());
+    assert!(stats.items_added.is_empty());
+
     memory.end_session().unwrap();
     assert!(memory.current_session().is_none());
 }
@@ -1456,7 +146,7 @@ fn test_memory_snapshot_export_import() {
     memory.add_node("Important memory");
 
     let snapshot = memory.export_session(session_id).unwrap();
-    assert!(snapshot.verify()); // BLAKE3 checksum passes
+    assert!(snapshot.verify().unwrap()); // BLAKE3 checksum passes
     assert_eq!(snapshot.sister_type, SisterType::Memory);
     assert_eq!(snapshot.version, Version::new(0, 2, 0));
 
@@ -1466,6 +156,153 @@ fn test_memory_snapshot_export_import() {
     let imported_id = memory2.import_session(snapshot).unwrap();
     assert!(memory2.current_session().is_some());
     assert_eq!(memory2.current_session().unwrap(), imported_id);
+
+    // Forking is opt-in
+    let err = memory2
+        .fork_session(imported_id, "alternative")
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::NotImplemented);
+    assert!(!memory2.current_session_info().unwrap().is_fork());
+}
+
+#[test]
+fn test_memory_session_retention() {
+    let archive = std::env::temp_dir().join(format!("mock_archive_{}", uuid::Uuid::new_v4()));
+    let _cleanup = RemoveOnDrop(archive.clone());
+    let policy = RetentionPolicy::new().max_sessions(2).archive_to(&archive);
+    let config = SisterConfig::new("/tmp/mock-memory").retention(policy);
+    let mut memory = MockMemory::init(config.clone()).unwrap();
+
+    for i in 0..4 {
+        memory.start_session(&format!("session-{}", i)).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+    }
+    memory.add_node("kept in snapshot");
+
+    let report = memory
+        .purge_sessions(config.retention.as_ref().unwrap())
+        .unwrap();
+    assert_eq!(report.purged.len(), 2);
+    assert_eq!(report.remaining, 2);
+    assert_eq!(report.archive_files.len(), 2);
+    assert!(report.archive_files.iter().all(|p| p.exists()));
+    assert_eq!(memory.list_sessions().unwrap().len(), 2);
+
+    let archived = memory
+        .recent_events(10)
+        .into_iter()
+        .filter(|e| matches!(e.event_type, EventType::ContextArchived { .. }))
+        .count();
+    assert_eq!(archived, 2);
+}
+
+/// Removes the directory when the test ends, pass or fail.
+struct RemoveOnDrop(std::path::PathBuf);
+
+impl Drop for RemoveOnDrop {
+    fn drop(&mut self) {
+        std::fs::remove_dir_all(&self.0).ok();
+    }
+}
+
+#[test]
+fn test_memory_checkpoints() {
+    let config =
+        SisterConfig::new("/tmp/mock-memory").checkpoint(CheckpointPolicy::new().every_items(2));
+    let mut memory = MockMemory::init(config.clone()).unwrap();
+    assert!(memory.checkpoint().is_err());
+
+    memory.start_session("long-run").unwrap();
+    memory.add_node("first");
+    memory.add_node("second");
+    let policy = config.checkpoint.as_ref().unwrap();
+    assert!(policy.is_due(2, None, Utc::now()));
+    let id = memory.checkpoint().unwrap();
+
+    memory.add_node("lost in crash");
+    assert_eq!(memory.current_session_info().unwrap().item_count, 3);
+
+    memory.restore_checkpoint(id).unwrap();
+    assert_eq!(memory.current_session_info().unwrap().item_count, 2);
+    let latest = memory.latest_checkpoint().unwrap().unwrap();
+    assert_eq!(latest.id, id);
+    assert_eq!(latest.item_count, 2);
+    assert!(memory.restore_checkpoint(CheckpointId::new()).is_err());
+}
+
+#[test]
+fn test_memory_bridge_adapter() {
+    let mut memory = MockMemory::init(SisterConfig::new("/tmp/mock-memory")).unwrap();
+    let session = memory.start_session("bridge_test").unwrap();
+    memory.add_node("Rust uses ownership");
+    memory.add_node("Hydra orchestrates sisters");
+
+    let mut bridge = BridgeAdapter::sessions(memory).recent_items(1);
+
+    let context = bridge.session_context().unwrap();
+    assert_eq!(context.context_id, session);
+    assert_eq!(context.recent_items, vec!["Hydra orchestrates sisters"]);
+    assert_eq!(bridge.summary().unwrap().sister_type, SisterType::Memory);
+
+    let mut params = Metadata::new();
+    params.insert("claim".into(), serde_json::json!("ownership"));
+    let result = bridge
+        .execute(HydraCommand {
+            command_type: "ground".into(),
+            params,
+            run_id: "run_1".into(),
+            step_id: 1,
+            budget: None,
+            dry_run: false,
+            idempotency_key: None,
+            priority: Priority::Normal,
+            deadline: None,
+            exclusive: false,
+            accept: vec![],
+        })
+        .unwrap();
+    assert!(result.success);
+    assert_eq!(result.evidence_ids.len(), 1);
+
+    let mut params = Metadata::new();
+    params.insert("query_type".into(), serde_json::json!("list"));
+    let result = bridge
+        .execute(HydraCommand {
+            command_type: "query".into(),
+            params,
+            run_id: "run_1".into(),
+            step_id: 2,
+            budget: None,
+            dry_run: false,
+            idempotency_key: None,
+            priority: Priority::Normal,
+            deadline: None,
+            exclusive: false,
+            accept: vec![],
+        })
+        .unwrap();
+    assert_eq!(result.data["results"].as_array().unwrap().len(), 2);
+
+    let err = bridge
+        .execute(HydraCommand {
+            command_type: "memory_teleport".into(),
+            params: Metadata::new(),
+            run_id: "run_1".into(),
+            step_id: 3,
+            budget: None,
+            dry_run: false,
+            idempotency_key: None,
+            priority: Priority::Normal,
+            deadline: None,
+            exclusive: false,
+            accept: vec![],
+        })
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+
+    // Hydra resumes a run in the still-current session
+    bridge.restore_session(context).unwrap();
+    assert_eq!(bridge.sister().current_session(), Some(session));
 }
 
 #[test]
@@ -1506,6 +343,121 @@ fn test_codebase_workspaces() {
     assert_eq!(codebase.list_workspaces().unwrap().len(), 1);
 }
 
+#[test]
+fn test_codebase_workspace_tags() {
+    let config = SisterConfig::default();
+    let mut codebase = MockCodebase::init(config).unwrap();
+    let auth = codebase.create_workspace("auth-refactor").unwrap();
+    codebase.create_workspace("ui-polish").unwrap();
+
+    codebase.tag_context(auth, &["auth", "backend"]).unwrap();
+    codebase.tag_context(auth, &["auth"]).unwrap();
+    assert_eq!(
+        codebase.context_tags(auth).unwrap(),
+        vec!["auth", "backend"]
+    );
+
+    let found = codebase
+        .find_workspaces(&ContextFilter::new().tag("auth"))
+        .unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].id, auth);
+
+    let found = codebase
+        .find_workspaces(&ContextFilter::new().name_contains("POLISH"))
+        .unwrap();
+    assert_eq!(found[0].name, "ui-polish");
+
+    codebase.untag_context(auth, &["auth"]).unwrap();
+    assert!(codebase
+        .find_workspaces(&ContextFilter::new().tag("auth"))
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_codebase_workspace_metadata() {
+    let mut codebase = MockCodebase::init(SisterConfig::default()).unwrap();
+    let ws = codebase.create_workspace("feature").unwrap();
+    codebase.switch_workspace(ws).unwrap();
+
+    codebase
+        .set_context_metadata(ws, "run_id", serde_json::json!("run_42"))
+        .unwrap();
+    let info = codebase.current_workspace_info().unwrap();
+    assert_eq!(info.metadata["run_id"], "run_42");
+
+    codebase.remove_context_metadata(ws, "run_id").unwrap();
+    codebase.remove_context_metadata(ws, "run_id").unwrap();
+    assert!(codebase
+        .current_workspace_info()
+        .unwrap()
+        .metadata
+        .is_empty());
+    assert!(codebase
+        .set_context_metadata(ContextId::new(), "k", serde_json::json!(1))
+        .is_err());
+
+    // Sisters that don't store metadata report NotImplemented
+    let mut memory = MockMemory::init(SisterConfig::new("/tmp/mock-memory")).unwrap();
+    let session = memory.start_session("s").unwrap();
+    let err = memory
+        .set_context_metadata(session, "run_id", serde_json::json!("run_42"))
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::NotImplemented);
+}
+
+#[test]
+fn test_coordinated_begin_and_rollback() {
+    let mut memory = MockMemory::init(SisterConfig::new("/tmp/mock-memory")).unwrap();
+    let mut codebase = MockCodebase::init(SisterConfig::default()).unwrap();
+    let feature = codebase.create_workspace("feature").unwrap();
+    let events = std::cell::RefCell::new(Vec::new());
+
+    let result = ContextCoordinator::new()
+        .session(SisterType::Memory, &mut memory)
+        .workspace(SisterType::Codebase, &mut codebase, feature)
+        .on_event(|e| events.borrow_mut().push(e.clone()))
+        .begin("fix-auth")
+        .unwrap();
+    assert_eq!(result.context_for(SisterType::Codebase), Some(feature));
+    assert_eq!(codebase.current_workspace(), feature);
+    assert_eq!(
+        memory.current_session(),
+        result.context_for(SisterType::Memory)
+    );
+
+    // Switching Codebase to a missing workspace rolls Memory back
+    let missing = ContextId::new();
+    let err = ContextCoordinator::new()
+        .session(SisterType::Memory, &mut memory)
+        .new_workspace(SisterType::Codebase, &mut codebase)
+        .workspace(
+            SisterType::Codebase,
+            &mut MockCodebase::init(SisterConfig::default()).unwrap(),
+            missing,
+        )
+        .on_event(|e| events.borrow_mut().push(e.clone()))
+        .begin("doomed")
+        .unwrap_err();
+    assert!(err.context.unwrap().contains_key("correlation_id"));
+    assert!(memory.current_session().is_none());
+    assert_eq!(codebase.current_workspace(), feature);
+    assert_eq!(codebase.list_workspaces().unwrap().len(), 2);
+
+    let events = events.into_inner();
+    assert_eq!(events.len(), 2);
+    assert!(events[0].is_committed());
+    assert!(matches!(
+        events[1].outcome,
+        CoordinationOutcome::RolledBack {
+            failed: SisterType::Codebase,
+            ..
+        }
+    ));
+    assert_eq!(events[1].contexts.len(), 2);
+}
+
 #[test]
 fn test_codebase_grounding() {
     let config = SisterConfig::default();
@@ -1530,10 +482,31 @@ fn test_codebase_workspace_export() {
 
     let default_ws = codebase.current_workspace();
     let snapshot = codebase.export_workspace(default_ws).unwrap();
-    assert!(snapshot.verify());
+    assert!(snapshot.verify().unwrap());
     assert_eq!(snapshot.sister_type, SisterType::Codebase);
 }
 
+#[test]
+fn test_codebase_chunked_workspace_transfer() {
+    let config = SisterConfig::default();
+    let mut codebase = MockCodebase::init(config).unwrap();
+    for i in 0..20 {
+        codebase.add_symbol(&format!("symbol_{}", i), "function");
+    }
+
+    let default_ws = codebase.current_workspace();
+    let chunks: Vec<_> = codebase
+        .export_workspace_chunked(default_ws, 64)
+        .unwrap()
+        .collect();
+    assert!(chunks.len() > 1);
+
+    let imported = codebase
+        .import_workspace_chunked(&mut chunks.into_iter())
+        .unwrap();
+    assert!(codebase.workspace_exists(imported));
+}
+
 #[test]
 fn test_identity_receipt_chain() {
     let config = SisterConfig::with_paths({
@@ -1586,6 +559,97 @@ fn test_identity_receipt_chain() {
     assert_eq!(result.status, GroundingStatus::Verified);
 }
 
+#[test]
+fn test_identity_receipt_pagination() {
+    let identity = MockIdentity::init(SisterConfig::stateless()).unwrap();
+    for i in 0..5 {
+        identity
+            .create_receipt(ActionBuilder::new(SisterType::Memory, format!("step_{}", i)).success())
+            .unwrap();
+    }
+
+    let first = identity
+        .list_receipts_page(ReceiptFilter::new(), None, 2)
+        .unwrap();
+    assert_eq!(first.receipts.len(), 2);
+    assert!(first.has_more());
+
+    let second = identity
+        .list_receipts_page(ReceiptFilter::new(), first.next_cursor, 2)
+        .unwrap();
+    assert_eq!(second.receipts[0].action_type(), "step_2");
+
+    let all: Vec<Receipt> = identity
+        .receipt_stream(ReceiptFilter::new(), 2)
+        .collect::<SisterResult<_>>()
+        .unwrap();
+    let positions: Vec<u64> = all.iter().map(|r| r.chain_position).collect();
+    assert_eq!(positions, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_identity_batch_receipts() {
+    let identity = MockIdentity::init(SisterConfig::stateless()).unwrap();
+    identity
+        .create_receipt(ActionBuilder::new(SisterType::Memory, "memory_add").success())
+        .unwrap();
+
+    let actions = (0..3)
+        .map(|i| ActionBuilder::new(SisterType::Vision, format!("capture_{}", i)).success())
+        .collect();
+    let ids = identity.create_receipts(actions).unwrap();
+    assert_eq!(ids.len(), 3);
+
+    let positions: Vec<u64> = ids
+        .iter()
+        .map(|id| identity.get_receipt(*id).unwrap().chain_position)
+        .collect();
+    assert_eq!(positions, vec![2, 3, 4]);
+    assert_eq!(
+        identity.get_receipt(ids[2]).unwrap().action_type(),
+        "capture_2"
+    );
+}
+
+#[test]
+fn test_identity_receipt_subscription() {
+    let identity = MockIdentity::init(SisterConfig::stateless()).unwrap();
+    let mut captures = identity.subscribe_receipts(ReceiptFilter::new().action("vision_capture"));
+
+    identity
+        .create_receipt(ActionBuilder::new(SisterType::Memory, "memory_add").success())
+        .unwrap();
+    let id = identity
+        .create_receipt(ActionBuilder::new(SisterType::Vision, "vision_capture").success())
+        .unwrap();
+
+    assert_eq!(captures.try_recv().unwrap().id, id);
+    assert!(captures.try_recv().is_err());
+}
+
+#[test]
+fn test_identity_receipt_compaction() {
+    let identity = MockIdentity::init(SisterConfig::stateless()).unwrap();
+    for i in 0..3 {
+        identity
+            .create_receipt(ActionBuilder::new(SisterType::Memory, format!("old_{}", i)).success())
+            .unwrap();
+    }
+    let cutoff = Utc::now() + chrono::Duration::milliseconds(1);
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    identity
+        .create_receipt(ActionBuilder::new(SisterType::Memory, "new").success())
+        .unwrap();
+
+    let segment = identity.compact_receipts(cutoff).unwrap().unwrap();
+    assert_eq!((segment.first_position, segment.last_position), (1, 3));
+    assert_eq!(identity.receipt_count().unwrap(), 1);
+    identity.verify_chain().unwrap();
+
+    // Nothing left before the cutoff
+    assert!(identity.compact_receipts(cutoff).unwrap().is_none());
+}
+
 #[test]
 fn test_time_stateless() {
     // Time uses stateless config — no data path needed
@@ -1699,7 +763,7 @@ fn test_contract_full_featured() {
 
     // Export session
     let snapshot = contract.export_session(session_id).unwrap();
-    assert!(snapshot.verify());
+    assert!(snapshot.verify().unwrap());
     assert_eq!(snapshot.sister_type, SisterType::Contract);
 
     // SisterInfo
@@ -1837,6 +901,13 @@ fn test_hydra_placeholder_types() {
         params: Metadata::new(),
         run_id: "run_001".to_string(),
         step_id: 1,
+        budget: None,
+        dry_run: false,
+        idempotency_key: None,
+        priority: Priority::Normal,
+        deadline: None,
+        exclusive: false,
+        accept: vec![],
     };
     assert_eq!(cmd.command_type, "summarize_recent");
 
@@ -1846,6 +917,7 @@ fn test_hydra_placeholder_types() {
         data: serde_json::json!({"summary": "3 new facts"}),
         error: None,
         evidence_ids: vec!["ev_1".to_string()],
+        usage: None,
     };
     assert!(result.success);
 
@@ -1858,6 +930,8 @@ fn test_hydra_placeholder_types() {
         capability: "trust:grant".to_string(),
         requested_at: Utc::now(),
         params: Metadata::new(),
+        token: None,
+        dry_run: false,
     };
     assert!(action.risk_level >= RiskLevel::Medium);
 
@@ -1901,4 +975,149 @@ fn test_action_outcomes() {
     let partial = ActionOutcome::partial(vec!["Field X was truncated".to_string()]);
     assert!(!partial.is_success());
     assert!(!partial.is_failure());
+    assert!(partial.errors().is_none());
+
+    let mut errors = ErrorGroup::new(3);
+    errors.push(1, SisterError::not_found("claim 1"));
+    let batch = ActionOutcome::partial_failure(&errors);
+    assert_eq!(batch.errors().unwrap().failed_indices(), vec![1]);
+    let json = serde_json::to_value(&batch).unwrap();
+    assert_eq!(json["status"], "partial");
+    assert_eq!(json["errors"]["errors"][0]["index"], 1);
+    assert!(json["warnings"][0].as_str().unwrap().contains("NOT_FOUND"));
+}
+
+#[test]
+fn test_pause_resume() {
+    let mut memory = MockMemory::init(SisterConfig::stateless()).unwrap();
+    memory.pause().unwrap();
+    assert_eq!(memory.health().status, Status::Paused);
+    assert!(memory.check_live() && !memory.check_ready());
+    assert_eq!(memory.pause().unwrap_err().code, ErrorCode::InvalidState);
+    memory.resume().unwrap();
+    assert_eq!(memory.health().status, Status::Ready);
+
+    let recent = memory.recent_events(2);
+    assert!(matches!(recent[0].event_type, EventType::Resumed));
+    assert!(matches!(recent[1].event_type, EventType::Paused));
+
+    let mut codebase = MockCodebase::init(SisterConfig::stateless()).unwrap();
+    assert_eq!(
+        codebase.pause().unwrap_err().code,
+        ErrorCode::NotImplemented
+    );
+}
+
+#[test]
+fn test_hot_config_reload() {
+    let config = SisterConfig::new("/data/memory.amem");
+    let mut memory = MockMemory::init(config.clone()).unwrap();
+
+    let diff = memory
+        .update_config(config.clone().memory_budget(128))
+        .unwrap();
+    assert_eq!(diff.changed, vec!["memory_budget_mb"]);
+    assert_eq!(memory.current_config().unwrap().memory_budget_mb, Some(128));
+    assert!(matches!(
+        &memory.recent_events(1)[0].event_type,
+        EventType::ConfigChanged { changed } if changed == &diff.changed
+    ));
+
+    let err = memory
+        .update_config(SisterConfig::new("/elsewhere.amem"))
+        .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+
+    let mut codebase = MockCodebase::init(config).unwrap();
+    assert_eq!(
+        codebase
+            .update_config(SisterConfig::stateless())
+            .unwrap_err()
+            .code,
+        ErrorCode::NotImplemented
+    );
+}
+
+#[test]
+fn test_default_self_test() {
+    let config = SisterConfig::new("/nonexistent/memory.amem").create_if_missing(false);
+    let memory = MockMemory::init(config).unwrap();
+
+    let report = memory.self_test(DiagnosticLevel::Quick).unwrap();
+    assert_eq!(report.checks, vec![CHECK_HEALTH, CHECK_STORAGE]);
+    assert!(!report.passed());
+    assert_eq!(report.findings_for(CHECK_STORAGE).count(), 1);
+
+    let codebase = MockCodebase::init(SisterConfig::stateless()).unwrap();
+    assert!(codebase.self_test(DiagnosticLevel::Deep).unwrap().passed());
+}
+
+#[test]
+fn test_read_only_wrapper() {
+    let mut memory = ReadOnly::<MockMemory>::init(SisterConfig::new("/data/memory.amem")).unwrap();
+    assert!(memory.current_config().unwrap().read_only);
+    assert!(memory.query(Query::recent(5)).is_ok());
+    assert!(memory.list_sessions().is_ok());
+
+    let err = memory.start_session("debug").unwrap_err();
+    assert_eq!(err.code, ErrorCode::PermissionDenied);
+    assert_eq!(err.context.unwrap()["operation"], "start_session");
+    assert_eq!(
+        memory.checkpoint().unwrap_err().code,
+        ErrorCode::PermissionDenied
+    );
+
+    let identity = MockIdentity::init(SisterConfig::new("/data/identity.aid")).unwrap();
+    identity
+        .create_receipt(ActionRecord::new(
+            SisterType::Memory,
+            "memory_add",
+            ActionOutcome::success(),
+        ))
+        .unwrap();
+    let identity = ReadOnly::new(identity);
+    assert_eq!(identity.receipt_count().unwrap(), 1);
+    let action = ActionRecord::new(SisterType::Memory, "memory_add", ActionOutcome::success());
+    assert_eq!(
+        identity.create_receipt(action).unwrap_err().code,
+        ErrorCode::PermissionDenied
+    );
+    assert_eq!(identity.receipt_count().unwrap(), 1);
+}
+
+#[test]
+fn test_disabled_capabilities() {
+    let config = SisterConfig::new("/data/memory.amem").disable_capability("memory_ground");
+    let memory = MockMemory::init(config).unwrap();
+
+    let names: Vec<String> = memory.capabilities().into_iter().map(|c| c.name).collect();
+    assert_eq!(names, vec!["memory_add", "memory_query", "memory_similar"]);
+
+    let err = memory.ground("the user prefers dark mode").unwrap_err();
+    assert_eq!(err.code, ErrorCode::PermissionDenied);
+    assert_eq!(err.context.unwrap()["capability"], "memory_ground");
+}
+
+#[test]
+fn test_discovery_document() {
+    let config = SisterConfig::new("/data/memory.amem").secret("embedding_api_key", "sk-123");
+    let mut memory = MockMemory::init(config).unwrap();
+    let doc = DiscoveryDocument::from(&memory);
+    assert_eq!(doc.sister_type(), SisterType::Memory);
+    assert!(doc.context.is_none());
+    assert!(!doc.query_types.is_empty());
+
+    let session = memory.start_session("overview").unwrap();
+    let json = serde_json::to_value(DiscoveryDocument::from(&memory)).unwrap();
+    assert_eq!(json["mcp_prefix"], "memory");
+    assert_eq!(
+        json["context"]["id"],
+        serde_json::to_value(session).unwrap()
+    );
+    assert_eq!(json["health"]["status"], "ready");
+    assert_eq!(json["config"]["secrets"]["embedding_api_key"], REDACTED);
+
+    let codebase = MockCodebase::init(SisterConfig::stateless()).unwrap();
+    let doc = DiscoveryDocument::from_workspaces(&codebase);
+    assert!(doc.context.is_some());
 }